@@ -15,9 +15,11 @@ use itertools::assert_equal;
 use itertools::{repeat_n, Itertools};
 
 use petgraph::graph::{edge_index, node_index, IndexType};
+use petgraph::matrix_graph::{MatrixGraph, NotZero, Nullable};
 use petgraph::prelude::*;
 use petgraph::visit::EdgeRef;
 use petgraph::visit::IntoEdgeReferences;
+use petgraph::visit::IntoNodeIdentifiers;
 use petgraph::visit::NodeIndexable;
 use petgraph::EdgeType;
 
@@ -365,6 +367,28 @@ fn json_stable_graph_nils() {
     assert_stable_graph_eq(&g1, &g2);
 }
 
+#[test]
+fn json_stable_graph_keeps_indices() {
+    let mut g1 = StableGraph::<i32, i32>::new();
+    let a = g1.add_node(0);
+    let b = g1.add_node(1);
+    let c = g1.add_node(2);
+    let d = g1.add_node(3);
+    let ab = g1.add_edge(a, b, 0);
+    let bc = g1.add_edge(b, c, 1);
+    let cd = g1.add_edge(c, d, 2);
+    let da = g1.add_edge(d, a, 3);
+    g1.remove_edge(bc);
+    g1.remove_node(c);
+
+    let g2: StableGraph<i32, i32> = rejson!(&g1);
+    assert_stable_graph_eq(&g1, &g2);
+    assert_equal(g2.node_indices(), vec![a, b, d]);
+    assert_equal(g2.edge_indices(), vec![ab, da]);
+    assert_eq!(g2.edge_endpoints(da), Some((d, a)));
+    assert!(g2.edge_weight(cd).is_none());
+}
+
 // bincode macros
 defmac!(encode ref g => bincode::serialize(g).unwrap());
 defmac!(decode ref data => bincode::deserialize(data).unwrap());
@@ -487,3 +511,88 @@ quickcheck! {
         assert_stable_graph_eq(&g1, &g2);
     }
 }
+
+fn assert_matrix_graph_eq<N, E, Ty, Null, Ix>(
+    g: &MatrixGraph<N, E, Ty, Null, Ix>,
+    h: &MatrixGraph<N, E, Ty, Null, Ix>,
+) where
+    N: PartialEq + Debug,
+    E: PartialEq + Debug,
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    assert_eq!(g.node_count(), h.node_count());
+    assert_eq!(g.edge_count(), h.edge_count());
+    assert_equal(g.node_identifiers(), h.node_identifiers());
+    for a in g.node_identifiers() {
+        assert_eq!(g.node_weight(a), h.node_weight(a));
+    }
+    assert_equal(g.edge_references(), h.edge_references());
+}
+
+#[test]
+fn json_matrix_graph_keeps_indices() {
+    let mut g1 = MatrixGraph::<_, _, Directed, Option<i32>, u8>::default();
+    let a = g1.add_node(10);
+    let b = g1.add_node(11);
+    let c = g1.add_node(12);
+    let d = g1.add_node(13);
+    g1.add_edge(a, b, 0);
+    g1.add_edge(b, c, 1);
+    g1.add_edge(c, d, 2);
+    g1.add_edge(d, a, 3);
+    g1.add_edge(d, d, 4);
+    g1.remove_node(b);
+    assert_eq!(g1.edge_count(), 3);
+
+    let json = serde_json::to_string(&g1).unwrap();
+    let g2: MatrixGraph<_, _, Directed, Option<i32>, u8> = serde_json::from_str(&json).unwrap();
+    assert_matrix_graph_eq(&g1, &g2);
+    assert_equal(g2.node_identifiers(), vec![a, c, d]);
+
+    // the same format as `StableGraph`
+    let sg: StableGraph<i32, i32, Directed, u8> = serde_json::from_str(&json).unwrap();
+    assert_equal(sg.node_indices(), vec![a, c, d]);
+    assert_eq!(sg.edge_count(), 3);
+    assert!(sg.find_edge(d, d).is_some());
+    let g3: MatrixGraph<_, _, Directed, Option<i32>, u8> = rejson!(&sg);
+    assert_matrix_graph_eq(&g1, &g3);
+}
+
+#[test]
+fn json_matrix_graph_undirected_not_zero() {
+    let mut g1 = MatrixGraph::<(), f32, Undirected, NotZero<f32>>::default();
+    let a = g1.add_node(());
+    let b = g1.add_node(());
+    let c = g1.add_node(());
+    g1.add_edge(a, b, 1.5);
+    g1.add_edge(c, b, 2.5);
+    g1.add_edge(c, c, 3.5);
+    let g2 = rejson!(&g1);
+    assert_matrix_graph_eq(&g1, &g2);
+}
+
+#[test]
+#[should_panic(expected = "parallel edge")]
+fn from_json_matrix_graph_parallel_edges() {
+    let mut sg = StableGraph::<(), ()>::new();
+    let a = sg.add_node(());
+    sg.add_edge(a, a, ());
+    sg.add_edge(a, a, ());
+    let _: MatrixGraph<(), ()> = rejson!(&sg);
+}
+
+quickcheck! {
+    fn json_matrix_graph_to_matrix_graph(g1: Graph<i32, i32>) -> () {
+        let mut m1 = MatrixGraph::<i32, i32, Directed, Option<i32>, u32>::default();
+        for &n in g1.node_weights() {
+            m1.add_node(n);
+        }
+        for e in g1.edge_references() {
+            m1.update_edge(node_index(e.source().index()), node_index(e.target().index()), *e.weight());
+        }
+        let m2 = rejson!(&m1);
+        assert_matrix_graph_eq(&m1, &m2);
+    }
+}
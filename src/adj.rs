@@ -1,10 +1,13 @@
 //! Simple adjacency list.
 use crate::data::{Build, DataMap, DataMapMut};
+use crate::graph::{node_index, Graph, MemoryUsage};
 use crate::iter_format::NoPretty;
 use crate::visit::{
     self, EdgeCount, EdgeRef, GetAdjacencyMatrix, IntoEdgeReferences, IntoNeighbors, NodeCount,
 };
+use crate::{Directed, Direction, Incoming, Outgoing};
 use fixedbitset::FixedBitSet;
+use std::cmp::Ordering;
 use std::fmt;
 use std::ops::Range;
 
@@ -59,6 +62,46 @@ item: NodeIndex<Ix>,
 iter: std::iter::Map<RowIter<'a, E, Ix>, fn(&WSuc<E, Ix>) -> NodeIndex<Ix>>,
 }
 
+/// An iterator over the common successors of two nodes.
+///
+/// Created with [`List::common_neighbors`].
+#[derive(Debug)]
+pub struct CommonNeighbors<'a, E, Ix: IndexType> {
+    a: &'a [WSuc<E, Ix>],
+    b: &'a [WSuc<E, Ix>],
+}
+
+impl<'a, E, Ix: IndexType> Clone for CommonNeighbors<'a, E, Ix> {
+    fn clone(&self) -> Self {
+        CommonNeighbors {
+            a: self.a,
+            b: self.b,
+        }
+    }
+}
+
+impl<'a, E, Ix: IndexType> Iterator for CommonNeighbors<'a, E, Ix> {
+    type Item = NodeIndex<Ix>;
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        while let (Some(x), Some(y)) = (self.a.first(), self.b.first()) {
+            match x.suc.cmp(&y.suc) {
+                Ordering::Less => self.a = &self.a[1..],
+                Ordering::Greater => self.b = &self.b[1..],
+                Ordering::Equal => {
+                    self.a = &self.a[1..];
+                    self.b = &self.b[1..];
+                    return Some(x.suc);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.a.len().min(self.b.len())))
+    }
+}
+
 /// A reference to an edge of the graph.
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct EdgeReference<'a, E, Ix: IndexType> {
@@ -149,35 +192,205 @@ iterator_wrap! {
 ///
 /// Allows parallel edges and self-loops.
 ///
-/// This data structure is append-only (except for [`clear`](#method.clear)), so indices
-/// returned at some point for a given graph will stay valid with this same
-/// graph until it is dropped or [`clear`](#method.clear) is called.
+/// Node and edge indices stay valid as long as nothing is removed from the
+/// graph. The removal methods document how they change the indices:
+///
+/// - [`remove_node`](#method.remove_node) moves the last node into the place
+///   of the removed node, like `Graph` does.
+/// - [`remove_edge`](#method.remove_edge) moves the last outgoing edge of the
+///   same source into the place of the removed edge.
+/// - [`retain_nodes`](#method.retain_nodes) and
+///   [`retain_edges`](#method.retain_edges) keep the relative order of the
+///   remaining nodes and edges, and number them consecutively.
+///
+/// The list keeps track of whether the successors of every node are sorted
+/// by index, see [`sort_neighbors`](#method.sort_neighbors). While they are,
+/// edge lookups use binary search and
+/// [`common_neighbors`](#method.common_neighbors) is available.
 ///
 /// Space consumption: **O(|E|)**.
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct List<E, Ix = DefaultIx>
 where
     Ix: IndexType,
 {
     suc: Vec<Row<E, Ix>>,
+    /// Whether every row is sorted by successor.
+    sorted: bool,
+}
+
+impl<E, Ix: IndexType> Default for List<E, Ix> {
+    fn default() -> Self {
+        List::new()
+    }
 }
 
 impl<E, Ix: IndexType> List<E, Ix> {
     /// Creates a new, empty adjacency list.
     pub fn new() -> List<E, Ix> {
-        List { suc: Vec::new() }
+        List {
+            suc: Vec::new(),
+            sorted: true,
+        }
     }
 
     /// Creates a new, empty adjacency list tailored for `nodes` nodes.
     pub fn with_capacity(nodes: usize) -> List<E, Ix> {
         List {
             suc: Vec::with_capacity(nodes),
+            sorted: true,
         }
     }
 
     /// Removes all nodes and edges from the list.
     pub fn clear(&mut self) {
-        self.suc.clear()
+        self.suc.clear();
+        self.sorted = true;
+    }
+
+    /// Shrinks the capacity of the list, including the successors of each
+    /// node, as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.suc.shrink_to_fit();
+        for row in &mut self.suc {
+            row.shrink_to_fit();
+        }
+    }
+
+    /// Return an estimate of the heap memory held by the list.
+    ///
+    /// The successor list of each node is counted as a node, and its entries
+    /// as edges.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let row = std::mem::size_of::<Row<E, Ix>>();
+        let suc = std::mem::size_of::<WSuc<E, Ix>>();
+        let mut usage = MemoryUsage {
+            nodes: self.suc.len() * row,
+            unused: (self.suc.capacity() - self.suc.len()) * row,
+            ..MemoryUsage::default()
+        };
+        for r in &self.suc {
+            usage.edges += r.len() * suc;
+            usage.unused += (r.capacity() - r.len()) * suc;
+        }
+        usage
+    }
+
+    /// Sorts the successors of every node by index, keeping parallel edges
+    /// in their relative order.
+    ///
+    /// Afterwards the neighbors of each node are visited in increasing
+    /// order, [`contains_edge`](#method.contains_edge) and
+    /// [`find_edge`](#method.find_edge) use binary search, and
+    /// [`common_neighbors`](#method.common_neighbors) can be used. The list
+    /// stays sorted until an edge is added or moved out of order, which
+    /// [`is_sorted`](#method.is_sorted) reports.
+    ///
+    /// This invalidates the edge indices of the edges that move.
+    ///
+    /// Computes in **O(|V| + |E| log e')** time, where **e'** is the largest
+    /// number of successors of a node.
+    ///
+    /// ```
+    /// use petgraph::adj::List;
+    /// use petgraph::visit::IntoNeighbors;
+    ///
+    /// let mut g = List::<()>::new();
+    /// let a = g.add_node();
+    /// let b = g.add_node();
+    /// let c = g.add_node();
+    /// g.add_edge(a, c, ());
+    /// g.add_edge(a, b, ());
+    /// assert!(!g.is_sorted());
+    ///
+    /// g.sort_neighbors();
+    /// assert!(g.is_sorted());
+    /// assert_eq!(g.neighbors(a).collect::<Vec<_>>(), vec![b, c]);
+    ///
+    /// // adding edges in increasing order keeps the list sorted
+    /// g.add_edge(a, c, ());
+    /// assert!(g.is_sorted());
+    /// ```
+    pub fn sort_neighbors(&mut self) {
+        if !self.sorted {
+            for row in &mut self.suc {
+                row.sort_by_key(|x| x.suc);
+            }
+            self.sorted = true;
+        }
+    }
+
+    /// Returns `true` if the successors of every node are known to be sorted
+    /// by index.
+    ///
+    /// A new list is sorted, and so is a list after
+    /// [`sort_neighbors`](#method.sort_neighbors). Adding an edge to a
+    /// smaller node than the last successor of its source, or removing an
+    /// edge that moves another one out of order, makes it unsorted.
+    pub fn is_sorted(&self) -> bool {
+        self.sorted
+    }
+
+    /// Returns an iterator over the nodes that are successors of both `a`
+    /// and `b`, in increasing order.
+    ///
+    /// A node that is the target of parallel edges is listed as many times as
+    /// the smaller number of edges to it from `a` or from `b`.
+    ///
+    /// Computes in **O(e'<sub>a</sub> + e'<sub>b</sub>)** time, where
+    /// **e'<sub>x</sub>** is the number of successors of **x**.
+    ///
+    /// **Panics** if the list is not sorted, or if `a` or `b` does not exist.
+    ///
+    /// ```
+    /// use petgraph::adj::List;
+    /// use petgraph::visit::IntoNeighbors;
+    ///
+    /// // count the triangles of an undirected graph, with each edge
+    /// // stored in both directions
+    /// let mut g = List::<()>::new();
+    /// let n: Vec<_> = (0..4).map(|_| g.add_node()).collect();
+    /// for &(a, b) in &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)] {
+    ///     g.add_edge(n[a], n[b], ());
+    ///     g.add_edge(n[b], n[a], ());
+    /// }
+    /// g.sort_neighbors();
+    ///
+    /// let mut triangles = 0;
+    /// for a in g.node_indices() {
+    ///     for b in g.neighbors(a).filter(|&b| a < b) {
+    ///         triangles += g.common_neighbors(a, b).filter(|&c| b < c).count();
+    ///     }
+    /// }
+    /// assert_eq!(triangles, 2);
+    /// ```
+    pub fn common_neighbors(
+        &self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+    ) -> CommonNeighbors<'_, E, Ix> {
+        assert!(
+            self.sorted,
+            "List::common_neighbors: the successors are not sorted"
+        );
+        CommonNeighbors {
+            a: &self.suc[a.index()],
+            b: &self.suc[b.index()],
+        }
+    }
+
+    /// Returns the position of the first successor of `row` not less than
+    /// `b`, if the list is sorted.
+    fn lower_bound(row: &[WSuc<E, Ix>], b: NodeIndex<Ix>) -> usize {
+        match row.binary_search_by(|x| {
+            if x.suc < b {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        }) {
+            Ok(i) | Err(i) => i,
+        }
     }
 
     /// Returns the number of edges in the list
@@ -210,8 +423,11 @@ impl<E, Ix: IndexType> List<E, Ix> {
         edges: I,
     ) -> NodeIndex<Ix> {
         let i = self.suc.len();
-        self.suc
-            .push(edges.map(|(suc, weight)| WSuc { suc, weight }).collect());
+        let row: Row<E, Ix> = edges.map(|(suc, weight)| WSuc { suc, weight }).collect();
+        if row.windows(2).any(|w| w[1].suc < w[0].suc) {
+            self.sorted = false;
+        }
+        self.suc.push(row);
         Ix::new(i)
     }
 
@@ -236,6 +452,9 @@ impl<E, Ix: IndexType> List<E, Ix> {
         }
         let row = &mut self.suc[a.index()];
         let rank = row.len();
+        if row.last().map_or(false, |x| b < x.suc) {
+            self.sorted = false;
+        }
         row.push(WSuc { suc: b, weight });
         EdgeIndex {
             from: a,
@@ -255,6 +474,122 @@ impl<E, Ix: IndexType> List<E, Ix> {
             .and_then(|row| row.get_mut(e.successor_index))
     }
 
+    /// Remove `a` from the graph, along with all its edges, and return `true`
+    /// if it existed.
+    ///
+    /// Apart from `a`, this invalidates the last node index in the graph
+    /// (that node will adopt the removed node index). Edge indices are
+    /// invalidated for the outgoing edges of `a`, and for the outgoing edges
+    /// of any node that come after a removed edge to `a`.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> bool {
+        if a.index() >= self.suc.len() {
+            return false;
+        }
+        self.suc.swap_remove(a.index());
+        let last = Ix::new(self.suc.len());
+        for row in &mut self.suc {
+            row.retain(|x| x.suc != a);
+            let mut moved = false;
+            for x in row.iter_mut().filter(|x| x.suc == last) {
+                x.suc = a;
+                moved = true;
+            }
+            if moved && self.sorted {
+                row.sort_by_key(|x| x.suc);
+            }
+        }
+        true
+    }
+
+    /// Remove the edge `e` from the graph and return its weight, or `None`
+    /// if it didn't exist.
+    ///
+    /// Apart from `e`, this invalidates the index of the last outgoing edge
+    /// of the same source (that edge will adopt the removed edge index).
+    ///
+    /// Computes in **O(1)** time.
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> Option<E> {
+        let row = self.suc.get_mut(e.from.index())?;
+        let i = e.successor_index;
+        if i < row.len() {
+            let weight = row.swap_remove(i).weight;
+            // the last successor moved into place `i`
+            if i + 1 < row.len() && row[i + 1].suc < row[i].suc {
+                self.sorted = false;
+            }
+            Some(weight)
+        } else {
+            None
+        }
+    }
+
+    /// Keep all nodes that return `true` from the `visit` closure, remove
+    /// the others along with their edges.
+    ///
+    /// The remaining nodes keep their relative order and are numbered
+    /// consecutively from zero, and so are the remaining outgoing edges of
+    /// each node.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn retain_nodes<F>(&mut self, mut visit: F)
+    where
+        F: FnMut(NodeIndex<Ix>) -> bool,
+    {
+        let mut node_map = Vec::with_capacity(self.suc.len());
+        let mut node_count = 0;
+        for i in 0..self.suc.len() {
+            if visit(Ix::new(i)) {
+                node_map.push(Some(Ix::new(node_count)));
+                node_count += 1;
+            } else {
+                node_map.push(None);
+            }
+        }
+        let mut keep = node_map.iter().map(Option::is_some);
+        self.suc.retain(|_| keep.next().unwrap());
+        for row in &mut self.suc {
+            row.retain(|x| node_map[x.suc.index()].is_some());
+            for x in row.iter_mut() {
+                x.suc = node_map[x.suc.index()].unwrap();
+            }
+        }
+    }
+
+    /// Keep all edges that return `true` from the `visit` closure, remove
+    /// the others.
+    ///
+    /// `visit` is called with the source, target and weight of each edge.
+    /// The remaining outgoing edges of each node keep their relative order
+    /// and are numbered consecutively from zero.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    ///
+    /// ```
+    /// use petgraph::adj::List;
+    ///
+    /// let mut g = List::<i32>::new();
+    /// let a = g.add_node();
+    /// let b = g.add_node();
+    /// g.add_edge(a, b, 1);
+    /// g.add_edge(a, a, 2);
+    /// g.add_edge(b, a, 3);
+    ///
+    /// g.retain_edges(|source, target, &weight| source != target && weight > 1);
+    /// assert_eq!(g.edge_count(), 1);
+    /// assert!(g.contains_edge(b, a));
+    /// ```
+    pub fn retain_edges<F>(&mut self, mut visit: F)
+    where
+        F: FnMut(NodeIndex<Ix>, NodeIndex<Ix>, &E) -> bool,
+    {
+        for (i, row) in self.suc.iter_mut().enumerate() {
+            let a = Ix::new(i);
+            row.retain(|x| visit(a, x.suc, &x.weight));
+        }
+    }
+
     /// Accesses the source and target of edge `e`
     ///
     /// Computes in **O(1)**
@@ -276,26 +611,29 @@ impl<E, Ix: IndexType> List<E, Ix> {
 
     /// Lookups whether there is an edge from `a` to `b`.
     ///
-    /// Computes in **O(e')** time, where **e'** is the number of successors of `a`.
+    /// Computes in **O(e')** time, where **e'** is the number of successors of `a`,
+    /// or in **O(log e')** time if the list [is sorted](#method.is_sorted).
     pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
-        match self.suc.get(a.index()) {
-            None => false,
-            Some(row) => row.iter().any(|x| x.suc == b),
-        }
+        self.find_edge(a, b).is_some()
     }
 
     /// Lookups whether there is an edge from `a` to `b`.
     ///
-    /// Computes in **O(e')** time, where **e'** is the number of successors of `a`.
+    /// Returns the edge from `a` to `b` with the smallest index, if any.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of successors of `a`,
+    /// or in **O(log e')** time if the list [is sorted](#method.is_sorted).
     pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
-        self.suc.get(a.index()).and_then(|row| {
-            row.iter()
-                .enumerate()
-                .find(|(_, x)| x.suc == b)
-                .map(|(i, _)| EdgeIndex {
-                    from: a,
-                    successor_index: i,
-                })
+        let row = self.suc.get(a.index())?;
+        let i = if self.sorted {
+            let i = Self::lower_bound(row, b);
+            Some(i).filter(|&i| i < row.len() && row[i].suc == b)
+        } else {
+            row.iter().position(|x| x.suc == b)
+        };
+        i.map(|i| EdgeIndex {
+            from: a,
+            successor_index: i,
         })
     }
 
@@ -324,6 +662,39 @@ impl<E, Ix: IndexType> List<E, Ix> {
 /// A very simple adjacency list with no node or label weights.
 pub type UnweightedList<Ix> = List<(), Ix>;
 
+/// Convert a `List` into a `Graph` with `()` node weights, with the edges
+/// listed by source, in the order they were added.
+impl<E, Ix: IndexType> From<List<E, Ix>> for Graph<(), E, Directed, Ix> {
+    fn from(list: List<E, Ix>) -> Self {
+        let mut graph = Graph::with_capacity(list.node_count(), list.edge_count());
+        for _ in 0..list.node_count() {
+            graph.add_node(());
+        }
+        for (a, row) in list.suc.into_iter().enumerate() {
+            for WSuc { suc, weight } in row {
+                graph.add_edge(node_index(a), node_index(suc.index()), weight);
+            }
+        }
+        graph
+    }
+}
+
+/// Convert a `Graph` into a `List`, dropping the node weights.
+impl<N, E, Ix: IndexType> From<Graph<N, E, Directed, Ix>> for List<E, Ix> {
+    fn from(graph: Graph<N, E, Directed, Ix>) -> Self {
+        let (nodes, edges) = graph.into_nodes_edges();
+        let mut list = List::with_capacity(nodes.len());
+        for _ in 0..nodes.len() {
+            list.add_node();
+        }
+        for edge in edges {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            list.add_edge(Ix::new(a), Ix::new(b), edge.weight);
+        }
+        list
+    }
+}
+
 impl<E, Ix: IndexType> Build for List<E, Ix> {
     /// Adds a new node to the list. This allocates a new `Vec` and then should
     /// run in amortized **O(1)** time.
@@ -351,25 +722,16 @@ impl<E, Ix: IndexType> Build for List<E, Ix> {
     ///
     /// Return the index of the new edge.
     ///
-    /// Computes in **O(e')** time, where **e'** is the number of successors of `a`.
+    /// Computes in **O(e')** time, where **e'** is the number of successors of `a`,
+    /// or in **O(log e')** time if the list [is sorted](#method.is_sorted).
     ///
     /// **Panics** if the source node does not exist.<br>
     fn update_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
-        let row = &mut self.suc[a.index()];
-        for (i, info) in row.iter_mut().enumerate() {
-            if info.suc == b {
-                info.weight = weight;
-                return EdgeIndex {
-                    from: a,
-                    successor_index: i,
-                };
-            }
-        }
-        let rank = row.len();
-        row.push(WSuc { suc: b, weight });
-        EdgeIndex {
-            from: a,
-            successor_index: rank,
+        if let Some(e) = self.find_edge(a, b) {
+            self.suc[a.index()][e.successor_index].weight = weight;
+            e
+        } else {
+            self.add_edge(a, b, weight)
         }
     }
 }
@@ -578,6 +940,23 @@ impl<E, Ix: IndexType> EdgeCount for List<E, Ix> {
     }
 }
 
+impl<E, Ix: IndexType> visit::NodeDegrees for List<E, Ix> {
+    /// Returns the number of edges from `a` or to `a`.
+    ///
+    /// Computes in **O(1)** time for outgoing edges, and in **O(|E|)** time
+    /// for incoming edges.
+    /// Panics if `a` is out of bounds.
+    fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        match dir {
+            Outgoing => self.suc[a.index()].len(),
+            Incoming => {
+                assert!(a.index() < self.suc.len());
+                self.suc.iter().flatten().filter(|e| e.suc == a).count()
+            }
+        }
+    }
+}
+
 impl<E, Ix: IndexType> visit::NodeIndexable for List<E, Ix> {
     fn node_bound(&self) -> usize {
         self.node_count()
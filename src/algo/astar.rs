@@ -1,10 +1,10 @@
-use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::{BinaryHeap, HashMap};
 
 use std::hash::Hash;
 
+use super::score_map::{IndexedScores, ScoreMap};
 use crate::scored::MinScored;
-use crate::visit::{EdgeRef, GraphBase, IntoEdges, Visitable};
+use crate::visit::{EdgeRef, IntoEdges, NodeIndexable, Visitable};
 
 use crate::algo::Measure;
 
@@ -64,73 +64,132 @@ use crate::algo::Measure;
 /// Returns the total cost + the path of subsequent `NodeId` from start to finish, if one was
 /// found.
 pub fn astar<G, F, H, K, IsGoal>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: IsGoal,
+    edge_cost: F,
+    estimate_cost: H,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdges + Visitable,
+    IsGoal: FnMut(G::NodeId) -> bool,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    H: FnMut(G::NodeId) -> K,
+    K: Measure + Copy,
+{
+    astar_scores(
+        graph,
+        start,
+        is_goal,
+        edge_cost,
+        estimate_cost,
+        &mut HashMap::new(),
+        &mut HashMap::new(),
+        &mut HashMap::new(),
+    )
+}
+
+/// \[Generic\] A* shortest path algorithm, with the scores of the nodes in
+/// vectors indexed by the node indices from `NodeIndexable`.
+///
+/// This is [`astar`](fn.astar.html) without hashing the nodes, which is
+/// faster for graphs with dense node indices, like `Graph` and `Csr`.
+///
+/// # Example
+/// ```
+/// use petgraph::algo::astar_indexed;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), u32>::from_edges(&[(0, 1, 2), (1, 2, 3), (0, 2, 7)]);
+/// let path = astar_indexed(&g, 0.into(), |n| n.index() == 2, |e| *e.weight(), |_| 0);
+/// assert_eq!(path, Some((5, vec![0.into(), 1.into(), 2.into()])));
+/// ```
+pub fn astar_indexed<G, F, H, K, IsGoal>(
+    graph: G,
+    start: G::NodeId,
+    is_goal: IsGoal,
+    edge_cost: F,
+    estimate_cost: H,
+) -> Option<(K, Vec<G::NodeId>)>
+where
+    G: IntoEdges + NodeIndexable,
+    IsGoal: FnMut(G::NodeId) -> bool,
+    F: FnMut(G::EdgeRef) -> K,
+    H: FnMut(G::NodeId) -> K,
+    K: Measure + Copy,
+{
+    astar_scores(
+        graph,
+        start,
+        is_goal,
+        edge_cost,
+        estimate_cost,
+        &mut IndexedScores::new(graph),
+        &mut IndexedScores::new(graph),
+        &mut IndexedScores::new(graph),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn astar_scores<G, F, H, K, IsGoal, S, P>(
     graph: G,
     start: G::NodeId,
     mut is_goal: IsGoal,
     mut edge_cost: F,
     mut estimate_cost: H,
+    scores: &mut S,
+    estimate_scores: &mut S,
+    came_from: &mut P,
 ) -> Option<(K, Vec<G::NodeId>)>
 where
-    G: IntoEdges + Visitable,
+    G: IntoEdges,
     IsGoal: FnMut(G::NodeId) -> bool,
-    G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> K,
     H: FnMut(G::NodeId) -> K,
     K: Measure + Copy,
+    // g-values, cost to reach the node, and f-values, cost to reach + estimate cost to goal
+    S: ScoreMap<G::NodeId, K>,
+    P: ScoreMap<G::NodeId, G::NodeId>,
 {
     let mut visit_next = BinaryHeap::new();
-    let mut scores = HashMap::new(); // g-values, cost to reach the node
-    let mut estimate_scores = HashMap::new(); // f-values, cost to reach + estimate cost to goal
-    let mut path_tracker = PathTracker::<G>::new();
 
     let zero_score = K::default();
-    scores.insert(start, zero_score);
+    scores.set_score(start, zero_score);
     visit_next.push(MinScored(estimate_cost(start), start));
 
     while let Some(MinScored(estimate_score, node)) = visit_next.pop() {
-        if is_goal(node) {
-            let path = path_tracker.reconstruct_path_to(node);
-            let cost = scores[&node];
-            return Some((cost, path));
-        }
-
         // This lookup can be unwrapped without fear of panic since the node was necessarily scored
         // before adding it to `visit_next`.
-        let node_score = scores[&node];
+        let node_score = scores.get_score(node).unwrap();
 
-        match estimate_scores.entry(node) {
-            Occupied(mut entry) => {
-                // If the node has already been visited with an equal or lower score than now, then
-                // we do not need to re-visit it.
-                if *entry.get() <= estimate_score {
-                    continue;
-                }
-                entry.insert(estimate_score);
-            }
-            Vacant(entry) => {
-                entry.insert(estimate_score);
+        if is_goal(node) {
+            return Some((node_score, reconstruct_path_to(came_from, node)));
+        }
+
+        // If the node has already been visited with an equal or lower score than now, then
+        // we do not need to re-visit it.
+        if let Some(score) = estimate_scores.get_score(node) {
+            if score <= estimate_score {
+                continue;
             }
         }
+        estimate_scores.set_score(node, estimate_score);
 
         for edge in graph.edges(node) {
             let next = edge.target();
             let next_score = node_score + edge_cost(edge);
 
-            match scores.entry(next) {
-                Occupied(mut entry) => {
-                    // No need to add neighbors that we have already reached through a shorter path
-                    // than now.
-                    if *entry.get() <= next_score {
-                        continue;
-                    }
-                    entry.insert(next_score);
-                }
-                Vacant(entry) => {
-                    entry.insert(next_score);
+            // No need to add neighbors that we have already reached through a shorter path
+            // than now.
+            if let Some(score) = scores.get_score(next) {
+                if score <= next_score {
+                    continue;
                 }
             }
+            scores.set_score(next, next_score);
 
-            path_tracker.set_predecessor(next, node);
+            came_from.set_score(next, node);
             let next_estimate_score = next_score + estimate_cost(next);
             visit_next.push(MinScored(next_estimate_score, next));
         }
@@ -139,40 +198,20 @@ where
     None
 }
 
-struct PathTracker<G>
+fn reconstruct_path_to<N, P>(came_from: &P, last: N) -> Vec<N>
 where
-    G: GraphBase,
-    G::NodeId: Eq + Hash,
+    N: Copy,
+    P: ScoreMap<N, N>,
 {
-    came_from: HashMap<G::NodeId, G::NodeId>,
-}
+    let mut path = vec![last];
 
-impl<G> PathTracker<G>
-where
-    G: GraphBase,
-    G::NodeId: Eq + Hash,
-{
-    fn new() -> PathTracker<G> {
-        PathTracker {
-            came_from: HashMap::new(),
-        }
+    let mut current = last;
+    while let Some(previous) = came_from.get_score(current) {
+        path.push(previous);
+        current = previous;
     }
 
-    fn set_predecessor(&mut self, node: G::NodeId, previous: G::NodeId) {
-        self.came_from.insert(node, previous);
-    }
-
-    fn reconstruct_path_to(&self, last: G::NodeId) -> Vec<G::NodeId> {
-        let mut path = vec![last];
-
-        let mut current = last;
-        while let Some(&previous) = self.came_from.get(&current) {
-            path.push(previous);
-            current = previous;
-        }
-
-        path.reverse();
+    path.reverse();
 
-        path
-    }
+    path
 }
@@ -2,9 +2,13 @@
 
 use crate::prelude::*;
 
-use crate::visit::{IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable, VisitMap, Visitable};
+use crate::visit::{
+    Data, GraphBase, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable, VisitMap, Visitable,
+};
+#[cfg(feature = "rayon")]
+use crate::visit::{GraphProp, IntoParallelEdgeReferences};
 
-use super::{FloatMeasure, NegativeCycle};
+use super::{BoundedMeasure, Error};
 
 #[derive(Debug, Clone)]
 pub struct Paths<NodeId, EdgeWeight> {
@@ -12,15 +16,24 @@ pub struct Paths<NodeId, EdgeWeight> {
     pub predecessors: Vec<Option<NodeId>>,
 }
 
+type PathsResult<G> = Result<
+    Paths<<G as GraphBase>::NodeId, <G as Data>::EdgeWeight>,
+    Error<<G as GraphBase>::NodeId>,
+>;
+
 /// \[Generic\] Compute shortest paths from node `source` to all other.
 ///
 /// Using the [Bellman–Ford algorithm][bf]; negative edge costs are
 /// permitted, but the graph must not have a cycle of negative weights
-/// (in that case it will return an error).
+/// (in that case it will return an `Error::NegativeCycle` error).
 ///
 /// On success, return one vec with path costs, and another one which points
 /// out the predecessor of a node along a shortest path. The vectors
-/// are indexed by the graph's node indices.
+/// are indexed by the graph's node indices. Nodes that can't be reached from
+/// `source` have the cost `BoundedMeasure::max()`, which is infinity for
+/// floats.
+///
+/// Edge costs may be floats or integers; sums that overflow are not used.
 ///
 /// [bf]: https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm
 ///
@@ -77,14 +90,16 @@ pub struct Paths<NodeId, EdgeWeight> {
 /// ]);
 ///
 /// assert!(bellman_ford(&graph_with_neg_cycle, NodeIndex::new(0)).is_err());
+///
+/// // Integer costs work the same way, and `d` can't be reached from `a`.
+/// let g = DiGraph::<(), i32>::from_edges(&[(0, 1, 3), (1, 2, -2), (0, 2, 2), (3, 2, 1)]);
+/// let path = bellman_ford(&g, a).unwrap();
+/// assert_eq!(path.distances, vec![0, 3, 1, std::i32::MAX]);
 /// ```
-pub fn bellman_ford<G>(
-    g: G,
-    source: G::NodeId,
-) -> Result<Paths<G::NodeId, G::EdgeWeight>, NegativeCycle>
+pub fn bellman_ford<G>(g: G, source: G::NodeId) -> PathsResult<G>
 where
     G: NodeCount + IntoNodeIdentifiers + IntoEdges + NodeIndexable,
-    G::EdgeWeight: FloatMeasure,
+    G::EdgeWeight: BoundedMeasure + Copy,
 {
     let ix = |i| g.to_index(i);
 
@@ -95,9 +110,8 @@ where
     for i in g.node_identifiers() {
         for edge in g.edges(i) {
             let j = edge.target();
-            let w = *edge.weight();
-            if distances[ix(i)] + w < distances[ix(j)] {
-                return Err(NegativeCycle(()));
+            if relaxed(distances[ix(i)], *edge.weight(), distances[ix(j)]).is_some() {
+                return Err(Error::NegativeCycle);
             }
         }
     }
@@ -144,7 +158,7 @@ where
 pub fn find_negative_cycle<G>(g: G, source: G::NodeId) -> Option<Vec<G::NodeId>>
 where
     G: NodeCount + IntoNodeIdentifiers + IntoEdges + NodeIndexable + Visitable,
-    G::EdgeWeight: FloatMeasure,
+    G::EdgeWeight: BoundedMeasure + Copy,
 {
     let ix = |i| g.to_index(i);
     let mut path = Vec::<G::NodeId>::new();
@@ -156,8 +170,7 @@ where
     'outer: for i in g.node_identifiers() {
         for edge in g.edges(i) {
             let j = edge.target();
-            let w = *edge.weight();
-            if distance[ix(i)] + w < distance[ix(j)] {
+            if relaxed(distance[ix(i)], *edge.weight(), distance[ix(j)]).is_some() {
                 // Step 3: negative cycle found
                 let start = j;
                 let mut node = start;
@@ -214,13 +227,13 @@ fn bellman_ford_initialize_relax<G>(
 ) -> (Vec<G::EdgeWeight>, Vec<Option<G::NodeId>>)
 where
     G: NodeCount + IntoNodeIdentifiers + IntoEdges + NodeIndexable,
-    G::EdgeWeight: FloatMeasure,
+    G::EdgeWeight: BoundedMeasure + Copy,
 {
     // Step 1: initialize graph
     let mut predecessor = vec![None; g.node_bound()];
-    let mut distance = vec![<_>::infinite(); g.node_bound()];
+    let mut distance = vec![<G::EdgeWeight as BoundedMeasure>::max(); g.node_bound()];
     let ix = |i| g.to_index(i);
-    distance[ix(source)] = <_>::zero();
+    distance[ix(source)] = G::EdgeWeight::default();
 
     // Step 2: relax edges repeatedly
     for _ in 1..g.node_count() {
@@ -228,9 +241,8 @@ where
         for i in g.node_identifiers() {
             for edge in g.edges(i) {
                 let j = edge.target();
-                let w = *edge.weight();
-                if distance[ix(i)] + w < distance[ix(j)] {
-                    distance[ix(j)] = distance[ix(i)] + w;
+                if let Some(d) = relaxed(distance[ix(i)], *edge.weight(), distance[ix(j)]) {
+                    distance[ix(j)] = d;
                     predecessor[ix(j)] = Some(i);
                     did_update = true;
                 }
@@ -242,3 +254,116 @@ where
     }
     (distance, predecessor)
 }
+
+/// Return the distance to the target of an edge of weight `w`, through its
+/// source at distance `from`, if it is shorter than the target's distance `to`.
+///
+/// Sources that are not reached yet, at distance `max()`, and sums that
+/// overflow give no distance.
+#[inline(always)]
+fn relaxed<K: BoundedMeasure + Copy>(from: K, w: K, to: K) -> Option<K> {
+    if from == K::max() {
+        return None;
+    }
+    match from.overflowing_add(w) {
+        (d, false) if d < to => Some(d),
+        _ => None,
+    }
+}
+
+/// \[Generic\] Compute shortest paths from node `source` to all other, with
+/// the Bellman–Ford algorithm in parallel with
+/// [`rayon`](https://crates.io/crates/rayon).
+///
+/// Each round relaxes all edges of the graph in parallel, from its
+/// `IntoParallelEdgeReferences`, until no distance goes down. Negative edge
+/// costs, given by `edge_cost`, are permitted, but the graph must not have a
+/// cycle of negative weight reachable from `source` (in that case it will
+/// return an `Error::NegativeCycle` error). An undirected edge is relaxed
+/// both ways.
+///
+/// Returns the path costs, in a vector indexed by the graph's node indices.
+/// Nodes that can't be reached from `source` have the cost infinity.
+///
+/// Requires crate feature `"rayon"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_bellman_ford;
+/// use petgraph::prelude::*;
+/// use std::f64::INFINITY;
+///
+/// let g = DiGraph::<(), f64>::from_edges(&[(0, 1, 3.), (1, 2, -2.), (0, 2, 2.), (3, 2, 1.)]);
+/// let distances = par_bellman_ford(&g, 0.into(), |e| *e.weight()).unwrap();
+/// assert_eq!(distances, [0., 3., 1., INFINITY]);
+///
+/// let cycle = DiGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, -2.), (2, 1, 1.)]);
+/// assert!(par_bellman_ford(&cycle, 0.into(), |e| *e.weight()).is_err());
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_bellman_ford<G, F>(
+    g: G,
+    source: G::NodeId,
+    edge_cost: F,
+) -> Result<Vec<f64>, Error<G::NodeId>>
+where
+    G: IntoParallelEdgeReferences + NodeIndexable + GraphProp + Sync,
+    F: Fn(G::EdgeRef) -> f64 + Sync,
+{
+    use rayon::prelude::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    let distance = (0..g.node_bound())
+        .map(|_| AtomicU64::new(std::f64::INFINITY.to_bits()))
+        .collect::<Vec<_>>();
+    distance[g.to_index(source)].store(0f64.to_bits(), Ordering::Relaxed);
+
+    // Lower the distance of `b` through `a`, and return whether it went down.
+    let relax = |a: usize, b: usize, w: f64| {
+        let from = f64::from_bits(distance[a].load(Ordering::Relaxed));
+        if from == std::f64::INFINITY {
+            return false;
+        }
+        let d = from + w;
+        let mut current = distance[b].load(Ordering::Relaxed);
+        while d < f64::from_bits(current) {
+            match distance[b].compare_exchange_weak(
+                current,
+                d.to_bits(),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(actual) => current = actual,
+            }
+        }
+        false
+    };
+    let relax_all = || {
+        g.par_edge_references()
+            .map(|edge| {
+                let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+                let w = edge_cost(edge);
+                let forward = relax(a, b, w);
+                let backward = !g.is_directed() && relax(b, a, w);
+                forward || backward
+            })
+            .reduce(|| false, |x, y| x || y)
+    };
+
+    // After a round, the distances along all shortest paths of up to that
+    // many edges are found, and the relaxations of a round may use the
+    // distances of other edges of the same round.
+    for _ in 1..g.node_bound() {
+        if !relax_all() {
+            break;
+        }
+    }
+    if relax_all() {
+        return Err(Error::NegativeCycle);
+    }
+    Ok(distance
+        .into_iter()
+        .map(|d| f64::from_bits(d.into_inner()))
+        .collect())
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::visit::IntoNeighborsDirected;
+use crate::{Direction, Incoming, Outgoing};
+
+/// \[Generic\] Bidirectional breadth first search for a shortest unweighted path.
+///
+/// Computes a path from `start` to `goal` with the least number of edges, by
+/// searching forward from `start` and backward from `goal` at the same time,
+/// always growing the smaller of the two frontiers by one level, until they
+/// meet. Compared to a breadth first search from `start` alone, this visits
+/// far fewer nodes on graphs where the number of nodes within a given
+/// distance grows quickly, like social networks.
+///
+/// The backward search follows the edges of a directed graph backwards, so the
+/// graph must implement `IntoNeighborsDirected`; in an undirected graph both
+/// searches follow the same edges.
+///
+/// Returns the nodes of the path, from `start` to `goal` inclusive, or `None`
+/// if `goal` is not reachable from `start`.
+///
+/// # Example
+/// ```
+/// use petgraph::algo::bidirectional_bfs;
+/// use petgraph::graph::node_index as n;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (0, 4), (4, 3), (3, 5)]);
+///
+/// assert_eq!(
+///     bidirectional_bfs(&g, n(0), n(5)),
+///     Some(vec![n(0), n(4), n(3), n(5)])
+/// );
+/// assert_eq!(bidirectional_bfs(&g, n(5), n(0)), None);
+/// ```
+pub fn bidirectional_bfs<G>(graph: G, start: G::NodeId, goal: G::NodeId) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighborsDirected,
+    G::NodeId: Eq + Hash,
+{
+    if start == goal {
+        return Some(vec![start]);
+    }
+
+    let mut forward = Search::new(start);
+    let mut backward = Search::new(goal);
+    while !forward.frontier.is_empty() && !backward.frontier.is_empty() {
+        let meeting = if forward.frontier.len() <= backward.frontier.len() {
+            forward.expand(graph, Outgoing, &backward)
+        } else {
+            backward.expand(graph, Incoming, &forward)
+        };
+        if let Some(meeting) = meeting {
+            let mut path = forward.path_to(meeting);
+            path.reverse();
+            path.extend(backward.path_to(meeting).into_iter().skip(1));
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// One direction of the search.
+struct Search<N> {
+    /// Map from each reached node to its predecessor in the search and its
+    /// distance from the search's source.
+    reached: HashMap<N, (Option<N>, usize)>,
+    /// The nodes of the deepest level reached.
+    frontier: Vec<N>,
+}
+
+impl<N> Search<N>
+where
+    N: Copy + Eq + Hash,
+{
+    fn new(source: N) -> Self {
+        let mut reached = HashMap::new();
+        reached.insert(source, (None, 0));
+        Search {
+            reached,
+            frontier: vec![source],
+        }
+    }
+
+    /// Reach the next level of nodes along edges in direction `dir`, and
+    /// return the node where a shortest path meets the `other` search, if the
+    /// searches met.
+    fn expand<G>(&mut self, graph: G, dir: Direction, other: &Self) -> Option<N>
+    where
+        G: IntoNeighborsDirected<NodeId = N>,
+    {
+        // Finish the whole level before picking a meeting node, since the
+        // first one found isn't necessarily on a shortest path.
+        let mut best: Option<(usize, N)> = None;
+        let mut next_frontier = Vec::new();
+        for node in self.frontier.drain(..) {
+            let dist = self.reached[&node].1 + 1;
+            for next in graph.neighbors_directed(node, dir) {
+                if self.reached.contains_key(&next) {
+                    continue;
+                }
+                self.reached.insert(next, (Some(node), dist));
+                next_frontier.push(next);
+                if let Some(&(_, other_dist)) = other.reached.get(&next) {
+                    let total = dist + other_dist;
+                    if best.map_or(true, |(best_total, _)| total < best_total) {
+                        best = Some((total, next));
+                    }
+                }
+            }
+        }
+        self.frontier = next_frontier;
+        best.map(|(_, meeting)| meeting)
+    }
+
+    /// Return the path from `node` back to the source of the search.
+    fn path_to(&self, node: N) -> Vec<N> {
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(&(Some(previous), _)) = self.reached.get(&current) {
+            path.push(previous);
+            current = previous;
+        }
+        path
+    }
+}
@@ -0,0 +1,144 @@
+//! Partition a bipartite graph into its two sides.
+
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+use super::{undirected_adjacency, Error};
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// An algorithm error: a cycle of odd length was found in the graph, so the
+/// graph is not bipartite.
+#[deprecated(note = "replaced by `Error::NotBipartite`")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OddCycle<N>(Vec<N>);
+
+#[allow(deprecated)]
+impl<N> OddCycle<N> {
+    /// Return the nodes of the cycle, in order.
+    ///
+    /// Consecutive nodes are adjacent, and so are the last and the first
+    /// node. A self loop is reported as a cycle of one node.
+    pub fn nodes(&self) -> &[N] {
+        &self.0
+    }
+
+    /// Return the nodes of the cycle, in order.
+    pub fn into_nodes(self) -> Vec<N> {
+        self.0
+    }
+}
+
+#[allow(deprecated)]
+impl<N> From<OddCycle<N>> for Error<N> {
+    fn from(cycle: OddCycle<N>) -> Self {
+        Error::NotBipartite(cycle.0)
+    }
+}
+
+/// \[Generic\] Partition the nodes of a bipartite graph into two sets, such
+/// that every edge connects a node of one set to a node of the other.
+///
+/// All connected components are partitioned, each by a breadth-first search.
+/// If the graph is not bipartite, return an `Error::NotBipartite` error with
+/// a cycle of odd length instead.
+///
+/// The input graph is treated as if undirected.
+///
+/// Computes in **O(|V| + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{bipartite_partition, Error};
+/// use petgraph::prelude::*;
+///
+/// // Two components: a square and a single edge.
+/// let mut g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (4, 5)]);
+/// let (left, right) = bipartite_partition(&g).unwrap();
+/// assert_eq!(left.len() + right.len(), 6);
+/// assert!(left.contains(&NodeIndex::new(0)) && left.contains(&NodeIndex::new(2)));
+/// assert!(right.contains(&NodeIndex::new(1)) && right.contains(&NodeIndex::new(3)));
+///
+/// // Adding a triangle makes the graph non-bipartite.
+/// g.extend_with_edges(&[(4, 6), (5, 6)]);
+/// match bipartite_partition(&g) {
+///     Err(Error::NotBipartite(cycle)) => assert_eq!(cycle.len(), 3),
+///     _ => panic!("the graph is not bipartite"),
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bipartite_partition<G>(
+    g: G,
+) -> Result<(HashSet<G::NodeId>, HashSet<G::NodeId>), Error<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    for a in g.node_identifiers() {
+        if g.neighbors(a).any(|b| b == a) {
+            return Err(Error::NotBipartite(vec![a]));
+        }
+    }
+
+    let (nodes, adj) = undirected_adjacency(g);
+    let mut depth = vec![std::usize::MAX; adj.len()];
+    let mut parent = vec![std::usize::MAX; adj.len()];
+    let mut queue = VecDeque::new();
+    for &root in &nodes {
+        if depth[root] != std::usize::MAX {
+            continue;
+        }
+        depth[root] = 0;
+        queue.push_back(root);
+        while let Some(i) = queue.pop_front() {
+            for &j in &adj[i] {
+                if depth[j] == std::usize::MAX {
+                    depth[j] = depth[i] + 1;
+                    parent[j] = i;
+                    queue.push_back(j);
+                } else if depth[j] % 2 == depth[i] % 2 {
+                    let cycle = odd_cycle(i, j, &depth, &parent);
+                    return Err(Error::NotBipartite(
+                        cycle.into_iter().map(|k| g.from_index(k)).collect(),
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut left = HashSet::new();
+    let mut right = HashSet::new();
+    for &i in &nodes {
+        if depth[i] % 2 == 0 {
+            left.insert(g.from_index(i));
+        } else {
+            right.insert(g.from_index(i));
+        }
+    }
+    Ok((left, right))
+}
+
+/// Close the cycle formed by the edge `a - b` between two nodes of equal
+/// depth parity and their paths to their lowest common ancestor in the BFS
+/// tree.
+fn odd_cycle(mut a: usize, mut b: usize, depth: &[usize], parent: &[usize]) -> Vec<usize> {
+    let mut path_a = vec![a];
+    let mut path_b = vec![b];
+    while depth[a] > depth[b] {
+        a = parent[a];
+        path_a.push(a);
+    }
+    while depth[b] > depth[a] {
+        b = parent[b];
+        path_b.push(b);
+    }
+    while a != b {
+        a = parent[a];
+        b = parent[b];
+        path_a.push(a);
+        path_b.push(b);
+    }
+    // the common ancestor ends both paths
+    path_b.pop();
+    path_a.extend(path_b.into_iter().rev());
+    path_a
+}
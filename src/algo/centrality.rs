@@ -0,0 +1,310 @@
+//! Centrality of nodes: betweenness, closeness and PageRank.
+//!
+//! The scores are returned in vectors indexed by the node indices from
+//! `NodeIndexable`, with zero at the indices that are not nodes.
+
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::{Error, Measure};
+use crate::scored::MinScored;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdges, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use crate::Direction::Incoming;
+
+/// The shortest paths from one source, for the accumulation of Brandes'
+/// algorithm.
+struct ShortestPaths {
+    /// The reached nodes, in order of their distance from the source.
+    order: Vec<usize>,
+    /// The predecessors of each node on its shortest paths.
+    predecessors: Vec<Vec<usize>>,
+    /// The number of shortest paths to each node.
+    paths: Vec<f64>,
+}
+
+impl ShortestPaths {
+    fn new(source: usize, node_bound: usize) -> Self {
+        let mut paths = vec![0.; node_bound];
+        paths[source] = 1.;
+        ShortestPaths {
+            order: Vec::new(),
+            predecessors: vec![Vec::new(); node_bound],
+            paths,
+        }
+    }
+
+    /// Add the dependencies of the source on each node to `centrality`.
+    fn accumulate(&self, source: usize, centrality: &mut [f64]) {
+        let mut dependency = vec![0.; self.paths.len()];
+        for &w in self.order.iter().rev() {
+            for &v in &self.predecessors[w] {
+                dependency[v] += self.paths[v] / self.paths[w] * (1. + dependency[w]);
+            }
+            if w != source {
+                centrality[w] += dependency[w];
+            }
+        }
+    }
+}
+
+/// Halve the scores of an undirected graph, where each path is found from
+/// both of its ends.
+fn halve_undirected<G: GraphProp>(g: G, centrality: &mut [f64]) {
+    if !g.is_directed() {
+        for c in centrality {
+            *c /= 2.;
+        }
+    }
+}
+
+/// \[Generic\] Brandes' betweenness centrality, counting each edge as a step.
+///
+/// The betweenness of a node is the sum, over all pairs of other nodes `s`
+/// and `t`, of the fraction of the shortest paths from `s` to `t` that go
+/// through the node. In an undirected graph, each pair is counted once. The
+/// scores are not normalized.
+///
+/// Computed in **O(|V| |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::betweenness_centrality;
+/// use petgraph::prelude::*;
+///
+/// // A path a - b - c - d
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// assert_eq!(betweenness_centrality(&g), [0., 2., 2., 0.]);
+/// ```
+pub fn betweenness_centrality<G>(g: G) -> Vec<f64>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let n = g.node_bound();
+    let mut centrality = vec![0.; n];
+    for s in g.node_identifiers() {
+        let s = g.to_index(s);
+        let mut paths = ShortestPaths::new(s, n);
+        let mut distance = vec![None; n];
+        distance[s] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(s);
+        while let Some(v) = queue.pop_front() {
+            paths.order.push(v);
+            let d = distance[v].unwrap() + 1;
+            for w in g.neighbors(g.from_index(v)) {
+                let w = g.to_index(w);
+                if distance[w].is_none() {
+                    distance[w] = Some(d);
+                    queue.push_back(w);
+                }
+                if distance[w] == Some(d) {
+                    paths.paths[w] += paths.paths[v];
+                    paths.predecessors[w].push(v);
+                }
+            }
+        }
+        paths.accumulate(s, &mut centrality);
+    }
+    halve_undirected(g, &mut centrality);
+    centrality
+}
+
+/// \[Generic\] Brandes' betweenness centrality, with the lengths of the
+/// shortest paths weighted by `edge_cost`.
+///
+/// Like [`betweenness_centrality`](fn.betweenness_centrality.html), with the
+/// shortest paths found by Dijkstra's algorithm. Edge costs must be positive.
+///
+/// Computed in **O(|V| |E| log |V|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::weighted_betweenness_centrality;
+/// use petgraph::prelude::*;
+///
+/// // The way from a to c through b is shorter than the direct edge.
+/// let g = DiGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 2, 1), (0, 2, 5)]);
+/// assert_eq!(weighted_betweenness_centrality(&g, |e| *e.weight()), [0., 1., 0.]);
+/// ```
+pub fn weighted_betweenness_centrality<G, F, K>(g: G, mut edge_cost: F) -> Vec<f64>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let n = g.node_bound();
+    let mut centrality = vec![0.; n];
+    for s in g.node_identifiers() {
+        let s = g.to_index(s);
+        let mut paths = ShortestPaths::new(s, n);
+        let mut distance = vec![None; n];
+        let mut settled = vec![false; n];
+        distance[s] = Some(K::default());
+        let mut queue = BinaryHeap::new();
+        queue.push(MinScored(K::default(), s));
+        while let Some(MinScored(d, v)) = queue.pop() {
+            if settled[v] {
+                continue;
+            }
+            settled[v] = true;
+            paths.order.push(v);
+            for edge in g.edges(g.from_index(v)) {
+                let w = g.to_index(edge.target());
+                if settled[w] {
+                    continue;
+                }
+                let next = d + edge_cost(edge);
+                match distance[w] {
+                    Some(current) if next > current => {}
+                    Some(current) if next == current => {
+                        paths.paths[w] += paths.paths[v];
+                        paths.predecessors[w].push(v);
+                    }
+                    _ => {
+                        distance[w] = Some(next);
+                        paths.paths[w] = paths.paths[v];
+                        paths.predecessors[w].clear();
+                        paths.predecessors[w].push(v);
+                        queue.push(MinScored(next, w));
+                    }
+                }
+            }
+        }
+        paths.accumulate(s, &mut centrality);
+    }
+    halve_undirected(g, &mut centrality);
+    centrality
+}
+
+/// \[Generic\] Closeness centrality, counting each edge as a step.
+///
+/// The closeness of a node is the number of other nodes it reaches, over
+/// the sum of their distances from it, scaled by the fraction of the other
+/// nodes that it reaches (the variant of Wasserman and Faust, for graphs that
+/// are not connected). A node that reaches no other node has closeness zero.
+/// In a directed graph, the distances are along the outgoing edges.
+///
+/// Computed in **O(|V| |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::closeness_centrality;
+/// use petgraph::prelude::*;
+///
+/// // A star, with center a
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let closeness = closeness_centrality(&g);
+/// assert_eq!(closeness[0], 1.);
+/// assert_eq!(closeness[1], 3. / 5.);
+/// ```
+pub fn closeness_centrality<G>(g: G) -> Vec<f64>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let n = g.node_bound();
+    let node_count = g.node_identifiers().count();
+    let mut closeness = vec![0.; n];
+    for s in g.node_identifiers() {
+        let s = g.to_index(s);
+        let mut distance = vec![None; n];
+        distance[s] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(s);
+        let (mut reached, mut total) = (0, 0);
+        while let Some(v) = queue.pop_front() {
+            let d = distance[v].unwrap() + 1;
+            for w in g.neighbors(g.from_index(v)) {
+                let w = g.to_index(w);
+                if distance[w].is_none() {
+                    distance[w] = Some(d);
+                    reached += 1;
+                    total += d;
+                    queue.push_back(w);
+                }
+            }
+        }
+        if total > 0 {
+            let reached = reached as f64;
+            closeness[s] = reached / total as f64 * reached / (node_count - 1) as f64;
+        }
+    }
+    closeness
+}
+
+/// \[Generic\] PageRank.
+///
+/// The rank of a node is the probability that a random surfer is at it, who
+/// follows a random outgoing edge with probability `damping`, and jumps to a
+/// random node otherwise, or when the node has no outgoing edges. The edges
+/// of an undirected graph are followed both ways.
+///
+/// Iterates until the ranks change by less than `tolerance`, summed over all
+/// nodes, and returns the ranks, which add up to one. If they still change
+/// by more after `max_iterations` iterations, return an
+/// `Error::Unconverged` error instead.
+///
+/// With crate feature `"rayon"`, `par_page_rank` computes the same in
+/// parallel.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::page_rank;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+/// let ranks = page_rank(&g, 0.85, 1e-9, 200).unwrap();
+/// assert!(ranks[0] > ranks[1] && ranks[3] < ranks[2]);
+/// assert!((ranks.iter().sum::<f64>() - 1.).abs() < 1e-9);
+/// assert!(page_rank(&g, 0.85, 1e-9, 10).is_err());
+/// ```
+pub fn page_rank<G>(
+    g: G,
+    damping: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<Vec<f64>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let nodes = g
+        .node_identifiers()
+        .map(|a| g.to_index(a))
+        .collect::<Vec<_>>();
+    let mut ranks = vec![0.; g.node_bound()];
+    if nodes.is_empty() {
+        return Ok(ranks);
+    }
+    let n = nodes.len() as f64;
+    let mut out_degree = vec![0.; g.node_bound()];
+    for &a in &nodes {
+        ranks[a] = 1. / n;
+        out_degree[a] = g.neighbors(g.from_index(a)).count() as f64;
+    }
+    for _ in 0..max_iterations {
+        let dangling: f64 = nodes
+            .iter()
+            .filter(|&&a| out_degree[a] == 0.)
+            .map(|&a| ranks[a])
+            .sum();
+        let jump = ((1. - damping) + damping * dangling) / n;
+        let mut next = vec![0.; ranks.len()];
+        for &a in &nodes {
+            let incoming: f64 = g
+                .neighbors_directed(g.from_index(a), Incoming)
+                .map(|b| {
+                    let b = g.to_index(b);
+                    ranks[b] / out_degree[b]
+                })
+                .sum();
+            next[a] = jump + damping * incoming;
+        }
+        let change: f64 = ranks.iter().zip(&next).map(|(x, y)| (x - y).abs()).sum();
+        ranks = next;
+        if change < tolerance {
+            return Ok(ranks);
+        }
+    }
+    Err(Error::Unconverged)
+}
@@ -0,0 +1,98 @@
+//! Compute a maximum weight closure of a directed graph.
+//!
+//! A *closure* of a directed graph is a set of nodes with no edges leaving the
+//! set: if a node is in the closure, then so are all of its successors. Given
+//! a (possibly negative) weight for every node, a maximum weight closure is a
+//! closure with the largest total weight.
+//!
+//! This models *project selection* problems, where an edge **a → b** means
+//! that choosing **a** requires choosing **b** as well, and the open-pit mining
+//! problem. The solution is derived from a minimum cut of an associated flow
+//! network, following Picard (1976).
+
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::ops::Sub;
+
+use super::flow::Network;
+use super::Measure;
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Compute a maximum weight closure of the graph.
+///
+/// `node_weight` gives the weight of each node, which may be negative. Return
+/// the total weight of the closure and its nodes. The empty set is a closure of
+/// weight zero, so the total weight is never negative.
+///
+/// For an undirected graph, every edge requires both of its endpoints to be
+/// either in or out of the closure.
+///
+/// The closure is computed from a maximum flow with Dinic's algorithm, in
+/// **O(|V|² |E|)** time. Among all maximum weight closures, the smallest one is
+/// returned.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::max_weight_closure;
+/// use petgraph::prelude::*;
+///
+/// // Projects with a profit (positive) or a cost (negative). An edge means
+/// // the source project requires the target project.
+/// let mut g = DiGraph::<i32, ()>::new();
+/// let website = g.add_node(10);
+/// let app = g.add_node(6);
+/// let server = g.add_node(-4);
+/// let design = g.add_node(-8);
+/// g.extend_with_edges(&[(website, server), (app, server), (app, design)]);
+///
+/// let (profit, chosen) = max_weight_closure(&g, |n| g[n]);
+/// assert_eq!(profit, 6);
+/// assert_eq!(chosen.len(), 2);
+/// assert!(chosen.contains(&website) && chosen.contains(&server));
+/// ```
+pub fn max_weight_closure<G, F, K>(g: G, mut node_weight: F) -> (K, HashSet<G::NodeId>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::NodeId) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let zero = K::default();
+    let n = g.node_bound();
+    let (source, sink) = (n, n + 1);
+    let mut network = Network::new(n + 2);
+
+    // Source edges carry the profits and sink edges the costs.
+    let mut total = zero;
+    for a in g.node_identifiers() {
+        let w = node_weight(a);
+        if w > zero {
+            total = total + w;
+            network.add_arc(source, g.to_index(a), w);
+        } else if w < zero {
+            network.add_arc(g.to_index(a), sink, zero - w);
+        }
+    }
+    if total == zero {
+        return (zero, HashSet::new());
+    }
+
+    // Dependencies must never be cut. Any capacity of at least `total` works,
+    // since a saturated dependency would mean the flow saturated every source
+    // edge, leaving nothing but the source on the source side of the cut.
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        network.add_arc(a, b, total);
+        if !g.is_directed() {
+            network.add_arc(b, a, total);
+        }
+    }
+
+    let cut = network.max_flow(source, sink);
+    let side = network.source_side(source);
+    let closure = g
+        .node_identifiers()
+        .filter(|&a| side[g.to_index(a)])
+        .collect();
+    (total - cut, closure)
+}
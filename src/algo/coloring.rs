@@ -0,0 +1,143 @@
+//! Compute proper colorings of a graph.
+//!
+//! A *proper coloring* assigns a color to every node such that adjacent nodes
+//! have different colors. Colors are numbered from zero. Finding a coloring
+//! with the fewest colors is NP-hard; the greedy algorithms here color the
+//! nodes one at a time, each with the smallest color not used by its
+//! neighbors.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+use super::undirected_adjacency;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The order in which [`greedy_color_with`](fn.greedy_color_with.html)
+/// colors the nodes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ColoringStrategy {
+    /// DSATUR: color the node with the most distinct colors among its
+    /// neighbors next, breaking ties by the number of uncolored neighbors.
+    Dsatur,
+    /// Color the nodes by decreasing degree.
+    LargestFirst,
+}
+
+/// \[Generic\] Compute a proper coloring of the graph with the DSATUR
+/// heuristic.
+///
+/// Return the color of each node and the number of colors used. The colors
+/// are `0` up to but not including the number of colors.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// DSATUR colors bipartite graphs, cycles and wheels with the fewest colors
+/// possible, and computes in **O((|V| + |E|) log |V|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::greedy_color;
+/// use petgraph::prelude::*;
+///
+/// // A cycle of length five needs three colors.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+/// let (coloring, colors) = greedy_color(&g);
+/// assert_eq!(colors, 3);
+/// for edge in g.edge_references() {
+///     assert_ne!(coloring[&edge.source()], coloring[&edge.target()]);
+/// }
+/// ```
+pub fn greedy_color<G>(g: G) -> (HashMap<G::NodeId, usize>, usize)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    greedy_color_with(g, ColoringStrategy::Dsatur)
+}
+
+/// \[Generic\] Compute a proper coloring of the graph, choosing the order of
+/// the nodes with `strategy`.
+///
+/// Return the color of each node and the number of colors used, like
+/// [`greedy_color`](fn.greedy_color.html).
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{greedy_color_with, ColoringStrategy};
+/// use petgraph::prelude::*;
+///
+/// // A star is colored with two colors, the center first.
+/// let g = UnGraph::<(), ()>::from_edges(&[(1, 0), (1, 2), (1, 3)]);
+/// let (coloring, colors) = greedy_color_with(&g, ColoringStrategy::LargestFirst);
+/// assert_eq!(colors, 2);
+/// assert_eq!(coloring[&NodeIndex::new(1)], 0);
+/// ```
+pub fn greedy_color_with<G>(g: G, strategy: ColoringStrategy) -> (HashMap<G::NodeId, usize>, usize)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, adj) = undirected_adjacency(g);
+    let mut color = vec![None; adj.len()];
+    let mut colors = 0;
+    let mut assign = |i: usize, color: &mut [Option<usize>]| {
+        let mut used = adj[i].iter().filter_map(|&j| color[j]).collect::<Vec<_>>();
+        used.sort_unstable();
+        used.dedup();
+        // the smallest color missing from the sorted list
+        let c = used
+            .iter()
+            .enumerate()
+            .find(|&(k, &c)| k != c)
+            .map_or(used.len(), |(k, _)| k);
+        color[i] = Some(c);
+        colors = colors.max(c + 1);
+    };
+
+    match strategy {
+        ColoringStrategy::LargestFirst => {
+            let mut order = nodes.clone();
+            order.sort_by_key(|&i| Reverse(adj[i].len()));
+            for i in order {
+                assign(i, &mut color);
+            }
+        }
+        ColoringStrategy::Dsatur => {
+            let mut neighbor_colors = vec![HashSet::new(); adj.len()];
+            let mut uncolored_degree = adj.iter().map(Vec::len).collect::<Vec<_>>();
+            let mut heap = nodes
+                .iter()
+                .map(|&i| (0, uncolored_degree[i], Reverse(i)))
+                .collect::<BinaryHeap<_>>();
+            while let Some((saturation, degree, Reverse(i))) = heap.pop() {
+                if color[i].is_some()
+                    || saturation != neighbor_colors[i].len()
+                    || degree != uncolored_degree[i]
+                {
+                    // stale entry
+                    continue;
+                }
+                assign(i, &mut color);
+                let c = color[i].unwrap();
+                for &j in &adj[i] {
+                    if color[j].is_none() {
+                        neighbor_colors[j].insert(c);
+                        uncolored_degree[j] -= 1;
+                        heap.push((neighbor_colors[j].len(), uncolored_degree[j], Reverse(j)));
+                    }
+                }
+            }
+        }
+    }
+
+    let coloring = nodes
+        .iter()
+        .map(|&i| (g.from_index(i), color[i].unwrap()))
+        .collect();
+    (coloring, colors)
+}
@@ -0,0 +1,126 @@
+//! Parallel single source shortest paths by delta-stepping.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rayon::prelude::*;
+
+use crate::visit::{EdgeRef, IntoEdges, NodeIndexable};
+
+/// \[Generic\] Delta-stepping shortest paths, in parallel with
+/// [`rayon`](https://crates.io/crates/rayon).
+///
+/// Compute the length of the shortest path from `start` to every node, like
+/// [`dijkstra`](fn.dijkstra.html), for non-negative edge costs given by
+/// `edge_cost`.
+///
+/// The nodes are kept in buckets of tentative distances of width `delta`.
+/// The buckets are settled in order, and the edges out of all the nodes of a
+/// bucket are relaxed in parallel: first the light edges, of cost at most
+/// `delta`, until the bucket is empty, and then the heavy ones. A small
+/// `delta` does little more work than Dijkstra's algorithm but has little
+/// parallelism; a large one is closer to the Bellman-Ford algorithm. The
+/// average edge cost, times a small factor, is a good start.
+///
+/// Returns a vector indexed by the node indices from `NodeIndexable`, with the
+/// path cost of each node, or `None` if the node can not be reached.
+///
+/// **Panics** if `delta` is not positive, or if an edge cost is negative.
+///
+/// Requires crate feature `"rayon"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::delta_stepping;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 0.5), (0, 2, 2.), (2, 3, 4.)]);
+/// let dist = delta_stepping(&g, 0.into(), 1., |e| *e.weight());
+/// assert_eq!(dist, [Some(0.), Some(1.), Some(1.5), Some(5.5)]);
+/// ```
+pub fn delta_stepping<G, F>(
+    graph: G,
+    start: G::NodeId,
+    delta: f64,
+    edge_cost: F,
+) -> Vec<Option<f64>>
+where
+    G: IntoEdges + NodeIndexable + Sync,
+    F: Fn(G::EdgeRef) -> f64 + Sync,
+{
+    assert!(delta > 0., "delta_stepping: delta must be positive");
+    let bucket = |d: f64| (d / delta) as usize;
+    // Non-negative floats are ordered like the integers of their bits.
+    let dist = (0..graph.node_bound())
+        .map(|_| AtomicU64::new(std::f64::INFINITY.to_bits()))
+        .collect::<Vec<_>>();
+    let load = |i: usize| f64::from_bits(dist[i].load(Ordering::Relaxed));
+
+    // Relax the light or heavy edges out of `nodes`, in parallel, and return
+    // the nodes whose distance went down, with their new distances.
+    let edge_cost = &edge_cost;
+    let relax = |nodes: &[usize], light: bool| {
+        nodes
+            .par_iter()
+            .flat_map_iter(|&a| {
+                let dist = &dist;
+                let d = load(a);
+                graph
+                    .edges(graph.from_index(a))
+                    .filter_map(move |edge| {
+                        let cost = edge_cost(edge);
+                        assert!(cost >= 0., "delta_stepping: negative edge cost");
+                        if (cost <= delta) != light {
+                            return None;
+                        }
+                        let b = graph.to_index(edge.target());
+                        let next = d + cost;
+                        if fetch_min(&dist[b], next.to_bits()) {
+                            Some((b, next))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut buckets = BTreeMap::new();
+    dist[graph.to_index(start)].store(0f64.to_bits(), Ordering::Relaxed);
+    buckets.insert(0, vec![graph.to_index(start)]);
+    while let Some(i) = buckets.keys().next().cloned() {
+        let mut settled = Vec::new();
+        while let Some(mut frontier) = buckets.remove(&i) {
+            // Drop the nodes that have moved to an earlier bucket since.
+            frontier.retain(|&a| bucket(load(a)) == i);
+            frontier.sort_unstable();
+            frontier.dedup();
+            for (b, d) in relax(&frontier, true) {
+                buckets.entry(bucket(d)).or_insert_with(Vec::new).push(b);
+            }
+            settled.extend(frontier);
+        }
+        settled.sort_unstable();
+        settled.dedup();
+        for (b, d) in relax(&settled, false) {
+            buckets.entry(bucket(d)).or_insert_with(Vec::new).push(b);
+        }
+    }
+
+    dist.into_iter()
+        .map(|d| Some(f64::from_bits(d.into_inner())).filter(|d| d.is_finite()))
+        .collect()
+}
+
+/// Lower `atomic` to `value`, and return whether it went down.
+fn fetch_min(atomic: &AtomicU64, value: u64) -> bool {
+    let mut current = atomic.load(Ordering::Relaxed);
+    while value < current {
+        match atomic.compare_exchange_weak(current, value, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => return true,
+            Err(actual) => current = actual,
+        }
+    }
+    false
+}
@@ -0,0 +1,292 @@
+//! Compare two versions of a graph.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::visit::{
+    EdgeIndexable, EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable,
+    NodeRef,
+};
+
+/// The differences between two graphs, as computed by
+/// [`graph_diff`](fn.graph_diff.html).
+///
+/// Nodes are given by their keys, and edges by the keys of their source and
+/// target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphDiff<K> {
+    /// The nodes of the second graph that are not in the first one.
+    pub added_nodes: Vec<K>,
+    /// The nodes of the first graph that are not in the second one.
+    pub removed_nodes: Vec<K>,
+    /// The nodes in both graphs whose weights differ.
+    pub changed_nodes: Vec<K>,
+    /// The edges of the second graph that are not in the first one.
+    pub added_edges: Vec<(K, K)>,
+    /// The edges of the first graph that are not in the second one.
+    pub removed_edges: Vec<(K, K)>,
+    /// The edges in both graphs whose weights differ.
+    pub changed_edges: Vec<(K, K)>,
+}
+
+impl<K> GraphDiff<K> {
+    /// Return `true` if the two graphs are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.changed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_edges.is_empty()
+    }
+}
+
+/// \[Generic\] Compute the differences between two graphs whose nodes are
+/// identified by the key `node_key(weight)`.
+///
+/// Nodes with the same key in `a` and `b` are the same node, which has
+/// changed if its weights differ; keys should be unique in each graph, and
+/// only the first node of a repeated key counts. Edges are the same if they
+/// join the same nodes, in either direction if `a` is undirected; parallel
+/// edges are paired in order, preferring pairs of equal weights, and the
+/// remaining ones are added or removed.
+///
+/// The nodes and edges of each list are in the order of the graph they come
+/// from, the changed ones in the order of `a`.
+///
+/// Computes in **O(|V| + |E|)** time, as long as there are few parallel
+/// edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::graph_diff;
+/// use petgraph::prelude::*;
+///
+/// // Crates and their versions, depending on each other.
+/// let mut old = DiGraph::<(&str, u32), ()>::new();
+/// let app = old.add_node(("app", 1));
+/// let log = old.add_node(("log", 4));
+/// let rand = old.add_node(("rand", 5));
+/// old.extend_with_edges(&[(app, log), (app, rand)]);
+///
+/// let mut new = DiGraph::<(&str, u32), ()>::new();
+/// let app = new.add_node(("app", 1));
+/// let log = new.add_node(("log", 5));
+/// let serde = new.add_node(("serde", 1));
+/// new.extend_with_edges(&[(app, log), (app, serde), (log, serde)]);
+///
+/// let diff = graph_diff(&old, &new, |&(name, _)| name);
+/// assert_eq!(diff.added_nodes, vec!["serde"]);
+/// assert_eq!(diff.removed_nodes, vec!["rand"]);
+/// assert_eq!(diff.changed_nodes, vec!["log"]);
+/// assert_eq!(diff.added_edges, vec![("app", "serde"), ("log", "serde")]);
+/// assert_eq!(diff.removed_edges, vec![("app", "rand")]);
+/// assert!(diff.changed_edges.is_empty());
+/// ```
+pub fn graph_diff<G1, G2, F, K>(a: G1, b: G2, mut node_key: F) -> GraphDiff<K>
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + GraphProp,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: PartialEq,
+    G1::EdgeWeight: PartialEq,
+    G2: IntoNodeReferences<NodeWeight = G1::NodeWeight>
+        + IntoEdgeReferences<EdgeWeight = G1::EdgeWeight>,
+    G2::NodeId: Eq + Hash,
+    F: FnMut(&G1::NodeWeight) -> K,
+    K: Clone + Eq + Hash,
+{
+    let mut a_keys = HashMap::new();
+    let mut a_nodes = HashMap::new();
+    for node in a.node_references() {
+        let key = node_key(node.weight());
+        a_keys.insert(node.id(), key.clone());
+        a_nodes.entry(key).or_insert(node);
+    }
+    let mut b_keys = HashMap::new();
+    let mut b_nodes = HashMap::new();
+    let mut added_nodes = Vec::new();
+    for node in b.node_references() {
+        let key = node_key(node.weight());
+        b_keys.insert(node.id(), key.clone());
+        if let Entry::Vacant(entry) = b_nodes.entry(key) {
+            if !a_nodes.contains_key(entry.key()) {
+                added_nodes.push(entry.key().clone());
+            }
+            entry.insert(node);
+        }
+    }
+    let mut removed_nodes = Vec::new();
+    let mut changed_nodes = Vec::new();
+    let mut seen = HashSet::new();
+    for node in a.node_references() {
+        let key = &a_keys[&node.id()];
+        if !seen.insert(key) {
+            continue;
+        }
+        match b_nodes.get(key) {
+            None => removed_nodes.push(key.clone()),
+            Some(other) if other.weight() != node.weight() => changed_nodes.push(key.clone()),
+            _ => {}
+        }
+    }
+
+    // the edges of `b` by the keys of their endpoints, not yet paired
+    let b_edges = b.edge_references().collect::<Vec<_>>();
+    let mut paired = vec![false; b_edges.len()];
+    let mut b_by_keys = HashMap::<_, Vec<_>>::new();
+    for (i, edge) in b_edges.iter().enumerate() {
+        let ends = (
+            b_keys[&edge.source()].clone(),
+            b_keys[&edge.target()].clone(),
+        );
+        b_by_keys.entry(ends).or_default().push(i);
+    }
+    let mut removed_edges = Vec::new();
+    let mut changed_edges = Vec::new();
+    for edge in a.edge_references() {
+        let ends = (
+            a_keys[&edge.source()].clone(),
+            a_keys[&edge.target()].clone(),
+        );
+        let mut candidates = b_by_keys
+            .get(&ends)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if !a.is_directed() {
+            let reversed = (ends.1.clone(), ends.0.clone());
+            candidates.extend(b_by_keys.get(&reversed).into_iter().flatten());
+        }
+        let mut candidates = candidates.into_iter().filter(|&&i| !paired[i]);
+        let first = candidates.clone().next();
+        match candidates.find(|&&i| b_edges[i].weight() == edge.weight()) {
+            Some(&i) => paired[i] = true,
+            None => match first {
+                Some(&i) => {
+                    paired[i] = true;
+                    changed_edges.push(ends);
+                }
+                None => removed_edges.push(ends),
+            },
+        }
+    }
+    let added_edges = b_edges
+        .iter()
+        .zip(paired)
+        .filter(|&(_, paired)| !paired)
+        .map(|(edge, _)| {
+            (
+                b_keys[&edge.source()].clone(),
+                b_keys[&edge.target()].clone(),
+            )
+        })
+        .collect();
+
+    GraphDiff {
+        added_nodes,
+        removed_nodes,
+        changed_nodes,
+        added_edges,
+        removed_edges,
+        changed_edges,
+    }
+}
+
+/// \[Generic\] Return `true` if the graphs `a` and `b` are equal, node by
+/// node and edge by edge with the same indices.
+///
+/// The graphs are equal if they are both directed or both undirected, have
+/// nodes at the same indices with equal weights, and have edges at the same
+/// indices with equal weights and with endpoints at the same indices, in
+/// either order if they are undirected. The order of the edges around each
+/// node doesn't matter.
+///
+/// To compare graphs whose nodes may be numbered differently, use
+/// [`is_same_up_to_iso`](fn.is_same_up_to_iso.html).
+///
+/// Computes in **O(|V| log |V| + |E| log |E|)** time.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "stable_graph")] {
+/// use petgraph::algo::graph_eq;
+/// use petgraph::prelude::*;
+///
+/// let mut a = UnGraph::<&str, u32>::new_undirected();
+/// let x = a.add_node("x");
+/// let y = a.add_node("y");
+/// a.add_edge(x, y, 1);
+///
+/// let mut b = StableUnGraph::<&str, u32>::default();
+/// let x = b.add_node("x");
+/// let y = b.add_node("y");
+/// b.add_edge(y, x, 1);
+/// assert!(graph_eq(&a, &b));
+///
+/// b[y] = "z";
+/// assert!(!graph_eq(&a, &b));
+/// # }
+/// ```
+pub fn graph_eq<G1, G2>(a: G1, b: G2) -> bool
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + EdgeIndexable + GraphProp,
+    G2: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + EdgeIndexable + GraphProp,
+    G1::NodeWeight: PartialEq<G2::NodeWeight>,
+    G1::EdgeWeight: PartialEq<G2::EdgeWeight>,
+{
+    if a.is_directed() != b.is_directed() {
+        return false;
+    }
+    let mut a_nodes = a
+        .node_references()
+        .map(|n| (NodeIndexable::to_index(&a, n.id()), n))
+        .collect::<Vec<_>>();
+    let mut b_nodes = b
+        .node_references()
+        .map(|n| (NodeIndexable::to_index(&b, n.id()), n))
+        .collect::<Vec<_>>();
+    if a_nodes.len() != b_nodes.len() {
+        return false;
+    }
+    a_nodes.sort_by_key(|n| n.0);
+    b_nodes.sort_by_key(|n| n.0);
+    if !a_nodes
+        .iter()
+        .zip(&b_nodes)
+        .all(|(x, y)| x.0 == y.0 && x.1.weight() == y.1.weight())
+    {
+        return false;
+    }
+    let mut a_edges = edge_indices(a);
+    let mut b_edges = edge_indices(b);
+    if a_edges.len() != b_edges.len() {
+        return false;
+    }
+    a_edges.sort_by_key(|e| e.0);
+    b_edges.sort_by_key(|e| e.0);
+    a_edges
+        .iter()
+        .zip(&b_edges)
+        .all(|(x, y)| x.0 == y.0 && x.1 == y.1 && x.2.weight() == y.2.weight())
+}
+
+/// Return the index, the endpoint indices and the reference of each edge of
+/// `g`, with the endpoints in order if `g` is undirected.
+fn edge_indices<G>(g: G) -> Vec<(usize, [usize; 2], G::EdgeRef)>
+where
+    G: IntoEdgeReferences + NodeIndexable + EdgeIndexable + GraphProp,
+{
+    g.edge_references()
+        .map(|e| {
+            let mut ends = [
+                NodeIndexable::to_index(&g, e.source()),
+                NodeIndexable::to_index(&g, e.target()),
+            ];
+            if !g.is_directed() {
+                ends.sort_unstable();
+            }
+            (EdgeIndexable::to_index(&g, e.id()), ends, e)
+        })
+        .collect()
+}
@@ -1,11 +1,11 @@
-use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::{BinaryHeap, HashMap};
 
 use std::hash::Hash;
 
+use super::score_map::{IndexedScores, ScoreMap};
 use crate::algo::Measure;
 use crate::scored::MinScored;
-use crate::visit::{EdgeRef, IntoEdges, VisitMap, Visitable};
+use crate::visit::{EdgeRef, IntoEdges, NodeIndexable, VisitMap, Visitable};
 
 /// \[Generic\] Dijkstra's shortest path algorithm.
 ///
@@ -73,7 +73,7 @@ pub fn dijkstra<G, F, K>(
     graph: G,
     start: G::NodeId,
     goal: Option<G::NodeId>,
-    mut edge_cost: F,
+    edge_cost: F,
 ) -> HashMap<G::NodeId, K>
 where
     G: IntoEdges + Visitable,
@@ -81,12 +81,62 @@ where
     F: FnMut(G::EdgeRef) -> K,
     K: Measure + Copy,
 {
-    let mut visited = graph.visit_map();
     let mut scores = HashMap::new();
-    //let mut predecessor = HashMap::new();
+    dijkstra_scores(graph, start, goal, edge_cost, &mut scores);
+    scores
+}
+
+/// \[Generic\] Dijkstra's shortest path algorithm, with the path costs in a
+/// vector indexed by the node indices from `NodeIndexable`.
+///
+/// This is [`dijkstra`](fn.dijkstra.html) without hashing the nodes, which
+/// is faster for graphs with dense node indices, like `Graph` and `Csr`.
+///
+/// Returns the path cost of each node, or `None` if it was not reached.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::dijkstra_indexed;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), u32>::from_edges(&[(0, 1, 2), (1, 2, 3), (0, 2, 7)]);
+/// let res = dijkstra_indexed(&g, 0.into(), None, |e| *e.weight());
+/// assert_eq!(res, [Some(0), Some(2), Some(5)]);
+/// ```
+pub fn dijkstra_indexed<G, F, K>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    edge_cost: F,
+) -> Vec<Option<K>>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    G::NodeId: Eq,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+{
+    let mut scores = IndexedScores::new(graph);
+    dijkstra_scores(graph, start, goal, edge_cost, &mut scores);
+    scores.values
+}
+
+fn dijkstra_scores<G, F, K, S>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    mut edge_cost: F,
+    scores: &mut S,
+) where
+    G: IntoEdges + Visitable,
+    G::NodeId: Eq,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy,
+    S: ScoreMap<G::NodeId, K>,
+{
+    let mut visited = graph.visit_map();
     let mut visit_next = BinaryHeap::new();
     let zero_score = K::default();
-    scores.insert(start, zero_score);
+    scores.set_score(start, zero_score);
     visit_next.push(MinScored(zero_score, start));
     while let Some(MinScored(node_score, node)) = visit_next.pop() {
         if visited.is_visited(&node) {
@@ -101,22 +151,15 @@ where
                 continue;
             }
             let next_score = node_score + edge_cost(edge);
-            match scores.entry(next) {
-                Occupied(ent) => {
-                    if next_score < *ent.get() {
-                        *ent.into_mut() = next_score;
-                        visit_next.push(MinScored(next_score, next));
-                        //predecessor.insert(next.clone(), node.clone());
-                    }
-                }
-                Vacant(ent) => {
-                    ent.insert(next_score);
-                    visit_next.push(MinScored(next_score, next));
-                    //predecessor.insert(next.clone(), node.clone());
-                }
+            let improved = match scores.get_score(next) {
+                Some(score) => next_score < score,
+                None => true,
+            };
+            if improved {
+                scores.set_score(next, next_score);
+                visit_next.push(MinScored(next_score, next));
             }
         }
         visited.visit(node);
     }
-    scores
 }
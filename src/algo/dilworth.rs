@@ -0,0 +1,178 @@
+//! Compute chain covers and antichains of a directed acyclic graph.
+//!
+//! A directed acyclic graph defines a partial order, where **a < b** if there
+//! is a path from **a** to **b**. A *chain* is a set of nodes that are pairwise
+//! comparable, and an *antichain* is a set of nodes where no node reaches
+//! another. By Dilworth's theorem, the fewest chains that cover all nodes are
+//! as many as the nodes of a largest antichain.
+//!
+//! Both are derived from a maximum matching in the bipartite graph that has an
+//! edge **a → b** for every pair **a < b**, following Fulkerson (1956).
+
+use fixedbitset::FixedBitSet;
+
+use super::flow::Network;
+use super::{toposort_by_key, Error};
+use crate::visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use crate::Outgoing;
+
+/// A maximum matching of the comparable pairs of a partial order.
+struct ChainMatching<N> {
+    /// The nodes in topological order.
+    order: Vec<N>,
+    /// The successor of each node in its chain, if any.
+    next: Vec<Option<usize>>,
+    /// For each node, whether its left and right copy are on the source side
+    /// of a minimum cut.
+    left: Vec<bool>,
+    right: Vec<bool>,
+}
+
+fn chain_matching<G>(g: G) -> Result<ChainMatching<G::NodeId>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let order = toposort_by_key(g, |_| ())?;
+    let n = g.node_bound();
+    let mut reach = vec![FixedBitSet::with_capacity(n); n];
+    for &a in order.iter().rev() {
+        let i = g.to_index(a);
+        let mut set = FixedBitSet::with_capacity(n);
+        for b in g.neighbors_directed(a, Outgoing) {
+            let j = g.to_index(b);
+            set.insert(j);
+            set.union_with(&reach[j]);
+        }
+        reach[i] = set;
+    }
+
+    // Left copies are `0..n`, right copies `n..2 * n`.
+    let (source, sink) = (2 * n, 2 * n + 1);
+    let mut network = Network::new(2 * n + 2);
+    let mut pairs = Vec::new();
+    for &a in &order {
+        let i = g.to_index(a);
+        network.add_arc(source, i, 1);
+        network.add_arc(n + i, sink, 1);
+        for j in reach[i].ones() {
+            pairs.push((network.add_arc(i, n + j, 1), i, j));
+        }
+    }
+    network.max_flow(source, sink);
+
+    let mut next = vec![None; n];
+    for (e, i, j) in pairs {
+        if network.flow(e) > 0 {
+            next[i] = Some(j);
+        }
+    }
+    let side = network.source_side(source);
+    Ok(ChainMatching {
+        order,
+        next,
+        left: side[..n].to_vec(),
+        right: side[n..2 * n].to_vec(),
+    })
+}
+
+/// \[Generic\] Compute a minimum chain cover of the partial order of a
+/// directed acyclic graph.
+///
+/// Return the fewest chains such that every node is in exactly one chain.
+/// Each chain lists its nodes in order, each node reaching the next one by a
+/// path in the graph; consecutive nodes need not be adjacent. The chains are
+/// ordered by their first node in a topological order.
+///
+/// If the graph has a cycle, return an `Error::Cycle` error with a node on a
+/// cycle.
+/// Self loops are also cycles.
+///
+/// Computes in **O(|V| |E| + |V|² √|V|)** time and **O(|V|²)** space.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::min_chain_cover;
+/// use petgraph::prelude::*;
+///
+/// // Divisibility of 1, 2, 3, 4 and 6.
+/// let mut g = DiGraph::<u32, ()>::new();
+/// let one = g.add_node(1);
+/// let two = g.add_node(2);
+/// let three = g.add_node(3);
+/// let four = g.add_node(4);
+/// let six = g.add_node(6);
+/// g.extend_with_edges(&[(one, two), (one, three), (two, four), (two, six), (three, six)]);
+///
+/// let chains = min_chain_cover(&g).unwrap();
+/// assert_eq!(chains.len(), 2);
+/// assert_eq!(chains.concat().len(), 5);
+/// ```
+pub fn min_chain_cover<G>(g: G) -> Result<Vec<Vec<G::NodeId>>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let matching = chain_matching(g)?;
+    let mut has_prev = vec![false; matching.next.len()];
+    for &j in matching.next.iter().flatten() {
+        has_prev[j] = true;
+    }
+    let mut chains = Vec::new();
+    for &a in &matching.order {
+        let mut i = g.to_index(a);
+        if has_prev[i] {
+            continue;
+        }
+        let mut chain = vec![a];
+        while let Some(j) = matching.next[i] {
+            chain.push(g.from_index(j));
+            i = j;
+        }
+        chains.push(chain);
+    }
+    Ok(chains)
+}
+
+/// \[Generic\] Compute a maximum antichain of the partial order of a directed
+/// acyclic graph.
+///
+/// Return the largest set of nodes such that there is no path from one of them
+/// to another, in topological order. Its size equals the number of chains of
+/// [`min_chain_cover`](fn.min_chain_cover.html).
+///
+/// If the graph has a cycle, return an `Error::Cycle` error with a node on a
+/// cycle.
+/// Self loops are also cycles.
+///
+/// Computes in **O(|V| |E| + |V|² √|V|)** time and **O(|V|²)** space.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::max_antichain;
+/// use petgraph::prelude::*;
+///
+/// // Divisibility of 1, 2, 3, 4 and 6.
+/// let mut g = DiGraph::<u32, ()>::new();
+/// let one = g.add_node(1);
+/// let two = g.add_node(2);
+/// let three = g.add_node(3);
+/// let four = g.add_node(4);
+/// let six = g.add_node(6);
+/// g.extend_with_edges(&[(one, two), (one, three), (two, four), (two, six), (three, six)]);
+///
+/// let antichain = max_antichain(&g).unwrap();
+/// assert_eq!(antichain.len(), 2);
+/// ```
+pub fn max_antichain<G>(g: G) -> Result<Vec<G::NodeId>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    // The nodes whose copies are both outside the minimum vertex cover
+    // given by the cut, by König's theorem.
+    let ChainMatching {
+        order, left, right, ..
+    } = chain_matching(g)?;
+    Ok(order
+        .into_iter()
+        .filter(|&a| left[g.to_index(a)] && !right[g.to_index(a)])
+        .collect())
+}
@@ -0,0 +1,128 @@
+//! Compute small dominating sets of a graph.
+//!
+//! A *dominating set* is a set of nodes such that every node of the graph is
+//! either in the set or adjacent to a node in the set. Finding a minimum
+//! dominating set is NP-hard; the greedy algorithms here compute a dominating
+//! set that is at most a factor **H(Δ + 1) ≤ ln(Δ + 1) + 1** larger than the
+//! optimum, where **Δ** is the maximum degree of the graph.
+
+use std::collections::{BinaryHeap, HashSet};
+use std::hash::Hash;
+
+use super::undirected_adjacency;
+use crate::scored::MinScored;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Compute a dominating set with the greedy set cover heuristic.
+///
+/// Repeatedly adds the node that dominates the most nodes that are not yet
+/// dominated, until all nodes are dominated.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::greedy_dominating_set;
+/// use petgraph::prelude::*;
+///
+/// // Two stars joined at their centers.
+/// let g = UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3),
+///     (4, 5), (4, 6), (4, 7),
+///     (0, 4),
+/// ]);
+/// let set = greedy_dominating_set(&g);
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(&NodeIndex::new(0)));
+/// assert!(set.contains(&NodeIndex::new(4)));
+/// ```
+pub fn greedy_dominating_set<G>(g: G) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    greedy_weighted_dominating_set(g, |_| 1.)
+}
+
+/// \[Generic\] Compute a dominating set of small total weight with the greedy
+/// weighted set cover heuristic.
+///
+/// `node_weight` gives the non-negative cost of adding a node to the set. The
+/// algorithm repeatedly adds the node with the least cost per newly dominated
+/// node, until all nodes are dominated.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// Computes in **O((|V| + |E|) log |V|)** time, up to re-evaluation of nodes
+/// whose number of newly dominated nodes changed.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::greedy_weighted_dominating_set;
+/// use petgraph::prelude::*;
+///
+/// // A path a - b - c where the middle node is expensive.
+/// let mut g = UnGraph::<f64, ()>::new_undirected();
+/// let a = g.add_node(1.);
+/// let b = g.add_node(10.);
+/// let c = g.add_node(1.);
+/// g.extend_with_edges(&[(a, b), (b, c)]);
+///
+/// let set = greedy_weighted_dominating_set(&g, |n| g[n]);
+/// assert_eq!(set.len(), 2);
+/// assert!(!set.contains(&b));
+/// ```
+pub fn greedy_weighted_dominating_set<G, F>(g: G, mut node_weight: F) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::NodeId) -> f64,
+{
+    let (nodes, adj) = undirected_adjacency(g);
+    let mut cost = vec![0.; adj.len()];
+    let mut dominated = vec![false; adj.len()];
+    let mut chosen = vec![false; adj.len()];
+    let mut heap = BinaryHeap::with_capacity(nodes.len());
+    for &i in &nodes {
+        cost[i] = node_weight(g.from_index(i));
+        heap.push(MinScored(cost[i] / (adj[i].len() + 1) as f64, i));
+    }
+
+    let mut undominated = nodes.len();
+    let mut set = HashSet::new();
+    while undominated > 0 {
+        let MinScored(score, i) = match heap.pop() {
+            Some(item) => item,
+            None => break,
+        };
+        if chosen[i] {
+            continue;
+        }
+        let gain = Some(i)
+            .iter()
+            .chain(&adj[i])
+            .filter(|&&j| !dominated[j])
+            .count();
+        if gain == 0 {
+            continue;
+        }
+        // The gain of a node only decreases, so a stale score is a lower
+        // bound; reinsert the node if its up to date score is worse.
+        let current = cost[i] / gain as f64;
+        if current > score {
+            heap.push(MinScored(current, i));
+            continue;
+        }
+        chosen[i] = true;
+        set.insert(g.from_index(i));
+        for &j in Some(i).iter().chain(&adj[i]) {
+            if !dominated[j] {
+                dominated[j] = true;
+                undominated -= 1;
+            }
+        }
+    }
+    set
+}
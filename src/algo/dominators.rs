@@ -11,12 +11,21 @@
 //! The node **A** is said to be the *immediate dominator* of a node **B** iff it
 //! strictly dominates **B** and there does not exist any node **C** where **A**
 //! dominates **C** and **C** dominates **B**.
+//!
+//! # Post-Dominance
+//!
+//! Likewise, with an exit node **X**, a node **A** is said to *post-dominate* a
+//! node **B** iff every path from **B** to **X** contains **A**. These are the
+//! dominators of the graph with reversed edges and **X** as its root. When
+//! there are several exits, they are all joined to a virtual exit node.
 
 use std::cmp::Ordering;
 use std::collections::{hash_map::Iter, HashMap, HashSet};
 use std::hash::Hash;
 
-use crate::visit::{DfsPostOrder, GraphBase, IntoNeighbors, Visitable, Walker};
+use crate::visit::{
+    DfsPostOrder, GraphBase, IntoNeighbors, IntoNeighborsDirected, Reversed, Visitable,
+};
 
 /// The dominance relation for some graph and root.
 #[derive(Debug, Clone)]
@@ -33,6 +42,8 @@ where
     N: Copy + Eq + Hash,
 {
     /// Get the root node used to construct these dominance relations.
+    ///
+    /// For the post-dominators of several exits, this is the first exit.
     pub fn root(&self) -> N {
         self.root
     }
@@ -40,12 +51,13 @@ where
     /// Get the immediate dominator of the given node.
     ///
     /// Returns `None` for any node that is not reachable from the root, and for
-    /// the root itself.
+    /// the root itself. For the post-dominators of several exits, this is
+    /// also `None` for the nodes that are immediately post-dominated by the
+    /// virtual exit, like the exits themselves.
     pub fn immediate_dominator(&self, node: N) -> Option<N> {
-        if node == self.root {
-            None
-        } else {
-            self.dominators.get(&node).cloned()
+        match self.dominators.get(&node) {
+            Some(&dom) if dom != node => Some(dom),
+            _ => None,
         }
     }
 
@@ -88,6 +100,65 @@ where
             node,
         }
     }
+
+    /// Compute the dominance frontier of every node reachable from the root.
+    ///
+    /// The dominance frontier of a node **A** is the set of nodes **B** where
+    /// **A** dominates a predecessor of **B**, but does not strictly dominate
+    /// **B**: the nodes where the dominance of **A** ends, as needed to place
+    /// φ-functions in SSA construction.
+    ///
+    /// `graph` must be the graph the dominators were computed for; for
+    /// post-dominators, the reversed graph, which gives the post-dominance
+    /// frontiers.
+    ///
+    /// Computes in **O(|E|·d)** time, where **d** is the depth of the
+    /// dominator tree, with the algorithm by Cooper et al.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::algo::dominators::simple_fast;
+    /// use petgraph::prelude::*;
+    ///
+    /// // 0 branches to 1 and 2, which join at 3.
+    /// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3)]);
+    /// let n = NodeIndex::new;
+    ///
+    /// let frontiers = simple_fast(&g, n(0)).dominance_frontiers(&g);
+    /// assert!(frontiers[&n(0)].is_empty());
+    /// assert!(frontiers[&n(1)].contains(&n(3)));
+    /// assert!(frontiers[&n(2)].contains(&n(3)));
+    /// assert!(frontiers[&n(3)].is_empty());
+    /// ```
+    pub fn dominance_frontiers<G>(&self, graph: G) -> HashMap<N, HashSet<N>>
+    where
+        G: IntoNeighbors<NodeId = N>,
+    {
+        let mut frontiers: HashMap<N, HashSet<N>> = self
+            .dominators
+            .keys()
+            .map(|&node| (node, HashSet::new()))
+            .collect();
+
+        // Walk up the dominator tree from every predecessor of a node, until
+        // its immediate dominator; the nodes on the way don't dominate it.
+        for &pred in self.dominators.keys() {
+            for node in graph.neighbors(pred) {
+                let idom = self.immediate_dominator(node);
+                let mut runner = Some(pred);
+                while let Some(r) = runner {
+                    if runner == idom {
+                        break;
+                    }
+                    if let Some(frontier) = frontiers.get_mut(&r) {
+                        frontier.insert(node);
+                    }
+                    runner = self.immediate_dominator(r);
+                }
+            }
+        }
+        frontiers
+    }
 }
 
 /// Iterator for a node's dominators.
@@ -133,7 +204,7 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         while let Some(next) = self.iter.next() {
-            if next.1 == &self.node {
+            if next.1 == &self.node && next.0 != &self.node {
                 return Some(*next.0);
             }
         }
@@ -163,10 +234,64 @@ where
     G: IntoNeighbors + Visitable,
     <G as GraphBase>::NodeId: Eq + Hash,
 {
-    let (post_order, predecessor_sets) = simple_fast_post_order(graph, root);
+    simple_fast_from_roots(graph, root, Some(root))
+}
+
+/// Compute the post-dominators of a graph, for one or several exit nodes.
+///
+/// These are the dominators of the graph with reversed edges, computed like
+/// [`simple_fast`](fn.simple_fast.html). With several exits, the nodes are
+/// post-dominated by a virtual exit that follows all the exits; it doesn't
+/// appear in the result, so the nodes it immediately post-dominates have no
+/// immediate dominator.
+///
+/// **Panics** if `exits` is empty.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::dominators::post_dominators;
+/// use petgraph::prelude::*;
+///
+/// // 0 branches to 1 and 2, which join at 3; 2 may also return early at 4.
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 3), (2, 3), (2, 4)]);
+/// let n = NodeIndex::new;
+///
+/// let doms = post_dominators(&g, Some(n(3)));
+/// assert_eq!(doms.immediate_dominator(n(1)), Some(n(3)));
+/// assert_eq!(doms.immediate_dominator(n(0)), Some(n(3)));
+/// // 4 can't reach the exit 3
+/// assert_eq!(doms.immediate_dominator(n(4)), None);
+///
+/// let doms = post_dominators(&g, vec![n(3), n(4)]);
+/// assert_eq!(doms.immediate_dominator(n(1)), Some(n(3)));
+/// // paths from 0 end in either exit
+/// assert_eq!(doms.immediate_dominator(n(0)), None);
+/// ```
+pub fn post_dominators<G, I>(graph: G, exits: I) -> Dominators<G::NodeId>
+where
+    G: IntoNeighborsDirected + Visitable,
+    <G as GraphBase>::NodeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+{
+    let exits: Vec<_> = exits.into_iter().collect();
+    let root = *exits.first().expect("post_dominators: no exit nodes");
+    simple_fast_from_roots(Reversed(graph), root, exits)
+}
+
+/// Compute the dominators of the nodes reachable from `roots`, which all
+/// follow a virtual root node, reported as the dominator `root`.
+///
+/// The nodes immediately dominated by the virtual root dominate themselves.
+fn simple_fast_from_roots<G, I>(graph: G, root: G::NodeId, roots: I) -> Dominators<G::NodeId>
+where
+    G: IntoNeighbors + Visitable,
+    <G as GraphBase>::NodeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+{
+    let roots: Vec<_> = roots.into_iter().collect();
+    let (post_order, predecessor_sets) = simple_fast_post_order(graph, &roots);
     let length = post_order.len();
     debug_assert!(length > 0);
-    debug_assert!(post_order.last() == Some(&root));
 
     // From here on out we use indices into `post_order` instead of actual
     // `NodeId`s wherever possible. This greatly improves the performance of
@@ -181,22 +306,24 @@ where
         .collect();
 
     // Maps a node's `post_order` index to its set of predecessors's indices
-    // into `post_order` (as a vec).
-    let idx_to_predecessor_vec =
+    // into `post_order` (as a vec). The virtual root comes last in the post
+    // order, at index `length`, and precedes all the roots.
+    let mut idx_to_predecessor_vec =
         predecessor_sets_to_idx_vecs(&post_order, &node_to_post_order_idx, predecessor_sets);
+    for r in &roots {
+        idx_to_predecessor_vec[node_to_post_order_idx[r]].push(length);
+    }
 
-    let mut dominators = vec![UNDEFINED; length];
-    dominators[length - 1] = length - 1;
+    let mut dominators = vec![UNDEFINED; length + 1];
+    dominators[length] = length;
 
     let mut changed = true;
     while changed {
         changed = false;
 
-        // Iterate in reverse post order, skipping the root.
-
-        for idx in (0..length - 1).rev() {
-            debug_assert!(post_order[idx] != root);
+        // Iterate in reverse post order, skipping the virtual root.
 
+        for idx in (0..length).rev() {
             // Take the intersection of every predecessor's dominator set; that
             // is the current best guess at the immediate dominator for this
             // node.
@@ -215,7 +342,7 @@ where
                 })
             };
 
-            debug_assert!(new_idom_idx < length);
+            debug_assert!(new_idom_idx <= length);
 
             if new_idom_idx != dominators[idx] {
                 dominators[idx] = new_idom_idx;
@@ -232,8 +359,12 @@ where
         root,
         dominators: dominators
             .into_iter()
+            .take(length)
             .enumerate()
-            .map(|(idx, dom_idx)| (post_order[idx], post_order[dom_idx]))
+            .map(|(idx, dom_idx)| {
+                let dom = if dom_idx == length { idx } else { dom_idx };
+                (post_order[idx], post_order[dom])
+            })
             .collect(),
     }
 }
@@ -276,7 +407,7 @@ type PredecessorSets<NodeId> = HashMap<NodeId, HashSet<NodeId>>;
 
 fn simple_fast_post_order<G>(
     graph: G,
-    root: G::NodeId,
+    roots: &[G::NodeId],
 ) -> (Vec<G::NodeId>, PredecessorSets<G::NodeId>)
 where
     G: IntoNeighbors + Visitable,
@@ -285,14 +416,18 @@ where
     let mut post_order = vec![];
     let mut predecessor_sets = HashMap::new();
 
-    for node in DfsPostOrder::new(graph, root).iter(graph) {
-        post_order.push(node);
-
-        for successor in graph.neighbors(node) {
-            predecessor_sets
-                .entry(successor)
-                .or_insert_with(HashSet::new)
-                .insert(node);
+    let mut dfs = DfsPostOrder::empty(graph);
+    for &root in roots {
+        dfs.move_to(root);
+        while let Some(node) = dfs.next(graph) {
+            post_order.push(node);
+
+            for successor in graph.neighbors(node) {
+                predecessor_sets
+                    .entry(successor)
+                    .or_insert_with(HashSet::new)
+                    .insert(node);
+            }
         }
     }
 
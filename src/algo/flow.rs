@@ -0,0 +1,285 @@
+//! Maximum flow and minimum cut.
+//!
+//! The capacity of each edge is given by a closure, and the flows are
+//! computed with Dinic's algorithm, in **O(|V|² |E|)** time.
+
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Sub;
+
+use super::Measure;
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+/// \[Generic\] Compute a maximum flow from `source` to `sink`.
+///
+/// `capacity` gives the capacity of each edge, which must not be negative.
+/// An undirected edge can carry flow either way, up to its capacity.
+///
+/// Returns the value of the flow and the flow on each edge, in the order of
+/// `edge_references`. The flow on an undirected edge is from its source to
+/// its target, and negative if it goes the other way.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::maximum_flow;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), u32>::from_edges(&[(0, 1, 3), (0, 2, 2), (1, 2, 1), (1, 3, 1), (2, 3, 4)]);
+/// let (value, flows) = maximum_flow(&g, 0.into(), 3.into(), |e| *e.weight());
+/// assert_eq!(value, 4);
+/// assert_eq!(flows, [2, 2, 1, 1, 3]);
+/// ```
+pub fn maximum_flow<G, F, K>(g: G, source: G::NodeId, sink: G::NodeId, capacity: F) -> (K, Vec<K>)
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let (mut network, arcs) = edge_network(g, capacity);
+    let value = network.max_flow(g.to_index(source), g.to_index(sink));
+    let flows = arcs
+        .into_iter()
+        .map(|(_, e)| {
+            if g.is_directed() {
+                network.flow(e)
+            } else {
+                network.flow(e) - network.flow(e + 2)
+            }
+        })
+        .collect();
+    (value, flows)
+}
+
+/// \[Generic\] Compute a minimum cut between `source` and `sink`.
+///
+/// `capacity` gives the capacity of each edge, which must not be negative.
+/// The cut is found from a [maximum flow](fn.maximum_flow.html), as the nodes
+/// that can still be reached from `source` in its residual network.
+///
+/// Returns the total capacity of the cut, the nodes on the side of `source`,
+/// and the edges of the cut: those from the side of `source` to the other
+/// side, or for an undirected graph, those between the two sides.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::minimum_cut;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::EdgeRef;
+///
+/// let g = DiGraph::<(), u32>::from_edges(&[(0, 1, 3), (0, 2, 2), (1, 2, 1), (1, 3, 1), (2, 3, 4)]);
+/// let (value, source_side, cut) = minimum_cut(&g, 0.into(), 3.into(), |e| *e.weight());
+/// assert_eq!(value, 4);
+/// assert_eq!(source_side.len(), 2);
+/// assert!(source_side.contains(&0.into()) && source_side.contains(&1.into()));
+/// let cut = cut.iter().map(|e| e.id().index()).collect::<Vec<_>>();
+/// assert_eq!(cut, [1, 2, 3]);
+/// ```
+pub fn minimum_cut<G, F, K>(
+    g: G,
+    source: G::NodeId,
+    sink: G::NodeId,
+    capacity: F,
+) -> (K, HashSet<G::NodeId>, Vec<G::EdgeRef>)
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let (mut network, arcs) = edge_network(g, capacity);
+    let source = g.to_index(source);
+    let value = network.max_flow(source, g.to_index(sink));
+    let reachable = network.source_side(source);
+    let source_side = (0..g.node_bound())
+        .filter(|&i| reachable[i])
+        .map(|i| g.from_index(i))
+        .collect();
+    let cut = arcs
+        .into_iter()
+        .map(|(edge, _)| edge)
+        .filter(|edge| {
+            let s = reachable[g.to_index(edge.source())];
+            let t = reachable[g.to_index(edge.target())];
+            if g.is_directed() {
+                s && !t
+            } else {
+                s != t
+            }
+        })
+        .collect();
+    (value, source_side, cut)
+}
+
+/// Build the flow network of the edges of `g`, with an arc for each edge of
+/// a directed graph, and a pair of arcs each way for an undirected one.
+///
+/// Returns the network and the edges with the ids of their first arcs.
+fn edge_network<G, F, K>(g: G, mut capacity: F) -> (Network<K>, Vec<(G::EdgeRef, usize)>)
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    let mut network = Network::new(g.node_bound());
+    let arcs = g
+        .edge_references()
+        .map(|edge| {
+            let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+            let c = capacity(edge);
+            let e = network.add_arc(a, b, c);
+            if !g.is_directed() {
+                network.add_arc(b, a, c);
+            }
+            (edge, e)
+        })
+        .collect();
+    (network, arcs)
+}
+
+/// A residual flow network over node indices `0..n`, solved with Dinic's
+/// algorithm.
+///
+/// Every arc is stored next to its reverse arc, so that the reverse of arc
+/// `e` is `e ^ 1`.
+#[derive(Clone, Debug)]
+pub(crate) struct Network<K> {
+    adj: Vec<Vec<usize>>,
+    target: Vec<usize>,
+    residual: Vec<K>,
+}
+
+impl<K> Network<K>
+where
+    K: Measure + Copy + Sub<K, Output = K>,
+{
+    pub(crate) fn new(node_count: usize) -> Self {
+        Network {
+            adj: vec![Vec::new(); node_count],
+            target: Vec::new(),
+            residual: Vec::new(),
+        }
+    }
+
+    /// Add an arc from `a` to `b` with the given capacity and return its id.
+    pub(crate) fn add_arc(&mut self, a: usize, b: usize, capacity: K) -> usize {
+        let e = self.target.len();
+        self.adj[a].push(e);
+        self.target.push(b);
+        self.residual.push(capacity);
+        self.adj[b].push(e + 1);
+        self.target.push(a);
+        self.residual.push(K::default());
+        e
+    }
+
+    /// Return the flow on arc `e`.
+    pub(crate) fn flow(&self, e: usize) -> K {
+        self.residual[e ^ 1]
+    }
+
+    /// Compute a maximum flow from `source` to `sink` and return its value.
+    pub(crate) fn max_flow(&mut self, source: usize, sink: usize) -> K {
+        let zero = K::default();
+        let mut total = zero;
+        if source == sink {
+            return total;
+        }
+        let n = self.adj.len();
+        let mut level = vec![usize::max_value(); n];
+        let mut next = vec![0; n];
+        let mut path = Vec::new();
+        loop {
+            self.levels(source, &mut level);
+            if level[sink] == usize::max_value() {
+                break;
+            }
+            for x in &mut next {
+                *x = 0;
+            }
+            // Find augmenting paths in the level graph until it is blocked.
+            let mut u = source;
+            loop {
+                if u == sink {
+                    let mut bottleneck = self.residual[path[0]];
+                    for &e in &path[1..] {
+                        if self.residual[e] < bottleneck {
+                            bottleneck = self.residual[e];
+                        }
+                    }
+                    for &e in &path {
+                        self.residual[e] = self.residual[e] - bottleneck;
+                        self.residual[e ^ 1] = self.residual[e ^ 1] + bottleneck;
+                    }
+                    total = total + bottleneck;
+                    path.clear();
+                    u = source;
+                    continue;
+                }
+                let mut advanced = false;
+                while next[u] < self.adj[u].len() {
+                    let e = self.adj[u][next[u]];
+                    let v = self.target[e];
+                    if self.residual[e] > zero && level[v] == level[u] + 1 {
+                        path.push(e);
+                        u = v;
+                        advanced = true;
+                        break;
+                    }
+                    next[u] += 1;
+                }
+                if !advanced {
+                    if u == source {
+                        break;
+                    }
+                    // dead end: retreat and never come back to `u` in this phase
+                    level[u] = usize::max_value();
+                    let e = path.pop().unwrap();
+                    u = self.target[e ^ 1];
+                    next[u] += 1;
+                }
+            }
+        }
+        total
+    }
+
+    /// Compute the BFS levels from `source` in the residual network.
+    fn levels(&self, source: usize, level: &mut [usize]) {
+        let zero = K::default();
+        for l in level.iter_mut() {
+            *l = usize::max_value();
+        }
+        level[source] = 0;
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+        while let Some(u) = queue.pop_front() {
+            for &e in &self.adj[u] {
+                let v = self.target[e];
+                if self.residual[e] > zero && level[v] == usize::max_value() {
+                    level[v] = level[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+    }
+
+    /// Return the nodes reachable from `source` in the residual network.
+    ///
+    /// After a maximum flow has been computed, these nodes form the source
+    /// side of a minimum cut.
+    pub(crate) fn source_side(&self, source: usize) -> Vec<bool> {
+        let zero = K::default();
+        let mut reachable = vec![false; self.adj.len()];
+        reachable[source] = true;
+        let mut stack = vec![source];
+        while let Some(u) = stack.pop() {
+            for &e in &self.adj[u] {
+                let v = self.target[e];
+                if self.residual[e] > zero && !reachable[v] {
+                    reachable[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        reachable
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use std::hash::Hash;
 
-use crate::algo::{BoundedMeasure, NegativeCycle};
+use crate::algo::{BoundedMeasure, Error};
 use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeCompactIndexable};
 
 #[allow(clippy::type_complexity, clippy::needless_range_loop)]
@@ -15,8 +15,9 @@ use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeCompact
 /// * `edge_cost`: closure that returns cost of a particular edge
 ///
 /// # Returns
-/// * `Ok`: (if graph contains no negative cycle) a hashmap containing all pairs shortest paths
-/// * `Err`: if graph contains negative cycle.
+/// * `Ok`: (if graph contains no negative cycle) a hashmap containing all pairs shortest paths,
+///   with the cost `BoundedMeasure::max()` for the pairs without a path, which is infinity for floats
+/// * `Err`: `Error::NegativeCycle` if graph contains negative cycle.
 ///
 /// # Examples
 /// ```rust
@@ -79,7 +80,7 @@ use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeCompact
 pub fn floyd_warshall<G, F, K>(
     graph: G,
     mut edge_cost: F,
-) -> Result<HashMap<(G::NodeId, G::NodeId), K>, NegativeCycle>
+) -> Result<HashMap<(G::NodeId, G::NodeId), K>, Error<G::NodeId>>
 where
     G: NodeCompactIndexable + IntoEdgeReferences + IntoNodeIdentifiers,
     G::NodeId: Eq + Hash,
@@ -115,7 +116,7 @@ where
     // value less than 0(default value) indicates a negative cycle
     for i in 0..num_of_nodes {
         if dist[i][i] < K::default() {
-            return Err(NegativeCycle(()));
+            return Err(Error::NegativeCycle);
         }
     }
 
@@ -0,0 +1,139 @@
+//! Compute a hash of the structure of a graph.
+//!
+//! The hash is derived from the Weisfeiler–Lehman color refinement: every node
+//! starts out with a label, and in each round the label of a node is replaced
+//! by a hash of its label and the multiset of labels of its neighbors. The
+//! refinement stops once the number of distinct labels no longer grows, and
+//! the graph hash combines the multisets of node labels of every round.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::mem;
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+fn hash_one<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// \[Generic\] Compute a Weisfeiler–Lehman hash of the structure of the graph.
+///
+/// Isomorphic graphs have equal hashes, so graphs with different hashes are
+/// certainly not isomorphic. The converse does not hold: for example, graphs
+/// where every node has the same degree are only told apart by their number
+/// of nodes and edges. The hash is thus useful to bucket graphs before running
+/// [`is_isomorphic`](fn.is_isomorphic.html) on the graphs in the same bucket.
+///
+/// Node and edge weights are ignored, see
+/// [`graph_hash_with`](fn.graph_hash_with.html) to include them.
+///
+/// Hash values are stable within a program, but may differ between versions of
+/// petgraph or Rust.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::graph_hash;
+/// use petgraph::prelude::*;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let path2 = UnGraph::<(), ()>::from_edges(&[(3, 0), (0, 2), (2, 1)]);
+/// let star = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(graph_hash(&path), graph_hash(&path2));
+/// assert_ne!(graph_hash(&path), graph_hash(&star));
+///
+/// // A hexagon and two triangles are not isomorphic, but have the same hash.
+/// let hexagon = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+/// let triangles = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+/// assert_eq!(graph_hash(&hexagon), graph_hash(&triangles));
+/// ```
+pub fn graph_hash<G>(g: G) -> u64
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    graph_hash_with(g, |_| (), |_| ())
+}
+
+/// \[Generic\] Compute a Weisfeiler–Lehman hash of the graph, including node
+/// and edge labels.
+///
+/// `node_label` and `edge_label` give the initial label of each node and the
+/// label of each edge, usually derived from their weights. Graphs that are
+/// isomorphic by a mapping which preserves the labels have equal hashes. See
+/// [`graph_hash`](fn.graph_hash.html) for more details.
+///
+/// The number of refinement rounds is at most **|V|**, and each round takes
+/// **O((|V| + |E|) log |V|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::graph_hash_with;
+/// use petgraph::prelude::*;
+///
+/// let a = DiGraph::<&str, u32>::from_edges(&[(0, 1, 7), (1, 2, 8)]);
+/// let mut b = a.clone();
+/// b.reverse();
+/// let hash = |g: &DiGraph<&str, u32>| graph_hash_with(g, |n| g[n], |e| *e.weight());
+/// assert_ne!(hash(&a), hash(&b));
+/// ```
+pub fn graph_hash_with<G, FN, FE, N, E>(g: G, mut node_label: FN, mut edge_label: FE) -> u64
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    FN: FnMut(G::NodeId) -> N,
+    FE: FnMut(G::EdgeRef) -> E,
+    N: Hash,
+    E: Hash,
+{
+    let nodes = g
+        .node_identifiers()
+        .map(|a| g.to_index(a))
+        .collect::<Vec<_>>();
+    let mut label = vec![0; g.node_bound()];
+    for a in g.node_identifiers() {
+        label[g.to_index(a)] = hash_one(&node_label(a));
+    }
+    let edges = g
+        .edge_references()
+        .map(|edge| {
+            (
+                g.to_index(edge.source()),
+                g.to_index(edge.target()),
+                hash_one(&edge_label(edge)),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut hasher = DefaultHasher::new();
+    (nodes.len(), edges.len()).hash(&mut hasher);
+    let mut next_label = vec![0; label.len()];
+    // (is outgoing, edge label, neighbor label) for every edge of a node
+    let mut neighborhood = vec![Vec::new(); label.len()];
+    let mut sorted = Vec::with_capacity(nodes.len());
+    let mut classes = 0;
+    loop {
+        sorted.clear();
+        sorted.extend(nodes.iter().map(|&i| label[i]));
+        sorted.sort_unstable();
+        sorted.hash(&mut hasher);
+        sorted.dedup();
+        if sorted.len() <= classes {
+            break;
+        }
+        classes = sorted.len();
+
+        for list in &mut neighborhood {
+            list.clear();
+        }
+        for &(a, b, w) in &edges {
+            neighborhood[a].push((true, w, label[b]));
+            neighborhood[b].push((!g.is_directed(), w, label[a]));
+        }
+        for &i in &nodes {
+            neighborhood[i].sort_unstable();
+            next_label[i] = hash_one(&(label[i], &neighborhood[i]));
+        }
+        mem::swap(&mut label, &mut next_label);
+    }
+    hasher.finish()
+}
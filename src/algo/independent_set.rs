@@ -0,0 +1,133 @@
+//! Compute maximal independent sets of a graph.
+//!
+//! An *independent set* is a set of nodes where no two nodes are adjacent, and
+//! it is *maximal* if no other node can be added to it without breaking that
+//! property. A maximal independent set is not necessarily a *maximum* one.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::hash::Hash;
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use super::undirected_adjacency;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Compute a maximal independent set with the greedy minimum
+/// degree heuristic.
+///
+/// Repeatedly adds a node of minimum degree in the remaining graph to the set,
+/// then removes it and its neighbors from the graph.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// Computes in **O((|V| + |E|) log |V|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::maximal_independent_set;
+/// use petgraph::prelude::*;
+///
+/// // A star: the leaves form the largest independent set.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let set = maximal_independent_set(&g);
+/// assert_eq!(set.len(), 3);
+/// assert!(!set.contains(&NodeIndex::new(0)));
+/// ```
+pub fn maximal_independent_set<G>(g: G) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, adj) = undirected_adjacency(g);
+    let mut degree = adj.iter().map(Vec::len).collect::<Vec<_>>();
+    let mut removed = vec![false; adj.len()];
+    let mut heap = nodes
+        .iter()
+        .map(|&i| Reverse((degree[i], i)))
+        .collect::<BinaryHeap<_>>();
+    let mut set = HashSet::new();
+
+    while let Some(Reverse((d, i))) = heap.pop() {
+        if removed[i] || degree[i] != d {
+            // stale entry
+            continue;
+        }
+        set.insert(g.from_index(i));
+        removed[i] = true;
+        for &j in &adj[i] {
+            if removed[j] {
+                continue;
+            }
+            removed[j] = true;
+            for &k in &adj[j] {
+                if !removed[k] {
+                    degree[k] -= 1;
+                    heap.push(Reverse((degree[k], k)));
+                }
+            }
+        }
+    }
+    set
+}
+
+/// \[Generic\] Compute a maximal independent set using Luby's randomized
+/// algorithm.
+///
+/// In each round, every remaining node draws a random value from `rng` and
+/// joins the set if its value is smaller than the values of all its remaining
+/// neighbors. The chosen nodes and their neighbors are then removed, and the
+/// rounds continue until no nodes remain. The expected number of rounds is
+/// **O(log |V|)**.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// Requires crate feature `"rand"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::luby_maximal_independent_set;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let set = luby_maximal_independent_set(&g, &mut rand::thread_rng());
+/// assert!(set.len() == 2);
+/// ```
+#[cfg(feature = "rand")]
+pub fn luby_maximal_independent_set<G, R>(g: G, rng: &mut R) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    R: Rng,
+{
+    let (mut remaining, adj) = undirected_adjacency(g);
+    let mut removed = vec![false; adj.len()];
+    let mut value = vec![0u64; adj.len()];
+    let mut chosen = Vec::new();
+    let mut set = HashSet::new();
+
+    while !remaining.is_empty() {
+        for &i in &remaining {
+            value[i] = rng.gen();
+        }
+        // ties are broken by node index
+        chosen.clear();
+        chosen.extend(remaining.iter().cloned().filter(|&i| {
+            adj[i]
+                .iter()
+                .all(|&j| removed[j] || (value[i], i) < (value[j], j))
+        }));
+        for &i in &chosen {
+            set.insert(g.from_index(i));
+            removed[i] = true;
+            for &j in &adj[i] {
+                removed[j] = true;
+            }
+        }
+        remaining.retain(|&i| !removed[i]);
+    }
+    set
+}
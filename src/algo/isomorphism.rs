@@ -1,4 +1,5 @@
 use crate::data::DataMap;
+use crate::unionfind::UnionFind;
 use crate::visit::EdgeCount;
 use crate::visit::EdgeRef;
 use crate::visit::GetAdjacencyMatrix;
@@ -7,8 +8,10 @@ use crate::visit::GraphProp;
 use crate::visit::IntoEdgesDirected;
 use crate::visit::IntoNeighborsDirected;
 use crate::visit::NodeCompactIndexable;
+use crate::visit::NodeIndexable;
 use crate::{Incoming, Outgoing};
 
+use self::matching::{Mode, Vf2Matches};
 use self::semantic::EdgeMatcher;
 use self::semantic::NoSemanticMatch;
 use self::semantic::NodeMatcher;
@@ -19,9 +22,9 @@ mod state {
 
     #[derive(Debug)]
     // TODO: make mapping generic over the index type of the other graph.
-    pub struct Vf2State<'a, G: GetAdjacencyMatrix> {
-        /// A reference to the graph this state was built from.
-        pub graph: &'a G,
+    pub struct Vf2State<G: GetAdjacencyMatrix> {
+        /// The graph this state was built from.
+        pub graph: G,
         /// The current mapping M(s) of nodes from G0 → G1 and G1 → G0,
         /// `usize::MAX` for no mapping.
         pub mapping: Vec<usize>,
@@ -40,11 +43,11 @@ mod state {
         generation: usize,
     }
 
-    impl<'a, G> Vf2State<'a, G>
+    impl<G> Vf2State<G>
     where
         G: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
     {
-        pub fn new(g: &'a G) -> Self {
+        pub fn new(g: G) -> Self {
             let c0 = g.node_count();
             Vf2State {
                 graph: g,
@@ -260,6 +263,18 @@ mod matching {
         Other,
     }
 
+    /// The kind of mapping to search for.
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    pub enum Mode {
+        /// A bijection that preserves both edges and non-edges.
+        Isomorphism,
+        /// An injection that preserves both edges and non-edges, that is, an
+        /// isomorphism onto a node-induced subgraph.
+        Subgraph,
+        /// An injection that preserves edges.
+        Monomorphism,
+    }
+
     #[derive(Clone, PartialEq, Debug)]
     enum Frame<G0, G1>
     where
@@ -278,10 +293,11 @@ mod matching {
     }
 
     fn is_feasible<G0, G1, NM, EM>(
-        st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
+        st: &mut (Vf2State<G0>, Vf2State<G1>),
         nodes: (G0::NodeId, G1::NodeId),
         node_match: &mut NM,
         edge_match: &mut EM,
+        mode: Mode,
     ) -> bool
     where
         G0: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
@@ -377,16 +393,32 @@ mod matching {
         // R_new: Equal for G0, G1: Ñ n Pred(G, n); both Succ and Pred,
         //      Ñ is G0 - M - Tin - Tout
         // last attempt to add these did not speed up any of the testcases
-        if r_succ!(0) > r_succ!(1) {
-            return false;
-        }
-        // R_pred
-        if st.0.graph.is_directed() && r_pred!(0) > r_pred!(1) {
-            return false;
+        //
+        // A monomorphism only needs to map the edges of nx to edges of mx, so
+        // the reverse check is replaced by comparing the degrees.
+        if mode == Mode::Monomorphism {
+            let succ_count = st.1.graph.neighbors_directed(nodes.1, Outgoing).count();
+            if r_succ!(0) > succ_count {
+                return false;
+            }
+            if st.0.graph.is_directed() {
+                let pred_count = st.1.graph.neighbors_directed(nodes.1, Incoming).count();
+                if r_pred!(0) > pred_count {
+                    return false;
+                }
+            }
+        } else {
+            if r_succ!(0) > r_succ!(1) {
+                return false;
+            }
+            // R_pred
+            if st.0.graph.is_directed() && r_pred!(0) > r_pred!(1) {
+                return false;
+            }
         }
 
         // // semantic feasibility: compare associated data for nodes
-        if NM::enabled() && !node_match.eq(st.0.graph, st.1.graph, nodes.0, nodes.1) {
+        if NM::enabled() && !node_match.eq(&st.0.graph, &st.1.graph, nodes.0, nodes.1) {
             return false;
         }
         // semantic feasibility: compare associated data for edges
@@ -413,8 +445,8 @@ mod matching {
                         );
                         let edges = (e0, e1);
                         if !edge_match.eq(
-                            st.0.graph,
-                            st.1.graph,
+                            &st.0.graph,
+                            &st.1.graph,
                             field!(edges, $j),
                             field!(edges, 1 - $j),
                         ) {
@@ -440,8 +472,8 @@ mod matching {
                             );
                             let edges = (e0, e1);
                             if !edge_match.eq(
-                                st.0.graph,
-                                st.1.graph,
+                                &st.0.graph,
+                                &st.1.graph,
                                 field!(edges, $j),
                                 field!(edges, 1 - $j),
                             ) {
@@ -453,13 +485,15 @@ mod matching {
             }
 
             edge_feasibility!(0);
-            edge_feasibility!(1);
+            if mode != Mode::Monomorphism {
+                edge_feasibility!(1);
+            }
         }
         true
     }
 
     fn next_candidate<G0, G1>(
-        st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
+        st: &mut (Vf2State<G0>, Vf2State<G1>),
     ) -> Option<(G0::NodeId, G1::NodeId, OpenList)>
     where
         G0: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
@@ -502,36 +536,33 @@ mod matching {
         }
     }
 
-    fn next_from_ix<G0, G1>(
-        st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
-        nx: G0::NodeId,
+    fn next_to_ix<G0, G1>(
+        st: &mut (Vf2State<G0>, Vf2State<G1>),
+        mx: G1::NodeId,
         open_list: OpenList,
-    ) -> Option<G0::NodeId>
+    ) -> Option<G1::NodeId>
     where
         G0: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
         G1: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
     {
-        // Find the next node index to try on the `from` side of the mapping
-        let start = st.0.graph.to_index(nx) + 1;
-        let cand0 = match open_list {
-            OpenList::Out => st.0.next_out_index(start),
-            OpenList::In => st.0.next_in_index(start),
-            OpenList::Other => st.0.next_rest_index(start),
+        // Find the next node index to try on the `to` side of the mapping
+        let start = st.1.graph.to_index(mx) + 1;
+        let cand1 = match open_list {
+            OpenList::Out => st.1.next_out_index(start),
+            OpenList::In => st.1.next_in_index(start),
+            OpenList::Other => st.1.next_rest_index(start),
         }
         .map(|c| c + start); // compensate for start offset.
-        match cand0 {
+        match cand1 {
             None => None, // no more candidates
             Some(ix) => {
                 debug_assert!(ix >= start);
-                Some(st.0.graph.from_index(ix))
+                Some(st.1.graph.from_index(ix))
             }
         }
     }
-
-    fn pop_state<G0, G1>(
-        st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
-        nodes: (G0::NodeId, G1::NodeId),
-    ) where
+    fn pop_state<G0, G1>(st: &mut (Vf2State<G0>, Vf2State<G1>), nodes: (G0::NodeId, G1::NodeId))
+    where
         G0: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
         G1: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
     {
@@ -539,10 +570,8 @@ mod matching {
         st.1.pop_mapping(nodes.1);
     }
 
-    fn push_state<G0, G1>(
-        st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
-        nodes: (G0::NodeId, G1::NodeId),
-    ) where
+    fn push_state<G0, G1>(st: &mut (Vf2State<G0>, Vf2State<G1>), nodes: (G0::NodeId, G1::NodeId))
+    where
         G0: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
         G1: GetAdjacencyMatrix + GraphProp + NodeCompactIndexable + IntoNeighborsDirected,
     {
@@ -550,12 +579,21 @@ mod matching {
         st.1.push_mapping(nodes.1, st.0.graph.to_index(nodes.0));
     }
 
-    /// Return Some(bool) if isomorphism is decided, else None.
-    pub fn try_match<G0, G1, NM, EM>(
-        mut st: &mut (Vf2State<'_, G0>, Vf2State<'_, G1>),
-        node_match: &mut NM,
-        edge_match: &mut EM,
-    ) -> Option<bool>
+    /// A depth first search for mappings of the nodes of `G0` into `G1`, that
+    /// can be resumed after every complete mapping it finds.
+    pub struct Vf2Matches<G0, G1, NM, EM>
+    where
+        G0: GetAdjacencyMatrix,
+        G1: GetAdjacencyMatrix,
+    {
+        st: (Vf2State<G0>, Vf2State<G1>),
+        stack: Vec<Frame<G0, G1>>,
+        node_match: NM,
+        edge_match: EM,
+        mode: Mode,
+    }
+
+    impl<G0, G1, NM, EM> Vf2Matches<G0, G1, NM, EM>
     where
         G0: NodeCompactIndexable
             + EdgeCount
@@ -570,69 +608,128 @@ mod matching {
         NM: NodeMatcher<G0, G1>,
         EM: EdgeMatcher<G0, G1>,
     {
-        if st.0.is_complete() {
-            return Some(true);
-        }
-
-        // A "depth first" search of a valid mapping from graph 1 to graph 2
-        // F(s, n, m) -- evaluate state s and add mapping n <-> m
-        // Find least T1out node (in st.out[1] but not in M[1])
-        let mut stack: Vec<Frame<G0, G1>> = vec![Frame::Outer];
-        while let Some(frame) = stack.pop() {
-            match frame {
-                Frame::Unwind { nodes, open_list } => {
-                    pop_state(&mut st, nodes);
-
-                    match next_from_ix(&mut st, nodes.0, open_list) {
-                        None => continue,
-                        Some(nx) => {
+        /// Search for mappings of the given kind from `g0` to `g1`.
+        pub fn new(g0: G0, g1: G1, node_match: NM, edge_match: EM, mode: Mode) -> Self {
+            let possible = if mode == Mode::Isomorphism {
+                g0.node_count() == g1.node_count() && g0.edge_count() == g1.edge_count()
+            } else {
+                g0.node_count() <= g1.node_count() && g0.edge_count() <= g1.edge_count()
+            };
+            Vf2Matches {
+                st: (Vf2State::new(g0), Vf2State::new(g1)),
+                stack: if possible {
+                    vec![Frame::Outer]
+                } else {
+                    Vec::new()
+                },
+                node_match,
+                edge_match,
+                mode,
+            }
+        }
+
+        /// Continue the search until the next complete mapping, and return
+        /// **false** if there are no more mappings.
+        ///
+        /// The mapping is left in `self.st.0.mapping`, as node indices of `G1`.
+        pub fn advance(&mut self) -> bool {
+            // A "depth first" search of a valid mapping from graph 0 to graph 1
+            // F(s, n, m) -- evaluate state s and add mapping n <-> m
+            // Find least T0out node (in st.out[0] but not in M[0])
+            let st = &mut self.st;
+            while let Some(frame) = self.stack.pop() {
+                match frame {
+                    Frame::Unwind { nodes, open_list } => {
+                        pop_state(st, nodes);
+
+                        if let Some(mx) = next_to_ix(st, nodes.1, open_list) {
                             let f = Frame::Inner {
-                                nodes: (nx, nodes.1),
+                                nodes: (nodes.0, mx),
                                 open_list,
                             };
-                            stack.push(f);
+                            self.stack.push(f);
                         }
                     }
-                }
-                Frame::Outer => match next_candidate(&mut st) {
-                    None => continue,
-                    Some((nx, mx, open_list)) => {
-                        let f = Frame::Inner {
-                            nodes: (nx, mx),
-                            open_list,
-                        };
-                        stack.push(f);
-                    }
-                },
-                Frame::Inner { nodes, open_list } => {
-                    if is_feasible(&mut st, nodes, node_match, edge_match) {
-                        push_state(&mut st, nodes);
+                    Frame::Outer => {
                         if st.0.is_complete() {
-                            return Some(true);
+                            return true;
                         }
-                        // Check cardinalities of Tin, Tout sets
-                        if st.0.out_size == st.1.out_size && st.0.ins_size == st.1.ins_size {
-                            let f0 = Frame::Unwind { nodes, open_list };
-                            stack.push(f0);
-                            stack.push(Frame::Outer);
-                            continue;
+                        if let Some((nx, mx, open_list)) = next_candidate(st) {
+                            let f = Frame::Inner {
+                                nodes: (nx, mx),
+                                open_list,
+                            };
+                            self.stack.push(f);
                         }
-                        pop_state(&mut st, nodes);
                     }
-                    match next_from_ix(&mut st, nodes.0, open_list) {
-                        None => continue,
-                        Some(nx) => {
+                    Frame::Inner { nodes, open_list } => {
+                        if is_feasible(
+                            st,
+                            nodes,
+                            &mut self.node_match,
+                            &mut self.edge_match,
+                            self.mode,
+                        ) {
+                            push_state(st, nodes);
+                            // Check cardinalities of Tin, Tout sets
+                            let viable = if self.mode == Mode::Isomorphism {
+                                st.0.out_size == st.1.out_size && st.0.ins_size == st.1.ins_size
+                            } else {
+                                st.0.out_size <= st.1.out_size && st.0.ins_size <= st.1.ins_size
+                            };
+                            if viable {
+                                let f0 = Frame::Unwind { nodes, open_list };
+                                self.stack.push(f0);
+                                self.stack.push(Frame::Outer);
+                                continue;
+                            }
+                            pop_state(st, nodes);
+                        }
+                        if let Some(mx) = next_to_ix(st, nodes.1, open_list) {
                             let f = Frame::Inner {
-                                nodes: (nx, nodes.1),
+                                nodes: (nodes.0, mx),
                                 open_list,
                             };
-                            stack.push(f);
+                            self.stack.push(f);
                         }
                     }
                 }
             }
+            false
+        }
+    }
+
+    impl<G0, G1, NM, EM> Iterator for Vf2Matches<G0, G1, NM, EM>
+    where
+        G0: NodeCompactIndexable
+            + EdgeCount
+            + GetAdjacencyMatrix
+            + GraphProp
+            + IntoNeighborsDirected,
+        G1: NodeCompactIndexable
+            + EdgeCount
+            + GetAdjacencyMatrix
+            + GraphProp
+            + IntoNeighborsDirected,
+        NM: NodeMatcher<G0, G1>,
+        EM: EdgeMatcher<G0, G1>,
+    {
+        type Item = Vec<G1::NodeId>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if !self.advance() {
+                return None;
+            }
+            let g1 = self.st.1.graph;
+            Some(
+                self.st
+                    .0
+                    .mapping
+                    .iter()
+                    .map(|&ix| g1.from_index(ix))
+                    .collect(),
+            )
         }
-        None
     }
 }
 
@@ -656,12 +753,7 @@ where
         + GraphProp<EdgeType = G0::EdgeType>
         + IntoNeighborsDirected,
 {
-    if g0.node_count() != g1.node_count() || g0.edge_count() != g1.edge_count() {
-        return false;
-    }
-
-    let mut st = (Vf2State::new(&g0), Vf2State::new(&g1));
-    self::matching::try_match(&mut st, &mut NoSemanticMatch, &mut NoSemanticMatch).unwrap_or(false)
+    Vf2Matches::new(g0, g1, NoSemanticMatch, NoSemanticMatch, Mode::Isomorphism).advance()
 }
 
 /// \[Generic\] Return `true` if the graphs `g0` and `g1` are isomorphic.
@@ -673,8 +765,8 @@ where
 pub fn is_isomorphic_matching<G0, G1, NM, EM>(
     g0: G0,
     g1: G1,
-    mut node_match: NM,
-    mut edge_match: EM,
+    node_match: NM,
+    edge_match: EM,
 ) -> bool
 where
     G0: NodeCompactIndexable
@@ -692,12 +784,117 @@ where
     NM: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool,
     EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
 {
-    if g0.node_count() != g1.node_count() || g0.edge_count() != g1.edge_count() {
-        return false;
-    }
+    Vf2Matches::new(g0, g1, node_match, edge_match, Mode::Isomorphism).advance()
+}
+
+/// \[Generic\] Return `true` if the graphs `g0` and `g1` are the same up to
+/// the numbering of their nodes: isomorphic with equal node and edge weights.
+///
+/// This is [`is_isomorphic_matching`](fn.is_isomorphic_matching.html) with
+/// `==` to match the weights. To compare graphs with the same numbering,
+/// use [`graph_eq`](fn.graph_eq.html).
+///
+/// The graphs should not be multigraphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::is_same_up_to_iso;
+/// use petgraph::prelude::*;
+///
+/// let a = DiGraph::<u32, char>::from_edges(&[(0, 1, 'x'), (1, 2, 'y')]);
+/// let b = DiGraph::<u32, char>::from_edges(&[(1, 0, 'y'), (2, 1, 'x')]);
+/// assert!(is_same_up_to_iso(&a, &b));
+/// let c = DiGraph::<u32, char>::from_edges(&[(1, 0, 'x'), (2, 1, 'y')]);
+/// assert!(!is_same_up_to_iso(&a, &c));
+/// ```
+pub fn is_same_up_to_iso<G0, G1>(g0: G0, g1: G1) -> bool
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected,
+    G0::NodeWeight: PartialEq<G1::NodeWeight>,
+    G0::EdgeWeight: PartialEq<G1::EdgeWeight>,
+{
+    is_isomorphic_matching(g0, g1, |x, y| x == y, |x, y| x == y)
+}
 
-    let mut st = (Vf2State::new(&g0), Vf2State::new(&g1));
-    self::matching::try_match(&mut st, &mut node_match, &mut edge_match).unwrap_or(false)
+/// \[Generic\] Return an isomorphism from `g0` to `g1`, if the graphs are
+/// isomorphic.
+///
+/// Using the VF2 algorithm, only matching graph syntactically (graph
+/// structure).
+///
+/// The mapping is returned as a vector where the node of `g0` with index `i`
+/// maps to element `i`, so that its nodes and edges can be aligned with those
+/// of `g1`.
+///
+/// The graphs should not be multigraphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::find_isomorphism;
+/// use petgraph::prelude::*;
+///
+/// let g0 = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let g1 = DiGraph::<(), ()>::from_edges(&[(2, 0), (1, 2)]);
+/// let mapping = find_isomorphism(&g0, &g1).unwrap();
+/// assert_eq!(mapping, vec![NodeIndex::new(1), NodeIndex::new(2), NodeIndex::new(0)]);
+///
+/// let g2 = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2)]);
+/// assert_eq!(find_isomorphism(&g0, &g2), None);
+/// ```
+pub fn find_isomorphism<G0, G1>(g0: G0, g1: G1) -> Option<Vec<G1::NodeId>>
+where
+    G0: NodeCompactIndexable + EdgeCount + GetAdjacencyMatrix + GraphProp + IntoNeighborsDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoNeighborsDirected,
+{
+    Vf2Matches::new(g0, g1, NoSemanticMatch, NoSemanticMatch, Mode::Isomorphism).next()
+}
+
+/// \[Generic\] Return an isomorphism from `g0` to `g1` that matches node and
+/// edge weights, if there is one.
+///
+/// Using the VF2 algorithm, examining both syntactic and semantic
+/// graph isomorphism (graph structure and matching node and edge weights).
+/// The mapping is returned as in [`find_isomorphism`](fn.find_isomorphism.html).
+///
+/// The graphs should not be multigraphs.
+pub fn find_isomorphism_matching<G0, G1, NM, EM>(
+    g0: G0,
+    g1: G1,
+    node_match: NM,
+    edge_match: EM,
+) -> Option<Vec<G1::NodeId>>
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected,
+    NM: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    Vf2Matches::new(g0, g1, node_match, edge_match, Mode::Isomorphism).next()
 }
 
 /// \[Generic\] Return `true` if `g0` is isomorphic to a subgraph of `g1`.
@@ -742,12 +939,7 @@ where
         + GraphProp<EdgeType = G0::EdgeType>
         + IntoNeighborsDirected,
 {
-    if g0.node_count() > g1.node_count() || g0.edge_count() > g1.edge_count() {
-        return false;
-    }
-
-    let mut st = (Vf2State::new(&g0), Vf2State::new(&g1));
-    self::matching::try_match(&mut st, &mut NoSemanticMatch, &mut NoSemanticMatch).unwrap_or(false)
+    Vf2Matches::new(g0, g1, NoSemanticMatch, NoSemanticMatch, Mode::Subgraph).advance()
 }
 
 /// \[Generic\] Return `true` if `g0` is isomorphic to a subgraph of `g1`.
@@ -759,8 +951,161 @@ where
 pub fn is_isomorphic_subgraph_matching<G0, G1, NM, EM>(
     g0: G0,
     g1: G1,
-    mut node_match: NM,
-    mut edge_match: EM,
+    node_match: NM,
+    edge_match: EM,
+) -> bool
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected,
+    NM: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    Vf2Matches::new(g0, g1, node_match, edge_match, Mode::Subgraph).advance()
+}
+
+/// \[Generic\] Return an iterator over all embeddings of `pattern` as a
+/// node-induced subgraph of `target`.
+///
+/// Using the VF2 algorithm, only matching graph syntactically (graph
+/// structure). See [`is_isomorphic_subgraph`](fn.is_isomorphic_subgraph.html)
+/// for the meaning of subgraph isomorphism.
+///
+/// Each embedding is a mapping of the nodes of `pattern` to distinct nodes
+/// of `target`: the node of `pattern` with index `i` maps to element `i` of
+/// the vector. Every embedding is produced exactly once, so an embedding and
+/// its composition with an automorphism of `pattern` are both produced.
+///
+/// The graphs should not be multigraphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::subgraph_isomorphisms_iter;
+/// use petgraph::prelude::*;
+///
+/// let pattern = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// // A square with one diagonal.
+/// let target = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+///
+/// // The paths of length two without a chord are 1 - 0 - 3 and 1 - 2 - 3,
+/// // each found in both directions.
+/// let embeddings = subgraph_isomorphisms_iter(&pattern, &target).collect::<Vec<_>>();
+/// assert_eq!(embeddings.len(), 4);
+/// for mapping in &embeddings {
+///     assert!(mapping[0] == NodeIndex::new(1) || mapping[0] == NodeIndex::new(3));
+/// }
+/// ```
+pub fn subgraph_isomorphisms_iter<G0, G1>(
+    pattern: G0,
+    target: G1,
+) -> impl Iterator<Item = Vec<G1::NodeId>>
+where
+    G0: NodeCompactIndexable + EdgeCount + GetAdjacencyMatrix + GraphProp + IntoNeighborsDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoNeighborsDirected,
+{
+    Vf2Matches::new(
+        pattern,
+        target,
+        NoSemanticMatch,
+        NoSemanticMatch,
+        Mode::Subgraph,
+    )
+}
+
+/// \[Generic\] Return an iterator over all embeddings of `pattern` as a
+/// node-induced subgraph of `target`.
+///
+/// Using the VF2 algorithm, examining both syntactic and semantic
+/// graph isomorphism (graph structure and matching node and edge weights).
+/// The embeddings are produced as in
+/// [`subgraph_isomorphisms_iter`](fn.subgraph_isomorphisms_iter.html).
+///
+/// The graphs should not be multigraphs.
+pub fn subgraph_isomorphisms_iter_matching<G0, G1, NM, EM>(
+    pattern: G0,
+    target: G1,
+    node_match: NM,
+    edge_match: EM,
+) -> impl Iterator<Item = Vec<G1::NodeId>>
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected,
+    NM: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    Vf2Matches::new(pattern, target, node_match, edge_match, Mode::Subgraph)
+}
+
+/// \[Generic\] Return `true` if `g0` is monomorphic to a subgraph of `g1`.
+///
+/// A *monomorphism* maps the nodes of `g0` to distinct nodes of `g1`, such
+/// that every edge of `g0` maps to an edge of `g1`. Unlike with
+/// [`is_isomorphic_subgraph`](fn.is_isomorphic_subgraph.html), `g1` may have
+/// additional edges between the mapped nodes, so the subgraph need not be
+/// node-induced.
+///
+/// Using the VF2 algorithm, only matching graph syntactically (graph
+/// structure).
+///
+/// The graphs should not be multigraphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{is_isomorphic_subgraph, is_monomorphic_subgraph};
+/// use petgraph::prelude::*;
+///
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// assert!(!is_isomorphic_subgraph(&path, &triangle));
+/// assert!(is_monomorphic_subgraph(&path, &triangle));
+/// ```
+pub fn is_monomorphic_subgraph<G0, G1>(g0: G0, g1: G1) -> bool
+where
+    G0: NodeCompactIndexable + EdgeCount + GetAdjacencyMatrix + GraphProp + IntoNeighborsDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoNeighborsDirected,
+{
+    Vf2Matches::new(g0, g1, NoSemanticMatch, NoSemanticMatch, Mode::Monomorphism).advance()
+}
+
+/// \[Generic\] Return `true` if `g0` is monomorphic to a subgraph of `g1`.
+///
+/// Using the VF2 algorithm, examining both syntactic and semantic
+/// graph monomorphism (graph structure and matching node and edge weights).
+/// Only the edges of `g0` are compared to the edges they map to.
+///
+/// The graphs should not be multigraphs.
+pub fn is_monomorphic_subgraph_matching<G0, G1, NM, EM>(
+    g0: G0,
+    g1: G1,
+    node_match: NM,
+    edge_match: EM,
 ) -> bool
 where
     G0: NodeCompactIndexable
@@ -778,10 +1123,239 @@ where
     NM: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool,
     EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
 {
-    if g0.node_count() > g1.node_count() || g0.edge_count() > g1.edge_count() {
-        return false;
+    Vf2Matches::new(g0, g1, node_match, edge_match, Mode::Monomorphism).advance()
+}
+
+/// \[Generic\] Return an iterator over all monomorphisms from `pattern` to
+/// subgraphs of `target`.
+///
+/// Using the VF2 algorithm, only matching graph syntactically (graph
+/// structure). See [`is_monomorphic_subgraph`](fn.is_monomorphic_subgraph.html)
+/// for the meaning of subgraph monomorphism.
+///
+/// The mappings are produced as in
+/// [`subgraph_isomorphisms_iter`](fn.subgraph_isomorphisms_iter.html).
+///
+/// The graphs should not be multigraphs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::subgraph_monomorphisms_iter;
+/// use petgraph::prelude::*;
+///
+/// // A path of length two can start at any node of a triangle, and go in
+/// // either direction.
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// assert_eq!(subgraph_monomorphisms_iter(&path, &triangle).count(), 6);
+/// ```
+pub fn subgraph_monomorphisms_iter<G0, G1>(
+    pattern: G0,
+    target: G1,
+) -> impl Iterator<Item = Vec<G1::NodeId>>
+where
+    G0: NodeCompactIndexable + EdgeCount + GetAdjacencyMatrix + GraphProp + IntoNeighborsDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoNeighborsDirected,
+{
+    Vf2Matches::new(
+        pattern,
+        target,
+        NoSemanticMatch,
+        NoSemanticMatch,
+        Mode::Monomorphism,
+    )
+}
+
+/// \[Generic\] Return an iterator over all monomorphisms from `pattern` to
+/// subgraphs of `target`.
+///
+/// Using the VF2 algorithm, examining both syntactic and semantic
+/// graph monomorphism (graph structure and matching node and edge weights).
+/// Only the edges of `pattern` are compared to the edges they map to.
+///
+/// The graphs should not be multigraphs.
+pub fn subgraph_monomorphisms_iter_matching<G0, G1, NM, EM>(
+    pattern: G0,
+    target: G1,
+    node_match: NM,
+    edge_match: EM,
+) -> impl Iterator<Item = Vec<G1::NodeId>>
+where
+    G0: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp
+        + IntoEdgesDirected,
+    G1: NodeCompactIndexable
+        + EdgeCount
+        + DataMap
+        + GetAdjacencyMatrix
+        + GraphProp<EdgeType = G0::EdgeType>
+        + IntoEdgesDirected,
+    NM: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> bool,
+    EM: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> bool,
+{
+    Vf2Matches::new(pattern, target, node_match, edge_match, Mode::Monomorphism)
+}
+
+/// Match nodes of a graph with themselves by their degrees, and with some nodes
+/// pinned to an image.
+struct PinnedMatch<'a> {
+    degree: &'a [(usize, usize)],
+    /// The required image of each node, or `usize::MAX` for any node.
+    pinned: &'a [usize],
+}
+
+impl<'a, G> NodeMatcher<G, G> for PinnedMatch<'a>
+where
+    G: NodeIndexable,
+{
+    #[inline]
+    fn enabled() -> bool {
+        true
     }
+    #[inline]
+    fn eq(&mut self, g0: &G, g1: &G, n0: G::NodeId, n1: G::NodeId) -> bool {
+        let (i, j) = (g0.to_index(n0), g1.to_index(n1));
+        self.degree[i] == self.degree[j]
+            && (self.pinned[i] == std::usize::MAX || self.pinned[i] == j)
+    }
+}
 
-    let mut st = (Vf2State::new(&g0), Vf2State::new(&g1));
-    self::matching::try_match(&mut st, &mut node_match, &mut edge_match).unwrap_or(false)
+/// \[Generic\] Compute a set of generators of the automorphism group of the
+/// graph.
+///
+/// An *automorphism* is an isomorphism of the graph to itself, that is, a
+/// permutation of the nodes that maps edges to edges. Each generator is
+/// returned as a mapping like in [`find_isomorphism`](fn.find_isomorphism.html):
+/// the node with index `i` maps to element `i` of the vector. The identity is
+/// never included, so the list is empty if the graph has no symmetries.
+///
+/// Every automorphism is a product of the generators. They form a *strong
+/// generating set*: with the nodes in index order *v₀, v₁, …*, the generators
+/// that fix *v₀, …, vᵢ₋₁* generate all automorphisms that fix those nodes.
+///
+/// The generators are found with one VF2 search for each candidate image of
+/// each node, where candidates already known to be in the same orbit are
+/// skipped. Node and edge weights are ignored.
+///
+/// The graph should not be a multigraph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::automorphism_generators;
+/// use petgraph::prelude::*;
+///
+/// // The automorphisms of a square are generated by a reflection and a rotation.
+/// let square = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let generators = automorphism_generators(&square);
+/// assert_eq!(generators.len(), 2);
+/// for p in &generators {
+///     for edge in square.edge_references() {
+///         let (a, b) = (p[edge.source().index()], p[edge.target().index()]);
+///         assert!(square.contains_edge(a, b));
+///     }
+/// }
+/// ```
+pub fn automorphism_generators<G>(g: G) -> Vec<Vec<G::NodeId>>
+where
+    G: NodeCompactIndexable + EdgeCount + GetAdjacencyMatrix + GraphProp + IntoNeighborsDirected,
+{
+    automorphisms(g).0
+}
+
+/// \[Generic\] Compute the orbits of the nodes of the graph under its
+/// automorphism group.
+///
+/// Two nodes are in the same orbit if an automorphism maps one to the other,
+/// so that they are indistinguishable by the structure of the graph. Each orbit
+/// is sorted by node index, and the orbits are sorted by their first node.
+///
+/// The orbits are computed from the
+/// [`automorphism_generators`](fn.automorphism_generators.html). Node and edge
+/// weights are ignored.
+///
+/// The graph should not be a multigraph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::node_orbits;
+/// use petgraph::prelude::*;
+///
+/// // A path; its ends are symmetric, and so are its inner nodes.
+/// let path = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let orbits = node_orbits(&path)
+///     .into_iter()
+///     .map(|orbit| orbit.into_iter().map(|n| n.index()).collect::<Vec<_>>())
+///     .collect::<Vec<_>>();
+/// assert_eq!(orbits, vec![vec![0, 3], vec![1, 2]]);
+/// ```
+pub fn node_orbits<G>(g: G) -> Vec<Vec<G::NodeId>>
+where
+    G: NodeCompactIndexable + EdgeCount + GetAdjacencyMatrix + GraphProp + IntoNeighborsDirected,
+{
+    let (_, orbits) = automorphisms(g);
+    let label = orbits.into_labeling();
+    let mut position = vec![std::usize::MAX; label.len()];
+    let mut result: Vec<Vec<G::NodeId>> = Vec::new();
+    for (i, &root) in label.iter().enumerate() {
+        if position[root] == std::usize::MAX {
+            position[root] = result.len();
+            result.push(Vec::new());
+        }
+        result[position[root]].push(g.from_index(i));
+    }
+    result
+}
+
+/// Compute a strong generating set of the automorphism group, and its orbits.
+fn automorphisms<G>(g: G) -> (Vec<Vec<G::NodeId>>, UnionFind<usize>)
+where
+    G: NodeCompactIndexable + EdgeCount + GetAdjacencyMatrix + GraphProp + IntoNeighborsDirected,
+{
+    let n = g.node_count();
+    let degree = (0..n)
+        .map(|i| {
+            let a = g.from_index(i);
+            (
+                g.neighbors_directed(a, Outgoing).count(),
+                g.neighbors_directed(a, Incoming).count(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut generators = Vec::new();
+    let mut orbits = UnionFind::new(n);
+    let mut pinned = vec![std::usize::MAX; n];
+    // Walk down the stabilizer chain of the nodes in index order, from the
+    // automorphisms that fix all nodes but the last, to the whole group. The
+    // generators found so far all fix the nodes before `i`, so their orbits
+    // are the known part of the orbit of `i`.
+    for i in (0..n).rev() {
+        for (k, pin) in pinned.iter_mut().enumerate() {
+            *pin = if k < i { k } else { std::usize::MAX };
+        }
+        for j in i + 1..n {
+            if degree[i] != degree[j] || orbits.equiv(i, j) {
+                continue;
+            }
+            pinned[i] = j;
+            let node_match = PinnedMatch {
+                degree: &degree,
+                pinned: &pinned,
+            };
+            let mut search = Vf2Matches::new(g, g, node_match, NoSemanticMatch, Mode::Isomorphism);
+            if let Some(mapping) = search.next() {
+                for (k, &image) in mapping.iter().enumerate() {
+                    orbits.union(k, g.to_index(image));
+                }
+                generators.push(mapping);
+            }
+        }
+    }
+    (generators, orbits)
 }
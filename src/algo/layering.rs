@@ -0,0 +1,139 @@
+//! Assign the nodes of a directed acyclic graph to layers.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::{remaining_cycle, Error};
+use crate::visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use crate::{Incoming, Outgoing};
+
+/// \[Generic\] Compute the Coffman–Graham layering of a directed acyclic
+/// graph, with at most `width` nodes in each layer.
+///
+/// Return the layers in order: every edge goes from a node in an earlier
+/// layer to a node in a later layer, like the generations of
+/// [`topological_generations`](../fn.topological_generations.html), but no
+/// layer has more than `width` nodes. This schedules unit-time tasks on
+/// `width` processors, or assigns the nodes to the rows of a layered drawing.
+///
+/// The nodes are first numbered in a topological order where, among the nodes
+/// that could come next, the one whose predecessors have the smallest numbers
+/// (comparing the predecessor numbers in decreasing order, lexicographically)
+/// comes first. Then, in reverse of that order, each node is placed in the
+/// last layer that is before all layers of its successors and is not already
+/// full. Each layer lists its nodes in the numbering order.
+///
+/// When the graph is transitively reduced, see
+/// [`tred`](../tred/index.html), the number of layers is at most
+/// `2 - 2 / width` times the minimum, and is the minimum for `width` 2. With
+/// `width` at least the number of nodes, the number of layers is the number
+/// of nodes on a longest path.
+///
+/// If the graph has a cycle, return an `Error::Cycle` error with a node on a
+/// cycle.
+/// Self loops are also cycles.
+///
+/// Computes with **O((|V| + |E|) log |V|)** priority queue operations.
+///
+/// **Panics** if `width` is zero.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::coffman_graham;
+/// use petgraph::prelude::*;
+///
+/// // Three independent chains of two tasks, on two processors.
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 3), (4, 5)]);
+/// let layers = coffman_graham(&g, 2).unwrap();
+/// assert_eq!(layers.len(), 3);
+/// assert!(layers.iter().all(|layer| layer.len() == 2));
+/// ```
+pub fn coffman_graham<G>(g: G, width: usize) -> Result<Vec<Vec<G::NodeId>>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    assert!(width > 0, "coffman_graham: width must be positive");
+
+    // Number the nodes, with Kahn's algorithm choosing the node with the
+    // smallest decreasing list of predecessor numbers.
+    let mut in_degree = vec![0; g.node_bound()];
+    let mut node_count = 0;
+    for a in g.node_identifiers() {
+        node_count += 1;
+        for b in g.neighbors_directed(a, Outgoing) {
+            in_degree[g.to_index(b)] += 1;
+        }
+    }
+    let mut number = vec![std::usize::MAX; g.node_bound()];
+    let mut ready = BinaryHeap::new();
+    for a in g.node_identifiers() {
+        if in_degree[g.to_index(a)] == 0 {
+            ready.push(Reverse((Vec::new(), g.to_index(a))));
+        }
+    }
+    let mut order = Vec::with_capacity(node_count);
+    while let Some(Reverse((_, i))) = ready.pop() {
+        number[i] = order.len();
+        order.push(i);
+        for b in g.neighbors_directed(g.from_index(i), Outgoing) {
+            let j = g.to_index(b);
+            in_degree[j] -= 1;
+            if in_degree[j] == 0 {
+                // all predecessors are numbered, so the list is final
+                let mut preds = g
+                    .neighbors_directed(b, Incoming)
+                    .map(|a| number[g.to_index(a)])
+                    .collect::<Vec<_>>();
+                preds.sort_unstable_by(|x, y| y.cmp(x));
+                preds.dedup();
+                ready.push(Reverse((preds, j)));
+            }
+        }
+    }
+    if order.len() < node_count {
+        return Err(remaining_cycle(g, &in_degree));
+    }
+
+    // Place the nodes from the sinks up; `level` counts layers from the last
+    // one, and `open[k]` leads to the first level from `k` that is not full.
+    let mut level = vec![0; g.node_bound()];
+    let mut levels: Vec<Vec<usize>> = Vec::new();
+    let mut open = Vec::new();
+    for &i in order.iter().rev() {
+        let mut k = g
+            .neighbors_directed(g.from_index(i), Outgoing)
+            .map(|b| level[g.to_index(b)] + 1)
+            .max()
+            .unwrap_or(0);
+        while open.len() <= k {
+            open.push(open.len());
+            levels.push(Vec::new());
+        }
+        // find the open level, halving the path
+        while open[k] != k {
+            open[k] = open[open[k]];
+            k = open[k];
+        }
+        level[i] = k;
+        levels[k].push(i);
+        if levels[k].len() == width {
+            if open.len() == k + 1 {
+                open.push(k + 1);
+                levels.push(Vec::new());
+            }
+            open[k] = k + 1;
+        }
+    }
+
+    while levels.last().map_or(false, Vec::is_empty) {
+        levels.pop();
+    }
+    Ok(levels
+        .into_iter()
+        .rev()
+        .map(|mut layer| {
+            layer.reverse();
+            layer.into_iter().map(|i| g.from_index(i)).collect()
+        })
+        .collect())
+}
@@ -0,0 +1,304 @@
+//! Compute the maximal cliques of a graph.
+//!
+//! ## Degeneracy and Bron–Kerbosch
+//! A *clique* is a set of nodes that are all pairwise adjacent, and a clique
+//! is *maximal* if no other node can be added to it. The cliques are enumerated
+//! with the Bron–Kerbosch algorithm using pivoting, where the outermost level
+//! of the recursion visits the nodes in a *degeneracy ordering* as described by
+//! [Eppstein, Löffler and Strash](https://arxiv.org/abs/1006.5440). This keeps
+//! the search close to linear in the size of the graph for sparse graphs.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use super::undirected_adjacency;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// Compute a degeneracy ordering of the node indices in `nodes`.
+///
+/// Repeatedly removes a node of minimum degree in the remaining graph.
+fn degeneracy_order(nodes: &[usize], adj: &[Vec<usize>]) -> Vec<usize> {
+    let mut degree = vec![0; adj.len()];
+    let mut max_degree = 0;
+    for &i in nodes {
+        degree[i] = adj[i].len();
+        max_degree = max_degree.max(degree[i]);
+    }
+    // bucket queue of nodes keyed by their current degree; stale entries are
+    // skipped when popped
+    let mut buckets = vec![Vec::new(); max_degree + 1];
+    for &i in nodes {
+        buckets[degree[i]].push(i);
+    }
+    let mut removed = vec![false; adj.len()];
+    let mut order = Vec::with_capacity(nodes.len());
+    let mut d = 0;
+    while order.len() < nodes.len() {
+        let i = match buckets[d].pop() {
+            Some(i) => i,
+            None => {
+                d += 1;
+                continue;
+            }
+        };
+        if removed[i] || degree[i] != d {
+            continue;
+        }
+        removed[i] = true;
+        order.push(i);
+        for &j in &adj[i] {
+            if !removed[j] {
+                degree[j] -= 1;
+                buckets[degree[j]].push(j);
+            }
+        }
+        d = d.saturating_sub(1);
+    }
+    order
+}
+
+/// \[Generic\] Compute a *degeneracy ordering* of the nodes of the graph.
+///
+/// The ordering is obtained by repeatedly removing a node of minimum degree
+/// from the graph. Every node has at most *d* neighbors later in the ordering,
+/// where *d* is the degeneracy of the graph.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// Computes in **O(|V| + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::degeneracy_ordering;
+/// use petgraph::prelude::*;
+///
+/// // A triangle with a pendant node.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let order = degeneracy_ordering(&g);
+/// assert_eq!(order.len(), 4);
+/// assert_eq!(order[0], NodeIndex::new(3));
+/// ```
+pub fn degeneracy_ordering<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adj) = undirected_adjacency(g);
+    degeneracy_order(&nodes, &adj)
+        .into_iter()
+        .map(|i| g.from_index(i))
+        .collect()
+}
+
+/// \[Generic\] Return an iterator over the maximal cliques of the graph.
+///
+/// The cliques are produced lazily using the degeneracy variant of the
+/// Bron–Kerbosch algorithm, see the [module documentation](index.html).
+/// Each clique is reported exactly once, as a vector of node ids in arbitrary
+/// order. Isolated nodes are reported as cliques of size one.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::maximal_cliques_iter;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let largest = maximal_cliques_iter(&g).map(|c| c.len()).max();
+/// assert_eq!(largest, Some(3));
+/// ```
+pub fn maximal_cliques_iter<G>(g: G) -> MaximalCliques<G>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, adj) = undirected_adjacency(g);
+    let order = degeneracy_order(&nodes, &adj);
+    let mut position = vec![0; adj.len()];
+    for (p, &i) in order.iter().enumerate() {
+        position[i] = p;
+    }
+    MaximalCliques {
+        graph: g,
+        adj,
+        order,
+        position,
+        next_root: 0,
+        clique: Vec::new(),
+        stack: Vec::new(),
+    }
+}
+
+/// \[Generic\] Compute all maximal cliques of the graph.
+///
+/// This collects the cliques from [`maximal_cliques_iter`](fn.maximal_cliques_iter.html),
+/// which should be preferred if the cliques can be processed one at a time.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::maximal_cliques;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let cliques = maximal_cliques(&g);
+/// assert_eq!(cliques.len(), 2);
+/// ```
+pub fn maximal_cliques<G>(g: G) -> Vec<HashSet<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    maximal_cliques_iter(g)
+        .map(|clique| clique.into_iter().collect())
+        .collect()
+}
+
+/// One level of the Bron–Kerbosch recursion.
+#[derive(Clone, Debug)]
+struct Frame {
+    /// Candidates that can extend the current clique.
+    candidates: Vec<usize>,
+    /// Nodes that were already used to extend the current clique.
+    excluded: Vec<usize>,
+    /// Nodes of `candidates` that are not adjacent to the pivot.
+    branches: Vec<usize>,
+    next: usize,
+}
+
+/// An iterator over the maximal cliques of a graph.
+///
+/// Created with [`maximal_cliques_iter`](fn.maximal_cliques_iter.html).
+#[derive(Clone, Debug)]
+pub struct MaximalCliques<G> {
+    graph: G,
+    adj: Vec<Vec<usize>>,
+    order: Vec<usize>,
+    position: Vec<usize>,
+    next_root: usize,
+    clique: Vec<usize>,
+    stack: Vec<Frame>,
+}
+
+/// Intersect two sorted lists.
+fn intersect(a: &[usize], b: &[usize]) -> Vec<usize> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] < b[j] {
+            i += 1;
+        } else if a[i] > b[j] {
+            j += 1;
+        } else {
+            result.push(a[i]);
+            i += 1;
+            j += 1;
+        }
+    }
+    result
+}
+
+fn intersection_len(a: &[usize], b: &[usize]) -> usize {
+    let (mut i, mut j, mut len) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] < b[j] {
+            i += 1;
+        } else if a[i] > b[j] {
+            j += 1;
+        } else {
+            len += 1;
+            i += 1;
+            j += 1;
+        }
+    }
+    len
+}
+
+impl<G> MaximalCliques<G> {
+    fn frame(&self, candidates: Vec<usize>, excluded: Vec<usize>) -> Frame {
+        // Tomita pivot: the node of candidates ∪ excluded with the most
+        // neighbors among the candidates.
+        let pivot = candidates
+            .iter()
+            .chain(&excluded)
+            .max_by_key(|&&u| intersection_len(&candidates, &self.adj[u]))
+            .cloned();
+        let branches = match pivot {
+            Some(u) => candidates
+                .iter()
+                .cloned()
+                .filter(|v| self.adj[u].binary_search(v).is_err())
+                .collect(),
+            None => Vec::new(),
+        };
+        Frame {
+            candidates,
+            excluded,
+            branches,
+            next: 0,
+        }
+    }
+}
+
+impl<G> Iterator for MaximalCliques<G>
+where
+    G: NodeIndexable,
+{
+    type Item = Vec<G::NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (candidates, excluded) = if let Some(frame) = self.stack.last_mut() {
+                if frame.next == frame.branches.len() {
+                    self.stack.pop();
+                    self.clique.pop();
+                    continue;
+                }
+                let v = frame.branches[frame.next];
+                frame.next += 1;
+                let candidates = intersect(&frame.candidates, &self.adj[v]);
+                let excluded = intersect(&frame.excluded, &self.adj[v]);
+                // move `v` from the candidates to the excluded nodes
+                if let Ok(i) = frame.candidates.binary_search(&v) {
+                    frame.candidates.remove(i);
+                }
+                if let Err(i) = frame.excluded.binary_search(&v) {
+                    frame.excluded.insert(i, v);
+                }
+                self.clique.push(v);
+                (candidates, excluded)
+            } else {
+                // Start a new subproblem rooted at the next node in the
+                // degeneracy ordering.
+                let v = *self.order.get(self.next_root)?;
+                self.next_root += 1;
+                let position = &self.position;
+                let (later, earlier) = self.adj[v]
+                    .iter()
+                    .cloned()
+                    .partition::<Vec<usize>, _>(|&u| position[u] > position[v]);
+                self.clique.push(v);
+                (later, earlier)
+            };
+
+            if candidates.is_empty() {
+                let clique = if excluded.is_empty() {
+                    let g = &self.graph;
+                    Some(self.clique.iter().map(|&i| g.from_index(i)).collect())
+                } else {
+                    None
+                };
+                self.clique.pop();
+                if clique.is_some() {
+                    return clique;
+                }
+            } else {
+                let frame = self.frame(candidates, excluded);
+                self.stack.push(frame);
+            }
+        }
+    }
+}
@@ -6,17 +6,44 @@
 
 pub mod astar;
 pub mod bellman_ford;
+pub mod bidirectional_bfs;
+pub mod bipartite;
+pub mod centrality;
+pub mod closure;
+pub mod coloring;
+#[cfg(feature = "rayon")]
+pub mod delta_stepping;
+pub mod diff;
 pub mod dijkstra;
+pub mod dilworth;
+pub mod dominating_set;
 pub mod dominators;
 pub mod feedback_arc_set;
+pub mod flow;
 pub mod floyd_warshall;
+pub mod graph_hash;
+pub mod independent_set;
 pub mod isomorphism;
 pub mod k_shortest_path;
+pub mod layering;
 pub mod matching;
+pub mod maximal_cliques;
+pub mod ordering;
+#[cfg(feature = "rayon")]
+pub mod parallel_bfs;
+#[cfg(feature = "rayon")]
+pub mod parallel_centrality;
+pub mod scc_iter;
+mod score_map;
 pub mod simple_paths;
+pub mod spanner;
+pub mod tournament;
 pub mod tred;
+pub mod walks;
 
+use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
 use std::num::NonZeroUsize;
 
 use crate::prelude::*;
@@ -25,7 +52,7 @@ use super::graph::IndexType;
 use super::unionfind::UnionFind;
 use super::visit::{
     GraphBase, GraphRef, IntoEdgeReferences, IntoNeighbors, IntoNeighborsDirected,
-    IntoNodeIdentifiers, NodeCompactIndexable, NodeIndexable, Reversed, VisitMap, Visitable,
+    IntoNodeIdentifiers, NodeIndexable, Reversed, VisitMap, Visitable,
 };
 use super::EdgeType;
 use crate::data::Element;
@@ -33,21 +60,60 @@ use crate::scored::MinScored;
 use crate::visit::Walker;
 use crate::visit::{Data, IntoNodeReferences, NodeRef};
 
-pub use astar::astar;
+pub use astar::{astar, astar_indexed};
+#[cfg(feature = "rayon")]
+pub use bellman_ford::par_bellman_ford;
 pub use bellman_ford::{bellman_ford, find_negative_cycle};
-pub use dijkstra::dijkstra;
+pub use bidirectional_bfs::bidirectional_bfs;
+#[allow(deprecated)]
+pub use bipartite::{bipartite_partition, OddCycle};
+pub use centrality::{
+    betweenness_centrality, closeness_centrality, page_rank, weighted_betweenness_centrality,
+};
+pub use closure::max_weight_closure;
+pub use coloring::{greedy_color, greedy_color_with, ColoringStrategy};
+#[cfg(feature = "rayon")]
+pub use delta_stepping::delta_stepping;
+pub use diff::{graph_diff, graph_eq, GraphDiff};
+pub use dijkstra::{dijkstra, dijkstra_indexed};
+pub use dilworth::{max_antichain, min_chain_cover};
+pub use dominating_set::{greedy_dominating_set, greedy_weighted_dominating_set};
 pub use feedback_arc_set::greedy_feedback_arc_set;
+pub use flow::{maximum_flow, minimum_cut};
 pub use floyd_warshall::floyd_warshall;
+pub use graph_hash::{graph_hash, graph_hash_with};
+#[cfg(feature = "rand")]
+pub use independent_set::luby_maximal_independent_set;
+pub use independent_set::maximal_independent_set;
 pub use isomorphism::{
-    is_isomorphic, is_isomorphic_matching, is_isomorphic_subgraph, is_isomorphic_subgraph_matching,
+    automorphism_generators, find_isomorphism, find_isomorphism_matching, is_isomorphic,
+    is_isomorphic_matching, is_isomorphic_subgraph, is_isomorphic_subgraph_matching,
+    is_monomorphic_subgraph, is_monomorphic_subgraph_matching, is_same_up_to_iso, node_orbits,
+    subgraph_isomorphisms_iter, subgraph_isomorphisms_iter_matching, subgraph_monomorphisms_iter,
+    subgraph_monomorphisms_iter_matching,
 };
 pub use k_shortest_path::k_shortest_path;
+pub use layering::coffman_graham;
 pub use matching::{greedy_matching, maximum_matching, Matching};
+pub use maximal_cliques::{degeneracy_ordering, maximal_cliques, maximal_cliques_iter};
+pub use ordering::{lex_bfs, reverse_cuthill_mckee};
+#[cfg(feature = "rayon")]
+pub use parallel_bfs::{parallel_bfs, BfsTree};
+#[cfg(feature = "rayon")]
+pub use parallel_centrality::{par_hits, par_katz_centrality, par_page_rank};
+pub use scc_iter::{tarjan_scc_iter, TarjanSccIter};
 pub use simple_paths::all_simple_paths;
+pub use spanner::greedy_spanner;
+pub use tournament::hamiltonian_path_in_tournament;
+pub use walks::{count_walks, count_walks_with, Semiring};
 
 /// \[Generic\] Return the number of connected components of the graph.
 ///
 /// For a directed graph, this is the *weakly* connected components.
+///
+/// Only the nodes given by `g.node_identifiers()` are counted, so the graph
+/// may have holes in its node indices, like a `StableGraph` or a filtered
+/// view.
 /// # Example
 /// ```rust
 /// use petgraph::Graph;
@@ -85,7 +151,7 @@ pub use simple_paths::all_simple_paths;
 /// ```
 pub fn connected_components<G>(g: G) -> usize
 where
-    G: NodeCompactIndexable + IntoEdgeReferences,
+    G: NodeIndexable + IntoNodeIdentifiers + IntoEdgeReferences,
 {
     let mut vertex_sets = UnionFind::new(g.node_bound());
     for edge in g.edge_references() {
@@ -94,10 +160,13 @@ where
         // union the two vertices of the edge
         vertex_sets.union(g.to_index(a), g.to_index(b));
     }
-    let mut labels = vertex_sets.into_labeling();
-    labels.sort_unstable();
-    labels.dedup();
-    labels.len()
+    // count the representatives, skipping the indices of missing nodes
+    g.node_identifiers()
+        .filter(|&a| {
+            let i = g.to_index(a);
+            vertex_sets.find_mut(i) == i
+        })
+        .count()
 }
 
 /// \[Generic\] Return `true` if the input graph contains a cycle.
@@ -124,7 +193,8 @@ where
 ///
 /// If the graph was acyclic, return a vector of nodes in topological order:
 /// each node is ordered before its successors.
-/// Otherwise, it will return a `Cycle` error. Self loops are also cycles.
+/// Otherwise, it will return an `Error::Cycle` error. Self loops are also
+/// cycles.
 ///
 /// To handle graphs with cycles, use the scc algorithms or `DfsPostOrder`
 /// instead of this function.
@@ -134,7 +204,7 @@ where
 pub fn toposort<G>(
     g: G,
     space: Option<&mut DfsSpace<G::NodeId, G::Map>>,
-) -> Result<Vec<G::NodeId>, Cycle<G::NodeId>>
+) -> Result<Vec<G::NodeId>, Error<G::NodeId>>
 where
     G: IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
 {
@@ -155,7 +225,7 @@ where
                     for succ in g.neighbors(nx) {
                         if succ == nx {
                             // self cycle
-                            return Err(Cycle(nx));
+                            return Err(Error::Cycle(nx));
                         }
                         if !dfs.discovered.is_visited(&succ) {
                             dfs.stack.push(succ);
@@ -178,7 +248,7 @@ where
             let mut cycle = false;
             while let Some(j) = dfs.next(Reversed(g)) {
                 if cycle {
-                    return Err(Cycle(j));
+                    return Err(Error::Cycle(j));
                 }
                 cycle = true;
             }
@@ -188,6 +258,181 @@ where
     })
 }
 
+/// \[Generic\] Perform a topological sort of a directed graph, choosing the
+/// node with the smallest key whenever several nodes could come next.
+///
+/// If the graph was acyclic, return a vector of nodes in topological order:
+/// each node is ordered before its successors. Among the nodes whose
+/// predecessors are all ordered, the next node is the one with the smallest
+/// `key`, and ties are broken by node index, so the order does not depend on
+/// the order in which the graph stores its nodes and edges. This is Kahn's
+/// algorithm with a priority queue.
+///
+/// Otherwise, it will return an `Error::Cycle` error with a node on a cycle.
+/// Self loops are also cycles.
+///
+/// Computes in **O(|V| log |V| + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::toposort_by_key;
+/// use petgraph::prelude::*;
+///
+/// let mut g = DiGraph::<&str, ()>::new();
+/// let fetch = g.add_node("fetch");
+/// let unpack = g.add_node("unpack");
+/// let configure = g.add_node("configure");
+/// let build = g.add_node("build");
+/// g.extend_with_edges(&[(fetch, unpack), (unpack, build), (configure, build)]);
+///
+/// // By node index: `configure` can only come after `unpack`.
+/// let order = toposort_by_key(&g, |_| ()).unwrap();
+/// assert_eq!(order, vec![fetch, unpack, configure, build]);
+///
+/// // Alphabetically by name.
+/// let order = toposort_by_key(&g, |n| g[n]).unwrap();
+/// assert_eq!(order, vec![configure, fetch, unpack, build]);
+/// ```
+pub fn toposort_by_key<G, F, K>(g: G, mut key: F) -> Result<Vec<G::NodeId>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::NodeId) -> K,
+    K: Ord,
+{
+    let mut in_degree = vec![0; g.node_bound()];
+    let mut node_count = 0;
+    for a in g.node_identifiers() {
+        node_count += 1;
+        for b in g.neighbors_directed(a, Outgoing) {
+            in_degree[g.to_index(b)] += 1;
+        }
+    }
+    let mut ready = BinaryHeap::new();
+    for a in g.node_identifiers() {
+        if in_degree[g.to_index(a)] == 0 {
+            ready.push(Reverse((key(a), g.to_index(a))));
+        }
+    }
+
+    let mut order = Vec::with_capacity(node_count);
+    while let Some(Reverse((_, i))) = ready.pop() {
+        let a = g.from_index(i);
+        order.push(a);
+        for b in g.neighbors_directed(a, Outgoing) {
+            let j = g.to_index(b);
+            in_degree[j] -= 1;
+            if in_degree[j] == 0 {
+                ready.push(Reverse((key(b), j)));
+            }
+        }
+    }
+
+    if order.len() < node_count {
+        return Err(remaining_cycle(g, &in_degree));
+    }
+    Ok(order)
+}
+
+/// \[Generic\] Partition the nodes of a directed acyclic graph into
+/// *topological generations*.
+///
+/// The first generation is the nodes without predecessors, and each following
+/// generation is the nodes whose predecessors are all in earlier generations.
+/// Within a generation no node is reachable from another, so the nodes of each
+/// generation can be processed in parallel once the earlier generations are
+/// done, and the number of generations is the length of the longest path in
+/// nodes. Each generation lists its nodes by node index.
+///
+/// If the graph has a cycle, return an `Error::Cycle` error with a node on a
+/// cycle. Self loops are also cycles.
+///
+/// Computes in **O(|V| log |V| + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::topological_generations;
+/// use petgraph::prelude::*;
+///
+/// let mut g = DiGraph::<&str, ()>::new();
+/// let fetch = g.add_node("fetch");
+/// let unpack = g.add_node("unpack");
+/// let configure = g.add_node("configure");
+/// let build = g.add_node("build");
+/// g.extend_with_edges(&[(fetch, unpack), (unpack, build), (configure, build)]);
+///
+/// let generations = topological_generations(&g).unwrap();
+/// assert_eq!(generations, vec![vec![fetch, configure], vec![unpack], vec![build]]);
+///
+/// g.add_edge(build, fetch, ());
+/// assert!(topological_generations(&g).is_err());
+/// ```
+pub fn topological_generations<G>(g: G) -> Result<Vec<Vec<G::NodeId>>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut in_degree = vec![0; g.node_bound()];
+    let mut node_count = 0;
+    for a in g.node_identifiers() {
+        node_count += 1;
+        for b in g.neighbors_directed(a, Outgoing) {
+            in_degree[g.to_index(b)] += 1;
+        }
+    }
+    let mut generation = g
+        .node_identifiers()
+        .map(|a| g.to_index(a))
+        .filter(|&i| in_degree[i] == 0)
+        .collect::<Vec<_>>();
+
+    let mut generations = Vec::new();
+    let mut ordered = 0;
+    while !generation.is_empty() {
+        generation.sort_unstable();
+        let mut next = Vec::new();
+        for &i in &generation {
+            for b in g.neighbors_directed(g.from_index(i), Outgoing) {
+                let j = g.to_index(b);
+                in_degree[j] -= 1;
+                if in_degree[j] == 0 {
+                    next.push(j);
+                }
+            }
+        }
+        ordered += generation.len();
+        generations.push(generation.into_iter().map(|i| g.from_index(i)).collect());
+        generation = next;
+    }
+
+    if ordered < node_count {
+        return Err(remaining_cycle(g, &in_degree));
+    }
+    Ok(generations)
+}
+
+/// Return a node on a cycle among the nodes with a positive `in_degree` left
+/// after Kahn's algorithm.
+///
+/// Every such node has a predecessor with a positive `in_degree`, so walking
+/// backwards from one of them must repeat a node, which is on a cycle.
+fn remaining_cycle<G>(g: G, in_degree: &[usize]) -> Error<G::NodeId>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut seen = vec![false; g.node_bound()];
+    let mut a = g
+        .node_identifiers()
+        .find(|&a| in_degree[g.to_index(a)] > 0)
+        .unwrap();
+    while !seen[g.to_index(a)] {
+        seen[g.to_index(a)] = true;
+        a = g
+            .neighbors_directed(a, Incoming)
+            .find(|&b| in_degree[g.to_index(b)] > 0)
+            .unwrap();
+    }
+    Error::Cycle(a)
+}
+
 /// \[Generic\] Return `true` if the input directed graph contains a cycle.
 ///
 /// This implementation is recursive; use `toposort` if an alternative is
@@ -256,6 +501,32 @@ where
     f(dfs)
 }
 
+/// Build the symmetric adjacency of `g` over node indices, with each list
+/// sorted, free of duplicates and free of self loops.
+pub(crate) fn undirected_adjacency<G>(g: G) -> (Vec<usize>, Vec<Vec<usize>>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut adj = vec![Vec::new(); g.node_bound()];
+    let mut nodes = Vec::new();
+    for a in g.node_identifiers() {
+        let i = g.to_index(a);
+        nodes.push(i);
+        for b in g.neighbors(a) {
+            let j = g.to_index(b);
+            if i != j {
+                adj[i].push(j);
+                adj[j].push(i);
+            }
+        }
+    }
+    for list in &mut adj {
+        list.sort_unstable();
+        list.dedup();
+    }
+    (nodes, adj)
+}
+
 /// \[Generic\] Check if there exists a path starting at `from` and reaching `to`.
 ///
 /// If `from` and `to` are equal, this function returns true.
@@ -503,6 +774,9 @@ impl<N> TarjanScc<N> {
 /// This implementation is recursive and does one pass over the nodes. It is based on
 /// [A Space-Efficient Algorithm for Finding Strongly Connected Components][2] by David J. Pierce,
 /// to provide a memory-efficient implementation of [Tarjan's algorithm][1].
+///
+/// Use [`tarjan_scc_iter`](fn.tarjan_scc_iter.html) to compute the sccs one
+/// at a time instead.
 pub fn tarjan_scc<G>(g: G) -> Vec<Vec<G::NodeId>>
 where
     G: IntoNodeIdentifiers + IntoNeighbors + NodeIndexable,
@@ -645,7 +919,9 @@ where
 /// The resulting graph has all the vertices of the input graph (with identical node indices),
 /// and **|V| - c** edges, where **c** is the number of connected components in `g`.
 ///
-/// Use `from_elements` to create a graph from the resulting iterator.
+/// Use `from_elements` to create a graph from the resulting iterator. See
+/// [`min_spanning_tree_edges`](fn.min_spanning_tree_edges.html) for the
+/// edges of `g` that make up the tree instead.
 pub fn min_spanning_tree<G>(g: G) -> MinSpanningTree<G>
 where
     G::NodeWeight: Clone,
@@ -740,10 +1016,146 @@ where
     }
 }
 
+/// \[Generic\] Compute the edges of a *minimum spanning tree* of a graph.
+///
+/// Like [`min_spanning_tree`](fn.min_spanning_tree.html), but produces the
+/// edge references of `g` that make up the minimum spanning forest, in order
+/// of increasing weight, instead of the elements of a new graph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::min_spanning_tree_edges;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), f32>::from_edges(&[(0, 1, 2.), (1, 2, 1.), (0, 2, 3.)]);
+/// let tree = min_spanning_tree_edges(&g).map(|e| e.id()).collect::<Vec<_>>();
+/// assert_eq!(tree, vec![EdgeIndex::new(1), EdgeIndex::new(0)]);
+/// ```
+pub fn min_spanning_tree_edges<G>(g: G) -> MinSpanningTreeEdges<G>
+where
+    G::EdgeWeight: Clone + PartialOrd,
+    G: IntoEdgeReferences + NodeIndexable,
+{
+    let subgraphs = UnionFind::new(g.node_bound());
+
+    let edges = g.edge_references();
+    let mut sort_edges = BinaryHeap::with_capacity(edges.size_hint().0);
+    for edge in edges {
+        sort_edges.push(MinScored(edge.weight().clone(), edge));
+    }
+
+    MinSpanningTreeEdges {
+        graph: g,
+        subgraphs,
+        sort_edges,
+    }
+}
+
+/// An iterator producing the edges of a minimum spanning forest of a graph.
+#[derive(Debug, Clone)]
+pub struct MinSpanningTreeEdges<G>
+where
+    G: IntoEdgeReferences,
+{
+    graph: G,
+    subgraphs: UnionFind<usize>,
+    sort_edges: BinaryHeap<MinScored<G::EdgeWeight, G::EdgeRef>>,
+}
+
+impl<G> Iterator for MinSpanningTreeEdges<G>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::EdgeWeight: PartialOrd,
+{
+    type Item = G::EdgeRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let g = self.graph;
+        // Kruskal's algorithm, like `MinSpanningTree`.
+        while let Some(MinScored(_, edge)) = self.sort_edges.pop() {
+            let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+            if self.subgraphs.union(a, b) {
+                return Some(edge);
+            }
+        }
+        None
+    }
+}
+
+/// An algorithm error.
+///
+/// The algorithms that can fail return this error, with the node id type `N`
+/// of the graph, so that their results compose with `?`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::{bellman_ford, toposort, Error};
+/// use petgraph::prelude::*;
+///
+/// // The cost of the path from the first to the last node in order.
+/// fn cost(g: &DiGraph<(), f64>) -> Result<f64, Error<NodeIndex>> {
+///     let order = toposort(g, None)?;
+///     let paths = bellman_ford(g, order[0])?;
+///     Ok(paths.distances[order[order.len() - 1].index()])
+/// }
+///
+/// let mut g = DiGraph::<(), f64>::from_edges(&[(0, 1, 2.), (1, 2, -1.)]);
+/// assert_eq!(cost(&g), Ok(1.));
+/// g.add_edge(NodeIndex::new(2), NodeIndex::new(0), 0.);
+/// assert!(cost(&g).unwrap_err().node_id().is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error<N> {
+    /// A cycle was found in a graph that must be acyclic. The node is on the
+    /// cycle.
+    Cycle(N),
+    /// A cycle of negative total weight was found, so there are no shortest
+    /// paths.
+    NegativeCycle,
+    /// The graph is not bipartite. The nodes form a cycle of odd length, in
+    /// order: consecutive nodes are adjacent, and so are the last and the
+    /// first node. A self loop is reported as a cycle of one node.
+    NotBipartite(Vec<N>),
+    /// An iterative algorithm did not converge within its iteration limit.
+    Unconverged,
+}
+
+impl<N> Error<N> {
+    /// Return a node on the cycle of a `Cycle` or `NotBipartite` error.
+    pub fn node_id(&self) -> Option<N>
+    where
+        N: Copy,
+    {
+        match *self {
+            Error::Cycle(a) => Some(a),
+            Error::NotBipartite(ref cycle) => cycle.first().cloned(),
+            _ => None,
+        }
+    }
+}
+
+impl<N: fmt::Debug> fmt::Display for Error<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Cycle(ref a) => write!(f, "the graph has a cycle through node {:?}", a),
+            Error::NegativeCycle => f.write_str("the graph has a cycle of negative weight"),
+            Error::NotBipartite(ref cycle) => {
+                write!(f, "the graph is not bipartite: odd cycle {:?}", cycle)
+            }
+            Error::Unconverged => f.write_str("the algorithm did not converge"),
+        }
+    }
+}
+
+impl<N: fmt::Debug> std::error::Error for Error<N> {}
+
 /// An algorithm error: a cycle was found in the graph.
+#[deprecated(note = "replaced by `Error::Cycle`")]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Cycle<N>(N);
 
+#[allow(deprecated)]
 impl<N> Cycle<N> {
     /// Return a node id that participates in the cycle
     pub fn node_id(&self) -> N
@@ -754,15 +1166,34 @@ impl<N> Cycle<N> {
     }
 }
 
+#[allow(deprecated)]
+impl<N> From<Cycle<N>> for Error<N> {
+    fn from(cycle: Cycle<N>) -> Self {
+        Error::Cycle(cycle.0)
+    }
+}
+
 /// An algorithm error: a cycle of negative weights was found in the graph.
+#[deprecated(note = "replaced by `Error::NegativeCycle`")]
 #[derive(Clone, Debug, PartialEq)]
 pub struct NegativeCycle(pub ());
 
+#[allow(deprecated)]
+impl<N> From<NegativeCycle> for Error<N> {
+    fn from(_: NegativeCycle) -> Self {
+        Error::NegativeCycle
+    }
+}
+
 /// Return `true` if the graph is bipartite. A graph is bipartite if it's nodes can be divided into
 /// two disjoint and indepedent sets U and V such that every edge connects U to one in V. This
 /// algorithm implements 2-coloring algorithm based on the BFS algorithm.
 ///
 /// Always treats the input graph as if undirected.
+///
+/// Only the component of `start` is checked; see
+/// [`bipartite_partition`](fn.bipartite_partition.html) to check and partition
+/// the whole graph.
 pub fn is_bipartite_undirected<G, N, VM>(g: G, start: N) -> bool
 where
     G: GraphRef + Visitable<NodeId = N, Map = VM> + IntoNeighbors<NodeId = N>,
@@ -845,9 +1276,14 @@ impl FloatMeasure for f64 {
     }
 }
 
+/// A measure with bounds, for integer and floating-point costs.
 pub trait BoundedMeasure: Measure + std::ops::Sub<Self, Output = Self> {
+    /// The least value of the measure, negative infinity for floats.
     fn min() -> Self;
+    /// The greatest value of the measure, used as an infinite cost; infinity
+    /// for floats.
     fn max() -> Self;
+    /// Return the sum, and `true` if it overflowed the bounds.
     fn overflowing_add(self, rhs: Self) -> (Self, bool);
 }
 
@@ -878,11 +1314,11 @@ macro_rules! impl_bounded_measure_float(
         $(
             impl BoundedMeasure for $t {
                 fn min() -> Self {
-                    std::$t::MIN
+                    std::$t::NEG_INFINITY
                 }
 
                 fn max() -> Self {
-                    std::$t::MAX
+                    std::$t::INFINITY
                 }
 
                 fn overflowing_add(self, rhs: Self) -> (Self, bool) {
@@ -0,0 +1,215 @@
+//! Compute orderings of the nodes of a graph.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::undirected_adjacency;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// Breadth-first search from `start`, returning the nodes in the order they
+/// were visited, and the number of nodes in each level.
+///
+/// `level` must be `usize::MAX` for all nodes that can be visited, and is
+/// reset to that before returning.
+fn bfs_levels(start: usize, adj: &[Vec<usize>], level: &mut [usize]) -> (Vec<usize>, Vec<usize>) {
+    let mut order = vec![start];
+    let mut widths = Vec::new();
+    level[start] = 0;
+    let mut i = 0;
+    while i < order.len() {
+        let a = order[i];
+        if level[a] == widths.len() {
+            widths.push(0);
+        }
+        widths[level[a]] += 1;
+        for &b in &adj[a] {
+            if level[b] == std::usize::MAX {
+                level[b] = level[a] + 1;
+                order.push(b);
+            }
+        }
+        i += 1;
+    }
+    for &a in &order {
+        level[a] = std::usize::MAX;
+    }
+    (order, widths)
+}
+
+/// \[Generic\] Compute the reverse Cuthill–McKee ordering of the nodes.
+///
+/// Numbering the nodes in this order tends to reduce the *bandwidth* of the
+/// adjacency matrix, the largest distance of a nonzero entry from the
+/// diagonal, and the fill-in of sparse matrix factorizations.
+///
+/// Each connected component is traversed breadth-first from a
+/// pseudo-peripheral node, visiting the neighbors of each node by increasing
+/// degree, and the resulting sequence of all nodes is reversed.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// Each search for a pseudo-peripheral node takes a few breadth-first
+/// searches of its component, each in **O(|V| + |E|)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::reverse_cuthill_mckee;
+/// use petgraph::prelude::*;
+///
+/// // A path with its nodes numbered out of order.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 3), (3, 1), (1, 4), (4, 2)]);
+/// let order = reverse_cuthill_mckee(&g);
+/// let order = order.iter().map(|n| n.index()).collect::<Vec<_>>();
+/// assert!(order == [0, 3, 1, 4, 2] || order == [2, 4, 1, 3, 0]);
+/// ```
+pub fn reverse_cuthill_mckee<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (mut nodes, mut adj) = undirected_adjacency(g);
+    let degree = adj.iter().map(Vec::len).collect::<Vec<_>>();
+    for list in &mut adj {
+        list.sort_by_key(|&b| degree[b]);
+    }
+    nodes.sort_by_key(|&a| degree[a]);
+
+    let mut level = vec![std::usize::MAX; adj.len()];
+    let mut order = Vec::with_capacity(nodes.len());
+    for &root in &nodes {
+        if level[root] != std::usize::MAX {
+            // already numbered
+            continue;
+        }
+        // Find a pseudo-peripheral node by repeatedly moving to a node of
+        // minimum degree in the last level, while that increases the
+        // eccentricity.
+        let mut start = root;
+        let (mut component, mut widths) = bfs_levels(start, &adj, &mut level);
+        loop {
+            let last = widths[widths.len() - 1];
+            let candidate = component[component.len() - last..]
+                .iter()
+                .cloned()
+                .min_by_key(|&a| degree[a])
+                .unwrap();
+            let (next_component, next_widths) = bfs_levels(candidate, &adj, &mut level);
+            if next_widths.len() <= widths.len() {
+                break;
+            }
+            start = candidate;
+            component = next_component;
+            widths = next_widths;
+        }
+        // `component` is the Cuthill-McKee order from `start`; mark it numbered
+        debug_assert_eq!(component[0], start);
+        for &a in &component {
+            level[a] = 0;
+        }
+        order.extend(component);
+    }
+    order.reverse();
+    order.into_iter().map(|i| g.from_index(i)).collect()
+}
+
+/// \[Generic\] Compute a lexicographic breadth-first search (Lex-BFS) ordering
+/// of the nodes.
+///
+/// Lex-BFS is a breadth-first search where ties between nodes are broken by
+/// preferring the node whose already visited neighbors were visited earliest:
+/// every node is labeled with the positions of its visited neighbors, and the
+/// node with the lexicographically largest label is visited next. It is a
+/// building block of, for example, recognizing chordal graphs: a graph is
+/// chordal if and only if the reverse of a Lex-BFS ordering is a perfect
+/// elimination ordering.
+///
+/// The search starts from the first node of `g.node_identifiers()`, and
+/// continues in the next unvisited node whenever a connected component is
+/// exhausted.
+///
+/// The input graph is treated as if undirected; self loops and parallel
+/// edges are ignored.
+///
+/// Computes in **O(|V| + |E|)** time using partition refinement, not counting
+/// the hashing of the node identifiers.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::lex_bfs;
+/// use petgraph::prelude::*;
+///
+/// // After 0 and 1, node 3 is preferred over 2, since it is also adjacent to 0.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (1, 3), (0, 3)]);
+/// let order = lex_bfs(&g);
+/// assert_eq!(order, vec![0, 1, 3, 2].into_iter().map(NodeIndex::new).collect::<Vec<_>>());
+/// ```
+pub fn lex_bfs<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let nodes = g.node_identifiers().collect::<Vec<_>>();
+    let index = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| (a, i))
+        .collect::<HashMap<_, _>>();
+    let mut adj = vec![Vec::new(); nodes.len()];
+    for (i, &a) in nodes.iter().enumerate() {
+        for b in g.neighbors(a) {
+            let j = index[&b];
+            if i != j {
+                adj[i].push(j);
+                adj[j].push(i);
+            }
+        }
+    }
+    for list in &mut adj {
+        list.sort_unstable();
+        list.dedup();
+    }
+
+    // The unvisited nodes are kept in `seq[i + 1..]`, partitioned into
+    // consecutive classes of nodes with equal labels, by decreasing label.
+    // Class `c` is `seq[start[c]..end[c]]`.
+    let n = nodes.len();
+    let mut seq = (0..n).collect::<Vec<_>>();
+    let mut pos = (0..n).collect::<Vec<_>>();
+    let mut class = vec![0; n];
+    let mut start = vec![0];
+    let mut end = vec![n];
+    // the class split off from each class in the current step
+    let mut split = vec![std::usize::MAX];
+    let mut touched = Vec::new();
+    for i in 0..n {
+        let a = seq[i];
+        start[class[a]] += 1;
+        // Move the unvisited neighbors of `a` to the front of their classes,
+        // where they form new classes with a larger label.
+        for &b in &adj[a] {
+            if pos[b] <= i {
+                continue;
+            }
+            let c = class[b];
+            if split[c] == std::usize::MAX {
+                split[c] = start.len();
+                start.push(start[c]);
+                end.push(start[c]);
+                split.push(std::usize::MAX);
+                touched.push(c);
+            }
+            let d = split[c];
+            let front = seq[start[c]];
+            seq.swap(pos[b], start[c]);
+            pos[front] = pos[b];
+            pos[b] = start[c];
+            start[c] += 1;
+            end[d] += 1;
+            class[b] = d;
+        }
+        for c in touched.drain(..) {
+            split[c] = std::usize::MAX;
+        }
+    }
+    seq.into_iter().map(|i| nodes[i]).collect()
+}
@@ -0,0 +1,143 @@
+//! Level-synchronous parallel breadth-first search.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use crate::Direction::Incoming;
+
+/// Switch to pulling when the frontier has more than `1 / ALPHA` of the edges
+/// of the unvisited nodes.
+const ALPHA: usize = 14;
+/// Switch back to pushing when the frontier has less than `1 / BETA` of the
+/// nodes.
+const BETA: usize = 24;
+
+const UNVISITED: usize = std::usize::MAX;
+
+/// The distances and parents of the nodes in a breadth-first search tree,
+/// indexed by the node indices from `NodeIndexable`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BfsTree<N> {
+    /// The number of edges on a shortest path from the start to each node, or
+    /// `None` if the node can not be reached.
+    pub distances: Vec<Option<usize>>,
+    /// The node before each node on a shortest path from the start, or `None`
+    /// for the start and the nodes that can not be reached.
+    pub parents: Vec<Option<N>>,
+}
+
+/// \[Generic\] Breadth-first search, in parallel with
+/// [`rayon`](https://crates.io/crates/rayon).
+///
+/// Visits the nodes reachable from `start` one level at a time, and the
+/// nodes of each level in parallel. Each level is found in the direction that
+/// is less work: pushing from the nodes of the frontier to their unvisited
+/// neighbors, or, when the frontier is large, pulling by each unvisited node
+/// from any neighbor in the frontier (along the incoming edges of a directed
+/// graph). This is the direction-optimizing search of Beamer, Asanović and
+/// Patterson.
+///
+/// Which of several parents at the same distance a node gets depends on the
+/// scheduling of the threads.
+///
+/// Requires crate feature `"rayon"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::parallel_bfs;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (0, 3), (4, 0)]);
+/// let tree = parallel_bfs(&g, 0.into());
+/// assert_eq!(tree.distances, [Some(0), Some(1), Some(2), Some(1), None]);
+/// assert_eq!(tree.parents[2], Some(1.into()));
+/// ```
+pub fn parallel_bfs<G>(graph: G, start: G::NodeId) -> BfsTree<G::NodeId>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+{
+    let nodes = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect::<Vec<_>>();
+    let degree = |a: usize| graph.neighbors(graph.from_index(a)).count();
+    let distances = (0..graph.node_bound())
+        .map(|_| AtomicUsize::new(UNVISITED))
+        .collect::<Vec<_>>();
+    let parents = (0..graph.node_bound())
+        .map(|_| AtomicUsize::new(UNVISITED))
+        .collect::<Vec<_>>();
+
+    let start = graph.to_index(start);
+    distances[start].store(0, Ordering::Relaxed);
+    let mut frontier = vec![start];
+    let mut frontier_edges = degree(start);
+    let mut unvisited_edges = nodes.par_iter().map(|&a| degree(a)).sum::<usize>() - frontier_edges;
+    let mut pulling = false;
+    let mut depth = 0;
+    while !frontier.is_empty() {
+        pulling = if pulling {
+            frontier.len() * BETA >= nodes.len()
+        } else {
+            frontier_edges * ALPHA > unvisited_edges
+        };
+        let next = if pulling {
+            nodes
+                .par_iter()
+                .filter(|&&b| distances[b].load(Ordering::Relaxed) == UNVISITED)
+                .filter_map(|&b| {
+                    let a = graph
+                        .neighbors_directed(graph.from_index(b), Incoming)
+                        .map(|a| graph.to_index(a))
+                        .find(|&a| distances[a].load(Ordering::Relaxed) == depth)?;
+                    distances[b].store(depth + 1, Ordering::Relaxed);
+                    parents[b].store(a, Ordering::Relaxed);
+                    Some(b)
+                })
+                .collect::<Vec<_>>()
+        } else {
+            frontier
+                .par_iter()
+                .flat_map_iter(|&a| {
+                    let (distances, parents) = (&distances, &parents);
+                    graph
+                        .neighbors(graph.from_index(a))
+                        .map(move |b| graph.to_index(b))
+                        .filter(move |&b| {
+                            let visited = distances[b]
+                                .compare_exchange(
+                                    UNVISITED,
+                                    depth + 1,
+                                    Ordering::Relaxed,
+                                    Ordering::Relaxed,
+                                )
+                                .is_ok();
+                            if visited {
+                                parents[b].store(a, Ordering::Relaxed);
+                            }
+                            visited
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+        frontier = next;
+        frontier_edges = frontier.par_iter().map(|&a| degree(a)).sum();
+        unvisited_edges -= frontier_edges;
+        depth += 1;
+    }
+
+    let unvisited = |i: usize| if i == UNVISITED { None } else { Some(i) };
+    BfsTree {
+        distances: distances
+            .into_iter()
+            .map(|d| unvisited(d.into_inner()))
+            .collect(),
+        parents: parents
+            .into_iter()
+            .map(|p| unvisited(p.into_inner()).map(|p| graph.from_index(p)))
+            .collect(),
+    }
+}
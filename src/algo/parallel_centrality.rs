@@ -0,0 +1,276 @@
+//! Parallel iterative ranking of nodes: PageRank, HITS and Katz centrality.
+//!
+//! Each iteration computes the new score of every node in parallel, from the
+//! scores of its neighbors in the previous iteration, and sums are added up
+//! in a fixed order, so the scores do not depend on the number of threads or
+//! their scheduling.
+
+use rayon::prelude::*;
+
+use super::Error;
+use crate::visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use crate::Direction::{self, Incoming, Outgoing};
+
+/// The number of values added up sequentially by each task of `sum`.
+const CHUNK: usize = 4096;
+
+/// Add up `values` in parallel, in an order that depends only on their
+/// number.
+fn sum(values: &[f64]) -> f64 {
+    values
+        .par_chunks(CHUNK)
+        .map(|chunk| chunk.iter().sum::<f64>())
+        .collect::<Vec<_>>()
+        .iter()
+        .sum()
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    sum(&a
+        .par_iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).abs())
+        .collect::<Vec<_>>())
+}
+
+/// The node indices of a graph, and which indices up to `node_bound` are
+/// nodes.
+struct Nodes {
+    count: usize,
+    present: Vec<bool>,
+}
+
+impl Nodes {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoNodeIdentifiers + NodeIndexable,
+    {
+        let mut present = vec![false; graph.node_bound()];
+        let mut count = 0;
+        for n in graph.node_identifiers() {
+            present[graph.to_index(n)] = true;
+            count += 1;
+        }
+        Nodes { count, present }
+    }
+
+    /// Compute a score for each node in parallel, and zero for the vacant
+    /// indices.
+    fn map<F>(&self, f: F) -> Vec<f64>
+    where
+        F: Fn(usize) -> f64 + Sync,
+    {
+        (0..self.present.len())
+            .into_par_iter()
+            .map(|i| if self.present[i] { f(i) } else { 0. })
+            .collect()
+    }
+}
+
+/// Add up `scores` of the neighbors of node `a` in direction `dir`.
+fn neighbor_sum<G>(graph: G, a: usize, dir: Direction, scores: &[f64]) -> f64
+where
+    G: IntoNeighborsDirected + NodeIndexable,
+{
+    graph
+        .neighbors_directed(graph.from_index(a), dir)
+        .map(|b| scores[graph.to_index(b)])
+        .sum()
+}
+
+/// Scale `scores` to sum to one, unless they are all zero.
+fn normalize(scores: &mut Vec<f64>) {
+    let total = sum(scores);
+    if total != 0. {
+        scores.par_iter_mut().for_each(|x| *x /= total);
+    }
+}
+
+/// \[Generic\] PageRank, in parallel with
+/// [`rayon`](https://crates.io/crates/rayon).
+///
+/// The rank of a node is the probability that a random surfer is at it, who
+/// follows a random outgoing edge with probability `damping`, and jumps to a
+/// random node otherwise, or when the node has no outgoing edges. The edges
+/// of an undirected graph are followed both ways.
+///
+/// Iterates until the ranks change by less than `tolerance`, summed over all
+/// nodes, and returns a vector indexed by the node indices from
+/// `NodeIndexable`, with the rank of each node, which add up to one. If the
+/// ranks still change by more after `max_iterations` iterations, return an
+/// `Error::Unconverged` error instead.
+///
+/// Requires crate feature `"rayon"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_page_rank;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+/// let ranks = par_page_rank(&g, 0.85, 1e-9, 200).unwrap();
+/// assert!(ranks[0] > ranks[1] && ranks[3] < ranks[2]);
+/// assert!((ranks.iter().sum::<f64>() - 1.).abs() < 1e-9);
+/// ```
+pub fn par_page_rank<G>(
+    graph: G,
+    damping: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<Vec<f64>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+{
+    let nodes = Nodes::new(graph);
+    if nodes.count == 0 {
+        return Ok(vec![0.; nodes.present.len()]);
+    }
+    let n = nodes.count as f64;
+    let out_degree = nodes.map(|a| graph.neighbors(graph.from_index(a)).count() as f64);
+    let mut ranks = nodes.map(|_| 1. / n);
+    for _ in 0..max_iterations {
+        // The rank of each node, shared out among its successors.
+        let shares = (0..ranks.len())
+            .into_par_iter()
+            .map(|a| {
+                if out_degree[a] > 0. {
+                    ranks[a] / out_degree[a]
+                } else {
+                    0.
+                }
+            })
+            .collect::<Vec<_>>();
+        let dangling = sum(&(0..ranks.len())
+            .into_par_iter()
+            .map(|a| if out_degree[a] > 0. { 0. } else { ranks[a] })
+            .collect::<Vec<_>>());
+        let jump = ((1. - damping) + damping * dangling) / n;
+        let next = nodes.map(|a| jump + damping * neighbor_sum(graph, a, Incoming, &shares));
+        let change = distance(&ranks, &next);
+        ranks = next;
+        if change < tolerance {
+            return Ok(ranks);
+        }
+    }
+    Err(Error::Unconverged)
+}
+
+/// The hub and authority scores of `par_hits`.
+type HitsResult<N> = Result<(Vec<f64>, Vec<f64>), Error<N>>;
+
+/// \[Generic\] HITS hub and authority scores, in parallel with
+/// [`rayon`](https://crates.io/crates/rayon).
+///
+/// The authority score of a node is the sum of the hub scores of its
+/// predecessors, and the hub score of a node is the sum of the authority
+/// scores of its successors, each scaled to add up to one.
+///
+/// Iterates until the hub scores change by less than `tolerance`, summed over
+/// all nodes, and returns the hub scores and the authority scores, in vectors
+/// indexed by the node indices from `NodeIndexable`. If the hub scores still
+/// change by more after `max_iterations` iterations, return an
+/// `Error::Unconverged` error instead.
+///
+/// Requires crate feature `"rayon"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_hits;
+/// use petgraph::prelude::*;
+///
+/// // Nodes 0 and 1 link to 2 and 3, and node 0 also links to 1.
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (1, 2), (1, 3)]);
+/// let (hubs, authorities) = par_hits(&g, 1e-9, 100).unwrap();
+/// assert!(hubs[0] > hubs[1] && hubs[2] == 0.);
+/// assert!(authorities[2] > authorities[1] && authorities[0] == 0.);
+/// ```
+pub fn par_hits<G>(graph: G, tolerance: f64, max_iterations: usize) -> HitsResult<G::NodeId>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+{
+    let nodes = Nodes::new(graph);
+    if nodes.count == 0 {
+        return Ok((vec![0.; nodes.present.len()], vec![0.; nodes.present.len()]));
+    }
+    let mut hubs = nodes.map(|_| 1.);
+    normalize(&mut hubs);
+    for _ in 0..max_iterations {
+        let mut authorities = nodes.map(|a| neighbor_sum(graph, a, Incoming, &hubs));
+        normalize(&mut authorities);
+        let mut next = nodes.map(|a| neighbor_sum(graph, a, Outgoing, &authorities));
+        normalize(&mut next);
+        let change = distance(&hubs, &next);
+        hubs = next;
+        if change < tolerance {
+            return Ok((hubs, authorities));
+        }
+    }
+    Err(Error::Unconverged)
+}
+
+/// \[Generic\] Katz centrality, in parallel with
+/// [`rayon`](https://crates.io/crates/rayon).
+///
+/// The centrality of a node is `beta` plus `alpha` times the sum of the
+/// centralities of its predecessors, which counts the walks that end at the
+/// node, with a weight of `alpha` to the power of their length. The iteration
+/// converges if `alpha` is less than one over the largest eigenvalue of the
+/// adjacency matrix.
+///
+/// Iterates until the centralities change by less than `tolerance`, summed
+/// over all nodes, and returns a vector indexed by the node indices from
+/// `NodeIndexable`, with the centrality of each node, scaled to a Euclidean
+/// norm of one. If the centralities still change by more after
+/// `max_iterations` iterations, as they do when `alpha` is too large, return
+/// an `Error::Unconverged` error instead.
+///
+/// Requires crate feature `"rayon"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::par_katz_centrality;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (3, 2)]);
+/// let katz = par_katz_centrality(&g, 0.1, 1., 1e-12, 100).unwrap();
+/// assert!(katz[2] > katz[1] && katz[1] > katz[0]);
+/// assert_eq!(katz[0], katz[3]);
+///
+/// // The walks around a cycle grow without bound if alpha is one.
+/// let cycle = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// assert!(par_katz_centrality(&cycle, 1., 1., 1e-12, 100).is_err());
+/// ```
+pub fn par_katz_centrality<G>(
+    graph: G,
+    alpha: f64,
+    beta: f64,
+    tolerance: f64,
+    max_iterations: usize,
+) -> Result<Vec<f64>, Error<G::NodeId>>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+{
+    let nodes = Nodes::new(graph);
+    let mut scores = vec![0.; nodes.present.len()];
+    if nodes.count == 0 {
+        return Ok(scores);
+    }
+    let mut converged = false;
+    for _ in 0..max_iterations {
+        let next = nodes.map(|a| beta + alpha * neighbor_sum(graph, a, Incoming, &scores));
+        let change = distance(&scores, &next);
+        scores = next;
+        if change < tolerance {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(Error::Unconverged);
+    }
+    let norm = sum(&scores.par_iter().map(|x| x * x).collect::<Vec<_>>()).sqrt();
+    if norm != 0. {
+        scores.par_iter_mut().for_each(|x| *x /= norm);
+    }
+    Ok(scores)
+}
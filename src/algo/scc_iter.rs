@@ -0,0 +1,165 @@
+//! Lazily compute the strongly connected components of a graph.
+
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Return an iterator over the *strongly connected components*
+/// of the graph.
+///
+/// The components are produced one at a time by an iterative version of the
+/// same algorithm as [`tarjan_scc`](../fn.tarjan_scc.html), and in the same
+/// order: their postorder (reverse topological sort). The order of node ids
+/// within each scc is arbitrary. Each component is computed only when it is
+/// requested, so taking the first few components of a large graph only
+/// explores the part of the graph they depend on.
+///
+/// For an undirected graph, the sccs are simply the connected components.
+///
+/// The search is not recursive, so it doesn't overflow the stack on graphs
+/// with long paths.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::tarjan_scc_iter;
+/// use petgraph::prelude::*;
+///
+/// let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 0), (1, 2), (2, 3), (3, 2)]);
+/// let mut sccs = tarjan_scc_iter(&g);
+///
+/// // a component comes before the components that have edges into it
+/// let mut first = sccs.next().unwrap();
+/// first.sort();
+/// assert_eq!(first, vec![NodeIndex::new(2), NodeIndex::new(3)]);
+/// assert_eq!(sccs.count(), 1);
+/// ```
+pub fn tarjan_scc_iter<G>(g: G) -> TarjanSccIter<G>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors + NodeIndexable,
+{
+    TarjanSccIter {
+        graph: g,
+        roots: g.node_identifiers(),
+        rootindex: vec![0; g.node_bound()],
+        index: 1,
+        componentcount: std::usize::MAX,
+        stack: Vec::new(),
+        dfs: Vec::new(),
+    }
+}
+
+/// A node of the depth first search of `TarjanSccIter`, with its remaining
+/// neighbors.
+struct Frame<N, I> {
+    node: N,
+    neighbors: I,
+    is_local_root: bool,
+}
+
+/// An iterator over the strongly connected components of a graph.
+///
+/// Created with [`tarjan_scc_iter`](fn.tarjan_scc_iter.html).
+pub struct TarjanSccIter<G>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    graph: G,
+    roots: G::NodeIdentifiers,
+    /// The root index of each node, 0 if not yet visited. Nodes of finished
+    /// components have indices above `componentcount`.
+    rootindex: Vec<usize>,
+    index: usize,
+    componentcount: usize,
+    stack: Vec<G::NodeId>,
+    dfs: Vec<Frame<G::NodeId, G::Neighbors>>,
+}
+
+impl<G> TarjanSccIter<G>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors + NodeIndexable,
+{
+    fn begin_visit(&mut self, v: G::NodeId) {
+        self.rootindex[self.graph.to_index(v)] = self.index;
+        self.index += 1;
+        self.dfs.push(Frame {
+            node: v,
+            neighbors: self.graph.neighbors(v),
+            is_local_root: true,
+        });
+    }
+
+    /// Lower the root index of the node on top of the search to that of `w`,
+    /// if it is less.
+    fn update_top(&mut self, w: G::NodeId) {
+        if let Some(frame) = self.dfs.last_mut() {
+            let w_index = self.rootindex[self.graph.to_index(w)];
+            let v_index = &mut self.rootindex[self.graph.to_index(frame.node)];
+            if w_index < *v_index {
+                *v_index = w_index;
+                frame.is_local_root = false;
+            }
+        }
+    }
+
+    /// Finish the visit of `v`, and return its component if `v` is its root.
+    fn finish_visit(&mut self, v: G::NodeId, is_local_root: bool) -> Option<Vec<G::NodeId>> {
+        if !is_local_root {
+            self.stack.push(v);
+            return None;
+        }
+        let g = self.graph;
+        let c = self.componentcount;
+        let v_index = self.rootindex[g.to_index(v)];
+        let mut start = self.stack.len();
+        while start > 0 && v_index <= self.rootindex[g.to_index(self.stack[start - 1])] {
+            start -= 1;
+            self.rootindex[g.to_index(self.stack[start])] = c;
+        }
+        self.rootindex[g.to_index(v)] = c;
+        self.index -= self.stack.len() - start + 1;
+        self.componentcount -= 1;
+        self.stack.push(v);
+        Some(self.stack.split_off(start))
+    }
+}
+
+impl<G> Iterator for TarjanSccIter<G>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors + NodeIndexable,
+{
+    type Item = Vec<G::NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = match self.dfs.last_mut() {
+                Some(frame) => frame,
+                None => {
+                    let g = self.graph;
+                    let rootindex = &self.rootindex;
+                    let root = self
+                        .roots
+                        .by_ref()
+                        .find(|&n| rootindex[g.to_index(n)] == 0)?;
+                    self.begin_visit(root);
+                    continue;
+                }
+            };
+            if let Some(w) = frame.neighbors.next() {
+                if self.rootindex[self.graph.to_index(w)] == 0 {
+                    self.begin_visit(w);
+                } else {
+                    self.update_top(w);
+                }
+                continue;
+            }
+            let Frame {
+                node,
+                is_local_root,
+                ..
+            } = self.dfs.pop().unwrap();
+            let component = self.finish_visit(node, is_local_root);
+            self.update_top(node);
+            if component.is_some() {
+                return component;
+            }
+        }
+    }
+}
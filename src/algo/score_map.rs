@@ -0,0 +1,59 @@
+//! Maps from nodes to scores, for the shortest path algorithms.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::visit::NodeIndexable;
+
+/// A map from nodes to values, like the scores or predecessors of the nodes.
+pub(crate) trait ScoreMap<N, V> {
+    fn get_score(&self, node: N) -> Option<V>;
+    fn set_score(&mut self, node: N, value: V);
+}
+
+impl<N, V> ScoreMap<N, V> for HashMap<N, V>
+where
+    N: Eq + Hash,
+    V: Copy,
+{
+    fn get_score(&self, node: N) -> Option<V> {
+        self.get(&node).cloned()
+    }
+
+    fn set_score(&mut self, node: N, value: V) {
+        self.insert(node, value);
+    }
+}
+
+/// A map backed by a vector, indexed by the node indices from
+/// `NodeIndexable`.
+pub(crate) struct IndexedScores<G, V> {
+    graph: G,
+    pub(crate) values: Vec<Option<V>>,
+}
+
+impl<G, V> IndexedScores<G, V>
+where
+    G: NodeIndexable,
+{
+    pub(crate) fn new(graph: G) -> Self {
+        IndexedScores {
+            values: (0..graph.node_bound()).map(|_| None).collect(),
+            graph,
+        }
+    }
+}
+
+impl<G, V> ScoreMap<G::NodeId, V> for IndexedScores<G, V>
+where
+    G: NodeIndexable,
+    V: Copy,
+{
+    fn get_score(&self, node: G::NodeId) -> Option<V> {
+        self.values[self.graph.to_index(node)]
+    }
+
+    fn set_score(&mut self, node: G::NodeId, value: V) {
+        self.values[self.graph.to_index(node)] = Some(value);
+    }
+}
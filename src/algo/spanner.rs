@@ -0,0 +1,112 @@
+//! Sparse subgraphs that preserve distances.
+//!
+//! A *t-spanner* of a graph is a subgraph in which the distance between any
+//! two nodes is at most **t** times their distance in the graph, for a
+//! *stretch factor* **t ≥ 1**.
+
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::collections::{BinaryHeap, HashMap};
+use std::ops::Mul;
+
+use super::Measure;
+use crate::scored::MinScored;
+use crate::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+/// \[Generic\] Compute a t-spanner of a graph with the greedy algorithm.
+///
+/// Goes through the edges by increasing cost, and keeps an edge from `u` to
+/// `v` of cost `w` only if the distance between `u` and `v` over the edges
+/// kept so far is more than `stretch * w`. The result is a t-spanner for
+/// `t = stretch`, and for `stretch = 2k - 1` it has **O(|V|^(1 + 1/k))**
+/// edges. The kept edges are returned in the order they were kept.
+///
+/// `edge_cost` gives the non-negative cost of an edge, and `stretch` should
+/// be at least one: a stretch of one keeps the edges needed to preserve all
+/// distances.
+///
+/// The input graph is treated as if undirected; self loops are never kept.
+///
+/// Computes in **O(|E| (|E'| + |V|) log |V|)** time, where **E'** is the set
+/// of kept edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::greedy_spanner;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::EdgeRef;
+///
+/// // A square with both diagonals.
+/// let g = UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 2), (1, 2, 2), (2, 3, 2), (3, 0, 2),
+///     (0, 2, 3), (1, 3, 3),
+/// ]);
+/// // The diagonals are shorter than the paths around them.
+/// assert_eq!(greedy_spanner(&g, |e| *e.weight(), 1).len(), 6);
+/// assert_eq!(greedy_spanner(&g, |e| *e.weight(), 2).len(), 4);
+/// // A stretch of three allows to drop a side of the square.
+/// assert_eq!(greedy_spanner(&g, |e| *e.weight(), 3).len(), 3);
+/// ```
+pub fn greedy_spanner<G, F, K>(g: G, mut edge_cost: F, stretch: K) -> Vec<G::EdgeRef>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Measure + Copy + Mul<Output = K>,
+{
+    let mut edges = g
+        .edge_references()
+        .filter(|e| e.source() != e.target())
+        .map(|e| (edge_cost(e), e))
+        .collect::<Vec<_>>();
+    edges.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut adjacency = vec![Vec::new(); g.node_bound()];
+    let mut spanner = Vec::new();
+    for (cost, edge) in edges {
+        let (u, v) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        if !within(&adjacency, u, v, stretch * cost) {
+            adjacency[u].push((v, cost));
+            adjacency[v].push((u, cost));
+            spanner.push(edge);
+        }
+    }
+    spanner
+}
+
+/// Return `true` if the distance from `start` to `goal` in `adjacency` is at
+/// most `bound`, with a Dijkstra search that stops beyond `bound`.
+fn within<K>(adjacency: &[Vec<(usize, K)>], start: usize, goal: usize, bound: K) -> bool
+where
+    K: Measure + Copy,
+{
+    let mut scores = HashMap::new();
+    let mut visit_next = BinaryHeap::new();
+    scores.insert(start, K::default());
+    visit_next.push(MinScored(K::default(), start));
+    while let Some(MinScored(score, a)) = visit_next.pop() {
+        if a == goal {
+            return true;
+        }
+        if scores[&a] < score {
+            continue;
+        }
+        for &(b, cost) in &adjacency[a] {
+            let next_score = score + cost;
+            if next_score > bound {
+                continue;
+            }
+            match scores.entry(b) {
+                Occupied(mut ent) => {
+                    if next_score < *ent.get() {
+                        *ent.get_mut() = next_score;
+                        visit_next.push(MinScored(next_score, b));
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                    visit_next.push(MinScored(next_score, b));
+                }
+            }
+        }
+    }
+    false
+}
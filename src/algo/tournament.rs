@@ -0,0 +1,72 @@
+//! Algorithms on tournaments.
+//!
+//! A *tournament* is a directed graph with exactly one edge between each pair
+//! of distinct nodes, as the outcome of a round-robin competition where
+//! every pair of players meets once.
+
+use fixedbitset::FixedBitSet;
+
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Find a Hamiltonian path in a tournament: an order of all the
+/// nodes where each node has an edge to the next one.
+///
+/// Every tournament has such a path, which ranks the players so that each one
+/// beat the next. The path is built by inserting the nodes one at a time,
+/// finding a position for each by binary search.
+///
+/// The result is a Hamiltonian path whenever every pair of distinct nodes is
+/// joined by an edge in at least one direction; otherwise it is just some
+/// order of the nodes. Self loops and parallel edges are ignored.
+///
+/// Computes in **O(|V|²)** time.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::hamiltonian_path_in_tournament;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 0), (2, 1), (3, 0), (1, 3), (3, 2)]);
+/// let path = hamiltonian_path_in_tournament(&g);
+/// assert_eq!(path.len(), 4);
+/// assert!(path.windows(2).all(|w| g.contains_edge(w[0], w[1])));
+/// ```
+pub fn hamiltonian_path_in_tournament<G>(g: G) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let n = g.node_bound();
+    let mut beats = FixedBitSet::with_capacity(n * n);
+    for a in g.node_identifiers() {
+        let i = g.to_index(a);
+        for b in g.neighbors(a) {
+            beats.insert(i * n + g.to_index(b));
+        }
+    }
+    let beats = |i: usize, j: usize| beats[i * n + j];
+
+    let mut path: Vec<usize> = Vec::with_capacity(n);
+    for a in g.node_identifiers() {
+        let v = g.to_index(a);
+        let position = match (path.first(), path.last()) {
+            (Some(&first), _) if !beats(first, v) => 0,
+            (_, Some(&last)) if beats(last, v) => path.len(),
+            (None, _) => 0,
+            _ => {
+                // Invariant: path[lo] beats v, and path[hi] does not.
+                let (mut lo, mut hi) = (0, path.len() - 1);
+                while hi - lo > 1 {
+                    let mid = (lo + hi) / 2;
+                    if beats(path[mid], v) {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                hi
+            }
+        };
+        path.insert(position, v);
+    }
+    path.into_iter().map(|i| g.from_index(i)).collect()
+}
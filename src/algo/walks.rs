@@ -0,0 +1,170 @@
+//! Counting walks with powers of the adjacency matrix.
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+/// A semiring, for adding up the weights of walks: the weight of a walk is
+/// the product of the weights of its edges, and the weights of different
+/// walks are added up.
+///
+/// Implemented for `bool`, with `||` and `&&`, and for the integer and
+/// floating point types, with `+` and `*`.
+pub trait Semiring: Clone {
+    /// The identity of `plus`, the weight of no walks.
+    fn zero() -> Self;
+    /// The identity of `times`, the weight of an empty walk.
+    fn one() -> Self;
+    /// Add up the weights of two walks.
+    fn plus(&self, other: &Self) -> Self;
+    /// Extend a walk by another.
+    fn times(&self, other: &Self) -> Self;
+}
+
+impl Semiring for bool {
+    fn zero() -> Self {
+        false
+    }
+
+    fn one() -> Self {
+        true
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        *self || *other
+    }
+
+    fn times(&self, other: &Self) -> Self {
+        *self && *other
+    }
+}
+
+macro_rules! impl_semiring(
+    ( $( $t:ident: $zero:expr, $one:expr ),* ) => {
+        $(
+            impl Semiring for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn one() -> Self {
+                    $one
+                }
+
+                fn plus(&self, other: &Self) -> Self {
+                    *self + *other
+                }
+
+                fn times(&self, other: &Self) -> Self {
+                    *self * *other
+                }
+            }
+        )*
+    };
+);
+
+impl_semiring!(
+    u8: 0, 1, u16: 0, 1, u32: 0, 1, u64: 0, 1, u128: 0, 1, usize: 0, 1,
+    i8: 0, 1, i16: 0, 1, i32: 0, 1, i64: 0, 1, i128: 0, 1, isize: 0, 1,
+    f32: 0., 1., f64: 0., 1.
+);
+
+/// \[Generic\] Count the walks of exactly `k` edges between each pair of
+/// nodes.
+///
+/// Returns a matrix, indexed by the node indices from `NodeIndexable`, where
+/// the entry in row `a` and column `b` is the number of walks from `a` to
+/// `b`, which may repeat nodes and edges. In an undirected graph, an edge
+/// can be walked both ways, and parallel edges make different walks.
+///
+/// This is [`count_walks_with`](fn.count_walks_with.html) with weight 1 for
+/// each edge. The counts grow exponentially with `k`, and overflow `usize`
+/// for large `k`; use `f64` weights with `count_walks_with` for those.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::count_walks;
+/// use petgraph::prelude::*;
+///
+/// // A triangle.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let walks = count_walks(&g, 3);
+/// // Around the triangle either way, or to a neighbor and back and over
+/// // the third edge.
+/// assert_eq!(walks[0][0], 2);
+/// assert_eq!(walks[0][1], 3);
+/// ```
+pub fn count_walks<G>(g: G, k: usize) -> Vec<Vec<usize>>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    count_walks_with(g, k, |_| 1)
+}
+
+/// \[Generic\] Add up the weights of the walks of exactly `k` edges between
+/// each pair of nodes, in a [`Semiring`](trait.Semiring.html).
+///
+/// Returns a matrix, indexed by the node indices from `NodeIndexable`, where
+/// the entry in row `a` and column `b` is the sum of the weights of the walks
+/// from `a` to `b`, and the weight of a walk is the product of the weights
+/// given by `edge_weight` for its edges. With `bool` weights, the entry says
+/// if there is a walk; with weights of 1, it is the number of walks.
+///
+/// Computes the `k`-th power of the weighted adjacency matrix by repeated
+/// squaring, in **O(|V|³ log k)** time and **O(|V|²)** space.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::count_walks_with;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), f64>::from_edges(&[(0, 1, 0.5), (1, 2, 0.5), (0, 2, 0.1)]);
+/// let reachable = count_walks_with(&g, 2, |_| true);
+/// assert_eq!(reachable[0], [false, false, true]);
+/// let probability = count_walks_with(&g, 2, |e| *e.weight());
+/// assert_eq!(probability[0][2], 0.25);
+/// ```
+pub fn count_walks_with<G, F, K>(g: G, k: usize, mut edge_weight: F) -> Vec<Vec<K>>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> K,
+    K: Semiring,
+{
+    let n = g.node_bound();
+    let mut base = vec![vec![K::zero(); n]; n];
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        let w = edge_weight(edge);
+        base[a][b] = base[a][b].plus(&w);
+        if !g.is_directed() && a != b {
+            base[b][a] = base[b][a].plus(&w);
+        }
+    }
+
+    let mut result = vec![vec![K::zero(); n]; n];
+    for (i, row) in result.iter_mut().enumerate() {
+        row[i] = K::one();
+    }
+    let mut k = k;
+    while k > 0 {
+        if k & 1 == 1 {
+            result = multiply(&result, &base);
+        }
+        k >>= 1;
+        if k > 0 {
+            base = multiply(&base, &base);
+        }
+    }
+    result
+}
+
+fn multiply<K: Semiring>(a: &[Vec<K>], b: &[Vec<K>]) -> Vec<Vec<K>> {
+    let n = a.len();
+    let mut product = vec![vec![K::zero(); n]; n];
+    for (row, a_row) in product.iter_mut().zip(a) {
+        for (a_ij, b_row) in a_row.iter().zip(b) {
+            for (entry, b_jk) in row.iter_mut().zip(b_row) {
+                *entry = entry.plus(&a_ij.times(b_jk));
+            }
+        }
+    }
+    product
+}
@@ -0,0 +1,634 @@
+//! `BipartiteGraph<A, B, E, Ix>` is a graph datastructure with two kinds of
+//! nodes, where each edge joins a node of one kind to a node of the other.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::graph::{DefaultIx, EdgeIndex, IndexType, NodeIndex, UnGraph};
+
+/// Index of a node on the left side of a `BipartiteGraph`.
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct LeftIndex<Ix = DefaultIx>(Ix);
+
+/// Index of a node on the right side of a `BipartiteGraph`.
+#[derive(Copy, Clone, Default, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct RightIndex<Ix = DefaultIx>(Ix);
+
+macro_rules! side_index {
+    ($name:ident) => {
+        impl<Ix: IndexType> $name<Ix> {
+            #[inline]
+            pub fn new(x: usize) -> Self {
+                $name(IndexType::new(x))
+            }
+
+            #[inline]
+            pub fn index(self) -> usize {
+                self.0.index()
+            }
+
+            #[inline]
+            pub fn end() -> Self {
+                $name(IndexType::max())
+            }
+        }
+
+        impl<Ix: fmt::Debug> fmt::Debug for $name<Ix> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, concat!(stringify!($name), "({:?})"), self.0)
+            }
+        }
+    };
+}
+
+side_index!(LeftIndex);
+side_index!(RightIndex);
+
+/// The node weights of a `BipartiteGraph` converted to a `Graph`, see
+/// [`BipartiteGraph::to_graph`](struct.BipartiteGraph.html#method.to_graph).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Partition<A, B> {
+    /// A node of the left side.
+    Left(A),
+    /// A node of the right side.
+    Right(B),
+}
+
+/// A node of a `BipartiteGraph`, and its edges.
+#[derive(Clone, Debug)]
+struct Node<N, Ix> {
+    weight: N,
+    edges: Vec<EdgeIndex<Ix>>,
+}
+
+/// An edge of a `BipartiteGraph`.
+#[derive(Clone, Debug)]
+struct Edge<E, Ix> {
+    weight: E,
+    left: LeftIndex<Ix>,
+    right: RightIndex<Ix>,
+}
+
+/// `BipartiteGraph<A, B, E, Ix>` is an undirected graph datastructure with
+/// two sides of nodes, where each edge joins a left node to a right node.
+///
+/// The sides have their own node weight types, `A` on the left and `B` on the
+/// right, and their own index types, [`LeftIndex`](struct.LeftIndex.html) and
+/// [`RightIndex`](struct.RightIndex.html). [`add_edge`](#method.add_edge)
+/// takes one of each, so an edge within a side doesn't type check.
+///
+/// `BipartiteGraph` is parameterized over:
+///
+/// - Associated data `A` and `B` for the left and right nodes, and `E` for
+///   edges, of arbitrary type.
+/// - Index type `Ix`, which determines the maximum size of each side and of
+///   the edges.
+///
+/// Parallel edges are allowed. Nodes and edges can't be removed, so their
+/// indices are always contiguous.
+///
+/// The graph has the bipartite algorithms built in, as
+/// [`maximum_matching`](#method.maximum_matching) and the projections
+/// [`project_left`](#method.project_left) and
+/// [`project_right`](#method.project_right). For other algorithms, convert it
+/// with [`to_graph`](#method.to_graph).
+///
+/// ```
+/// use petgraph::bipartite_graph::BipartiteGraph;
+///
+/// // workers and the jobs they can do
+/// let mut g = BipartiteGraph::<_, _, ()>::new();
+/// let ann = g.add_left("Ann");
+/// let ben = g.add_left("Ben");
+/// let cook = g.add_right("cook");
+/// let drive = g.add_right("drive");
+/// g.add_edge(ann, cook, ());
+/// g.add_edge(ann, drive, ());
+/// g.add_edge(ben, cook, ());
+///
+/// let matching = g.maximum_matching();
+/// assert_eq!(matching.len(), 2);
+/// assert_eq!(g.edge_endpoints(matching[0]), Some((ann, drive)));
+/// assert_eq!(g.edge_endpoints(matching[1]), Some((ben, cook)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BipartiteGraph<A, B, E, Ix = DefaultIx> {
+    left: Vec<Node<A, Ix>>,
+    right: Vec<Node<B, Ix>>,
+    edges: Vec<Edge<E, Ix>>,
+}
+
+/// Create a new empty `BipartiteGraph`.
+impl<A, B, E, Ix> Default for BipartiteGraph<A, B, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::with_capacity(0, 0, 0)
+    }
+}
+
+impl<A, B, E, Ix> BipartiteGraph<A, B, E, Ix>
+where
+    Ix: IndexType,
+{
+    /// Create a new empty `BipartiteGraph`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new `BipartiteGraph` with estimated capacity.
+    pub fn with_capacity(left: usize, right: usize, edges: usize) -> Self {
+        BipartiteGraph {
+            left: Vec::with_capacity(left),
+            right: Vec::with_capacity(right),
+            edges: Vec::with_capacity(edges),
+        }
+    }
+
+    /// Return the number of nodes on the left side.
+    pub fn left_count(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Return the number of nodes on the right side.
+    pub fn right_count(&self) -> usize {
+        self.right.len()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Remove all nodes and edges.
+    pub fn clear(&mut self) {
+        self.left.clear();
+        self.right.clear();
+        self.edges.clear();
+    }
+
+    /// Add a node with associated data `weight` to the left side, and return
+    /// its index.
+    ///
+    /// **Panics** if the left side is at the maximum number of nodes for its
+    /// index type.
+    pub fn add_left(&mut self, weight: A) -> LeftIndex<Ix> {
+        let a = LeftIndex::new(self.left.len());
+        assert!(<Ix as IndexType>::max().index() == !0 || LeftIndex::end() != a);
+        self.left.push(Node {
+            weight,
+            edges: Vec::new(),
+        });
+        a
+    }
+
+    /// Add a node with associated data `weight` to the right side, and return
+    /// its index.
+    ///
+    /// **Panics** if the right side is at the maximum number of nodes for its
+    /// index type.
+    pub fn add_right(&mut self, weight: B) -> RightIndex<Ix> {
+        let b = RightIndex::new(self.right.len());
+        assert!(<Ix as IndexType>::max().index() == !0 || RightIndex::end() != b);
+        self.right.push(Node {
+            weight,
+            edges: Vec::new(),
+        });
+        b
+    }
+
+    /// Add an edge joining `a` and `b` with associated data `weight`, and
+    /// return its index.
+    ///
+    /// **Panics** if any of the nodes don't exist, or if the graph is at the
+    /// maximum number of edges for its index type.
+    pub fn add_edge(&mut self, a: LeftIndex<Ix>, b: RightIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        let e = EdgeIndex::new(self.edges.len());
+        assert!(<Ix as IndexType>::max().index() == !0 || EdgeIndex::end() != e);
+        assert!(
+            a.index() < self.left.len() && b.index() < self.right.len(),
+            "BipartiteGraph::add_edge: node index out of bounds"
+        );
+        self.left[a.index()].edges.push(e);
+        self.right[b.index()].edges.push(e);
+        self.edges.push(Edge {
+            weight,
+            left: a,
+            right: b,
+        });
+        e
+    }
+
+    /// Return the first edge joining `a` and `b`, if any.
+    pub fn find_edge(&self, a: LeftIndex<Ix>, b: RightIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        self.left
+            .get(a.index())?
+            .edges
+            .iter()
+            .cloned()
+            .find(|e| self.edges[e.index()].right == b)
+    }
+
+    /// Return a reference to the weight of left node `a`, or `None` if it
+    /// doesn't exist.
+    pub fn left_weight(&self, a: LeftIndex<Ix>) -> Option<&A> {
+        self.left.get(a.index()).map(|node| &node.weight)
+    }
+
+    /// Return a mutable reference to the weight of left node `a`, or `None`
+    /// if it doesn't exist.
+    pub fn left_weight_mut(&mut self, a: LeftIndex<Ix>) -> Option<&mut A> {
+        self.left.get_mut(a.index()).map(|node| &mut node.weight)
+    }
+
+    /// Return a reference to the weight of right node `b`, or `None` if it
+    /// doesn't exist.
+    pub fn right_weight(&self, b: RightIndex<Ix>) -> Option<&B> {
+        self.right.get(b.index()).map(|node| &node.weight)
+    }
+
+    /// Return a mutable reference to the weight of right node `b`, or `None`
+    /// if it doesn't exist.
+    pub fn right_weight_mut(&mut self, b: RightIndex<Ix>) -> Option<&mut B> {
+        self.right.get_mut(b.index()).map(|node| &mut node.weight)
+    }
+
+    /// Return a reference to the weight of edge `e`, or `None` if it doesn't
+    /// exist.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.edges.get(e.index()).map(|edge| &edge.weight)
+    }
+
+    /// Return a mutable reference to the weight of edge `e`, or `None` if it
+    /// doesn't exist.
+    pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
+        self.edges.get_mut(e.index()).map(|edge| &mut edge.weight)
+    }
+
+    /// Return the left and right node of edge `e`, or `None` if it doesn't
+    /// exist.
+    pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(LeftIndex<Ix>, RightIndex<Ix>)> {
+        self.edges
+            .get(e.index())
+            .map(|edge| (edge.left, edge.right))
+    }
+
+    /// Return the edges of left node `a`, in the order they were added.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    pub fn left_edges(&self, a: LeftIndex<Ix>) -> &[EdgeIndex<Ix>] {
+        &self.left[a.index()].edges
+    }
+
+    /// Return the edges of right node `b`, in the order they were added.
+    ///
+    /// **Panics** if the node `b` does not exist.
+    pub fn right_edges(&self, b: RightIndex<Ix>) -> &[EdgeIndex<Ix>] {
+        &self.right[b.index()].edges
+    }
+
+    /// Return an iterator of the right nodes joined to left node `a`, once
+    /// for each edge.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `RightIndex<Ix>`.
+    pub fn left_neighbors(&self, a: LeftIndex<Ix>) -> impl Iterator<Item = RightIndex<Ix>> + '_ {
+        self.left_edges(a)
+            .iter()
+            .map(move |e| self.edges[e.index()].right)
+    }
+
+    /// Return an iterator of the left nodes joined to right node `b`, once
+    /// for each edge.
+    ///
+    /// **Panics** if the node `b` does not exist.<br>
+    /// Iterator element type is `LeftIndex<Ix>`.
+    pub fn right_neighbors(&self, b: RightIndex<Ix>) -> impl Iterator<Item = LeftIndex<Ix>> + '_ {
+        self.right_edges(b)
+            .iter()
+            .map(move |e| self.edges[e.index()].left)
+    }
+
+    /// Return an iterator over the indices of the left nodes.
+    pub fn left_indices(&self) -> impl Iterator<Item = LeftIndex<Ix>> {
+        (0..self.left_count()).map(LeftIndex::new)
+    }
+
+    /// Return an iterator over the indices of the right nodes.
+    pub fn right_indices(&self) -> impl Iterator<Item = RightIndex<Ix>> {
+        (0..self.right_count()).map(RightIndex::new)
+    }
+
+    /// Return an iterator over the edge indices of the graph.
+    pub fn edge_indices(&self) -> impl Iterator<Item = EdgeIndex<Ix>> {
+        (0..self.edge_count()).map(EdgeIndex::new)
+    }
+
+    /// Compute a matching of the largest possible size with the
+    /// Hopcroft–Karp algorithm, and return its edges in the order of their
+    /// left nodes.
+    ///
+    /// Computes in **O(|E| √|V|)** time.
+    pub fn maximum_matching(&self) -> Vec<EdgeIndex<Ix>> {
+        const NONE: usize = std::usize::MAX;
+        // the matched edge of each left node, and left node of each right node
+        let mut left_mate = vec![NONE; self.left.len()];
+        let mut right_mate = vec![NONE; self.right.len()];
+        let mut layer = vec![NONE; self.left.len()];
+        let mut next_edge = vec![0; self.left.len()];
+        let mut queue = Vec::new();
+        let mut path = Vec::new();
+        let mut path_edges = Vec::new();
+        loop {
+            // layer the left nodes by their distance from a free left node
+            queue.clear();
+            for (a, layer) in layer.iter_mut().enumerate() {
+                if left_mate[a] == NONE {
+                    *layer = 0;
+                    queue.push(a);
+                } else {
+                    *layer = NONE;
+                }
+            }
+            let mut found = false;
+            let mut i = 0;
+            while i < queue.len() {
+                let a = queue[i];
+                i += 1;
+                for e in &self.left[a].edges {
+                    match right_mate[self.edges[e.index()].right.index()] {
+                        NONE => found = true,
+                        c if layer[c] == NONE => {
+                            layer[c] = layer[a] + 1;
+                            queue.push(c);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            if !found {
+                break;
+            }
+
+            // augment along disjoint shortest paths, found depth first
+            for x in next_edge.iter_mut() {
+                *x = 0;
+            }
+            for start in 0..self.left.len() {
+                if left_mate[start] != NONE {
+                    continue;
+                }
+                path.clear();
+                path_edges.clear();
+                path.push(start);
+                while let Some(&a) = path.last() {
+                    let edges = &self.left[a].edges;
+                    if next_edge[a] == edges.len() {
+                        // a dead end, not to be visited again in this phase
+                        layer[a] = NONE;
+                        path.pop();
+                        path_edges.pop();
+                        continue;
+                    }
+                    let e = edges[next_edge[a]].index();
+                    next_edge[a] += 1;
+                    let b = self.edges[e].right.index();
+                    match right_mate[b] {
+                        NONE => {
+                            path_edges.push(e);
+                            for (&a, &e) in path.iter().zip(&path_edges) {
+                                left_mate[a] = e;
+                                right_mate[self.edges[e].right.index()] = a;
+                            }
+                            break;
+                        }
+                        c if layer[c] == layer[a] + 1 => {
+                            path.push(c);
+                            path_edges.push(e);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        left_mate
+            .into_iter()
+            .filter(|&e| e != NONE)
+            .map(EdgeIndex::new)
+            .collect()
+    }
+
+    /// Return the projection of the graph onto its left side: the undirected
+    /// graph of the left nodes, with an edge between each pair of them that
+    /// have a common neighbor.
+    ///
+    /// The left nodes keep their indices, as `NodeIndex`. The weight of an
+    /// edge starts at `init` and is updated by `aggregate(&mut weight, w1,
+    /// w2)` for the weights `w1` and `w2` of each pair of edges joining its
+    /// two nodes to a common neighbor, `w1` from the earlier node.
+    ///
+    /// Computes in **O(|V| + |E| + Σ d(v)²)** time, the sum over the right
+    /// nodes.
+    ///
+    /// ```
+    /// use petgraph::bipartite_graph::BipartiteGraph;
+    /// use petgraph::graph::NodeIndex;
+    ///
+    /// // people and the events they attended, with the hours spent there
+    /// let mut g = BipartiteGraph::<_, _, u32>::new();
+    /// let people: Vec<_> = (0..3).map(|i| g.add_left(i)).collect();
+    /// let talk = g.add_right("talk");
+    /// let party = g.add_right("party");
+    /// g.add_edge(people[0], talk, 1);
+    /// g.add_edge(people[1], talk, 2);
+    /// g.add_edge(people[1], party, 3);
+    /// g.add_edge(people[0], party, 5);
+    ///
+    /// let hours = g.project_left(0, |w, w1, w2| *w += w1.min(w2));
+    /// let e = hours.find_edge(NodeIndex::new(0), NodeIndex::new(1)).unwrap();
+    /// assert_eq!(hours[e], 1 + 3);
+    /// assert_eq!(hours.edge_count(), 1);
+    /// ```
+    pub fn project_left<W, F>(&self, init: W, aggregate: F) -> UnGraph<A, W, Ix>
+    where
+        A: Clone,
+        W: Clone,
+        F: FnMut(&mut W, &E, &E),
+    {
+        project(&self.left, &self.right, init, aggregate, |e| {
+            let edge = &self.edges[e.index()];
+            (edge.left.index(), &edge.weight)
+        })
+    }
+
+    /// Return the projection of the graph onto its right side: the
+    /// undirected graph of the right nodes, with an edge between each pair of
+    /// them that have a common neighbor.
+    ///
+    /// The right nodes keep their indices, as `NodeIndex`. The edge weights
+    /// are computed as for [`project_left`](#method.project_left).
+    ///
+    /// Computes in **O(|V| + |E| + Σ d(v)²)** time, the sum over the left
+    /// nodes.
+    pub fn project_right<W, F>(&self, init: W, aggregate: F) -> UnGraph<B, W, Ix>
+    where
+        B: Clone,
+        W: Clone,
+        F: FnMut(&mut W, &E, &E),
+    {
+        project(&self.right, &self.left, init, aggregate, |e| {
+            let edge = &self.edges[e.index()];
+            (edge.right.index(), &edge.weight)
+        })
+    }
+
+    /// Return the graph as an undirected `Graph`, to run other algorithms
+    /// on it.
+    ///
+    /// The left nodes keep their indices, and right node `b` becomes the node
+    /// with index `left_count() + b.index()`. The edges keep their indices.
+    ///
+    /// **Panics** if the graph has more nodes than the index type can
+    /// represent.
+    pub fn to_graph(&self) -> UnGraph<Partition<A, B>, E, Ix>
+    where
+        A: Clone,
+        B: Clone,
+        E: Clone,
+    {
+        let mut graph =
+            UnGraph::with_capacity(self.left_count() + self.right_count(), self.edge_count());
+        for node in &self.left {
+            graph.add_node(Partition::Left(node.weight.clone()));
+        }
+        for node in &self.right {
+            graph.add_node(Partition::Right(node.weight.clone()));
+        }
+        for edge in &self.edges {
+            graph.add_edge(
+                NodeIndex::new(edge.left.index()),
+                NodeIndex::new(self.left_count() + edge.right.index()),
+                edge.weight.clone(),
+            );
+        }
+        graph
+    }
+}
+
+/// Project the nodes `keep` through their common neighbors in `through`,
+/// where `end` returns the node of `keep` and the weight of an edge.
+fn project<'a, N, M, E: 'a, W, F, G, Ix>(
+    keep: &[Node<N, Ix>],
+    through: &[Node<M, Ix>],
+    init: W,
+    mut aggregate: F,
+    end: G,
+) -> UnGraph<N, W, Ix>
+where
+    N: Clone,
+    W: Clone,
+    F: FnMut(&mut W, &E, &E),
+    G: Fn(EdgeIndex<Ix>) -> (usize, &'a E),
+    Ix: IndexType,
+{
+    let mut graph = UnGraph::with_capacity(keep.len(), 0);
+    for node in keep {
+        graph.add_node(node.weight.clone());
+    }
+    let mut weights = BTreeMap::new();
+    let mut incident = Vec::new();
+    for node in through {
+        incident.clear();
+        incident.extend(node.edges.iter().map(|&e| end(e)));
+        incident.sort_by_key(|&(i, _)| i);
+        for (k, &(i, w1)) in incident.iter().enumerate() {
+            for &(j, w2) in &incident[k + 1..] {
+                if i != j {
+                    let w = weights.entry((i, j)).or_insert_with(|| init.clone());
+                    aggregate(w, w1, w2);
+                }
+            }
+        }
+    }
+    for ((i, j), w) in weights {
+        graph.add_edge(NodeIndex::new(i), NodeIndex::new(j), w);
+    }
+    graph
+}
+
+/// Index the `BipartiteGraph` by `LeftIndex` to access left node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<A, B, E, Ix> Index<LeftIndex<Ix>> for BipartiteGraph<A, B, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Output = A;
+    fn index(&self, index: LeftIndex<Ix>) -> &A {
+        &self.left[index.index()].weight
+    }
+}
+
+/// Index the `BipartiteGraph` by `LeftIndex` to access left node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<A, B, E, Ix> IndexMut<LeftIndex<Ix>> for BipartiteGraph<A, B, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: LeftIndex<Ix>) -> &mut A {
+        &mut self.left[index.index()].weight
+    }
+}
+
+/// Index the `BipartiteGraph` by `RightIndex` to access right node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<A, B, E, Ix> Index<RightIndex<Ix>> for BipartiteGraph<A, B, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Output = B;
+    fn index(&self, index: RightIndex<Ix>) -> &B {
+        &self.right[index.index()].weight
+    }
+}
+
+/// Index the `BipartiteGraph` by `RightIndex` to access right node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<A, B, E, Ix> IndexMut<RightIndex<Ix>> for BipartiteGraph<A, B, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: RightIndex<Ix>) -> &mut B {
+        &mut self.right[index.index()].weight
+    }
+}
+
+/// Index the `BipartiteGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<A, B, E, Ix> Index<EdgeIndex<Ix>> for BipartiteGraph<A, B, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.edges[index.index()].weight
+    }
+}
+
+/// Index the `BipartiteGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<A, B, E, Ix> IndexMut<EdgeIndex<Ix>> for BipartiteGraph<A, B, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: EdgeIndex<Ix>) -> &mut E {
+        &mut self.edges[index.index()].weight
+    }
+}
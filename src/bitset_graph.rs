@@ -0,0 +1,789 @@
+//! `BitSetGraph<N, Ty, Ix>` is a graph datastructure with the neighbors of
+//! each node kept in a compressed bitset.
+
+use std::iter::Enumerate;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, Range};
+use std::slice;
+
+use fixedbitset::FixedBitSet;
+
+use crate::graph::{DefaultIx, IndexType, NodeIndex};
+use crate::visit::{
+    Data, EdgeCount, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeDegrees, NodeIndexable, Visitable,
+};
+use crate::{Directed, Direction, EdgeType, Incoming, Outgoing, Undirected};
+
+mod compressed;
+
+pub use self::compressed::{CompressedBitSet, Iter};
+
+/// A `BitSetGraph` with directed edges.
+pub type DiBitSetGraph<N, Ix = DefaultIx> = BitSetGraph<N, Directed, Ix>;
+/// A `BitSetGraph` with undirected edges.
+pub type UnBitSetGraph<N, Ix = DefaultIx> = BitSetGraph<N, Undirected, Ix>;
+
+/// `BitSetGraph<N, Ty, Ix>` is a graph datastructure where the neighbors of
+/// each node are kept in a [`CompressedBitSet`](struct.CompressedBitSet.html).
+///
+/// The neighbor sets make it fast to intersect the neighborhoods of two
+/// nodes, which is the inner loop of triangle counting, clique detection and
+/// common neighbor measures, while taking little space for sparse graphs.
+///
+/// `BitSetGraph` is parameterized over:
+///
+/// - Associated data `N` for nodes. Edges have no associated data, their
+///   weight is `()`.
+/// - Edge type `Ty` that determines whether the graph edges are directed or
+///   undirected.
+/// - Index type `Ix`, which determines the maximum size of the graph. The
+///   nodes are stored as `u32` values in the neighbor sets, so a graph has at
+///   most 2^32 nodes whatever the index type.
+///
+/// The graph allows self loops but not parallel edges. A directed graph keeps
+/// the set of incoming neighbors of each node too.
+///
+/// Nodes are identified by `NodeIndex<Ix>`, and edges by their endpoints.
+/// Nodes can't be removed, so node indices are always contiguous.
+///
+/// Depends on crate feature `bitset_graph`.
+///
+/// ```
+/// use petgraph::bitset_graph::UnBitSetGraph;
+/// use petgraph::visit::IntoEdgeReferences;
+///
+/// let mut g = UnBitSetGraph::<()>::new();
+/// let n: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+/// for &(a, b) in &[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (3, 4)] {
+///     g.add_edge(n[a], n[b]);
+/// }
+/// assert_eq!(g.common_neighbor_count(n[1], n[2]), 2);
+///
+/// // each triangle is counted once for each of its edges
+/// let triangles: usize = g
+///     .edge_references()
+///     .map(|(a, b, _)| g.common_neighbor_count(a, b))
+///     .sum::<usize>() / 3;
+/// assert_eq!(triangles, 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BitSetGraph<N, Ty = Directed, Ix = DefaultIx> {
+    nodes: Vec<N>,
+    /// The neighbors of each node, or its outgoing neighbors if directed.
+    outgoing: Vec<CompressedBitSet>,
+    /// The incoming neighbors of each node if directed, otherwise empty.
+    incoming: Vec<CompressedBitSet>,
+    edge_count: usize,
+    ty: PhantomData<Ty>,
+    ix: PhantomData<Ix>,
+}
+
+/// Create a new empty `BitSetGraph`.
+impl<N, Ty, Ix> Default for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl<N, Ty, Ix> BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new empty `BitSetGraph`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new `BitSetGraph` with estimated capacity for `nodes` nodes.
+    pub fn with_capacity(nodes: usize) -> Self {
+        BitSetGraph {
+            nodes: Vec::with_capacity(nodes),
+            outgoing: Vec::with_capacity(nodes),
+            incoming: Vec::with_capacity(if Ty::is_directed() { nodes } else { 0 }),
+            edge_count: 0,
+            ty: PhantomData,
+            ix: PhantomData,
+        }
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Whether the graph has directed edges.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Remove all nodes and edges.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.outgoing.clear();
+        self.incoming.clear();
+        self.edge_count = 0;
+    }
+
+    /// Remove all edges.
+    pub fn clear_edges(&mut self) {
+        for set in self.outgoing.iter_mut().chain(&mut self.incoming) {
+            set.clear();
+        }
+        self.edge_count = 0;
+    }
+
+    /// Add a node with associated data `weight` to the graph, and return its
+    /// index.
+    ///
+    /// **Panics** if the graph has as many nodes as the index type or a
+    /// `u32` can represent.
+    pub fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        let a = NodeIndex::new(self.nodes.len());
+        assert!(
+            NodeIndex::end() != a && a.index() <= std::u32::MAX as usize,
+            "BitSetGraph: node count exceeds the index type"
+        );
+        self.nodes.push(weight);
+        self.outgoing.push(CompressedBitSet::new());
+        if self.is_directed() {
+            self.incoming.push(CompressedBitSet::new());
+        }
+        a
+    }
+
+    /// Add an edge from `a` to `b` to the graph, and return `true` if it
+    /// wasn't in the graph before.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.assert_node(a);
+        self.assert_node(b);
+        let added = self.outgoing[a.index()].insert(b.index() as u32);
+        if added {
+            if self.is_directed() {
+                self.incoming[b.index()].insert(a.index() as u32);
+            } else {
+                self.outgoing[b.index()].insert(a.index() as u32);
+            }
+            self.edge_count += 1;
+        }
+        added
+    }
+
+    /// Remove the edge from `a` to `b` from the graph, and return `true` if
+    /// it was in the graph.
+    pub fn remove_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        let removed = match self.outgoing.get_mut(a.index()) {
+            Some(set) => set.remove(b.index() as u32),
+            None => false,
+        };
+        if removed {
+            if self.is_directed() {
+                self.incoming[b.index()].remove(a.index() as u32);
+            } else {
+                self.outgoing[b.index()].remove(a.index() as u32);
+            }
+            self.edge_count -= 1;
+        }
+        removed
+    }
+
+    /// Return `true` if there is an edge from `a` to `b`.
+    ///
+    /// Computes in **O(log |V|)** time.
+    pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.outgoing
+            .get(a.index())
+            .map_or(false, |set| set.contains(b.index() as u32))
+    }
+
+    /// Return a reference to the weight of node `a`, or `None` if it doesn't
+    /// exist.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.nodes.get(a.index())
+    }
+
+    /// Return a mutable reference to the weight of node `a`, or `None` if it
+    /// doesn't exist.
+    pub fn node_weight_mut(&mut self, a: NodeIndex<Ix>) -> Option<&mut N> {
+        self.nodes.get_mut(a.index())
+    }
+
+    /// Return an iterator over the node indices of the graph.
+    pub fn node_indices(&self) -> NodeIdentifiers<Ix> {
+        NodeIdentifiers {
+            r: 0..self.node_count(),
+            ix: PhantomData,
+        }
+    }
+
+    /// Return the set of neighbors of `a`, as `u32` node indices.
+    ///
+    /// - `Directed`: Targets of outgoing edges from `a`.
+    /// - `Undirected`: All neighbors of `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    pub fn neighbor_set(&self, a: NodeIndex<Ix>) -> &CompressedBitSet {
+        &self.outgoing[a.index()]
+    }
+
+    /// Return the set of neighbors of `a` in the direction `dir`, as `u32`
+    /// node indices.
+    ///
+    /// - `Directed`, `Outgoing`: Targets of outgoing edges from `a`.
+    /// - `Directed`, `Incoming`: Sources of incoming edges to `a`.
+    /// - `Undirected`: All neighbors of `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    pub fn neighbor_set_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> &CompressedBitSet {
+        if dir == Incoming && self.is_directed() {
+            &self.incoming[a.index()]
+        } else {
+            &self.outgoing[a.index()]
+        }
+    }
+
+    /// Return an iterator of the neighbors of `a`, in increasing order.
+    ///
+    /// - `Directed`: Targets of outgoing edges from `a`.
+    /// - `Undirected`: All neighbors of `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Neighbors<'_, Ix> {
+        self.neighbors_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of the neighbors of `a` in the direction `dir`, in
+    /// increasing order.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Neighbors<'_, Ix> {
+        Neighbors {
+            iter: self.neighbor_set_directed(a, dir).iter(),
+            ix: PhantomData,
+        }
+    }
+
+    /// Return the set of nodes that are neighbors of both `a` and `b`, as
+    /// `u32` node indices.
+    ///
+    /// For a directed graph, these are the common targets of outgoing edges.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    pub fn common_neighbors(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> CompressedBitSet {
+        self.outgoing[a.index()].intersection(&self.outgoing[b.index()])
+    }
+
+    /// Return the number of nodes that are neighbors of both `a` and `b`,
+    /// without creating the set of them.
+    ///
+    /// For a directed graph, these are the common targets of outgoing edges.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    pub fn common_neighbor_count(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> usize {
+        self.outgoing[a.index()].intersection_len(&self.outgoing[b.index()])
+    }
+
+    /// Return an iterator of the edges of `a`, as `(source, target, &())`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges of `a`, with `a` as the source.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `(NodeIndex<Ix>, NodeIndex<Ix>, &())`.
+    pub fn edges(&self, a: NodeIndex<Ix>) -> Edges<'_, Ix> {
+        self.edges_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of the edges of `a` in the direction `dir`, as
+    /// `(source, target, &())`.
+    ///
+    /// - `Outgoing`: Edges with `a` as the source.
+    /// - `Incoming`: Edges with `a` as the target.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `(NodeIndex<Ix>, NodeIndex<Ix>, &())`.
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Edges<'_, Ix> {
+        Edges {
+            node: a,
+            dir,
+            iter: self.neighbor_set_directed(a, dir).iter(),
+        }
+    }
+
+    fn assert_node(&self, a: NodeIndex<Ix>) {
+        assert!(
+            a.index() < self.node_count(),
+            "BitSetGraph: node index {} is out of bounds",
+            a.index()
+        );
+    }
+}
+
+/// Iterator over the node indices of a graph.
+#[derive(Debug, Clone)]
+pub struct NodeIdentifiers<Ix = DefaultIx> {
+    r: Range<usize>,
+    ix: PhantomData<Ix>,
+}
+
+impl<Ix: IndexType> Iterator for NodeIdentifiers<Ix> {
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.r.next().map(NodeIndex::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.r.size_hint()
+    }
+}
+
+impl<Ix: IndexType> ExactSizeIterator for NodeIdentifiers<Ix> {}
+
+/// Iterator over the neighbors of a node, in increasing order.
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a, Ix = DefaultIx> {
+    iter: Iter<'a>,
+    ix: PhantomData<Ix>,
+}
+
+impl<'a, Ix: IndexType> Iterator for Neighbors<'a, Ix> {
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|b| NodeIndex::new(b as usize))
+    }
+}
+
+/// Iterator over the edges of a node.
+#[derive(Debug, Clone)]
+pub struct Edges<'a, Ix = DefaultIx> {
+    node: NodeIndex<Ix>,
+    dir: Direction,
+    iter: Iter<'a>,
+}
+
+impl<'a, Ix: IndexType> Iterator for Edges<'a, Ix> {
+    type Item = (NodeIndex<Ix>, NodeIndex<Ix>, &'a ());
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let b = NodeIndex::new(self.iter.next()? as usize);
+        Some(match self.dir {
+            Outgoing => (self.node, b, &()),
+            Incoming => (b, self.node, &()),
+        })
+    }
+}
+
+/// Iterator over the nodes of a graph, with their weights.
+#[derive(Debug, Clone)]
+pub struct NodeReferences<'a, N: 'a, Ix = DefaultIx> {
+    iter: Enumerate<slice::Iter<'a, N>>,
+    ix: PhantomData<Ix>,
+}
+
+impl<'a, N, Ix: IndexType> Iterator for NodeReferences<'a, N, Ix> {
+    type Item = (NodeIndex<Ix>, &'a N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, w)| (NodeIndex::new(i), w))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, N, Ix: IndexType> ExactSizeIterator for NodeReferences<'a, N, Ix> {}
+
+/// Iterator over all edges of a graph, as `(source, target, &())`.
+///
+/// The edges of an undirected graph are listed once, with the smaller node
+/// index as the source.
+#[derive(Debug, Clone)]
+pub struct EdgeReferences<'a, Ty, Ix = DefaultIx> {
+    rows: Enumerate<slice::Iter<'a, CompressedBitSet>>,
+    current: Option<(NodeIndex<Ix>, Iter<'a>)>,
+    ty: PhantomData<Ty>,
+}
+
+impl<'a, Ty: EdgeType, Ix: IndexType> Iterator for EdgeReferences<'a, Ty, Ix> {
+    type Item = (NodeIndex<Ix>, NodeIndex<Ix>, &'a ());
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((a, iter)) = &mut self.current {
+                if let Some(b) = iter.next() {
+                    let b = NodeIndex::new(b as usize);
+                    if Ty::is_directed() || b >= *a {
+                        return Some((*a, b, &()));
+                    }
+                    continue;
+                }
+            }
+            let (a, set) = self.rows.next()?;
+            self.current = Some((NodeIndex::new(a), set.iter()));
+        }
+    }
+}
+
+/// Index the `BitSetGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, Ty, Ix> Index<NodeIndex<Ix>> for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        &self.nodes[index.index()]
+    }
+}
+
+/// Index the `BitSetGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, Ty, Ix> IndexMut<NodeIndex<Ix>> for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: NodeIndex<Ix>) -> &mut N {
+        &mut self.nodes[index.index()]
+    }
+}
+
+impl<N, Ty, Ix> GraphBase for BitSetGraph<N, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = (NodeIndex<Ix>, NodeIndex<Ix>);
+}
+
+impl<N, Ty, Ix> GraphProp for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeType = Ty;
+}
+
+impl<N, Ty, Ix> Data for BitSetGraph<N, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = ();
+}
+
+impl<N, Ty, Ix> NodeCount for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        self.node_count()
+    }
+}
+
+impl<N, Ty, Ix> EdgeCount for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+impl<N, Ty, Ix> NodeIndexable for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn node_bound(&self) -> usize {
+        self.node_count()
+    }
+    #[inline]
+    fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    #[inline]
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        NodeIndex::new(ix)
+    }
+}
+
+impl<N, Ty, Ix> NodeCompactIndexable for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+
+impl<N, Ty, Ix> Visitable for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Map = FixedBitSet;
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.node_count())
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+        map.grow(self.node_count());
+    }
+}
+
+impl<N, Ty, Ix> NodeDegrees for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        self.neighbor_set_directed(a, dir).len()
+    }
+}
+
+impl<N, Ty, Ix> IntoNodeIdentifiers for &BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeIdentifiers = NodeIdentifiers<Ix>;
+    fn node_identifiers(self) -> NodeIdentifiers<Ix> {
+        self.node_indices()
+    }
+}
+
+impl<'a, N, Ty, Ix> IntoNodeReferences for &'a BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = NodeReferences<'a, N, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences {
+            iter: self.nodes.iter().enumerate(),
+            ix: PhantomData,
+        }
+    }
+}
+
+impl<'a, N, Ty, Ix> IntoEdgeReferences for &'a BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeRef = (NodeIndex<Ix>, NodeIndex<Ix>, &'a ());
+    type EdgeReferences = EdgeReferences<'a, Ty, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        EdgeReferences {
+            rows: self.outgoing.iter().enumerate(),
+            current: None,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'a, N, Ty, Ix> IntoNeighbors for &'a BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Neighbors = Neighbors<'a, Ix>;
+    fn neighbors(self, a: NodeIndex<Ix>) -> Self::Neighbors {
+        BitSetGraph::neighbors(self, a)
+    }
+}
+
+impl<'a, N, Ty, Ix> IntoNeighborsDirected for &'a BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NeighborsDirected = Neighbors<'a, Ix>;
+    fn neighbors_directed(self, a: NodeIndex<Ix>, dir: Direction) -> Self::NeighborsDirected {
+        BitSetGraph::neighbors_directed(self, a, dir)
+    }
+}
+
+impl<'a, N, Ty, Ix> IntoEdges for &'a BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Edges = Edges<'a, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        BitSetGraph::edges(self, a)
+    }
+}
+
+impl<'a, N, Ty, Ix> IntoEdgesDirected for &'a BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgesDirected = Edges<'a, Ix>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        BitSetGraph::edges_directed(self, a, dir)
+    }
+}
+
+/// The `BitSetGraph` is its own adjacency matrix, so `.adjacency_matrix()`
+/// does nothing.
+impl<N, Ty, Ix> GetAdjacencyMatrix for BitSetGraph<N, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type AdjMatrix = ();
+    #[inline]
+    fn adjacency_matrix(&self) {}
+    #[inline]
+    fn is_adjacent(&self, _: &(), a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.contains_edge(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitSetGraph, DiBitSetGraph, UnBitSetGraph};
+    use crate::algo::{has_path_connecting, kosaraju_scc, toposort};
+    use crate::graph::NodeIndex;
+    use crate::visit::{EdgeRef, IntoEdgeReferences, NodeDegrees};
+    use crate::{EdgeType, Incoming, Outgoing};
+
+    fn graph<Ty: EdgeType>(n: usize, edges: &[(usize, usize)]) -> BitSetGraph<usize, Ty> {
+        let mut g = BitSetGraph::new();
+        for i in 0..n {
+            g.add_node(i);
+        }
+        for &(a, b) in edges {
+            g.add_edge(NodeIndex::new(a), NodeIndex::new(b));
+        }
+        g
+    }
+
+    fn pairs<I, E>(edges: I) -> Vec<(usize, usize)>
+    where
+        I: IntoIterator<Item = E>,
+        E: EdgeRef<NodeId = NodeIndex>,
+    {
+        edges
+            .into_iter()
+            .map(|e| (e.source().index(), e.target().index()))
+            .collect()
+    }
+
+    #[test]
+    fn directed() {
+        let mut g: DiBitSetGraph<usize> = graph(4, &[(0, 1), (0, 2), (2, 1), (3, 3), (1, 3)]);
+        let n = NodeIndex::new;
+        assert!(!g.add_edge(n(0), n(2)));
+        assert_eq!(g.edge_count(), 5);
+        assert!(g.contains_edge(n(2), n(1)));
+        assert!(!g.contains_edge(n(1), n(2)));
+        assert_eq!(g.neighbors(n(0)).collect::<Vec<_>>(), vec![n(1), n(2)]);
+        assert_eq!(
+            g.neighbors_directed(n(1), Incoming).collect::<Vec<_>>(),
+            vec![n(0), n(2)]
+        );
+        assert_eq!(
+            pairs(g.edges_directed(n(3), Incoming)),
+            vec![(1, 3), (3, 3)]
+        );
+        assert_eq!(
+            pairs(g.edge_references()),
+            vec![(0, 1), (0, 2), (1, 3), (2, 1), (3, 3)]
+        );
+        assert_eq!(g.in_degree(n(3)), 2);
+        assert_eq!(g.out_degree(n(3)), 1);
+        assert_eq!(g.common_neighbor_count(n(0), n(2)), 1);
+        assert_eq!(kosaraju_scc(&g).len(), 4);
+        assert!(has_path_connecting(&g, n(0), n(3), None));
+
+        assert!(g.remove_edge(n(3), n(3)));
+        assert!(!g.remove_edge(n(3), n(3)));
+        assert_eq!(g.edge_count(), 4);
+        assert_eq!(g.in_degree(n(3)), 1);
+        assert_eq!(toposort(&g, None).unwrap(), vec![n(0), n(2), n(1), n(3)]);
+    }
+
+    #[test]
+    fn undirected() {
+        let mut g: UnBitSetGraph<usize> = graph(4, &[(1, 0), (0, 2), (2, 1), (3, 3), (1, 3)]);
+        let n = NodeIndex::new;
+        assert!(!g.add_edge(n(0), n(1)));
+        assert_eq!(g.edge_count(), 5);
+        assert!(g.contains_edge(n(0), n(1)));
+        assert!(g.contains_edge(n(1), n(0)));
+        assert_eq!(
+            g.neighbors(n(1)).collect::<Vec<_>>(),
+            vec![n(0), n(2), n(3)]
+        );
+        assert_eq!(
+            pairs(g.edges_directed(n(3), Outgoing)),
+            vec![(3, 1), (3, 3)]
+        );
+        assert_eq!(
+            pairs(g.edges_directed(n(3), Incoming)),
+            vec![(1, 3), (3, 3)]
+        );
+        assert_eq!(
+            pairs(g.edge_references()),
+            vec![(0, 1), (0, 2), (1, 2), (1, 3), (3, 3)]
+        );
+        assert_eq!(g.in_degree(n(1)), 3);
+        assert_eq!(
+            g.common_neighbors(n(0), n(1)).iter().collect::<Vec<_>>(),
+            vec![2]
+        );
+
+        assert!(g.remove_edge(n(1), n(0)));
+        assert!(!g.contains_edge(n(0), n(1)));
+        assert_eq!(g.edge_count(), 4);
+        g.clear_edges();
+        assert_eq!(g.edge_count(), 0);
+        assert_eq!(g.neighbors(n(1)).count(), 0);
+    }
+
+    #[test]
+    fn triangles() {
+        // a wheel: a hub connected to every node of a cycle
+        let k = 100_000;
+        let mut edges: Vec<_> = (1..=k).map(|i| (0, i)).collect();
+        edges.extend((1..=k).map(|i| (i, i % k + 1)));
+        let g: UnBitSetGraph<usize> = graph(k + 1, &edges);
+        let triangles: usize = g
+            .edge_references()
+            .map(|(a, b, _)| g.common_neighbor_count(a, b))
+            .sum::<usize>()
+            / 3;
+        assert_eq!(triangles, k);
+    }
+}
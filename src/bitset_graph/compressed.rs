@@ -0,0 +1,490 @@
+//! A compressed bitset of `u32` values, in the manner of roaring bitmaps.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::FromIterator;
+use std::slice;
+
+/// The largest number of values kept in an array container.
+const ARRAY_LIMIT: usize = 4096;
+/// The number of words in a bitmap container.
+const BITMAP_WORDS: usize = 1 << 10;
+
+/// The low 16 bits of the values of a `CompressedBitSet` with the same high
+/// 16 bits.
+#[derive(Clone, PartialEq, Eq)]
+enum Container {
+    /// The values in increasing order, at most `ARRAY_LIMIT` of them.
+    Array(Vec<u16>),
+    /// A bit for each of the 2^16 values, and the number of values.
+    Bitmap(Box<[u64; BITMAP_WORDS]>, usize),
+}
+
+impl Container {
+    fn len(&self) -> usize {
+        match self {
+            Container::Array(values) => values.len(),
+            Container::Bitmap(_, len) => *len,
+        }
+    }
+
+    fn contains(&self, x: u16) -> bool {
+        match self {
+            Container::Array(values) => values.binary_search(&x).is_ok(),
+            Container::Bitmap(words, _) => words[x as usize / 64] & (1 << (x % 64)) != 0,
+        }
+    }
+
+    fn insert(&mut self, x: u16) -> bool {
+        match self {
+            Container::Array(values) => {
+                let i = match values.binary_search(&x) {
+                    Ok(_) => return false,
+                    Err(i) => i,
+                };
+                if values.len() < ARRAY_LIMIT {
+                    values.insert(i, x);
+                    return true;
+                }
+                *self = Container::bitmap(values);
+                self.insert(x)
+            }
+            Container::Bitmap(words, len) => {
+                let word = &mut words[x as usize / 64];
+                let bit = 1 << (x % 64);
+                if *word & bit != 0 {
+                    return false;
+                }
+                *word |= bit;
+                *len += 1;
+                true
+            }
+        }
+    }
+
+    fn remove(&mut self, x: u16) -> bool {
+        match self {
+            Container::Array(values) => match values.binary_search(&x) {
+                Ok(i) => {
+                    values.remove(i);
+                    true
+                }
+                Err(_) => false,
+            },
+            Container::Bitmap(words, len) => {
+                let word = &mut words[x as usize / 64];
+                let bit = 1 << (x % 64);
+                if *word & bit == 0 {
+                    return false;
+                }
+                *word &= !bit;
+                *len -= 1;
+                if *len <= ARRAY_LIMIT {
+                    *self = Container::Array(self.iter().collect());
+                }
+                true
+            }
+        }
+    }
+
+    /// Create a bitmap container of `values`.
+    fn bitmap(values: &[u16]) -> Self {
+        let mut words = Box::new([0; BITMAP_WORDS]);
+        for &x in values {
+            words[x as usize / 64] |= 1 << (x % 64);
+        }
+        Container::Bitmap(words, values.len())
+    }
+
+    /// Create the smallest container of the bitmap `words`.
+    fn from_words(words: Box<[u64; BITMAP_WORDS]>) -> Self {
+        let len = words.iter().map(|w| w.count_ones() as usize).sum();
+        let container = Container::Bitmap(words, len);
+        if len <= ARRAY_LIMIT {
+            Container::Array(container.iter().collect())
+        } else {
+            container
+        }
+    }
+
+    fn iter(&self) -> ContainerIter<'_> {
+        match self {
+            Container::Array(values) => ContainerIter::Array(values.iter()),
+            Container::Bitmap(words, _) => ContainerIter::Bitmap {
+                words,
+                index: 0,
+                word: words[0],
+            },
+        }
+    }
+
+    fn intersection_len(&self, other: &Container) -> usize {
+        match (self, other) {
+            (Container::Array(xs), Container::Array(ys)) => {
+                let (mut i, mut j, mut count) = (0, 0, 0);
+                while i < xs.len() && j < ys.len() {
+                    match xs[i].cmp(&ys[j]) {
+                        Ordering::Less => i += 1,
+                        Ordering::Greater => j += 1,
+                        Ordering::Equal => {
+                            count += 1;
+                            i += 1;
+                            j += 1;
+                        }
+                    }
+                }
+                count
+            }
+            (Container::Array(xs), bitmap) | (bitmap, Container::Array(xs)) => {
+                xs.iter().filter(|&&x| bitmap.contains(x)).count()
+            }
+            (Container::Bitmap(xs, _), Container::Bitmap(ys, _)) => xs
+                .iter()
+                .zip(ys.iter())
+                .map(|(x, y)| (x & y).count_ones() as usize)
+                .sum(),
+        }
+    }
+
+    /// Return the intersection of the containers, or `None` if it is empty.
+    fn intersection(&self, other: &Container) -> Option<Container> {
+        let container = match (self, other) {
+            (Container::Array(xs), _) => {
+                Container::Array(xs.iter().cloned().filter(|&x| other.contains(x)).collect())
+            }
+            (_, Container::Array(ys)) => {
+                Container::Array(ys.iter().cloned().filter(|&y| self.contains(y)).collect())
+            }
+            (Container::Bitmap(xs, _), Container::Bitmap(ys, _)) => {
+                let mut words = xs.clone();
+                for (x, y) in words.iter_mut().zip(ys.iter()) {
+                    *x &= y;
+                }
+                Container::from_words(words)
+            }
+        };
+        if container.len() == 0 {
+            None
+        } else {
+            Some(container)
+        }
+    }
+}
+
+/// Iterator over the values of a container, in increasing order.
+#[derive(Clone)]
+enum ContainerIter<'a> {
+    Array(slice::Iter<'a, u16>),
+    Bitmap {
+        words: &'a [u64; BITMAP_WORDS],
+        index: usize,
+        word: u64,
+    },
+}
+
+impl<'a> Iterator for ContainerIter<'a> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        match self {
+            ContainerIter::Array(iter) => iter.next().cloned(),
+            ContainerIter::Bitmap { words, index, word } => {
+                while *word == 0 {
+                    *index += 1;
+                    *word = *words.get(*index)?;
+                }
+                let bit = word.trailing_zeros() as usize;
+                *word &= *word - 1;
+                Some((*index * 64 + bit) as u16)
+            }
+        }
+    }
+}
+
+/// `CompressedBitSet` is a set of `u32` values, compressed in the manner of
+/// [roaring bitmaps](https://roaringbitmap.org/).
+///
+/// The values are grouped by their high 16 bits, and the low 16 bits of each
+/// group are stored either as a sorted array, for at most 4096 values, or as a
+/// bitmap of all 2^16 values. This takes little space for both sparse and
+/// dense sets, and makes intersections fast: dense groups are intersected a
+/// word at a time, and sparse groups by merging or lookups.
+///
+/// ```
+/// use petgraph::bitset_graph::CompressedBitSet;
+///
+/// let evens: CompressedBitSet = (0..100_000).step_by(2).collect();
+/// let threes: CompressedBitSet = (0..100_000).step_by(3).collect();
+/// assert_eq!(evens.intersection_len(&threes), 16_667);
+/// assert_eq!(evens.intersection(&threes).iter().nth(2), Some(12));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct CompressedBitSet {
+    /// The non-empty containers, sorted by the high bits of their values.
+    containers: Vec<(u16, Container)>,
+    len: usize,
+}
+
+/// Split `x` into its high and low bits.
+fn split(x: u32) -> (u16, u16) {
+    ((x >> 16) as u16, x as u16)
+}
+
+impl CompressedBitSet {
+    /// Create a new empty `CompressedBitSet`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the number of values in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all values.
+    pub fn clear(&mut self) {
+        self.containers.clear();
+        self.len = 0;
+    }
+
+    fn find(&self, high: u16) -> Result<usize, usize> {
+        self.containers.binary_search_by_key(&high, |&(h, _)| h)
+    }
+
+    /// Return `true` if `x` is in the set.
+    ///
+    /// Computes in **O(log n)** time.
+    pub fn contains(&self, x: u32) -> bool {
+        let (high, low) = split(x);
+        match self.find(high) {
+            Ok(i) => self.containers[i].1.contains(low),
+            Err(_) => false,
+        }
+    }
+
+    /// Add `x` to the set, and return `true` if it wasn't in the set before.
+    pub fn insert(&mut self, x: u32) -> bool {
+        let (high, low) = split(x);
+        let i = match self.find(high) {
+            Ok(i) => i,
+            Err(i) => {
+                self.containers
+                    .insert(i, (high, Container::Array(Vec::new())));
+                i
+            }
+        };
+        let inserted = self.containers[i].1.insert(low);
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// Remove `x` from the set, and return `true` if it was in the set.
+    pub fn remove(&mut self, x: u32) -> bool {
+        let (high, low) = split(x);
+        let i = match self.find(high) {
+            Ok(i) => i,
+            Err(_) => return false,
+        };
+        let removed = self.containers[i].1.remove(low);
+        if removed {
+            self.len -= 1;
+            if self.containers[i].1.len() == 0 {
+                self.containers.remove(i);
+            }
+        }
+        removed
+    }
+
+    /// Return an iterator of the values of the set, in increasing order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            containers: self.containers.iter(),
+            current: None,
+        }
+    }
+
+    /// Return the number of values in both `self` and `other`, without
+    /// creating their intersection.
+    pub fn intersection_len(&self, other: &CompressedBitSet) -> usize {
+        self.pairs(other)
+            .map(|(_, x, y)| x.intersection_len(y))
+            .sum()
+    }
+
+    /// Return the set of values in both `self` and `other`.
+    pub fn intersection(&self, other: &CompressedBitSet) -> CompressedBitSet {
+        let mut result = CompressedBitSet::new();
+        for (high, x, y) in self.pairs(other) {
+            if let Some(container) = x.intersection(y) {
+                result.len += container.len();
+                result.containers.push((high, container));
+            }
+        }
+        result
+    }
+
+    /// Remove the values that are not in `other`.
+    pub fn intersect_with(&mut self, other: &CompressedBitSet) {
+        *self = self.intersection(other);
+    }
+
+    /// Return an iterator of the high bits and the pairs of containers of
+    /// `self` and `other` with those high bits.
+    fn pairs<'a>(
+        &'a self,
+        other: &'a CompressedBitSet,
+    ) -> impl Iterator<Item = (u16, &'a Container, &'a Container)> {
+        let mut ys = other.containers.iter().peekable();
+        self.containers.iter().filter_map(move |&(high, ref x)| {
+            while ys.peek().map_or(false, |&&(h, _)| h < high) {
+                ys.next();
+            }
+            match ys.peek() {
+                Some(&&(h, ref y)) if h == high => Some((high, x, y)),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl fmt::Debug for CompressedBitSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<u32> for CompressedBitSet {
+    fn extend<I: IntoIterator<Item = u32>>(&mut self, iter: I) {
+        for x in iter {
+            self.insert(x);
+        }
+    }
+}
+
+impl FromIterator<u32> for CompressedBitSet {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> Self {
+        let mut set = CompressedBitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a CompressedBitSet {
+    type Item = u32;
+    type IntoIter = Iter<'a>;
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the values of a `CompressedBitSet`, in increasing order.
+#[derive(Clone)]
+pub struct Iter<'a> {
+    containers: slice::Iter<'a, (u16, Container)>,
+    current: Option<(u32, ContainerIter<'a>)>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        loop {
+            if let Some((high, iter)) = &mut self.current {
+                if let Some(low) = iter.next() {
+                    return Some(*high << 16 | low as u32);
+                }
+            }
+            let (high, container) = self.containers.next()?;
+            self.current = Some(((*high).into(), container.iter()));
+        }
+    }
+}
+
+impl<'a> fmt::Debug for Iter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CompressedBitSet, Container, ARRAY_LIMIT};
+
+    fn is_bitmap(set: &CompressedBitSet, high: u16) -> bool {
+        let i = set.find(high).unwrap();
+        match set.containers[i].1 {
+            Container::Array(_) => false,
+            Container::Bitmap(..) => true,
+        }
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut set = CompressedBitSet::new();
+        let values = [7, 0, 65_535, 65_536, 1 << 20, std::u32::MAX];
+        for &x in &values {
+            assert!(set.insert(x));
+            assert!(!set.insert(x));
+        }
+        assert_eq!(set.len(), values.len());
+        let mut sorted = values.to_vec();
+        sorted.sort();
+        assert_eq!(set.iter().collect::<Vec<_>>(), sorted);
+        assert!(set.contains(65_536));
+        assert!(!set.contains(65_537));
+
+        assert!(set.remove(65_536));
+        assert!(!set.remove(65_536));
+        assert!(!set.remove(3));
+        assert_eq!(set.len(), values.len() - 1);
+        assert_eq!(set.containers.len(), 3);
+    }
+
+    #[test]
+    fn array_and_bitmap() {
+        let mut set = CompressedBitSet::new();
+        let high = 1 << 16;
+        set.extend((0..ARRAY_LIMIT as u32).map(|x| high + 3 * x));
+        assert!(!is_bitmap(&set, 1));
+        set.insert(high + 1);
+        assert!(is_bitmap(&set, 1));
+        assert_eq!(set.len(), ARRAY_LIMIT + 1);
+        assert!(set.contains(high + 3));
+        assert!(!set.contains(high + 2));
+        let values: Vec<_> = set.iter().collect();
+        assert_eq!(values.len(), ARRAY_LIMIT + 1);
+        assert_eq!(&values[..3], &[high, high + 1, high + 3]);
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+
+        set.remove(high);
+        assert!(!is_bitmap(&set, 1));
+        assert_eq!(
+            set.iter().take(2).collect::<Vec<_>>(),
+            vec![high + 1, high + 3]
+        );
+    }
+
+    #[test]
+    fn intersection() {
+        let dense: CompressedBitSet = (0..200_000).filter(|x| x % 7 != 0).collect();
+        let sparse: CompressedBitSet = (0..200_000).step_by(5).collect();
+        let denser: CompressedBitSet = (0..200_000).filter(|x| x % 2 == 0).collect();
+        for a in &[&dense, &sparse, &denser] {
+            for b in &[&dense, &sparse, &denser] {
+                let expected: Vec<_> = a.iter().filter(|&x| b.contains(x)).collect();
+                let intersection = a.intersection(b);
+                assert_eq!(intersection.iter().collect::<Vec<_>>(), expected);
+                assert_eq!(intersection.len(), expected.len());
+                assert_eq!(a.intersection_len(b), expected.len());
+            }
+        }
+        assert!(sparse.intersection(&CompressedBitSet::new()).is_empty());
+    }
+}
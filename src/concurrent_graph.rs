@@ -0,0 +1,803 @@
+//! `ConcurrentGraph<N, E, Ty, Ix>` is a graph datastructure that threads can
+//! read and add to concurrently.
+
+use std::marker::PhantomData;
+use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use fixedbitset::FixedBitSet;
+
+use crate::data::DataMap;
+use crate::graph::{DefaultIx, IndexType, NodeIndex};
+use crate::visit::{
+    Data, EdgeCount, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges, IntoEdgesDirected,
+    IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences, NodeCount,
+    NodeIndexable, Visitable,
+};
+use crate::{Directed, Direction, EdgeType, Incoming, Outgoing, Undirected};
+
+/// A `ConcurrentGraph` with directed edges.
+pub type DiConcurrentGraph<N, E, Ix = DefaultIx> = ConcurrentGraph<N, E, Directed, Ix>;
+/// A `ConcurrentGraph` with undirected edges.
+pub type UnConcurrentGraph<N, E, Ix = DefaultIx> = ConcurrentGraph<N, E, Undirected, Ix>;
+
+/// The default number of shards of a `ConcurrentGraph`.
+const DEFAULT_SHARDS: usize = 16;
+
+/// A node with its edges.
+#[derive(Debug)]
+struct Node<N, E, Ix> {
+    weight: N,
+    /// The targets and weights of the edges starting at the node.
+    outgoing: Vec<(NodeIndex<Ix>, E)>,
+    /// The sources of the edges ending at the node, and the position of each
+    /// edge in the outgoing edges of its source.
+    incoming: Vec<(NodeIndex<Ix>, usize)>,
+}
+
+/// A shard of the nodes of a `ConcurrentGraph`, with the edges starting at
+/// them.
+#[derive(Debug)]
+struct Shard<N, E, Ix> {
+    nodes: Vec<Node<N, E, Ix>>,
+    edge_count: usize,
+}
+
+/// `ConcurrentGraph<N, E, Ty, Ix>` is a graph datastructure that threads can
+/// read and add nodes and edges to concurrently, through a shared reference.
+///
+/// The nodes are spread over a number of shards, each behind its own
+/// `RwLock`, along with the edges starting at them. Adding a node locks one
+/// shard, and adding an edge locks the one or two shards of its endpoints, so
+/// threads working on different shards don't block each other.
+/// [`add_edges`](#method.add_edges) adds a batch of edges locking each shard
+/// only once.
+///
+/// To run algorithms, take a [`snapshot`](#method.snapshot): it holds the
+/// read locks of all shards, so it sees a consistent graph that doesn't
+/// change while it's alive, and implements the graph traits. Writers wait
+/// until the snapshot is dropped.
+///
+/// `ConcurrentGraph` is parameterized over:
+///
+/// - Associated data `N` for nodes and `E` for edges, of arbitrary type.
+/// - Edge type `Ty` that determines whether the graph edges are directed or
+///   undirected.
+/// - Index type `Ix`, which determines the maximum size of the graph.
+///
+/// Nodes and edges can't be removed. Parallel edges and self loops are
+/// allowed.
+///
+/// Node indices are given out per shard, and node `a` lives in shard
+/// `a.index() % shard_count()`. Nodes added concurrently may leave gaps in
+/// the indices until the other shards catch up, so use
+/// [`node_bound`](struct.Snapshot.html#method.node_bound) rather than the
+/// node count as the bound of the indices.
+///
+/// ```
+/// use petgraph::algo::dijkstra;
+/// use petgraph::concurrent_graph::DiConcurrentGraph;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let g = Arc::new(DiConcurrentGraph::<usize, u32>::new());
+/// let hub = g.add_node(0);
+/// let threads: Vec<_> = (1..5)
+///     .map(|i| {
+///         let g = g.clone();
+///         thread::spawn(move || {
+///             let a = g.add_node(i);
+///             g.add_edge(hub, a, i as u32);
+///         })
+///     })
+///     .collect();
+/// for t in threads {
+///     t.join().unwrap();
+/// }
+///
+/// let snapshot = g.snapshot();
+/// assert_eq!(snapshot.edge_count(), 4);
+/// let distances = dijkstra(&snapshot, hub, None, |(_, _, &w)| w);
+/// assert_eq!(distances.values().max(), Some(&4));
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    shards: Vec<RwLock<Shard<N, E, Ix>>>,
+    /// The shard of the next node, modulo the number of shards.
+    next_shard: AtomicUsize,
+    ty: PhantomData<fn() -> Ty>,
+}
+
+/// Create a new empty `ConcurrentGraph`.
+impl<N, E, Ty, Ix> Default for ConcurrentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::with_shards(DEFAULT_SHARDS)
+    }
+}
+
+impl<N, E, Ty, Ix> ConcurrentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new empty `ConcurrentGraph`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new empty `ConcurrentGraph` with `shards` shards.
+    ///
+    /// More shards let more threads add to the graph at the same time, and
+    /// make taking a snapshot slower.
+    ///
+    /// **Panics** if `shards` is zero.
+    pub fn with_shards(shards: usize) -> Self {
+        assert!(
+            shards > 0,
+            "ConcurrentGraph: the number of shards must be positive"
+        );
+        ConcurrentGraph {
+            shards: (0..shards)
+                .map(|_| {
+                    RwLock::new(Shard {
+                        nodes: Vec::new(),
+                        edge_count: 0,
+                    })
+                })
+                .collect(),
+            next_shard: AtomicUsize::new(0),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return the number of shards.
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Whether the graph has directed edges.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Return the number of nodes in the graph.
+    ///
+    /// Other threads may add nodes at the same time, so the count is only
+    /// exact if there are none.
+    pub fn node_count(&self) -> usize {
+        (0..self.shards.len())
+            .map(|s| self.read(s).nodes.len())
+            .sum()
+    }
+
+    /// Return the number of edges in the graph.
+    ///
+    /// Other threads may add edges at the same time, so the count is only
+    /// exact if there are none.
+    pub fn edge_count(&self) -> usize {
+        (0..self.shards.len())
+            .map(|s| self.read(s).edge_count)
+            .sum()
+    }
+
+    /// Add a node with associated data `weight` to the graph, and return its
+    /// index.
+    ///
+    /// **Panics** if the index doesn't fit the index type.
+    pub fn add_node(&self, weight: N) -> NodeIndex<Ix> {
+        let s = self.next_shard.fetch_add(1, Ordering::Relaxed) % self.shards.len();
+        let mut shard = self.write(s);
+        let index = shard.nodes.len() * self.shards.len() + s;
+        assert!(
+            index < <Ix as IndexType>::max().index() || <Ix as IndexType>::max().index() == !0,
+            "ConcurrentGraph: node index exceeds the index type"
+        );
+        shard.nodes.push(Node {
+            weight,
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        });
+        NodeIndex::new(index)
+    }
+
+    /// Add an edge from `a` to `b` with associated data `weight`.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    pub fn add_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) {
+        self.add_edges(Some((a, b, weight)));
+    }
+
+    /// Add a batch of edges, each as `(source, target, weight)`, and return
+    /// how many were added.
+    ///
+    /// The shards of the endpoints are locked together, each once, so the
+    /// batch shows up at once in snapshots, and batches over different
+    /// shards are added in parallel.
+    ///
+    /// **Panics** if any of the nodes don't exist, without adding any edges.
+    pub fn add_edges<I>(&self, edges: I) -> usize
+    where
+        I: IntoIterator<Item = (NodeIndex<Ix>, NodeIndex<Ix>, E)>,
+    {
+        let edges: Vec<_> = edges.into_iter().collect();
+        let n = self.shards.len();
+        let mut locked = vec![false; n];
+        for &(a, b, _) in &edges {
+            locked[a.index() % n] = true;
+            locked[b.index() % n] = true;
+        }
+        // lock in order of the shards, so that writers can't deadlock
+        let mut shards: Vec<_> = (0..n)
+            .map(|s| if locked[s] { Some(self.write(s)) } else { None })
+            .collect();
+        for &(a, b, _) in &edges {
+            for &x in &[a, b] {
+                let shard = shards[x.index() % n].as_ref().unwrap();
+                assert!(
+                    x.index() / n < shard.nodes.len(),
+                    "ConcurrentGraph: node index {} is out of bounds",
+                    x.index()
+                );
+            }
+        }
+        let count = edges.len();
+        for (a, b, weight) in edges {
+            let source = shards[a.index() % n].as_mut().unwrap();
+            let outgoing = &mut source.nodes[a.index() / n].outgoing;
+            let position = outgoing.len();
+            outgoing.push((b, weight));
+            source.edge_count += 1;
+            let target = shards[b.index() % n].as_mut().unwrap();
+            target.nodes[b.index() / n].incoming.push((a, position));
+        }
+        count
+    }
+
+    /// Return a clone of the weight of node `a`, or `None` if it doesn't
+    /// exist.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<N>
+    where
+        N: Clone,
+    {
+        self.with_node(a, |node| node.weight.clone())
+    }
+
+    /// Replace the weight of node `a` with `weight`, and return the old
+    /// weight, or `None` if the node doesn't exist.
+    pub fn set_node_weight(&self, a: NodeIndex<Ix>, weight: N) -> Option<N> {
+        let n = self.shards.len();
+        let mut shard = self.write(a.index() % n);
+        let node = shard.nodes.get_mut(a.index() / n)?;
+        Some(std::mem::replace(&mut node.weight, weight))
+    }
+
+    /// Return `true` if node `a` exists.
+    pub fn contains_node(&self, a: NodeIndex<Ix>) -> bool {
+        self.with_node(a, |_| ()).is_some()
+    }
+
+    /// Return `true` if there is an edge from `a` to `b`, or for an
+    /// undirected graph, an edge between them.
+    pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.neighbors(a).contains(&b)
+    }
+
+    /// Return the neighbors of `a`, as they are when called.
+    ///
+    /// - `Directed`: Targets of outgoing edges from `a`.
+    /// - `Undirected`: All other endpoints of edges of `a`.
+    ///
+    /// Produces an empty list if the node doesn't exist.
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Vec<NodeIndex<Ix>> {
+        self.neighbors_directed(a, Outgoing)
+    }
+
+    /// Return the neighbors of `a` in the direction `dir`, as they are when
+    /// called.
+    ///
+    /// Produces an empty list if the node doesn't exist.
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Vec<NodeIndex<Ix>> {
+        self.with_node(a, |node| {
+            let outgoing = node.outgoing.iter().map(|&(b, _)| b);
+            let incoming = node.incoming.iter().map(|&(b, _)| b);
+            match (Ty::is_directed(), dir) {
+                (true, Outgoing) => outgoing.collect(),
+                (true, Incoming) => incoming.collect(),
+                // an undirected self loop is listed once
+                (false, _) => outgoing.chain(incoming.filter(|&b| b != a)).collect(),
+            }
+        })
+        .unwrap_or_default()
+    }
+
+    /// Take a snapshot of the graph, to read it and run algorithms on it.
+    ///
+    /// The snapshot holds the read locks of all shards, so threads adding to
+    /// the graph wait until it is dropped.
+    pub fn snapshot(&self) -> Snapshot<'_, N, E, Ty, Ix> {
+        let shards: Vec<_> = (0..self.shards.len()).map(|s| self.read(s)).collect();
+        let n = shards.len();
+        let node_bound = shards
+            .iter()
+            .enumerate()
+            .filter(|(_, shard)| !shard.nodes.is_empty())
+            .map(|(s, shard)| (shard.nodes.len() - 1) * n + s + 1)
+            .max()
+            .unwrap_or(0);
+        Snapshot {
+            node_count: shards.iter().map(|shard| shard.nodes.len()).sum(),
+            edge_count: shards.iter().map(|shard| shard.edge_count).sum(),
+            node_bound,
+            shards,
+            ty: PhantomData,
+        }
+    }
+
+    fn with_node<F, R>(&self, a: NodeIndex<Ix>, f: F) -> Option<R>
+    where
+        F: FnOnce(&Node<N, E, Ix>) -> R,
+    {
+        let n = self.shards.len();
+        let shard = self.read(a.index() % n);
+        shard.nodes.get(a.index() / n).map(f)
+    }
+
+    // The shards are always consistent between operations, since no user
+    // code runs while they are locked, so a poisoned lock is ignored.
+    fn read(&self, s: usize) -> RwLockReadGuard<'_, Shard<N, E, Ix>> {
+        self.shards[s]
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self, s: usize) -> RwLockWriteGuard<'_, Shard<N, E, Ix>> {
+        self.shards[s]
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A consistent view of a [`ConcurrentGraph`](struct.ConcurrentGraph.html),
+/// that holds the read locks of all its shards.
+///
+/// Edges are identified by their endpoints, and edge references are
+/// `(source, target, &weight)`, as for `GraphMap`.
+#[derive(Debug)]
+pub struct Snapshot<'a, N: 'a, E: 'a, Ty, Ix: 'a> {
+    shards: Vec<RwLockReadGuard<'a, Shard<N, E, Ix>>>,
+    node_count: usize,
+    edge_count: usize,
+    node_bound: usize,
+    ty: PhantomData<fn() -> Ty>,
+}
+
+impl<'a, N, E, Ty, Ix> Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Return an upper bound of the node indices in the graph.
+    pub fn node_bound(&self) -> usize {
+        self.node_bound
+    }
+
+    fn node(&self, a: NodeIndex<Ix>) -> Option<&Node<N, E, Ix>> {
+        let n = self.shards.len();
+        self.shards[a.index() % n].nodes.get(a.index() / n)
+    }
+
+    /// Return `true` if node `a` exists.
+    pub fn contains_node(&self, a: NodeIndex<Ix>) -> bool {
+        self.node(a).is_some()
+    }
+
+    /// Return a reference to the weight of node `a`, or `None` if it doesn't
+    /// exist.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.node(a).map(|node| &node.weight)
+    }
+
+    /// Return a reference to the weight of the first edge from `a` to `b`,
+    /// or for an undirected graph, between them, or `None` if there is none.
+    pub fn edge_weight(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<&E> {
+        self.edges(a).find(|e| e.1 == b).map(|e| e.2)
+    }
+
+    /// Return an iterator over the node indices of the graph.
+    pub fn node_indices(&self) -> NodeIndices<'_, 'a, N, E, Ty, Ix> {
+        NodeIndices {
+            snapshot: self,
+            next: 0,
+        }
+    }
+
+    /// Return an iterator of the neighbors of `a`.
+    ///
+    /// - `Directed`: Targets of outgoing edges from `a`.
+    /// - `Undirected`: All other endpoints of edges of `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Neighbors<'_, 'a, N, E, Ty, Ix> {
+        self.neighbors_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of the neighbors of `a` in the direction `dir`.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors_directed(
+        &self,
+        a: NodeIndex<Ix>,
+        dir: Direction,
+    ) -> Neighbors<'_, 'a, N, E, Ty, Ix> {
+        Neighbors(self.edges_directed(a, dir))
+    }
+
+    /// Return an iterator of the edges of `a`, as `(source, target,
+    /// &weight)`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges of `a`, with `a` as the source.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `(NodeIndex<Ix>, NodeIndex<Ix>, &E)`.
+    pub fn edges(&self, a: NodeIndex<Ix>) -> Edges<'_, 'a, N, E, Ty, Ix> {
+        self.edges_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of the edges of `a` in the direction `dir`, as
+    /// `(source, target, &weight)`.
+    ///
+    /// - `Outgoing`: Edges with `a` as the source.
+    /// - `Incoming`: Edges with `a` as the target.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `(NodeIndex<Ix>, NodeIndex<Ix>, &E)`.
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Edges<'_, 'a, N, E, Ty, Ix> {
+        let node = match self.node(a) {
+            Some(node) => node,
+            None => panic!("ConcurrentGraph: node index {} is out of bounds", a.index()),
+        };
+        let (outgoing, incoming) = match (Ty::is_directed(), dir) {
+            (true, Outgoing) => (&node.outgoing[..], &[][..]),
+            (true, Incoming) => (&[][..], &node.incoming[..]),
+            (false, _) => (&node.outgoing[..], &node.incoming[..]),
+        };
+        Edges {
+            snapshot: self,
+            node: a,
+            dir,
+            outgoing: outgoing.iter(),
+            incoming: incoming.iter(),
+        }
+    }
+}
+
+/// Iterator over the node indices of a snapshot.
+#[derive(Debug, Clone)]
+pub struct NodeIndices<'b, 'a: 'b, N: 'a, E: 'a, Ty: 'b, Ix: 'a> {
+    snapshot: &'b Snapshot<'a, N, E, Ty, Ix>,
+    next: usize,
+}
+
+impl<'b, 'a, N, E, Ty, Ix> Iterator for NodeIndices<'b, 'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        while self.next < self.snapshot.node_bound {
+            let a = NodeIndex::new(self.next);
+            self.next += 1;
+            if self.snapshot.contains_node(a) {
+                return Some(a);
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over the nodes of a snapshot, as `(index, &weight)`.
+#[derive(Debug, Clone)]
+pub struct NodeReferences<'b, 'a: 'b, N: 'a, E: 'a, Ty: 'b, Ix: 'a> {
+    nodes: NodeIndices<'b, 'a, N, E, Ty, Ix>,
+}
+
+impl<'b, 'a, N, E, Ty, Ix> Iterator for NodeReferences<'b, 'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = (NodeIndex<Ix>, &'b N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.nodes.next()?;
+        let snapshot: &'b Snapshot<'a, N, E, Ty, Ix> = self.nodes.snapshot;
+        Some((a, &snapshot.node(a).unwrap().weight))
+    }
+}
+
+/// Iterator over the edges of a node in a snapshot.
+#[derive(Debug, Clone)]
+pub struct Edges<'b, 'a: 'b, N: 'a, E: 'a, Ty: 'b, Ix: 'a> {
+    snapshot: &'b Snapshot<'a, N, E, Ty, Ix>,
+    node: NodeIndex<Ix>,
+    dir: Direction,
+    outgoing: slice::Iter<'b, (NodeIndex<Ix>, E)>,
+    incoming: slice::Iter<'b, (NodeIndex<Ix>, usize)>,
+}
+
+impl<'b, 'a, N, E, Ty, Ix> Iterator for Edges<'b, 'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = (NodeIndex<Ix>, NodeIndex<Ix>, &'b E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let a = self.node;
+        let (b, weight) = match self.outgoing.next() {
+            Some((b, weight)) => (*b, weight),
+            None => loop {
+                let &(b, position) = self.incoming.next()?;
+                // an undirected self loop is listed once, as outgoing
+                if Ty::is_directed() || b != a {
+                    let snapshot: &'b Snapshot<'a, N, E, Ty, Ix> = self.snapshot;
+                    break (b, &snapshot.node(b).unwrap().outgoing[position].1);
+                }
+            },
+        };
+        Some(match self.dir {
+            Outgoing => (a, b, weight),
+            Incoming => (b, a, weight),
+        })
+    }
+}
+
+/// Iterator over the neighbors of a node in a snapshot.
+#[derive(Debug, Clone)]
+pub struct Neighbors<'b, 'a: 'b, N: 'a, E: 'a, Ty: 'b, Ix: 'a>(Edges<'b, 'a, N, E, Ty, Ix>);
+
+impl<'b, 'a, N, E, Ty, Ix> Iterator for Neighbors<'b, 'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        let a = self.0.node;
+        self.0
+            .next()
+            .map(|(source, target, _)| if source == a { target } else { source })
+    }
+}
+
+/// Iterator over all edges of a snapshot, as `(source, target, &weight)`.
+///
+/// The edges of an undirected graph are listed once.
+#[derive(Debug, Clone)]
+pub struct EdgeReferences<'b, 'a: 'b, N: 'a, E: 'a, Ty: 'b, Ix: 'a> {
+    nodes: NodeIndices<'b, 'a, N, E, Ty, Ix>,
+    #[allow(clippy::type_complexity)]
+    current: Option<(NodeIndex<Ix>, slice::Iter<'b, (NodeIndex<Ix>, E)>)>,
+}
+
+impl<'b, 'a, N, E, Ty, Ix> Iterator for EdgeReferences<'b, 'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = (NodeIndex<Ix>, NodeIndex<Ix>, &'b E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((a, iter)) = &mut self.current {
+                if let Some((b, weight)) = iter.next() {
+                    return Some((*a, *b, weight));
+                }
+            }
+            let a = self.nodes.next()?;
+            let snapshot: &'b Snapshot<'a, N, E, Ty, Ix> = self.nodes.snapshot;
+            self.current = Some((a, snapshot.node(a).unwrap().outgoing.iter()));
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> GraphBase for Snapshot<'a, N, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = (NodeIndex<Ix>, NodeIndex<Ix>);
+}
+
+impl<'a, N, E, Ty, Ix> GraphProp for Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeType = Ty;
+}
+
+impl<'a, N, E, Ty, Ix> Data for Snapshot<'a, N, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<'a, N, E, Ty, Ix> DataMap for Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_weight(&self, a: Self::NodeId) -> Option<&N> {
+        self.node_weight(a)
+    }
+    fn edge_weight(&self, (a, b): Self::EdgeId) -> Option<&E> {
+        if self.contains_node(a) {
+            self.edge_weight(a, b)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> NodeCount for Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        self.node_count()
+    }
+}
+
+impl<'a, N, E, Ty, Ix> EdgeCount for Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+impl<'a, N, E, Ty, Ix> NodeIndexable for Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn node_bound(&self) -> usize {
+        self.node_bound()
+    }
+    #[inline]
+    fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    #[inline]
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        NodeIndex::new(ix)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> Visitable for Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Map = FixedBitSet;
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.node_bound())
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+        map.grow(self.node_bound());
+    }
+}
+
+impl<'b, 'a, N, E, Ty, Ix> IntoNodeIdentifiers for &'b Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeIdentifiers = NodeIndices<'b, 'a, N, E, Ty, Ix>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.node_indices()
+    }
+}
+
+impl<'b, 'a, N, E, Ty, Ix> IntoNodeReferences for &'b Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'b N);
+    type NodeReferences = NodeReferences<'b, 'a, N, E, Ty, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences {
+            nodes: self.node_indices(),
+        }
+    }
+}
+
+impl<'b, 'a, N, E, Ty, Ix> IntoEdgeReferences for &'b Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeRef = (NodeIndex<Ix>, NodeIndex<Ix>, &'b E);
+    type EdgeReferences = EdgeReferences<'b, 'a, N, E, Ty, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        EdgeReferences {
+            nodes: self.node_indices(),
+            current: None,
+        }
+    }
+}
+
+impl<'b, 'a, N, E, Ty, Ix> IntoNeighbors for &'b Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Neighbors = Neighbors<'b, 'a, N, E, Ty, Ix>;
+    fn neighbors(self, a: NodeIndex<Ix>) -> Self::Neighbors {
+        Snapshot::neighbors(self, a)
+    }
+}
+
+impl<'b, 'a, N, E, Ty, Ix> IntoNeighborsDirected for &'b Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NeighborsDirected = Neighbors<'b, 'a, N, E, Ty, Ix>;
+    fn neighbors_directed(self, a: NodeIndex<Ix>, dir: Direction) -> Self::NeighborsDirected {
+        Snapshot::neighbors_directed(self, a, dir)
+    }
+}
+
+impl<'b, 'a, N, E, Ty, Ix> IntoEdges for &'b Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Edges = Edges<'b, 'a, N, E, Ty, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        Snapshot::edges(self, a)
+    }
+}
+
+impl<'b, 'a, N, E, Ty, Ix> IntoEdgesDirected for &'b Snapshot<'a, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgesDirected = Edges<'b, 'a, N, E, Ty, Ix>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        Snapshot::edges_directed(self, a, dir)
+    }
+}
@@ -0,0 +1,357 @@
+//! Conversions between the graph types.
+//!
+//! Any two of [`Graph`], [`StableGraph`], [`GraphMap`], [`MatrixGraph`],
+//! [`Csr`] and [`adj::List`] with the same edge type convert into each other,
+//! with `From` where every graph of the source type fits the target type, and
+//! with `TryFrom` otherwise, failing with a [`ConversionError`]:
+//!
+//! | into           | fails for                                  |
+//! |----------------|--------------------------------------------|
+//! | `Graph`        | —                                          |
+//! | `StableGraph`  | —                                          |
+//! | `GraphMap`     | parallel edges, nodes with equal weights   |
+//! | `MatrixGraph`  | parallel edges                             |
+//! | `Csr`          | parallel edges                             |
+//! | `adj::List`    | —                                          |
+//!
+//! `GraphMap`, `MatrixGraph` and `Csr` have no parallel edges, so converting
+//! one of them into a `MatrixGraph` or `Csr` uses `From`. `adj::List` is
+//! always directed, so it converts only with directed graphs. Both graphs must
+//! have the same index type, which for `MatrixGraph` is `u16` by default,
+//! except that `GraphMap` converts with any index type.
+//!
+//! The nodes keep their order, and their indices if both graph types have
+//! indices:
+//!
+//! - `StableGraph` and `MatrixGraph` keep their vacant node indices between
+//!   each other; into other graph types, the nodes are numbered consecutively.
+//! - A `GraphMap` numbers its nodes in the order they are listed by
+//!   [`nodes`](../graphmap/struct.GraphMap.html#method.nodes), and its node
+//!   weights are the nodes themselves.
+//! - An `adj::List` has no node weights: converting into it drops them, and
+//!   converting from it gives `()` weights, or for a `GraphMap`, the node
+//!   indices as its nodes.
+//!
+//! The edges are listed in the order of the source graph, where `Csr` lists
+//! them by source and target, and each undirected edge of a `Csr` once,
+//! from the smaller node index. `Csr` needs `E: Clone` to be created.
+//!
+//! ```
+//! # #[cfg(all(feature = "graphmap", feature = "matrix_graph", feature = "stable_graph"))] {
+//! use std::convert::TryFrom;
+//!
+//! use petgraph::convert::ConversionError;
+//! use petgraph::csr::Csr;
+//! use petgraph::graphmap::DiGraphMap;
+//! use petgraph::matrix_graph::DiMatrix;
+//! use petgraph::prelude::*;
+//!
+//! let g = DiGraph::<&str, u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (1, 2, 3)]);
+//! assert_eq!(
+//!     DiMatrix::<&str, u32, Option<u32>, u32>::try_from(g).err(),
+//!     Some(ConversionError::ParallelEdges)
+//! );
+//!
+//! let g = DiGraph::<&str, u32>::from_edges(&[(0, 1, 1), (1, 2, 2)]);
+//! let csr = Csr::<&str, u32>::try_from(g).unwrap();
+//! let matrix = DiMatrix::<&str, u32, Option<u32>, u32>::from(csr);
+//! let mut stable = StableDiGraph::<&str, u32>::from(matrix);
+//! stable[NodeIndex::new(0)] = "a";
+//! stable[NodeIndex::new(1)] = "b";
+//! stable[NodeIndex::new(2)] = "c";
+//! let map = DiGraphMap::<&str, u32>::try_from(stable).unwrap();
+//! assert_eq!(map.edge_weight("b", "c"), Some(&2));
+//! # }
+//! ```
+//!
+//! [`Graph`]: ../graph/struct.Graph.html
+//! [`StableGraph`]: ../stable_graph/struct.StableGraph.html
+//! [`GraphMap`]: ../graphmap/struct.GraphMap.html
+//! [`MatrixGraph`]: ../matrix_graph/struct.MatrixGraph.html
+//! [`Csr`]: ../csr/struct.Csr.html
+//! [`adj::List`]: ../adj/struct.List.html
+//! [`ConversionError`]: enum.ConversionError.html
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+use crate::adj::List;
+use crate::csr::Csr;
+use crate::graph::{Graph, IndexType};
+use crate::Directed;
+#[cfg(any(
+    feature = "graphmap",
+    feature = "matrix_graph",
+    feature = "stable_graph"
+))]
+use crate::EdgeType;
+
+#[cfg(feature = "graphmap")]
+use crate::graphmap::{GraphMap, NodeTrait};
+#[cfg(feature = "graphmap")]
+use std::hash::BuildHasher;
+
+#[cfg(feature = "matrix_graph")]
+use crate::matrix_graph::{MatrixGraph, Nullable};
+
+#[cfg(feature = "stable_graph")]
+use crate::stable_graph::StableGraph;
+
+/// The reason a graph doesn't fit another graph type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The graph has parallel edges, and the graph type doesn't allow them.
+    ParallelEdges,
+    /// The graph has nodes with equal weights, and the graph type uses the
+    /// weights to tell its nodes apart.
+    DuplicateNodes,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConversionError::ParallelEdges => {
+                f.write_str("the graph has parallel edges, which the graph type doesn't allow")
+            }
+            ConversionError::DuplicateNodes => f.write_str(
+                "the graph has nodes with equal weights, which the graph type doesn't allow",
+            ),
+        }
+    }
+}
+
+impl Error for ConversionError {}
+
+/// Convert `graph` through a `Graph`, into a graph type that can hold every
+/// graph of the source type.
+fn through_graph<T, U, N, E, Ty, Ix>(graph: T) -> U
+where
+    T: Into<Graph<N, E, Ty, Ix>>,
+    U: TryFrom<Graph<N, E, Ty, Ix>>,
+{
+    match U::try_from(graph.into()) {
+        Ok(graph) => graph,
+        Err(_) => unreachable!("the graph fits the graph type"),
+    }
+}
+
+/// Implement `From` or `TryFrom` from one graph type into another, through a
+/// `Graph`.
+macro_rules! convert_through_graph {
+    (
+        $(#[$attr:meta])*
+        impl <$($param:ident),*> $conversion:ident<$from:ty> for $to:ty
+        where { $($bounds:tt)* }
+        graph: $graph:ty
+    ) => {
+        convert_through_graph!(@impl $conversion $(#[$attr])*
+            [$($param),*] $from, $to, $graph, [$($bounds)*]);
+    };
+    (@impl From $(#[$attr:meta])*
+        [$($param:ident),*] $from:ty, $to:ty, $graph:ty, [$($bounds:tt)*]) => {
+        $(#[$attr])*
+        impl<$($param),*> From<$from> for $to
+        where
+            $($bounds)*
+        {
+            fn from(graph: $from) -> Self {
+                through_graph::<_, _, _, _, _, _>(<$graph>::from(graph))
+            }
+        }
+    };
+    (@impl TryFrom $(#[$attr:meta])*
+        [$($param:ident),*] $from:ty, $to:ty, $graph:ty, [$($bounds:tt)*]) => {
+        $(#[$attr])*
+        impl<$($param),*> TryFrom<$from> for $to
+        where
+            $($bounds)*
+        {
+            type Error = ConversionError;
+            fn try_from(graph: $from) -> Result<Self, ConversionError> {
+                Self::try_from(<$graph>::from(graph))
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "stable_graph", feature = "graphmap"))]
+convert_through_graph! {
+    /// Convert a `StableGraph` into a `GraphMap`, failing if it has parallel
+    /// edges or nodes with equal weights.
+    impl<N, E, Ty, Ix, S> TryFrom<StableGraph<N, E, Ty, Ix>> for GraphMap<N, E, Ty, S>
+    where { N: NodeTrait, Ty: EdgeType, Ix: IndexType, S: BuildHasher + Default }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(all(feature = "stable_graph", feature = "graphmap"))]
+convert_through_graph! {
+    /// Convert a `GraphMap` into a `StableGraph`, with its nodes numbered in
+    /// order.
+    impl<N, E, Ty, Ix, S> From<GraphMap<N, E, Ty, S>> for StableGraph<N, E, Ty, Ix>
+    where { N: NodeTrait, Ty: EdgeType, Ix: IndexType, S: BuildHasher }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(feature = "stable_graph")]
+convert_through_graph! {
+    /// Convert a `StableGraph` into a `Csr`, failing if it has parallel
+    /// edges.
+    impl<N, E, Ty, Ix> TryFrom<StableGraph<N, E, Ty, Ix>> for Csr<N, E, Ty, Ix>
+    where { E: Clone, Ty: EdgeType, Ix: IndexType }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(feature = "stable_graph")]
+convert_through_graph! {
+    /// Convert a `Csr` into a `StableGraph`.
+    impl<N, E, Ty, Ix> From<Csr<N, E, Ty, Ix>> for StableGraph<N, E, Ty, Ix>
+    where { Ty: EdgeType, Ix: IndexType }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(feature = "stable_graph")]
+convert_through_graph! {
+    /// Convert a `StableGraph` into an `adj::List`, dropping the node
+    /// weights.
+    impl<N, E, Ix> From<StableGraph<N, E, Directed, Ix>> for List<E, Ix>
+    where { Ix: IndexType }
+    graph: Graph<N, E, Directed, Ix>
+}
+
+#[cfg(feature = "stable_graph")]
+convert_through_graph! {
+    /// Convert an `adj::List` into a `StableGraph`.
+    impl<E, Ix> From<List<E, Ix>> for StableGraph<(), E, Directed, Ix>
+    where { Ix: IndexType }
+    graph: Graph<(), E, Directed, Ix>
+}
+
+#[cfg(all(feature = "graphmap", feature = "matrix_graph"))]
+convert_through_graph! {
+    /// Convert a `GraphMap` into a `MatrixGraph`, with its nodes numbered in
+    /// order.
+    impl<N, E, Ty, S, Null, Ix> From<GraphMap<N, E, Ty, S>> for MatrixGraph<N, E, Ty, Null, Ix>
+    where {
+        N: NodeTrait,
+        Ty: EdgeType,
+        S: BuildHasher,
+        Null: Nullable<Wrapped = E>,
+        Ix: IndexType,
+    }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(all(feature = "graphmap", feature = "matrix_graph"))]
+convert_through_graph! {
+    /// Convert a `MatrixGraph` into a `GraphMap`, failing if it has nodes
+    /// with equal weights.
+    impl<N, E, Ty, S, Null, Ix> TryFrom<MatrixGraph<N, E, Ty, Null, Ix>> for GraphMap<N, E, Ty, S>
+    where {
+        N: NodeTrait,
+        Ty: EdgeType,
+        S: BuildHasher + Default,
+        Null: Nullable<Wrapped = E>,
+        Ix: IndexType,
+    }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(feature = "graphmap")]
+convert_through_graph! {
+    /// Convert a `GraphMap` into a `Csr`, with its nodes numbered in order.
+    impl<N, E, Ty, S, Ix> From<GraphMap<N, E, Ty, S>> for Csr<N, E, Ty, Ix>
+    where { N: NodeTrait, E: Clone, Ty: EdgeType, S: BuildHasher, Ix: IndexType }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(feature = "graphmap")]
+convert_through_graph! {
+    /// Convert a `Csr` into a `GraphMap`, failing if it has nodes with equal
+    /// weights.
+    impl<N, E, Ty, S, Ix> TryFrom<Csr<N, E, Ty, Ix>> for GraphMap<N, E, Ty, S>
+    where { N: NodeTrait, Ty: EdgeType, S: BuildHasher + Default, Ix: IndexType }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(feature = "graphmap")]
+convert_through_graph! {
+    /// Convert a `GraphMap` into an `adj::List`, with its nodes numbered in
+    /// order and without their weights.
+    impl<N, E, S, Ix> From<GraphMap<N, E, Directed, S>> for List<E, Ix>
+    where { N: NodeTrait, S: BuildHasher, Ix: IndexType }
+    graph: Graph<N, E, Directed, Ix>
+}
+
+/// Convert an `adj::List` into a `GraphMap` with the node indices as nodes,
+/// failing if it has parallel edges.
+#[cfg(feature = "graphmap")]
+impl<E, Ix, S> TryFrom<List<E, Ix>> for GraphMap<Ix, E, Directed, S>
+where
+    Ix: IndexType + NodeTrait,
+    S: BuildHasher + Default,
+{
+    type Error = ConversionError;
+
+    fn try_from(list: List<E, Ix>) -> Result<Self, ConversionError> {
+        let (nodes, edges) = Graph::from(list).into_nodes_edges();
+        let mut graph = Graph::<Ix, E, Directed, Ix>::with_capacity(nodes.len(), edges.len());
+        for i in 0..nodes.len() {
+            graph.add_node(Ix::new(i));
+        }
+        for edge in edges {
+            graph.add_edge(edge.source(), edge.target(), edge.weight);
+        }
+        Self::try_from(graph)
+    }
+}
+
+#[cfg(feature = "matrix_graph")]
+convert_through_graph! {
+    /// Convert a `MatrixGraph` into a `Csr`, with its nodes numbered
+    /// consecutively.
+    impl<N, E, Ty, Null, Ix> From<MatrixGraph<N, E, Ty, Null, Ix>> for Csr<N, E, Ty, Ix>
+    where { E: Clone, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(feature = "matrix_graph")]
+convert_through_graph! {
+    /// Convert a `Csr` into a `MatrixGraph`.
+    impl<N, E, Ty, Null, Ix> From<Csr<N, E, Ty, Ix>> for MatrixGraph<N, E, Ty, Null, Ix>
+    where { Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType }
+    graph: Graph<N, E, Ty, Ix>
+}
+
+#[cfg(feature = "matrix_graph")]
+convert_through_graph! {
+    /// Convert a `MatrixGraph` into an `adj::List`, with its nodes numbered
+    /// consecutively and without their weights.
+    impl<N, E, Null, Ix> From<MatrixGraph<N, E, Directed, Null, Ix>> for List<E, Ix>
+    where { Null: Nullable<Wrapped = E>, Ix: IndexType }
+    graph: Graph<N, E, Directed, Ix>
+}
+
+#[cfg(feature = "matrix_graph")]
+convert_through_graph! {
+    /// Convert an `adj::List` into a `MatrixGraph`, failing if it has
+    /// parallel edges.
+    impl<E, Null, Ix> TryFrom<List<E, Ix>> for MatrixGraph<(), E, Directed, Null, Ix>
+    where { Null: Nullable<Wrapped = E>, Ix: IndexType }
+    graph: Graph<(), E, Directed, Ix>
+}
+
+convert_through_graph! {
+    /// Convert a `Csr` into an `adj::List`, dropping the node weights.
+    impl<N, E, Ix> From<Csr<N, E, Directed, Ix>> for List<E, Ix>
+    where { Ix: IndexType }
+    graph: Graph<N, E, Directed, Ix>
+}
+
+convert_through_graph! {
+    /// Convert an `adj::List` into a `Csr`, failing if it has parallel
+    /// edges.
+    impl<E, Ix> TryFrom<List<E, Ix>> for Csr<(), E, Directed, Ix>
+    where { E: Clone, Ix: IndexType }
+    graph: Graph<(), E, Directed, Ix>
+}
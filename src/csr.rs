@@ -1,15 +1,17 @@
 //! Compressed Sparse Row (CSR) is a sparse adjacency matrix graph.
 
 use std::cmp::{max, Ordering};
+use std::convert::TryFrom;
 use std::iter::{Enumerate, Zip};
 use std::marker::PhantomData;
+use std::mem;
 use std::ops::{Index, IndexMut, Range};
-use std::slice::Windows;
+use std::slice::{IterMut as SliceIterMut, Windows};
 
 use crate::visit::{
     Data, EdgeCount, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences,
-    IntoEdges, IntoNeighbors, IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable,
-    NodeCount, NodeIndexable, Visitable,
+    IntoEdges, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences,
+    NodeCompactIndexable, NodeCount, NodeDegrees, NodeIndexable, Visitable,
 };
 
 use crate::util::zip;
@@ -17,7 +19,10 @@ use crate::util::zip;
 #[doc(no_inline)]
 pub use crate::graph::{DefaultIx, IndexType};
 
-use crate::{Directed, EdgeType, IntoWeightedEdge};
+use crate::convert::ConversionError;
+use crate::graph::{node_index, Graph, MemoryUsage};
+use crate::graph_impl::vec_usage;
+use crate::{Directed, Direction, EdgeType, IntoWeightedEdge, Outgoing};
 
 /// Csr node index type, a plain integer.
 pub type NodeIndex<Ix = DefaultIx> = Ix;
@@ -40,7 +45,9 @@ const BINARY_SEARCH_CUTOFF: usize = 32;
 ///
 /// Self loops are allowed, no parallel edges.
 ///
-/// Fast iteration of the outgoing edges of a vertex.
+/// Fast iteration of the outgoing edges of a vertex. A directed `Csr` can also
+/// store its transpose, for fast access to the incoming edges of a vertex;
+/// see [`set_incoming_edges`](#method.set_incoming_edges).
 ///
 /// [`CSR`]: https://en.wikipedia.org/wiki/Sparse_matrix#Compressed_sparse_row_(CSR,_CRS_or_Yale_format)
 #[derive(Debug)]
@@ -54,9 +61,70 @@ pub struct Csr<N = (), E = (), Ty = Directed, Ix = DefaultIx> {
     row: Vec<usize>,
     node_weights: Vec<N>,
     edge_count: usize,
+    /// The transpose of `column` and `row`, if incoming edges are stored
+    incoming: Option<Transpose<Ix>>,
     ty: PhantomData<Ty>,
 }
 
+/// The sources of the incoming edges of each node, in the same layout as the
+/// outgoing edges of `Csr`.
+#[derive(Clone, Debug)]
+struct Transpose<Ix> {
+    /// Source of each incoming edge
+    column: Vec<NodeIndex<Ix>>,
+    /// Index of start of row. Always node_count + 1 long.
+    row: Vec<usize>,
+}
+
+impl<Ix> Transpose<Ix>
+where
+    Ix: IndexType,
+{
+    /// Compute the transpose of the `Csr` rows `row` and columns `column`.
+    ///
+    /// Computes in **O(|E| + |V|)** time.
+    fn new(row: &[usize], column: &[NodeIndex<Ix>]) -> Self {
+        let mut in_row = vec![0; row.len()];
+        for &b in column {
+            in_row[b.index() + 1] += 1;
+        }
+        for i in 1..in_row.len() {
+            in_row[i] += in_row[i - 1];
+        }
+        // the sources of each row are added in increasing order
+        let mut next = in_row.clone();
+        let mut in_column = vec![Ix::new(0); column.len()];
+        for (a, w) in row.windows(2).enumerate() {
+            for &b in &column[w[0]..w[1]] {
+                in_column[next[b.index()]] = Ix::new(a);
+                next[b.index()] += 1;
+            }
+        }
+        Transpose {
+            column: in_column,
+            row: in_row,
+        }
+    }
+
+    /// Add an edge from `a` to `b`, which must not exist already.
+    fn insert(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) {
+        let start = self.row[b.index()];
+        let sources = &self.column[start..self.row[b.index() + 1]];
+        let pos = match sources.binary_search(&a) {
+            Ok(_) => unreachable!(),
+            Err(i) => start + i,
+        };
+        self.column.insert(pos, a);
+        for r in &mut self.row[b.index() + 1..] {
+            *r += 1;
+        }
+    }
+
+    fn sources(&self, b: NodeIndex<Ix>) -> &[NodeIndex<Ix>] {
+        &self.column[self.row[b.index()]..self.row[b.index() + 1]]
+    }
+}
+
 impl<N, E, Ty, Ix> Default for Csr<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -75,6 +143,7 @@ impl<N: Clone, E: Clone, Ty, Ix: Clone> Clone for Csr<N, E, Ty, Ix> {
             row: self.row.clone(),
             node_weights: self.node_weights.clone(),
             edge_count: self.edge_count,
+            incoming: self.incoming.clone(),
             ty: self.ty,
         }
     }
@@ -93,6 +162,7 @@ where
             row: vec![0; 1],
             node_weights: vec![],
             edge_count: 0,
+            incoming: None,
             ty: PhantomData,
         }
     }
@@ -123,6 +193,7 @@ where
             row: vec![0; n + 1],
             node_weights: (0..n).map(|_| N::default()).collect(),
             edge_count: 0,
+            incoming: None,
             ty: PhantomData,
         }
     }
@@ -143,7 +214,8 @@ where
     /// Edges **must** be sorted and unique, where the sort order is the default
     /// order for the pair *(u, v)* in Rust (*u* has priority).
     ///
-    /// Computes in **O(|E| + |V|)** time.
+    /// Computes in **O(|E| + |V|)** time. For edges in any order, or an
+    /// undirected graph, use [`add_edges_batch`](#method.add_edges_batch).
     /// # Example
     /// ```rust
     /// use petgraph::csr::Csr;
@@ -262,6 +334,91 @@ where
         if !self.is_directed() {
             self.edge_count = 0;
         }
+        if let Some(incoming) = &mut self.incoming {
+            incoming.column.clear();
+            for r in &mut incoming.row {
+                *r = 0;
+            }
+        }
+    }
+
+    /// Shrinks the capacity of the graph as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.column.shrink_to_fit();
+        self.edges.shrink_to_fit();
+        self.row.shrink_to_fit();
+        self.node_weights.shrink_to_fit();
+        if let Some(incoming) = &mut self.incoming {
+            incoming.column.shrink_to_fit();
+            incoming.row.shrink_to_fit();
+        }
+    }
+
+    /// Return an estimate of the heap memory held by the graph.
+    ///
+    /// The edges include their targets, and the row offsets and the stored
+    /// incoming edges, if any, are counted as adjacency.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let (nodes, unused_nodes) = vec_usage(&self.node_weights, self.node_weights.capacity());
+        let (targets, unused_targets) = vec_usage(&self.column, self.column.capacity());
+        let (weights, unused_weights) = vec_usage(&self.edges, self.edges.capacity());
+        let (rows, unused_rows) = vec_usage(&self.row, self.row.capacity());
+        let mut usage = MemoryUsage {
+            nodes,
+            edges: targets + weights,
+            vacant: 0,
+            adjacency: rows,
+            unused: unused_nodes + unused_targets + unused_weights + unused_rows,
+        };
+        if let Some(incoming) = &self.incoming {
+            for &(used, unused) in &[
+                vec_usage(&incoming.column, incoming.column.capacity()),
+                vec_usage(&incoming.row, incoming.row.capacity()),
+            ] {
+                usage.adjacency += used;
+                usage.unused += unused;
+            }
+        }
+        usage
+    }
+
+    /// Set whether the graph stores its incoming edges, in addition to its
+    /// outgoing edges.
+    ///
+    /// With the incoming edges stored, the incoming neighbors and in-degree
+    /// of a node take **O(1)** time to find, instead of **O(|E|)**, at the cost
+    /// of **O(|E| + |V|)** more space. Adding an edge becomes a bit slower.
+    ///
+    /// An undirected graph never stores its incoming edges, since they are the
+    /// same as its outgoing edges.
+    ///
+    /// Computes in **O(|E| + |V|)** time.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::algo::kosaraju_scc;
+    /// use petgraph::csr::Csr;
+    /// use petgraph::Incoming;
+    ///
+    /// let mut graph = Csr::<(), ()>::from_sorted_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]).unwrap();
+    /// graph.set_incoming_edges(true);
+    /// assert_eq!(graph.neighbors_slice_directed(2, Incoming), &[1]);
+    ///
+    /// // algorithms that need the incoming neighbors can now be used
+    /// assert_eq!(kosaraju_scc(&graph).len(), 2);
+    /// ```
+    pub fn set_incoming_edges(&mut self, enable: bool) {
+        self.incoming = if enable && self.is_directed() {
+            Some(Transpose::new(&self.row, &self.column))
+        } else {
+            None
+        };
+    }
+
+    /// Return `true` if the incoming edges of the graph can be found quickly:
+    /// if it stores them, or if it is undirected.
+    pub fn has_incoming_edges(&self) -> bool {
+        !self.is_directed() || self.incoming.is_some()
     }
 
     /// Adds a new node with the given weight, returning the corresponding node index.
@@ -269,13 +426,17 @@ where
         let i = self.row.len() - 1;
         self.row.insert(i, self.column.len());
         self.node_weights.insert(i, weight);
+        if let Some(incoming) = &mut self.incoming {
+            incoming.row.push(incoming.column.len());
+        }
         Ix::new(i)
     }
 
     /// Return `true` if the edge was added
     ///
     /// If you add all edges in row-major order, the time complexity
-    /// is **O(|V|·|E|)** for the whole operation.
+    /// is **O(|V|·|E|)** for the whole operation. Use
+    /// [`add_edges_batch`](#method.add_edges_batch) to add many edges at once.
     ///
     /// **Panics** if `a` or `b` are out of bounds.
     pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> bool
@@ -293,6 +454,152 @@ where
         ret
     }
 
+    /// Create a new `Csr` from parallel arrays of the sources, targets and
+    /// weights of its edges, where edge `i` goes from `sources[i]` to
+    /// `targets[i]` with weight `weights[i]`.
+    ///
+    /// The edges may be in any order; they are sorted into rows like
+    /// [`add_edges_batch`](#method.add_edges_batch) does, which also
+    /// keeps only the first of repeated edges. Node weights `N` are set to
+    /// default values, and nodes are inserted to match the edges.
+    ///
+    /// Computes in **O(|V| + |E| log |E|)** time.
+    ///
+    /// **Panics** if the arrays have different lengths.
+    ///
+    /// ```
+    /// use petgraph::csr::Csr;
+    ///
+    /// let graph = Csr::<(), u32>::from_parallel_arrays(&[2, 0, 0], &[0, 2, 1], vec![1, 2, 3]);
+    /// assert_eq!(graph.node_count(), 3);
+    /// assert_eq!(graph.neighbors_slice(0), &[1, 2]);
+    /// assert_eq!(graph.edges_slice(0), &[3, 2]);
+    /// ```
+    pub fn from_parallel_arrays(
+        sources: &[NodeIndex<Ix>],
+        targets: &[NodeIndex<Ix>],
+        weights: Vec<E>,
+    ) -> Self
+    where
+        N: Default,
+        E: Clone,
+    {
+        assert!(
+            sources.len() == targets.len() && targets.len() == weights.len(),
+            "Csr::from_parallel_arrays: the arrays have different lengths"
+        );
+        let node_bound = sources
+            .iter()
+            .chain(targets)
+            .map(|a| a.index() + 1)
+            .max()
+            .unwrap_or(0);
+        let mut graph = Self::with_nodes(node_bound);
+        graph.add_edges_batch(
+            sources
+                .iter()
+                .zip(targets)
+                .zip(weights)
+                .map(|((&a, &b), weight)| (a, b, weight)),
+        );
+        graph
+    }
+
+    /// Add the edges of `edges` to the graph, and return the number of edges
+    /// that were added.
+    ///
+    /// Edges that already exist in the graph are skipped, as are repeated
+    /// edges in `edges`, except for the first of them.
+    ///
+    /// Computes in **O(|V| + |E| + k log k)** time for `k` new edges, or
+    /// **O(|V| + |E| + k)** if they are already sorted. Building a graph with a
+    /// single batch is much faster than adding its edges one by one.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    ///
+    /// # Example
+    /// ```rust
+    /// use petgraph::csr::Csr;
+    /// use petgraph::Undirected;
+    ///
+    /// let mut graph = Csr::<(), i32, Undirected>::with_nodes(4);
+    /// assert_eq!(graph.add_edges_batch(vec![(2, 3, 1), (0, 1, 2), (3, 2, 3)]), 2);
+    /// assert_eq!(graph.add_edges_batch(vec![(1, 2, 4), (1, 0, 5)]), 1);
+    ///
+    /// assert_eq!(graph.edge_count(), 3);
+    /// assert_eq!(graph.neighbors_slice(1), &[0, 2]);
+    /// assert_eq!(graph.edges_slice(1), &[2, 4]);
+    /// ```
+    pub fn add_edges_batch<I>(&mut self, edges: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: IntoWeightedEdge<E, NodeId = NodeIndex<Ix>>,
+        E: Clone,
+    {
+        let node_count = self.node_count();
+        let mut batch = edges
+            .into_iter()
+            .map(|elt| {
+                let (a, b, weight) = elt.into_weighted_edge();
+                assert!(a.index() < node_count && b.index() < node_count);
+                if !self.is_directed() && b < a {
+                    (b, a, weight)
+                } else {
+                    (a, b, weight)
+                }
+            })
+            .collect::<Vec<_>>();
+        // the sort is stable, so the first of repeated edges is kept
+        batch.sort_by_key(|&(a, b, _)| (a, b));
+        batch.dedup_by_key(|&mut (a, b, _)| (a, b));
+        batch.retain(|&(a, b, _)| !self.contains_edge(a, b));
+        let added = batch.len();
+        if added == 0 {
+            return 0;
+        }
+        if !self.is_directed() {
+            self.edge_count += added;
+            let reversed = batch
+                .iter()
+                .filter(|&&(a, b, _)| a != b)
+                .map(|(a, b, weight)| (*b, *a, weight.clone()))
+                .collect::<Vec<_>>();
+            batch.extend(reversed);
+            batch.sort_by_key(|&(a, b, _)| (a, b));
+        }
+
+        // merge the new edges into each row
+        let capacity = self.column.len() + batch.len();
+        let mut old = mem::replace(&mut self.column, Vec::with_capacity(capacity))
+            .into_iter()
+            .zip(mem::replace(&mut self.edges, Vec::with_capacity(capacity)));
+        let mut new = batch.into_iter().peekable();
+        for a in 0..node_count {
+            let row_len = self.row[a + 1] - self.row[a];
+            self.row[a] = self.column.len();
+            let mut old_row = old.by_ref().take(row_len).peekable();
+            loop {
+                let new_target = new
+                    .peek()
+                    .filter(|&&(n, _, _)| n.index() == a)
+                    .map(|&(_, b, _)| b);
+                let (b, weight) = match (old_row.peek(), new_target) {
+                    (Some(&(b, _)), Some(c)) if b < c => old_row.next().unwrap(),
+                    (_, Some(_)) => new.next().map(|(_, b, w)| (b, w)).unwrap(),
+                    (Some(_), None) => old_row.next().unwrap(),
+                    (None, None) => break,
+                };
+                self.column.push(b);
+                self.edges.push(weight);
+            }
+        }
+        self.row[node_count] = self.column.len();
+        if self.incoming.is_some() {
+            self.incoming = Some(Transpose::new(&self.row, &self.column));
+        }
+        added
+    }
+
     // Return false if the edge already exists
     fn add_edge_(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> bool {
         assert!(a.index() < self.node_count() && b.index() < self.node_count());
@@ -309,6 +616,9 @@ where
         for r in &mut self.row[a.index() + 1..] {
             *r += 1;
         }
+        if let Some(incoming) = &mut self.incoming {
+            incoming.insert(a, b);
+        }
         true
     }
 
@@ -368,6 +678,27 @@ where
         self.neighbors_of(a).1
     }
 
+    /// Return the neighbors of `a` in the direction `dir`, sorted by index.
+    ///
+    /// - `Outgoing`, or an undirected graph: The same as
+    ///   [`neighbors_slice`](#method.neighbors_slice).
+    /// - `Incoming`: The sources of the incoming edges of `a`.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist, or if the incoming neighbors
+    /// of a directed graph that doesn't store its incoming edges are requested.
+    pub fn neighbors_slice_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> &[NodeIndex<Ix>] {
+        if dir == Outgoing || !self.is_directed() {
+            return self.neighbors_slice(a);
+        }
+        assert!(a.index() < self.node_count());
+        match &self.incoming {
+            Some(incoming) => incoming.sources(a),
+            None => panic!("Csr: incoming edges are not stored, see `set_incoming_edges`"),
+        }
+    }
+
     /// Computes in **O(1)** time.
     ///
     /// **Panics** if the node `a` does not exist.
@@ -375,6 +706,29 @@ where
         &self.edges[self.neighbors_range(a)]
     }
 
+    /// Return a mutable slice of the weights of the edges of `a`, in the
+    /// same order as [`neighbors_slice`](#method.neighbors_slice).
+    ///
+    /// For an undirected graph, each edge is stored once for each of its
+    /// endpoints, and this only gives access to the weights stored with `a`.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    pub fn edges_slice_mut(&mut self, a: NodeIndex<Ix>) -> &mut [E] {
+        let r = self.neighbors_range(a);
+        &mut self.edges[r]
+    }
+
+    /// Return an iterator over mutable references to the weights of all
+    /// edges, in the order of their edge indices.
+    ///
+    /// For an undirected graph, the weight of each edge appears once for each
+    /// of its endpoints.
+    pub fn edge_weights_mut(&mut self) -> SliceIterMut<'_, E> {
+        self.edges.iter_mut()
+    }
+
     /// Return an iterator of all edges of `a`.
     ///
     /// - `Directed`: Outgoing edges from `a`.
@@ -393,6 +747,66 @@ where
     }
 }
 
+/// Convert a `Csr` into a `Graph`, with the edges listed by source and
+/// target, and each undirected edge once, from the smaller node index.
+impl<N, E, Ty, Ix> From<Csr<N, E, Ty, Ix>> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(csr: Csr<N, E, Ty, Ix>) -> Self {
+        let edge_count = csr.edge_count();
+        let Csr {
+            column,
+            edges,
+            row,
+            node_weights,
+            ..
+        } = csr;
+        let mut graph = Graph::with_capacity(node_weights.len(), edge_count);
+        for weight in node_weights {
+            graph.add_node(weight);
+        }
+        let mut edges = column.into_iter().zip(edges);
+        for (a, range) in row.windows(2).enumerate() {
+            for (b, weight) in edges.by_ref().take(range[1] - range[0]) {
+                if Ty::is_directed() || b.index() >= a {
+                    graph.add_edge(node_index(a), node_index(b.index()), weight);
+                }
+            }
+        }
+        graph
+    }
+}
+
+/// Convert a `Graph` into a `Csr`, failing if it has parallel edges.
+impl<N, E, Ty, Ix> TryFrom<Graph<N, E, Ty, Ix>> for Csr<N, E, Ty, Ix>
+where
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: Graph<N, E, Ty, Ix>) -> Result<Self, ConversionError> {
+        let (nodes, edges) = graph.into_nodes_edges();
+        let mut csr = Csr::new();
+        for node in nodes {
+            csr.add_node(node.weight);
+        }
+        let edge_count = edges.len();
+        let added = csr.add_edges_batch(edges.into_iter().map(|edge| {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            (Ix::new(a), Ix::new(b), edge.weight)
+        }));
+        if added == edge_count {
+            Ok(csr)
+        } else {
+            Err(ConversionError::ParallelEdges)
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Edges<'a, E: 'a, Ty = Directed, Ix: 'a = DefaultIx> {
     index: usize,
@@ -507,6 +921,92 @@ where
     }
 }
 
+/// Parallel iterators, with [`rayon`](https://crates.io/crates/rayon).
+///
+/// Requires crate feature `"rayon"`.
+#[cfg(feature = "rayon")]
+impl<N, E, Ty, Ix> Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType + Send + Sync,
+    Ix: IndexType + Send + Sync,
+{
+    /// Return a parallel iterator over the node indices and weights of the
+    /// graph.
+    pub fn par_node_references(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = (NodeIndex<Ix>, &N)>
+    where
+        N: Sync,
+    {
+        use rayon::prelude::*;
+        self.node_weights
+            .par_iter()
+            .enumerate()
+            .map(|(i, weight)| (Ix::new(i), weight))
+    }
+
+    /// Return a parallel iterator over all edges, where the edges of an
+    /// undirected graph are listed once, like
+    /// [`edge_references`](#impl-IntoEdgeReferences).
+    pub fn par_edge_references(
+        &self,
+    ) -> impl rayon::iter::ParallelIterator<Item = EdgeReference<'_, E, Ty, Ix>>
+    where
+        N: Sync,
+        E: Sync,
+    {
+        use rayon::prelude::*;
+        (0..self.node_count())
+            .into_par_iter()
+            .flat_map_iter(move |i| {
+                (self.row[i]..self.row[i + 1]).filter_map(move |k| {
+                    let target = self.column[k];
+                    // an undirected edge is stored at both of its endpoints
+                    if !Ty::is_directed() && target.index() < i {
+                        return None;
+                    }
+                    Some(EdgeReference {
+                        index: k,
+                        source: Ix::new(i),
+                        target,
+                        weight: &self.edges[k],
+                        ty: PhantomData,
+                    })
+                })
+            })
+    }
+
+    /// Return a parallel iterator yielding mutable access to all node
+    /// weights.
+    pub fn par_node_weights_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut N>
+    where
+        N: Send,
+    {
+        use rayon::prelude::*;
+        self.node_weights.par_iter_mut()
+    }
+
+    /// Return a parallel iterator yielding mutable access to all edge
+    /// weights.
+    ///
+    /// For an undirected graph, the weight of each edge appears once for each
+    /// of its endpoints.
+    pub fn par_edge_weights_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut E>
+    where
+        E: Send,
+    {
+        use rayon::prelude::*;
+        self.edges.par_iter_mut()
+    }
+}
+
+/// Iterator over all edges of a graph.
+///
+/// The edges of an undirected graph are listed once.
 #[derive(Debug, Clone)]
 pub struct EdgeReferences<'a, E: 'a, Ty, Ix: 'a> {
     source_index: NodeIndex<Ix>,
@@ -529,6 +1029,10 @@ where
             if let Some((&j, w)) = self.iter.next() {
                 let index = self.index;
                 self.index += 1;
+                // an undirected edge is stored at both of its endpoints
+                if !Ty::is_directed() && j < self.source_index {
+                    continue;
+                }
                 return Some(EdgeReference {
                     index,
                     source: self.source_index,
@@ -629,6 +1133,30 @@ where
     }
 }
 
+impl<'a, N, E, Ty, Ix> IntoNeighborsDirected for &'a Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NeighborsDirected = Neighbors<'a, Ix>;
+
+    /// Return an iterator of all neighbors of `a` in the direction `dir`.
+    ///
+    /// - `Directed`, `Outgoing`: Targets of outgoing edges from `a`.
+    /// - `Directed`, `Incoming`: Sources of incoming edges to `a`.
+    /// - `Undirected`: Opposing endpoints of all edges connected to `a`.
+    ///
+    /// **Panics** if the node `a` does not exist, or if the incoming neighbors
+    /// of a directed graph that doesn't store its incoming edges are requested
+    /// (see [`Csr::set_incoming_edges`]).<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    fn neighbors_directed(self, a: Self::NodeId, dir: Direction) -> Self::NeighborsDirected {
+        Neighbors {
+            iter: self.neighbors_slice_directed(a, dir).iter(),
+        }
+    }
+}
+
 impl<N, E, Ty, Ix> NodeIndexable for Csr<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -730,6 +1258,27 @@ where
     }
 }
 
+impl<N, E, Ty, Ix> NodeDegrees for Csr<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Computes in **O(1)** time, except for the incoming edges of a directed
+    /// graph that doesn't store them, which take **O(|E|)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        if dir == Outgoing || !self.is_directed() {
+            self.out_degree(a)
+        } else if self.incoming.is_some() {
+            self.neighbors_slice_directed(a, dir).len()
+        } else {
+            assert!(a.index() < self.node_count());
+            self.column.iter().filter(|&&b| b == a).count()
+        }
+    }
+}
+
 impl<N, E, Ty, Ix> GraphProp for Csr<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -1088,6 +1637,22 @@ mod tests {
         assert_eq!(&m.edges, &m2.edges);
     }
 
+    #[test]
+    fn test_edge_references_undirected() {
+        use crate::visit::EdgeRef;
+        use crate::visit::IntoEdgeReferences;
+        let mut m: Csr<(), u8, Undirected> = Csr::with_nodes(3);
+        m.add_edge(0, 1, 1);
+        m.add_edge(2, 1, 2);
+        m.add_edge(2, 2, 3);
+        let edges = m
+            .edge_references()
+            .map(|e| (e.source(), e.target(), *e.weight()))
+            .collect::<Vec<_>>();
+        assert_eq!(edges, [(0, 1, 1), (1, 2, 2), (2, 2, 3)]);
+        assert_eq!(edges.len(), m.edge_count());
+    }
+
     #[test]
     fn test_add_node() {
         let mut g: Csr = Csr::new();
@@ -1131,6 +1696,108 @@ mod tests {
         assert_eq!(g.edge_count(), 1);
     }
 
+    #[test]
+    fn test_add_edges_batch() {
+        let edges = [
+            (3, 1, 0),
+            (0, 2, 1),
+            (1, 1, 2),
+            (2, 0, 3),
+            (0, 2, 4),
+            (4, 3, 5),
+            (1, 3, 6),
+        ];
+        let mut directed: Csr<(), i32> = Csr::with_nodes(5);
+        let mut undirected: Csr<(), i32, Undirected> = Csr::with_nodes(5);
+        assert!(directed.add_edge(0, 2, -1));
+        assert!(undirected.add_edge(1, 3, -1));
+        assert_eq!(directed.add_edges_batch(edges.iter().cloned()), 5);
+        assert_eq!(undirected.add_edges_batch(edges.iter().cloned()), 3);
+
+        // the same as adding the edges one by one
+        let mut directed2: Csr<(), i32> = Csr::with_nodes(5);
+        let mut undirected2: Csr<(), i32, Undirected> = Csr::with_nodes(5);
+        directed2.add_edge(0, 2, -1);
+        undirected2.add_edge(1, 3, -1);
+        for &(a, b, w) in &edges {
+            directed2.add_edge(a, b, w);
+            undirected2.add_edge(a, b, w);
+        }
+        assert_eq!(&directed.row, &directed2.row);
+        assert_eq!(&directed.column, &directed2.column);
+        assert_eq!(&directed.edges, &directed2.edges);
+        assert_eq!(&undirected.row, &undirected2.row);
+        assert_eq!(&undirected.column, &undirected2.column);
+        assert_eq!(&undirected.edges, &undirected2.edges);
+        assert_eq!(directed.edge_count(), 6);
+        assert_eq!(undirected.edge_count(), 4);
+        assert_eq!(undirected.neighbors_slice(0), &[2]);
+        assert_eq!(undirected.edges_slice(2), &[1]);
+    }
+
+    #[test]
+    fn test_edge_weights_mut() {
+        let mut g: Csr<(), i32> =
+            Csr::from_sorted_edges(&[(0, 1, 1), (1, 0, 2), (1, 2, 3)]).unwrap();
+        for w in g.edge_weights_mut() {
+            *w *= 10;
+        }
+        g.edges_slice_mut(1)[1] += 1;
+        assert_eq!(g.edges_slice(0), &[10]);
+        assert_eq!(g.edges_slice(1), &[20, 31]);
+    }
+
+    #[test]
+    fn test_incoming_edges() {
+        use crate::algo::kosaraju_scc;
+        use crate::visit::{IntoNeighborsDirected, NodeDegrees};
+        use crate::{Incoming, Outgoing};
+
+        let mut g: Csr<(), i32> = Csr::with_nodes(3);
+        g.add_edge(2, 0, 1);
+        g.set_incoming_edges(true);
+        assert!(g.has_incoming_edges());
+        g.add_edge(0, 1, 2);
+        g.add_edge(1, 1, 3);
+        g.add_edge(2, 1, 4);
+        let d = g.add_node(());
+        g.add_edges_batch(vec![(d, 0, 5), (1, d, 6)]);
+        g.add_edge(0, d, 7);
+
+        assert_eq!(g.neighbors_slice_directed(0, Incoming), &[2, d]);
+        assert_eq!(g.neighbors_slice_directed(1, Incoming), &[0, 1, 2]);
+        assert_eq!(g.neighbors_slice_directed(2, Incoming), &[]);
+        assert_eq!(g.neighbors_slice_directed(d, Incoming), &[0, 1]);
+        assert_eq!(g.neighbors_slice_directed(d, Outgoing), &[0]);
+        assert_eq!(g.neighbors_directed(1, Incoming).count(), 3);
+        assert_eq!(g.degree_directed(1, Incoming), 3);
+
+        // the same as computing them all at once
+        let mut g2 = g.clone();
+        g2.set_incoming_edges(false);
+        assert!(!g2.has_incoming_edges());
+        assert_eq!(g2.degree_directed(1, Incoming), 3);
+        g2.set_incoming_edges(true);
+        let (t, t2) = (g.incoming.as_ref().unwrap(), g2.incoming.unwrap());
+        assert_eq!((&t.row, &t.column), (&t2.row, &t2.column));
+
+        let mut sccs = kosaraju_scc(&g);
+        sccs.iter_mut().for_each(|scc| scc.sort());
+        sccs.sort();
+        assert_eq!(sccs, vec![vec![0, 1, 3], vec![2]]);
+
+        g.clear_edges();
+        assert_eq!(g.neighbors_slice_directed(1, Incoming), &[]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_incoming_edges_not_stored() {
+        use crate::Incoming;
+        let g: Csr = Csr::from_sorted_edges(&[(0, 1)]).unwrap();
+        g.neighbors_slice_directed(1, Incoming);
+    }
+
     #[test]
     fn test_node_references() {
         use crate::visit::IntoNodeReferences;
@@ -1145,4 +1812,26 @@ mod tests {
         assert_eq!(refs.next(), Some((2, &44)));
         assert_eq!(refs.next(), None);
     }
+
+    #[test]
+    fn test_from_parallel_arrays() {
+        let sources = [3, 0, 1, 0, 3, 2];
+        let targets = [0, 2, 1, 2, 3, 0];
+        let weights = vec![1, 2, 3, 4, 5, 6];
+        let directed: Csr<(), i32> = Csr::from_parallel_arrays(&sources, &targets, weights.clone());
+        let undirected: Csr<(), i32, Undirected> =
+            Csr::from_parallel_arrays(&sources, &targets, weights);
+        assert_eq!(directed.node_count(), 4);
+        assert_eq!(directed.edge_count(), 5);
+        assert_eq!(directed.neighbors_slice(0), &[2]);
+        assert_eq!(directed.edges_slice(0), &[2]);
+        assert_eq!(directed.neighbors_slice(3), &[0, 3]);
+        assert_eq!(undirected.edge_count(), 4);
+        assert_eq!(undirected.neighbors_slice(0), &[2, 3]);
+        assert_eq!(undirected.edges_slice(0), &[2, 1]);
+        assert_eq!(undirected.neighbors_slice(2), &[0]);
+
+        let empty: Csr = Csr::from_parallel_arrays(&[], &[], vec![]);
+        assert_eq!(empty.node_count(), 0);
+    }
 }
@@ -0,0 +1,536 @@
+//! Reading and writing edge lists and node lists as CSV or TSV.
+//!
+//! Records are read one at a time from any `BufRead` into any graph that
+//! implements `Build`, so large files are never held in memory. Nodes are
+//! named by the text of their ID column, and a map from the names to the node
+//! IDs of the graph is shared between calls, so a node list and then an edge
+//! list can be read into the same graph.
+//!
+//! Fields may be quoted with `"`, with `""` for a quote inside, and quoted
+//! fields may span lines. Blank lines are skipped.
+//!
+//! # Examples
+//!
+//! ```
+//! use petgraph::csv::{read_edge_list, read_node_list, CsvFormat};
+//! use petgraph::prelude::*;
+//! use std::collections::HashMap;
+//!
+//! let nodes = "name,age\nalice,34\nbob,27\n";
+//! let edges = "from,to,since\nalice,bob,2019\nbob,\"carol, jr.\",2021\n";
+//!
+//! let mut graph = DiGraph::<(String, u32), u32>::new();
+//! let mut names = HashMap::new();
+//! let format = CsvFormat::csv().with_id("name").with_source("from").with_target("to");
+//! read_node_list(&mut graph, &mut names, nodes.as_bytes(), &format, |name, record| {
+//!     Ok((name.to_string(), record.parse("age")?))
+//! })
+//! .unwrap();
+//! read_edge_list(
+//!     &mut graph,
+//!     &mut names,
+//!     edges.as_bytes(),
+//!     &format,
+//!     |name| (name.to_string(), 0),
+//!     |record| record.parse("since"),
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(graph.node_count(), 3);
+//! assert_eq!(graph[names["carol, jr."]], ("carol, jr.".to_string(), 0));
+//! assert_eq!(graph[graph.find_edge(names["alice"], names["bob"]).unwrap()], 2019);
+//! ```
+
+use std::collections::hash_map::{Entry, HashMap};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, BufRead, Write};
+use std::mem;
+use std::str::FromStr;
+
+use crate::data::Build;
+use crate::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef};
+
+/// A column of a record, by position counting from 0, or by name in the
+/// header.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// The column at this position.
+    Index(usize),
+    /// The column with this name in the header.
+    Name(String),
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Self {
+        Column::Index(index)
+    }
+}
+
+impl<'a> From<&'a str> for Column {
+    fn from(name: &'a str) -> Self {
+        Column::Name(name.to_string())
+    }
+}
+
+impl Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Column::Index(index) => write!(f, "{}", index),
+            Column::Name(ref name) => write!(f, "{:?}", name),
+        }
+    }
+}
+
+/// The layout of CSV or TSV data.
+///
+/// By default the first line is a header, nodes are identified by the first
+/// column of a node list, and edges go from the first to the second column
+/// of an edge list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvFormat {
+    delimiter: char,
+    header: bool,
+    id: Column,
+    source: Column,
+    target: Column,
+}
+
+impl CsvFormat {
+    /// Comma separated values.
+    pub fn csv() -> Self {
+        CsvFormat {
+            delimiter: ',',
+            header: true,
+            id: Column::Index(0),
+            source: Column::Index(0),
+            target: Column::Index(1),
+        }
+    }
+
+    /// Tab separated values.
+    pub fn tsv() -> Self {
+        CsvFormat::csv().with_delimiter('\t')
+    }
+
+    /// Set the character between fields.
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set whether the first line is a header with the names of the columns.
+    pub fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Set the column with the ID of a node in a node list.
+    pub fn with_id(mut self, column: impl Into<Column>) -> Self {
+        self.id = column.into();
+        self
+    }
+
+    /// Set the column with the ID of the source node in an edge list.
+    pub fn with_source(mut self, column: impl Into<Column>) -> Self {
+        self.source = column.into();
+        self
+    }
+
+    /// Set the column with the ID of the target node in an edge list.
+    pub fn with_target(mut self, column: impl Into<Column>) -> Self {
+        self.target = column.into();
+        self
+    }
+}
+
+impl Default for CsvFormat {
+    fn default() -> Self {
+        CsvFormat::csv()
+    }
+}
+
+/// One line of CSV data.
+#[derive(Copy, Clone, Debug)]
+pub struct Record<'a> {
+    header: &'a [String],
+    fields: &'a [String],
+}
+
+impl<'a> Record<'a> {
+    /// Return the field in `column`.
+    pub fn get(&self, column: impl Into<Column>) -> Result<&'a str, ReadErrorKind> {
+        self.field(&column.into())
+    }
+
+    /// Parse the field in `column`.
+    pub fn parse<T>(&self, column: impl Into<Column>) -> Result<T, ReadErrorKind>
+    where
+        T: FromStr,
+    {
+        let field = self.get(column)?;
+        field
+            .parse()
+            .map_err(|_| ReadErrorKind::InvalidValue(field.to_string()))
+    }
+
+    /// Return the number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Return `true` if the record has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Return an iterator over the fields.
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> {
+        self.fields.iter().map(|field| &**field)
+    }
+
+    fn field(&self, column: &Column) -> Result<&'a str, ReadErrorKind> {
+        let index = match *column {
+            Column::Index(index) => Some(index),
+            Column::Name(ref name) => self.header.iter().position(|h| h == name),
+        };
+        index
+            .and_then(|index| self.fields.get(index))
+            .map(|field| &**field)
+            .ok_or_else(|| ReadErrorKind::MissingColumn(column.clone()))
+    }
+}
+
+/// An error from reading CSV data, with the line it is on.
+#[derive(Debug)]
+pub struct ReadError {
+    line: usize,
+    kind: ReadErrorKind,
+}
+
+/// The kind of a [`ReadError`].
+///
+/// [`ReadError`]: struct.ReadError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReadErrorKind {
+    /// Reading the input failed.
+    Io(io::Error),
+    /// The input ended inside a quoted field.
+    UnterminatedQuote,
+    /// The record has no such column.
+    MissingColumn(Column),
+    /// The field could not be parsed.
+    InvalidValue(String),
+    /// There already is a node with this ID.
+    DuplicateNode(String),
+}
+
+impl ReadError {
+    /// The line of the error, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// What the error is.
+    pub fn kind(&self) -> &ReadErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match self.kind {
+            ReadErrorKind::Io(ref error) => write!(f, "{}", error),
+            ReadErrorKind::UnterminatedQuote => f.write_str("unterminated quoted field"),
+            ReadErrorKind::MissingColumn(ref column) => write!(f, "there is no column {}", column),
+            ReadErrorKind::InvalidValue(ref value) => write!(f, "invalid value {:?}", value),
+            ReadErrorKind::DuplicateNode(ref id) => {
+                write!(f, "there already is a node {:?}", id)
+            }
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.kind {
+            ReadErrorKind::Io(ref error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Read records from `input`, calling `f` with each one, after the header if
+/// there is one.
+fn read_records<R, F>(input: R, format: &CsvFormat, mut f: F) -> Result<(), ReadError>
+where
+    R: BufRead,
+    F: FnMut(Record) -> Result<(), ReadErrorKind>,
+{
+    let mut reader = Reader {
+        input,
+        delimiter: format.delimiter,
+        line: 0,
+        buf: String::new(),
+    };
+    let mut header = Vec::new();
+    let mut fields = Vec::new();
+    if format.header {
+        reader.next_record(&mut header)?;
+    }
+    while reader.next_record(&mut fields)? {
+        let record = Record {
+            header: &header,
+            fields: &fields,
+        };
+        f(record).map_err(|kind| ReadError {
+            line: reader.line,
+            kind,
+        })?;
+    }
+    Ok(())
+}
+
+struct Reader<R> {
+    input: R,
+    delimiter: char,
+    /// The last line read, counting from 1.
+    line: usize,
+    buf: String,
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Read the next line into `buf`, returning `false` at the end of the
+    /// input.
+    fn next_line(&mut self) -> Result<bool, ReadError> {
+        self.buf.clear();
+        match self.input.read_line(&mut self.buf) {
+            Ok(0) => Ok(false),
+            Ok(_) => {
+                self.line += 1;
+                let len = self.buf.trim_end_matches(&['\r', '\n'][..]).len();
+                self.buf.truncate(len);
+                Ok(true)
+            }
+            Err(error) => Err(ReadError {
+                line: self.line + 1,
+                kind: ReadErrorKind::Io(error),
+            }),
+        }
+    }
+
+    /// Read the next record into `fields`, returning `false` at the end of
+    /// the input.
+    fn next_record(&mut self, fields: &mut Vec<String>) -> Result<bool, ReadError> {
+        fields.clear();
+        loop {
+            if !self.next_line()? {
+                return Ok(false);
+            }
+            if !self.buf.is_empty() {
+                break;
+            }
+        }
+        let mut field = String::new();
+        let mut quoted = false;
+        loop {
+            let mut chars = self.buf.chars().peekable();
+            while let Some(c) = chars.next() {
+                if quoted {
+                    if c != '"' {
+                        field.push(c);
+                    } else if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        quoted = false;
+                    }
+                } else if c == self.delimiter {
+                    fields.push(mem::take(&mut field));
+                } else if c == '"' && field.is_empty() {
+                    quoted = true;
+                } else {
+                    field.push(c);
+                }
+            }
+            if !quoted {
+                break;
+            }
+            field.push('\n');
+            if !self.next_line()? {
+                return Err(ReadError {
+                    line: self.line,
+                    kind: ReadErrorKind::UnterminatedQuote,
+                });
+            }
+        }
+        fields.push(field);
+        Ok(true)
+    }
+}
+
+/// Read a node list from `input` into `graph`.
+///
+/// The weight of each node is made by `node_weight` from its ID and its
+/// record, and the node is added to `node_ids` by its ID, which must be new.
+pub fn read_node_list<G, R, F>(
+    graph: &mut G,
+    node_ids: &mut HashMap<String, G::NodeId>,
+    input: R,
+    format: &CsvFormat,
+    mut node_weight: F,
+) -> Result<(), ReadError>
+where
+    G: Build,
+    R: BufRead,
+    F: FnMut(&str, Record) -> Result<G::NodeWeight, ReadErrorKind>,
+{
+    read_records(input, format, |record| {
+        let id = record.field(&format.id)?;
+        match node_ids.entry(id.to_string()) {
+            Entry::Occupied(_) => Err(ReadErrorKind::DuplicateNode(id.to_string())),
+            Entry::Vacant(entry) => {
+                entry.insert(graph.add_node(node_weight(id, record)?));
+                Ok(())
+            }
+        }
+    })
+}
+
+/// Read an edge list from `input` into `graph`.
+///
+/// The weight of each edge is made by `edge_weight` from its record. Nodes
+/// are looked up by ID in `node_ids`, and those that are missing are added,
+/// with weights made by `node_weight` from their ID.
+///
+/// Edges are added with `Build::add_edge`, so for graphs without parallel
+/// edges, an edge that already exists is skipped.
+pub fn read_edge_list<G, R, NF, EF>(
+    graph: &mut G,
+    node_ids: &mut HashMap<String, G::NodeId>,
+    input: R,
+    format: &CsvFormat,
+    mut node_weight: NF,
+    mut edge_weight: EF,
+) -> Result<(), ReadError>
+where
+    G: Build,
+    R: BufRead,
+    NF: FnMut(&str) -> G::NodeWeight,
+    EF: FnMut(Record) -> Result<G::EdgeWeight, ReadErrorKind>,
+{
+    read_records(input, format, |record| {
+        let mut node = |column| -> Result<G::NodeId, ReadErrorKind> {
+            let id = record.field(column)?;
+            if let Some(&node) = node_ids.get(id) {
+                return Ok(node);
+            }
+            let node = graph.add_node(node_weight(id));
+            node_ids.insert(id.to_string(), node);
+            Ok(node)
+        };
+        let source = node(&format.source)?;
+        let target = node(&format.target)?;
+        let weight = edge_weight(record)?;
+        graph.add_edge(source, target, weight);
+        Ok(())
+    })
+}
+
+/// Write the fields of one record, quoting those that need it.
+fn write_record<W, I>(output: &mut W, delimiter: char, fields: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    for (i, field) in fields.into_iter().enumerate() {
+        let field = field.as_ref();
+        if i > 0 {
+            write!(output, "{}", delimiter)?;
+        }
+        if field.contains(|c| c == delimiter || c == '"' || c == '\n' || c == '\r') {
+            write!(output, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            output.write_all(field.as_bytes())?;
+        }
+    }
+    writeln!(output)
+}
+
+/// The name of `column` in a header, or `default` for a column by position.
+fn column_name<'a>(column: &'a Column, default: &'a str) -> &'a str {
+    match *column {
+        Column::Index(_) => default,
+        Column::Name(ref name) => name,
+    }
+}
+
+/// Write the nodes of `graph` as a node list to `output`.
+///
+/// Each record has the index of the node from `NodeIndexable` as its ID,
+/// followed by the fields from `node_fields`. The header, if the format has
+/// one, names the ID column after the format's ID column, or `id`, and then
+/// the fields after `columns`.
+pub fn write_node_list<G, W, F>(
+    graph: G,
+    mut output: W,
+    format: &CsvFormat,
+    columns: &[&str],
+    mut node_fields: F,
+) -> io::Result<()>
+where
+    G: IntoNodeReferences + NodeIndexable,
+    W: Write,
+    F: FnMut(G::NodeRef) -> Vec<String>,
+{
+    if format.header {
+        let id = column_name(&format.id, "id");
+        let header = Some(id).into_iter().chain(columns.iter().cloned());
+        write_record(&mut output, format.delimiter, header)?;
+    }
+    for node in graph.node_references() {
+        let id = graph.to_index(node.id()).to_string();
+        let fields = Some(id).into_iter().chain(node_fields(node));
+        write_record(&mut output, format.delimiter, fields)?;
+    }
+    Ok(())
+}
+
+/// Write the edges of `graph` as an edge list to `output`.
+///
+/// Each record has the indices of the source and target nodes from
+/// `NodeIndexable`, followed by the fields from `edge_fields`. The header,
+/// if the format has one, names the first two columns after the format's
+/// source and target columns, or `source` and `target`, and then the fields
+/// after `columns`.
+pub fn write_edge_list<G, W, F>(
+    graph: G,
+    mut output: W,
+    format: &CsvFormat,
+    columns: &[&str],
+    mut edge_fields: F,
+) -> io::Result<()>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    W: Write,
+    F: FnMut(G::EdgeRef) -> Vec<String>,
+{
+    if format.header {
+        let source = column_name(&format.source, "source");
+        let target = column_name(&format.target, "target");
+        let header = vec![source, target]
+            .into_iter()
+            .chain(columns.iter().cloned());
+        write_record(&mut output, format.delimiter, header)?;
+    }
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source()).to_string();
+        let target = graph.to_index(edge.target()).to_string();
+        let fields = vec![source, target].into_iter().chain(edge_fields(edge));
+        write_record(&mut output, format.delimiter, fields)?;
+    }
+    Ok(())
+}
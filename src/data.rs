@@ -8,6 +8,8 @@ use crate::stable_graph::StableGraph;
 use crate::visit::{Data, NodeCount, NodeIndexable, Reversed};
 use crate::EdgeType;
 use crate::Graph;
+#[cfg(feature = "graphmap")]
+use std::hash::BuildHasher;
 
 trait_template! {
     /// Access node and edge weights (associated data).
@@ -186,10 +188,11 @@ where
 }
 
 #[cfg(feature = "graphmap")]
-impl<N, E, Ty> Build for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> Build for GraphMap<N, E, Ty, S>
 where
     Ty: EdgeType,
     N: NodeTrait,
+    S: BuildHasher,
 {
     fn add_node(&mut self, weight: Self::NodeWeight) -> Self::NodeId {
         self.add_node(weight)
@@ -1,4 +1,31 @@
-//! Simple graphviz dot file format output.
+//! Simple graphviz dot file format output, and reading of .dot files.
+//!
+//! [`Dot`] writes a graph, and [`parse`] and [`parse_with`] read one back
+//! into a `Graph`, which converts into a `StableGraph` with `From`.
+//!
+//! [`Dot`]: struct.Dot.html
+//! [`parse`]: fn.parse.html
+//! [`parse_with`]: fn.parse_with.html
+//!
+//! ```
+//! # #[cfg(feature = "stable_graph")] {
+//! use petgraph::dot::{parse_with, Config, Dot};
+//! use petgraph::prelude::*;
+//!
+//! let graph = DiGraph::<&str, &str>::from_edges(&[(0, 1, "x"), (1, 2, "y")]);
+//! let dot = Dot::with_config(&graph, &[Config::NodeIndexLabel]).to_string();
+//!
+//! let parsed = parse_with::<_, _, Directed, _, _>(
+//!     &dot,
+//!     |node| node.attributes["label"].clone(),
+//!     |edge| edge["label"].trim_matches('"').to_string(),
+//! )
+//! .unwrap();
+//! let stable = StableGraph::from(parsed.graph);
+//! assert_eq!(stable.node_weights().collect::<Vec<_>>(), ["0", "1", "2"]);
+//! assert_eq!(stable.edge_weights().collect::<Vec<_>>(), ["x", "y"]);
+//! # }
+//! ```
 
 use std::fmt::{self, Display, Write};
 
@@ -6,6 +33,15 @@ use crate::visit::{
     EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
 };
 
+mod parse;
+
+pub use self::parse::{
+    parse, parse_with, AttributeMap, DotNode, ParseError, ParseErrorKind, ParsedGraph,
+};
+
+/// Attributes as pairs of attribute name and value.
+pub type Attributes = Vec<(&'static str, String)>;
+
 /// `Dot` implements output to graphviz .dot format for a graph.
 ///
 /// Formatting and options are rather simple, this is mostly intended
@@ -47,6 +83,59 @@ use crate::visit::{
 ///
 /// // If you need multiple config options, just list them all in the slice.
 /// ```
+///
+/// The attributes of nodes, edges and the graph itself, and clusters of
+/// nodes, can be set with the builder methods:
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::dot::{Dot, Config};
+///
+/// let mut graph = Graph::<&str, u32>::new();
+/// let a = graph.add_node("a.rs");
+/// let b = graph.add_node("b.rs");
+/// let c = graph.add_node("main");
+/// graph.extend_with_edges(&[(c, a, 1), (c, b, 2)]);
+///
+/// let node_attributes = |_, (_, name): (_, &&str)| {
+///     if name.ends_with(".rs") {
+///         vec![("shape", "note".to_string())]
+///     } else {
+///         vec![("shape", "box".to_string()), ("color", "red".to_string())]
+///     }
+/// };
+/// let edge_attributes = |_, edge: petgraph::graph::EdgeReference<u32>| {
+///     vec![("label", format!("{} uses", edge.weight()))]
+/// };
+/// let cluster = |_, (_, name): (_, &&str)| {
+///     if name.ends_with(".rs") {
+///         Some("sources".to_string())
+///     } else {
+///         None
+///     }
+/// };
+/// let dot = Dot::new(&graph)
+///     .with_node_attributes(&node_attributes)
+///     .with_edge_attributes(&edge_attributes)
+///     .with_graph_attribute("rankdir", "LR")
+///     .with_clusters(&cluster);
+///
+/// assert_eq!(
+///     format!("{}", dot),
+///     r#"digraph {
+///     rankdir = "LR"
+///     2 [ label = "main" shape = "box" color = "red" ]
+///     subgraph cluster_0 {
+///         label = "sources"
+///         0 [ label = "a.rs" shape = "note" ]
+///         1 [ label = "b.rs" shape = "note" ]
+///     }
+///     2 -> 0 [ label = "1 uses" ]
+///     2 -> 1 [ label = "2 uses" ]
+/// }
+/// "#
+/// );
+/// ```
 pub struct Dot<'a, G>
 where
     G: IntoEdgeReferences + IntoNodeReferences,
@@ -54,6 +143,10 @@ where
     graph: G,
     get_edge_attributes: &'a dyn Fn(G, G::EdgeRef) -> String,
     get_node_attributes: &'a dyn Fn(G, G::NodeRef) -> String,
+    get_edge_attribute_list: Option<&'a dyn Fn(G, G::EdgeRef) -> Attributes>,
+    get_node_attribute_list: Option<&'a dyn Fn(G, G::NodeRef) -> Attributes>,
+    get_cluster: Option<&'a dyn Fn(G, G::NodeRef) -> Option<String>>,
+    graph_attributes: Attributes,
     config: Configs,
 }
 
@@ -89,9 +182,60 @@ where
             graph,
             get_edge_attributes,
             get_node_attributes,
+            get_edge_attribute_list: None,
+            get_node_attribute_list: None,
+            get_cluster: None,
+            graph_attributes: Vec::new(),
             config,
         }
     }
+
+    /// Set the attributes of each edge, as pairs of attribute name and value,
+    /// like `("color", "red".to_string())`.
+    ///
+    /// The values are quoted and escaped. A `label` attribute replaces the
+    /// default label of the edge.
+    pub fn with_edge_attributes(
+        mut self,
+        get_edge_attributes: &'a dyn Fn(G, G::EdgeRef) -> Attributes,
+    ) -> Self {
+        self.get_edge_attribute_list = Some(get_edge_attributes);
+        self
+    }
+
+    /// Set the attributes of each node, as pairs of attribute name and value,
+    /// like `("shape", "box".to_string())`.
+    ///
+    /// The values are quoted and escaped. A `label` attribute replaces the
+    /// default label of the node.
+    pub fn with_node_attributes(
+        mut self,
+        get_node_attributes: &'a dyn Fn(G, G::NodeRef) -> Attributes,
+    ) -> Self {
+        self.get_node_attribute_list = Some(get_node_attributes);
+        self
+    }
+
+    /// Add an attribute of the graph itself, like `rankdir` or `size`.
+    ///
+    /// The value is quoted and escaped.
+    pub fn with_graph_attribute(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.graph_attributes.push((name, value.into()));
+        self
+    }
+
+    /// Group the nodes into clusters: `get_cluster` returns the name of the
+    /// cluster of each node, or `None` to leave it outside of any cluster.
+    ///
+    /// Each cluster is drawn as a subgraph labeled with its name, in the order
+    /// of its first node, after the nodes outside of clusters.
+    pub fn with_clusters(
+        mut self,
+        get_cluster: &'a dyn Fn(G, G::NodeRef) -> Option<String>,
+    ) -> Self {
+        self.get_cluster = Some(get_cluster);
+        self
+    }
 }
 
 /// `Dot` configuration.
@@ -157,20 +301,31 @@ where
         if !self.config.GraphContentOnly {
             writeln!(f, "{} {{", TYPE[g.is_directed() as usize])?;
         }
+        for (name, value) in &self.graph_attributes {
+            writeln!(f, "{}{} = \"{}\"", INDENT, name, Escaped(value))?;
+        }
 
-        // output all labels
+        // output all labels, grouping the nodes of each cluster
+        let mut clusters: Vec<(String, Vec<G::NodeRef>)> = Vec::new();
         for node in g.node_references() {
-            write!(f, "{}{} [ ", INDENT, g.to_index(node.id()),)?;
-            if !self.config.NodeNoLabel {
-                write!(f, "label = \"")?;
-                if self.config.NodeIndexLabel {
-                    write!(f, "{}", g.to_index(node.id()))?;
-                } else {
-                    Escaped(FnFmt(node.weight(), &node_fmt)).fmt(f)?;
+            if let Some(get_cluster) = self.get_cluster {
+                if let Some(name) = get_cluster(g, node) {
+                    match clusters.iter_mut().find(|cluster| cluster.0 == name) {
+                        Some(cluster) => cluster.1.push(node),
+                        None => clusters.push((name, vec![node])),
+                    }
+                    continue;
                 }
-                write!(f, "\" ")?;
             }
-            writeln!(f, "{}]", (self.get_node_attributes)(g, node))?;
+            self.node_fmt(f, INDENT, node, &node_fmt)?;
+        }
+        for (i, (name, nodes)) in clusters.into_iter().enumerate() {
+            writeln!(f, "{}subgraph cluster_{} {{", INDENT, i)?;
+            writeln!(f, "{0}{0}label = \"{1}\"", INDENT, Escaped(name))?;
+            for node in nodes {
+                self.node_fmt(f, &INDENT.repeat(2), node, &node_fmt)?;
+            }
+            writeln!(f, "{}}}", INDENT)?;
         }
         // output all edges
         for (i, edge) in g.edge_references().enumerate() {
@@ -182,7 +337,11 @@ where
                 EDGE[g.is_directed() as usize],
                 g.to_index(edge.target()),
             )?;
-            if !self.config.EdgeNoLabel {
+            let attributes = match self.get_edge_attribute_list {
+                Some(get_attributes) => get_attributes(g, edge),
+                None => Vec::new(),
+            };
+            if !self.config.EdgeNoLabel && !has_label(&attributes) {
                 write!(f, "label = \"")?;
                 if self.config.EdgeIndexLabel {
                     write!(f, "{}", i)?;
@@ -191,6 +350,7 @@ where
                 }
                 write!(f, "\" ")?;
             }
+            attributes_fmt(f, &attributes)?;
             writeln!(f, "{}]", (self.get_edge_attributes)(g, edge))?;
         }
 
@@ -199,6 +359,47 @@ where
         }
         Ok(())
     }
+
+    fn node_fmt<NF>(
+        &self,
+        f: &mut fmt::Formatter,
+        indent: &str,
+        node: G::NodeRef,
+        node_fmt: &NF,
+    ) -> fmt::Result
+    where
+        NF: Fn(&G::NodeWeight, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let g = self.graph;
+        write!(f, "{}{} [ ", indent, g.to_index(node.id()))?;
+        let attributes = match self.get_node_attribute_list {
+            Some(get_attributes) => get_attributes(g, node),
+            None => Vec::new(),
+        };
+        if !self.config.NodeNoLabel && !has_label(&attributes) {
+            write!(f, "label = \"")?;
+            if self.config.NodeIndexLabel {
+                write!(f, "{}", g.to_index(node.id()))?;
+            } else {
+                Escaped(FnFmt(node.weight(), node_fmt)).fmt(f)?;
+            }
+            write!(f, "\" ")?;
+        }
+        attributes_fmt(f, &attributes)?;
+        writeln!(f, "{}]", (self.get_node_attributes)(g, node))
+    }
+}
+
+fn has_label(attributes: &[(&str, String)]) -> bool {
+    attributes.iter().any(|&(name, _)| name == "label")
+}
+
+/// Write `attributes` as `name = "value"`, each followed by a space.
+fn attributes_fmt(f: &mut fmt::Formatter, attributes: &[(&str, String)]) -> fmt::Result {
+    for (name, value) in attributes {
+        write!(f, "{} = \"{}\" ", name, Escaped(value))?;
+    }
+    Ok(())
 }
 
 impl<'a, G> fmt::Display for Dot<'a, G>
@@ -346,4 +547,90 @@ mod test {
         );
         assert_eq!(dot, "digraph {\n    0 [ label = \"a\"]\n    1 [ label = \"b\"]\n    0 -> 1 [ label = \"EDGE_LABEL\"]\n}\n");
     }
+
+    #[test]
+    fn test_attribute_lists() {
+        let graph = simple_graph();
+        let dot = format!(
+            "{}",
+            Dot::new(&graph)
+                .with_node_attributes(&|_, nr| vec![(
+                    "tooltip",
+                    format!("node \"{}\"", nr.weight())
+                )])
+                .with_edge_attributes(&|_, _| vec![
+                    ("label", "e".to_string()),
+                    ("color", "blue".to_string())
+                ])
+                .with_graph_attribute("rankdir", "LR")
+                .with_graph_attribute("size", "4,4"),
+        );
+        assert_eq!(dot, "digraph {\n    rankdir = \"LR\"\n    size = \"4,4\"\n    0 [ label = \"A\" tooltip = \"node \\\"A\\\"\" ]\n    1 [ label = \"B\" tooltip = \"node \\\"B\\\"\" ]\n    0 -> 1 [ label = \"e\" color = \"blue\" ]\n}\n");
+    }
+
+    #[test]
+    fn test_clusters() {
+        let mut graph = simple_graph();
+        graph.add_node("C");
+        graph.add_node("D");
+        let dot = format!(
+            "{}",
+            Dot::with_config(&graph, &[Config::EdgeNoLabel, Config::GraphContentOnly])
+                .with_clusters(&|_, nr| match *nr.weight() {
+                    "A" | "D" => Some("x".to_string()),
+                    "C" => Some("y".to_string()),
+                    _ => None,
+                }),
+        );
+        assert_eq!(dot, "    1 [ label = \"B\" ]\n    subgraph cluster_0 {\n        label = \"x\"\n        0 [ label = \"A\" ]\n        3 [ label = \"D\" ]\n    }\n    subgraph cluster_1 {\n        label = \"y\"\n        2 [ label = \"C\" ]\n    }\n    0 -> 1 [ ]\n");
+    }
+
+    #[test]
+    fn test_graph_types() {
+        use crate::adj::List;
+        use crate::concurrent_graph::ConcurrentGraph;
+        use crate::csr::Csr;
+        use crate::prelude::{UnGraph, Undirected};
+        use crate::visit::NodeFiltered;
+        use std::convert::TryFrom;
+
+        let mut graph = UnGraph::<u8, u8>::default();
+        graph.extend_with_edges(&[(0, 1, 1), (1, 2, 2), (2, 2, 3)]);
+        let expected = "graph {\n    0 [ label = \"0\" ]\n    1 [ label = \"0\" ]\n    2 [ label = \"0\" ]\n    0 -- 1 [ label = \"1\" ]\n    1 -- 2 [ label = \"2\" ]\n    2 -- 2 [ label = \"3\" ]\n}\n";
+        assert_eq!(format!("{}", Dot::new(&graph)), expected);
+
+        let csr = Csr::<_, _, _, u32>::try_from(graph.clone()).unwrap();
+        assert_eq!(format!("{}", Dot::new(&csr)), expected);
+        let filtered = NodeFiltered::from_fn(&csr, |_| true);
+        assert_eq!(format!("{}", Dot::new(&filtered)), expected);
+
+        #[cfg(feature = "matrix_graph")]
+        {
+            use crate::matrix_graph::MatrixGraph;
+            use crate::visit::EdgeFiltered;
+
+            let matrix = MatrixGraph::<_, _, _, Option<_>, u32>::try_from(graph.clone()).unwrap();
+            let filtered = EdgeFiltered::from_fn(&matrix, |e| *e.2 != 2);
+            assert_eq!(
+                format!("{}", Dot::new(&filtered)),
+                "graph {\n    0 [ label = \"0\" ]\n    1 [ label = \"0\" ]\n    2 [ label = \"0\" ]\n    1 -- 0 [ label = \"1\" ]\n    2 -- 2 [ label = \"3\" ]\n}\n"
+            );
+        }
+
+        let list = List::<u8>::from(graph.clone().into_edge_type());
+        assert_eq!(
+            format!("{:?}", Dot::with_config(&list, &[Config::NodeNoLabel])),
+            "digraph {\n    0 [ ]\n    1 [ ]\n    2 [ ]\n    0 -> 1 [ label = \"1\" ]\n    1 -> 2 [ label = \"2\" ]\n    2 -> 2 [ label = \"3\" ]\n}\n"
+        );
+
+        let concurrent = ConcurrentGraph::<u8, u8, Undirected>::default();
+        for _ in graph.node_indices() {
+            concurrent.add_node(0);
+        }
+        for edge in graph.raw_edges() {
+            concurrent.add_edge(edge.source(), edge.target(), edge.weight);
+        }
+        let snapshot = concurrent.snapshot();
+        assert_eq!(format!("{}", Dot::new(&snapshot)), expected);
+    }
 }
@@ -0,0 +1,921 @@
+//! Reading of graphviz .dot files.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::graph::{Graph, NodeIndex};
+use crate::EdgeType;
+
+/// The attributes of a node, an edge or a graph read from a .dot file, by
+/// name, in the order they were first set.
+pub type AttributeMap = IndexMap<String, String>;
+
+/// A node read from a .dot file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DotNode {
+    /// The node ID, which names the node in the .dot file.
+    pub id: String,
+    /// The attributes of the node.
+    pub attributes: AttributeMap,
+    /// The names of the subgraphs the node is in, outermost first.
+    pub subgraphs: Vec<String>,
+}
+
+/// A graph read from a .dot file, by [`parse`] or [`parse_with`].
+///
+/// [`parse`]: fn.parse.html
+/// [`parse_with`]: fn.parse_with.html
+#[derive(Clone, Debug)]
+pub struct ParsedGraph<N, E, Ty: EdgeType> {
+    /// The name of the graph, if it has one.
+    pub name: Option<String>,
+    /// Whether the graph is `strict`, which merges parallel edges.
+    pub strict: bool,
+    /// The attributes of the graph itself, like `rankdir`.
+    pub attributes: AttributeMap,
+    /// The nodes and edges, in the order they first appear in the file.
+    pub graph: Graph<N, E, Ty>,
+}
+
+/// An error reading a .dot file, at a line and column of the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    kind: ParseErrorKind,
+}
+
+/// The kind of a [`ParseError`].
+///
+/// [`ParseError`]: struct.ParseError.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input ended in the middle of the graph, a string or a comment.
+    UnexpectedEnd,
+    /// This token was not expected there.
+    UnexpectedToken(String),
+    /// This character doesn't start any token.
+    UnexpectedChar(char),
+    /// The graph is a `graph` read as directed, or a `digraph` read as
+    /// undirected.
+    GraphType,
+    /// There is an edge `--` in a `digraph`, or `->` in a `graph`.
+    EdgeOp,
+}
+
+impl ParseError {
+    /// The line of the error, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the error, in characters counting from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// What the error is.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match self.kind {
+            ParseErrorKind::UnexpectedEnd => f.write_str("unexpected end of input"),
+            ParseErrorKind::UnexpectedToken(ref token) => {
+                write!(f, "unexpected token `{}`", token)
+            }
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {:?}", c),
+            ParseErrorKind::GraphType => {
+                f.write_str("the graph is not of the expected directed or undirected type")
+            }
+            ParseErrorKind::EdgeOp => f.write_str("the edge doesn't match the graph type"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Read a graph in graphviz .dot format, with the IDs and attributes of the
+/// nodes and the attributes of the edges as weights.
+///
+/// `Ty` picks whether a `digraph` or a `graph` is expected; the other is an
+/// error.
+///
+/// The whole .dot language is read:
+///
+/// - IDs may be identifiers, numerals, quoted strings, concatenations of
+///   quoted strings with `+`, or HTML strings like `<<b>bold</b>>`. In quoted
+///   strings `\"` is read as `"`, and a backslash at the end of a line joins
+///   it with the next; other escapes like `\n` are kept as they are, for
+///   graphviz to interpret. HTML strings are read without their outermost
+///   `<` and `>`.
+/// - Default attributes set with `node [...]` and `edge [...]` apply to the
+///   nodes and edges created after them, in the same subgraph.
+/// - An edge from or to a subgraph is an edge from or to each of its nodes,
+///   and an edge statement like `a -> b -> c` adds an edge for each step.
+/// - In a `strict` graph, the attributes of parallel edges are merged into
+///   a single edge.
+/// - Ports, as in `a:n -> b:s`, are read as the `tailport` and `headport`
+///   attributes of the edge.
+/// - Attributes of subgraphs are not kept; the names of the subgraphs a node
+///   is in are in [`DotNode::subgraphs`].
+///
+/// Comments, `// ...`, `/* ... */` and lines starting with `#`, are skipped.
+///
+/// [`DotNode::subgraphs`]: struct.DotNode.html#structfield.subgraphs
+///
+/// ```
+/// use petgraph::dot::parse;
+/// use petgraph::Directed;
+///
+/// let parsed = parse::<Directed>(
+///     r#"digraph deps {
+///         rankdir = LR
+///         node [shape = box]
+///         main -> { "a.rs" "b.rs" } [color = red]
+///     }"#,
+/// )
+/// .unwrap();
+/// assert_eq!(parsed.name.as_deref(), Some("deps"));
+/// assert_eq!(parsed.attributes["rankdir"], "LR");
+///
+/// let graph = parsed.graph;
+/// assert_eq!(graph.node_count(), 3);
+/// assert_eq!(graph.edge_count(), 2);
+/// for node in graph.node_weights() {
+///     assert_eq!(node.attributes["shape"], "box");
+/// }
+/// for edge in graph.edge_weights() {
+///     assert_eq!(edge["color"], "red");
+/// }
+/// ```
+pub fn parse<Ty: EdgeType>(
+    input: &str,
+) -> Result<ParsedGraph<DotNode, AttributeMap, Ty>, ParseError> {
+    let tokens = lex(input)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        end: end_position(input),
+        graph: Graph::default(),
+        node_ids: HashMap::new(),
+        attributes: AttributeMap::new(),
+        strict: false,
+    };
+    let name = parser.parse_graph()?;
+    Ok(ParsedGraph {
+        name,
+        strict: parser.strict,
+        attributes: parser.attributes,
+        graph: parser.graph,
+    })
+}
+
+/// Read a graph in graphviz .dot format, like [`parse`], and make the node
+/// and edge weights with `node_weight` and `edge_weight`.
+///
+/// [`parse`]: fn.parse.html
+///
+/// ```
+/// use petgraph::dot::parse_with;
+/// use petgraph::Undirected;
+///
+/// let parsed = parse_with::<_, _, Undirected, _, _>(
+///     "graph { a -- b [weight = 2.5]; b -- c [weight = 1] }",
+///     |node| node.id.clone(),
+///     |edge| edge["weight"].parse::<f64>().unwrap(),
+/// )
+/// .unwrap();
+/// let graph = parsed.graph;
+/// assert_eq!(graph.node_weights().collect::<Vec<_>>(), ["a", "b", "c"]);
+/// assert_eq!(graph.edge_weights().sum::<f64>(), 3.5);
+/// ```
+pub fn parse_with<N, E, Ty, NF, EF>(
+    input: &str,
+    mut node_weight: NF,
+    mut edge_weight: EF,
+) -> Result<ParsedGraph<N, E, Ty>, ParseError>
+where
+    Ty: EdgeType,
+    NF: FnMut(&DotNode) -> N,
+    EF: FnMut(&AttributeMap) -> E,
+{
+    let parsed = parse::<Ty>(input)?;
+    Ok(ParsedGraph {
+        name: parsed.name,
+        strict: parsed.strict,
+        attributes: parsed.attributes,
+        graph: parsed
+            .graph
+            .map(|_, node| node_weight(node), |_, edge| edge_weight(edge)),
+    })
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// An identifier, numeral or string.
+    Id(String),
+    /// A quoted string, which can be concatenated with `+`.
+    Quoted(String),
+    Keyword(Keyword),
+    Punct(char),
+    EdgeOp {
+        directed: bool,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Keyword {
+    Strict,
+    Graph,
+    Digraph,
+    Subgraph,
+    Node,
+    Edge,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Token::Id(ref id) => f.write_str(id),
+            Token::Quoted(ref id) => write!(f, "\"{}\"", id),
+            Token::Keyword(keyword) => f.write_str(match keyword {
+                Keyword::Strict => "strict",
+                Keyword::Graph => "graph",
+                Keyword::Digraph => "digraph",
+                Keyword::Subgraph => "subgraph",
+                Keyword::Node => "node",
+                Keyword::Edge => "edge",
+            }),
+            Token::Punct(c) => write!(f, "{}", c),
+            Token::EdgeOp { directed: true } => f.write_str("->"),
+            Token::EdgeOp { directed: false } => f.write_str("--"),
+        }
+    }
+}
+
+/// A token, with its line and column.
+type Spanned = (Token, usize, usize);
+
+fn error(line: usize, column: usize, kind: ParseErrorKind) -> ParseError {
+    ParseError { line, column, kind }
+}
+
+/// The position just after the end of `input`.
+fn end_position(input: &str) -> (usize, usize) {
+    let line = input.split('\n').count();
+    let column = input
+        .rsplit('\n')
+        .next()
+        .map_or(0, |last| last.chars().count());
+    (line, column + 1)
+}
+
+fn is_id_start(c: char) -> bool {
+    c == '_' || c.is_ascii_alphabetic() || !c.is_ascii()
+}
+
+fn is_id_continue(c: char) -> bool {
+    is_id_start(c) || c.is_ascii_digit()
+}
+
+fn lex(input: &str) -> Result<Vec<Spanned>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    let (mut line, mut column) = (1, 1);
+    let mut line_start = true;
+    // Take the next character, keeping track of the position.
+    macro_rules! bump {
+        () => {{
+            let c = chars.next();
+            if c == Some('\n') {
+                line += 1;
+                column = 1;
+            } else if c.is_some() {
+                column += 1;
+            }
+            c
+        }};
+    }
+    macro_rules! bump_or_end {
+        () => {
+            match bump!() {
+                Some(c) => c,
+                None => return Err(error(line, column, ParseErrorKind::UnexpectedEnd)),
+            }
+        };
+    }
+
+    while let Some(&c) = chars.peek() {
+        let (start_line, start_column) = (line, column);
+        if c == '\n' {
+            bump!();
+            line_start = true;
+            continue;
+        }
+        if c.is_whitespace() {
+            bump!();
+            continue;
+        }
+        if c == '#' && line_start {
+            while chars.peek().map_or(false, |&c| c != '\n') {
+                bump!();
+            }
+            continue;
+        }
+        line_start = false;
+        bump!();
+        let token = match c {
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.peek().map_or(false, |&c| c != '\n') {
+                    bump!();
+                }
+                continue;
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                bump!();
+                loop {
+                    if bump_or_end!() == '*' && chars.peek() == Some(&'/') {
+                        bump!();
+                        break;
+                    }
+                }
+                continue;
+            }
+            '{' | '}' | '[' | ']' | '=' | ';' | ',' | ':' | '+' => Token::Punct(c),
+            '-' if chars.peek() == Some(&'>') => {
+                bump!();
+                Token::EdgeOp { directed: true }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                bump!();
+                Token::EdgeOp { directed: false }
+            }
+            '"' => {
+                let mut id = String::new();
+                loop {
+                    match bump_or_end!() {
+                        '"' => break,
+                        '\\' => match bump_or_end!() {
+                            '"' => id.push('"'),
+                            '\n' => {}
+                            '\r' if chars.peek() == Some(&'\n') => {
+                                bump!();
+                            }
+                            c => {
+                                id.push('\\');
+                                id.push(c);
+                            }
+                        },
+                        c => id.push(c),
+                    }
+                }
+                Token::Quoted(id)
+            }
+            '<' => {
+                let mut id = String::new();
+                let mut depth = 0;
+                loop {
+                    let c = bump_or_end!();
+                    match c {
+                        '<' => depth += 1,
+                        '>' if depth == 0 => break,
+                        '>' => depth -= 1,
+                        _ => {}
+                    }
+                    id.push(c);
+                }
+                Token::Id(id)
+            }
+            c if c == '-' || c == '.' || c.is_ascii_digit() => {
+                let mut id = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if c == '.' || c.is_ascii_digit() {
+                        id.push(c);
+                        bump!();
+                    } else {
+                        break;
+                    }
+                }
+                if id == "-" || id == "." || id == "-." {
+                    return Err(error(
+                        start_line,
+                        start_column,
+                        ParseErrorKind::UnexpectedChar(c),
+                    ));
+                }
+                Token::Id(id)
+            }
+            c if is_id_start(c) => {
+                let mut id = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if is_id_continue(c) {
+                        id.push(c);
+                        bump!();
+                    } else {
+                        break;
+                    }
+                }
+                match &*id.to_ascii_lowercase() {
+                    "strict" => Token::Keyword(Keyword::Strict),
+                    "graph" => Token::Keyword(Keyword::Graph),
+                    "digraph" => Token::Keyword(Keyword::Digraph),
+                    "subgraph" => Token::Keyword(Keyword::Subgraph),
+                    "node" => Token::Keyword(Keyword::Node),
+                    "edge" => Token::Keyword(Keyword::Edge),
+                    _ => Token::Id(id),
+                }
+            }
+            c => {
+                return Err(error(
+                    start_line,
+                    start_column,
+                    ParseErrorKind::UnexpectedChar(c),
+                ))
+            }
+        };
+        tokens.push((token, start_line, start_column));
+    }
+    Ok(tokens)
+}
+
+/// The default attributes and the subgraphs of the statements being read.
+#[derive(Clone, Default)]
+struct Scope {
+    node: AttributeMap,
+    edge: AttributeMap,
+    subgraphs: Vec<String>,
+}
+
+/// A side of an edge statement.
+enum Operand {
+    /// A node, and its port.
+    Node(NodeIndex, Option<String>),
+    /// The nodes of a subgraph.
+    Subgraph(Vec<NodeIndex>),
+}
+
+impl Operand {
+    fn nodes_port(&self) -> (&[NodeIndex], Option<&String>) {
+        match *self {
+            Operand::Node(ref node, ref port) => (std::slice::from_ref(node), port.as_ref()),
+            Operand::Subgraph(ref nodes) => (nodes, None),
+        }
+    }
+}
+
+struct Parser<'a, Ty> {
+    tokens: &'a [Spanned],
+    pos: usize,
+    /// The position of the end of the input.
+    end: (usize, usize),
+    graph: Graph<DotNode, AttributeMap, Ty>,
+    node_ids: HashMap<String, NodeIndex>,
+    attributes: AttributeMap,
+    strict: bool,
+}
+
+impl<'a, Ty: EdgeType> Parser<'a, Ty> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.pos).map(|token| &token.0)
+    }
+
+    fn next(&mut self) -> Result<&'a Token, ParseError> {
+        match self.tokens.get(self.pos) {
+            Some(token) => {
+                self.pos += 1;
+                Ok(&token.0)
+            }
+            None => Err(self.unexpected(self.pos)),
+        }
+    }
+
+    /// Skip the next token if it is `token`.
+    fn eat(&mut self, token: &Token) -> bool {
+        let found = self.peek() == Some(token);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, punct: char) -> Result<(), ParseError> {
+        if self.eat(&Token::Punct(punct)) {
+            Ok(())
+        } else {
+            Err(self.unexpected(self.pos))
+        }
+    }
+
+    fn error(&self, pos: usize, kind: ParseErrorKind) -> ParseError {
+        match self.tokens.get(pos) {
+            Some(&(_, line, column)) => error(line, column, kind),
+            None => error(self.end.0, self.end.1, ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    /// An error for the token at `pos`, or for the end of the input.
+    fn unexpected(&self, pos: usize) -> ParseError {
+        let token = self
+            .tokens
+            .get(pos)
+            .map_or(String::new(), |t| t.0.to_string());
+        self.error(pos, ParseErrorKind::UnexpectedToken(token))
+    }
+
+    /// `graph : [ strict ] (graph | digraph) [ ID ] '{' stmt_list '}'`
+    fn parse_graph(&mut self) -> Result<Option<String>, ParseError> {
+        self.strict = self.eat(&Token::Keyword(Keyword::Strict));
+        let directed = match *self.next()? {
+            Token::Keyword(Keyword::Digraph) => true,
+            Token::Keyword(Keyword::Graph) => false,
+            _ => return Err(self.unexpected(self.pos - 1)),
+        };
+        if directed != Ty::is_directed() {
+            return Err(self.error(self.pos - 1, ParseErrorKind::GraphType));
+        }
+        let name = match self.peek() {
+            Some(Token::Punct('{')) => None,
+            _ => Some(self.id()?),
+        };
+        self.expect('{')?;
+        self.stmt_list(&mut Scope::default(), true)?;
+        if self.pos < self.tokens.len() {
+            return Err(self.unexpected(self.pos));
+        }
+        Ok(name)
+    }
+
+    /// Read statements up to and including the closing `}`, and return the
+    /// nodes in them.
+    fn stmt_list(
+        &mut self,
+        scope: &mut Scope,
+        top: bool,
+    ) -> Result<IndexSet<NodeIndex>, ParseError> {
+        let mut nodes = IndexSet::new();
+        loop {
+            match self.peek() {
+                Some(Token::Punct('}')) => {
+                    self.pos += 1;
+                    return Ok(nodes);
+                }
+                Some(Token::Punct(';')) => self.pos += 1,
+                _ => self.stmt(scope, top, &mut nodes)?,
+            }
+        }
+    }
+
+    fn stmt(
+        &mut self,
+        scope: &mut Scope,
+        top: bool,
+        nodes: &mut IndexSet<NodeIndex>,
+    ) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(Token::Keyword(Keyword::Graph)) => {
+                self.pos += 1;
+                let attributes = self.attr_list()?;
+                if top {
+                    self.attributes.extend(attributes);
+                }
+                return Ok(());
+            }
+            Some(Token::Keyword(Keyword::Node)) => {
+                self.pos += 1;
+                let attributes = self.attr_list()?;
+                scope.node.extend(attributes);
+                return Ok(());
+            }
+            Some(Token::Keyword(Keyword::Edge)) => {
+                self.pos += 1;
+                let attributes = self.attr_list()?;
+                scope.edge.extend(attributes);
+                return Ok(());
+            }
+            _ => {}
+        }
+        if self.tokens.get(self.pos + 1).map(|t| &t.0) == Some(&Token::Punct('=')) {
+            let name = self.id()?;
+            self.expect('=')?;
+            let value = self.id()?;
+            if top {
+                self.attributes.insert(name, value);
+            }
+            return Ok(());
+        }
+
+        let first = self.operand(scope, nodes)?;
+        if let Some(Token::EdgeOp { .. }) = self.peek() {
+            let mut operands = vec![first];
+            while let Some(&Token::EdgeOp { directed }) = self.peek() {
+                if directed != Ty::is_directed() {
+                    return Err(self.error(self.pos, ParseErrorKind::EdgeOp));
+                }
+                self.pos += 1;
+                operands.push(self.operand(scope, nodes)?);
+            }
+            let attributes = match self.peek() {
+                Some(Token::Punct('[')) => self.attr_list()?,
+                _ => AttributeMap::new(),
+            };
+            for pair in operands.windows(2) {
+                self.add_edges(&pair[0], &pair[1], scope, &attributes);
+            }
+        } else if let Operand::Node(node, _) = first {
+            if let Some(Token::Punct('[')) = self.peek() {
+                let attributes = self.attr_list()?;
+                self.graph[node].attributes.extend(attributes);
+            }
+        }
+        Ok(())
+    }
+
+    /// `attr_list : '[' [ a_list ] ']' [ attr_list ]`
+    fn attr_list(&mut self) -> Result<AttributeMap, ParseError> {
+        let mut attributes = AttributeMap::new();
+        if self.peek() != Some(&Token::Punct('[')) {
+            return Err(self.unexpected(self.pos));
+        }
+        while self.eat(&Token::Punct('[')) {
+            while !self.eat(&Token::Punct(']')) {
+                let name = self.id()?;
+                self.expect('=')?;
+                let value = self.id()?;
+                attributes.insert(name, value);
+                if !self.eat(&Token::Punct(';')) {
+                    self.eat(&Token::Punct(','));
+                }
+            }
+        }
+        Ok(attributes)
+    }
+
+    /// A node with its port, or a subgraph.
+    fn operand(
+        &mut self,
+        scope: &Scope,
+        nodes: &mut IndexSet<NodeIndex>,
+    ) -> Result<Operand, ParseError> {
+        match self.peek() {
+            Some(Token::Keyword(Keyword::Subgraph)) | Some(Token::Punct('{')) => {
+                let mut inner = scope.clone();
+                if self.eat(&Token::Keyword(Keyword::Subgraph))
+                    && self.peek() != Some(&Token::Punct('{'))
+                {
+                    inner.subgraphs.push(self.id()?);
+                }
+                self.expect('{')?;
+                let inner_nodes = self.stmt_list(&mut inner, false)?;
+                nodes.extend(inner_nodes.iter().cloned());
+                Ok(Operand::Subgraph(inner_nodes.into_iter().collect()))
+            }
+            _ => {
+                let id = self.id()?;
+                let port = if self.eat(&Token::Punct(':')) {
+                    let mut port = self.id()?;
+                    if self.eat(&Token::Punct(':')) {
+                        port.push(':');
+                        port.push_str(&self.id()?);
+                    }
+                    Some(port)
+                } else {
+                    None
+                };
+                let node = self.node(id, scope);
+                nodes.insert(node);
+                Ok(Operand::Node(node, port))
+            }
+        }
+    }
+
+    /// An identifier, numeral, HTML string, or quoted strings joined by `+`.
+    fn id(&mut self) -> Result<String, ParseError> {
+        match *self.next()? {
+            Token::Id(ref id) => Ok(id.clone()),
+            Token::Quoted(ref id) => {
+                let mut id = id.clone();
+                while self.eat(&Token::Punct('+')) {
+                    match *self.next()? {
+                        Token::Quoted(ref more) => id.push_str(more),
+                        _ => return Err(self.unexpected(self.pos - 1)),
+                    }
+                }
+                Ok(id)
+            }
+            _ => Err(self.unexpected(self.pos - 1)),
+        }
+    }
+
+    /// The node with this ID, added with the default attributes if it is new.
+    fn node(&mut self, id: String, scope: &Scope) -> NodeIndex {
+        let node = match self.node_ids.get(&id) {
+            Some(&node) => node,
+            None => {
+                let node = self.graph.add_node(DotNode {
+                    id: id.clone(),
+                    attributes: scope.node.clone(),
+                    subgraphs: Vec::new(),
+                });
+                self.node_ids.insert(id, node);
+                node
+            }
+        };
+        let subgraphs = &mut self.graph[node].subgraphs;
+        for name in &scope.subgraphs {
+            if !subgraphs.contains(name) {
+                subgraphs.push(name.clone());
+            }
+        }
+        node
+    }
+
+    /// Add an edge from each node of `tail` to each node of `head`.
+    fn add_edges(
+        &mut self,
+        tail: &Operand,
+        head: &Operand,
+        scope: &Scope,
+        attributes: &AttributeMap,
+    ) {
+        let (tails, tail_port) = tail.nodes_port();
+        let (heads, head_port) = head.nodes_port();
+        for &a in tails {
+            for &b in heads {
+                let mut weight = scope.edge.clone();
+                if let Some(port) = tail_port {
+                    weight.insert("tailport".to_string(), port.clone());
+                }
+                if let Some(port) = head_port {
+                    weight.insert("headport".to_string(), port.clone());
+                }
+                weight.extend(attributes.iter().map(|(k, v)| (k.clone(), v.clone())));
+                if self.strict {
+                    if let Some(edge) = self.graph.find_edge(a, b) {
+                        self.graph[edge].extend(weight);
+                        continue;
+                    }
+                }
+                self.graph.add_edge(a, b, weight);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, ParseErrorKind};
+    use crate::dot::{Config, Dot};
+    use crate::graph::NodeIndex;
+    use crate::prelude::{Directed, Graph, Undirected};
+
+    fn edges<N, E, Ty: crate::EdgeType>(graph: &Graph<N, E, Ty>) -> Vec<(usize, usize)> {
+        graph
+            .raw_edges()
+            .iter()
+            .map(|e| (e.source().index(), e.target().index()))
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut graph = Graph::<&str, &str>::new();
+        let a = graph.add_node("a \"quoted\"");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b, "ab");
+        graph.add_edge(b, b, "loop");
+        let dot = format!("{}", Dot::with_config(&graph, &[Config::NodeIndexLabel]));
+        let parsed = parse::<Directed>(&dot).unwrap();
+        assert_eq!(parsed.name, None);
+        let g = parsed.graph;
+        assert_eq!(g.node_count(), 2);
+        assert_eq!(g[NodeIndex::new(1)].id, "1");
+        assert_eq!(g[NodeIndex::new(1)].attributes["label"], "1");
+        assert_eq!(edges(&g), [(0, 1), (1, 1)]);
+        assert_eq!(g.raw_edges()[1].weight["label"], "loop");
+
+        let dot = format!("{}", Dot::new(&graph));
+        let g = parse::<Directed>(&dot).unwrap().graph;
+        assert_eq!(g[NodeIndex::new(0)].attributes["label"], "a \"quoted\"");
+    }
+
+    #[test]
+    fn test_defaults_and_subgraphs() {
+        let parsed = parse::<Undirected>(
+            r#"
+            /* a comment */
+            strict graph G {
+                graph [size = "4,4"]; label = top
+                node [color = red]
+                a // comment
+                subgraph cluster_x {
+                    node [shape = box]
+                    b; c [shape = circle]
+                    subgraph inner { d }
+                    graph [label = ignored]
+                }
+                a -- subgraph { e f } [weight = 1]
+                e -- a [weight = 2, style = bold]
+                edge [color = blue]
+                b -- c -- b
+# preprocessor line
+            }
+            "#,
+        )
+        .unwrap();
+        assert!(parsed.strict);
+        assert_eq!(parsed.name.as_deref(), Some("G"));
+        assert_eq!(
+            parsed.attributes.iter().collect::<Vec<_>>(),
+            [
+                (&"size".to_string(), &"4,4".to_string()),
+                (&"label".to_string(), &"top".to_string())
+            ]
+        );
+        let g = parsed.graph;
+        let ids = g.node_weights().map(|n| &*n.id).collect::<Vec<_>>();
+        assert_eq!(ids, ["a", "b", "c", "d", "e", "f"]);
+        let b = &g[NodeIndex::new(1)];
+        assert_eq!(b.attributes["color"], "red");
+        assert_eq!(b.attributes["shape"], "box");
+        assert_eq!(g[NodeIndex::new(2)].attributes["shape"], "circle");
+        assert_eq!(g[NodeIndex::new(3)].subgraphs, ["cluster_x", "inner"]);
+        assert!(!g[NodeIndex::new(4)].attributes.contains_key("shape"));
+        assert!(g[NodeIndex::new(4)].subgraphs.is_empty());
+
+        // the strict graph merges `e -- a` into `a -- e`, and `c -- b` into
+        // `b -- c`
+        assert_eq!(edges(&g), [(0, 4), (0, 5), (1, 2)]);
+        assert_eq!(g.raw_edges()[0].weight["weight"], "2");
+        assert_eq!(g.raw_edges()[0].weight["style"], "bold");
+        assert_eq!(g.raw_edges()[1].weight["weight"], "1");
+        assert_eq!(g.raw_edges()[2].weight["color"], "blue");
+    }
+
+    #[test]
+    fn test_ids() {
+        let parsed = parse::<Directed>(
+            "digraph { \"a\" + \"\\\nb\" -> -1.5:n -> <<b>x</b>>:p:sw -> _\u{e9} [label = \"a\\\"\\l\"] }",
+        )
+        .unwrap();
+        let g = parsed.graph;
+        let ids = g.node_weights().map(|n| &*n.id).collect::<Vec<_>>();
+        assert_eq!(ids, ["ab", "-1.5", "<b>x</b>", "_\u{e9}"]);
+        assert_eq!(g.raw_edges()[0].weight["headport"], "n");
+        assert_eq!(g.raw_edges()[1].weight["tailport"], "n");
+        assert_eq!(g.raw_edges()[1].weight["headport"], "p:sw");
+        assert_eq!(g.raw_edges()[2].weight["label"], "a\"\\l");
+        // not strict, so parallel edges are kept
+        let g = parse::<Directed>("DiGraph { a -> b; a -> b }")
+            .unwrap()
+            .graph;
+        assert_eq!(g.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |input| {
+            let error = parse::<Directed>(input).unwrap_err();
+            (error.line(), error.column(), error.kind().clone())
+        };
+        assert_eq!(error("graph {}"), (1, 1, ParseErrorKind::GraphType));
+        assert_eq!(
+            error("digraph {\n a -- b }"),
+            (2, 4, ParseErrorKind::EdgeOp)
+        );
+        assert_eq!(
+            error("digraph {\n a -> "),
+            (2, 7, ParseErrorKind::UnexpectedEnd)
+        );
+        assert_eq!(
+            error("digraph { \"a }"),
+            (1, 15, ParseErrorKind::UnexpectedEnd)
+        );
+        assert_eq!(
+            error("digraph { a [b] }"),
+            (1, 15, ParseErrorKind::UnexpectedToken("]".to_string()))
+        );
+        assert_eq!(
+            error("digraph { } }"),
+            (1, 13, ParseErrorKind::UnexpectedToken("}".to_string()))
+        );
+        assert_eq!(
+            error("digraph { a @ }"),
+            (1, 13, ParseErrorKind::UnexpectedChar('@'))
+        );
+        assert_eq!(
+            parse::<Directed>("digraph {\n a -> ")
+                .unwrap_err()
+                .to_string(),
+            "2:7: unexpected end of input"
+        );
+    }
+}
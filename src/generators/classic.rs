@@ -0,0 +1,168 @@
+//! Complete graphs and other classic families.
+
+use super::{from_edges, with_nodes};
+use crate::data::Create;
+use crate::visit::GraphProp;
+
+/// Create the complete graph on `n` nodes, with an edge between each pair of
+/// distinct nodes.
+///
+/// A directed graph type gets edges in both directions between each pair of
+/// nodes, for `n * (n - 1)` edges; an undirected one gets `n * (n - 1) / 2`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::complete_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = complete_graph(5);
+/// assert_eq!(g.edge_count(), 10);
+/// let g: DiGraph<(), ()> = complete_graph(5);
+/// assert_eq!(g.edge_count(), 20);
+/// ```
+pub fn complete_graph<G>(n: usize) -> G
+where
+    G: Create + GraphProp,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let directed = G::default().is_directed();
+    let pairs = n * n.saturating_sub(1) / 2;
+    let edges = if directed { 2 * pairs } else { pairs };
+    let (mut g, nodes) = with_nodes::<G>(n, edges);
+    for (i, &a) in nodes.iter().enumerate() {
+        for &b in &nodes[i + 1..] {
+            g.add_edge(a, b, G::EdgeWeight::default());
+            if directed {
+                g.add_edge(b, a, G::EdgeWeight::default());
+            }
+        }
+    }
+    g
+}
+
+/// Create the complete bipartite graph with `m` nodes on one side and `n` on
+/// the other, and an edge between each pair of nodes on different sides.
+///
+/// The nodes `0..m` are on the first side and `m..m + n` on the second, and
+/// the `m * n` edges go from the first side to the second.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::complete_bipartite_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = complete_bipartite_graph(2, 3);
+/// assert_eq!(g.node_count(), 5);
+/// assert_eq!(g.edge_count(), 6);
+/// assert!(g.contains_edge(1.into(), 4.into()));
+/// ```
+pub fn complete_bipartite_graph<G>(m: usize, n: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let (mut g, nodes) = with_nodes::<G>(m + n, m * n);
+    let (first, second) = nodes.split_at(m);
+    for &a in first {
+        for &b in second {
+            g.add_edge(a, b, G::EdgeWeight::default());
+        }
+    }
+    g
+}
+
+/// Create the path graph on `n` nodes, with an edge from each node `i` to
+/// node `i + 1`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::path_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: DiGraph<(), ()> = path_graph(4);
+/// assert_eq!(g.edge_count(), 3);
+/// assert!(g.contains_edge(2.into(), 3.into()));
+/// ```
+pub fn path_graph<G>(n: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    from_edges(n, (1..n).map(|i| (i - 1, i)))
+}
+
+/// Create the cycle graph on `n` nodes, with an edge from each node `i` to
+/// node `i + 1`, and from the last node to node `0`.
+///
+/// With fewer than three nodes the closing edge would be a self loop or a
+/// parallel edge, so it is left out and the result is a path.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::cycle_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: DiGraph<(), ()> = cycle_graph(4);
+/// assert_eq!(g.edge_count(), 4);
+/// assert!(g.contains_edge(3.into(), 0.into()));
+/// ```
+pub fn cycle_graph<G>(n: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let closing = if n >= 3 { Some((n - 1, 0)) } else { None };
+    from_edges(n, (1..n).map(|i| (i - 1, i)).chain(closing))
+}
+
+/// Create the star graph on `n` nodes, with an edge from the center, node
+/// `0`, to each of the other nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::star_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = star_graph(5);
+/// assert_eq!(g.edge_count(), 4);
+/// assert_eq!(g.neighbors(0.into()).count(), 4);
+/// ```
+pub fn star_graph<G>(n: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    from_edges(n, (1..n).map(|i| (0, i)))
+}
+
+/// Create the wheel graph on `n` nodes: a hub, node `0`, with an edge to each
+/// of the other nodes, which form a cycle.
+///
+/// The edges are those of [`star_graph(n)`](fn.star_graph.html), followed by
+/// those of [`cycle_graph(n - 1)`](fn.cycle_graph.html) on the nodes `1..n`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::wheel_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = wheel_graph(6);
+/// assert_eq!(g.edge_count(), 10);
+/// assert!(g.node_indices().skip(1).all(|a| g.neighbors(a).count() == 3));
+/// ```
+pub fn wheel_graph<G>(n: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let closing = if n >= 4 { Some((n - 1, 1)) } else { None };
+    let spokes = (1..n).map(|i| (0, i));
+    let rim = (2..n).map(|i| (i - 1, i)).chain(closing);
+    from_edges(n, spokes.chain(rim))
+}
@@ -0,0 +1,191 @@
+//! Grid graphs.
+
+use std::collections::HashSet;
+
+use crate::data::Create;
+
+/// A generator of grid graphs, in any number of dimensions.
+///
+/// The nodes are the points with integer coordinates `c` where
+/// `0 <= c[k] < dims[k]` for each dimension `k`, numbered with the first
+/// coordinate varying fastest: the point `[x, y, z]` of a `[w, h, d]` grid is
+/// node `x + w * (y + h * z)`. By default each node is joined to the nodes one
+/// step away along each axis, which is the 4-neighborhood in two dimensions
+/// and the 6-neighborhood in three.
+///
+/// With [`diagonals`](#method.diagonals), each node is also joined to the
+/// nodes one step away along several axes at once: the 8-neighborhood in two
+/// dimensions (the moves of a chess king) and the 26-neighborhood in three.
+/// With [`periodic`](#method.periodic), the grid wraps around at its borders
+/// like a torus. Edges go from the node with the smaller coordinate along the
+/// first axis where the two endpoints differ, taking a wrapped step as one
+/// step forward. Neither self loops nor parallel edges are created, even if
+/// the grid wraps around a side of one or two nodes.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::Grid;
+/// use petgraph::prelude::*;
+///
+/// // A 3 × 2 grid with the coordinates as node weights.
+/// let g: UnGraph<[usize; 2], ()> = Grid::new(&[3, 2]).build_with(|c| [c[0], c[1]]);
+/// assert_eq!(g.node_count(), 6);
+/// assert_eq!(g.edge_count(), 7);
+/// assert_eq!(g[NodeIndex::new(4)], [1, 1]);
+///
+/// // A torus where each node has 8 neighbors.
+/// let g: UnGraph<(), ()> = Grid::new(&[4, 4]).diagonals(true).periodic(true).build();
+/// assert!(g.node_indices().all(|a| g.neighbors(a).count() == 8));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Grid {
+    dims: Vec<usize>,
+    diagonals: bool,
+    periodic: bool,
+}
+
+impl Grid {
+    /// Create a generator of grids with the given size along each dimension,
+    /// without diagonals or wrapping around.
+    pub fn new(dims: &[usize]) -> Self {
+        Grid {
+            dims: dims.to_vec(),
+            diagonals: false,
+            periodic: false,
+        }
+    }
+
+    /// Set whether to join nodes one step away along several axes at once.
+    pub fn diagonals(mut self, diagonals: bool) -> Self {
+        self.diagonals = diagonals;
+        self
+    }
+
+    /// Set whether the grid wraps around at its borders.
+    pub fn periodic(mut self, periodic: bool) -> Self {
+        self.periodic = periodic;
+        self
+    }
+
+    /// Return the coordinates of the node with number `index`.
+    pub fn coordinates(&self, mut index: usize) -> Vec<usize> {
+        self.dims
+            .iter()
+            .map(|&size| {
+                let c = index % size;
+                index /= size;
+                c
+            })
+            .collect()
+    }
+
+    /// Create the grid graph with default node and edge weights.
+    pub fn build<G>(&self) -> G
+    where
+        G: Create,
+        G::NodeWeight: Default,
+        G::EdgeWeight: Default,
+    {
+        self.build_with(|_| G::NodeWeight::default())
+    }
+
+    /// Create the grid graph, with the node weights computed from the
+    /// coordinates of the nodes by `node_weight`, and default edge weights.
+    pub fn build_with<G, F>(&self, mut node_weight: F) -> G
+    where
+        G: Create,
+        G::EdgeWeight: Default,
+        F: FnMut(&[usize]) -> G::NodeWeight,
+    {
+        let n = self.dims.iter().product::<usize>();
+        let steps = self.steps();
+        let mut edges = Vec::with_capacity(n * steps.len());
+        let mut seen = HashSet::new();
+        let mut coords = vec![0; self.dims.len()];
+        for i in 0..n {
+            for step in &steps {
+                if let Some(j) = self.neighbor(&coords, step) {
+                    // Wrapping around a side of at most two nodes can lead
+                    // back to the node itself or to a node already joined.
+                    let pair = (i.min(j), i.max(j));
+                    if i != j && (!self.periodic || seen.insert(pair)) {
+                        edges.push((i, j));
+                    }
+                }
+            }
+            self.advance(&mut coords);
+        }
+
+        let mut g = G::with_capacity(n, edges.len());
+        let mut coords = vec![0; self.dims.len()];
+        let nodes = (0..n)
+            .map(|_| {
+                let a = g.add_node(node_weight(&coords));
+                self.advance(&mut coords);
+                a
+            })
+            .collect::<Vec<_>>();
+        for (i, j) in edges {
+            g.add_edge(nodes[i], nodes[j], G::EdgeWeight::default());
+        }
+        g
+    }
+
+    /// Return the steps from a node to the nodes it has edges to: the vectors
+    /// in `{-1, 0, 1}^d` whose first nonzero entry is `1`, either all of them
+    /// or those with a single nonzero entry.
+    fn steps(&self) -> Vec<Vec<isize>> {
+        let d = self.dims.len();
+        if !self.diagonals {
+            return (0..d)
+                .map(|k| (0..d).map(|l| (k == l) as isize).collect())
+                .collect();
+        }
+        let mut steps = Vec::new();
+        let mut step = vec![-1isize; d];
+        loop {
+            if step.iter().find(|&&s| s != 0) == Some(&1) {
+                steps.push(step.clone());
+            }
+            // count in base 3 over the digits -1, 0, 1
+            match step.iter().position(|&s| s < 1) {
+                Some(k) => {
+                    step[k] += 1;
+                    for s in &mut step[..k] {
+                        *s = -1;
+                    }
+                }
+                None => return steps,
+            }
+        }
+    }
+
+    /// Return the number of the node one `step` away from `coords`, if it is
+    /// in the grid.
+    fn neighbor(&self, coords: &[usize], step: &[isize]) -> Option<usize> {
+        let mut index = 0;
+        for k in (0..self.dims.len()).rev() {
+            let size = self.dims[k] as isize;
+            let mut c = coords[k] as isize + step[k];
+            if c < 0 || c >= size {
+                if !self.periodic {
+                    return None;
+                }
+                c = c.rem_euclid(size);
+            }
+            index = index * size as usize + c as usize;
+        }
+        Some(index)
+    }
+
+    /// Move `coords` to the coordinates of the next node.
+    fn advance(&self, coords: &mut [usize]) {
+        for (c, &size) in coords.iter_mut().zip(&self.dims) {
+            *c += 1;
+            if *c < size {
+                return;
+            }
+            *c = 0;
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Constructors of common graphs.
+//!
+//! The generators are generic over the graph type through the
+//! [`Create`](../data/trait.Create.html) trait, and fill in default node and
+//! edge weights. The nodes are numbered from `0` in the order they are added,
+//! so that for `Graph` and `StableGraph` the node with index `i` is the `i`th
+//! node of the generated graph. Graph types that identify nodes by their
+//! weights, like `GraphMap`, are not supported.
+//!
+//! Each edge of the generated graph is added once; directed graph types get an
+//! edge from the first to the second of its endpoints as listed in the
+//! documentation of each generator, unless noted otherwise.
+
+mod classic;
+mod lattice;
+mod named;
+#[cfg(feature = "rand")]
+mod random;
+
+pub use self::classic::{
+    complete_bipartite_graph, complete_graph, cycle_graph, path_graph, star_graph, wheel_graph,
+};
+pub use self::lattice::Grid;
+pub use self::named::{
+    complete_multipartite_graph, cube_graph, dodecahedron_graph, hypercube_graph, kneser_graph,
+    petersen_graph,
+};
+#[cfg(feature = "rand")]
+pub use self::random::{random_dag, random_dag_with_edge_count, random_tournament, watts_strogatz};
+
+use crate::data::Create;
+
+/// Create a graph with `n` nodes and room for `edges` edges, and return it
+/// with its nodes.
+fn with_nodes<G>(n: usize, edges: usize) -> (G, Vec<G::NodeId>)
+where
+    G: Create,
+    G::NodeWeight: Default,
+{
+    let mut g = G::with_capacity(n, edges);
+    let nodes = (0..n)
+        .map(|_| g.add_node(G::NodeWeight::default()))
+        .collect();
+    (g, nodes)
+}
+
+/// Create a graph with `n` nodes and the given edges between node numbers.
+fn from_edges<G, I>(n: usize, edges: I) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let edges = edges.into_iter();
+    let (mut g, nodes) = with_nodes::<G>(n, edges.size_hint().0);
+    for (a, b) in edges {
+        g.add_edge(nodes[a], nodes[b], G::EdgeWeight::default());
+    }
+    g
+}
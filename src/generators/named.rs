@@ -0,0 +1,216 @@
+//! Named graphs and families of graphs.
+
+use super::{from_edges, with_nodes};
+use crate::data::Create;
+
+/// Create the Petersen graph: 10 nodes and 15 edges, where each node has
+/// three neighbors and the shortest cycle has five edges.
+///
+/// The nodes `0..5` form the outer cycle, with an edge from `i` to
+/// `(i + 1) % 5`, the nodes `5..10` form the inner pentagram, with an edge
+/// from `5 + i` to `5 + (i + 2) % 5`, and there is a spoke from each `i` to
+/// `5 + i`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::petersen_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = petersen_graph();
+/// assert_eq!(g.edge_count(), 15);
+/// assert!(g.node_indices().all(|a| g.neighbors(a).count() == 3));
+/// ```
+pub fn petersen_graph<G>() -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let outer = (0..5).map(|i| (i, (i + 1) % 5));
+    let spokes = (0..5).map(|i| (i, 5 + i));
+    let inner = (0..5).map(|i| (5 + i, 5 + (i + 2) % 5));
+    from_edges(10, outer.chain(spokes).chain(inner))
+}
+
+/// Create the hypercube graph `Q_d`: the `2^d` nodes are the `d`-bit numbers,
+/// with an edge between two nodes if they differ in a single bit.
+///
+/// The edges go from the node with the bit clear to the node with the bit
+/// set.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::hypercube_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = hypercube_graph(4);
+/// assert_eq!(g.node_count(), 16);
+/// assert_eq!(g.edge_count(), 32);
+/// assert!(g.contains_edge(0b0101.into(), 0b0111.into()));
+/// ```
+pub fn hypercube_graph<G>(d: u32) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let n = 1usize << d;
+    let edges = (0..n).flat_map(move |i| {
+        (0..d)
+            .map(|b| 1 << b)
+            .filter(move |bit| i & bit == 0)
+            .map(move |bit| (i, i | bit))
+    });
+    from_edges(n, edges)
+}
+
+/// Create the cube graph: the skeleton of a cube, which is
+/// [`hypercube_graph(3)`](fn.hypercube_graph.html).
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::cube_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = cube_graph();
+/// assert_eq!((g.node_count(), g.edge_count()), (8, 12));
+/// ```
+pub fn cube_graph<G>() -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    hypercube_graph(3)
+}
+
+/// Create the dodecahedron graph: the skeleton of a dodecahedron, with 20
+/// nodes and 30 edges.
+///
+/// The nodes `0..20` form a Hamiltonian cycle, with an edge from `i` to
+/// `(i + 1) % 20`, and the remaining edges are given by the LCF notation
+/// `[10, 7, 4, -4, -7, 10, -4, 7, -7, 4]^2`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::dodecahedron_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = dodecahedron_graph();
+/// assert_eq!(g.edge_count(), 30);
+/// assert!(g.node_indices().all(|a| g.neighbors(a).count() == 3));
+/// ```
+pub fn dodecahedron_graph<G>() -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    const LCF: [isize; 10] = [10, 7, 4, -4, -7, 10, -4, 7, -7, 4];
+    let n = 20;
+    let cycle = (0..n).map(|i| (i, (i + 1) % n));
+    // Each chord is listed from both of its ends; keep it once.
+    let chords = (0..n)
+        .map(|i| {
+            (
+                i,
+                (i as isize + LCF[i % 10]).rem_euclid(n as isize) as usize,
+            )
+        })
+        .filter(|&(i, j)| i < j);
+    from_edges(n, cycle.chain(chords))
+}
+
+/// Create the complete multipartite graph with parts of the given sizes, and
+/// an edge between each pair of nodes in different parts.
+///
+/// The nodes of each part are numbered consecutively, the first part first,
+/// and the edges go from the earlier part to the later one.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::complete_multipartite_graph;
+/// use petgraph::prelude::*;
+///
+/// // The octahedron: three parts of two nodes.
+/// let g: UnGraph<(), ()> = complete_multipartite_graph(&[2, 2, 2]);
+/// assert_eq!(g.edge_count(), 12);
+/// assert!(!g.contains_edge(2.into(), 3.into()));
+/// ```
+pub fn complete_multipartite_graph<G>(sizes: &[usize]) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    let n = sizes.iter().sum::<usize>();
+    let edges = (n * n - sizes.iter().map(|s| s * s).sum::<usize>()) / 2;
+    let (mut g, nodes) = with_nodes::<G>(n, edges);
+    let mut start = 0;
+    for &size in sizes {
+        let (part, rest) = nodes[start..].split_at(size);
+        for &a in part {
+            for &b in rest {
+                g.add_edge(a, b, G::EdgeWeight::default());
+            }
+        }
+        start += size;
+    }
+    g
+}
+
+/// Create the Kneser graph `K(n, k)`, whose nodes are the subsets of `k`
+/// elements of `{0, .., n - 1}`, with an edge between each pair of disjoint
+/// subsets.
+///
+/// The subsets are numbered in lexicographic order of their sorted elements:
+/// `{0, 1, .., k - 1}` is node `0`. The edges go from the earlier subset to
+/// the later one. `K(5, 2)` is the Petersen graph.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than 64.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::kneser_graph;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = kneser_graph(5, 2);
+/// assert_eq!(g.node_count(), 10);
+/// assert_eq!(g.edge_count(), 15);
+/// ```
+pub fn kneser_graph<G>(n: usize, k: usize) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+{
+    assert!(n <= 64, "kneser_graph: n = {} is greater than 64", n);
+    let mut subsets = Vec::new();
+    if k <= n {
+        let mut subset = (0..k).collect::<Vec<_>>();
+        loop {
+            subsets.push(subset.iter().fold(0u64, |mask, &x| mask | 1 << x));
+            // Advance the rightmost element that can still move right.
+            match (0..k).rev().find(|&i| subset[i] < n - k + i) {
+                Some(i) => {
+                    subset[i] += 1;
+                    for j in i + 1..k {
+                        subset[j] = subset[j - 1] + 1;
+                    }
+                }
+                None => break,
+            }
+        }
+    }
+    let mut edges = Vec::new();
+    for (i, &a) in subsets.iter().enumerate() {
+        for (j, &b) in subsets.iter().enumerate().skip(i + 1) {
+            if a & b == 0 {
+                edges.push((i, j));
+            }
+        }
+    }
+    from_edges(subsets.len(), edges)
+}
@@ -0,0 +1,235 @@
+//! Random graph generators.
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use super::from_edges;
+use crate::data::Create;
+
+/// Generate a small-world graph with the Watts–Strogatz model.
+///
+/// Starts from a ring of `n` nodes where each node is joined to its `k / 2`
+/// nearest neighbors on either side, from node `i` to node `i + j` (modulo
+/// `n`) for `j` in `1..=k / 2`. Then each of these edges is *rewired* with
+/// probability `beta`: the edge from `i` is replaced by an edge from `i` to a
+/// node drawn uniformly at random from `rng`, avoiding self loops and
+/// parallel edges. An edge is kept if `i` is already adjacent to all other
+/// nodes.
+///
+/// The graph has `n * (k / 2)` edges. With `beta = 0` it is the ring lattice,
+/// and with `beta = 1` it is close to a random graph; in between, it keeps the
+/// high clustering of the lattice but has short paths between its nodes.
+///
+/// Requires crate feature `"rand"`.
+///
+/// # Panics
+///
+/// Panics if `k` is not `0` and not less than `n`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::generators::watts_strogatz;
+/// use petgraph::prelude::*;
+///
+/// let g: UnGraph<(), ()> = watts_strogatz(20, 4, 0.1, &mut rand::thread_rng());
+/// assert_eq!(g.node_count(), 20);
+/// assert_eq!(g.edge_count(), 40);
+/// ```
+pub fn watts_strogatz<G, R>(n: usize, k: usize, beta: f64, rng: &mut R) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng,
+{
+    assert!(
+        k == 0 || k < n,
+        "watts_strogatz: k = {} must be less than n = {}",
+        k,
+        n
+    );
+    let half = k / 2;
+    let mut degree = vec![2 * half; n];
+    let mut edges = Vec::with_capacity(n * half);
+    let mut present = HashSet::with_capacity(n * half);
+    let normalize = |a: usize, b: usize| (a.min(b), a.max(b));
+    for j in 1..=half {
+        for i in 0..n {
+            let b = (i + j) % n;
+            edges.push((i, b));
+            present.insert(normalize(i, b));
+        }
+    }
+    for (i, b) in &mut edges {
+        let i = *i;
+        if degree[i] + 1 >= n || rng.gen::<f64>() >= beta {
+            continue;
+        }
+        let w = loop {
+            let w = rng.gen_range(0, n);
+            if w != i && !present.contains(&normalize(i, w)) {
+                break w;
+            }
+        };
+        present.remove(&normalize(i, *b));
+        present.insert(normalize(i, w));
+        degree[*b] -= 1;
+        degree[w] += 1;
+        *b = w;
+    }
+    from_edges(n, edges)
+}
+
+/// Return the nodes `0..n` in an order drawn uniformly at random.
+fn random_order<R: Rng>(n: usize, rng: &mut R) -> Vec<usize> {
+    let mut order = (0..n).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+    order
+}
+
+/// Generate a random directed acyclic graph where each edge is present with
+/// probability `p`.
+///
+/// The nodes are put in an order drawn uniformly at random from `rng`, and
+/// each pair of nodes gets an edge from the earlier to the later node in that
+/// order with probability `p`, independently of the others. The graph is
+/// acyclic by construction, and its node indices are in no particular
+/// topological order. The expected number of edges is `p * n * (n - 1) / 2`.
+///
+/// Computes in **O(|V|²)** time.
+///
+/// Requires crate feature `"rand"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::is_cyclic_directed;
+/// use petgraph::generators::random_dag;
+/// use petgraph::prelude::*;
+///
+/// let g: DiGraph<(), ()> = random_dag(20, 0.3, &mut rand::thread_rng());
+/// assert_eq!(g.node_count(), 20);
+/// assert!(!is_cyclic_directed(&g));
+/// ```
+pub fn random_dag<G, R>(n: usize, p: f64, rng: &mut R) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng,
+{
+    let order = random_order(n, rng);
+    let mut edges = Vec::new();
+    for (k, &a) in order.iter().enumerate() {
+        for &b in &order[k + 1..] {
+            if rng.gen::<f64>() < p {
+                edges.push((a, b));
+            }
+        }
+    }
+    from_edges(n, edges)
+}
+
+/// Generate a random directed acyclic graph with `m` edges.
+///
+/// The nodes are put in an order drawn uniformly at random from `rng`, and
+/// `m` distinct pairs of nodes are drawn uniformly at random, each getting an
+/// edge from the earlier to the later node in that order. The graph is
+/// acyclic by construction, and its node indices are in no particular
+/// topological order.
+///
+/// Computes in **O(|V| + |E| log |E|)** time.
+///
+/// Requires crate feature `"rand"`.
+///
+/// # Panics
+///
+/// Panics if `m` is greater than `n * (n - 1) / 2`, the number of edges of an
+/// acyclic graph on `n` nodes without parallel edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::toposort;
+/// use petgraph::generators::random_dag_with_edge_count;
+/// use petgraph::prelude::*;
+///
+/// let g: DiGraph<(), ()> = random_dag_with_edge_count(10, 45, &mut rand::thread_rng());
+/// assert_eq!(g.edge_count(), 45);
+/// assert!(toposort(&g, None).is_ok());
+/// ```
+pub fn random_dag_with_edge_count<G, R>(n: usize, m: usize, rng: &mut R) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng,
+{
+    let pairs = n * n.saturating_sub(1) / 2;
+    assert!(
+        m <= pairs,
+        "random_dag_with_edge_count: m = {} is greater than the {} possible edges",
+        m,
+        pairs
+    );
+    // Draw `m` distinct pair numbers with Floyd's algorithm.
+    let mut chosen = HashSet::with_capacity(m);
+    for j in pairs - m..pairs {
+        let t = rng.gen_range(0, j + 1);
+        if !chosen.insert(t) {
+            chosen.insert(j);
+        }
+    }
+    let mut chosen = chosen.into_iter().collect::<Vec<_>>();
+    chosen.sort_unstable();
+
+    // Pairs are numbered row by row: (0, 1), (0, 2), .., (1, 2), ..
+    let order = random_order(n, rng);
+    let mut edges = Vec::with_capacity(m);
+    let (mut row, mut start) = (0, 0);
+    for t in chosen {
+        while t >= start + (n - 1 - row) {
+            start += n - 1 - row;
+            row += 1;
+        }
+        let column = row + 1 + (t - start);
+        edges.push((order[row], order[column]));
+    }
+    from_edges(n, edges)
+}
+
+/// Generate a random tournament on `n` nodes: a directed graph with exactly
+/// one edge between each pair of distinct nodes.
+///
+/// Each pair of nodes `i < j` gets an edge from `i` to `j` or from `j` to
+/// `i`, chosen with equal probability from `rng`. A Hamiltonian path of the
+/// result is found by
+/// [`hamiltonian_path_in_tournament`](../algo/tournament/fn.hamiltonian_path_in_tournament.html).
+///
+/// Requires crate feature `"rand"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::hamiltonian_path_in_tournament;
+/// use petgraph::generators::random_tournament;
+/// use petgraph::prelude::*;
+///
+/// let g: DiGraph<(), ()> = random_tournament(8, &mut rand::thread_rng());
+/// assert_eq!(g.edge_count(), 28);
+/// let path = hamiltonian_path_in_tournament(&g);
+/// assert!(path.windows(2).all(|w| g.contains_edge(w[0], w[1])));
+/// ```
+pub fn random_tournament<G, R>(n: usize, rng: &mut R) -> G
+where
+    G: Create,
+    G::NodeWeight: Default,
+    G::EdgeWeight: Default,
+    R: Rng,
+{
+    let mut edges = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+    for i in 0..n {
+        for j in i + 1..n {
+            edges.push(if rng.gen() { (i, j) } else { (j, i) });
+        }
+    }
+    from_edges(n, edges)
+}
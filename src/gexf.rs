@@ -0,0 +1,536 @@
+//! [GEXF](https://gexf.net/) 1.3 output, the native format of Gephi.
+
+use std::fmt::{self, Display, Write};
+
+use indexmap::IndexMap;
+
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+/// The value of a GEXF attribute, of one of the GEXF attribute types.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// An `integer`.
+    Integer(i32),
+    /// A `long`.
+    Long(i64),
+    /// A `float`.
+    Float(f32),
+    /// A `double`.
+    Double(f64),
+    /// A `boolean`.
+    Boolean(bool),
+    /// A `string`.
+    String(String),
+}
+
+impl Value {
+    /// The GEXF name of the type of the value, like `"double"`.
+    pub fn type_name(&self) -> &'static str {
+        match *self {
+            Value::Integer(_) => "integer",
+            Value::Long(_) => "long",
+            Value::Float(_) => "float",
+            Value::Double(_) => "double",
+            Value::Boolean(_) => "boolean",
+            Value::String(_) => "string",
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Value::Integer(value) => write!(f, "{}", value),
+            Value::Long(value) => write!(f, "{}", value),
+            Value::Float(value) => fmt_float(value.into(), f),
+            Value::Double(value) => fmt_float(value, f),
+            Value::Boolean(value) => write!(f, "{}", value),
+            Value::String(ref value) => f.write_str(value),
+        }
+    }
+}
+
+/// Write a float the way XML Schema spells it, with `INF` for infinity.
+fn fmt_float(value: f64, f: &mut fmt::Formatter) -> fmt::Result {
+    if value.is_infinite() {
+        f.write_str(if value > 0. { "INF" } else { "-INF" })
+    } else {
+        write!(f, "{}", value)
+    }
+}
+
+macro_rules! impl_from {
+    ($($t:ty => $variant:ident,)*) => {
+        $(
+            impl From<$t> for Value {
+                fn from(value: $t) -> Self {
+                    Value::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from! {
+    i32 => Integer,
+    i64 => Long,
+    f32 => Float,
+    f64 => Double,
+    bool => Boolean,
+    String => String,
+    &str => String,
+}
+
+/// The value of an attribute of a node or an edge, with the time interval
+/// when it has the value, if it is dynamic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    /// The name of the attribute.
+    pub name: String,
+    /// The value of the attribute.
+    pub value: Value,
+    /// The start and end times of the value, with `None` for an unbounded
+    /// side. `None` for a static value.
+    pub interval: Option<(Option<f64>, Option<f64>)>,
+}
+
+impl Attribute {
+    /// A static attribute value.
+    pub fn new(name: impl Into<String>, value: impl Into<Value>) -> Self {
+        Attribute {
+            name: name.into(),
+            value: value.into(),
+            interval: None,
+        }
+    }
+
+    /// A dynamic attribute value, from time `start` up to time `end`.
+    pub fn during(
+        name: impl Into<String>,
+        value: impl Into<Value>,
+        start: Option<f64>,
+        end: Option<f64>,
+    ) -> Self {
+        Attribute {
+            name: name.into(),
+            value: value.into(),
+            interval: Some((start, end)),
+        }
+    }
+}
+
+/// `Gexf` implements output to GEXF 1.3, the native format of Gephi, for a
+/// graph.
+///
+/// Nodes are labeled with their weights, using their `Display` or `Debug`
+/// implementation, and have their index from `NodeIndexable` as ID.
+///
+/// The attributes of the nodes and edges are declared by their name and the
+/// type of their first value; later values of the same attribute should have
+/// the same type. An attribute with a time interval on any of its values is
+/// dynamic, and makes the graph dynamic, with times as doubles.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::gexf::{Attribute, Gexf};
+/// use petgraph::prelude::*;
+///
+/// let mut graph = Graph::<&str, f64>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// graph.add_edge(a, b, 2.5);
+///
+/// let gexf = Gexf::new(&graph)
+///     .with_edge_weights(&|_, edge| *edge.weight())
+///     .with_node_attributes(&|_, node| {
+///         vec![
+///             Attribute::new("name", node.1.to_uppercase()),
+///             Attribute::during("score", 1, None, Some(2000.)),
+///             Attribute::during("score", 2, Some(2000.), None),
+///         ]
+///     });
+/// assert_eq!(
+///     gexf.to_string(),
+///     r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <gexf xmlns="http://gexf.net/1.3" version="1.3">
+///   <graph defaultedgetype="directed" mode="dynamic" timeformat="double">
+///     <attributes class="node" mode="static">
+///       <attribute id="0" title="name" type="string"/>
+///     </attributes>
+///     <attributes class="node" mode="dynamic">
+///       <attribute id="1" title="score" type="integer"/>
+///     </attributes>
+///     <nodes>
+///       <node id="0" label="a">
+///         <attvalues>
+///           <attvalue for="0" value="A"/>
+///           <attvalue for="1" value="1" end="2000"/>
+///           <attvalue for="1" value="2" start="2000"/>
+///         </attvalues>
+///       </node>
+///       <node id="1" label="b">
+///         <attvalues>
+///           <attvalue for="0" value="B"/>
+///           <attvalue for="1" value="1" end="2000"/>
+///           <attvalue for="1" value="2" start="2000"/>
+///         </attvalues>
+///       </node>
+///     </nodes>
+///     <edges>
+///       <edge id="0" source="0" target="1" weight="2.5"/>
+///     </edges>
+///   </graph>
+/// </gexf>
+/// "#
+/// );
+/// ```
+pub struct Gexf<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    get_edge_weight: Option<&'a dyn Fn(G, G::EdgeRef) -> f64>,
+    get_node_attributes: Option<&'a dyn Fn(G, G::NodeRef) -> Vec<Attribute>>,
+    get_edge_attributes: Option<&'a dyn Fn(G, G::EdgeRef) -> Vec<Attribute>>,
+}
+
+static INDENT: &str = "  ";
+
+impl<'a, G> Gexf<'a, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+{
+    /// Create a `Gexf` formatting wrapper, without attributes.
+    pub fn new(graph: G) -> Self {
+        Gexf {
+            graph,
+            get_edge_weight: None,
+            get_node_attributes: None,
+            get_edge_attributes: None,
+        }
+    }
+
+    /// Set the weight of each edge, which Gephi uses for the thickness of
+    /// edges and in its algorithms.
+    pub fn with_edge_weights(mut self, get_edge_weight: &'a dyn Fn(G, G::EdgeRef) -> f64) -> Self {
+        self.get_edge_weight = Some(get_edge_weight);
+        self
+    }
+
+    /// Set the attributes of each node.
+    pub fn with_node_attributes(
+        mut self,
+        get_node_attributes: &'a dyn Fn(G, G::NodeRef) -> Vec<Attribute>,
+    ) -> Self {
+        self.get_node_attributes = Some(get_node_attributes);
+        self
+    }
+
+    /// Set the attributes of each edge.
+    pub fn with_edge_attributes(
+        mut self,
+        get_edge_attributes: &'a dyn Fn(G, G::EdgeRef) -> Vec<Attribute>,
+    ) -> Self {
+        self.get_edge_attributes = Some(get_edge_attributes);
+        self
+    }
+}
+
+/// The attributes of one class of elements, by name, with their ID, type
+/// and whether they are dynamic.
+type Declarations<'b> = IndexMap<&'b str, (usize, &'static str, bool)>;
+
+fn declarations<'b, I>(attribute_lists: I) -> Declarations<'b>
+where
+    I: IntoIterator<Item = &'b Vec<Attribute>>,
+{
+    let mut declarations = Declarations::new();
+    for attribute in attribute_lists.into_iter().flatten() {
+        let id = declarations.len();
+        let declaration = declarations.entry(&*attribute.name).or_insert((
+            id,
+            attribute.value.type_name(),
+            false,
+        ));
+        declaration.2 |= attribute.interval.is_some();
+    }
+    // number the static attributes first, as they are written first
+    let mut ids = 0..;
+    for dynamic in &[false, true] {
+        for declaration in declarations.values_mut() {
+            if declaration.2 == *dynamic {
+                declaration.0 = ids.next().unwrap();
+            }
+        }
+    }
+    declarations
+}
+
+fn declarations_fmt(
+    f: &mut fmt::Formatter,
+    class: &str,
+    declarations: &Declarations,
+) -> fmt::Result {
+    for &dynamic in &[false, true] {
+        if !declarations.values().any(|d| d.2 == dynamic) {
+            continue;
+        }
+        let mode = if dynamic { "dynamic" } else { "static" };
+        writeln!(
+            f,
+            "{0}{0}<attributes class=\"{1}\" mode=\"{2}\">",
+            INDENT, class, mode
+        )?;
+        for (name, &(id, type_name, _)) in declarations.iter().filter(|d| (d.1).2 == dynamic) {
+            writeln!(
+                f,
+                "{0}{0}{0}<attribute id=\"{1}\" title=\"{2}\" type=\"{3}\"/>",
+                INDENT,
+                id,
+                Escaped(name),
+                type_name
+            )?;
+        }
+        writeln!(f, "{0}{0}</attributes>", INDENT)?;
+    }
+    Ok(())
+}
+
+/// Write the end of an element with `attributes`.
+fn attvalues_fmt(
+    f: &mut fmt::Formatter,
+    element: &str,
+    declarations: &Declarations,
+    attributes: &[Attribute],
+) -> fmt::Result {
+    if attributes.is_empty() {
+        return writeln!(f, "/>");
+    }
+    writeln!(f, ">")?;
+    writeln!(f, "{0}{0}{0}{0}<attvalues>", INDENT)?;
+    for attribute in attributes {
+        write!(
+            f,
+            "{0}{0}{0}{0}{0}<attvalue for=\"{1}\" value=\"{2}\"",
+            INDENT,
+            declarations[&*attribute.name].0,
+            Escaped(&attribute.value)
+        )?;
+        if let Some((start, end)) = attribute.interval {
+            if let Some(start) = start {
+                write!(f, " start=\"{}\"", start)?;
+            }
+            if let Some(end) = end {
+                write!(f, " end=\"{}\"", end)?;
+            }
+        }
+        writeln!(f, "/>")?;
+    }
+    writeln!(f, "{0}{0}{0}{0}</attvalues>", INDENT)?;
+    writeln!(f, "{0}{0}{0}</{1}>", INDENT, element)
+}
+
+impl<'a, G> Gexf<'a, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    fn graph_fmt<NF>(&self, f: &mut fmt::Formatter, node_fmt: NF) -> fmt::Result
+    where
+        NF: Fn(&G::NodeWeight, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let g = self.graph;
+        let nodes = g
+            .node_references()
+            .map(|node| {
+                let attributes = match self.get_node_attributes {
+                    Some(get_attributes) => get_attributes(g, node),
+                    None => Vec::new(),
+                };
+                (node, attributes)
+            })
+            .collect::<Vec<_>>();
+        let edges = g
+            .edge_references()
+            .map(|edge| {
+                let attributes = match self.get_edge_attributes {
+                    Some(get_attributes) => get_attributes(g, edge),
+                    None => Vec::new(),
+                };
+                (edge, attributes)
+            })
+            .collect::<Vec<_>>();
+        let node_declarations = declarations(nodes.iter().map(|n| &n.1));
+        let edge_declarations = declarations(edges.iter().map(|e| &e.1));
+        let dynamic = node_declarations
+            .values()
+            .chain(edge_declarations.values())
+            .any(|d| d.2);
+
+        writeln!(f, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(f, "<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">")?;
+        let edge_type = if g.is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        write!(f, "{}<graph defaultedgetype=\"{}\"", INDENT, edge_type)?;
+        if dynamic {
+            writeln!(f, " mode=\"dynamic\" timeformat=\"double\">")?;
+        } else {
+            writeln!(f, ">")?;
+        }
+        declarations_fmt(f, "node", &node_declarations)?;
+        declarations_fmt(f, "edge", &edge_declarations)?;
+
+        writeln!(f, "{0}{0}<nodes>", INDENT)?;
+        for (node, attributes) in &nodes {
+            write!(
+                f,
+                "{0}{0}{0}<node id=\"{1}\" label=\"",
+                INDENT,
+                g.to_index(node.id())
+            )?;
+            write!(&mut Escaper(&mut *f), "{}", FnFmt(node.weight(), &node_fmt))?;
+            write!(f, "\"")?;
+            attvalues_fmt(f, "node", &node_declarations, attributes)?;
+        }
+        writeln!(f, "{0}{0}</nodes>", INDENT)?;
+
+        writeln!(f, "{0}{0}<edges>", INDENT)?;
+        for (i, (edge, attributes)) in edges.iter().enumerate() {
+            write!(
+                f,
+                "{0}{0}{0}<edge id=\"{1}\" source=\"{2}\" target=\"{3}\"",
+                INDENT,
+                i,
+                g.to_index(edge.source()),
+                g.to_index(edge.target())
+            )?;
+            if let Some(get_weight) = self.get_edge_weight {
+                write!(f, " weight=\"{}\"", get_weight(g, *edge))?;
+            }
+            attvalues_fmt(f, "edge", &edge_declarations, attributes)?;
+        }
+        writeln!(f, "{0}{0}</edges>", INDENT)?;
+        writeln!(f, "{}</graph>", INDENT)?;
+        writeln!(f, "</gexf>")
+    }
+}
+
+impl<'a, G> fmt::Display for Gexf<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::NodeWeight: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Display::fmt)
+    }
+}
+
+impl<'a, G> fmt::Debug for Gexf<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::NodeWeight: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Debug::fmt)
+    }
+}
+
+/// Escape for XML
+struct Escaper<W>(W);
+
+impl<W> fmt::Write for Escaper<W>
+where
+    W: fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '&' => self.0.write_str("&amp;"),
+            '<' => self.0.write_str("&lt;"),
+            '>' => self.0.write_str("&gt;"),
+            '"' => self.0.write_str("&quot;"),
+            _ => self.0.write_char(c),
+        }
+    }
+}
+
+/// Pass Display formatting through the XML escaping filter
+struct Escaped<T>(T);
+
+impl<T> Display for Escaped<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(&mut Escaper(f), "{}", &self.0)
+    }
+}
+
+/// Format data using a specific format function
+struct FnFmt<'a, T, F>(&'a T, F);
+
+impl<'a, T, F> Display for FnFmt<'a, T, F>
+where
+    F: Fn(&'a T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.1(self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Attribute, Gexf};
+    use crate::prelude::UnGraph;
+
+    #[test]
+    fn test_undirected_edge_attributes() {
+        let mut graph = UnGraph::<&str, u8>::new_undirected();
+        let a = graph.add_node("<a>");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b, 1);
+        graph.add_edge(b, b, 2);
+        let gexf = Gexf::new(&graph).with_edge_attributes(&|_, edge| {
+            if *edge.weight() == 1 {
+                vec![Attribute::new("kind", "x & y")]
+            } else {
+                vec![]
+            }
+        });
+        assert_eq!(
+            format!("{:?}", gexf),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<gexf xmlns="http://gexf.net/1.3" version="1.3">
+  <graph defaultedgetype="undirected">
+    <attributes class="edge" mode="static">
+      <attribute id="0" title="kind" type="string"/>
+    </attributes>
+    <nodes>
+      <node id="0" label="&quot;&lt;a&gt;&quot;"/>
+      <node id="1" label="&quot;b&quot;"/>
+    </nodes>
+    <edges>
+      <edge id="0" source="0" target="1">
+        <attvalues>
+          <attvalue for="0" value="x &amp; y"/>
+        </attvalues>
+      </edge>
+      <edge id="1" source="1" target="1"/>
+    </edges>
+  </graph>
+</gexf>
+"#
+        );
+    }
+}
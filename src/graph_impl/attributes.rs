@@ -0,0 +1,558 @@
+//! Maps of extra data for the nodes or edges of a graph, indexed by the
+//! same `NodeIndex` and `EdgeIndex` as the graph.
+//!
+//! [`NodeMap`] and [`EdgeMap`] hold a value for every index up to a bound,
+//! in a `Vec`, like the nodes and edges of a `Graph`. They follow the
+//! removals from a `Graph` with `swap_remove`, and as observers of an
+//! [`ObservedGraph`] they follow every change to the graph on their own.
+//!
+//! [`SparseNodeMap`] and [`SparseEdgeMap`] hold values for only some
+//! indices, with holes in between, like the nodes and edges of a
+//! `StableGraph`. Removing a node or edge from a `StableGraph` doesn't move
+//! other indices, but its index may be reused, so its value should be removed
+//! from the map too.
+//!
+//! [`NodeMap`]: struct.NodeMap.html
+//! [`EdgeMap`]: struct.EdgeMap.html
+//! [`SparseNodeMap`]: struct.SparseNodeMap.html
+//! [`SparseEdgeMap`]: struct.SparseEdgeMap.html
+//! [`ObservedGraph`]: ../observed/struct.ObservedGraph.html
+//!
+//! ```
+//! # #[cfg(feature = "stable_graph")] {
+//! use petgraph::attributes::{NodeMap, SparseNodeMap};
+//! use petgraph::graph::{Graph, NodeIndex};
+//! use petgraph::stable_graph::StableGraph;
+//!
+//! let mut g = Graph::<&str, ()>::new();
+//! let a = g.add_node("a");
+//! let b = g.add_node("b");
+//! let c = g.add_node("c");
+//! let mut color = NodeMap::from_elem(&g, "red");
+//! color[b] = "blue";
+//! g.remove_node(a);
+//! color.swap_remove(a);
+//! // `c` took the place of `a`
+//! assert_eq!((g[a], color[a]), ("c", "red"));
+//! assert_eq!((g[b], color[b]), ("b", "blue"));
+//!
+//! let mut g = StableGraph::<&str, ()>::new();
+//! let a = g.add_node("a");
+//! let b = g.add_node("b");
+//! let mut label = SparseNodeMap::new();
+//! label.insert(b, 2);
+//! g.remove_node(b);
+//! label.remove(b);
+//! let c = g.add_node("c");
+//! assert_eq!(c, b);
+//! assert_eq!(label.get(c), None);
+//! assert_eq!(label.get(a), None);
+//! # }
+//! ```
+
+use std::iter::Enumerate;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use super::observed::GraphObserver;
+use super::{DefaultIx, EdgeIndex, IndexType, NodeIndex};
+use crate::visit::{
+    EdgeIndexable, EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable,
+};
+
+/// Iterator over the indices and values of a [`NodeMap`] or an [`EdgeMap`].
+///
+/// [`NodeMap`]: struct.NodeMap.html
+/// [`EdgeMap`]: struct.EdgeMap.html
+#[derive(Debug, Clone)]
+pub struct Iter<'a, I, T> {
+    iter: Enumerate<slice::Iter<'a, T>>,
+    index: fn(usize) -> I,
+}
+
+impl<'a, I, T> Iterator for Iter<'a, I, T> {
+    type Item = (I, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, x)| ((self.index)(i), x))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I, T> DoubleEndedIterator for Iter<'a, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(i, x)| ((self.index)(i), x))
+    }
+}
+
+impl<'a, I, T> ExactSizeIterator for Iter<'a, I, T> {}
+
+/// Iterator over the indices and values of a [`SparseNodeMap`] or a
+/// [`SparseEdgeMap`].
+///
+/// [`SparseNodeMap`]: struct.SparseNodeMap.html
+/// [`SparseEdgeMap`]: struct.SparseEdgeMap.html
+#[derive(Debug, Clone)]
+pub struct SparseIter<'a, I, T> {
+    iter: Enumerate<slice::Iter<'a, Option<T>>>,
+    index: fn(usize) -> I,
+}
+
+impl<'a, I, T> Iterator for SparseIter<'a, I, T> {
+    type Item = (I, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        self.iter
+            .by_ref()
+            .filter_map(|(i, x)| x.as_ref().map(|x| (index(i), x)))
+            .next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, I, T> DoubleEndedIterator for SparseIter<'a, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        self.iter
+            .by_ref()
+            .rev()
+            .filter_map(|(i, x)| x.as_ref().map(|x| (index(i), x)))
+            .next()
+    }
+}
+
+macro_rules! index_maps {
+    (
+        $(#[$dense_attr:meta])*
+        dense: $dense:ident,
+        $(#[$sparse_attr:meta])*
+        sparse: $sparse:ident,
+        index: $index:ident,
+    ) => {
+        $(#[$dense_attr])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $dense<T, Ix = DefaultIx> {
+            values: Vec<T>,
+            ix: std::marker::PhantomData<Ix>,
+        }
+
+        impl<T, Ix: IndexType> Default for $dense<T, Ix> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T, Ix: IndexType> $dense<T, Ix> {
+            /// Create an empty map.
+            pub fn new() -> Self {
+                $dense {
+                    values: Vec::new(),
+                    ix: std::marker::PhantomData,
+                }
+            }
+
+            /// Create an empty map with room for `capacity` values.
+            pub fn with_capacity(capacity: usize) -> Self {
+                $dense {
+                    values: Vec::with_capacity(capacity),
+                    ix: std::marker::PhantomData,
+                }
+            }
+
+            /// Return the number of values, one more than the last index.
+            pub fn len(&self) -> usize {
+                self.values.len()
+            }
+
+            /// Return `true` if the map has no values.
+            pub fn is_empty(&self) -> bool {
+                self.values.is_empty()
+            }
+
+            /// Return the value at `i`, if the map reaches that far.
+            pub fn get(&self, i: $index<Ix>) -> Option<&T> {
+                self.values.get(i.index())
+            }
+
+            /// Return the value at `i` mutably, if the map reaches that far.
+            pub fn get_mut(&mut self, i: $index<Ix>) -> Option<&mut T> {
+                self.values.get_mut(i.index())
+            }
+
+            /// Add `value` at the next index, and return the index.
+            ///
+            /// That is the index a `Graph` gives the next one it adds.
+            pub fn push(&mut self, value: T) -> $index<Ix> {
+                let i = $index::new(self.values.len());
+                self.values.push(value);
+                i
+            }
+
+            /// Remove the value at `i` and return it, and move the last value
+            /// into its place.
+            ///
+            /// That is how a `Graph` moves its indices on a removal.
+            ///
+            /// **Panics** if `i` is out of bounds.
+            pub fn swap_remove(&mut self, i: $index<Ix>) -> T {
+                self.values.swap_remove(i.index())
+            }
+
+            /// Remove all values.
+            pub fn clear(&mut self) {
+                self.values.clear();
+            }
+
+            /// Return an iterator over the indices and values, in index order.
+            pub fn iter(&self) -> Iter<'_, $index<Ix>, T> {
+                Iter {
+                    iter: self.values.iter().enumerate(),
+                    index: $index::new,
+                }
+            }
+
+            /// Return the values as a slice, in index order.
+            pub fn as_slice(&self) -> &[T] {
+                &self.values
+            }
+
+            /// Return the values as a mutable slice, in index order.
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                &mut self.values
+            }
+
+            /// Return the values as a `Vec`, in index order.
+            pub fn into_vec(self) -> Vec<T> {
+                self.values
+            }
+        }
+
+        impl<T, Ix: IndexType> Index<$index<Ix>> for $dense<T, Ix> {
+            type Output = T;
+
+            fn index(&self, i: $index<Ix>) -> &T {
+                &self.values[i.index()]
+            }
+        }
+
+        impl<T, Ix: IndexType> IndexMut<$index<Ix>> for $dense<T, Ix> {
+            fn index_mut(&mut self, i: $index<Ix>) -> &mut T {
+                &mut self.values[i.index()]
+            }
+        }
+
+        impl<'a, T, Ix: IndexType> IntoIterator for &'a $dense<T, Ix> {
+            type Item = ($index<Ix>, &'a T);
+            type IntoIter = Iter<'a, $index<Ix>, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        $(#[$sparse_attr])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub struct $sparse<T, Ix = DefaultIx> {
+            values: Vec<Option<T>>,
+            len: usize,
+            ix: std::marker::PhantomData<Ix>,
+        }
+
+        impl<T, Ix: IndexType> Default for $sparse<T, Ix> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T, Ix: IndexType> $sparse<T, Ix> {
+            /// Create an empty map.
+            pub fn new() -> Self {
+                $sparse {
+                    values: Vec::new(),
+                    len: 0,
+                    ix: std::marker::PhantomData,
+                }
+            }
+
+            /// Return the number of values.
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            /// Return `true` if the map has no values.
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            /// Return `true` if the map has a value at `i`.
+            pub fn contains_key(&self, i: $index<Ix>) -> bool {
+                self.get(i).is_some()
+            }
+
+            /// Return the value at `i`.
+            pub fn get(&self, i: $index<Ix>) -> Option<&T> {
+                self.values.get(i.index()).and_then(|x| x.as_ref())
+            }
+
+            /// Return the value at `i` mutably.
+            pub fn get_mut(&mut self, i: $index<Ix>) -> Option<&mut T> {
+                self.values.get_mut(i.index()).and_then(|x| x.as_mut())
+            }
+
+            /// Set the value at `i` to `value`, and return the value it
+            /// replaced.
+            pub fn insert(&mut self, i: $index<Ix>, value: T) -> Option<T> {
+                if i.index() >= self.values.len() {
+                    self.values.resize_with(i.index() + 1, || None);
+                }
+                let old = self.values[i.index()].replace(value);
+                if old.is_none() {
+                    self.len += 1;
+                }
+                old
+            }
+
+            /// Remove the value at `i` and return it.
+            pub fn remove(&mut self, i: $index<Ix>) -> Option<T> {
+                let old = self.values.get_mut(i.index()).and_then(|x| x.take());
+                if old.is_some() {
+                    self.len -= 1;
+                }
+                old
+            }
+
+            /// Keep only the values for which `f` returns `true`.
+            ///
+            /// With a `StableGraph` `g`, `map.retain(|a, _| g.contains_node(a))`
+            /// removes the values of the nodes removed from the graph.
+            pub fn retain<F>(&mut self, mut f: F)
+            where
+                F: FnMut($index<Ix>, &mut T) -> bool,
+            {
+                for (i, slot) in self.values.iter_mut().enumerate() {
+                    if let Some(ref mut value) = *slot {
+                        if !f($index::new(i), value) {
+                            *slot = None;
+                            self.len -= 1;
+                        }
+                    }
+                }
+            }
+
+            /// Remove all values.
+            pub fn clear(&mut self) {
+                self.values.clear();
+                self.len = 0;
+            }
+
+            /// Return an iterator over the indices and values, in index order.
+            pub fn iter(&self) -> SparseIter<'_, $index<Ix>, T> {
+                SparseIter {
+                    iter: self.values.iter().enumerate(),
+                    index: $index::new,
+                }
+            }
+        }
+
+        impl<T, Ix: IndexType> Index<$index<Ix>> for $sparse<T, Ix> {
+            type Output = T;
+
+            /// **Panics** if there is no value at `i`.
+            fn index(&self, i: $index<Ix>) -> &T {
+                self.get(i).unwrap()
+            }
+        }
+
+        impl<T, Ix: IndexType> IndexMut<$index<Ix>> for $sparse<T, Ix> {
+            /// **Panics** if there is no value at `i`.
+            fn index_mut(&mut self, i: $index<Ix>) -> &mut T {
+                self.get_mut(i).unwrap()
+            }
+        }
+
+        impl<'a, T, Ix: IndexType> IntoIterator for &'a $sparse<T, Ix> {
+            type Item = ($index<Ix>, &'a T);
+            type IntoIter = SparseIter<'a, $index<Ix>, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl<T, Ix: IndexType> Extend<($index<Ix>, T)> for $sparse<T, Ix> {
+            fn extend<I>(&mut self, iterable: I)
+            where
+                I: IntoIterator<Item = ($index<Ix>, T)>,
+            {
+                for (i, value) in iterable {
+                    self.insert(i, value);
+                }
+            }
+        }
+
+        impl<T, Ix: IndexType> std::iter::FromIterator<($index<Ix>, T)> for $sparse<T, Ix> {
+            fn from_iter<I>(iterable: I) -> Self
+            where
+                I: IntoIterator<Item = ($index<Ix>, T)>,
+            {
+                let mut map = Self::new();
+                map.extend(iterable);
+                map
+            }
+        }
+    };
+}
+
+index_maps! {
+    /// `NodeMap<T, Ix>` holds a value of type `T` for each node index of a
+    /// graph, from zero up to its length.
+    ///
+    /// It is indexed by `NodeIndex`, and follows a `Graph` if it is changed
+    /// along with it: [`push`](#method.push) for each node added and
+    /// [`swap_remove`](#method.swap_remove) for each node removed. As a
+    /// [`GraphObserver`](../observed/trait.GraphObserver.html) it does so
+    /// on its own, adding a default value for each new node.
+    dense: NodeMap,
+    /// `SparseNodeMap<T, Ix>` holds values of type `T` for some of the node
+    /// indices of a graph.
+    ///
+    /// It is indexed by `NodeIndex`; the values are kept in a `Vec` up to the
+    /// highest index with a value, with holes at the indices without one.
+    sparse: SparseNodeMap,
+    index: NodeIndex,
+}
+
+index_maps! {
+    /// `EdgeMap<T, Ix>` holds a value of type `T` for each edge index of a
+    /// graph, from zero up to its length.
+    ///
+    /// It is indexed by `EdgeIndex`, and follows a `Graph` if it is changed
+    /// along with it: [`push`](#method.push) for each edge added and
+    /// [`swap_remove`](#method.swap_remove) for each edge removed. As a
+    /// [`GraphObserver`](../observed/trait.GraphObserver.html) it does so
+    /// on its own, adding a default value for each new edge.
+    dense: EdgeMap,
+    /// `SparseEdgeMap<T, Ix>` holds values of type `T` for some of the edge
+    /// indices of a graph.
+    ///
+    /// It is indexed by `EdgeIndex`; the values are kept in a `Vec` up to the
+    /// highest index with a value, with holes at the indices without one.
+    sparse: SparseEdgeMap,
+    index: EdgeIndex,
+}
+
+impl<T, Ix: IndexType> NodeMap<T, Ix> {
+    /// Create a map with the value `f(a)` for each node index `a` of `graph`
+    /// up to its node bound.
+    pub fn from_fn<G, F>(graph: G, mut f: F) -> Self
+    where
+        G: NodeIndexable<NodeId = NodeIndex<Ix>>,
+        F: FnMut(NodeIndex<Ix>) -> T,
+    {
+        let n = graph.node_bound();
+        let mut map = Self::with_capacity(n);
+        for i in 0..n {
+            map.push(f(graph.from_index(i)));
+        }
+        map
+    }
+
+    /// Create a map with `value` for each node index of `graph` up to its
+    /// node bound.
+    pub fn from_elem<G>(graph: G, value: T) -> Self
+    where
+        G: NodeIndexable<NodeId = NodeIndex<Ix>>,
+        T: Clone,
+    {
+        Self::from_fn(graph, |_| value.clone())
+    }
+}
+
+impl<T, Ix: IndexType> EdgeMap<T, Ix> {
+    /// Create a map with the value `f(e)` for each edge index `e` of `graph`
+    /// up to its edge bound.
+    pub fn from_fn<G, F>(graph: G, mut f: F) -> Self
+    where
+        G: EdgeIndexable<EdgeId = EdgeIndex<Ix>>,
+        F: FnMut(EdgeIndex<Ix>) -> T,
+    {
+        let n = graph.edge_bound();
+        let mut map = Self::with_capacity(n);
+        for i in 0..n {
+            map.push(f(graph.from_index(i)));
+        }
+        map
+    }
+
+    /// Create a map with `value` for each edge index of `graph` up to its
+    /// edge bound.
+    pub fn from_elem<G>(graph: G, value: T) -> Self
+    where
+        G: EdgeIndexable<EdgeId = EdgeIndex<Ix>>,
+        T: Clone,
+    {
+        Self::from_fn(graph, |_| value.clone())
+    }
+}
+
+impl<T, Ix: IndexType> SparseNodeMap<T, Ix> {
+    /// Create a map with the value `f(a)` for each node `a` of `graph`.
+    pub fn from_fn<G, F>(graph: G, mut f: F) -> Self
+    where
+        G: IntoNodeIdentifiers<NodeId = NodeIndex<Ix>>,
+        F: FnMut(NodeIndex<Ix>) -> T,
+    {
+        graph.node_identifiers().map(|a| (a, f(a))).collect()
+    }
+}
+
+impl<T, Ix: IndexType> SparseEdgeMap<T, Ix> {
+    /// Create a map with the value `f(e)` for each edge `e` of `graph`.
+    pub fn from_fn<G, F>(graph: G, mut f: F) -> Self
+    where
+        G: IntoEdgeReferences<EdgeId = EdgeIndex<Ix>>,
+        F: FnMut(EdgeIndex<Ix>) -> T,
+    {
+        graph
+            .edge_references()
+            .map(|e| (e.id(), f(e.id())))
+            .collect()
+    }
+}
+
+impl<N, E, T, Ix> GraphObserver<N, E, Ix> for NodeMap<T, Ix>
+where
+    T: Default,
+    Ix: IndexType,
+{
+    fn node_added(&mut self, a: NodeIndex<Ix>, _: &N) {
+        debug_assert_eq!(a.index(), self.len());
+        self.push(T::default());
+    }
+
+    fn node_removed(&mut self, a: NodeIndex<Ix>, _: &N) {
+        self.swap_remove(a);
+    }
+}
+
+impl<N, E, T, Ix> GraphObserver<N, E, Ix> for EdgeMap<T, Ix>
+where
+    T: Default,
+    Ix: IndexType,
+{
+    fn edge_added(&mut self, e: EdgeIndex<Ix>, _: NodeIndex<Ix>, _: NodeIndex<Ix>, _: &E) {
+        debug_assert_eq!(e.index(), self.len());
+        self.push(T::default());
+    }
+
+    fn edge_removed(&mut self, e: EdgeIndex<Ix>, _: NodeIndex<Ix>, _: NodeIndex<Ix>, _: &E) {
+        self.swap_remove(e);
+    }
+}
@@ -0,0 +1,298 @@
+//! `GraphBuilder` collects nodes by key and edges between keys, and checks
+//! them before creating a `Graph`.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use super::{DefaultIx, EdgeIndex, Graph, GraphError, IndexType, NodeIndex};
+use crate::{Directed, EdgeType, Undirected};
+
+/// What [`GraphBuilder`] does with self loops or parallel edges.
+///
+/// [`GraphBuilder`]: struct.GraphBuilder.html
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EdgePolicy {
+    /// Add the edges to the graph.
+    Allow,
+    /// Leave the edges out of the graph. Of parallel edges, the first one
+    /// added to the builder is kept.
+    Ignore,
+    /// Fail to build the graph.
+    Reject,
+}
+
+#[allow(clippy::type_complexity)]
+enum Parallel<E> {
+    Policy(EdgePolicy),
+    Merge(Box<dyn FnMut(&mut E, E)>),
+}
+
+/// An error from [`GraphBuilder::build`].
+///
+/// [`GraphBuilder::build`]: struct.GraphBuilder.html#method.build
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuildError<K> {
+    /// There is a self loop at the node with this key, and self loops are
+    /// rejected.
+    SelfLoop(K),
+    /// There are parallel edges between the nodes with these keys, and
+    /// parallel edges are rejected.
+    ParallelEdges(K, K),
+    /// The graph can't hold the nodes or edges.
+    Graph(GraphError),
+}
+
+impl<K> From<GraphError> for BuildError<K> {
+    fn from(error: GraphError) -> Self {
+        BuildError::Graph(error)
+    }
+}
+
+impl<K: fmt::Debug> fmt::Display for BuildError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::SelfLoop(ref a) => write!(f, "self loop at node {:?}", a),
+            BuildError::ParallelEdges(ref a, ref b) => {
+                write!(f, "parallel edges between nodes {:?} and {:?}", a, b)
+            }
+            BuildError::Graph(ref error) => error.fmt(f),
+        }
+    }
+}
+
+impl<K: fmt::Debug> std::error::Error for BuildError<K> {}
+
+/// A builder of a `Graph` whose nodes are identified by keys.
+///
+/// Nodes are added by key, each key once however often it is added, and
+/// edges by the keys of their endpoints, adding those nodes if they are new.
+/// [`build`](#method.build) then creates the graph, with the keys as node
+/// weights and the nodes and edges in the order they were first added, and a
+/// map from each key to its node.
+///
+/// By default self loops and parallel edges are added to the graph like any
+/// other edge; [`self_loops`](#method.self_loops) and
+/// [`parallel_edges`](#method.parallel_edges) choose to leave them out or to
+/// fail instead, and [`merge_parallel_edges`](#method.merge_parallel_edges)
+/// combines the weights of parallel edges into one edge. Edges of an
+/// undirected graph are parallel in either direction.
+///
+/// # Example
+/// ```rust
+/// use petgraph::builder::{BuildError, EdgePolicy, GraphBuilder};
+///
+/// let mut builder = GraphBuilder::<&str, u32>::new()
+///     .self_loops(EdgePolicy::Ignore)
+///     .merge_parallel_edges(|w, x| *w += x);
+/// builder.add_edge("a", "b", 1);
+/// builder.add_edge("b", "b", 2);
+/// builder.add_edge("b", "c", 3);
+/// builder.add_edge("a", "b", 4);
+/// builder.add_node("d");
+/// let (g, nodes) = builder.build().unwrap();
+/// assert_eq!(g.node_count(), 4);
+/// assert_eq!(g.edge_count(), 2);
+/// let ab = g.find_edge(nodes["a"], nodes["b"]).unwrap();
+/// assert_eq!(g[ab], 5);
+///
+/// let mut builder = GraphBuilder::<&str, ()>::new().parallel_edges(EdgePolicy::Reject);
+/// builder.add_edge("a", "b", ());
+/// builder.add_edge("a", "b", ());
+/// assert_eq!(builder.build().unwrap_err(), BuildError::ParallelEdges("a", "b"));
+/// ```
+pub struct GraphBuilder<K, E, Ty = Directed, Ix = DefaultIx> {
+    keys: Vec<K>,
+    index: HashMap<K, usize>,
+    edges: Vec<(usize, usize, E)>,
+    self_loops: EdgePolicy,
+    parallel_edges: Parallel<E>,
+    ty: PhantomData<(Ty, Ix)>,
+}
+
+/// A `GraphBuilder` of directed graphs.
+pub type DiGraphBuilder<K, E, Ix = DefaultIx> = GraphBuilder<K, E, Directed, Ix>;
+
+/// A `GraphBuilder` of undirected graphs.
+pub type UnGraphBuilder<K, E, Ix = DefaultIx> = GraphBuilder<K, E, Undirected, Ix>;
+
+impl<K, E, Ty, Ix> fmt::Debug for GraphBuilder<K, E, Ty, Ix>
+where
+    K: fmt::Debug,
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GraphBuilder")
+            .field("keys", &self.keys)
+            .field("edges", &self.edges)
+            .field("self_loops", &self.self_loops)
+            .finish()
+    }
+}
+
+impl<K, E, Ty, Ix> Default for GraphBuilder<K, E, Ty, Ix>
+where
+    K: Hash + Eq + Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, E, Ty, Ix> GraphBuilder<K, E, Ty, Ix>
+where
+    K: Hash + Eq + Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a builder with no nodes or edges, that allows self loops and
+    /// parallel edges.
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// Create a builder with room for `nodes` nodes and `edges` edges.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        GraphBuilder {
+            keys: Vec::with_capacity(nodes),
+            index: HashMap::with_capacity(nodes),
+            edges: Vec::with_capacity(edges),
+            self_loops: EdgePolicy::Allow,
+            parallel_edges: Parallel::Policy(EdgePolicy::Allow),
+            ty: PhantomData,
+        }
+    }
+
+    /// Set what to do with self loops.
+    pub fn self_loops(mut self, policy: EdgePolicy) -> Self {
+        self.self_loops = policy;
+        self
+    }
+
+    /// Set what to do with parallel edges.
+    pub fn parallel_edges(mut self, policy: EdgePolicy) -> Self {
+        self.parallel_edges = Parallel::Policy(policy);
+        self
+    }
+
+    /// Merge parallel edges into the first of them, by calling `merge` with
+    /// its weight and the weight of each of the others in turn.
+    pub fn merge_parallel_edges<F>(mut self, merge: F) -> Self
+    where
+        F: FnMut(&mut E, E) + 'static,
+    {
+        self.parallel_edges = Parallel::Merge(Box::new(merge));
+        self
+    }
+
+    /// Return the number of distinct nodes added.
+    pub fn node_count(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Return the number of edges added, before self loops and parallel
+    /// edges are checked.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Return `true` if a node with key `key` was added.
+    pub fn contains_node(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+
+    fn node(&mut self, key: K) -> (usize, bool) {
+        match self.index.entry(key) {
+            Entry::Occupied(ent) => (*ent.get(), false),
+            Entry::Vacant(ent) => {
+                let i = self.keys.len();
+                self.keys.push(ent.key().clone());
+                ent.insert(i);
+                (i, true)
+            }
+        }
+    }
+
+    /// Add a node with key `key`, unless there already is one.
+    ///
+    /// Return `true` if the node is new.
+    pub fn add_node(&mut self, key: K) -> bool {
+        self.node(key).1
+    }
+
+    /// Add an edge from the node with key `a` to the node with key `b`, with
+    /// weight `weight`, adding the nodes if they are new.
+    pub fn add_edge(&mut self, a: K, b: K, weight: E) {
+        let a = self.node(a).0;
+        let b = self.node(b).0;
+        self.edges.push((a, b, weight));
+    }
+
+    /// Create the graph, and a map from each key to its node.
+    ///
+    /// Return an error if there is a self loop or parallel edges that are
+    /// rejected, or if the graph's index type can't hold all nodes or edges.
+    #[allow(clippy::type_complexity)]
+    pub fn build(self) -> Result<(Graph<K, E, Ty, Ix>, HashMap<K, NodeIndex<Ix>>), BuildError<K>> {
+        let GraphBuilder {
+            keys,
+            index,
+            edges,
+            self_loops,
+            mut parallel_edges,
+            ..
+        } = self;
+        let mut graph = Graph::with_capacity(keys.len(), edges.len());
+        for key in &keys {
+            graph.try_add_node(key.clone())?;
+        }
+        // the first edge between each pair of nodes, unless parallel edges
+        // are allowed
+        let mut first = HashMap::new();
+        for (a, b, weight) in edges {
+            if a == b {
+                match self_loops {
+                    EdgePolicy::Allow => {}
+                    EdgePolicy::Ignore => continue,
+                    EdgePolicy::Reject => return Err(BuildError::SelfLoop(keys[a].clone())),
+                }
+            }
+            if let Parallel::Policy(EdgePolicy::Allow) = parallel_edges {
+            } else {
+                let pair = if Ty::is_directed() || a <= b {
+                    [a, b]
+                } else {
+                    [b, a]
+                };
+                match first.entry(pair) {
+                    Entry::Vacant(ent) => {
+                        ent.insert(EdgeIndex::<Ix>::new(graph.edge_count()));
+                    }
+                    Entry::Occupied(ent) => {
+                        match parallel_edges {
+                            Parallel::Policy(EdgePolicy::Reject) => {
+                                return Err(BuildError::ParallelEdges(
+                                    keys[a].clone(),
+                                    keys[b].clone(),
+                                ));
+                            }
+                            Parallel::Merge(ref mut merge) => merge(&mut graph[*ent.get()], weight),
+                            _ => {}
+                        }
+                        continue;
+                    }
+                }
+            }
+            graph.try_add_edge(NodeIndex::new(a), NodeIndex::new(b), weight)?;
+        }
+        let nodes = index
+            .into_iter()
+            .map(|(key, i)| (key, NodeIndex::new(i)))
+            .collect();
+        Ok((graph, nodes))
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use super::{Edge, EdgeIndex, IndexType, NodeIndex};
+use crate::{Direction, Incoming, Outgoing};
+
+/// An index of the edges of a graph by their endpoints, for constant time
+/// edge lookup.
+///
+/// It holds one edge for each pair of nodes with any edge from the first to
+/// the second, the most recently added one unless it was moved. The nodes of
+/// an undirected edge are stored in order.
+#[derive(Clone, Debug)]
+pub(crate) struct EndpointIndex<Ix> {
+    directed: bool,
+    edges: HashMap<[NodeIndex<Ix>; 2], EdgeIndex<Ix>>,
+}
+
+impl<Ix: IndexType> EndpointIndex<Ix> {
+    /// Create an index of `edges`, each with its endpoints, in the order they
+    /// were added.
+    pub fn new<I>(directed: bool, edges: I) -> Self
+    where
+        I: IntoIterator<Item = (EdgeIndex<Ix>, [NodeIndex<Ix>; 2])>,
+    {
+        let mut index = EndpointIndex {
+            directed,
+            edges: HashMap::new(),
+        };
+        for (e, node) in edges {
+            index.insert(node, e);
+        }
+        index
+    }
+
+    fn key(&self, node: [NodeIndex<Ix>; 2]) -> [NodeIndex<Ix>; 2] {
+        if !self.directed && node[1] < node[0] {
+            [node[1], node[0]]
+        } else {
+            node
+        }
+    }
+
+    /// Return an edge from `a` to `b`.
+    pub fn get(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        self.edges.get(&self.key([a, b])).cloned()
+    }
+
+    /// Return an edge between `a` and `b` in either direction, from `edges`,
+    /// and its direction: `Outgoing` if it is from `a` to `b`.
+    pub fn get_undirected<E>(
+        &self,
+        edges: &[Edge<E, Ix>],
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+    ) -> Option<(EdgeIndex<Ix>, Direction)> {
+        if self.directed {
+            match self.get(a, b) {
+                Some(e) => Some((e, Outgoing)),
+                None => self.get(b, a).map(|e| (e, Incoming)),
+            }
+        } else {
+            let e = self.get(a, b)?;
+            let dir = if edges[e.index()].node[0] == a {
+                Outgoing
+            } else {
+                Incoming
+            };
+            Some((e, dir))
+        }
+    }
+
+    /// Add the edge `e` with endpoints `node`.
+    pub fn insert(&mut self, node: [NodeIndex<Ix>; 2], e: EdgeIndex<Ix>) {
+        let key = self.key(node);
+        self.edges.insert(key, e);
+    }
+
+    /// Remove the edge `e` with endpoints `node`, where `other` is another
+    /// edge with the same endpoints, if there is any.
+    pub fn remove(
+        &mut self,
+        node: [NodeIndex<Ix>; 2],
+        e: EdgeIndex<Ix>,
+        other: Option<EdgeIndex<Ix>>,
+    ) {
+        let key = self.key(node);
+        if self.edges.get(&key) == Some(&e) {
+            match other {
+                Some(other) => self.edges.insert(key, other),
+                None => self.edges.remove(&key),
+            };
+        }
+    }
+
+    /// Record that the edge with endpoints `node` moved from index `from` to
+    /// index `to`.
+    pub fn move_edge(&mut self, node: [NodeIndex<Ix>; 2], from: EdgeIndex<Ix>, to: EdgeIndex<Ix>) {
+        let key = self.key(node);
+        if let Some(e) = self.edges.get_mut(&key) {
+            if *e == from {
+                *e = to;
+            }
+        }
+    }
+
+    /// Record that the endpoints `from` of some edges changed to `to`, where
+    /// no edge had the endpoints `to` before.
+    pub fn move_endpoints(&mut self, from: [NodeIndex<Ix>; 2], to: [NodeIndex<Ix>; 2]) {
+        let from = self.key(from);
+        if let Some(e) = self.edges.remove(&from) {
+            let to = self.key(to);
+            self.edges.insert(to, e);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.edges.clear();
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.edges.shrink_to_fit();
+    }
+
+    /// Return the memory used by the entries and by the spare capacity.
+    pub fn memory_usage(&self) -> (usize, usize) {
+        let entry = size_of::<([NodeIndex<Ix>; 2], EdgeIndex<Ix>)>();
+        let len = self.edges.len();
+        (len * entry, (self.edges.capacity() - len) * entry)
+    }
+}
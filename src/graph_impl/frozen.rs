@@ -7,7 +7,7 @@ use crate::graph::{GraphIndex, IndexType};
 use crate::visit::{
     Data, EdgeCount, EdgeIndexable, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdges,
     IntoEdgesDirected, IntoNeighborsDirected, IntoNodeIdentifiers, NodeCompactIndexable, NodeCount,
-    NodeIndexable,
+    NodeDegrees, NodeIndexable,
 };
 use crate::visit::{IntoEdgeReferences, IntoNeighbors, IntoNodeReferences, Visitable};
 use crate::{Direction, EdgeType};
@@ -105,4 +105,5 @@ NodeIndexable! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 EdgeCount! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 EdgeIndexable! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 GraphProp! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
+NodeDegrees! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
 Visitable! {delegate_impl [['a, G], G, Frozen<'a, G>, deref_twice]}
@@ -0,0 +1,487 @@
+//! `GenerationalGraph` keeps a `Graph` together with generational handles to
+//! its nodes and edges.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::{DefaultIx, EdgeIndex, Graph, IndexType, NodeIndex};
+use crate::{Directed, Direction, EdgeType, Incoming, Outgoing};
+
+/// A handle to a node of a `GenerationalGraph`.
+///
+/// It holds the slot of the node and the generation of the slot when the
+/// node was added, so that it stops referring to anything once its node is
+/// removed, even if the slot is reused by a later node.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GenNodeIndex<Ix = DefaultIx> {
+    slot: Ix,
+    generation: u32,
+}
+
+/// A handle to an edge of a `GenerationalGraph`.
+///
+/// Like [`GenNodeIndex`](struct.GenNodeIndex.html), it stops referring to
+/// anything once its edge is removed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GenEdgeIndex<Ix = DefaultIx> {
+    slot: Ix,
+    generation: u32,
+}
+
+impl<Ix: IndexType> GenNodeIndex<Ix> {
+    /// Return the generation of the handle's slot when its node was added.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+impl<Ix: IndexType> GenEdgeIndex<Ix> {
+    /// Return the generation of the handle's slot when its edge was added.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+/// A slot for handles to refer to, holding the current index of its item in
+/// the graph.
+#[derive(Clone, Debug)]
+struct Slot<I> {
+    generation: u32,
+    index: I,
+}
+
+/// The handle slots of the nodes or the edges of a `GenerationalGraph`.
+#[derive(Clone, Debug)]
+struct Slots<I> {
+    slots: Vec<Slot<I>>,
+    /// The slot of the item at each index of the graph.
+    slot_of: Vec<usize>,
+    free: Vec<usize>,
+}
+
+impl<I: Copy + PartialEq> Slots<I> {
+    fn new() -> Self {
+        Slots {
+            slots: Vec::new(),
+            slot_of: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Give a slot to the item just added at `index`, which is the last
+    /// index of the graph, and return the slot with its generation.
+    fn insert(&mut self, index: I) -> (usize, u32) {
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.slots[slot].index = index;
+                slot
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    index,
+                });
+                self.slots.len() - 1
+            }
+        };
+        self.slot_of.push(slot);
+        (slot, self.slots[slot].generation)
+    }
+
+    /// Return the index of the item of `slot`, if `generation` is current.
+    fn get(&self, slot: usize, generation: u32) -> Option<I> {
+        match self.slots.get(slot) {
+            Some(s) if s.generation == generation => Some(s.index),
+            _ => None,
+        }
+    }
+
+    /// Free the slot of the item removed from `removed` by a swap remove,
+    /// which moved the last item, at index `last`, to `removed`.
+    fn swap_remove(&mut self, removed: usize, last: usize, removed_index: I) {
+        let slot = self.slot_of.swap_remove(removed);
+        let s = &mut self.slots[slot];
+        s.generation = s.generation.wrapping_add(1);
+        self.free.push(slot);
+        if removed != last {
+            self.slots[self.slot_of[removed]].index = removed_index;
+        }
+    }
+}
+
+/// `GenerationalGraph<N, E, Ty, Ix>` is a `Graph` whose nodes and edges are
+/// referred to by generational handles.
+///
+/// Removing a node or an edge from a `Graph` moves the last node or edge
+/// into its place, so that a `NodeIndex` or `EdgeIndex` kept from before the
+/// removal can silently refer to a different item. The handles of a
+/// `GenerationalGraph`, [`GenNodeIndex`](struct.GenNodeIndex.html) and
+/// [`GenEdgeIndex`](struct.GenEdgeIndex.html), keep referring to the same
+/// item when others are removed, and a handle to a removed item is detected
+/// as stale by every method, even after its slot is reused.
+///
+/// The underlying `Graph` is available with [`graph`](#method.graph), to run
+/// algorithms on, and [`node_handle`](#method.node_handle) and
+/// [`edge_handle`](#method.edge_handle) translate its indices back to
+/// handles.
+///
+/// Each handle holds a `u32` generation that is increased every time its
+/// slot is freed, so a stale handle could only be mistaken for a current one
+/// after 2<sup>32</sup> removals from the same slot.
+///
+/// ```
+/// use petgraph::generational::GenerationalGraph;
+///
+/// let mut g = GenerationalGraph::<&str, ()>::new();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// let c = g.add_node("c");
+/// g.add_edge(b, c, ());
+///
+/// // removing `a` moves `c` in the underlying graph, but its handle follows
+/// g.remove_node(a);
+/// assert_eq!(g[c], "c");
+/// assert!(!g.contains_node(a));
+///
+/// // the slot of `a` is reused, but its handle stays stale
+/// let d = g.add_node("d");
+/// assert_eq!(g.node_weight(a), None);
+/// assert_eq!(g[d], "d");
+/// ```
+pub struct GenerationalGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    graph: Graph<N, E, Ty, Ix>,
+    nodes: Slots<NodeIndex<Ix>>,
+    edges: Slots<EdgeIndex<Ix>>,
+}
+
+impl<N, E, Ty, Ix> Clone for GenerationalGraph<N, E, Ty, Ix>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn clone(&self) -> Self {
+        GenerationalGraph {
+            graph: self.graph.clone(),
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+        }
+    }
+}
+
+impl<N, E, Ty, Ix> fmt::Debug for GenerationalGraph<N, E, Ty, Ix>
+where
+    N: fmt::Debug,
+    E: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GenerationalGraph")
+            .field("graph", &self.graph)
+            .finish()
+    }
+}
+
+impl<N, E, Ty, Ix> Default for GenerationalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        GenerationalGraph {
+            graph: Graph::default(),
+            nodes: Slots::new(),
+            edges: Slots::new(),
+        }
+    }
+}
+
+impl<N, E> GenerationalGraph<N, E, Directed> {
+    /// Create a new `GenerationalGraph` with directed edges.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<N, E, Ty, Ix> GenerationalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Return the underlying graph.
+    pub fn graph(&self) -> &Graph<N, E, Ty, Ix> {
+        &self.graph
+    }
+
+    /// Return the underlying graph, dropping the handles.
+    pub fn into_graph(self) -> Graph<N, E, Ty, Ix> {
+        self.graph
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Add a node with associated data `weight`, and return its handle.
+    ///
+    /// **Panics** if the underlying graph is at the maximum number of nodes
+    /// for its index type.
+    pub fn add_node(&mut self, weight: N) -> GenNodeIndex<Ix> {
+        let index = self.graph.add_node(weight);
+        let (slot, generation) = self.nodes.insert(index);
+        GenNodeIndex {
+            slot: Ix::new(slot),
+            generation,
+        }
+    }
+
+    /// Add an edge from `a` to `b` with associated data `weight`, and return
+    /// its handle.
+    ///
+    /// **Panics** if `a` or `b` is stale, or if the underlying graph is at
+    /// the maximum number of edges for its index type.
+    pub fn add_edge(
+        &mut self,
+        a: GenNodeIndex<Ix>,
+        b: GenNodeIndex<Ix>,
+        weight: E,
+    ) -> GenEdgeIndex<Ix> {
+        let a = self
+            .node_index(a)
+            .expect("GenerationalGraph::add_edge: stale node handle");
+        let b = self
+            .node_index(b)
+            .expect("GenerationalGraph::add_edge: stale node handle");
+        let index = self.graph.add_edge(a, b, weight);
+        let (slot, generation) = self.edges.insert(index);
+        GenEdgeIndex {
+            slot: Ix::new(slot),
+            generation,
+        }
+    }
+
+    /// Remove `a` and all its edges from the graph, and return its weight,
+    /// or `None` if the handle is stale.
+    ///
+    /// The handles of the other nodes and edges stay valid.
+    pub fn remove_node(&mut self, a: GenNodeIndex<Ix>) -> Option<N> {
+        let a = self.node_index(a)?;
+        for &dir in &[Outgoing, Incoming] {
+            while let Some(e) = self.graph.first_edge(a, dir) {
+                self.remove_edge_index(e);
+            }
+        }
+        let last = self.graph.node_count() - 1;
+        let weight = self.graph.remove_node(a);
+        self.nodes.swap_remove(a.index(), last, a);
+        weight
+    }
+
+    /// Remove the edge `e` from the graph, and return its weight, or `None`
+    /// if the handle is stale.
+    ///
+    /// The handles of the other edges stay valid.
+    pub fn remove_edge(&mut self, e: GenEdgeIndex<Ix>) -> Option<E> {
+        let e = self.edge_index(e)?;
+        self.remove_edge_index(e)
+    }
+
+    fn remove_edge_index(&mut self, e: EdgeIndex<Ix>) -> Option<E> {
+        let last = self.graph.edge_count() - 1;
+        let weight = self.graph.remove_edge(e);
+        self.edges.swap_remove(e.index(), last, e);
+        weight
+    }
+
+    /// Return `true` if the node of the handle `a` is in the graph.
+    pub fn contains_node(&self, a: GenNodeIndex<Ix>) -> bool {
+        self.node_index(a).is_some()
+    }
+
+    /// Return `true` if the edge of the handle `e` is in the graph.
+    pub fn contains_edge(&self, e: GenEdgeIndex<Ix>) -> bool {
+        self.edge_index(e).is_some()
+    }
+
+    /// Return the current index of node `a` in the underlying graph, or
+    /// `None` if the handle is stale.
+    ///
+    /// The index is only current until the next removal.
+    pub fn node_index(&self, a: GenNodeIndex<Ix>) -> Option<NodeIndex<Ix>> {
+        self.nodes.get(a.slot.index(), a.generation)
+    }
+
+    /// Return the current index of edge `e` in the underlying graph, or
+    /// `None` if the handle is stale.
+    ///
+    /// The index is only current until the next removal.
+    pub fn edge_index(&self, e: GenEdgeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        self.edges.get(e.slot.index(), e.generation)
+    }
+
+    /// Return the handle of the node at index `a` of the underlying graph,
+    /// or `None` if it doesn't exist.
+    pub fn node_handle(&self, a: NodeIndex<Ix>) -> Option<GenNodeIndex<Ix>> {
+        let slot = *self.nodes.slot_of.get(a.index())?;
+        Some(GenNodeIndex {
+            slot: Ix::new(slot),
+            generation: self.nodes.slots[slot].generation,
+        })
+    }
+
+    /// Return the handle of the edge at index `e` of the underlying graph,
+    /// or `None` if it doesn't exist.
+    pub fn edge_handle(&self, e: EdgeIndex<Ix>) -> Option<GenEdgeIndex<Ix>> {
+        let slot = *self.edges.slot_of.get(e.index())?;
+        Some(GenEdgeIndex {
+            slot: Ix::new(slot),
+            generation: self.edges.slots[slot].generation,
+        })
+    }
+
+    /// Access the weight for node `a`, or `None` if the handle is stale.
+    pub fn node_weight(&self, a: GenNodeIndex<Ix>) -> Option<&N> {
+        self.graph.node_weight(self.node_index(a)?)
+    }
+
+    /// Access the weight for node `a`, mutably, or `None` if the handle is
+    /// stale.
+    pub fn node_weight_mut(&mut self, a: GenNodeIndex<Ix>) -> Option<&mut N> {
+        let a = self.node_index(a)?;
+        self.graph.node_weight_mut(a)
+    }
+
+    /// Access the weight for edge `e`, or `None` if the handle is stale.
+    pub fn edge_weight(&self, e: GenEdgeIndex<Ix>) -> Option<&E> {
+        self.graph.edge_weight(self.edge_index(e)?)
+    }
+
+    /// Access the weight for edge `e`, mutably, or `None` if the handle is
+    /// stale.
+    pub fn edge_weight_mut(&mut self, e: GenEdgeIndex<Ix>) -> Option<&mut E> {
+        let e = self.edge_index(e)?;
+        self.graph.edge_weight_mut(e)
+    }
+
+    /// Return the handles of the source and target nodes of edge `e`, or
+    /// `None` if the handle is stale.
+    pub fn edge_endpoints(
+        &self,
+        e: GenEdgeIndex<Ix>,
+    ) -> Option<(GenNodeIndex<Ix>, GenNodeIndex<Ix>)> {
+        let (a, b) = self.graph.edge_endpoints(self.edge_index(e)?)?;
+        Some((self.node_handle(a)?, self.node_handle(b)?))
+    }
+
+    /// Return the handle of an edge from `a` to `b` (or between them, if
+    /// undirected), or `None` if there is none or a handle is stale.
+    pub fn find_edge(&self, a: GenNodeIndex<Ix>, b: GenNodeIndex<Ix>) -> Option<GenEdgeIndex<Ix>> {
+        let e = self
+            .graph
+            .find_edge(self.node_index(a)?, self.node_index(b)?)?;
+        self.edge_handle(e)
+    }
+
+    /// Return an iterator of the handles of the neighbors of `a` in
+    /// direction `dir`, like `Graph::neighbors_directed`.
+    ///
+    /// **Panics** if `a` is stale.
+    pub fn neighbors_directed(
+        &self,
+        a: GenNodeIndex<Ix>,
+        dir: Direction,
+    ) -> impl Iterator<Item = GenNodeIndex<Ix>> + '_ {
+        let a = self
+            .node_index(a)
+            .expect("GenerationalGraph::neighbors_directed: stale node handle");
+        self.graph
+            .neighbors_directed(a, dir)
+            .map(move |b| self.node_handle(b).unwrap())
+    }
+}
+
+/// Index the `GenerationalGraph` by `GenNodeIndex` to access node weights.
+///
+/// **Panics** if the handle is stale.
+impl<N, E, Ty, Ix> Index<GenNodeIndex<Ix>> for GenerationalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: GenNodeIndex<Ix>) -> &N {
+        self.node_weight(index).unwrap()
+    }
+}
+
+/// Index the `GenerationalGraph` by `GenNodeIndex` to access node weights.
+///
+/// **Panics** if the handle is stale.
+impl<N, E, Ty, Ix> IndexMut<GenNodeIndex<Ix>> for GenerationalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: GenNodeIndex<Ix>) -> &mut N {
+        self.node_weight_mut(index).unwrap()
+    }
+}
+
+/// Index the `GenerationalGraph` by `GenEdgeIndex` to access edge weights.
+///
+/// **Panics** if the handle is stale.
+impl<N, E, Ty, Ix> Index<GenEdgeIndex<Ix>> for GenerationalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: GenEdgeIndex<Ix>) -> &E {
+        self.edge_weight(index).unwrap()
+    }
+}
+
+/// Index the `GenerationalGraph` by `GenEdgeIndex` to access edge weights.
+///
+/// **Panics** if the handle is stale.
+impl<N, E, Ty, Ix> IndexMut<GenEdgeIndex<Ix>> for GenerationalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: GenEdgeIndex<Ix>) -> &mut E {
+        self.edge_weight_mut(index).unwrap()
+    }
+}
+
+/// Wrap a `Graph` into a `GenerationalGraph`, giving handles to its nodes
+/// and edges in index order.
+impl<N, E, Ty, Ix> From<Graph<N, E, Ty, Ix>> for GenerationalGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: Graph<N, E, Ty, Ix>) -> Self {
+        let mut nodes = Slots::new();
+        for a in graph.node_indices() {
+            nodes.insert(a);
+        }
+        let mut edges = Slots::new();
+        for e in graph.edge_indices() {
+            edges.insert(e);
+        }
+        GenerationalGraph {
+            graph,
+            nodes,
+            edges,
+        }
+    }
+}
@@ -0,0 +1,410 @@
+//! `KeyedGraph` is a `Graph` whose nodes can be found by a key, like a name.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::{Index, IndexMut};
+
+use super::{DefaultIx, EdgeIndex, Graph, IndexType, NodeIndex};
+use crate::{Directed, EdgeType};
+
+/// `KeyedGraph<K, N, E, Ty, Ix>` is a `Graph` with a distinct key of type `K`
+/// for each node, kept apart from the node weights.
+///
+/// Nodes can be found by their key and edges by the keys of their endpoints,
+/// and [`add_edge_by_key`](#method.add_edge_by_key) adds the nodes of an edge
+/// if there are no nodes with their keys yet, which is how a graph is read
+/// from a list of edges between names.
+///
+/// The keys are stored twice, in the list of keys by node index and in the
+/// map from keys to node indices, which are kept up to date as nodes are
+/// removed and the indices move like those of a `Graph`.
+///
+/// The underlying `Graph` is available with [`graph`](#method.graph), to run
+/// algorithms on.
+///
+/// ```
+/// use petgraph::algo::dijkstra;
+/// use petgraph::keyed_graph::KeyedGraph;
+///
+/// let mut g = KeyedGraph::<String, (), u32>::new();
+/// for line in "a b 1\nb c 2\na c 5".lines() {
+///     let f: Vec<_> = line.split(' ').collect();
+///     g.add_edge_by_key(f[0].to_string(), f[1].to_string(), f[2].parse().unwrap());
+/// }
+/// assert_eq!(g.node_count(), 3);
+/// let a = g.node_index("a").unwrap();
+/// let c = g.node_index("c").unwrap();
+/// let dist = dijkstra(g.graph(), a, Some(c), |e| *e.weight());
+/// assert_eq!(dist[&c], 3);
+///
+/// g.remove_node_by_key("a");
+/// // the last node, `c`, took the place of `a`
+/// assert_eq!(g.node_index("c"), Some(a));
+/// assert_eq!(g.key(a).map(|k| &k[..]), Some("c"));
+/// assert_eq!(g.find_edge_by_key("b", "c").map(|e| g[e]), Some(2));
+/// ```
+pub struct KeyedGraph<K, N, E, Ty = Directed, Ix = DefaultIx> {
+    graph: Graph<N, E, Ty, Ix>,
+    keys: Vec<K>,
+    index: HashMap<K, NodeIndex<Ix>>,
+}
+
+impl<K, N, E, Ty, Ix> Clone for KeyedGraph<K, N, E, Ty, Ix>
+where
+    K: Clone,
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn clone(&self) -> Self {
+        KeyedGraph {
+            graph: self.graph.clone(),
+            keys: self.keys.clone(),
+            index: self.index.clone(),
+        }
+    }
+}
+
+impl<K, N, E, Ty, Ix> fmt::Debug for KeyedGraph<K, N, E, Ty, Ix>
+where
+    K: fmt::Debug,
+    N: fmt::Debug,
+    E: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("KeyedGraph")
+            .field("graph", &self.graph)
+            .field("keys", &self.keys)
+            .finish()
+    }
+}
+
+impl<K, N, E, Ty, Ix> KeyedGraph<K, N, E, Ty, Ix>
+where
+    K: Hash + Eq + Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new empty graph.
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// Create a new empty graph with room for `nodes` nodes and `edges`
+    /// edges.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        KeyedGraph {
+            graph: Graph::with_capacity(nodes, edges),
+            keys: Vec::with_capacity(nodes),
+            index: HashMap::with_capacity(nodes),
+        }
+    }
+
+    /// Return the underlying graph.
+    pub fn graph(&self) -> &Graph<N, E, Ty, Ix> {
+        &self.graph
+    }
+
+    /// Return the underlying graph and the keys of its nodes, in index order.
+    pub fn into_parts(self) -> (Graph<N, E, Ty, Ix>, Vec<K>) {
+        (self.graph, self.keys)
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Return the keys of the nodes, in index order.
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Return the key of node `a`.
+    pub fn key(&self, a: NodeIndex<Ix>) -> Option<&K> {
+        self.keys.get(a.index())
+    }
+
+    /// Return the index of the node with key `key`.
+    pub fn node_index<Q>(&self, key: &Q) -> Option<NodeIndex<Ix>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.get(key).cloned()
+    }
+
+    /// Return `true` if there is a node with key `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index.contains_key(key)
+    }
+
+    /// Access the weight for node `a`.
+    ///
+    /// Also available with indexing syntax: `&graph[a]`.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.graph.node_weight(a)
+    }
+
+    /// Access the weight for node `a`, mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[a]`.
+    pub fn node_weight_mut(&mut self, a: NodeIndex<Ix>) -> Option<&mut N> {
+        self.graph.node_weight_mut(a)
+    }
+
+    /// Access the weight for the node with key `key`.
+    pub fn node_weight_by_key<Q>(&self, key: &Q) -> Option<&N>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let a = self.node_index(key)?;
+        self.graph.node_weight(a)
+    }
+
+    /// Access the weight for the node with key `key`, mutably.
+    pub fn node_weight_by_key_mut<Q>(&mut self, key: &Q) -> Option<&mut N>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let a = self.node_index(key)?;
+        self.graph.node_weight_mut(a)
+    }
+
+    /// Access the weight for edge `e`.
+    ///
+    /// Also available with indexing syntax: `&graph[e]`.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.graph.edge_weight(e)
+    }
+
+    /// Access the weight for edge `e`, mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[e]`.
+    pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
+        self.graph.edge_weight_mut(e)
+    }
+
+    /// Add a node with key `key` and weight `weight`, and return its index.
+    ///
+    /// If there already is a node with key `key`, its weight is replaced
+    /// instead.
+    pub fn add_node(&mut self, key: K, weight: N) -> NodeIndex<Ix> {
+        match self.index.get(&key) {
+            Some(&a) => {
+                self.graph[a] = weight;
+                a
+            }
+            None => {
+                let a = self.graph.add_node(weight);
+                self.keys.push(key.clone());
+                self.index.insert(key, a);
+                a
+            }
+        }
+    }
+
+    /// Return the index of the node with key `key`, adding it with a default
+    /// weight if there is none.
+    fn node_or_default(&mut self, key: K) -> NodeIndex<Ix>
+    where
+        N: Default,
+    {
+        match self.index.get(&key) {
+            Some(&a) => a,
+            None => self.add_node(key, N::default()),
+        }
+    }
+
+    /// Add an edge from `a` to `b`, with weight `weight`, and return its
+    /// index.
+    ///
+    /// **Panics** if the nodes don't exist.
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        self.graph.add_edge(a, b, weight)
+    }
+
+    /// Add an edge from the node with key `a` to the node with key `b`, with
+    /// weight `weight`, and return its index.
+    ///
+    /// Nodes that don't exist yet are added, with default weights.
+    pub fn add_edge_by_key(&mut self, a: K, b: K, weight: E) -> EdgeIndex<Ix>
+    where
+        N: Default,
+    {
+        let a = self.node_or_default(a);
+        let b = self.node_or_default(b);
+        self.graph.add_edge(a, b, weight)
+    }
+
+    /// Add or update an edge from the node with key `a` to the node with key
+    /// `b`, and return its index.
+    ///
+    /// Nodes that don't exist yet are added, with default weights.
+    pub fn update_edge_by_key(&mut self, a: K, b: K, weight: E) -> EdgeIndex<Ix>
+    where
+        N: Default,
+    {
+        let a = self.node_or_default(a);
+        let b = self.node_or_default(b);
+        self.graph.update_edge(a, b, weight)
+    }
+
+    /// Return an edge from the node with key `a` to the node with key `b`.
+    pub fn find_edge_by_key<Q>(&self, a: &Q, b: &Q) -> Option<EdgeIndex<Ix>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let a = self.node_index(a)?;
+        let b = self.node_index(b)?;
+        self.graph.find_edge(a, b)
+    }
+
+    /// Remove node `a` and its edges, and return its key and weight.
+    ///
+    /// The last node takes the index of the removed node, like in
+    /// `Graph::remove_node`.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> Option<(K, N)> {
+        let weight = self.graph.remove_node(a)?;
+        let key = self.keys.swap_remove(a.index());
+        self.index.remove(&key);
+        if let Some(moved) = self.keys.get(a.index()) {
+            self.index.insert(moved.clone(), a);
+        }
+        Some((key, weight))
+    }
+
+    /// Remove the node with key `key` and its edges, and return its key and
+    /// weight.
+    pub fn remove_node_by_key<Q>(&mut self, key: &Q) -> Option<(K, N)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let a = self.node_index(key)?;
+        self.remove_node(a)
+    }
+
+    /// Remove edge `e` and return its weight.
+    ///
+    /// The last edge takes the index of the removed edge, like in
+    /// `Graph::remove_edge`.
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> Option<E> {
+        self.graph.remove_edge(e)
+    }
+
+    /// Remove all nodes and edges.
+    pub fn clear(&mut self) {
+        self.graph.clear();
+        self.keys.clear();
+        self.index.clear();
+    }
+}
+
+impl<K, N, E, Ty, Ix> Default for KeyedGraph<K, N, E, Ty, Ix>
+where
+    K: Hash + Eq + Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Add the edges between the keys, with the nodes that don't exist yet.
+impl<K, N, E, Ty, Ix> Extend<(K, K, E)> for KeyedGraph<K, N, E, Ty, Ix>
+where
+    K: Hash + Eq + Clone,
+    N: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn extend<I>(&mut self, iterable: I)
+    where
+        I: IntoIterator<Item = (K, K, E)>,
+    {
+        for (a, b, weight) in iterable {
+            self.add_edge_by_key(a, b, weight);
+        }
+    }
+}
+
+/// Create a graph from the edges between the keys, with default node
+/// weights.
+impl<K, N, E, Ty, Ix> FromIterator<(K, K, E)> for KeyedGraph<K, N, E, Ty, Ix>
+where
+    K: Hash + Eq + Clone,
+    N: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from_iter<I>(iterable: I) -> Self
+    where
+        I: IntoIterator<Item = (K, K, E)>,
+    {
+        let mut g = Self::new();
+        g.extend(iterable);
+        g
+    }
+}
+
+impl<K, N, E, Ty, Ix> Index<NodeIndex<Ix>> for KeyedGraph<K, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        &self.graph[index]
+    }
+}
+
+impl<K, N, E, Ty, Ix> IndexMut<NodeIndex<Ix>> for KeyedGraph<K, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: NodeIndex<Ix>) -> &mut N {
+        &mut self.graph[index]
+    }
+}
+
+impl<K, N, E, Ty, Ix> Index<EdgeIndex<Ix>> for KeyedGraph<K, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.graph[index]
+    }
+}
+
+impl<K, N, E, Ty, Ix> IndexMut<EdgeIndex<Ix>> for KeyedGraph<K, N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: EdgeIndex<Ix>) -> &mut E {
+        &mut self.graph[index]
+    }
+}
@@ -3,7 +3,7 @@ use std::fmt;
 use std::hash::Hash;
 use std::iter;
 use std::marker::PhantomData;
-use std::mem::size_of;
+use std::mem::{size_of, size_of_val};
 use std::ops::{Index, IndexMut, Range};
 use std::slice;
 
@@ -16,8 +16,10 @@ use crate::iter_format::{DebugMap, IterFormatExt, NoPretty};
 use crate::util::enumerate;
 use crate::visit;
 
+use self::endpoint_index::EndpointIndex;
+
 #[cfg(feature = "serde-1")]
-mod serialization;
+pub(crate) mod serialization;
 
 /// The default integer type for graph indices.
 /// `u32` is the default to reduce the size of the graph's data and improve
@@ -146,6 +148,139 @@ impl<Ix: fmt::Debug> fmt::Debug for NodeIndex<Ix> {
     }
 }
 
+/// The translation of node and edge indices after nodes were removed from a
+/// `Graph` without reordering the rest, as returned by
+/// [`remove_node_shift`](struct.Graph.html#method.remove_node_shift) and
+/// [`remove_nodes_shift`](struct.Graph.html#method.remove_nodes_shift).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexShift<Ix = DefaultIx> {
+    node_map: Vec<NodeIndex<Ix>>,
+    edge_map: Vec<EdgeIndex<Ix>>,
+}
+
+impl<Ix: IndexType> IndexShift<Ix> {
+    /// Return the new index of the node that had index `a`, or `None` if it
+    /// was removed or didn't exist.
+    pub fn node(&self, a: NodeIndex<Ix>) -> Option<NodeIndex<Ix>> {
+        self.node_map
+            .get(a.index())
+            .cloned()
+            .filter(|&b| b != NodeIndex::end())
+    }
+
+    /// Return the new index of the edge that had index `e`, or `None` if it
+    /// was removed or didn't exist.
+    pub fn edge(&self, e: EdgeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        self.edge_map
+            .get(e.index())
+            .cloned()
+            .filter(|&f| f != EdgeIndex::end())
+    }
+
+    /// Return the new index of each old node index, `NodeIndex::end()` for a
+    /// removed node.
+    pub fn node_map(&self) -> &[NodeIndex<Ix>] {
+        &self.node_map
+    }
+
+    /// Return the new index of each old edge index, `EdgeIndex::end()` for a
+    /// removed edge.
+    pub fn edge_map(&self) -> &[EdgeIndex<Ix>] {
+        &self.edge_map
+    }
+}
+
+/// The error type for fallible graph operations, like
+/// [`Graph::try_add_edge`](struct.Graph.html#method.try_add_edge).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GraphError {
+    /// The graph is at the maximum number of nodes for its index type.
+    NodeIxLimit,
+    /// The graph is at the maximum number of edges for its index type.
+    EdgeIxLimit,
+    /// The node with this index is not in the graph.
+    NodeMissed(usize),
+    /// The graph does not allow parallel edges, and there already is an
+    /// edge between the nodes.
+    EdgeExists,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GraphError::NodeIxLimit => {
+                f.write_str("the graph is at the maximum number of nodes for its index type")
+            }
+            GraphError::EdgeIxLimit => {
+                f.write_str("the graph is at the maximum number of edges for its index type")
+            }
+            GraphError::NodeMissed(i) => write!(f, "node index {} is not a node in the graph", i),
+            GraphError::EdgeExists => f.write_str("there already is an edge between the nodes"),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// An estimate of the heap memory held by a graph, in bytes, as returned by
+/// [`Graph::memory_usage`](struct.Graph.html#method.memory_usage) and the
+/// `memory_usage` methods of the other graph types.
+///
+/// Each part counts the size of the elements of the collections involved; the
+/// bookkeeping of the allocator and of hash tables is not included.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MemoryUsage {
+    /// Memory used by the nodes of the graph.
+    pub nodes: usize,
+    /// Memory used by the edges of the graph.
+    pub edges: usize,
+    /// Memory used by vacant node and edge entries, kept for reuse.
+    pub vacant: usize,
+    /// Memory used by adjacency structures apart from the nodes and edges,
+    /// like neighbor lists, empty matrix cells or lookup indices.
+    pub adjacency: usize,
+    /// Memory allocated for future insertions, see `shrink_to_fit`.
+    pub unused: usize,
+}
+
+impl MemoryUsage {
+    /// Return the total of all parts.
+    pub fn total(&self) -> usize {
+        self.nodes + self.edges + self.vacant + self.adjacency + self.unused
+    }
+}
+
+/// Return the memory used by the elements of `v` and by its spare capacity.
+pub(crate) fn vec_usage<T>(v: &[T], capacity: usize) -> (usize, usize) {
+    (size_of_val(v), (capacity - v.len()) * size_of::<T>())
+}
+
+/// Return `true` if `len` indices are in use and another one can be added
+/// without reaching `Ix::max()`, which marks the end of lists.
+pub(crate) fn index_available<Ix: IndexType>(len: usize) -> bool {
+    <Ix as IndexType>::max().index() == !0 || len < <Ix as IndexType>::max().index()
+}
+
+/// Check the array lengths given to `from_parallel_arrays`.
+fn check_parallel_arrays<Ix: IndexType>(sources: usize, targets: usize, weights: usize) {
+    assert!(
+        sources == targets && targets == weights,
+        "from_parallel_arrays: the arrays have different lengths"
+    );
+    assert!(
+        weights == 0 || index_available::<Ix>(weights - 1),
+        "from_parallel_arrays: too many edges for the index type"
+    );
+}
+
+/// Check the number of nodes needed by the edges of `from_parallel_arrays`.
+fn check_node_bound<Ix: IndexType>(node_bound: usize) {
+    assert!(
+        node_bound == 0 || index_available::<Ix>(node_bound - 1),
+        "from_parallel_arrays: too many nodes for the index type"
+    );
+}
+
 /// Short version of `NodeIndex::new`
 pub fn node_index<Ix: IndexType>(index: usize) -> NodeIndex<Ix> {
     NodeIndex::new(index)
@@ -345,6 +480,8 @@ impl<E, Ix: IndexType> Edge<E, Ix> {
 pub struct Graph<N, E, Ty = Directed, Ix = DefaultIx> {
     nodes: Vec<Node<N, Ix>>,
     edges: Vec<Edge<E, Ix>>,
+    /// The index of the edges by their endpoints, if it is enabled
+    endpoints: Option<EndpointIndex<Ix>>,
     ty: PhantomData<Ty>,
 }
 
@@ -370,6 +507,7 @@ where
         Graph {
             nodes: self.nodes.clone(),
             edges: self.edges.clone(),
+            endpoints: self.endpoints.clone(),
             ty: self.ty,
         }
     }
@@ -377,6 +515,7 @@ where
     fn clone_from(&mut self, rhs: &Self) {
         self.nodes.clone_from(&rhs.nodes);
         self.edges.clone_from(&rhs.edges);
+        self.endpoints.clone_from(&rhs.endpoints);
         self.ty = rhs.ty;
     }
 }
@@ -459,6 +598,7 @@ impl<N, E> Graph<N, E, Directed> {
         Graph {
             nodes: Vec::new(),
             edges: Vec::new(),
+            endpoints: None,
             ty: PhantomData,
         }
     }
@@ -473,6 +613,7 @@ impl<N, E> Graph<N, E, Undirected> {
         Graph {
             nodes: Vec::new(),
             edges: Vec::new(),
+            endpoints: None,
             ty: PhantomData,
         }
     }
@@ -488,6 +629,7 @@ where
         Graph {
             nodes: Vec::with_capacity(nodes),
             edges: Vec::with_capacity(edges),
+            endpoints: None,
             ty: PhantomData,
         }
     }
@@ -532,6 +674,29 @@ where
         node_idx
     }
 
+    /// Add a node (also called vertex) with associated data `weight` to the
+    /// graph, or return an error if the graph is at the maximum number of
+    /// nodes for its index type.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// ```
+    /// use petgraph::graph::{DiGraph, GraphError};
+    ///
+    /// let mut g = DiGraph::<(), (), u8>::default();
+    /// for _ in 0..255 {
+    ///     g.try_add_node(()).unwrap();
+    /// }
+    /// assert_eq!(g.try_add_node(()), Err(GraphError::NodeIxLimit));
+    /// ```
+    pub fn try_add_node(&mut self, weight: N) -> Result<NodeIndex<Ix>, GraphError> {
+        if index_available::<Ix>(self.nodes.len()) {
+            Ok(self.add_node(weight))
+        } else {
+            Err(GraphError::NodeIxLimit)
+        }
+    }
+
     /// Access the weight for node `a`.
     ///
     /// Also available with indexing syntax: `&graph[a]`.
@@ -582,9 +747,48 @@ where
             }
         }
         self.edges.push(edge);
+        if let Some(endpoints) = &mut self.endpoints {
+            endpoints.insert([a, b], edge_idx);
+        }
         edge_idx
     }
 
+    /// Add an edge from `a` to `b` to the graph, with its associated
+    /// data `weight`, or return an error if either node doesn't exist or
+    /// the graph is at the maximum number of edges for its index type.
+    ///
+    /// Return the index of the new edge.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// ```
+    /// use petgraph::graph::{Graph, GraphError, NodeIndex};
+    ///
+    /// let mut g = Graph::<(), ()>::new();
+    /// let a = g.add_node(());
+    /// assert!(g.try_add_edge(a, a, ()).is_ok());
+    /// assert_eq!(
+    ///     g.try_add_edge(a, NodeIndex::new(1), ()),
+    ///     Err(GraphError::NodeMissed(1))
+    /// );
+    /// ```
+    pub fn try_add_edge(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+        weight: E,
+    ) -> Result<EdgeIndex<Ix>, GraphError> {
+        for &n in &[a, b] {
+            if n.index() >= self.nodes.len() {
+                return Err(GraphError::NodeMissed(n.index()));
+            }
+        }
+        if !index_available::<Ix>(self.edges.len()) {
+            return Err(GraphError::EdgeIxLimit);
+        }
+        Ok(self.add_edge(a, b, weight))
+    }
+
     /// Add or update an edge from `a` to `b`.
     /// If the edge already exists, its weight is updated.
     ///
@@ -618,6 +822,28 @@ where
         self.edges.get_mut(e.index()).map(|ed| &mut ed.weight)
     }
 
+    /// Access the weight for an edge from `a` to `b`, as found by
+    /// [`find_edge`](#method.find_edge).
+    ///
+    /// Also available with indexing syntax: `&graph[(a, b)]`.
+    pub fn edge_weight_between(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<&E> {
+        let e = self.find_edge(a, b)?;
+        self.edge_weight(e)
+    }
+
+    /// Access the weight for an edge from `a` to `b`, as found by
+    /// [`find_edge`](#method.find_edge), mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[(a, b)]`.
+    pub fn edge_weight_between_mut(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+    ) -> Option<&mut E> {
+        let e = self.find_edge(a, b)?;
+        self.edge_weight_mut(e)
+    }
+
     /// Access the source and target nodes for `e`.
     pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(NodeIndex<Ix>, NodeIndex<Ix>)> {
         self.edges
@@ -676,12 +902,108 @@ where
             let mut edges = edges_walker_mut(&mut self.edges, swap_edges[k], d);
             while let Some(curedge) = edges.next_edge() {
                 debug_assert!(curedge.node[k] == old_index);
+                let old_node = curedge.node;
                 curedge.node[k] = new_index;
+                if let Some(endpoints) = &mut self.endpoints {
+                    endpoints.move_endpoints(old_node, curedge.node);
+                }
             }
         }
         Some(node.weight)
     }
 
+    /// Remove `a` from the graph if it exists, and return its weight along
+    /// with the translation of the indices, or `None` if it doesn't exist.
+    ///
+    /// Unlike `.remove_node(a)`, this keeps the relative order of the
+    /// remaining nodes and edges: the nodes after `a` move down by one index,
+    /// and each remaining edge moves down by the number of removed edges
+    /// before it.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    ///
+    /// ```
+    /// use petgraph::graph::{EdgeIndex, NodeIndex};
+    /// use petgraph::Graph;
+    ///
+    /// let mut g = Graph::<&str, ()>::new();
+    /// let a = g.add_node("a");
+    /// let b = g.add_node("b");
+    /// let c = g.add_node("c");
+    /// let ab = g.add_edge(a, b, ());
+    /// let bc = g.add_edge(b, c, ());
+    ///
+    /// let (weight, shift) = g.remove_node_shift(a).unwrap();
+    /// assert_eq!(weight, "a");
+    /// assert_eq!(shift.node(a), None);
+    /// assert_eq!(shift.node(c), Some(NodeIndex::new(1)));
+    /// assert_eq!(shift.edge(ab), None);
+    /// assert_eq!(shift.edge(bc), Some(EdgeIndex::new(0)));
+    /// assert_eq!(g.node_weights().collect::<Vec<_>>(), vec![&"b", &"c"]);
+    /// ```
+    pub fn remove_node_shift(&mut self, a: NodeIndex<Ix>) -> Option<(N, IndexShift<Ix>)> {
+        self.nodes.get(a.index())?;
+        let mut weight = None;
+        let shift = self.remove_nodes_shift_with(|i| i == a, |_, w| weight = Some(w));
+        weight.map(|w| (w, shift))
+    }
+
+    /// Remove the nodes of `nodes` from the graph, and return the
+    /// translation of the indices.
+    ///
+    /// Like `.remove_node_shift(a)`, this keeps the relative order of the
+    /// remaining nodes and edges. Indices that are out of bounds or repeated
+    /// are ignored.
+    ///
+    /// Computes in **O(|V| + |E|)** time, plus the length of `nodes`.
+    pub fn remove_nodes_shift<I>(&mut self, nodes: I) -> IndexShift<Ix>
+    where
+        I: IntoIterator<Item = NodeIndex<Ix>>,
+    {
+        let mut remove = FixedBitSet::with_capacity(self.node_count());
+        for a in nodes {
+            if a.index() < self.node_count() {
+                remove.insert(a.index());
+            }
+        }
+        self.remove_nodes_shift_with(|a| remove.contains(a.index()), |_, _| {})
+    }
+
+    fn remove_nodes_shift_with<F, R>(&mut self, mut remove: F, mut removed: R) -> IndexShift<Ix>
+    where
+        F: FnMut(NodeIndex<Ix>) -> bool,
+        R: FnMut(NodeIndex<Ix>, N),
+    {
+        let nodes = std::mem::take(&mut self.nodes);
+        let edges = std::mem::take(&mut self.edges);
+        if let Some(endpoints) = &mut self.endpoints {
+            endpoints.clear();
+        }
+        self.nodes.reserve(nodes.len());
+        self.edges.reserve(edges.len());
+        let mut node_map = Vec::with_capacity(nodes.len());
+        let mut edge_map = Vec::with_capacity(edges.len());
+        for (i, node) in enumerate(nodes) {
+            let a = NodeIndex::new(i);
+            if remove(a) {
+                node_map.push(NodeIndex::end());
+                removed(a, node.weight);
+            } else {
+                node_map.push(self.add_node(node.weight));
+            }
+        }
+        for edge in edges {
+            let source = node_map[edge.source().index()];
+            let target = node_map[edge.target().index()];
+            if source == NodeIndex::end() || target == NodeIndex::end() {
+                edge_map.push(EdgeIndex::end());
+            } else {
+                edge_map.push(self.add_edge(source, target, edge.weight));
+            }
+        }
+        IndexShift { node_map, edge_map }
+    }
+
     /// For edge `e` with endpoints `edge_node`, replace links to it,
     /// with links to `edge_next`.
     fn change_edge_links(
@@ -737,6 +1059,12 @@ where
         // Remove the edge from its in and out lists by replacing it with
         // a link to the next in the list.
         self.change_edge_links(edge_node, e, edge_next);
+        if self.endpoints.is_some() {
+            let other = self.find_edge_linear(edge_node[0], edge_node[1]);
+            if let Some(endpoints) = &mut self.endpoints {
+                endpoints.remove(edge_node, e, other);
+            }
+        }
         self.remove_edge_adjust_indices(e)
     }
 
@@ -755,9 +1083,98 @@ where
         // Update the edge lists by replacing links to the old index by references to the new
         // edge index.
         self.change_edge_links(swap, swapped_e, [e, e]);
+        if let Some(endpoints) = &mut self.endpoints {
+            endpoints.move_edge(swap, swapped_e, e);
+        }
         Some(edge.weight)
     }
 
+    /// Subdivide the edge `e` with a new node of weight `weight`: replace the
+    /// edge from `a` to `b` by an edge from `a` to the new node and one from
+    /// the new node to `b`, both with the weight of `e`.
+    ///
+    /// Return the index of the new node, or `None` if the edge doesn't exist.
+    ///
+    /// Invalidates edge indices like `.remove_edge(e)`; the two new edges are
+    /// the last edge indices.
+    ///
+    /// Computes in **O(e')** time, like `.remove_edge(e)`.
+    pub fn subdivide_edge(&mut self, e: EdgeIndex<Ix>, weight: N) -> Option<NodeIndex<Ix>>
+    where
+        E: Clone,
+    {
+        let (a, b) = self.edge_endpoints(e)?;
+        let edge_weight = self.remove_edge(e)?;
+        let x = self.add_node(weight);
+        self.add_edge(a, x, edge_weight.clone());
+        self.add_edge(x, b, edge_weight);
+        Some(x)
+    }
+
+    /// Contract the edge `e` from `a` to `b`: remove it, and merge `b` into
+    /// `a` by moving the other edges of `b` to `a`, and its weight with
+    /// `merge(&mut weight_of_a, weight_of_b)`.
+    ///
+    /// Other edges between `a` and `b` become self loops of the merged node,
+    /// and edges from `a` and `b` to the same node become parallel edges;
+    /// they can be removed with `.retain_edges()`. Contracting a self loop
+    /// just removes it.
+    ///
+    /// Return the index of the merged node, or `None` if the edge doesn't
+    /// exist.
+    ///
+    /// Invalidates node and edge indices like `.remove_node(b)`, including
+    /// the index of `a` if it is the last node; the moved edges are the last
+    /// edge indices.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges of
+    /// `a` and `b`, and of the displaced node.
+    pub fn contract_edge<F>(&mut self, e: EdgeIndex<Ix>, merge: F) -> Option<NodeIndex<Ix>>
+    where
+        F: FnOnce(&mut N, N),
+    {
+        let (a, b) = self.edge_endpoints(e)?;
+        if a == b {
+            self.remove_edge(e);
+            return Some(a);
+        }
+        let mut incident = self
+            .edges_directed(b, Outgoing)
+            .chain(self.edges_directed(b, Incoming))
+            .map(|edge| edge.index)
+            .collect::<Vec<_>>();
+        incident.sort_by(|x, y| y.cmp(x));
+        incident.dedup();
+        // Remove from the highest index down, so that no edge left to
+        // remove is displaced.
+        let mut moved = Vec::with_capacity(incident.len());
+        for f in incident {
+            let (x, y) = self.edge_endpoints(f).unwrap();
+            let weight = self.remove_edge(f).unwrap();
+            if f != e {
+                moved.push((x, y, weight));
+            }
+        }
+
+        let last = NodeIndex::new(self.node_count() - 1);
+        let weight = self.remove_node(b).unwrap();
+        let merged = if a == last { b } else { a };
+        merge(&mut self[merged], weight);
+        let renumber = |x: NodeIndex<Ix>| {
+            if x == a || x == b {
+                merged
+            } else if x == last {
+                b
+            } else {
+                x
+            }
+        };
+        for (x, y, weight) in moved.into_iter().rev() {
+            self.add_edge(renumber(x), renumber(y), weight);
+        }
+        Some(merged)
+    }
+
     /// Return an iterator of all nodes with an edge starting from `a`.
     ///
     /// - `Directed`: Outgoing edges from `a`.
@@ -884,7 +1301,9 @@ where
     /// Lookup if there is an edge from `a` to `b`.
     ///
     /// Computes in **O(e')** time, where **e'** is the number of edges
-    /// connected to `a` (and `b`, if the graph edges are undirected).
+    /// connected to `a` (and `b`, if the graph edges are undirected), or in
+    /// **O(1)** time if the [endpoint index](#method.enable_endpoint_index)
+    /// is enabled.
     pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
         self.find_edge(a, b).is_some()
     }
@@ -892,15 +1311,24 @@ where
     /// Lookup an edge from `a` to `b`.
     ///
     /// Computes in **O(e')** time, where **e'** is the number of edges
-    /// connected to `a` (and `b`, if the graph edges are undirected).
+    /// connected to `a` (and `b`, if the graph edges are undirected), or in
+    /// **O(1)** time if the [endpoint index](#method.enable_endpoint_index)
+    /// is enabled.
     pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        match &self.endpoints {
+            Some(endpoints) => endpoints.get(a, b),
+            None => self.find_edge_linear(a, b),
+        }
+    }
+
+    /// Lookup an edge from `a` to `b` in the edge lists of `a`.
+    fn find_edge_linear(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        let node = self.nodes.get(a.index())?;
         if !self.is_directed() {
-            self.find_edge_undirected(a, b).map(|(ix, _)| ix)
+            self.find_edge_undirected_from_node(node, b)
+                .map(|(ix, _)| ix)
         } else {
-            match self.nodes.get(a.index()) {
-                None => None,
-                Some(node) => self.find_edge_directed_from_node(node, b),
-            }
+            self.find_edge_directed_from_node(node, b)
         }
     }
 
@@ -931,6 +1359,9 @@ where
         a: NodeIndex<Ix>,
         b: NodeIndex<Ix>,
     ) -> Option<(EdgeIndex<Ix>, Direction)> {
+        if let Some(endpoints) = &self.endpoints {
+            return endpoints.get_undirected(&self.edges, a, b);
+        }
         match self.nodes.get(a.index()) {
             None => None,
             Some(node) => self.find_edge_undirected_from_node(node, b),
@@ -1168,17 +1599,24 @@ where
         for node in &mut self.nodes {
             node.next.swap(0, 1);
         }
+        self.rebuild_endpoint_index();
     }
 
     /// Remove all nodes and edges
     pub fn clear(&mut self) {
         self.nodes.clear();
         self.edges.clear();
+        if let Some(endpoints) = &mut self.endpoints {
+            endpoints.clear();
+        }
     }
 
     /// Remove all edges
     pub fn clear_edges(&mut self) {
         self.edges.clear();
+        if let Some(endpoints) = &mut self.endpoints {
+            endpoints.clear();
+        }
         for node in &mut self.nodes {
             node.next = [EdgeIndex::end(), EdgeIndex::end()];
         }
@@ -1241,6 +1679,39 @@ where
     pub fn shrink_to_fit(&mut self) {
         self.nodes.shrink_to_fit();
         self.edges.shrink_to_fit();
+        if let Some(endpoints) = &mut self.endpoints {
+            endpoints.shrink_to_fit();
+        }
+    }
+
+    /// Return an estimate of the heap memory held by the graph.
+    ///
+    /// The endpoint index, if it is enabled, is counted as adjacency.
+    ///
+    /// ```
+    /// use petgraph::graph::UnGraph;
+    ///
+    /// let mut g = UnGraph::<u32, ()>::with_capacity(100, 0);
+    /// g.add_node(0);
+    /// assert!(g.memory_usage().unused > 0);
+    /// g.shrink_to_fit();
+    /// assert_eq!(g.memory_usage().unused, 0);
+    /// assert_eq!(g.memory_usage().total(), g.memory_usage().nodes);
+    /// ```
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let (nodes, unused_nodes) = vec_usage(&self.nodes, self.nodes.capacity());
+        let (edges, unused_edges) = vec_usage(&self.edges, self.edges.capacity());
+        let (adjacency, unused_index) = match &self.endpoints {
+            Some(endpoints) => endpoints.memory_usage(),
+            None => (0, 0),
+        };
+        MemoryUsage {
+            nodes,
+            edges,
+            vacant: 0,
+            adjacency,
+            unused: unused_nodes + unused_edges + unused_index,
+        }
     }
 
     /// Keep all nodes that return `true` from the `visit` closure,
@@ -1283,6 +1754,82 @@ where
         }
     }
 
+    /// Remove all nodes that return `true` from the `filter` closure, along
+    /// with their edges, and return their weights.
+    ///
+    /// The weights are returned in the order of the nodes' indices before
+    /// the removal. The edges of the removed nodes are dropped; use
+    /// `.drain_edges()` first to keep their weights.
+    ///
+    /// Node and edge indices are invalidated as they would be following the
+    /// removal of each node, from the last index to the first.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    ///
+    /// let mut g = Graph::<i32, ()>::new();
+    /// for i in 0..6 {
+    ///     g.add_node(i);
+    /// }
+    /// let odd = g.drain_nodes(|_, &weight| weight % 2 == 1);
+    /// assert_eq!(odd, vec![1, 3, 5]);
+    /// assert_eq!(g.node_count(), 3);
+    /// ```
+    pub fn drain_nodes<F>(&mut self, mut filter: F) -> Vec<N>
+    where
+        F: FnMut(NodeIndex<Ix>, &N) -> bool,
+    {
+        let mut drained = Vec::new();
+        for index in self.node_indices().rev() {
+            if filter(index, &self.nodes[index.index()].weight) {
+                drained.extend(self.remove_node(index));
+            }
+        }
+        drained.reverse();
+        drained
+    }
+
+    /// Remove all edges that return `true` from the `filter` closure, and
+    /// return their source, target and weight.
+    ///
+    /// The edges are returned in the order of their indices before the
+    /// removal.
+    ///
+    /// Edge indices are invalidated as they would be following the removal
+    /// of each edge, from the last index to the first.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    /// use petgraph::visit::EdgeRef;
+    ///
+    /// let mut g = Graph::<(), u32>::from_edges(&[(0, 1, 5), (1, 2, 1), (2, 0, 7)]);
+    /// let heavy = g.drain_edges(|edge| *edge.weight() > 4);
+    /// let heavy: Vec<_> = heavy.into_iter().map(|(a, b, w)| (a.index(), b.index(), w)).collect();
+    /// assert_eq!(heavy, vec![(0, 1, 5), (2, 0, 7)]);
+    /// assert_eq!(g.edge_count(), 1);
+    /// ```
+    pub fn drain_edges<F>(&mut self, mut filter: F) -> Vec<(NodeIndex<Ix>, NodeIndex<Ix>, E)>
+    where
+        F: FnMut(EdgeReference<E, Ix>) -> bool,
+    {
+        let mut drained = Vec::new();
+        for index in self.edge_indices().rev() {
+            let edge = &self.edges[index.index()];
+            let node = edge.node;
+            if filter(EdgeReference {
+                index,
+                node,
+                weight: &edge.weight,
+            }) {
+                if let Some(weight) = self.remove_edge(index) {
+                    drained.push((node[0], node[1], weight));
+                }
+            }
+        }
+        drained.reverse();
+        drained
+    }
+
     /// Create a new `Graph` from an iterable of edges.
     ///
     /// Node weights `N` are set to default values.
@@ -1312,6 +1859,83 @@ where
         g
     }
 
+    /// Create a new `Graph` from parallel arrays of the sources, targets and
+    /// weights of its edges, where edge `i` goes from `sources[i]` to
+    /// `targets[i]` with weight `weights[i]`.
+    ///
+    /// The result is the same as adding the edges one by one with
+    /// [`from_edges`](#method.from_edges), but the adjacency lists are built
+    /// in a single pass, without the checks of `add_edge`. Node weights `N`
+    /// are set to default values, and nodes are inserted to match the edges.
+    ///
+    /// Computes in **O(|V| + |E|)** time, on the current thread. With crate
+    /// feature `"rayon"`,
+    /// [`par_from_parallel_arrays`](#method.par_from_parallel_arrays) builds
+    /// the same graph in parallel.
+    ///
+    /// **Panics** if the arrays have different lengths, or if the graph would
+    /// exceed the maximum number of nodes or edges for its index type.
+    ///
+    /// ```
+    /// use petgraph::graph::{DiGraph, NodeIndex};
+    ///
+    /// let sources = [0u32, 0, 1, 2];
+    /// let targets = [1u32, 2, 2, 0];
+    /// let g = DiGraph::<(), f64>::from_parallel_arrays(&sources, &targets, vec![0.5, 1., 2., 4.]);
+    /// assert_eq!(g.node_count(), 3);
+    /// assert_eq!(g.edge_count(), 4);
+    /// assert!(g.neighbors(NodeIndex::new(0)).eq(vec![NodeIndex::new(2), NodeIndex::new(1)]));
+    /// ```
+    pub fn from_parallel_arrays<A>(sources: &[A], targets: &[A], weights: Vec<E>) -> Self
+    where
+        A: Copy + Into<NodeIndex<Ix>>,
+        N: Default,
+    {
+        Self::from_parallel_arrays_with(sources, targets, weights.into_iter(), N::default)
+    }
+
+    /// Build the graph of `from_parallel_arrays`, with node weights from
+    /// `node_weight`.
+    pub(crate) fn from_parallel_arrays_with<A, I, F>(
+        sources: &[A],
+        targets: &[A],
+        weights: I,
+        mut node_weight: F,
+    ) -> Self
+    where
+        A: Copy + Into<NodeIndex<Ix>>,
+        I: ExactSizeIterator<Item = E>,
+        F: FnMut() -> N,
+    {
+        check_parallel_arrays::<Ix>(sources.len(), targets.len(), weights.len());
+        let node_bound = sources
+            .iter()
+            .chain(targets)
+            .map(|&a| a.into().index() + 1)
+            .max()
+            .unwrap_or(0);
+        check_node_bound::<Ix>(node_bound);
+        let mut g = Self::with_capacity(node_bound, weights.len());
+        g.nodes.extend((0..node_bound).map(|_| Node {
+            weight: node_weight(),
+            next: [EdgeIndex::end(), EdgeIndex::end()],
+        }));
+        for (i, ((&a, &b), weight)) in sources.iter().zip(targets).zip(weights).enumerate() {
+            let (a, b) = (a.into(), b.into());
+            let edge_idx = EdgeIndex::new(i);
+            // the same links as add_edge, which also holds for self loops
+            let next = [g.nodes[a.index()].next[0], g.nodes[b.index()].next[1]];
+            g.nodes[a.index()].next[0] = edge_idx;
+            g.nodes[b.index()].next[1] = edge_idx;
+            g.edges.push(Edge {
+                weight,
+                node: [a, b],
+                next,
+            });
+        }
+        g
+    }
+
     /// Extend the graph from an iterable of edges.
     ///
     /// Node weights `N` are set to default values.
@@ -1418,10 +2042,79 @@ where
     where
         NewTy: EdgeType,
     {
-        Graph {
+        let mut graph = Graph {
             nodes: self.nodes,
             edges: self.edges,
+            endpoints: self.endpoints,
             ty: PhantomData,
+        };
+        graph.rebuild_endpoint_index();
+        graph
+    }
+
+    /// Enable the endpoint index of the graph: a hash map from the endpoints
+    /// of each edge to its index, that is kept up to date by every change to
+    /// the graph.
+    ///
+    /// It makes [`find_edge`](#method.find_edge),
+    /// [`contains_edge`](#method.contains_edge) and
+    /// [`find_edge_undirected`](#method.find_edge_undirected) compute in
+    /// **O(1)** time instead of the number of edges of the node, at the cost
+    /// of memory for each pair of nodes with an edge, and of a hash map update
+    /// for each added or removed edge.
+    ///
+    /// With parallel edges, the edge found may differ from the one found
+    /// without the index. The index is kept by `clone` and
+    /// `into_edge_type`, but not by the methods that create a new graph like
+    /// `map`.
+    ///
+    /// Does nothing if the index is already enabled.
+    ///
+    /// Computes in **O(|E|)** time.
+    ///
+    /// ```
+    /// use petgraph::Graph;
+    ///
+    /// let mut g = Graph::<(), ()>::new();
+    /// let hub = g.add_node(());
+    /// for _ in 0..1000 {
+    ///     let a = g.add_node(());
+    ///     g.add_edge(hub, a, ());
+    /// }
+    /// g.enable_endpoint_index();
+    /// let a = g.add_node(());
+    /// let e = g.add_edge(hub, a, ());
+    /// assert_eq!(g.find_edge(hub, a), Some(e));
+    /// g.remove_edge(e);
+    /// assert!(!g.contains_edge(hub, a));
+    /// ```
+    pub fn enable_endpoint_index(&mut self) {
+        if self.endpoints.is_none() {
+            self.endpoints = Some(EndpointIndex::new(
+                self.is_directed(),
+                self.edges
+                    .iter()
+                    .enumerate()
+                    .map(|(i, edge)| (EdgeIndex::new(i), edge.node)),
+            ));
+        }
+    }
+
+    /// Disable the endpoint index of the graph, and free its memory.
+    pub fn disable_endpoint_index(&mut self) {
+        self.endpoints = None;
+    }
+
+    /// Return `true` if the endpoint index of the graph is enabled.
+    pub fn has_endpoint_index(&self) -> bool {
+        self.endpoints.is_some()
+    }
+
+    /// Rebuild the endpoint index, if it is enabled, after changes to many
+    /// edges.
+    fn rebuild_endpoint_index(&mut self) {
+        if self.endpoints.take().is_some() {
+            self.enable_endpoint_index();
         }
     }
 
@@ -1896,6 +2589,79 @@ where
     }
 }
 
+/// Index the `Graph` by a pair of nodes to access the weight of an edge from
+/// the first to the second.
+///
+/// **Panics** if there is no such edge.
+impl<N, E, Ty, Ix> Index<(NodeIndex<Ix>, NodeIndex<Ix>)> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, (a, b): (NodeIndex<Ix>, NodeIndex<Ix>)) -> &E {
+        self.edge_weight_between(a, b)
+            .expect("Graph::index: no such edge")
+    }
+}
+
+/// Index the `Graph` by a pair of nodes to access the weight of an edge from
+/// the first to the second, mutably.
+///
+/// **Panics** if there is no such edge.
+impl<N, E, Ty, Ix> IndexMut<(NodeIndex<Ix>, NodeIndex<Ix>)> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, (a, b): (NodeIndex<Ix>, NodeIndex<Ix>)) -> &mut E {
+        self.edge_weight_between_mut(a, b)
+            .expect("Graph::index: no such edge")
+    }
+}
+
+/// Graphs are equal if they have nodes with equal weights at the same indices
+/// and edges with equal weights and endpoints at the same indices, as
+/// compared by [`graph_eq`](../algo/fn.graph_eq.html).
+impl<N, E, Ty, Ix> PartialEq for Graph<N, E, Ty, Ix>
+where
+    N: PartialEq,
+    E: PartialEq,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn eq(&self, other: &Self) -> bool {
+        let ends = |e: &Edge<E, Ix>| {
+            if Ty::is_directed() || e.node[0] <= e.node[1] {
+                e.node
+            } else {
+                [e.node[1], e.node[0]]
+            }
+        };
+        self.nodes.len() == other.nodes.len()
+            && self.edges.len() == other.edges.len()
+            && self
+                .nodes
+                .iter()
+                .zip(&other.nodes)
+                .all(|(x, y)| x.weight == y.weight)
+            && self
+                .edges
+                .iter()
+                .zip(&other.edges)
+                .all(|(x, y)| ends(x) == ends(y) && x.weight == y.weight)
+    }
+}
+
+impl<N, E, Ty, Ix> Eq for Graph<N, E, Ty, Ix>
+where
+    N: Eq,
+    E: Eq,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+
 /// Create a new empty `Graph`.
 impl<N, E, Ty, Ix> Default for Graph<N, E, Ty, Ix>
 where
@@ -2234,6 +3000,155 @@ where
     }
 }
 
+/// Parallel iterators, with [`rayon`](https://crates.io/crates/rayon).
+///
+/// Requires crate feature `"rayon"`.
+#[cfg(feature = "rayon")]
+impl<N, E, Ty, Ix> Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType + Send + Sync,
+{
+    /// Create a new `Graph` from parallel arrays of the sources, targets and
+    /// weights of its edges, like
+    /// [`from_parallel_arrays`](#method.from_parallel_arrays), but build the
+    /// adjacency lists in parallel.
+    ///
+    /// The edges are sorted by source and by target, so that the edges of
+    /// each list are next to each other and can be linked independently.
+    /// The result is the same graph as `from_parallel_arrays`.
+    ///
+    /// Computes in **O(|V| + |E| log |E|)** time, split over the threads of
+    /// the rayon thread pool.
+    ///
+    /// **Panics** if the arrays have different lengths, or if the graph would
+    /// exceed the maximum number of nodes or edges for its index type.
+    pub fn par_from_parallel_arrays<A>(sources: &[A], targets: &[A], weights: Vec<E>) -> Self
+    where
+        A: Copy + Into<NodeIndex<Ix>> + Sync,
+        N: Default + Send,
+        E: Send,
+    {
+        use rayon::prelude::*;
+        check_parallel_arrays::<Ix>(sources.len(), targets.len(), weights.len());
+        let node_bound = sources
+            .par_iter()
+            .chain(targets)
+            .map(|&a| a.into().index() + 1)
+            .max()
+            .unwrap_or(0);
+        check_node_bound::<Ix>(node_bound);
+
+        let mut nodes: Vec<Node<N, Ix>> = (0..node_bound)
+            .into_par_iter()
+            .map(|_| Node {
+                weight: N::default(),
+                next: [EdgeIndex::end(), EdgeIndex::end()],
+            })
+            .collect();
+        let mut link = |ends: &[A], k: usize| -> Vec<EdgeIndex<Ix>> {
+            let mut lists: Vec<(usize, usize)> = ends
+                .par_iter()
+                .enumerate()
+                .map(|(i, &a)| (a.into().index(), i))
+                .collect();
+            lists.par_sort_unstable();
+            // add_edge links each edge to the edge added before it, and the
+            // node to the edge added last
+            let mut links: Vec<(usize, EdgeIndex<Ix>)> = lists
+                .par_iter()
+                .enumerate()
+                .map(|(p, &(a, i))| match p.checked_sub(1).map(|q| lists[q]) {
+                    Some((b, j)) if a == b => (i, edge_index(j)),
+                    _ => (i, EdgeIndex::end()),
+                })
+                .collect();
+            links.par_sort_unstable_by_key(|&(i, _)| i);
+            for (p, &(a, i)) in lists.iter().enumerate() {
+                if lists.get(p + 1).map_or(true, |&(b, _)| a != b) {
+                    nodes[a].next[k] = edge_index(i);
+                }
+            }
+            links.into_par_iter().map(|(_, e)| e).collect()
+        };
+        let next_out = link(sources, 0);
+        let next_in = link(targets, 1);
+        let edges = weights
+            .into_par_iter()
+            .zip(sources.par_iter().zip(targets))
+            .zip(next_out.into_par_iter().zip(next_in))
+            .map(|((weight, (&a, &b)), (out, inc))| Edge {
+                weight,
+                node: [a.into(), b.into()],
+                next: [out, inc],
+            })
+            .collect();
+        Graph {
+            nodes,
+            edges,
+            endpoints: None,
+            ty: PhantomData,
+        }
+    }
+
+    /// Return a parallel iterator over the node indices and weights of the
+    /// graph.
+    pub fn par_node_references(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = (NodeIndex<Ix>, &N)>
+    where
+        N: Sync,
+    {
+        use rayon::prelude::*;
+        self.nodes
+            .par_iter()
+            .enumerate()
+            .map(|(i, node)| (node_index(i), &node.weight))
+    }
+
+    /// Return a parallel iterator over all edges.
+    pub fn par_edge_references(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = EdgeReference<'_, E, Ix>>
+    where
+        E: Sync,
+    {
+        use rayon::prelude::*;
+        self.edges
+            .par_iter()
+            .enumerate()
+            .map(|(i, edge)| EdgeReference {
+                index: edge_index(i),
+                node: edge.node,
+                weight: &edge.weight,
+            })
+    }
+
+    /// Return a parallel iterator yielding mutable access to all node
+    /// weights.
+    pub fn par_node_weights_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut N>
+    where
+        N: Send,
+    {
+        use rayon::prelude::*;
+        self.nodes.par_iter_mut().map(|node| &mut node.weight)
+    }
+
+    /// Return a parallel iterator yielding mutable access to all edge
+    /// weights.
+    pub fn par_edge_weights_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut E>
+    where
+        E: Send,
+    {
+        use rayon::prelude::*;
+        self.edges.par_iter_mut().map(|edge| &mut edge.weight)
+    }
+}
+
 impl<N, E, Ty, Ix> visit::EdgeCount for Graph<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -2245,6 +3160,17 @@ where
     }
 }
 
+impl<N, E, Ty, Ix> visit::NodeDegrees for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Computes in **O(e')** time, where **e'** is the number of edges of `a`.
+    fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        self.neighbors_directed(a, dir).count()
+    }
+}
+
 impl<'a, N, E, Ty, Ix> visit::IntoNodeReferences for &'a Graph<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -2388,9 +3314,17 @@ where
     }
 }
 
+pub mod attributes;
+pub mod builder;
+mod endpoint_index;
 mod frozen;
+pub mod generational;
+pub mod keyed_graph;
+pub mod observed;
+pub mod persistent_graph;
 #[cfg(feature = "stable_graph")]
 pub mod stable_graph;
+pub mod static_graph;
 
 /// `Frozen` is a graph wrapper.
 ///
@@ -0,0 +1,429 @@
+//! `ObservedGraph` keeps a `Graph` together with an observer that is told
+//! about every change to it.
+
+use std::fmt;
+use std::ops::Index;
+
+use super::{DefaultIx, EdgeIndex, Graph, IndexType, NodeIndex};
+use crate::{Directed, EdgeType, Incoming, Outgoing};
+
+/// An observer of the changes to an [`ObservedGraph`].
+///
+/// Each method is called right after its change is made to the graph, and
+/// does nothing by default, so an observer only implements the events it
+/// cares about.
+///
+/// A pair of observers is an observer too, that passes each event to both.
+///
+/// [`ObservedGraph`]: struct.ObservedGraph.html
+pub trait GraphObserver<N, E, Ix = DefaultIx> {
+    /// The node `a` was added, with weight `weight`.
+    fn node_added(&mut self, _a: NodeIndex<Ix>, _weight: &N) {}
+
+    /// The node `a` was removed, with weight `weight`.
+    ///
+    /// Its edges were removed before it, each with its own event.
+    fn node_removed(&mut self, _a: NodeIndex<Ix>, _weight: &N) {}
+
+    /// The last node of the graph moved from index `from` to index `to`, the
+    /// index of a node just removed, and the endpoints of its edges with it.
+    fn node_moved(&mut self, _from: NodeIndex<Ix>, _to: NodeIndex<Ix>) {}
+
+    /// The weight of node `a` was replaced: `old` by `new`.
+    fn node_weight_changed(&mut self, _a: NodeIndex<Ix>, _old: &N, _new: &N) {}
+
+    /// The edge `e` from `a` to `b` was added, with weight `weight`.
+    fn edge_added(&mut self, _e: EdgeIndex<Ix>, _a: NodeIndex<Ix>, _b: NodeIndex<Ix>, _weight: &E) {
+    }
+
+    /// The edge `e` from `a` to `b` was removed, with weight `weight`.
+    fn edge_removed(
+        &mut self,
+        _e: EdgeIndex<Ix>,
+        _a: NodeIndex<Ix>,
+        _b: NodeIndex<Ix>,
+        _weight: &E,
+    ) {
+    }
+
+    /// The last edge of the graph moved from index `from` to index `to`, the
+    /// index of an edge just removed.
+    fn edge_moved(&mut self, _from: EdgeIndex<Ix>, _to: EdgeIndex<Ix>) {}
+
+    /// The weight of edge `e` was replaced: `old` by `new`.
+    fn edge_weight_changed(&mut self, _e: EdgeIndex<Ix>, _old: &E, _new: &E) {}
+}
+
+/// The observer that ignores all events.
+impl<N, E, Ix> GraphObserver<N, E, Ix> for () {}
+
+impl<N, E, Ix, A, B> GraphObserver<N, E, Ix> for (A, B)
+where
+    A: GraphObserver<N, E, Ix>,
+    B: GraphObserver<N, E, Ix>,
+    Ix: Copy,
+{
+    fn node_added(&mut self, a: NodeIndex<Ix>, weight: &N) {
+        self.0.node_added(a, weight);
+        self.1.node_added(a, weight);
+    }
+
+    fn node_removed(&mut self, a: NodeIndex<Ix>, weight: &N) {
+        self.0.node_removed(a, weight);
+        self.1.node_removed(a, weight);
+    }
+
+    fn node_moved(&mut self, from: NodeIndex<Ix>, to: NodeIndex<Ix>) {
+        self.0.node_moved(from, to);
+        self.1.node_moved(from, to);
+    }
+
+    fn node_weight_changed(&mut self, a: NodeIndex<Ix>, old: &N, new: &N) {
+        self.0.node_weight_changed(a, old, new);
+        self.1.node_weight_changed(a, old, new);
+    }
+
+    fn edge_added(&mut self, e: EdgeIndex<Ix>, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: &E) {
+        self.0.edge_added(e, a, b, weight);
+        self.1.edge_added(e, a, b, weight);
+    }
+
+    fn edge_removed(&mut self, e: EdgeIndex<Ix>, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: &E) {
+        self.0.edge_removed(e, a, b, weight);
+        self.1.edge_removed(e, a, b, weight);
+    }
+
+    fn edge_moved(&mut self, from: EdgeIndex<Ix>, to: EdgeIndex<Ix>) {
+        self.0.edge_moved(from, to);
+        self.1.edge_moved(from, to);
+    }
+
+    fn edge_weight_changed(&mut self, e: EdgeIndex<Ix>, old: &E, new: &E) {
+        self.0.edge_weight_changed(e, old, new);
+        self.1.edge_weight_changed(e, old, new);
+    }
+}
+
+/// `ObservedGraph<N, E, Ty, Ix, O>` is a `Graph` that tells an observer `O`
+/// about every change to it.
+///
+/// Data derived from a graph, like a lookup of nodes by name, has to be
+/// updated on every change to the graph. An `ObservedGraph` only allows
+/// changes through its own methods, which pass each of them to a
+/// [`GraphObserver`](trait.GraphObserver.html), so the derived data can be
+/// kept in the observer and can't miss one.
+///
+/// The events include the moves of the indices by removals: removing a
+/// node or an edge from a `Graph` moves the last node or edge into its
+/// place.
+///
+/// The underlying `Graph` is available with [`graph`](#method.graph), to run
+/// algorithms on, but weights can only be changed by replacing them, with
+/// [`set_node_weight`](#method.set_node_weight) and
+/// [`set_edge_weight`](#method.set_edge_weight).
+///
+/// ```
+/// use std::collections::HashMap;
+/// use petgraph::graph::NodeIndex;
+/// use petgraph::observed::{GraphObserver, ObservedGraph};
+///
+/// /// A lookup of the nodes by name.
+/// #[derive(Default)]
+/// struct Names(HashMap<&'static str, NodeIndex>);
+///
+/// impl<E> GraphObserver<&'static str, E> for Names {
+///     fn node_added(&mut self, a: NodeIndex, name: &&'static str) {
+///         self.0.insert(*name, a);
+///     }
+///     fn node_removed(&mut self, _: NodeIndex, name: &&'static str) {
+///         self.0.remove(name);
+///     }
+///     fn node_moved(&mut self, from: NodeIndex, to: NodeIndex) {
+///         for a in self.0.values_mut().filter(|a| **a == from) {
+///             *a = to;
+///         }
+///     }
+///     fn node_weight_changed(&mut self, a: NodeIndex, old: &&'static str, new: &&'static str) {
+///         self.0.remove(old);
+///         self.0.insert(*new, a);
+///     }
+/// }
+///
+/// let mut g = ObservedGraph::<_, (), _>::with_observer(Names::default());
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// g.add_node("c");
+/// g.add_edge(a, b, ());
+///
+/// // removing `a` moves `c` into its place
+/// g.remove_node(a);
+/// g.set_node_weight(b, "B");
+/// let names = &g.observer().0;
+/// assert_eq!(names.get("c"), Some(&a));
+/// assert_eq!(names.get("B"), Some(&b));
+/// assert_eq!(names.len(), 2);
+/// ```
+pub struct ObservedGraph<N, E, O, Ty = Directed, Ix = DefaultIx> {
+    graph: Graph<N, E, Ty, Ix>,
+    observer: O,
+}
+
+impl<N, E, O, Ty, Ix> Clone for ObservedGraph<N, E, O, Ty, Ix>
+where
+    N: Clone,
+    E: Clone,
+    O: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn clone(&self) -> Self {
+        ObservedGraph {
+            graph: self.graph.clone(),
+            observer: self.observer.clone(),
+        }
+    }
+}
+
+impl<N, E, O, Ty, Ix> fmt::Debug for ObservedGraph<N, E, O, Ty, Ix>
+where
+    N: fmt::Debug,
+    E: fmt::Debug,
+    O: fmt::Debug,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ObservedGraph")
+            .field("graph", &self.graph)
+            .field("observer", &self.observer)
+            .finish()
+    }
+}
+
+impl<N, E, O, Ty, Ix> ObservedGraph<N, E, O, Ty, Ix>
+where
+    O: GraphObserver<N, E, Ix>,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new empty graph, with the observer `observer`.
+    pub fn with_observer(observer: O) -> Self {
+        ObservedGraph {
+            graph: Graph::default(),
+            observer,
+        }
+    }
+
+    /// Wrap `graph`, with the observer `observer`.
+    ///
+    /// The observer isn't told about the nodes and edges already in `graph`.
+    pub fn from_parts(graph: Graph<N, E, Ty, Ix>, observer: O) -> Self {
+        ObservedGraph { graph, observer }
+    }
+
+    /// Return the underlying graph and the observer.
+    pub fn into_parts(self) -> (Graph<N, E, Ty, Ix>, O) {
+        (self.graph, self.observer)
+    }
+
+    /// Return the underlying graph.
+    pub fn graph(&self) -> &Graph<N, E, Ty, Ix> {
+        &self.graph
+    }
+
+    /// Return the observer.
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    /// Return the observer, mutably.
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    /// Access the weight for node `a`.
+    ///
+    /// Also available with indexing syntax: `&graph[a]`.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.graph.node_weight(a)
+    }
+
+    /// Access the weight for edge `e`.
+    ///
+    /// Also available with indexing syntax: `&graph[e]`.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.graph.edge_weight(e)
+    }
+
+    /// Add a node with associated data `weight`, and return its index.
+    ///
+    /// Fires `node_added`.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes for its
+    /// index type.
+    pub fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        let a = self.graph.add_node(weight);
+        self.observer.node_added(a, &self.graph[a]);
+        a
+    }
+
+    /// Add an edge from `a` to `b` with associated data `weight`, and return
+    /// its index.
+    ///
+    /// Fires `edge_added`.
+    ///
+    /// **Panics** if any of the nodes don't exist, or if the graph is at the
+    /// maximum number of edges for its index type.
+    pub fn add_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        let e = self.graph.add_edge(a, b, weight);
+        self.observer.edge_added(e, a, b, &self.graph[e]);
+        e
+    }
+
+    /// Add or update an edge from `a` to `b`, like `Graph::update_edge`, and
+    /// return its index.
+    ///
+    /// Fires `edge_weight_changed` if the edge exists, and `edge_added`
+    /// otherwise.
+    ///
+    /// **Panics** if any of the nodes don't exist.
+    pub fn update_edge(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> EdgeIndex<Ix> {
+        match self.graph.find_edge(a, b) {
+            Some(e) => {
+                self.set_edge_weight(e, weight);
+                e
+            }
+            None => self.add_edge(a, b, weight),
+        }
+    }
+
+    /// Remove `a` and all its edges from the graph, and return its weight,
+    /// or `None` if it doesn't exist.
+    ///
+    /// Fires `edge_removed` and `edge_moved` for each edge of `a`, like
+    /// [`remove_edge`](#method.remove_edge), then `node_removed`, then
+    /// `node_moved` if the last node moved into the place of `a`.
+    pub fn remove_node(&mut self, a: NodeIndex<Ix>) -> Option<N> {
+        self.graph.node_weight(a)?;
+        for &dir in &[Outgoing, Incoming] {
+            while let Some(e) = self.graph.first_edge(a, dir) {
+                self.remove_edge(e);
+            }
+        }
+        let last = NodeIndex::new(self.graph.node_count() - 1);
+        let weight = self.graph.remove_node(a)?;
+        self.observer.node_removed(a, &weight);
+        if a != last {
+            self.observer.node_moved(last, a);
+        }
+        Some(weight)
+    }
+
+    /// Remove the edge `e` from the graph, and return its weight, or `None`
+    /// if it doesn't exist.
+    ///
+    /// Fires `edge_removed`, then `edge_moved` if the last edge moved into
+    /// the place of `e`.
+    pub fn remove_edge(&mut self, e: EdgeIndex<Ix>) -> Option<E> {
+        let (a, b) = self.graph.edge_endpoints(e)?;
+        let last = EdgeIndex::new(self.graph.edge_count() - 1);
+        let weight = self.graph.remove_edge(e)?;
+        self.observer.edge_removed(e, a, b, &weight);
+        if e != last {
+            self.observer.edge_moved(last, e);
+        }
+        Some(weight)
+    }
+
+    /// Replace the weight of node `a` by `weight`, and return the old weight,
+    /// or `None` if the node doesn't exist.
+    ///
+    /// Fires `node_weight_changed`.
+    pub fn set_node_weight(&mut self, a: NodeIndex<Ix>, weight: N) -> Option<N> {
+        let slot = self.graph.node_weight_mut(a)?;
+        let old = std::mem::replace(slot, weight);
+        self.observer.node_weight_changed(a, &old, slot);
+        Some(old)
+    }
+
+    /// Replace the weight of edge `e` by `weight`, and return the old weight,
+    /// or `None` if the edge doesn't exist.
+    ///
+    /// Fires `edge_weight_changed`.
+    pub fn set_edge_weight(&mut self, e: EdgeIndex<Ix>, weight: E) -> Option<E> {
+        let slot = self.graph.edge_weight_mut(e)?;
+        let old = std::mem::replace(slot, weight);
+        self.observer.edge_weight_changed(e, &old, slot);
+        Some(old)
+    }
+
+    /// Remove all edges.
+    ///
+    /// Fires `edge_removed` for each edge, from the last to the first, so
+    /// that no edge moves.
+    pub fn clear_edges(&mut self) {
+        while self.graph.edge_count() > 0 {
+            self.remove_edge(EdgeIndex::new(self.graph.edge_count() - 1));
+        }
+    }
+
+    /// Remove all nodes and edges.
+    ///
+    /// Fires `edge_removed` for each edge, then `node_removed` for each node,
+    /// both from the last to the first, so that nothing moves.
+    pub fn clear(&mut self) {
+        self.clear_edges();
+        while self.graph.node_count() > 0 {
+            self.remove_node(NodeIndex::new(self.graph.node_count() - 1));
+        }
+    }
+}
+
+impl<N, E, O, Ty, Ix> Default for ObservedGraph<N, E, O, Ty, Ix>
+where
+    O: GraphObserver<N, E, Ix> + Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::with_observer(O::default())
+    }
+}
+
+/// Index the `ObservedGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, O, Ty, Ix> Index<NodeIndex<Ix>> for ObservedGraph<N, E, O, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        &self.graph[index]
+    }
+}
+
+/// Index the `ObservedGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, O, Ty, Ix> Index<EdgeIndex<Ix>> for ObservedGraph<N, E, O, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.graph[index]
+    }
+}
@@ -0,0 +1,847 @@
+//! `PersistentGraph<N, E, Ty, Ix>` is an immutable graph, where adding a node
+//! or an edge makes a new version that shares most of its structure with the
+//! old one.
+
+use std::iter::Enumerate;
+use std::marker::PhantomData;
+use std::ops::Index;
+
+use fixedbitset::FixedBitSet;
+
+use self::vector::Vector;
+use super::{
+    swap_pair, DefaultIx, Edge, EdgeIndex, EdgeIndices, EdgeReference, Graph, IndexType, Node,
+    NodeIndex, NodeIndices, DIRECTIONS,
+};
+use crate::data::DataMap;
+use crate::visit::{
+    Data, EdgeCount, EdgeIndexable, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences,
+    IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeIndexable, Visitable,
+};
+use crate::{Directed, Direction, EdgeType, Incoming, Outgoing, Undirected};
+
+mod vector;
+
+/// `PersistentGraph<N, E, Ty, Ix>` is an immutable graph, where adding a node
+/// or an edge makes a new version that shares most of its structure with the
+/// old one.
+///
+/// The nodes and edges are stored like in a [`Graph`], with the adjacency
+/// list of each node linked through its edges, but in persistent vectors:
+/// tries of small chunks behind reference counted pointers. Adding a node or
+/// an edge, or changing a weight, copies only the chunks on the path to the
+/// nodes and edges it changes, in **O(log |V| + log |E|)** time, and leaves
+/// the old version as it was. So many versions of a graph can be kept
+/// cheaply, for example for undo or to explore alternatives from a common
+/// base, and cloning a `PersistentGraph` takes **O(1)** time.
+///
+/// `PersistentGraph` is parameterized over:
+///
+/// - Associated data `N` for nodes and `E` for edges, called *weights*.
+///   The associated data can be of arbitrary type, and must be `Clone` to
+///   make new versions.
+/// - Edge type `Ty` that determines whether the graph edges are directed or
+///   undirected.
+/// - Index type `Ix`, which determines the maximum size of the graph.
+///
+/// Nodes and edges can't be removed, so their indices are never invalidated,
+/// and are the same as in a `Graph` built by the same additions: the
+/// neighbors and edges of a node are listed in the same order too. Accessing
+/// a node or an edge takes **O(log n)** time instead of **O(1)**.
+///
+/// The versions can be shared between threads.
+///
+/// ```
+/// use petgraph::algo::dijkstra;
+/// use petgraph::graph::NodeIndex;
+/// use petgraph::persistent_graph::PersistentGraph;
+///
+/// let g = PersistentGraph::<&str, u32>::new();
+/// let (g, a) = g.add_node("a");
+/// let (g, b) = g.add_node("b");
+/// let (g, c) = g.add_node("c");
+/// let (v1, _) = g.add_edge(a, b, 1);
+/// let (v1, _) = v1.add_edge(b, c, 1);
+/// let (v2, _) = v1.add_edge(a, c, 5);
+/// let (v3, _) = v1.add_edge(a, c, 1);
+///
+/// assert_eq!(v1.edge_count(), 2);
+/// assert_eq!(dijkstra(&v2, a, Some(c), |e| *e.weight())[&c], 2);
+/// assert_eq!(dijkstra(&v3, a, Some(c), |e| *e.weight())[&c], 1);
+/// ```
+///
+/// [`Graph`]: ../graph/struct.Graph.html
+#[derive(Debug)]
+pub struct PersistentGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    nodes: Vector<Node<N, Ix>>,
+    edges: Vector<Edge<E, Ix>>,
+    ty: PhantomData<Ty>,
+}
+
+/// A directed persistent graph.
+pub type DiPersistentGraph<N, E, Ix = DefaultIx> = PersistentGraph<N, E, Directed, Ix>;
+
+/// An undirected persistent graph.
+pub type UnPersistentGraph<N, E, Ix = DefaultIx> = PersistentGraph<N, E, Undirected, Ix>;
+
+/// Clone the graph in **O(1)** time, sharing all of its structure.
+impl<N, E, Ty, Ix> Clone for PersistentGraph<N, E, Ty, Ix> {
+    fn clone(&self) -> Self {
+        PersistentGraph {
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<N, E, Ty, Ix> Default for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, E, Ty, Ix> PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a new empty `PersistentGraph`.
+    pub fn new() -> Self {
+        PersistentGraph {
+            nodes: Vector::new(),
+            edges: Vector::new(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return the number of nodes (vertices) in the graph.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return the number of edges in the graph.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Whether the graph has directed edges or not.
+    #[inline]
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Access the weight for node `a`.
+    ///
+    /// Also available with indexing syntax: `&graph[a]`.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.nodes.get(a.index()).map(|n| &n.weight)
+    }
+
+    /// Access the weight for edge `e`.
+    ///
+    /// Also available with indexing syntax: `&graph[e]`.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.edges.get(e.index()).map(|e| &e.weight)
+    }
+
+    /// Access the source and target nodes for `e`.
+    pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(NodeIndex<Ix>, NodeIndex<Ix>)> {
+        self.edges
+            .get(e.index())
+            .map(|ed| (ed.source(), ed.target()))
+    }
+
+    /// Return an iterator of all nodes with an edge starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Neighbors<'_, E, Ix> {
+        self.neighbors_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of all neighbors that have an edge between them and
+    /// `a`, in the specified direction.
+    /// If the graph's edges are undirected, this is equivalent to *.neighbors(a)*.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// The neighbors are listed in the same order as in a `Graph` built by the
+    /// same additions.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Neighbors<'_, E, Ix> {
+        let mut next = self.first_edges(a);
+        let mut skip_start = a;
+        if self.is_directed() {
+            next[1 - dir.index()] = EdgeIndex::end();
+            skip_start = NodeIndex::end();
+        }
+        Neighbors {
+            skip_start,
+            edges: &self.edges,
+            next,
+        }
+    }
+
+    /// Return the first outgoing and incoming edges of `a`.
+    fn first_edges(&self, a: NodeIndex<Ix>) -> [EdgeIndex<Ix>; 2] {
+        match self.nodes.get(a.index()) {
+            None => [EdgeIndex::end(), EdgeIndex::end()],
+            Some(n) => n.next,
+        }
+    }
+
+    /// Return an iterator of all edges of `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges connected to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `EdgeReference<E, Ix>`.
+    pub fn edges(&self, a: NodeIndex<Ix>) -> Edges<'_, E, Ty, Ix> {
+        self.edges_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of all edges of `a`, in the specified direction.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`, `Outgoing`: All edges connected to `a`, with `a` being the source of each
+    ///   edge.
+    /// - `Undirected`, `Incoming`: All edges connected to `a`, with `a` being the target of each
+    ///   edge.
+    ///
+    /// Produces an empty iterator if the node `a` doesn't exist.<br>
+    /// Iterator element type is `EdgeReference<E, Ix>`.
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Edges<'_, E, Ty, Ix> {
+        Edges {
+            skip_start: a,
+            edges: &self.edges,
+            direction: dir,
+            next: self.first_edges(a),
+            ty: PhantomData,
+        }
+    }
+
+    /// Lookup an edge from `a` to `b`.
+    ///
+    /// Computes in **O(e' log |E|)** time, where **e'** is the number of edges
+    /// connected to `a` (and `b`, if the graph edges are undirected).
+    pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        let next = self.first_edges(a);
+        let directions: &[Direction] = if self.is_directed() {
+            &DIRECTIONS[..1]
+        } else {
+            &DIRECTIONS
+        };
+        for &d in directions {
+            let k = d.index();
+            let mut edix = next[k];
+            while let Some(edge) = self.edges.get(edix.index()) {
+                if edge.node[1 - k] == b {
+                    return Some(edix);
+                }
+                edix = edge.next[k];
+            }
+        }
+        None
+    }
+
+    /// Lookup if there is an edge from `a` to `b`.
+    ///
+    /// Computes in **O(e' log |E|)** time, where **e'** is the number of edges
+    /// connected to `a` (and `b`, if the graph edges are undirected).
+    pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.find_edge(a, b).is_some()
+    }
+
+    /// Return an iterator over the node indices of the graph.
+    pub fn node_indices(&self) -> NodeIndices<Ix> {
+        NodeIndices {
+            r: 0..self.node_count(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return an iterator over the edge indices of the graph.
+    pub fn edge_indices(&self) -> EdgeIndices<Ix> {
+        EdgeIndices {
+            r: 0..self.edge_count(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Create a `Graph` with the same nodes and edges, and the same indices,
+    /// cloning the weights.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    pub fn to_graph(&self) -> Graph<N, E, Ty, Ix>
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let mut graph = Graph::with_capacity(self.node_count(), self.edge_count());
+        graph.nodes.extend(self.nodes.iter().cloned());
+        graph.edges.extend(self.edges.iter().cloned());
+        graph
+    }
+}
+
+impl<N, E, Ty, Ix> PersistentGraph<N, E, Ty, Ix>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Return a new version of the graph with a node added, with associated
+    /// data `weight`, and the index of the new node.
+    ///
+    /// Computes in **O(log |V|)** time.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes for its index
+    /// type (N/A if usize).
+    pub fn add_node(&self, weight: N) -> (Self, NodeIndex<Ix>) {
+        let node_idx = NodeIndex::new(self.nodes.len());
+        // check for max capacity, except if we use usize
+        assert!(<Ix as IndexType>::max().index() == !0 || NodeIndex::end() != node_idx);
+        let mut graph = self.clone();
+        graph.nodes.push(Node {
+            weight,
+            next: [EdgeIndex::end(), EdgeIndex::end()],
+        });
+        (graph, node_idx)
+    }
+
+    /// Return a new version of the graph with an edge from `a` to `b` added,
+    /// with associated data `weight`, and the index of the new edge.
+    ///
+    /// Computes in **O(log |V| + log |E|)** time.
+    ///
+    /// **Panics** if any of the nodes don't exist.<br>
+    /// **Panics** if the graph is at the maximum number of edges for its index
+    /// type (N/A if usize).
+    pub fn add_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>, weight: E) -> (Self, EdgeIndex<Ix>) {
+        let edge_idx = EdgeIndex::new(self.edges.len());
+        assert!(<Ix as IndexType>::max().index() == !0 || EdgeIndex::end() != edge_idx);
+        let next = match (self.nodes.get(a.index()), self.nodes.get(b.index())) {
+            (Some(an), _) if a == b => an.next,
+            (Some(an), Some(bn)) => [an.next[0], bn.next[1]],
+            _ => panic!("PersistentGraph::add_edge: node indices out of bounds"),
+        };
+        let mut graph = self.clone();
+        graph.nodes.get_mut(a.index()).unwrap().next[0] = edge_idx;
+        graph.nodes.get_mut(b.index()).unwrap().next[1] = edge_idx;
+        graph.edges.push(Edge {
+            weight,
+            node: [a, b],
+            next,
+        });
+        (graph, edge_idx)
+    }
+
+    /// Return a new version of the graph with the weight of node `a` replaced
+    /// by `weight`.
+    ///
+    /// Computes in **O(log |V|)** time.
+    ///
+    /// **Panics** if the node doesn't exist.
+    pub fn with_node_weight(&self, a: NodeIndex<Ix>, weight: N) -> Self {
+        let mut graph = self.clone();
+        match graph.nodes.get_mut(a.index()) {
+            Some(node) => node.weight = weight,
+            None => panic!("PersistentGraph::with_node_weight: node index out of bounds"),
+        }
+        graph
+    }
+
+    /// Return a new version of the graph with the weight of edge `e` replaced
+    /// by `weight`.
+    ///
+    /// Computes in **O(log |E|)** time.
+    ///
+    /// **Panics** if the edge doesn't exist.
+    pub fn with_edge_weight(&self, e: EdgeIndex<Ix>, weight: E) -> Self {
+        let mut graph = self.clone();
+        match graph.edges.get_mut(e.index()) {
+            Some(edge) => edge.weight = weight,
+            None => panic!("PersistentGraph::with_edge_weight: edge index out of bounds"),
+        }
+        graph
+    }
+}
+
+/// Create a `PersistentGraph` with the same nodes and edges as `graph`, and
+/// the same indices.
+impl<N, E, Ty, Ix> From<Graph<N, E, Ty, Ix>> for PersistentGraph<N, E, Ty, Ix>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: Graph<N, E, Ty, Ix>) -> Self {
+        let (nodes, edges) = graph.into_nodes_edges();
+        PersistentGraph {
+            nodes: nodes.into_iter().collect(),
+            edges: edges.into_iter().collect(),
+            ty: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the neighbors of a node.
+///
+/// Iterator element type is `NodeIndex<Ix>`.
+#[derive(Debug)]
+pub struct Neighbors<'a, E: 'a, Ix: 'a = DefaultIx> {
+    /// starting node to skip over
+    skip_start: NodeIndex<Ix>,
+    edges: &'a Vector<Edge<E, Ix>>,
+    next: [EdgeIndex<Ix>; 2],
+}
+
+impl<'a, E, Ix> Iterator for Neighbors<'a, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        // First any outgoing edges
+        if let Some(edge) = self.edges.get(self.next[0].index()) {
+            self.next[0] = edge.next[0];
+            return Some(edge.node[1]);
+        }
+        // Then incoming edges, skipping self loops that were already listed
+        // as outgoing edges
+        while let Some(edge) = self.edges.get(self.next[1].index()) {
+            self.next[1] = edge.next[1];
+            if edge.node[0] != self.skip_start {
+                return Some(edge.node[0]);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, E, Ix> Clone for Neighbors<'a, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn clone(&self) -> Self {
+        Neighbors {
+            skip_start: self.skip_start,
+            edges: self.edges,
+            next: self.next,
+        }
+    }
+}
+
+/// Iterator over the edges of from or to a node
+#[derive(Debug)]
+pub struct Edges<'a, E: 'a, Ty, Ix: 'a = DefaultIx> {
+    /// starting node to skip over
+    skip_start: NodeIndex<Ix>,
+    edges: &'a Vector<Edge<E, Ix>>,
+    /// Next edge to visit.
+    next: [EdgeIndex<Ix>; 2],
+    /// For directed graphs: the direction to iterate in
+    /// For undirected graphs: the direction of edges
+    direction: Direction,
+    ty: PhantomData<Ty>,
+}
+
+impl<'a, E, Ty, Ix> Iterator for Edges<'a, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Item = EdgeReference<'a, E, Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The same walk as for `graph::Edges`: a directed graph follows the
+        // list of `direction`, an undirected graph follows both lists and
+        // turns its edges so that `skip_start` is at the `direction` end.
+        let (iterate_over, reverse) = if Ty::is_directed() {
+            (Some(self.direction), None)
+        } else {
+            (None, Some(self.direction.opposite()))
+        };
+
+        if iterate_over.unwrap_or(Outgoing) == Outgoing {
+            let index = self.next[0];
+            if let Some(Edge { node, weight, next }) = self.edges.get(index.index()) {
+                self.next[0] = next[0];
+                return Some(EdgeReference {
+                    index,
+                    node: if reverse == Some(Outgoing) {
+                        swap_pair(*node)
+                    } else {
+                        *node
+                    },
+                    weight,
+                });
+            }
+        }
+
+        if iterate_over.unwrap_or(Incoming) == Incoming {
+            while let Some(Edge { node, weight, next }) = self.edges.get(self.next[1].index()) {
+                let index = self.next[1];
+                self.next[1] = next[1];
+                // self loops were already listed as outgoing edges
+                if iterate_over.is_none() && node[0] == self.skip_start {
+                    continue;
+                }
+                return Some(EdgeReference {
+                    index,
+                    node: if reverse == Some(Incoming) {
+                        swap_pair(*node)
+                    } else {
+                        *node
+                    },
+                    weight,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, E, Ty, Ix> Clone for Edges<'a, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    fn clone(&self) -> Self {
+        Edges {
+            skip_start: self.skip_start,
+            edges: self.edges,
+            next: self.next,
+            direction: self.direction,
+            ty: PhantomData,
+        }
+    }
+}
+
+/// Iterator over all nodes of a graph.
+#[derive(Debug, Clone)]
+pub struct NodeReferences<'a, N: 'a, Ix: 'a = DefaultIx> {
+    iter: Enumerate<vector::Iter<'a, Node<N, Ix>>>,
+}
+
+impl<'a, N, Ix> Iterator for NodeReferences<'a, N, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = (NodeIndex<Ix>, &'a N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(i, node)| (NodeIndex::new(i), &node.weight))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, N, Ix> ExactSizeIterator for NodeReferences<'a, N, Ix> where Ix: IndexType {}
+
+/// Iterator over all edges of a graph.
+#[derive(Debug, Clone)]
+pub struct EdgeReferences<'a, E: 'a, Ix: 'a = DefaultIx> {
+    iter: Enumerate<vector::Iter<'a, Edge<E, Ix>>>,
+}
+
+impl<'a, E, Ix> Iterator for EdgeReferences<'a, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = EdgeReference<'a, E, Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, edge)| EdgeReference {
+            index: EdgeIndex::new(i),
+            node: edge.node,
+            weight: &edge.weight,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, E, Ix> ExactSizeIterator for EdgeReferences<'a, E, Ix> where Ix: IndexType {}
+
+/// Index the `PersistentGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, Ty, Ix> Index<NodeIndex<Ix>> for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        &self.nodes.get(index.index()).unwrap().weight
+    }
+}
+
+/// Index the `PersistentGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ty, Ix> Index<EdgeIndex<Ix>> for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.edges.get(index.index()).unwrap().weight
+    }
+}
+
+impl<N, E, Ty, Ix> GraphBase for PersistentGraph<N, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = EdgeIndex<Ix>;
+}
+
+impl<N, E, Ty, Ix> GraphProp for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeType = Ty;
+}
+
+impl<N, E, Ty, Ix> Data for PersistentGraph<N, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, Ty, Ix> DataMap for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_weight(&self, a: Self::NodeId) -> Option<&N> {
+        self.node_weight(a)
+    }
+    fn edge_weight(&self, e: Self::EdgeId) -> Option<&E> {
+        self.edge_weight(e)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeCount for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        self.node_count()
+    }
+}
+
+impl<N, E, Ty, Ix> EdgeCount for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+impl<N, E, Ty, Ix> NodeIndexable for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn node_bound(&self) -> usize {
+        self.node_count()
+    }
+    #[inline]
+    fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    #[inline]
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        NodeIndex::new(ix)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeCompactIndexable for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+
+impl<N, E, Ty, Ix> EdgeIndexable for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn edge_bound(&self) -> usize {
+        self.edge_count()
+    }
+    fn to_index(&self, ix: EdgeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    fn from_index(&self, ix: usize) -> Self::EdgeId {
+        EdgeIndex::new(ix)
+    }
+}
+
+impl<N, E, Ty, Ix> Visitable for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Map = FixedBitSet;
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.node_count())
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+        map.grow(self.node_count());
+    }
+}
+
+impl<N, E, Ty, Ix> IntoNodeIdentifiers for &PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeIdentifiers = NodeIndices<Ix>;
+    fn node_identifiers(self) -> NodeIndices<Ix> {
+        self.node_indices()
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNodeReferences for &'a PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = NodeReferences<'a, N, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences {
+            iter: self.nodes.iter().enumerate(),
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdgeReferences for &'a PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeRef = EdgeReference<'a, E, Ix>;
+    type EdgeReferences = EdgeReferences<'a, E, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        EdgeReferences {
+            iter: self.edges.iter().enumerate(),
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNeighbors for &'a PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Neighbors = Neighbors<'a, E, Ix>;
+    fn neighbors(self, a: NodeIndex<Ix>) -> Self::Neighbors {
+        PersistentGraph::neighbors(self, a)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNeighborsDirected for &'a PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NeighborsDirected = Neighbors<'a, E, Ix>;
+    fn neighbors_directed(self, a: NodeIndex<Ix>, dir: Direction) -> Self::NeighborsDirected {
+        PersistentGraph::neighbors_directed(self, a, dir)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdges for &'a PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Edges = Edges<'a, E, Ty, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        PersistentGraph::edges(self, a)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdgesDirected for &'a PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgesDirected = Edges<'a, E, Ty, Ix>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        PersistentGraph::edges_directed(self, a, dir)
+    }
+}
+
+/// The adjacency matrix for **PersistentGraph** is a bitmap that's computed by
+/// `.adjacency_matrix()`.
+impl<N, E, Ty, Ix> GetAdjacencyMatrix for PersistentGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type AdjMatrix = FixedBitSet;
+
+    fn adjacency_matrix(&self) -> FixedBitSet {
+        let n = self.node_count();
+        let mut matrix = FixedBitSet::with_capacity(n * n);
+        for edge in self.edges.iter() {
+            let [a, b] = edge.node;
+            matrix.put(a.index() * n + b.index());
+            if !self.is_directed() {
+                matrix.put(b.index() * n + a.index());
+            }
+        }
+        matrix
+    }
+
+    fn is_adjacent(&self, matrix: &FixedBitSet, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        let n = self.node_count();
+        matrix.contains(a.index() * n + b.index())
+    }
+}
@@ -0,0 +1,254 @@
+//! A persistent vector, whose clones share their structure.
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::mem;
+use std::slice;
+use std::sync::Arc;
+
+const BITS: usize = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+/// A node of the trie of a `Vector`.
+#[derive(Clone)]
+enum Chunk<T> {
+    /// Up to `WIDTH` subtries, all of them full except the last.
+    Branch(Vec<Arc<Chunk<T>>>),
+    /// Up to `WIDTH` elements.
+    Leaf(Vec<T>),
+}
+
+/// `Vector<T>` is a vector stored as a trie of `WIDTH`-wide chunks behind
+/// reference counted pointers.
+///
+/// Cloning a vector only clones the pointer to its root, and changing an
+/// element or pushing one copies the chunks on the path to it that are
+/// shared with other clones, so **O(log n)** of them. Chunks that aren't
+/// shared are changed in place.
+pub struct Vector<T> {
+    len: usize,
+    /// The number of index bits below the root.
+    shift: usize,
+    root: Arc<Chunk<T>>,
+}
+
+impl<T> Clone for Vector<T> {
+    fn clone(&self) -> Self {
+        Vector {
+            len: self.len,
+            shift: self.shift,
+            root: self.root.clone(),
+        }
+    }
+}
+
+impl<T> Default for Vector<T> {
+    fn default() -> Self {
+        Vector {
+            len: 0,
+            shift: 0,
+            root: Arc::new(Chunk::Leaf(Vec::new())),
+        }
+    }
+}
+
+impl<T> Vector<T> {
+    /// Create a new empty `Vector`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the leaf with the element at index `i`, which must be in
+    /// bounds.
+    fn leaf(&self, i: usize) -> &[T] {
+        let mut chunk = &*self.root;
+        let mut shift = self.shift;
+        loop {
+            match chunk {
+                Chunk::Branch(children) => {
+                    chunk = &children[(i >> shift) & MASK];
+                    shift -= BITS;
+                }
+                Chunk::Leaf(elements) => return elements,
+            }
+        }
+    }
+
+    /// Return a reference to the element at index `i`, or `None` if it is
+    /// out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i < self.len {
+            Some(&self.leaf(i)[i & MASK])
+        } else {
+            None
+        }
+    }
+
+    /// Return an iterator of the elements.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            vector: self,
+            index: 0,
+            leaf: [].iter(),
+        }
+    }
+}
+
+impl<T: Clone> Vector<T> {
+    /// Return a mutable reference to the element at index `i`, or `None` if
+    /// it is out of bounds.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        if i >= self.len {
+            return None;
+        }
+        let mut chunk = Arc::make_mut(&mut self.root);
+        let mut shift = self.shift;
+        loop {
+            match chunk {
+                Chunk::Branch(children) => {
+                    chunk = Arc::make_mut(&mut children[(i >> shift) & MASK]);
+                    shift -= BITS;
+                }
+                Chunk::Leaf(elements) => return Some(&mut elements[i & MASK]),
+            }
+        }
+    }
+
+    /// Add `value` at the end.
+    pub fn push(&mut self, value: T) {
+        if self.len == 1 << (self.shift + BITS) {
+            let root = mem::replace(&mut self.root, Arc::new(Chunk::Branch(Vec::new())));
+            self.root = Arc::new(Chunk::Branch(vec![root]));
+            self.shift += BITS;
+        }
+        let i = self.len;
+        let mut chunk = Arc::make_mut(&mut self.root);
+        let mut shift = self.shift;
+        loop {
+            match chunk {
+                Chunk::Branch(children) => {
+                    let c = (i >> shift) & MASK;
+                    if c == children.len() {
+                        children.push(Arc::new(if shift == BITS {
+                            Chunk::Leaf(Vec::with_capacity(WIDTH))
+                        } else {
+                            Chunk::Branch(Vec::new())
+                        }));
+                    }
+                    chunk = Arc::make_mut(&mut children[c]);
+                    shift -= BITS;
+                }
+                Chunk::Leaf(elements) => {
+                    elements.push(value);
+                    break;
+                }
+            }
+        }
+        self.len += 1;
+    }
+}
+
+impl<T: Clone> FromIterator<T> for Vector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector = Vector::new();
+        for value in iter {
+            vector.push(value);
+        }
+        vector
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Vector<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over the elements of a `Vector`.
+pub struct Iter<'a, T: 'a> {
+    vector: &'a Vector<T>,
+    /// The index of the first element after the current leaf.
+    index: usize,
+    leaf: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            vector: self.vector,
+            index: self.index,
+            leaf: self.leaf.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if let Some(value) = self.leaf.next() {
+            return Some(value);
+        }
+        if self.index >= self.vector.len {
+            return None;
+        }
+        let leaf = self.vector.leaf(self.index);
+        let end = (self.vector.len - self.index).min(leaf.len());
+        self.leaf = leaf[..end].iter();
+        self.index += end;
+        self.leaf.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.leaf.len() + self.vector.len - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> fmt::Debug for Iter<'a, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Vector, WIDTH};
+
+    #[test]
+    fn push_get() {
+        let n = WIDTH * WIDTH * 3 + 7;
+        let v: Vector<usize> = (0..n).collect();
+        assert_eq!(v.len(), n);
+        for i in 0..n {
+            assert_eq!(v.get(i), Some(&i));
+        }
+        assert_eq!(v.get(n), None);
+        assert!(v.iter().cloned().eq(0..n));
+        assert_eq!(v.iter().len(), n);
+    }
+
+    #[test]
+    fn versions() {
+        let mut v: Vector<usize> = (0..100).collect();
+        let w = v.clone();
+        *v.get_mut(40).unwrap() = 1000;
+        v.push(100);
+        assert_eq!(v.get(40), Some(&1000));
+        assert_eq!(v.get(100), Some(&100));
+        assert_eq!(w.get(40), Some(&40));
+        assert_eq!(w.get(100), None);
+        assert!(w.iter().cloned().eq(0..100));
+    }
+}
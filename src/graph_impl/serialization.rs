@@ -329,6 +329,7 @@ where
         let mut gr = Graph {
             nodes: nodes,
             edges: edges,
+            endpoints: None,
             ty: ty,
         };
         let nc = gr.node_count();
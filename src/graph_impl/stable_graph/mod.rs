@@ -19,7 +19,7 @@ use crate::{Directed, Direction, EdgeType, Graph, Incoming, Outgoing, Undirected
 use crate::iter_format::{DebugMap, IterFormatExt, NoPretty};
 use crate::iter_utils::IterUtilsExt;
 
-use super::{index_twice, Edge, Frozen, Node, Pair, DIRECTIONS};
+use super::{index_available, index_twice, Edge, EndpointIndex, Frozen, Node, Pair, DIRECTIONS};
 use crate::visit;
 use crate::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences, NodeIndexable};
 use crate::IntoWeightedEdge;
@@ -27,7 +27,8 @@ use crate::IntoWeightedEdge;
 // reexport those things that are shared with Graph
 #[doc(no_inline)]
 pub use crate::graph::{
-    edge_index, node_index, DefaultIx, EdgeIndex, GraphIndex, IndexType, NodeIndex,
+    edge_index, node_index, DefaultIx, EdgeIndex, GraphError, GraphIndex, IndexType, MemoryUsage,
+    NodeIndex,
 };
 
 use crate::util::enumerate;
@@ -68,6 +69,10 @@ mod serialization;
 ///
 /// - Indices don't allow as much compile time checking as references.
 ///
+/// - With crate feature `serde-1`, the graph implements `Serialize` and
+/// `Deserialize`, and the vacancies are part of the serialized form, so a
+/// round trip keeps every node and edge index.
+///
 /// Depends on crate feature `stable_graph` (default). *Stable Graph is still
 /// missing a few methods compared to Graph. You can contribute to help it
 /// achieve parity.*
@@ -197,6 +202,92 @@ where
         self.g.capacity()
     }
 
+    /// Remove the vacant node and edge entries at the end of the graph and
+    /// shrink the capacity of the graph as much as possible.
+    ///
+    /// The indices of the nodes and edges in the graph stay valid, but the
+    /// order in which vacant indices are reused may change.
+    ///
+    /// Computes in **O(|V| + |E|)** time, where **|V|** and **|E|** include
+    /// the vacant entries.
+    ///
+    /// ```
+    /// use petgraph::stable_graph::StableGraph;
+    ///
+    /// let mut g = StableGraph::<u32, ()>::new();
+    /// let nodes: Vec<_> = (0..100).map(|i| g.add_node(i)).collect();
+    /// for &a in &nodes[1..] {
+    ///     g.remove_node(a);
+    /// }
+    /// let before = g.memory_usage();
+    /// assert!(before.vacant > 0);
+    ///
+    /// g.shrink_to_fit();
+    /// let after = g.memory_usage();
+    /// assert_eq!((after.vacant, after.unused), (0, 0));
+    /// assert!(after.total() < before.total());
+    /// assert_eq!(g[nodes[0]], 0);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        while self
+            .g
+            .nodes
+            .last()
+            .map_or(false, |node| node.weight.is_none())
+        {
+            self.g.nodes.pop();
+        }
+        while self
+            .g
+            .edges
+            .last()
+            .map_or(false, |edge| edge.weight.is_none())
+        {
+            self.g.edges.pop();
+        }
+        self.relink_free_lists();
+        self.g.shrink_to_fit();
+        self.check_free_lists();
+    }
+
+    /// Return an estimate of the heap memory held by the graph.
+    ///
+    /// Vacant node and edge entries, left by removals, are counted apart from
+    /// the nodes and edges in the graph.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let mut usage = self.g.memory_usage();
+        let vacant_nodes =
+            (self.g.nodes.len() - self.node_count) * size_of::<Node<Option<N>, Ix>>();
+        let vacant_edges =
+            (self.g.edges.len() - self.edge_count) * size_of::<Edge<Option<E>, Ix>>();
+        usage.nodes -= vacant_nodes;
+        usage.edges -= vacant_edges;
+        usage.vacant = vacant_nodes + vacant_edges;
+        usage
+    }
+
+    /// Reverse the direction of all edges
+    pub fn reverse(&mut self) {
+        // swap edge endpoints,
+        // edge incoming / outgoing lists,
+        // node incoming / outgoing lists
+        // (not touching the free lists of vacant entries)
+        for edge in &mut self.g.edges {
+            if edge.weight.is_some() {
+                edge.node.swap(0, 1);
+                edge.next.swap(0, 1);
+            }
+        }
+        for node in &mut self.g.nodes {
+            if node.weight.is_some() {
+                node.next.swap(0, 1);
+            }
+        }
+        if self.g.endpoints.take().is_some() {
+            self.enable_endpoint_index();
+        }
+    }
+
     /// Remove all nodes and edges
     pub fn clear(&mut self) {
         self.node_count = 0;
@@ -211,6 +302,9 @@ where
         self.edge_count = 0;
         self.free_edge = EdgeIndex::end();
         self.g.edges.clear();
+        if let Some(endpoints) = &mut self.g.endpoints {
+            endpoints.clear();
+        }
         // clear edges without touching the free list
         for node in &mut self.g.nodes {
             if node.weight.is_some() {
@@ -258,6 +352,22 @@ where
         }
     }
 
+    /// Add a node (also called vertex) with associated data `weight` to the
+    /// graph, or return an error if the graph is at the maximum number of
+    /// nodes for its index type.
+    ///
+    /// A vacant node index is reused if there is any, so the limit is only
+    /// reached when every index is in use.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn try_add_node(&mut self, weight: N) -> Result<NodeIndex<Ix>, GraphError> {
+        if self.free_node != NodeIndex::end() || index_available::<Ix>(self.g.nodes.len()) {
+            Ok(self.add_node(weight))
+        } else {
+            Err(GraphError::NodeIxLimit)
+        }
+    }
+
     /// free_node: Which free list to update for the vacancy
     fn add_vacant_node(&mut self, free_node: &mut NodeIndex<Ix>) {
         let node_idx = self.g.add_node(None);
@@ -395,9 +505,47 @@ where
         if let Some(edge) = new_edge {
             self.g.edges.push(edge);
         }
+        if let Some(endpoints) = &mut self.g.endpoints {
+            endpoints.insert([a, b], edge_idx);
+        }
         edge_idx
     }
 
+    /// Add an edge from `a` to `b` to the graph, with its associated
+    /// data `weight`, or return an error if either node doesn't exist or
+    /// the graph is at the maximum number of edges for its index type.
+    ///
+    /// Return the index of the new edge.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// ```
+    /// use petgraph::stable_graph::{GraphError, StableGraph};
+    ///
+    /// let mut g = StableGraph::<(), ()>::new();
+    /// let a = g.add_node(());
+    /// let b = g.add_node(());
+    /// g.remove_node(b);
+    /// assert!(g.try_add_edge(a, a, ()).is_ok());
+    /// assert_eq!(g.try_add_edge(a, b, ()), Err(GraphError::NodeMissed(1)));
+    /// ```
+    pub fn try_add_edge(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+        weight: E,
+    ) -> Result<EdgeIndex<Ix>, GraphError> {
+        for &n in &[a, b] {
+            if !self.contains_node(n) {
+                return Err(GraphError::NodeMissed(n.index()));
+            }
+        }
+        if self.free_edge == EdgeIndex::end() && !index_available::<Ix>(self.g.edges.len()) {
+            return Err(GraphError::EdgeIxLimit);
+        }
+        Ok(self.add_edge(a, b, weight))
+    }
+
     /// free_edge: Which free list to update for the vacancy
     fn add_vacant_edge(&mut self, free_edge: &mut EdgeIndex<Ix>) {
         let edge_idx = EdgeIndex::new(self.g.edges.len());
@@ -450,6 +598,12 @@ where
         // Remove the edge from its in and out lists by replacing it with
         // a link to the next in the list.
         self.g.change_edge_links(edge_node, e, edge_next);
+        if self.g.endpoints.is_some() {
+            let other = self.find_edge_linear(edge_node[0], edge_node[1]);
+            if let Some(endpoints) = &mut self.g.endpoints {
+                endpoints.remove(edge_node, e, other);
+            }
+        }
 
         // Clear the edge and put it in the free list
         let edge = &mut self.g.edges[e.index()];
@@ -460,6 +614,74 @@ where
         edge.weight.take()
     }
 
+    /// Subdivide the edge `e` with a new node of weight `weight`: replace the
+    /// edge from `a` to `b` by an edge from `a` to the new node and one from
+    /// the new node to `b`, both with the weight of `e`.
+    ///
+    /// Return the index of the new node, or `None` if the edge doesn't exist.
+    ///
+    /// Invalidates the edge index `e` but no other.
+    pub fn subdivide_edge(&mut self, e: EdgeIndex<Ix>, weight: N) -> Option<NodeIndex<Ix>>
+    where
+        E: Clone,
+    {
+        let (a, b) = self.edge_endpoints(e)?;
+        let edge_weight = self.remove_edge(e)?;
+        let x = self.add_node(weight);
+        self.add_edge(a, x, edge_weight.clone());
+        self.add_edge(x, b, edge_weight);
+        Some(x)
+    }
+
+    /// Contract the edge `e` from `a` to `b`: remove it, and merge `b` into
+    /// `a` by moving the other edges of `b` to `a`, and its weight with
+    /// `merge(&mut weight_of_a, weight_of_b)`.
+    ///
+    /// Other edges between `a` and `b` become self loops of `a`, and edges
+    /// from `a` and `b` to the same node become parallel edges; they can be
+    /// removed with `.retain_edges()`. Contracting a self loop just removes
+    /// it.
+    ///
+    /// Return `a`, or `None` if the edge doesn't exist.
+    ///
+    /// Invalidates the node index `b` and the indices of the edges of `b`.
+    ///
+    /// Computes in **O(e')** time, where **e'** is the number of edges of
+    /// `a` and `b`.
+    pub fn contract_edge<F>(&mut self, e: EdgeIndex<Ix>, merge: F) -> Option<NodeIndex<Ix>>
+    where
+        F: FnOnce(&mut N, N),
+    {
+        let (a, b) = self.edge_endpoints(e)?;
+        if a == b {
+            self.remove_edge(e);
+            return Some(a);
+        }
+        let mut incident = self
+            .edges_directed(b, Outgoing)
+            .chain(self.edges_directed(b, Incoming))
+            .map(|edge| edge.id())
+            .collect::<Vec<_>>();
+        incident.sort();
+        incident.dedup();
+        let mut moved = Vec::with_capacity(incident.len());
+        for f in incident {
+            let (x, y) = self.edge_endpoints(f).unwrap();
+            let weight = self.remove_edge(f).unwrap();
+            if f != e {
+                moved.push((x, y, weight));
+            }
+        }
+
+        let weight = self.remove_node(b).unwrap();
+        merge(&mut self[a], weight);
+        let renumber = |x| if x == b { a } else { x };
+        for (x, y, weight) in moved {
+            self.add_edge(renumber(x), renumber(y), weight);
+        }
+        Some(a)
+    }
+
     /// Access the weight for node `a`.
     ///
     /// Also available with indexing syntax: `&graph[a]`.
@@ -526,6 +748,28 @@ where
         }
     }
 
+    /// Access the weight for an edge from `a` to `b`, as found by
+    /// [`find_edge`](#method.find_edge).
+    ///
+    /// Also available with indexing syntax: `&graph[(a, b)]`.
+    pub fn edge_weight_between(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<&E> {
+        let e = self.find_edge(a, b)?;
+        self.edge_weight(e)
+    }
+
+    /// Access the weight for an edge from `a` to `b`, as found by
+    /// [`find_edge`](#method.find_edge), mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[(a, b)]`.
+    pub fn edge_weight_between_mut(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+    ) -> Option<&mut E> {
+        let e = self.find_edge(a, b)?;
+        self.edge_weight_mut(e)
+    }
+
     /// Return an iterator yielding immutable access to all edge weights.
     ///
     /// The order in which weights are yielded matches the order of their edge
@@ -563,7 +807,9 @@ where
     /// Lookup if there is an edge from `a` to `b`.
     ///
     /// Computes in **O(e')** time, where **e'** is the number of edges
-    /// connected to `a` (and `b`, if the graph edges are undirected).
+    /// connected to `a` (and `b`, if the graph edges are undirected), or in
+    /// **O(1)** time if the [endpoint index](#method.enable_endpoint_index)
+    /// is enabled.
     pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
         self.find_edge(a, b).is_some()
     }
@@ -571,10 +817,22 @@ where
     /// Lookup an edge from `a` to `b`.
     ///
     /// Computes in **O(e')** time, where **e'** is the number of edges
-    /// connected to `a` (and `b`, if the graph edges are undirected).
+    /// connected to `a` (and `b`, if the graph edges are undirected), or in
+    /// **O(1)** time if the [endpoint index](#method.enable_endpoint_index)
+    /// is enabled.
     pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        match &self.g.endpoints {
+            Some(endpoints) => endpoints.get(a, b),
+            None => self.find_edge_linear(a, b),
+        }
+    }
+
+    /// Lookup an edge from `a` to `b` in the edge lists of `a`.
+    fn find_edge_linear(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
         if !self.is_directed() {
-            self.find_edge_undirected(a, b).map(|(ix, _)| ix)
+            self.get_node(a)
+                .and_then(|node| self.g.find_edge_undirected_from_node(node, b))
+                .map(|(ix, _)| ix)
         } else {
             match self.get_node(a) {
                 None => None,
@@ -595,12 +853,52 @@ where
         a: NodeIndex<Ix>,
         b: NodeIndex<Ix>,
     ) -> Option<(EdgeIndex<Ix>, Direction)> {
+        if let Some(endpoints) = &self.g.endpoints {
+            return endpoints.get_undirected(&self.g.edges, a, b);
+        }
         match self.get_node(a) {
             None => None,
             Some(node) => self.g.find_edge_undirected_from_node(node, b),
         }
     }
 
+    /// Enable the endpoint index of the graph: a hash map from the endpoints
+    /// of each edge to its index, that is kept up to date by every change to
+    /// the graph.
+    ///
+    /// It makes [`find_edge`](#method.find_edge),
+    /// [`contains_edge`](#method.contains_edge) and
+    /// [`find_edge_undirected`](#method.find_edge_undirected) compute in
+    /// **O(1)** time, like
+    /// [`Graph::enable_endpoint_index`](../graph/struct.Graph.html#method.enable_endpoint_index).
+    ///
+    /// Does nothing if the index is already enabled.
+    ///
+    /// Computes in **O(|E|)** time.
+    pub fn enable_endpoint_index(&mut self) {
+        if self.g.endpoints.is_none() {
+            self.g.endpoints = Some(EndpointIndex::new(
+                self.is_directed(),
+                self.g
+                    .edges
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, edge)| edge.weight.is_some())
+                    .map(|(i, edge)| (EdgeIndex::new(i), edge.node)),
+            ));
+        }
+    }
+
+    /// Disable the endpoint index of the graph, and free its memory.
+    pub fn disable_endpoint_index(&mut self) {
+        self.g.endpoints = None;
+    }
+
+    /// Return `true` if the endpoint index of the graph is enabled.
+    pub fn has_endpoint_index(&self) -> bool {
+        self.g.endpoints.is_some()
+    }
+
     /// Return an iterator of all nodes with an edge starting from `a`.
     ///
     /// - `Directed`: Outgoing edges from `a`.
@@ -802,6 +1100,107 @@ where
         self.check_free_lists();
     }
 
+    /// Remove all nodes that return `true` from the `filter` closure, along
+    /// with their edges, and return their weights in the order of their
+    /// indices.
+    ///
+    /// The edges of the removed nodes are dropped; use `.drain_edges()`
+    /// first to keep their weights.
+    ///
+    /// The node indices of the removed nodes are invalidated, but none other.
+    /// Edge indices are invalidated as they would be following the removal of
+    /// each edge with an endpoint in a removed node.
+    ///
+    /// ```
+    /// use petgraph::stable_graph::StableGraph;
+    ///
+    /// let mut g = StableGraph::<i32, ()>::new();
+    /// let nodes: Vec<_> = (0..4).map(|i| g.add_node(i)).collect();
+    /// g.add_edge(nodes[0], nodes[1], ());
+    /// let odd = g.drain_nodes(|_, &weight| weight % 2 == 1);
+    /// assert_eq!(odd, vec![1, 3]);
+    /// assert_eq!(g[nodes[2]], 2);
+    /// assert_eq!(g.edge_count(), 0);
+    /// ```
+    pub fn drain_nodes<F>(&mut self, mut filter: F) -> Vec<N>
+    where
+        F: FnMut(NodeIndex<Ix>, &N) -> bool,
+    {
+        let mut drained = Vec::new();
+        for i in 0..self.node_bound() {
+            let ix = node_index(i);
+            if let Some(weight) = self.node_weight(ix) {
+                if filter(ix, weight) {
+                    drained.extend(self.remove_node(ix));
+                }
+            }
+        }
+        self.check_free_lists();
+        drained
+    }
+
+    /// Remove all edges that return `true` from the `filter` closure, and
+    /// return their source, target and weight in the order of their indices.
+    ///
+    /// The edge indices of the removed edges are invalidated, but none other.
+    pub fn drain_edges<F>(&mut self, mut filter: F) -> Vec<(NodeIndex<Ix>, NodeIndex<Ix>, E)>
+    where
+        F: FnMut(EdgeReference<E, Ix>) -> bool,
+    {
+        let mut drained = Vec::new();
+        for i in 0..self.edge_bound() {
+            let index = edge_index(i);
+            let edge = &self.g.edges[i];
+            let node = edge.node;
+            let drain = match &edge.weight {
+                Some(weight) => filter(EdgeReference {
+                    index,
+                    node,
+                    weight,
+                }),
+                None => false,
+            };
+            if drain {
+                if let Some(weight) = self.remove_edge(index) {
+                    drained.push((node[0], node[1], weight));
+                }
+            }
+        }
+        self.check_free_lists();
+        drained
+    }
+
+    /// Create a new `StableGraph` from parallel arrays of the sources, targets
+    /// and weights of its edges, where edge `i` goes from `sources[i]` to
+    /// `targets[i]` with weight `weights[i]`.
+    ///
+    /// See [`Graph::from_parallel_arrays`](../graph/struct.Graph.html#method.from_parallel_arrays);
+    /// the resulting graph has no vacancies.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    ///
+    /// **Panics** if the arrays have different lengths, or if the graph would
+    /// exceed the maximum number of nodes or edges for its index type.
+    pub fn from_parallel_arrays<A>(sources: &[A], targets: &[A], weights: Vec<E>) -> Self
+    where
+        A: Copy + Into<NodeIndex<Ix>>,
+        N: Default,
+    {
+        let g = Graph::from_parallel_arrays_with(
+            sources,
+            targets,
+            weights.into_iter().map(Some),
+            || Some(N::default()),
+        );
+        StableGraph {
+            node_count: g.node_count(),
+            edge_count: g.edge_count(),
+            g,
+            free_node: NodeIndex::end(),
+            free_edge: EdgeIndex::end(),
+        }
+    }
+
     /// Create a new `StableGraph` from an iterable of edges.
     ///
     /// Node weights `N` are set to default values.
@@ -947,6 +1346,58 @@ where
         }
     }
 
+    /// Convert the graph into a `Graph` without vacancies, and return it with
+    /// the translation from the old indices to the new ones.
+    ///
+    /// The node map has an entry for each old node index up to the node
+    /// bound, holding the new index of the node, or `NodeIndex::end()` for a
+    /// vacancy; the edge map is the same for the edges. Nodes and edges keep
+    /// their relative order.
+    ///
+    /// Computes in **O(|V| + |E|)** time.
+    ///
+    /// ```
+    /// use petgraph::stable_graph::{NodeIndex, StableGraph};
+    ///
+    /// let mut sg = StableGraph::<&str, ()>::new();
+    /// let a = sg.add_node("a");
+    /// let b = sg.add_node("b");
+    /// let c = sg.add_node("c");
+    /// sg.add_edge(a, c, ());
+    /// sg.remove_node(b);
+    ///
+    /// let (g, node_map, edge_map) = sg.into_graph_with_map();
+    /// assert_eq!(node_map, vec![a, NodeIndex::end(), b]);
+    /// assert_eq!(g[node_map[c.index()]], "c");
+    /// assert_eq!(g.edge_endpoints(edge_map[0]), Some((a, b)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn into_graph_with_map(
+        self,
+    ) -> (Graph<N, E, Ty, Ix>, Vec<NodeIndex<Ix>>, Vec<EdgeIndex<Ix>>) {
+        let mut result_g = Graph::with_capacity(self.node_count(), self.edge_count());
+        let mut node_index_map = vec![NodeIndex::end(); self.node_bound()];
+        let mut edge_index_map = vec![EdgeIndex::end(); self.edge_bound()];
+
+        for (i, node) in enumerate(self.g.nodes) {
+            if let Some(nw) = node.weight {
+                node_index_map[i] = result_g.add_node(nw);
+            }
+        }
+        for (i, edge) in enumerate(self.g.edges) {
+            let source_index = edge.source().index();
+            let target_index = edge.target().index();
+            if let Some(ew) = edge.weight {
+                let source = node_index_map[source_index];
+                let target = node_index_map[target_index];
+                debug_assert!(source != NodeIndex::end());
+                debug_assert!(target != NodeIndex::end());
+                edge_index_map[i] = result_g.add_edge(source, target, ew);
+            }
+        }
+        (result_g, node_index_map, edge_index_map)
+    }
+
     //
     // internal methods
     //
@@ -979,6 +1430,65 @@ where
         self.node_count += 1;
     }
 
+    /// Link all vacant nodes and edges into new free lists.
+    fn relink_free_lists(&mut self) {
+        let mut free_node = NodeIndex::end();
+        for node_index in 0..self.g.nodes.len() {
+            let node = &mut self.g.nodes[node_index];
+            if node.weight.is_none() {
+                node.next = [free_node._into_edge(), EdgeIndex::end()];
+                if free_node != NodeIndex::end() {
+                    self.g.nodes[free_node.index()].next[1] = EdgeIndex::new(node_index);
+                }
+                free_node = NodeIndex::new(node_index);
+            }
+        }
+        self.free_node = free_node;
+
+        let mut free_edge = EdgeIndex::end();
+        for (edge_index, edge) in enumerate(&mut self.g.edges) {
+            if edge.weight.is_none() {
+                edge.next = [free_edge, EdgeIndex::end()];
+                free_edge = EdgeIndex::new(edge_index);
+            }
+        }
+        self.free_edge = free_edge;
+    }
+
+    /// Create a graph without edges, with the nodes `nodes` by index, where
+    /// `None` is a vacancy.
+    pub(crate) fn from_vacant_nodes(nodes: Vec<Option<N>>) -> Self {
+        let mut graph = StableGraph::with_capacity(nodes.len(), 0);
+        for weight in nodes {
+            graph.node_count += weight.is_some() as usize;
+            graph.g.nodes.push(Node {
+                weight,
+                next: [EdgeIndex::end(); 2],
+            });
+        }
+        graph.relink_free_lists();
+        graph
+    }
+
+    /// Return the nodes by index, where `None` is a vacancy, and the edges
+    /// with their endpoints.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn into_vacant_nodes_edges(
+        self,
+    ) -> (Vec<Option<N>>, Vec<(NodeIndex<Ix>, NodeIndex<Ix>, E)>) {
+        let nodes = self.g.nodes.into_iter().map(|node| node.weight).collect();
+        let edges = self
+            .g
+            .edges
+            .into_iter()
+            .filter_map(|edge| {
+                let (a, b) = (edge.source(), edge.target());
+                edge.weight.map(|weight| (a, b, weight))
+            })
+            .collect();
+        (nodes, edges)
+    }
+
     /// Create the node if it does not exist,
     /// adding vacant nodes for padding if needed.
     fn ensure_node_exists(&mut self, node_ix: NodeIndex<Ix>)
@@ -1177,6 +1687,62 @@ where
     }
 }
 
+/// Index the `StableGraph` by a pair of nodes to access the weight of an edge from
+/// the first to the second.
+///
+/// **Panics** if there is no such edge.
+impl<N, E, Ty, Ix> Index<(NodeIndex<Ix>, NodeIndex<Ix>)> for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, (a, b): (NodeIndex<Ix>, NodeIndex<Ix>)) -> &E {
+        self.edge_weight_between(a, b)
+            .expect("StableGraph::index: no such edge")
+    }
+}
+
+/// Index the `StableGraph` by a pair of nodes to access the weight of an edge from
+/// the first to the second, mutably.
+///
+/// **Panics** if there is no such edge.
+impl<N, E, Ty, Ix> IndexMut<(NodeIndex<Ix>, NodeIndex<Ix>)> for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, (a, b): (NodeIndex<Ix>, NodeIndex<Ix>)) -> &mut E {
+        self.edge_weight_between_mut(a, b)
+            .expect("StableGraph::index: no such edge")
+    }
+}
+
+/// Graphs are equal if they have nodes with equal weights at the same indices
+/// and edges with equal weights and endpoints at the same indices, as
+/// compared by [`graph_eq`](../algo/fn.graph_eq.html). Vacant indices are
+/// not compared.
+impl<N, E, Ty, Ix> PartialEq for StableGraph<N, E, Ty, Ix>
+where
+    N: PartialEq,
+    E: PartialEq,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn eq(&self, other: &Self) -> bool {
+        crate::algo::graph_eq(self, other)
+    }
+}
+
+impl<N, E, Ty, Ix> Eq for StableGraph<N, E, Ty, Ix>
+where
+    N: Eq,
+    E: Eq,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+
 /// Create a new empty `StableGraph`.
 impl<N, E, Ty, Ix> Default for StableGraph<N, E, Ty, Ix>
 where
@@ -1215,6 +1781,7 @@ where
             g: Graph {
                 edges: edges.collect(),
                 nodes: nodes.collect(),
+                endpoints: g.endpoints,
                 ty: g.ty,
             },
             free_node: NodeIndex::end(),
@@ -1232,34 +1799,16 @@ where
 ///
 /// Only if the stable graph had no vacancies after deletions (if node bound was
 /// equal to node count, and the same for edges), would the resulting graph have
-/// the same node and edge indices as the input.
+/// the same node and edge indices as the input. Use
+/// [`into_graph_with_map`](struct.StableGraph.html#method.into_graph_with_map)
+/// to also get the translation of the indices.
 impl<N, E, Ty, Ix> From<StableGraph<N, E, Ty, Ix>> for Graph<N, E, Ty, Ix>
 where
     Ty: EdgeType,
     Ix: IndexType,
 {
     fn from(graph: StableGraph<N, E, Ty, Ix>) -> Self {
-        let mut result_g = Graph::with_capacity(graph.node_count(), graph.edge_count());
-        // mapping from old node index to new node index
-        let mut node_index_map = vec![NodeIndex::end(); graph.node_bound()];
-
-        for (i, node) in enumerate(graph.g.nodes) {
-            if let Some(nw) = node.weight {
-                node_index_map[i] = result_g.add_node(nw);
-            }
-        }
-        for edge in graph.g.edges {
-            let source_index = edge.source().index();
-            let target_index = edge.target().index();
-            if let Some(ew) = edge.weight {
-                let source = node_index_map[source_index];
-                let target = node_index_map[target_index];
-                debug_assert!(source != NodeIndex::end());
-                debug_assert!(target != NodeIndex::end());
-                result_g.add_edge(source, target, ew);
-            }
-        }
-        result_g
+        graph.into_graph_with_map().0
     }
 }
 
@@ -1908,6 +2457,76 @@ where
     }
 }
 
+/// Parallel iterators, with [`rayon`](https://crates.io/crates/rayon). The
+/// vacant places of removed nodes and edges are skipped.
+///
+/// Requires crate feature `"rayon"`.
+#[cfg(feature = "rayon")]
+impl<N, E, Ty, Ix> StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType + Send + Sync,
+{
+    /// Return a parallel iterator over the node indices and weights of the
+    /// graph.
+    pub fn par_node_references(
+        &self,
+    ) -> impl rayon::iter::ParallelIterator<Item = (NodeIndex<Ix>, &N)>
+    where
+        N: Sync,
+    {
+        use rayon::prelude::*;
+        self.g
+            .nodes
+            .par_iter()
+            .enumerate()
+            .filter_map(|(i, node)| node.weight.as_ref().map(|w| (node_index(i), w)))
+    }
+
+    /// Return a parallel iterator over all edges.
+    pub fn par_edge_references(
+        &self,
+    ) -> impl rayon::iter::ParallelIterator<Item = EdgeReference<'_, E, Ix>>
+    where
+        E: Sync,
+    {
+        use rayon::prelude::*;
+        self.g.edges.par_iter().enumerate().filter_map(|(i, edge)| {
+            edge.weight.as_ref().map(|weight| EdgeReference {
+                index: edge_index(i),
+                node: edge.node,
+                weight,
+            })
+        })
+    }
+
+    /// Return a parallel iterator yielding mutable access to all node
+    /// weights.
+    pub fn par_node_weights_mut(&mut self) -> impl rayon::iter::ParallelIterator<Item = &mut N>
+    where
+        N: Send,
+    {
+        use rayon::prelude::*;
+        self.g
+            .nodes
+            .par_iter_mut()
+            .filter_map(|node| node.weight.as_mut())
+    }
+
+    /// Return a parallel iterator yielding mutable access to all edge
+    /// weights.
+    pub fn par_edge_weights_mut(&mut self) -> impl rayon::iter::ParallelIterator<Item = &mut E>
+    where
+        E: Send,
+    {
+        use rayon::prelude::*;
+        self.g
+            .edges
+            .par_iter_mut()
+            .filter_map(|edge| edge.weight.as_mut())
+    }
+}
+
 impl<N, E, Ty, Ix> visit::EdgeCount for StableGraph<N, E, Ty, Ix>
 where
     Ty: EdgeType,
@@ -1919,6 +2538,17 @@ where
     }
 }
 
+impl<N, E, Ty, Ix> visit::NodeDegrees for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Computes in **O(e')** time, where **e'** is the number of edges of `a`.
+    fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        self.neighbors_directed(a, dir).count()
+    }
+}
+
 #[test]
 fn stable_graph() {
     let mut gr = StableGraph::<_, _>::with_capacity(0, 0);
@@ -233,6 +233,7 @@ where
             g: Graph {
                 nodes: nodes,
                 edges: edges,
+                endpoints: None,
                 ty: ty,
             },
             node_count: 0,
@@ -0,0 +1,957 @@
+//! `StaticGraph<N, E, Ty, Ix>` is an immutable graph, laid out for fast
+//! traversal.
+
+use std::cmp::Ordering;
+use std::iter::{Enumerate, Zip};
+use std::marker::PhantomData;
+use std::ops::{Index, Range};
+use std::slice;
+
+use fixedbitset::FixedBitSet;
+
+use super::{
+    DefaultIx, EdgeIndex, EdgeIndices, EdgeReference, Graph, IndexType, NodeIndex, NodeIndices,
+};
+use crate::data::DataMap;
+use crate::util::zip;
+use crate::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp,
+    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeDegrees,
+    NodeIndexable, NodeRef, Visitable,
+};
+use crate::{Directed, Direction, EdgeType, Incoming, Outgoing};
+
+/// The adjacency lists of all nodes, one after the other.
+#[derive(Clone, Debug)]
+struct Adjacency<Ix> {
+    /// Index of the start of the list of each node. Always node_count + 1
+    /// long, the last element is always equal to neighbors.len().
+    start: Vec<usize>,
+    /// The neighbor of each entry
+    neighbors: Vec<NodeIndex<Ix>>,
+    /// The edge of each entry; lock step with neighbors
+    edges: Vec<EdgeIndex<Ix>>,
+}
+
+impl<Ix: IndexType> Adjacency<Ix> {
+    /// Build the adjacency lists from `entries`, each `(a, b, e)` listing the
+    /// edge `e` to the neighbor `b` of node `a`.
+    ///
+    /// The entries of each node keep their relative order.
+    fn new<I>(node_count: usize, entries: I) -> Self
+    where
+        I: Iterator<Item = (NodeIndex<Ix>, NodeIndex<Ix>, EdgeIndex<Ix>)> + Clone,
+    {
+        let mut start = vec![0; node_count + 1];
+        for (a, _, _) in entries.clone() {
+            start[a.index() + 1] += 1;
+        }
+        for i in 1..start.len() {
+            start[i] += start[i - 1];
+        }
+        let len = start[node_count];
+        let mut next = start.clone();
+        let mut neighbors = vec![NodeIndex::end(); len];
+        let mut edges = vec![EdgeIndex::end(); len];
+        for (a, b, e) in entries {
+            let pos = &mut next[a.index()];
+            neighbors[*pos] = b;
+            edges[*pos] = e;
+            *pos += 1;
+        }
+        Adjacency {
+            start,
+            neighbors,
+            edges,
+        }
+    }
+
+    fn range(&self, a: NodeIndex<Ix>) -> Range<usize> {
+        self.start[a.index()]..self.start[a.index() + 1]
+    }
+
+    /// Sort the adjacency list of each node by neighbor, then by edge.
+    fn sort(&mut self) {
+        let mut row = Vec::new();
+        for w in self.start.windows(2) {
+            let r = w[0]..w[1];
+            row.clear();
+            row.extend(
+                zip(&self.neighbors[r.clone()], &self.edges[r.clone()]).map(|(&b, &e)| (b, e)),
+            );
+            row.sort_unstable();
+            for (i, (b, e)) in r.zip(row.drain(..)) {
+                self.neighbors[i] = b;
+                self.edges[i] = e;
+            }
+        }
+    }
+}
+
+/// `StaticGraph<N, E, Ty, Ix>` is an immutable graph, laid out for fast
+/// traversal.
+///
+/// A `StaticGraph` is created from another graph, and its nodes and edges
+/// can't be added or removed afterwards, only their weights accessed.
+/// The adjacency lists of all nodes are stored one after the other in
+/// contiguous arrays, in the compressed sparse row format, which makes
+/// visiting the neighbors of a node fast and takes little space.
+///
+/// `StaticGraph` is parameterized over:
+///
+/// - Associated data `N` for nodes and `E` for edges, called *weights*.
+///   The associated data can be of arbitrary type.
+/// - Edge type `Ty` that determines whether the graph edges are directed or
+///   undirected.
+/// - Index type `Ix`, which determines the maximum size of the graph.
+///
+/// The node and edge indices are the same as in the `Graph` it was created
+/// from. When it is created from another kind of graph, the nodes and edges
+/// are numbered in the order of its node and edge references.
+///
+/// A directed `StaticGraph` only stores the outgoing edges of each node,
+/// unless it is built [`with_incoming_edges`](#method.with_incoming_edges);
+/// otherwise finding the incoming edges of a node takes a scan of all edges.
+/// It can also be built
+/// [`with_sorted_neighbors`](#method.with_sorted_neighbors), for fast edge
+/// lookup and intersections of neighborhoods.
+///
+/// Using **O(|E| + |V|)** space.
+///
+/// ```
+/// use petgraph::algo::kosaraju_scc;
+/// use petgraph::graph::{DiGraph, NodeIndex};
+/// use petgraph::static_graph::StaticGraph;
+///
+/// let g = DiGraph::<&str, u32>::from_edges(&[(0, 1, 2), (1, 2, 3), (2, 0, 4), (1, 3, 1)]);
+/// let s = StaticGraph::from(&g).with_sorted_neighbors().with_incoming_edges();
+///
+/// let (a, b) = (NodeIndex::new(0), NodeIndex::new(1));
+/// assert_eq!(s.neighbors_slice(b), &[NodeIndex::new(2), NodeIndex::new(3)]);
+/// assert_eq!(s.find_edge(a, b), g.find_edge(a, b));
+/// assert_eq!(kosaraju_scc(&s).len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StaticGraph<N, E, Ty = Directed, Ix = DefaultIx> {
+    node_weights: Vec<N>,
+    edge_weights: Vec<E>,
+    /// The source and target of each edge; lock step with edge_weights
+    edge_nodes: Vec<[NodeIndex<Ix>; 2]>,
+    /// The outgoing edges of each node, or all edges for an undirected graph
+    outgoing: Adjacency<Ix>,
+    /// The incoming edges of each node, if they are stored
+    incoming: Option<Adjacency<Ix>>,
+    sorted: bool,
+    ty: PhantomData<Ty>,
+}
+
+impl<N, E, Ty, Ix> StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Create a `StaticGraph` from the node weights and the edges, as their
+    /// source, target and weight.
+    fn from_parts(node_weights: Vec<N>, edges: Vec<(NodeIndex<Ix>, NodeIndex<Ix>, E)>) -> Self {
+        assert!(
+            node_weights.len() <= <Ix as IndexType>::max().index(),
+            "StaticGraph: node index out of bounds"
+        );
+        assert!(
+            edges.len() <= <Ix as IndexType>::max().index(),
+            "StaticGraph: edge index out of bounds"
+        );
+        let mut edge_nodes = Vec::with_capacity(edges.len());
+        let mut edge_weights = Vec::with_capacity(edges.len());
+        for (a, b, weight) in edges {
+            edge_nodes.push([a, b]);
+            edge_weights.push(weight);
+        }
+        let entries = edge_nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &[a, b])| (a, b, EdgeIndex::new(i)));
+        let outgoing = if Ty::is_directed() {
+            Adjacency::new(node_weights.len(), entries)
+        } else {
+            // self loops are listed once
+            let reversed = entries.clone().filter(|&(a, b, _)| a != b);
+            let reversed = reversed.map(|(a, b, e)| (b, a, e));
+            Adjacency::new(node_weights.len(), entries.chain(reversed))
+        };
+        StaticGraph {
+            node_weights,
+            edge_weights,
+            edge_nodes,
+            outgoing,
+            incoming: None,
+            sorted: false,
+            ty: PhantomData,
+        }
+    }
+
+    /// Create a `StaticGraph` from any graph, cloning its weights.
+    ///
+    /// The nodes and edges are numbered in the order of the node references
+    /// and the edge references of `g`, which for a `Graph` is the order of
+    /// their indices.
+    ///
+    /// **Panics** if the number of nodes or edges does not fit with the
+    /// index type.
+    pub fn from_graph<G>(g: G) -> Self
+    where
+        G: IntoNodeReferences
+            + IntoEdgeReferences
+            + NodeIndexable
+            + GraphProp<EdgeType = Ty>
+            + Data<NodeWeight = N, EdgeWeight = E>,
+        N: Clone,
+        E: Clone,
+    {
+        let mut node_map = vec![NodeIndex::end(); g.node_bound()];
+        let mut node_weights = Vec::new();
+        for node in g.node_references() {
+            node_map[g.to_index(node.id())] = NodeIndex::new(node_weights.len());
+            node_weights.push(node.weight().clone());
+        }
+        let edges = g
+            .edge_references()
+            .map(|edge| {
+                (
+                    node_map[g.to_index(edge.source())],
+                    node_map[g.to_index(edge.target())],
+                    edge.weight().clone(),
+                )
+            })
+            .collect();
+        Self::from_parts(node_weights, edges)
+    }
+
+    /// Return the graph, with the adjacency list of each node sorted by
+    /// neighbor.
+    ///
+    /// Parallel edges to the same neighbor are sorted by edge index.
+    ///
+    /// Computes in **O(|V| + |E| log |E|)** time.
+    pub fn with_sorted_neighbors(mut self) -> Self {
+        self.outgoing.sort();
+        if let Some(incoming) = &mut self.incoming {
+            incoming.sort();
+        }
+        self.sorted = true;
+        self
+    }
+
+    /// Return the graph, storing the incoming edges of each node too, so
+    /// that they are as fast to visit as the outgoing edges.
+    ///
+    /// An undirected graph doesn't need to store its incoming edges, since
+    /// they are the same as its outgoing edges, and is returned unchanged.
+    ///
+    /// Computes in **O(|V| + |E|)** time, or **O(|V| + |E| log |E|)** if the
+    /// neighbors are sorted.
+    pub fn with_incoming_edges(mut self) -> Self {
+        if self.is_directed() && self.incoming.is_none() {
+            let entries = self
+                .edge_nodes
+                .iter()
+                .enumerate()
+                .map(|(i, &[a, b])| (b, a, EdgeIndex::new(i)));
+            let mut incoming = Adjacency::new(self.node_count(), entries);
+            if self.sorted {
+                incoming.sort();
+            }
+            self.incoming = Some(incoming);
+        }
+        self
+    }
+
+    /// Return the number of nodes (vertices) in the graph.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn node_count(&self) -> usize {
+        self.node_weights.len()
+    }
+
+    /// Return the number of edges in the graph.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn edge_count(&self) -> usize {
+        self.edge_weights.len()
+    }
+
+    /// Whether the graph has directed edges or not.
+    #[inline]
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Return `true` if the adjacency lists are sorted by neighbor.
+    pub fn has_sorted_neighbors(&self) -> bool {
+        self.sorted
+    }
+
+    /// Return `true` if the incoming edges of the graph are as fast to visit
+    /// as its outgoing edges: if it stores them, or if it is undirected.
+    pub fn has_incoming_edges(&self) -> bool {
+        !self.is_directed() || self.incoming.is_some()
+    }
+
+    /// Access the weight for node `a`.
+    ///
+    /// Also available with indexing syntax: `&graph[a]`.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.node_weights.get(a.index())
+    }
+
+    /// Access the weight for node `a`, mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[a]`.
+    pub fn node_weight_mut(&mut self, a: NodeIndex<Ix>) -> Option<&mut N> {
+        self.node_weights.get_mut(a.index())
+    }
+
+    /// Access the weight for edge `e`.
+    ///
+    /// Also available with indexing syntax: `&graph[e]`.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.edge_weights.get(e.index())
+    }
+
+    /// Access the weight for edge `e`, mutably.
+    ///
+    /// Also available with indexing syntax: `&mut graph[e]`.
+    pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
+        self.edge_weights.get_mut(e.index())
+    }
+
+    /// Access the source and target nodes for `e`.
+    pub fn edge_endpoints(&self, e: EdgeIndex<Ix>) -> Option<(NodeIndex<Ix>, NodeIndex<Ix>)> {
+        self.edge_nodes.get(e.index()).map(|&[a, b]| (a, b))
+    }
+
+    /// Return the neighbors of `a`, in the order of its adjacency list.
+    ///
+    /// - `Directed`: Targets of outgoing edges from `a`.
+    /// - `Undirected`: Opposing endpoints of all edges connected to `a`.
+    ///
+    /// A node appears once for each edge to it, and a self loop of an
+    /// undirected graph appears once.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    pub fn neighbors_slice(&self, a: NodeIndex<Ix>) -> &[NodeIndex<Ix>] {
+        &self.outgoing.neighbors[self.outgoing.range(a)]
+    }
+
+    /// Return the neighbors of `a` in the direction `dir`, in the order of
+    /// its adjacency list.
+    ///
+    /// - `Outgoing`, or an undirected graph: The same as
+    ///   [`neighbors_slice`](#method.neighbors_slice).
+    /// - `Incoming`: The sources of the incoming edges of `a`.
+    ///
+    /// Computes in **O(1)** time.
+    ///
+    /// **Panics** if the node `a` does not exist, or if the incoming neighbors
+    /// of a directed graph that doesn't store its incoming edges are requested.
+    pub fn neighbors_slice_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> &[NodeIndex<Ix>] {
+        let adjacency = match self.adjacency(dir) {
+            Some(adjacency) => adjacency,
+            None => panic!("StaticGraph: incoming edges are not stored, see `with_incoming_edges`"),
+        };
+        &adjacency.neighbors[adjacency.range(a)]
+    }
+
+    /// Return the adjacency lists of `dir`, or `None` if they aren't stored.
+    fn adjacency(&self, dir: Direction) -> Option<&Adjacency<Ix>> {
+        if dir == Outgoing || !self.is_directed() {
+            Some(&self.outgoing)
+        } else {
+            self.incoming.as_ref()
+        }
+    }
+
+    /// Return an iterator of the neighbors and edges of `a` in the direction
+    /// `dir`, which scans all edges if they aren't stored.
+    fn adjacency_iter(&self, a: NodeIndex<Ix>, dir: Direction) -> AdjacencyIter<'_, Ix> {
+        match self.adjacency(dir) {
+            Some(adjacency) => {
+                let r = adjacency.range(a);
+                AdjacencyIter::List(zip(&adjacency.neighbors[r.clone()], &adjacency.edges[r]))
+            }
+            None => {
+                assert!(a.index() < self.node_count());
+                AdjacencyIter::Scan(a, self.edge_nodes.iter().enumerate())
+            }
+        }
+    }
+
+    /// Lookup an edge from `a` to `b`.
+    ///
+    /// Computes in **O(log e')** time if the neighbors are sorted, and
+    /// otherwise in **O(e')** time, where **e'** is the number of edges of
+    /// `a`.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    pub fn find_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> Option<EdgeIndex<Ix>> {
+        let r = self.outgoing.range(a);
+        let neighbors = &self.outgoing.neighbors[r.clone()];
+        let i = if self.sorted {
+            // the first of parallel edges
+            let i = neighbors
+                .binary_search_by(|&n| {
+                    if n < b {
+                        Ordering::Less
+                    } else {
+                        Ordering::Greater
+                    }
+                })
+                .unwrap_err();
+            Some(i).filter(|&i| neighbors.get(i) == Some(&b))
+        } else {
+            neighbors.iter().position(|&n| n == b)
+        }?;
+        Some(self.outgoing.edges[r.start + i])
+    }
+
+    /// Return `true` if there is an edge from `a` to `b`.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    pub fn contains_edge(&self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        self.find_edge(a, b).is_some()
+    }
+
+    /// Return an iterator of all edges of `a`, in the order of its adjacency
+    /// list.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges connected to `a`, with `a` being the source
+    ///   of each edge.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `EdgeReference<E, Ix>`.
+    pub fn edges(&self, a: NodeIndex<Ix>) -> Edges<'_, E, Ix> {
+        self.edges_directed(a, Outgoing)
+    }
+
+    /// Return an iterator of all edges of `a`, in the specified direction.
+    ///
+    /// - `Directed`, `Outgoing`: All edges from `a`.
+    /// - `Directed`, `Incoming`: All edges to `a`.
+    /// - `Undirected`, `Outgoing`: All edges connected to `a`, with `a` being
+    ///   the source of each edge.
+    /// - `Undirected`, `Incoming`: All edges connected to `a`, with `a` being
+    ///   the target of each edge.
+    ///
+    /// The incoming edges of a directed graph that doesn't store them are
+    /// found by scanning all edges, in **O(|E|)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `EdgeReference<E, Ix>`.
+    pub fn edges_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> Edges<'_, E, Ix> {
+        Edges {
+            node: a,
+            direction: dir,
+            iter: self.adjacency_iter(a, dir),
+            edge_weights: &self.edge_weights,
+        }
+    }
+
+    /// Return an iterator over the node indices of the graph.
+    pub fn node_indices(&self) -> NodeIndices<Ix> {
+        NodeIndices {
+            r: 0..self.node_count(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return an iterator over the edge indices of the graph.
+    pub fn edge_indices(&self) -> EdgeIndices<Ix> {
+        EdgeIndices {
+            r: 0..self.edge_count(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return the node weights, in the order of their indices.
+    pub fn node_weights(&self) -> &[N] {
+        &self.node_weights
+    }
+
+    /// Return the edge weights, in the order of their indices.
+    pub fn edge_weights(&self) -> &[E] {
+        &self.edge_weights
+    }
+}
+
+/// Create a `StaticGraph` with the same node and edge indices as `graph`.
+impl<N, E, Ty, Ix> From<Graph<N, E, Ty, Ix>> for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: Graph<N, E, Ty, Ix>) -> Self {
+        let (nodes, edges) = graph.into_nodes_edges();
+        StaticGraph::from_parts(
+            nodes.into_iter().map(|node| node.weight).collect(),
+            edges
+                .into_iter()
+                .map(|edge| (edge.source(), edge.target(), edge.weight))
+                .collect(),
+        )
+    }
+}
+
+/// Create a `StaticGraph` with the same node and edge indices as `graph`,
+/// cloning its weights.
+impl<'a, N, E, Ty, Ix> From<&'a Graph<N, E, Ty, Ix>> for StaticGraph<N, E, Ty, Ix>
+where
+    N: Clone,
+    E: Clone,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn from(graph: &'a Graph<N, E, Ty, Ix>) -> Self {
+        StaticGraph::from_graph(graph)
+    }
+}
+
+/// Iterator over the neighbors and edges of a node.
+#[derive(Debug, Clone)]
+enum AdjacencyIter<'a, Ix> {
+    /// The entries of an adjacency list
+    List(Zip<slice::Iter<'a, NodeIndex<Ix>>, slice::Iter<'a, EdgeIndex<Ix>>>),
+    /// A scan of all edges, for the edges to a node
+    Scan(
+        NodeIndex<Ix>,
+        Enumerate<slice::Iter<'a, [NodeIndex<Ix>; 2]>>,
+    ),
+}
+
+impl<'a, Ix> Iterator for AdjacencyIter<'a, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = (NodeIndex<Ix>, EdgeIndex<Ix>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            AdjacencyIter::List(iter) => iter.next().map(|(&b, &e)| (b, e)),
+            AdjacencyIter::Scan(a, iter) => {
+                let a = *a;
+                iter.find(|&(_, node)| node[1] == a)
+                    .map(|(i, node)| (node[0], EdgeIndex::new(i)))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            AdjacencyIter::List(iter) => iter.size_hint(),
+            AdjacencyIter::Scan(_, iter) => (0, iter.size_hint().1),
+        }
+    }
+}
+
+/// Iterator over the edges of a node.
+#[derive(Debug, Clone)]
+pub struct Edges<'a, E: 'a, Ix: 'a = DefaultIx> {
+    node: NodeIndex<Ix>,
+    direction: Direction,
+    iter: AdjacencyIter<'a, Ix>,
+    edge_weights: &'a [E],
+}
+
+impl<'a, E, Ix> Iterator for Edges<'a, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = EdgeReference<'a, E, Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (b, e) = self.iter.next()?;
+        Some(EdgeReference {
+            index: e,
+            node: if self.direction == Outgoing {
+                [self.node, b]
+            } else {
+                [b, self.node]
+            },
+            weight: &self.edge_weights[e.index()],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator over the neighbors of a node.
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a, Ix: 'a = DefaultIx> {
+    iter: AdjacencyIter<'a, Ix>,
+}
+
+impl<'a, Ix> Iterator for Neighbors<'a, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(b, _)| b)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator over all nodes of a graph.
+#[derive(Debug, Clone)]
+pub struct NodeReferences<'a, N: 'a, Ix = DefaultIx> {
+    iter: Enumerate<slice::Iter<'a, N>>,
+    ty: PhantomData<Ix>,
+}
+
+impl<'a, N, Ix> Iterator for NodeReferences<'a, N, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = (NodeIndex<Ix>, &'a N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(i, weight)| (NodeIndex::new(i), weight))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, N, Ix> ExactSizeIterator for NodeReferences<'a, N, Ix> where Ix: IndexType {}
+
+/// Iterator over all edges of a graph.
+#[derive(Debug, Clone)]
+pub struct EdgeReferences<'a, E: 'a, Ix: 'a = DefaultIx> {
+    #[allow(clippy::type_complexity)]
+    iter: Enumerate<Zip<slice::Iter<'a, [NodeIndex<Ix>; 2]>, slice::Iter<'a, E>>>,
+}
+
+impl<'a, E, Ix> Iterator for EdgeReferences<'a, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = EdgeReference<'a, E, Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(i, (&node, weight))| EdgeReference {
+            index: EdgeIndex::new(i),
+            node,
+            weight,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, E, Ix> ExactSizeIterator for EdgeReferences<'a, E, Ix> where Ix: IndexType {}
+
+/// Index the `StaticGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, Ty, Ix> Index<NodeIndex<Ix>> for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        &self.node_weights[index.index()]
+    }
+}
+
+/// Index the `StaticGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ty, Ix> Index<EdgeIndex<Ix>> for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.edge_weights[index.index()]
+    }
+}
+
+impl<N, E, Ty, Ix> GraphBase for StaticGraph<N, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = EdgeIndex<Ix>;
+}
+
+impl<N, E, Ty, Ix> GraphProp for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeType = Ty;
+}
+
+impl<N, E, Ty, Ix> Data for StaticGraph<N, E, Ty, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, Ty, Ix> DataMap for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_weight(&self, a: Self::NodeId) -> Option<&N> {
+        self.node_weight(a)
+    }
+    fn edge_weight(&self, e: Self::EdgeId) -> Option<&E> {
+        self.edge_weight(e)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeCount for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        self.node_count()
+    }
+}
+
+impl<N, E, Ty, Ix> EdgeCount for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+impl<N, E, Ty, Ix> NodeIndexable for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    #[inline]
+    fn node_bound(&self) -> usize {
+        self.node_count()
+    }
+    #[inline]
+    fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    #[inline]
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        NodeIndex::new(ix)
+    }
+}
+
+impl<N, E, Ty, Ix> NodeCompactIndexable for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+}
+
+impl<N, E, Ty, Ix> EdgeIndexable for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn edge_bound(&self) -> usize {
+        self.edge_count()
+    }
+    fn to_index(&self, ix: EdgeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    fn from_index(&self, ix: usize) -> Self::EdgeId {
+        EdgeIndex::new(ix)
+    }
+}
+
+impl<N, E, Ty, Ix> Visitable for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Map = FixedBitSet;
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.node_count())
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+        map.grow(self.node_count());
+    }
+}
+
+impl<N, E, Ty, Ix> NodeDegrees for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    /// Computes in **O(1)** time, except for the incoming edges of a directed
+    /// graph that doesn't store them, which take **O(|E|)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    fn degree_directed(&self, a: NodeIndex<Ix>, dir: Direction) -> usize {
+        if dir == Incoming && self.is_directed() && self.incoming.is_none() {
+            assert!(a.index() < self.node_count());
+            self.edge_nodes.iter().filter(|node| node[1] == a).count()
+        } else {
+            self.neighbors_slice_directed(a, dir).len()
+        }
+    }
+}
+
+impl<N, E, Ty, Ix> IntoNodeIdentifiers for &StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeIdentifiers = NodeIndices<Ix>;
+    fn node_identifiers(self) -> NodeIndices<Ix> {
+        self.node_indices()
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNodeReferences for &'a StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = NodeReferences<'a, N, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences {
+            iter: self.node_weights.iter().enumerate(),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdgeReferences for &'a StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgeRef = EdgeReference<'a, E, Ix>;
+    type EdgeReferences = EdgeReferences<'a, E, Ix>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        EdgeReferences {
+            iter: zip(&self.edge_nodes, &self.edge_weights).enumerate(),
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNeighbors for &'a StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Neighbors = Neighbors<'a, Ix>;
+
+    /// Return an iterator of the neighbors of `a`, the same as
+    /// [`StaticGraph::neighbors_slice`].
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    fn neighbors(self, a: NodeIndex<Ix>) -> Self::Neighbors {
+        Neighbors {
+            iter: self.adjacency_iter(a, Outgoing),
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoNeighborsDirected for &'a StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type NeighborsDirected = Neighbors<'a, Ix>;
+
+    /// Return an iterator of the neighbors of `a` in the direction `dir`, the
+    /// same as [`StaticGraph::neighbors_slice_directed`].
+    ///
+    /// The incoming neighbors of a directed graph that doesn't store its
+    /// incoming edges are found by scanning all edges, in **O(|E|)** time.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    fn neighbors_directed(self, a: NodeIndex<Ix>, dir: Direction) -> Self::NeighborsDirected {
+        Neighbors {
+            iter: self.adjacency_iter(a, dir),
+        }
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdges for &'a StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type Edges = Edges<'a, E, Ix>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        self.edges(a)
+    }
+}
+
+impl<'a, N, E, Ty, Ix> IntoEdgesDirected for &'a StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type EdgesDirected = Edges<'a, E, Ix>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        self.edges_directed(a, dir)
+    }
+}
+
+/// The adjacency matrix for **StaticGraph** is a bitmap that's computed by
+/// `.adjacency_matrix()`.
+impl<N, E, Ty, Ix> GetAdjacencyMatrix for StaticGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    type AdjMatrix = FixedBitSet;
+
+    fn adjacency_matrix(&self) -> FixedBitSet {
+        let n = self.node_count();
+        let mut matrix = FixedBitSet::with_capacity(n * n);
+        for &[a, b] in &self.edge_nodes {
+            matrix.put(a.index() * n + b.index());
+            if !self.is_directed() {
+                matrix.put(b.index() * n + a.index());
+            }
+        }
+        matrix
+    }
+
+    fn is_adjacent(&self, matrix: &FixedBitSet, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> bool {
+        let n = self.node_count();
+        matrix.contains(a.index() * n + b.index())
+    }
+}
@@ -5,9 +5,11 @@ use indexmap::map::Keys;
 use indexmap::map::{Iter as IndexMapIter, IterMut as IndexMapIterMut};
 use indexmap::IndexMap;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
 use std::fmt;
-use std::hash::{self, Hash};
+use std::hash::{self, BuildHasher, Hash};
 use std::iter::FromIterator;
 use std::iter::{Cloned, DoubleEndedIterator};
 use std::marker::PhantomData;
@@ -17,21 +19,29 @@ use std::slice::Iter;
 
 use crate::{Directed, Direction, EdgeType, Incoming, Outgoing, Undirected};
 
-use crate::graph::node_index;
+use crate::convert::ConversionError;
+use crate::data::DataMap;
 use crate::graph::Graph;
+use crate::graph::{node_index, MemoryUsage, NodeIndex};
 use crate::visit;
 use crate::IntoWeightedEdge;
 
+mod multi;
+pub use self::multi::{DiMultiGraphMap, MultiGraphMap, UnMultiGraphMap};
+
 /// A `GraphMap` with undirected edges.
 ///
 /// For example, an edge between *1* and *2* is equivalent to an edge between
 /// *2* and *1*.
-pub type UnGraphMap<N, E> = GraphMap<N, E, Undirected>;
+pub type UnGraphMap<N, E, S = RandomState> = GraphMap<N, E, Undirected, S>;
 /// A `GraphMap` with directed edges.
 ///
 /// For example, an edge from *1* to *2* is distinct from an edge from *2* to
 /// *1*.
-pub type DiGraphMap<N, E> = GraphMap<N, E, Directed>;
+pub type DiGraphMap<N, E, S = RandomState> = GraphMap<N, E, Directed, S>;
+
+/// A `Graph` and the index of each of its nodes, from `into_graph_with_map`.
+type GraphWithMap<N, E, Ty, Ix, S> = (Graph<N, E, Ty, Ix>, HashMap<N, NodeIndex<Ix>, S>);
 
 /// `GraphMap<N, E, Ty>` is a graph datastructure using an associative array
 /// of its node weights `N`.
@@ -51,20 +61,31 @@ pub type DiGraphMap<N, E> = GraphMap<N, E, Directed>;
 /// - `E` can be of arbitrary type.
 /// - Edge type `Ty` that determines whether the graph edges are directed or
 /// undirected.
+/// - The hash builder `S` of the maps of nodes and edges, the std hasher by
+///   default. A faster hasher like FxHash can be plugged in with
+///   [`with_hasher`](#method.with_hasher).
 ///
 /// You can use the type aliases `UnGraphMap` and `DiGraphMap` for convenience.
 ///
-/// `GraphMap` does not allow parallel edges, but self loops are allowed.
+/// `GraphMap` does not allow parallel edges, but self loops are allowed. Use
+/// [`MultiGraphMap`](struct.MultiGraphMap.html) for parallel edges.
+///
+/// The id of an edge is the pair of its endpoints `(a, b)`, which stays valid
+/// as long as the edge exists, however the rest of the graph changes. For an
+/// undirected graph, `(a, b)` and `(b, a)` are ids of the same edge, and
+/// `EdgeIndexable` maps them to the same index.
 ///
 /// Depends on crate feature `graphmap` (default).
 #[derive(Clone)]
-pub struct GraphMap<N, E, Ty> {
-    nodes: IndexMap<N, Vec<(N, CompactDirection)>>,
-    edges: IndexMap<(N, N), E>,
+pub struct GraphMap<N, E, Ty, S = RandomState> {
+    nodes: IndexMap<N, Vec<(N, CompactDirection)>, S>,
+    edges: IndexMap<(N, N), E, S>,
     ty: PhantomData<Ty>,
 }
 
-impl<N: Eq + Hash + fmt::Debug, E: fmt::Debug, Ty: EdgeType> fmt::Debug for GraphMap<N, E, Ty> {
+impl<N: Eq + Hash + fmt::Debug, E: fmt::Debug, Ty: EdgeType, S> fmt::Debug
+    for GraphMap<N, E, Ty, S>
+{
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.nodes.fmt(f)
     }
@@ -128,31 +149,7 @@ where
 
     /// Create a new `GraphMap` with estimated capacity.
     pub fn with_capacity(nodes: usize, edges: usize) -> Self {
-        GraphMap {
-            nodes: IndexMap::with_capacity(nodes),
-            edges: IndexMap::with_capacity(edges),
-            ty: PhantomData,
-        }
-    }
-
-    /// Return the current node and edge capacity of the graph.
-    pub fn capacity(&self) -> (usize, usize) {
-        (self.nodes.capacity(), self.edges.capacity())
-    }
-
-    /// Use their natural order to map the node pair (a, b) to a canonical edge id.
-    #[inline]
-    fn edge_key(a: N, b: N) -> (N, N) {
-        if Ty::is_directed() || a <= b {
-            (a, b)
-        } else {
-            (b, a)
-        }
-    }
-
-    /// Whether the graph has directed edges.
-    pub fn is_directed(&self) -> bool {
-        Ty::is_directed()
+        Self::with_capacity_and_hasher(nodes, edges, RandomState::new())
     }
 
     /// Create a new `GraphMap` from an iterable of edges.
@@ -182,6 +179,156 @@ where
         Self::from_iter(iterable)
     }
 
+    /// Create a new `GraphMap` from a `Graph`, with the node keys computed by
+    /// `key_fn` from the node indices and weights of `graph`.
+    ///
+    /// Edges are inserted in the order of their indices, so of parallel edges
+    /// in `graph`, the one with the highest index gives the edge weight. Nodes
+    /// that map to the same key are merged.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    /// use petgraph::Graph;
+    ///
+    /// let mut graph = Graph::new();
+    /// let a = graph.add_node("a");
+    /// let b = graph.add_node("b");
+    /// graph.add_edge(a, b, 1.5);
+    ///
+    /// let map = DiGraphMap::from_graph(&graph, |_, &name| name);
+    /// assert_eq!(map.edge_weight("a", "b"), Some(&1.5));
+    /// ```
+    pub fn from_graph<NW, Ix, F>(graph: &Graph<NW, E, Ty, Ix>, mut key_fn: F) -> Self
+    where
+        E: Clone,
+        Ix: crate::graph::IndexType,
+        F: FnMut(NodeIndex<Ix>, &NW) -> N,
+    {
+        let mut g = Self::with_capacity(graph.node_count(), graph.edge_count());
+        let keys = graph
+            .node_indices()
+            .map(|i| g.add_node(key_fn(i, &graph[i])))
+            .collect::<Vec<_>>();
+        for edge in graph.raw_edges() {
+            g.add_edge(
+                keys[edge.source().index()],
+                keys[edge.target().index()],
+                edge.weight.clone(),
+            );
+        }
+        g
+    }
+}
+
+impl<N, E, Ty, S> GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    /// Create a new `GraphMap` which uses the given hash builder to hash the
+    /// nodes.
+    ///
+    /// ```
+    /// use petgraph::graphmap::DiGraphMap;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// // any `BuildHasher`, for example one for FxHash or aHash, can be used
+    /// let mut g = DiGraphMap::with_hasher(BuildHasherDefault::<DefaultHasher>::default());
+    /// g.add_edge(1, 2, "a");
+    /// assert!(g.contains_edge(1, 2));
+    /// ```
+    pub fn with_hasher(hash_builder: S) -> Self
+    where
+        S: Clone,
+    {
+        Self::with_capacity_and_hasher(0, 0, hash_builder)
+    }
+
+    /// Create a new `GraphMap` with estimated capacity, which uses the given
+    /// hash builder to hash the nodes.
+    pub fn with_capacity_and_hasher(nodes: usize, edges: usize, hash_builder: S) -> Self
+    where
+        S: Clone,
+    {
+        GraphMap {
+            nodes: IndexMap::with_capacity_and_hasher(nodes, hash_builder.clone()),
+            edges: IndexMap::with_capacity_and_hasher(edges, hash_builder),
+            ty: PhantomData,
+        }
+    }
+
+    /// Return a reference to the graph's hash builder.
+    pub fn hasher(&self) -> &S {
+        self.nodes.hasher()
+    }
+
+    /// Return the current node and edge capacity of the graph.
+    pub fn capacity(&self) -> (usize, usize) {
+        (self.nodes.capacity(), self.edges.capacity())
+    }
+
+    /// Shrinks the capacity of the graph, including the neighbor list of
+    /// each node, as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+        for neighbors in self.nodes.values_mut() {
+            neighbors.shrink_to_fit();
+        }
+    }
+
+    /// Return an estimate of the heap memory held by the graph.
+    ///
+    /// Each entry of the node and edge maps is counted with its hash and its
+    /// slot in the hash table. The neighbor lists of the nodes are counted
+    /// as adjacency.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    ///
+    /// let g = UnGraphMap::<u32, ()>::from_edges(&[(0, 1), (1, 2)]);
+    /// let usage = g.memory_usage();
+    /// assert!(usage.nodes > 0 && usage.edges > 0 && usage.adjacency > 0);
+    /// assert_eq!(usage.vacant, 0);
+    /// ```
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let node_entry =
+            mem::size_of::<(usize, N, Vec<(N, CompactDirection)>)>() + mem::size_of::<usize>();
+        let edge_entry = mem::size_of::<(usize, (N, N), E)>() + mem::size_of::<usize>();
+        let neighbor = mem::size_of::<(N, CompactDirection)>();
+        let mut usage = MemoryUsage {
+            nodes: self.nodes.len() * node_entry,
+            edges: self.edges.len() * edge_entry,
+            unused: (self.nodes.capacity() - self.nodes.len()) * node_entry
+                + (self.edges.capacity() - self.edges.len()) * edge_entry,
+            ..MemoryUsage::default()
+        };
+        for neighbors in self.nodes.values() {
+            usage.adjacency += neighbors.len() * neighbor;
+            usage.unused += (neighbors.capacity() - neighbors.len()) * neighbor;
+        }
+        usage
+    }
+
+    /// Use their natural order to map the node pair (a, b) to a canonical edge id.
+    #[inline]
+    fn edge_key(a: N, b: N) -> (N, N) {
+        if Ty::is_directed() || a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Whether the graph has directed edges.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
     /// Return the number of nodes in the graph.
     pub fn node_count(&self) -> usize {
         self.nodes.len()
@@ -388,7 +535,7 @@ where
     ///
     /// Produces an empty iterator if the node doesn't exist.<br>
     /// Iterator element type is `(N, &E)`.
-    pub fn edges(&self, from: N) -> Edges<N, E, Ty> {
+    pub fn edges(&self, from: N) -> Edges<N, E, Ty, S> {
         Edges {
             from,
             iter: self.neighbors(from),
@@ -408,7 +555,7 @@ where
     ///
     /// Produces an empty iterator if the node doesn't exist.<br>
     /// Iterator element type is `(N, &E)`.
-    pub fn edges_directed(&self, from: N, dir: Direction) -> EdgesDirected<N, E, Ty> {
+    pub fn edges_directed(&self, from: N, dir: Direction) -> EdgesDirected<N, E, Ty, S> {
         EdgesDirected {
             from,
             iter: self.neighbors_directed(from, dir),
@@ -457,6 +604,9 @@ where
     ///    node weights in the resulting `Graph`, too.
     /// 2. Note that the index type is user-chosen.
     ///
+    /// Use [`into_graph_with_map`](#method.into_graph_with_map) to also get
+    /// the index of each node in the `Graph`.
+    ///
     /// Computes in **O(|V| + |E|)** time (average).
     ///
     /// **Panics** if the number of nodes or edges does not fit with
@@ -477,14 +627,95 @@ where
         }
         gr
     }
+
+    /// Return a `Graph` that corresponds to this `GraphMap`, like
+    /// [`into_graph`](#method.into_graph), together with a map from each
+    /// node of the `GraphMap` to its index in the `Graph`.
+    ///
+    /// Computes in **O(|V| + |E|)** time (average).
+    ///
+    /// **Panics** if the number of nodes or edges does not fit with
+    /// the resulting graph's index type.
+    ///
+    /// ```
+    /// use petgraph::graphmap::UnGraphMap;
+    /// use petgraph::graph::UnGraph;
+    ///
+    /// let map = UnGraphMap::<_, ()>::from_edges(&[("a", "b"), ("b", "c")]);
+    /// let (graph, index_of): (UnGraph<_, _>, _) = map.into_graph_with_map();
+    /// assert_eq!(graph[index_of["c"]], "c");
+    /// assert!(graph.contains_edge(index_of["a"], index_of["b"]));
+    /// ```
+    pub fn into_graph_with_map<Ix>(self) -> GraphWithMap<N, E, Ty, Ix, S>
+    where
+        Ix: crate::graph::IndexType,
+        S: Clone,
+    {
+        let mut node_map =
+            HashMap::with_capacity_and_hasher(self.node_count(), self.hasher().clone());
+        for (i, &node) in self.nodes.keys().enumerate() {
+            node_map.insert(node, node_index(i));
+        }
+        (self.into_graph(), node_map)
+    }
+}
+
+/// Convert a `GraphMap` into a `Graph`, like
+/// [`into_graph`](struct.GraphMap.html#method.into_graph).
+impl<N, E, Ty, Ix, S> From<GraphMap<N, E, Ty, S>> for Graph<N, E, Ty, Ix>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    Ix: crate::graph::IndexType,
+    S: BuildHasher,
+{
+    fn from(graph: GraphMap<N, E, Ty, S>) -> Self {
+        graph.into_graph()
+    }
+}
+
+/// Convert a `Graph` into a `GraphMap` with its node weights as nodes,
+/// failing if it has nodes with equal weights or parallel edges.
+impl<N, E, Ty, Ix, S> TryFrom<Graph<N, E, Ty, Ix>> for GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    Ix: crate::graph::IndexType,
+    S: BuildHasher + Default,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: Graph<N, E, Ty, Ix>) -> Result<Self, ConversionError> {
+        let (nodes, edges) = graph.into_nodes_edges();
+        let mut g = GraphMap {
+            nodes: IndexMap::with_capacity_and_hasher(nodes.len(), S::default()),
+            edges: IndexMap::with_capacity_and_hasher(edges.len(), S::default()),
+            ty: PhantomData,
+        };
+        for node in &nodes {
+            if g.contains_node(node.weight) {
+                return Err(ConversionError::DuplicateNodes);
+            }
+            g.add_node(node.weight);
+        }
+        for edge in edges {
+            let a = nodes[edge.source().index()].weight;
+            let b = nodes[edge.target().index()].weight;
+            if g.add_edge(a, b, edge.weight).is_some() {
+                return Err(ConversionError::ParallelEdges);
+            }
+        }
+        Ok(g)
+    }
 }
 
 /// Create a new `GraphMap` from an iterable of edges.
-impl<N, E, Ty, Item> FromIterator<Item> for GraphMap<N, E, Ty>
+impl<N, E, Ty, Item, S> FromIterator<Item> for GraphMap<N, E, Ty, S>
 where
     Item: IntoWeightedEdge<E, NodeId = N>,
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher + Clone + Default,
 {
     fn from_iter<I>(iterable: I) -> Self
     where
@@ -492,7 +723,7 @@ where
     {
         let iter = iterable.into_iter();
         let (low, _) = iter.size_hint();
-        let mut g = Self::with_capacity(0, low);
+        let mut g = Self::with_capacity_and_hasher(0, low, S::default());
         g.extend(iter);
         g
     }
@@ -501,11 +732,12 @@ where
 /// Extend the graph from an iterable of edges.
 ///
 /// Nodes are inserted automatically to match the edges.
-impl<N, E, Ty, Item> Extend<Item> for GraphMap<N, E, Ty>
+impl<N, E, Ty, Item, S> Extend<Item> for GraphMap<N, E, Ty, S>
 where
     Item: IntoWeightedEdge<E, NodeId = N>,
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn extend<I>(&mut self, iterable: I)
     where
@@ -611,21 +843,22 @@ where
 }
 
 #[derive(Debug, Clone)]
-pub struct Edges<'a, N, E: 'a, Ty>
+pub struct Edges<'a, N, E: 'a, Ty, S = RandomState>
 where
     N: 'a + NodeTrait,
     Ty: EdgeType,
 {
     from: N,
-    edges: &'a IndexMap<(N, N), E>,
+    edges: &'a IndexMap<(N, N), E, S>,
     iter: Neighbors<'a, N, Ty>,
 }
 
-impl<'a, N, E, Ty> Iterator for Edges<'a, N, E, Ty>
+impl<'a, N, E, Ty, S> Iterator for Edges<'a, N, E, Ty, S>
 where
     N: 'a + NodeTrait,
     E: 'a,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Item = (N, N, &'a E);
     fn next(&mut self) -> Option<Self::Item> {
@@ -643,22 +876,23 @@ where
 }
 
 #[derive(Debug, Clone)]
-pub struct EdgesDirected<'a, N, E: 'a, Ty>
+pub struct EdgesDirected<'a, N, E: 'a, Ty, S = RandomState>
 where
     N: 'a + NodeTrait,
     Ty: EdgeType,
 {
     from: N,
     dir: Direction,
-    edges: &'a IndexMap<(N, N), E>,
+    edges: &'a IndexMap<(N, N), E, S>,
     iter: NeighborsDirected<'a, N, Ty>,
 }
 
-impl<'a, N, E, Ty> Iterator for EdgesDirected<'a, N, E, Ty>
+impl<'a, N, E, Ty, S> Iterator for EdgesDirected<'a, N, E, Ty, S>
 where
     N: 'a + NodeTrait,
     E: 'a,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Item = (N, N, &'a E);
     fn next(&mut self) -> Option<Self::Item> {
@@ -788,10 +1022,11 @@ where
 }
 
 /// Index `GraphMap` by node pairs to access edge weights.
-impl<N, E, Ty> Index<(N, N)> for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> Index<(N, N)> for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Output = E;
     fn index(&self, index: (N, N)) -> &E {
@@ -802,10 +1037,11 @@ where
 }
 
 /// Index `GraphMap` by node pairs to access edge weights.
-impl<N, E, Ty> IndexMut<(N, N)> for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> IndexMut<(N, N)> for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn index_mut(&mut self, index: (N, N)) -> &mut E {
         let index = Self::edge_key(index.0, index.1);
@@ -938,27 +1174,46 @@ where
     }
 }
 
-impl<N, E, Ty> visit::GraphBase for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::GraphBase for GraphMap<N, E, Ty, S>
 where
     N: Copy + PartialEq,
+    S: BuildHasher,
 {
     type NodeId = N;
     type EdgeId = (N, N);
 }
 
-impl<N, E, Ty> visit::Data for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::Data for GraphMap<N, E, Ty, S>
 where
     N: Copy + PartialEq,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type NodeWeight = N;
     type EdgeWeight = E;
 }
 
-impl<N, E, Ty> visit::Visitable for GraphMap<N, E, Ty>
+/// The edge id `(a, b)` names the edge connecting `a` with `b`, for an
+/// undirected graph in either order.
+impl<N, E, Ty, S> DataMap for GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    fn node_weight(&self, id: N) -> Option<&N> {
+        self.nodes.get_full(&id).map(|(_, n, _)| n)
+    }
+    fn edge_weight(&self, id: (N, N)) -> Option<&E> {
+        self.edge_weight(id.0, id.1)
+    }
+}
+
+impl<N, E, Ty, S> visit::Visitable for GraphMap<N, E, Ty, S>
 where
     N: Copy + Ord + Hash,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Map = HashSet<N>;
     fn visit_map(&self) -> HashSet<N> {
@@ -969,18 +1224,20 @@ where
     }
 }
 
-impl<N, E, Ty> visit::GraphProp for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::GraphProp for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type EdgeType = Ty;
 }
 
-impl<'a, N, E, Ty> visit::IntoNodeReferences for &'a GraphMap<N, E, Ty>
+impl<'a, N, E, Ty, S> visit::IntoNodeReferences for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type NodeRef = (N, &'a N);
     type NodeReferences = NodeReferences<'a, N, E, Ty>;
@@ -993,10 +1250,11 @@ where
     }
 }
 
-impl<'a, N, E: 'a, Ty> visit::IntoNodeIdentifiers for &'a GraphMap<N, E, Ty>
+impl<'a, N, E: 'a, Ty, S> visit::IntoNodeIdentifiers for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type NodeIdentifiers = NodeIdentifiers<'a, N, E, Ty>;
 
@@ -1009,20 +1267,22 @@ where
     }
 }
 
-impl<N, E, Ty> visit::NodeCount for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::NodeCount for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn node_count(&self) -> usize {
         (*self).node_count()
     }
 }
 
-impl<N, E, Ty> visit::NodeIndexable for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::NodeIndexable for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn node_bound(&self) -> usize {
         self.node_count()
@@ -1042,17 +1302,19 @@ where
     }
 }
 
-impl<N, E, Ty> visit::NodeCompactIndexable for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::NodeCompactIndexable for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
 }
 
-impl<'a, N: 'a, E, Ty> visit::IntoNeighbors for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E, Ty, S> visit::IntoNeighbors for &'a GraphMap<N, E, Ty, S>
 where
     N: Copy + Ord + Hash,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type Neighbors = Neighbors<'a, N, Ty>;
     fn neighbors(self, n: Self::NodeId) -> Self::Neighbors {
@@ -1060,10 +1322,11 @@ where
     }
 }
 
-impl<'a, N: 'a, E, Ty> visit::IntoNeighborsDirected for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E, Ty, S> visit::IntoNeighborsDirected for &'a GraphMap<N, E, Ty, S>
 where
     N: Copy + Ord + Hash,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type NeighborsDirected = NeighborsDirected<'a, N, Ty>;
     fn neighbors_directed(self, n: N, dir: Direction) -> Self::NeighborsDirected {
@@ -1071,17 +1334,20 @@ where
     }
 }
 
-impl<N, E, Ty> visit::EdgeIndexable for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::EdgeIndexable for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     fn edge_bound(&self) -> usize {
         self.edge_count()
     }
 
+    /// For an undirected graph, `(a, b)` and `(b, a)` are the same edge and
+    /// have the same index.
     fn to_index(&self, ix: Self::EdgeId) -> usize {
-        let (i, _, _) = self.edges.get_full(&ix).unwrap();
+        let (i, _, _) = self.edges.get_full(&Self::edge_key(ix.0, ix.1)).unwrap();
         i
     }
 
@@ -1096,32 +1362,35 @@ where
     }
 }
 
-impl<'a, N: 'a, E: 'a, Ty> visit::IntoEdges for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdges for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
-    type Edges = Edges<'a, N, E, Ty>;
+    type Edges = Edges<'a, N, E, Ty, S>;
     fn edges(self, a: Self::NodeId) -> Self::Edges {
         self.edges(a)
     }
 }
 
-impl<'a, N: 'a, E: 'a, Ty> visit::IntoEdgesDirected for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdgesDirected for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
-    type EdgesDirected = EdgesDirected<'a, N, E, Ty>;
+    type EdgesDirected = EdgesDirected<'a, N, E, Ty, S>;
     fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
         self.edges_directed(a, dir)
     }
 }
 
-impl<'a, N: 'a, E: 'a, Ty> visit::IntoEdgeReferences for &'a GraphMap<N, E, Ty>
+impl<'a, N: 'a, E: 'a, Ty, S> visit::IntoEdgeReferences for &'a GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type EdgeRef = (N, N, &'a E);
     type EdgeReferences = AllEdges<'a, N, E, Ty>;
@@ -1130,10 +1399,11 @@ where
     }
 }
 
-impl<N, E, Ty> visit::EdgeCount for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::EdgeCount for GraphMap<N, E, Ty, S>
 where
     N: NodeTrait,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     #[inline]
     fn edge_count(&self) -> usize {
@@ -1141,11 +1411,29 @@ where
     }
 }
 
+impl<N, E, Ty, S> visit::NodeDegrees for GraphMap<N, E, Ty, S>
+where
+    N: NodeTrait,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    /// Computes in **O(1)** time for an undirected graph, and otherwise in
+    /// **O(e')** time, where **e'** is the number of edges of `a`.
+    fn degree_directed(&self, a: N, dir: Direction) -> usize {
+        if Ty::is_directed() {
+            self.neighbors_directed(a, dir).count()
+        } else {
+            self.nodes.get(&a).map_or(0, Vec::len)
+        }
+    }
+}
+
 /// The `GraphMap` keeps an adjacency matrix internally.
-impl<N, E, Ty> visit::GetAdjacencyMatrix for GraphMap<N, E, Ty>
+impl<N, E, Ty, S> visit::GetAdjacencyMatrix for GraphMap<N, E, Ty, S>
 where
     N: Copy + Ord + Hash,
     Ty: EdgeType,
+    S: BuildHasher,
 {
     type AdjMatrix = ();
     #[inline]
@@ -0,0 +1,321 @@
+//! `MultiGraphMap<N, K, E, Ty>` is a `GraphMap` with parallel edges, told
+//! apart by an edge key.
+
+use indexmap::IndexMap;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+
+use super::{GraphMap, NodeTrait, Nodes};
+use crate::{Directed, Direction, EdgeType, Incoming, Outgoing, Undirected};
+
+/// A `MultiGraphMap` with undirected edges.
+pub type UnMultiGraphMap<N, K, E, S = RandomState> = MultiGraphMap<N, K, E, Undirected, S>;
+/// A `MultiGraphMap` with directed edges.
+pub type DiMultiGraphMap<N, K, E, S = RandomState> = MultiGraphMap<N, K, E, Directed, S>;
+
+/// `MultiGraphMap<N, K, E, Ty>` is a graph datastructure where node values
+/// are mapping keys, like `GraphMap`, that allows parallel edges.
+///
+/// Each edge is identified by its endpoints and an edge key `K`, so there can
+/// be any number of edges between two nodes, as long as their keys differ.
+/// The edges between two nodes are kept in insertion order.
+///
+/// `MultiGraphMap` is parameterized over:
+///
+/// - Node identifiers `N`, as for `GraphMap`.
+/// - Edge keys `K`, which must be suitable as hash table keys (implementing
+///   `Eq + Hash`).
+/// - Associated data `E` for edges, of arbitrary type.
+/// - Edge type `Ty` that determines whether the graph edges are directed or
+///   undirected.
+/// - The hash builder `S` of the underlying `GraphMap`.
+///
+/// It is backed by a `GraphMap` with an edge for each connected pair of
+/// nodes, whose weight is the map from the keys to the weights of the edges
+/// between them. Use [`as_graph_map`](#method.as_graph_map) to run
+/// algorithms on it.
+///
+/// Depends on crate feature `graphmap` (default).
+///
+/// ```
+/// use petgraph::graphmap::UnMultiGraphMap;
+///
+/// // flights between airports, keyed by flight number
+/// let mut flights = UnMultiGraphMap::new();
+/// flights.add_edge("LHR", "JFK", "BA117", 415);
+/// flights.add_edge("LHR", "JFK", "VS3", 420);
+/// flights.add_edge("JFK", "SFO", "UA535", 380);
+///
+/// assert_eq!(flights.edge_count(), 3);
+/// assert_eq!(flights.edges_between("JFK", "LHR").count(), 2);
+/// assert_eq!(flights.edge_weight("JFK", "LHR", &"VS3"), Some(&420));
+/// assert_eq!(flights.neighbors("JFK").count(), 2);
+/// ```
+#[derive(Clone)]
+pub struct MultiGraphMap<N, K, E, Ty, S = RandomState> {
+    graph: GraphMap<N, IndexMap<K, E>, Ty, S>,
+    edge_count: usize,
+}
+
+impl<N, K, E, Ty, S> fmt::Debug for MultiGraphMap<N, K, E, Ty, S>
+where
+    N: Eq + Hash + fmt::Debug,
+    K: fmt::Debug,
+    E: fmt::Debug,
+    Ty: EdgeType,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph.fmt(f)
+    }
+}
+
+impl<N, K, E, Ty> MultiGraphMap<N, K, E, Ty>
+where
+    N: NodeTrait,
+    K: Eq + Hash,
+    Ty: EdgeType,
+{
+    /// Create a new `MultiGraphMap`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new `MultiGraphMap` with estimated capacity, where `pairs`
+    /// is the number of connected pairs of nodes.
+    pub fn with_capacity(nodes: usize, pairs: usize) -> Self {
+        Self::with_capacity_and_hasher(nodes, pairs, RandomState::new())
+    }
+}
+
+impl<N, K, E, Ty, S> MultiGraphMap<N, K, E, Ty, S>
+where
+    N: NodeTrait,
+    K: Eq + Hash,
+    Ty: EdgeType,
+    S: BuildHasher,
+{
+    /// Create a new `MultiGraphMap` which uses the given hash builder to hash
+    /// the nodes.
+    pub fn with_hasher(hash_builder: S) -> Self
+    where
+        S: Clone,
+    {
+        Self::with_capacity_and_hasher(0, 0, hash_builder)
+    }
+
+    /// Create a new `MultiGraphMap` with estimated capacity, which uses the
+    /// given hash builder to hash the nodes.
+    pub fn with_capacity_and_hasher(nodes: usize, pairs: usize, hash_builder: S) -> Self
+    where
+        S: Clone,
+    {
+        MultiGraphMap {
+            graph: GraphMap::with_capacity_and_hasher(nodes, pairs, hash_builder),
+            edge_count: 0,
+        }
+    }
+
+    /// Whether the graph has directed edges.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+
+    /// Return the underlying `GraphMap`, with an edge for each connected pair
+    /// of nodes.
+    pub fn as_graph_map(&self) -> &GraphMap<N, IndexMap<K, E>, Ty, S> {
+        &self.graph
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// Return the number of edges in the graph, counting parallel edges.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Remove all nodes and edges
+    pub fn clear(&mut self) {
+        self.graph.clear();
+        self.edge_count = 0;
+    }
+
+    /// Add node `n` to the graph.
+    pub fn add_node(&mut self, n: N) -> N {
+        self.graph.add_node(n)
+    }
+
+    /// Return `true` if node `n` was removed, along with its edges.
+    ///
+    /// Computes in **O(V)** time, due to the removal of edges with other nodes.
+    pub fn remove_node(&mut self, n: N) -> bool {
+        let removed: usize = if Ty::is_directed() {
+            let outgoing = self.graph.edges_directed(n, Outgoing);
+            // self loops are both outgoing and incoming
+            let incoming = self
+                .graph
+                .edges_directed(n, Incoming)
+                .filter(|&(a, b, _)| a != b);
+            outgoing
+                .chain(incoming)
+                .map(|(_, _, edges)| edges.len())
+                .sum()
+        } else {
+            self.graph.edges(n).map(|(_, _, edges)| edges.len()).sum()
+        };
+        if self.graph.remove_node(n) {
+            self.edge_count -= removed;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Return `true` if the node is contained in the graph.
+    pub fn contains_node(&self, n: N) -> bool {
+        self.graph.contains_node(n)
+    }
+
+    /// Add an edge with key `key` connecting `a` and `b` to the graph, with
+    /// associated data `weight`. For a directed graph, the edge is directed
+    /// from `a` to `b`.
+    ///
+    /// Inserts nodes `a` and/or `b` if they aren't already part of the graph.
+    ///
+    /// Return `None` if the edge did not previously exist, otherwise, the
+    /// associated data is updated and the old value is returned as
+    /// `Some(old_weight)`.
+    pub fn add_edge(&mut self, a: N, b: N, key: K, weight: E) -> Option<E> {
+        let old = match self.graph.edge_weight_mut(a, b) {
+            Some(edges) => edges.insert(key, weight),
+            None => {
+                let mut edges = IndexMap::with_capacity(1);
+                edges.insert(key, weight);
+                self.graph.add_edge(a, b, edges);
+                None
+            }
+        };
+        if old.is_none() {
+            self.edge_count += 1;
+        }
+        old
+    }
+
+    /// Remove the edge with key `key` connecting `a` and `b` from the graph
+    /// and return the edge weight.
+    ///
+    /// Return `None` if the edge didn't exist.
+    pub fn remove_edge(&mut self, a: N, b: N, key: &K) -> Option<E> {
+        let edges = self.graph.edge_weight_mut(a, b)?;
+        let weight = edges.shift_remove(key)?;
+        if edges.is_empty() {
+            self.graph.remove_edge(a, b);
+        }
+        self.edge_count -= 1;
+        Some(weight)
+    }
+
+    /// Remove all edges connecting `a` and `b` from the graph, and return
+    /// them with their keys, in insertion order.
+    ///
+    /// Return `None` if there were no edges.
+    pub fn remove_edges_between(&mut self, a: N, b: N) -> Option<IndexMap<K, E>> {
+        let edges = self.graph.remove_edge(a, b)?;
+        self.edge_count -= edges.len();
+        Some(edges)
+    }
+
+    /// Return `true` if the edge with key `key` connecting `a` with `b` is
+    /// contained in the graph.
+    pub fn contains_edge(&self, a: N, b: N, key: &K) -> bool {
+        self.edge_weight(a, b, key).is_some()
+    }
+
+    /// Return a reference to the weight of the edge with key `key`
+    /// connecting `a` with `b`, or `None` if the edge does not exist in the
+    /// graph.
+    pub fn edge_weight(&self, a: N, b: N, key: &K) -> Option<&E> {
+        self.graph.edge_weight(a, b)?.get(key)
+    }
+
+    /// Return a mutable reference to the weight of the edge with key `key`
+    /// connecting `a` with `b`, or `None` if the edge does not exist in the
+    /// graph.
+    pub fn edge_weight_mut(&mut self, a: N, b: N, key: &K) -> Option<&mut E> {
+        self.graph.edge_weight_mut(a, b)?.get_mut(key)
+    }
+
+    /// Return an iterator over the nodes of the graph.
+    ///
+    /// Iterator element type is `N`.
+    pub fn nodes(&self) -> Nodes<'_, N> {
+        self.graph.nodes()
+    }
+
+    /// Return an iterator of all nodes with an edge starting from `a`, each
+    /// neighbor once however many edges connect them.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `N`.
+    pub fn neighbors(&self, a: N) -> impl Iterator<Item = N> + '_ {
+        self.graph.neighbors(a)
+    }
+
+    /// Return an iterator of all neighbors that have an edge between them and
+    /// `a`, in the specified direction, each neighbor once.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `N`.
+    pub fn neighbors_directed(&self, a: N, dir: Direction) -> impl Iterator<Item = N> + '_ {
+        self.graph.neighbors_directed(a, dir)
+    }
+
+    /// Return an iterator of the edges connecting `a` and `b`, as their keys
+    /// and weights in insertion order.
+    ///
+    /// Iterator element type is `(&K, &E)`.
+    pub fn edges_between(&self, a: N, b: N) -> impl Iterator<Item = (&K, &E)> {
+        self.graph.edge_weight(a, b).into_iter().flatten()
+    }
+
+    /// Return an iterator of the edges starting from `a`.
+    ///
+    /// - `Directed`: Outgoing edges from `a`.
+    /// - `Undirected`: All edges from or to `a`.
+    ///
+    /// Produces an empty iterator if the node doesn't exist.<br>
+    /// Iterator element type is `(N, N, &K, &E)`.
+    pub fn edges(&self, a: N) -> impl Iterator<Item = (N, N, &K, &E)> {
+        self.graph
+            .edges(a)
+            .flat_map(|(a, b, edges)| edges.iter().map(move |(k, w)| (a, b, k, w)))
+    }
+
+    /// Return an iterator over all edges of the graph with their keys and
+    /// weights, in arbitrary order.
+    ///
+    /// Iterator element type is `(N, N, &K, &E)`.
+    pub fn all_edges(&self) -> impl Iterator<Item = (N, N, &K, &E)> {
+        self.graph
+            .all_edges()
+            .flat_map(|(a, b, edges)| edges.iter().map(move |(k, w)| (a, b, k, w)))
+    }
+}
+
+/// Create a new empty `MultiGraphMap`.
+impl<N, K, E, Ty> Default for MultiGraphMap<N, K, E, Ty>
+where
+    N: NodeTrait,
+    K: Eq + Hash,
+    Ty: EdgeType,
+{
+    fn default() -> Self {
+        MultiGraphMap::with_capacity(0, 0)
+    }
+}
@@ -0,0 +1,1033 @@
+//! [GraphML](http://graphml.graphdrawing.org/) output and reading.
+//!
+//! GraphML is an XML format for graphs, with typed attributes on the graph,
+//! its nodes and its edges. Gephi, yEd and NetworkX read and write it.
+//!
+//! [`GraphMl`] writes a graph, with the attributes made by closures, and
+//! [`parse`] and [`parse_with`] read a graph back.
+//!
+//! [`GraphMl`]: struct.GraphMl.html
+//! [`parse`]: fn.parse.html
+//! [`parse_with`]: fn.parse_with.html
+//!
+//! ```
+//! use petgraph::graphml::{parse_with, AttributeMap, AttributeValue, GraphMl};
+//! use petgraph::visit::{EdgeRef, NodeRef};
+//! use petgraph::{Directed, Graph};
+//!
+//! let mut graph = Graph::<&str, f64>::new();
+//! let a = graph.add_node("a");
+//! let b = graph.add_node("b");
+//! graph.add_edge(a, b, 2.5);
+//!
+//! let node_attributes = |_, node: (_, &&str)| {
+//!     let mut attributes = AttributeMap::new();
+//!     attributes.insert("name".to_string(), node.weight().to_string().into());
+//!     attributes
+//! };
+//! let edge_attributes = |_, edge: petgraph::graph::EdgeReference<f64>| {
+//!     let mut attributes = AttributeMap::new();
+//!     attributes.insert("weight".to_string(), (*edge.weight()).into());
+//!     attributes
+//! };
+//! let graphml = GraphMl::new(&graph)
+//!     .with_node_attributes(&node_attributes)
+//!     .with_edge_attributes(&edge_attributes)
+//!     .to_string();
+//! assert!(graphml.contains(r#"<key id="d1" for="edge" attr.name="weight" attr.type="double"/>"#));
+//!
+//! let parsed = parse_with::<_, _, Directed, _, _>(
+//!     &graphml,
+//!     |node| node.attributes["name"].to_string(),
+//!     |edge| match edge["weight"] {
+//!         AttributeValue::Double(weight) => weight,
+//!         _ => unreachable!(),
+//!     },
+//! )
+//! .unwrap();
+//! assert_eq!(parsed.graph.node_weights().collect::<Vec<_>>(), ["a", "b"]);
+//! assert_eq!(parsed.graph.edge_weights().collect::<Vec<_>>(), [&2.5]);
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+
+use indexmap::IndexMap;
+
+use crate::graph::{Graph, NodeIndex};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+use crate::EdgeType;
+
+/// The value of a GraphML attribute, of one of the GraphML attribute types.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    /// A `boolean`.
+    Boolean(bool),
+    /// An `int`.
+    Int(i32),
+    /// A `long`.
+    Long(i64),
+    /// A `float`.
+    Float(f32),
+    /// A `double`.
+    Double(f64),
+    /// A `string`.
+    String(String),
+}
+
+/// Attributes by name, in the order they were first set.
+pub type AttributeMap = IndexMap<String, AttributeValue>;
+
+impl AttributeValue {
+    /// The GraphML name of the type of the value, like `"double"`.
+    pub fn type_name(&self) -> &'static str {
+        match *self {
+            AttributeValue::Boolean(_) => "boolean",
+            AttributeValue::Int(_) => "int",
+            AttributeValue::Long(_) => "long",
+            AttributeValue::Float(_) => "float",
+            AttributeValue::Double(_) => "double",
+            AttributeValue::String(_) => "string",
+        }
+    }
+
+    /// Parse `text` as a value of the GraphML type `type_name`.
+    fn parse(type_name: &str, text: &str) -> Option<Self> {
+        let value = text.trim();
+        Some(match type_name {
+            "boolean" => AttributeValue::Boolean(match value {
+                "true" | "1" => true,
+                "false" | "0" => false,
+                _ => return None,
+            }),
+            "int" => AttributeValue::Int(value.parse().ok()?),
+            "long" => AttributeValue::Long(value.parse().ok()?),
+            "float" => AttributeValue::Float(parse_float(value)? as f32),
+            "double" => AttributeValue::Double(parse_float(value)?),
+            _ => AttributeValue::String(text.to_string()),
+        })
+    }
+}
+
+fn parse_float(value: &str) -> Option<f64> {
+    match value {
+        "INF" => Some(std::f64::INFINITY),
+        "-INF" => Some(std::f64::NEG_INFINITY),
+        _ => value.parse().ok(),
+    }
+}
+
+/// Write a float the way GraphML spells it, with `INF` for infinity.
+fn fmt_float(value: f64, f: &mut fmt::Formatter) -> fmt::Result {
+    if value.is_infinite() {
+        f.write_str(if value > 0. { "INF" } else { "-INF" })
+    } else {
+        write!(f, "{}", value)
+    }
+}
+
+impl Display for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AttributeValue::Boolean(value) => write!(f, "{}", value),
+            AttributeValue::Int(value) => write!(f, "{}", value),
+            AttributeValue::Long(value) => write!(f, "{}", value),
+            AttributeValue::Float(value) => fmt_float(value.into(), f),
+            AttributeValue::Double(value) => fmt_float(value, f),
+            AttributeValue::String(ref value) => f.write_str(value),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($t:ty => $variant:ident,)*) => {
+        $(
+            impl From<$t> for AttributeValue {
+                fn from(value: $t) -> Self {
+                    AttributeValue::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from! {
+    bool => Boolean,
+    i32 => Int,
+    i64 => Long,
+    f32 => Float,
+    f64 => Double,
+    String => String,
+    &str => String,
+}
+
+/// `GraphMl` implements output to GraphML for a graph.
+///
+/// Node `n` is written with the ID `n{i}`, where `i` is its index from
+/// `NodeIndexable`. The attributes of the graph, its nodes and its edges are
+/// declared as keys by their name and the type of their first value; later
+/// values of the same attribute should have the same type.
+///
+/// See the [module documentation](index.html) for an example.
+pub struct GraphMl<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    get_node_attributes: Option<&'a dyn Fn(G, G::NodeRef) -> AttributeMap>,
+    get_edge_attributes: Option<&'a dyn Fn(G, G::EdgeRef) -> AttributeMap>,
+    graph_attributes: AttributeMap,
+}
+
+impl<'a, G> GraphMl<'a, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+{
+    /// Create a `GraphMl` formatting wrapper, with no attributes.
+    pub fn new(graph: G) -> Self {
+        GraphMl {
+            graph,
+            get_node_attributes: None,
+            get_edge_attributes: None,
+            graph_attributes: AttributeMap::new(),
+        }
+    }
+
+    /// Set the attributes of each node.
+    pub fn with_node_attributes(
+        mut self,
+        get_node_attributes: &'a dyn Fn(G, G::NodeRef) -> AttributeMap,
+    ) -> Self {
+        self.get_node_attributes = Some(get_node_attributes);
+        self
+    }
+
+    /// Set the attributes of each edge.
+    pub fn with_edge_attributes(
+        mut self,
+        get_edge_attributes: &'a dyn Fn(G, G::EdgeRef) -> AttributeMap,
+    ) -> Self {
+        self.get_edge_attributes = Some(get_edge_attributes);
+        self
+    }
+
+    /// Add an attribute of the graph itself.
+    pub fn with_graph_attribute(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<AttributeValue>,
+    ) -> Self {
+        self.graph_attributes.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// The keys of the attributes of one kind of element, by name, with their ID
+/// number and type.
+type Keys<'b> = IndexMap<&'b str, (usize, &'static str)>;
+
+fn collect_keys<'b, I>(maps: I, first_id: usize) -> Keys<'b>
+where
+    I: IntoIterator<Item = &'b AttributeMap>,
+{
+    let mut keys = Keys::new();
+    for map in maps {
+        for (name, value) in map {
+            let id = first_id + keys.len();
+            keys.entry(name).or_insert((id, value.type_name()));
+        }
+    }
+    keys
+}
+
+/// Write `attributes` as `data` elements, indented by `indent`.
+fn data_fmt(
+    f: &mut fmt::Formatter,
+    indent: &str,
+    keys: &Keys,
+    attributes: &AttributeMap,
+) -> fmt::Result {
+    for (name, value) in attributes {
+        writeln!(
+            f,
+            "{}<data key=\"d{}\">{}</data>",
+            indent,
+            keys[&**name].0,
+            Escaped(value)
+        )?;
+    }
+    Ok(())
+}
+
+impl<'a, G> Display for GraphMl<'a, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let g = self.graph;
+        let nodes = g
+            .node_references()
+            .map(|node| {
+                let attributes = match self.get_node_attributes {
+                    Some(get_attributes) => get_attributes(g, node),
+                    None => AttributeMap::new(),
+                };
+                (g.to_index(node.id()), attributes)
+            })
+            .collect::<Vec<_>>();
+        let edges = g
+            .edge_references()
+            .map(|edge| {
+                let attributes = match self.get_edge_attributes {
+                    Some(get_attributes) => get_attributes(g, edge),
+                    None => AttributeMap::new(),
+                };
+                (
+                    g.to_index(edge.source()),
+                    g.to_index(edge.target()),
+                    attributes,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let graph_keys = collect_keys(Some(&self.graph_attributes), 0);
+        let node_keys = collect_keys(nodes.iter().map(|n| &n.1), graph_keys.len());
+        let edge_keys = collect_keys(
+            edges.iter().map(|e| &e.2),
+            graph_keys.len() + node_keys.len(),
+        );
+
+        writeln!(f, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(
+            f,
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">"
+        )?;
+        for (kind, keys) in &[
+            ("graph", &graph_keys),
+            ("node", &node_keys),
+            ("edge", &edge_keys),
+        ] {
+            for (name, &(id, type_name)) in keys.iter() {
+                writeln!(
+                    f,
+                    "{}<key id=\"d{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+                    INDENT,
+                    id,
+                    kind,
+                    Escaped(name),
+                    type_name
+                )?;
+            }
+        }
+        let edge_default = if g.is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        writeln!(f, "{}<graph edgedefault=\"{}\">", INDENT, edge_default)?;
+        data_fmt(f, &INDENT.repeat(2), &graph_keys, &self.graph_attributes)?;
+        for (index, attributes) in &nodes {
+            if attributes.is_empty() {
+                writeln!(f, "{0}{0}<node id=\"n{1}\"/>", INDENT, index)?;
+            } else {
+                writeln!(f, "{0}{0}<node id=\"n{1}\">", INDENT, index)?;
+                data_fmt(f, &INDENT.repeat(3), &node_keys, attributes)?;
+                writeln!(f, "{0}{0}</node>", INDENT)?;
+            }
+        }
+        for (source, target, attributes) in &edges {
+            write!(
+                f,
+                "{0}{0}<edge source=\"n{1}\" target=\"n{2}\"",
+                INDENT, source, target
+            )?;
+            if attributes.is_empty() {
+                writeln!(f, "/>")?;
+            } else {
+                writeln!(f, ">")?;
+                data_fmt(f, &INDENT.repeat(3), &edge_keys, attributes)?;
+                writeln!(f, "{0}{0}</edge>", INDENT)?;
+            }
+        }
+        writeln!(f, "{}</graph>", INDENT)?;
+        writeln!(f, "</graphml>")
+    }
+}
+
+static INDENT: &str = "  ";
+
+/// Escape the characters with a special meaning in XML.
+struct Escaper<W>(W);
+
+impl<W> fmt::Write for Escaper<W>
+where
+    W: fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '&' => self.0.write_str("&amp;"),
+            '<' => self.0.write_str("&lt;"),
+            '>' => self.0.write_str("&gt;"),
+            '"' => self.0.write_str("&quot;"),
+            '\'' => self.0.write_str("&apos;"),
+            _ => self.0.write_char(c),
+        }
+    }
+}
+
+/// Pass Display formatting through a simple escaping filter
+struct Escaped<T>(T);
+
+impl<T> Display for Escaped<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(&mut Escaper(f), "{}", &self.0)
+    }
+}
+
+/// A node read from GraphML.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphMlNode {
+    /// The node ID in the GraphML document.
+    pub id: String,
+    /// The attributes of the node.
+    pub attributes: AttributeMap,
+}
+
+/// A graph read from GraphML, by [`parse`] or [`parse_with`].
+///
+/// [`parse`]: fn.parse.html
+/// [`parse_with`]: fn.parse_with.html
+#[derive(Clone, Debug)]
+pub struct ParsedGraph<N, E, Ty: EdgeType> {
+    /// The ID of the graph, if it has one.
+    pub id: Option<String>,
+    /// The attributes of the graph itself.
+    pub attributes: AttributeMap,
+    /// The nodes and edges, in the order they appear in the document.
+    pub graph: Graph<N, E, Ty>,
+}
+
+/// An error reading GraphML, at a line and column of the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    kind: ParseErrorKind,
+}
+
+/// The kind of a [`ParseError`].
+///
+/// [`ParseError`]: struct.ParseError.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input ended in the middle of the document.
+    UnexpectedEnd,
+    /// The input is not well-formed XML.
+    Syntax,
+    /// This element has no attribute with this name, which GraphML requires.
+    MissingAttribute(&'static str),
+    /// There is no key with this ID.
+    UnknownKey(String),
+    /// There is no node with this ID.
+    UnknownNode(String),
+    /// There already is a node with this ID.
+    DuplicateNode(String),
+    /// This text is not a value of the type of its key.
+    InvalidValue(String),
+    /// The graph or an edge is directed, and the graph was read as
+    /// undirected, or the other way around.
+    GraphType,
+    /// The graph has a hyperedge, which a `Graph` can't hold.
+    Hyperedge,
+}
+
+impl ParseError {
+    /// The line of the error, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the error, in characters counting from 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// What the error is.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match self.kind {
+            ParseErrorKind::UnexpectedEnd => f.write_str("unexpected end of input"),
+            ParseErrorKind::Syntax => f.write_str("malformed XML"),
+            ParseErrorKind::MissingAttribute(name) => {
+                write!(f, "the element has no `{}` attribute", name)
+            }
+            ParseErrorKind::UnknownKey(ref id) => write!(f, "there is no key {:?}", id),
+            ParseErrorKind::UnknownNode(ref id) => write!(f, "there is no node {:?}", id),
+            ParseErrorKind::DuplicateNode(ref id) => {
+                write!(f, "there already is a node {:?}", id)
+            }
+            ParseErrorKind::InvalidValue(ref text) => {
+                write!(f, "{:?} is not a value of the type of its key", text)
+            }
+            ParseErrorKind::GraphType => {
+                f.write_str("the graph is not of the expected directed or undirected type")
+            }
+            ParseErrorKind::Hyperedge => f.write_str("hyperedges are not supported"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Read a graph from GraphML, with the IDs and attributes of the nodes and
+/// the attributes of the edges as weights.
+///
+/// `Ty` picks whether a directed or an undirected graph is expected; the
+/// other is an error, and so is an edge with a `directed` attribute that
+/// doesn't match.
+///
+/// Attributes are read with the types of their keys, and keys with a
+/// default value give it to the elements without the attribute. Nested
+/// graphs are read into the one graph, and ports are skipped.
+pub fn parse<Ty: EdgeType>(
+    input: &str,
+) -> Result<ParsedGraph<GraphMlNode, AttributeMap, Ty>, ParseError> {
+    let mut reader = Reader { input, pos: 0 };
+    let mut parser = Parser {
+        keys: HashMap::new(),
+        key_order: Vec::new(),
+        graph: Graph::default(),
+        node_ids: HashMap::new(),
+        edges: Vec::new(),
+        id: None,
+        attributes: AttributeMap::new(),
+        seen_graph: false,
+    };
+    parser
+        .parse(&mut reader)
+        .map_err(|(pos, kind)| position_error(input, pos, kind))?;
+    let Parser {
+        keys,
+        key_order,
+        mut graph,
+        node_ids,
+        edges,
+        id,
+        mut attributes,
+        ..
+    } = parser;
+    for (pos, source, target, edge_attributes) in edges {
+        let node = |id: String| match node_ids.get(&id) {
+            Some(&node) => Ok(node),
+            None => Err(position_error(input, pos, ParseErrorKind::UnknownNode(id))),
+        };
+        let (a, b) = (node(source)?, node(target)?);
+        graph.add_edge(a, b, edge_attributes);
+    }
+    let defaults = |kind: &str, attributes: &mut AttributeMap| {
+        for key in key_order.iter().map(|id| &keys[id]) {
+            if key.kind != kind && key.kind != "all" || attributes.contains_key(&key.name) {
+                continue;
+            }
+            if let Some(ref default) = key.default {
+                attributes.insert(key.name.clone(), default.clone());
+            }
+        }
+    };
+    for node in graph.node_weights_mut() {
+        defaults("node", &mut node.attributes);
+    }
+    for edge in graph.edge_weights_mut() {
+        defaults("edge", edge);
+    }
+    defaults("graph", &mut attributes);
+    Ok(ParsedGraph {
+        id,
+        attributes,
+        graph,
+    })
+}
+
+/// Read a graph from GraphML, like [`parse`], and make the node and edge
+/// weights with `node_weight` and `edge_weight`.
+///
+/// [`parse`]: fn.parse.html
+pub fn parse_with<N, E, Ty, NF, EF>(
+    input: &str,
+    mut node_weight: NF,
+    mut edge_weight: EF,
+) -> Result<ParsedGraph<N, E, Ty>, ParseError>
+where
+    Ty: EdgeType,
+    NF: FnMut(&GraphMlNode) -> N,
+    EF: FnMut(&AttributeMap) -> E,
+{
+    let parsed = parse::<Ty>(input)?;
+    Ok(ParsedGraph {
+        id: parsed.id,
+        attributes: parsed.attributes,
+        graph: parsed
+            .graph
+            .map(|_, node| node_weight(node), |_, edge| edge_weight(edge)),
+    })
+}
+
+/// The error at byte offset `pos` of `input`.
+fn position_error(input: &str, pos: usize, kind: ParseErrorKind) -> ParseError {
+    let before = &input[..pos];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+    ParseError { line, column, kind }
+}
+
+/// An error at a byte offset of the input.
+type PosError = (usize, ParseErrorKind);
+
+#[derive(Debug)]
+enum Event<'a> {
+    Start {
+        name: &'a str,
+        attributes: Vec<(&'a str, String)>,
+        empty: bool,
+    },
+    End(&'a str),
+    Text(String),
+}
+
+/// A reader of the events of an XML document, without namespace prefixes.
+struct Reader<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    /// Skip past the next `pattern`.
+    fn skip_past(&mut self, pattern: &str) -> Result<(), PosError> {
+        match self.rest().find(pattern) {
+            Some(i) => {
+                self.pos += i + pattern.len();
+                Ok(())
+            }
+            None => Err((self.input.len(), ParseErrorKind::UnexpectedEnd)),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// A name, without its namespace prefix.
+    fn name(&mut self) -> Result<&'a str, PosError> {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '/' || c == '>' || c == '=')
+            .unwrap_or(rest.len());
+        if end == 0 {
+            return Err((self.pos, ParseErrorKind::Syntax));
+        }
+        self.pos += end;
+        let name = &rest[..end];
+        Ok(name.rsplit(':').next().unwrap_or(name))
+    }
+
+    fn next(&mut self) -> Result<Option<Event<'a>>, PosError> {
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Ok(None);
+            }
+            if !rest.starts_with('<') {
+                let end = rest.find('<').unwrap_or(rest.len());
+                let text =
+                    unescape(&rest[..end]).map_err(|i| (self.pos + i, ParseErrorKind::Syntax))?;
+                self.pos += end;
+                return Ok(Some(Event::Text(text)));
+            }
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                let start = self.pos;
+                self.skip_past("]]>")?;
+                let text = &self.input[start..self.pos - "]]>".len()];
+                return Ok(Some(Event::Text(text.to_string())));
+            } else if rest.starts_with("<!") {
+                // a doctype, with an optional internal subset in brackets
+                let mut depth = 0;
+                let end = rest.char_indices().find(|&(_, c)| {
+                    match c {
+                        '[' => depth += 1,
+                        ']' => depth -= 1,
+                        '>' if depth == 0 => return true,
+                        _ => {}
+                    }
+                    false
+                });
+                match end {
+                    Some((i, _)) => self.pos += i + 1,
+                    None => return Err((self.input.len(), ParseErrorKind::UnexpectedEnd)),
+                }
+            } else if rest.starts_with("</") {
+                self.pos += 2;
+                let name = self.name()?;
+                self.skip_whitespace();
+                if !self.rest().starts_with('>') {
+                    return Err(self.syntax_or_end());
+                }
+                self.pos += 1;
+                return Ok(Some(Event::End(name)));
+            } else {
+                self.pos += 1;
+                return self.start_tag().map(Some);
+            }
+        }
+    }
+
+    fn start_tag(&mut self) -> Result<Event<'a>, PosError> {
+        let name = self.name()?;
+        let mut attributes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(Event::Start {
+                    name,
+                    attributes,
+                    empty: true,
+                });
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                return Ok(Event::Start {
+                    name,
+                    attributes,
+                    empty: false,
+                });
+            }
+            let attribute = self.name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.syntax_or_end());
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote) if quote == '"' || quote == '\'' => quote,
+                _ => return Err(self.syntax_or_end()),
+            };
+            self.pos += 1;
+            let start = self.pos;
+            let end = match self.rest().find(quote) {
+                Some(end) => end,
+                None => return Err((self.input.len(), ParseErrorKind::UnexpectedEnd)),
+            };
+            let value = unescape(&self.input[start..start + end])
+                .map_err(|i| (start + i, ParseErrorKind::Syntax))?;
+            self.pos += end + 1;
+            attributes.push((attribute, value));
+        }
+    }
+
+    fn syntax_or_end(&self) -> PosError {
+        if self.rest().is_empty() {
+            (self.pos, ParseErrorKind::UnexpectedEnd)
+        } else {
+            (self.pos, ParseErrorKind::Syntax)
+        }
+    }
+}
+
+/// Replace the character and entity references in `text`, or return the
+/// offset of an invalid one.
+fn unescape(text: &str) -> Result<String, usize> {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        result.push_str(&rest[..i]);
+        let offset = text.len() - rest.len() + i;
+        let end = rest[i..].find(';').ok_or(offset)?;
+        let entity = &rest[i + 1..i + end];
+        let c = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16)
+                .ok()
+                .and_then(std::char::from_u32)
+                .ok_or(offset)?,
+            _ if entity.starts_with('#') => entity[1..]
+                .parse()
+                .ok()
+                .and_then(std::char::from_u32)
+                .ok_or(offset)?,
+            _ => return Err(offset),
+        };
+        result.push(c);
+        rest = &rest[i + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+struct Key {
+    /// The kind of element of the key: `graph`, `node`, `edge` or `all`.
+    kind: String,
+    name: String,
+    type_name: String,
+    default: Option<AttributeValue>,
+}
+
+/// The element that the `data` in it belong to.
+#[derive(Copy, Clone)]
+enum Owner {
+    /// The outermost graph.
+    Graph,
+    Node(NodeIndex),
+    /// An edge, by its index in `Parser::edges`.
+    Edge(usize),
+    /// A key, by its index in `Parser::key_order`, whose `default` is read.
+    Key(usize),
+    /// A nested graph, a port, or another element.
+    Other,
+}
+
+/// The text of a `data` or `default` element being read.
+struct Capture {
+    pos: usize,
+    owner: Owner,
+    /// The ID of the key of a `data` element.
+    key: Option<String>,
+    text: String,
+    /// The number of elements open inside of it.
+    depth: usize,
+}
+
+struct Parser<Ty> {
+    keys: HashMap<String, Key>,
+    /// The IDs of the keys, in the order of the document.
+    key_order: Vec<String>,
+    graph: Graph<GraphMlNode, AttributeMap, Ty>,
+    node_ids: HashMap<String, NodeIndex>,
+    /// The edges, with their position, until all nodes are known.
+    edges: Vec<(usize, String, String, AttributeMap)>,
+    id: Option<String>,
+    attributes: AttributeMap,
+    seen_graph: bool,
+}
+
+fn attribute<'b>(attributes: &'b [(&str, String)], name: &str) -> Option<&'b str> {
+    attributes.iter().find(|a| a.0 == name).map(|a| &*a.1)
+}
+
+impl<Ty: EdgeType> Parser<Ty> {
+    fn parse(&mut self, reader: &mut Reader) -> Result<(), PosError> {
+        let mut open = Vec::new();
+        let mut capture: Option<Capture> = None;
+        loop {
+            let pos = reader.pos;
+            let event = match reader.next()? {
+                Some(event) => event,
+                None => break,
+            };
+            if let Some(current) = &mut capture {
+                match event {
+                    Event::Text(text) => current.text.push_str(&text),
+                    Event::Start { empty: false, .. } => current.depth += 1,
+                    Event::Start { empty: true, .. } => {}
+                    Event::End(_) if current.depth > 0 => current.depth -= 1,
+                    Event::End(_) => {
+                        if let Some(current) = capture.take() {
+                            self.finish(current)?;
+                        }
+                    }
+                }
+                continue;
+            }
+            match event {
+                Event::Text(_) => {}
+                Event::End(name) => match open.pop() {
+                    Some((open_name, _)) if open_name == name => {}
+                    _ => return Err((pos, ParseErrorKind::Syntax)),
+                },
+                Event::Start {
+                    name,
+                    attributes,
+                    empty,
+                } => {
+                    let parent = open.last().map_or(Owner::Other, |&(_, owner)| owner);
+                    if name == "data" || name == "default" {
+                        let key = if name == "data" {
+                            match attribute(&attributes, "key") {
+                                Some(key) => Some(key.to_string()),
+                                None => return Err((pos, ParseErrorKind::MissingAttribute("key"))),
+                            }
+                        } else {
+                            None
+                        };
+                        let current = Capture {
+                            pos,
+                            owner: parent,
+                            key,
+                            text: String::new(),
+                            depth: 0,
+                        };
+                        if empty {
+                            self.finish(current)?;
+                        } else {
+                            capture = Some(current);
+                        }
+                        continue;
+                    }
+                    let owner = self.start(pos, name, &attributes)?;
+                    if !empty {
+                        open.push((name, owner));
+                    }
+                }
+            }
+        }
+        if capture.is_some() || !open.is_empty() {
+            return Err((reader.input.len(), ParseErrorKind::UnexpectedEnd));
+        }
+        Ok(())
+    }
+
+    /// Read the start of an element, and return the owner of its `data`.
+    fn start(
+        &mut self,
+        pos: usize,
+        name: &str,
+        attributes: &[(&str, String)],
+    ) -> Result<Owner, PosError> {
+        let required = |name| match attribute(attributes, name) {
+            Some(value) => Ok(value.to_string()),
+            None => Err((pos, ParseErrorKind::MissingAttribute(name))),
+        };
+        Ok(match name {
+            "key" => {
+                let id = required("id")?;
+                let key = Key {
+                    kind: attribute(attributes, "for").unwrap_or("all").to_string(),
+                    name: attribute(attributes, "attr.name")
+                        .unwrap_or(&id)
+                        .to_string(),
+                    type_name: attribute(attributes, "attr.type")
+                        .unwrap_or("string")
+                        .to_string(),
+                    default: None,
+                };
+                if self.keys.insert(id.clone(), key).is_none() {
+                    self.key_order.push(id.clone());
+                }
+                let index = self.key_order.iter().position(|k| *k == id).unwrap();
+                Owner::Key(index)
+            }
+            "graph" => {
+                let directed = match attribute(attributes, "edgedefault") {
+                    Some("directed") => Some(true),
+                    Some("undirected") => Some(false),
+                    _ => None,
+                };
+                if directed.map_or(false, |d| d != Ty::is_directed()) {
+                    return Err((pos, ParseErrorKind::GraphType));
+                }
+                if self.seen_graph {
+                    Owner::Other
+                } else {
+                    self.seen_graph = true;
+                    self.id = attribute(attributes, "id").map(str::to_string);
+                    Owner::Graph
+                }
+            }
+            "node" => {
+                let id = required("id")?;
+                if self.node_ids.contains_key(&id) {
+                    return Err((pos, ParseErrorKind::DuplicateNode(id)));
+                }
+                let node = self.graph.add_node(GraphMlNode {
+                    id: id.clone(),
+                    attributes: AttributeMap::new(),
+                });
+                self.node_ids.insert(id, node);
+                Owner::Node(node)
+            }
+            "edge" => {
+                let source = required("source")?;
+                let target = required("target")?;
+                let directed = match attribute(attributes, "directed") {
+                    Some("true") => Some(true),
+                    Some("false") => Some(false),
+                    _ => None,
+                };
+                if directed.map_or(false, |d| d != Ty::is_directed()) {
+                    return Err((pos, ParseErrorKind::GraphType));
+                }
+                self.edges.push((pos, source, target, AttributeMap::new()));
+                Owner::Edge(self.edges.len() - 1)
+            }
+            "hyperedge" => return Err((pos, ParseErrorKind::Hyperedge)),
+            _ => Owner::Other,
+        })
+    }
+
+    /// Store the value of a `data` or `default` element.
+    fn finish(&mut self, capture: Capture) -> Result<(), PosError> {
+        let key_id = match (capture.key, capture.owner) {
+            (Some(key_id), _) => key_id,
+            (None, Owner::Key(index)) => self.key_order[index].clone(),
+            (None, _) => return Ok(()),
+        };
+        let key = match self.keys.get_mut(&key_id) {
+            Some(key) => key,
+            None => return Err((capture.pos, ParseErrorKind::UnknownKey(key_id))),
+        };
+        let value = match AttributeValue::parse(&key.type_name, &capture.text) {
+            Some(value) => value,
+            None => return Err((capture.pos, ParseErrorKind::InvalidValue(capture.text))),
+        };
+        let name = key.name.clone();
+        match capture.owner {
+            Owner::Graph => {
+                self.attributes.insert(name, value);
+            }
+            Owner::Node(node) => {
+                self.graph[node].attributes.insert(name, value);
+            }
+            Owner::Edge(index) => {
+                self.edges[index].3.insert(name, value);
+            }
+            Owner::Key(_) => key.default = Some(value),
+            Owner::Other => {}
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,590 @@
+//! `HyperGraph<N, E, Ix>` is a graph datastructure where an edge connects
+//! any number of nodes.
+
+use std::iter::Enumerate;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut, Range};
+use std::slice;
+
+use fixedbitset::FixedBitSet;
+
+use crate::data::DataMap;
+use crate::graph::{DefaultIx, EdgeIndex, IndexType, NodeIndex, UnGraph};
+use crate::visit::{
+    Data, EdgeCount, GraphBase, GraphProp, IntoNeighbors, IntoNodeIdentifiers, IntoNodeReferences,
+    NodeCompactIndexable, NodeCount, NodeIndexable, Visitable,
+};
+use crate::Undirected;
+
+/// A node of a `HyperGraph`, and the edges it belongs to.
+#[derive(Clone, Debug)]
+struct Node<N, Ix> {
+    weight: N,
+    edges: Vec<EdgeIndex<Ix>>,
+}
+
+/// An edge of a `HyperGraph`, and the nodes it connects.
+#[derive(Clone, Debug)]
+struct HyperEdge<E, Ix> {
+    weight: E,
+    nodes: Vec<NodeIndex<Ix>>,
+}
+
+/// The node weights of the incidence graph of a `HyperGraph`, see
+/// [`HyperGraph::to_incidence_graph`](struct.HyperGraph.html#method.to_incidence_graph).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Incidence<N, E> {
+    /// A node of the hypergraph.
+    Node(N),
+    /// An edge of the hypergraph.
+    Edge(E),
+}
+
+/// `HyperGraph<N, E, Ix>` is a graph datastructure where an edge, called a
+/// hyperedge, connects an arbitrary set of nodes.
+///
+/// It suits relations between more than two things, such as the reactants of
+/// a chemical reaction or the authors of a paper, that don't break down into
+/// pairs without losing information.
+///
+/// `HyperGraph` is parameterized over:
+///
+/// - Associated data `N` for nodes and `E` for edges, of arbitrary type.
+/// - Index type `Ix`, which determines the maximum size of the graph.
+///
+/// Each node keeps the list of edges it belongs to, and each edge the list of
+/// nodes it connects, in the order they were added. An edge connects each
+/// node at most once, and may connect no nodes at all. Nodes and edges can't
+/// be removed, so their indices are always contiguous.
+///
+/// Two nodes are neighbors if they share an edge. The graph implements the
+/// node traversal traits with this neighbor relation, so algorithms like
+/// `Bfs` or `has_path_connecting` can run on it directly. For other
+/// algorithms, convert it to its [2-section](#method.to_two_section) or
+/// its [incidence graph](#method.to_incidence_graph).
+///
+/// ```
+/// use petgraph::hypergraph::HyperGraph;
+///
+/// // papers and their authors
+/// let mut g = HyperGraph::<_, _>::new();
+/// let ada = g.add_node("Ada");
+/// let bob = g.add_node("Bob");
+/// let cy = g.add_node("Cy");
+/// let paper = g.add_edge(vec![ada, bob, cy], "On Graphs");
+/// g.add_edge(vec![ada, bob], "More Graphs");
+///
+/// assert_eq!(g.edge_nodes(paper), &[ada, bob, cy]);
+/// assert_eq!(g.incident_edges(ada).len(), 2);
+///
+/// // the co-authorship graph, weighted by the number of joint papers
+/// let coauthors = g.to_two_section();
+/// let e = coauthors.find_edge(ada, bob).unwrap();
+/// assert_eq!(coauthors[e], 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HyperGraph<N, E, Ix = DefaultIx> {
+    nodes: Vec<Node<N, Ix>>,
+    edges: Vec<HyperEdge<E, Ix>>,
+}
+
+/// Create a new empty `HyperGraph`.
+impl<N, E, Ix> Default for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn default() -> Self {
+        Self::with_capacity(0, 0)
+    }
+}
+
+impl<N, E, Ix> HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    /// Create a new empty `HyperGraph`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new `HyperGraph` with estimated capacity.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        HyperGraph {
+            nodes: Vec::with_capacity(nodes),
+            edges: Vec::with_capacity(edges),
+        }
+    }
+
+    /// Return the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Return the number of edges in the graph.
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Remove all nodes and edges.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+    }
+
+    /// Add a node with associated data `weight` to the graph, and return its
+    /// index.
+    ///
+    /// **Panics** if the graph is at the maximum number of nodes for its
+    /// index type.
+    pub fn add_node(&mut self, weight: N) -> NodeIndex<Ix> {
+        let a = NodeIndex::new(self.nodes.len());
+        assert!(<Ix as IndexType>::max().index() == !0 || NodeIndex::end() != a);
+        self.nodes.push(Node {
+            weight,
+            edges: Vec::new(),
+        });
+        a
+    }
+
+    /// Add an edge connecting `nodes`, with associated data `weight`, and
+    /// return its index.
+    ///
+    /// Nodes listed more than once are connected once.
+    ///
+    /// **Panics** if any of the nodes don't exist, or if the graph is at the
+    /// maximum number of edges for its index type.
+    pub fn add_edge<I>(&mut self, nodes: I, weight: E) -> EdgeIndex<Ix>
+    where
+        I: IntoIterator<Item = NodeIndex<Ix>>,
+    {
+        let e = EdgeIndex::new(self.edges.len());
+        assert!(<Ix as IndexType>::max().index() == !0 || EdgeIndex::end() != e);
+        let mut members = Vec::new();
+        for a in nodes {
+            let node = match self.nodes.get_mut(a.index()) {
+                Some(node) => node,
+                None => panic!(
+                    "HyperGraph::add_edge: node index {} is out of bounds",
+                    a.index()
+                ),
+            };
+            // the new edge is last in the list of a node already in it
+            if node.edges.last() != Some(&e) {
+                node.edges.push(e);
+                members.push(a);
+            }
+        }
+        self.edges.push(HyperEdge {
+            weight,
+            nodes: members,
+        });
+        e
+    }
+
+    /// Return a reference to the weight of node `a`, or `None` if it doesn't
+    /// exist.
+    pub fn node_weight(&self, a: NodeIndex<Ix>) -> Option<&N> {
+        self.nodes.get(a.index()).map(|node| &node.weight)
+    }
+
+    /// Return a mutable reference to the weight of node `a`, or `None` if it
+    /// doesn't exist.
+    pub fn node_weight_mut(&mut self, a: NodeIndex<Ix>) -> Option<&mut N> {
+        self.nodes.get_mut(a.index()).map(|node| &mut node.weight)
+    }
+
+    /// Return a reference to the weight of edge `e`, or `None` if it doesn't
+    /// exist.
+    pub fn edge_weight(&self, e: EdgeIndex<Ix>) -> Option<&E> {
+        self.edges.get(e.index()).map(|edge| &edge.weight)
+    }
+
+    /// Return a mutable reference to the weight of edge `e`, or `None` if it
+    /// doesn't exist.
+    pub fn edge_weight_mut(&mut self, e: EdgeIndex<Ix>) -> Option<&mut E> {
+        self.edges.get_mut(e.index()).map(|edge| &mut edge.weight)
+    }
+
+    /// Return the nodes connected by edge `e`, in the order they were added.
+    ///
+    /// **Panics** if the edge `e` does not exist.
+    pub fn edge_nodes(&self, e: EdgeIndex<Ix>) -> &[NodeIndex<Ix>] {
+        &self.edges[e.index()].nodes
+    }
+
+    /// Return the edges that node `a` belongs to, in the order they were
+    /// added.
+    ///
+    /// **Panics** if the node `a` does not exist.
+    pub fn incident_edges(&self, a: NodeIndex<Ix>) -> &[EdgeIndex<Ix>] {
+        &self.nodes[a.index()].edges
+    }
+
+    /// Return an iterator of the neighbors of `a`, the nodes that share an
+    /// edge with it.
+    ///
+    /// A neighbor is listed once for each edge it shares with `a`, and `a`
+    /// itself is not listed.
+    ///
+    /// **Panics** if the node `a` does not exist.<br>
+    /// Iterator element type is `NodeIndex<Ix>`.
+    pub fn neighbors(&self, a: NodeIndex<Ix>) -> Neighbors<'_, E, Ix> {
+        Neighbors {
+            node: a,
+            incident: self.nodes[a.index()].edges.iter(),
+            edges: &self.edges,
+            members: [].iter(),
+        }
+    }
+
+    /// Return an iterator over the node indices of the graph.
+    pub fn node_indices(&self) -> NodeIndices<Ix> {
+        NodeIndices {
+            r: 0..self.node_count(),
+            ix: PhantomData,
+        }
+    }
+
+    /// Return an iterator over the edge indices of the graph.
+    pub fn edge_indices(&self) -> EdgeIndices<Ix> {
+        EdgeIndices {
+            r: 0..self.edge_count(),
+            ix: PhantomData,
+        }
+    }
+
+    /// Return the 2-section of the graph: the undirected graph with the same
+    /// nodes, and an edge between each pair of nodes that share an edge here.
+    ///
+    /// Nodes keep their indices. The weight of each edge is the number of
+    /// hypergraph edges the pair shares.
+    ///
+    /// **Panics** if the 2-section has more edges than the index type can
+    /// represent.
+    pub fn to_two_section(&self) -> UnGraph<N, usize, Ix>
+    where
+        N: Clone,
+    {
+        let mut graph = UnGraph::with_capacity(self.node_count(), 0);
+        for node in &self.nodes {
+            graph.add_node(node.weight.clone());
+        }
+        let mut shared = vec![0; self.node_count()];
+        let mut touched = Vec::new();
+        for a in self.node_indices() {
+            for b in self.neighbors(a).filter(|&b| b > a) {
+                if shared[b.index()] == 0 {
+                    touched.push(b);
+                }
+                shared[b.index()] += 1;
+            }
+            for b in touched.drain(..) {
+                graph.add_edge(a, b, shared[b.index()]);
+                shared[b.index()] = 0;
+            }
+        }
+        graph
+    }
+
+    /// Return the incidence graph: the bipartite undirected graph with a node
+    /// for each node and each edge of the hypergraph, and an edge between a
+    /// node and each edge it belongs to.
+    ///
+    /// The nodes keep their indices, and edge `e` becomes the node with index
+    /// `node_count() + e.index()`.
+    ///
+    /// **Panics** if the incidence graph has more nodes or edges than the
+    /// index type can represent.
+    pub fn to_incidence_graph(&self) -> UnGraph<Incidence<N, E>, (), Ix>
+    where
+        N: Clone,
+        E: Clone,
+    {
+        let memberships = self.nodes.iter().map(|node| node.edges.len()).sum();
+        let mut graph = UnGraph::with_capacity(self.node_count() + self.edge_count(), memberships);
+        for node in &self.nodes {
+            graph.add_node(Incidence::Node(node.weight.clone()));
+        }
+        for edge in &self.edges {
+            let e = graph.add_node(Incidence::Edge(edge.weight.clone()));
+            for &a in &edge.nodes {
+                graph.add_edge(a, e, ());
+            }
+        }
+        graph
+    }
+}
+
+/// Iterator over the neighbors of a node.
+///
+/// Iterator element type is `NodeIndex<Ix>`.
+#[derive(Debug, Clone)]
+pub struct Neighbors<'a, E: 'a, Ix: 'a = DefaultIx> {
+    node: NodeIndex<Ix>,
+    incident: slice::Iter<'a, EdgeIndex<Ix>>,
+    edges: &'a [HyperEdge<E, Ix>],
+    members: slice::Iter<'a, NodeIndex<Ix>>,
+}
+
+impl<'a, E, Ix> Iterator for Neighbors<'a, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<NodeIndex<Ix>> {
+        loop {
+            match self.members.next() {
+                Some(&b) if b != self.node => return Some(b),
+                Some(_) => {}
+                None => {
+                    let e = self.incident.next()?;
+                    self.members = self.edges[e.index()].nodes.iter();
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over the node indices of a graph.
+#[derive(Debug, Clone)]
+pub struct NodeIndices<Ix = DefaultIx> {
+    r: Range<usize>,
+    ix: PhantomData<Ix>,
+}
+
+impl<Ix: IndexType> Iterator for NodeIndices<Ix> {
+    type Item = NodeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.r.next().map(NodeIndex::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.r.size_hint()
+    }
+}
+
+impl<Ix: IndexType> ExactSizeIterator for NodeIndices<Ix> {}
+
+/// Iterator over the edge indices of a graph.
+#[derive(Debug, Clone)]
+pub struct EdgeIndices<Ix = DefaultIx> {
+    r: Range<usize>,
+    ix: PhantomData<Ix>,
+}
+
+impl<Ix: IndexType> Iterator for EdgeIndices<Ix> {
+    type Item = EdgeIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.r.next().map(EdgeIndex::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.r.size_hint()
+    }
+}
+
+impl<Ix: IndexType> ExactSizeIterator for EdgeIndices<Ix> {}
+
+/// Iterator over the nodes of a graph, with their weights.
+#[derive(Debug, Clone)]
+pub struct NodeReferences<'a, N: 'a, Ix: 'a = DefaultIx> {
+    iter: Enumerate<slice::Iter<'a, Node<N, Ix>>>,
+}
+
+impl<'a, N, Ix> Iterator for NodeReferences<'a, N, Ix>
+where
+    Ix: IndexType,
+{
+    type Item = (NodeIndex<Ix>, &'a N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(i, node)| (NodeIndex::new(i), &node.weight))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, N, Ix> ExactSizeIterator for NodeReferences<'a, N, Ix> where Ix: IndexType {}
+
+/// Index the `HyperGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, Ix> Index<NodeIndex<Ix>> for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Output = N;
+    fn index(&self, index: NodeIndex<Ix>) -> &N {
+        &self.nodes[index.index()].weight
+    }
+}
+
+/// Index the `HyperGraph` by `NodeIndex` to access node weights.
+///
+/// **Panics** if the node doesn't exist.
+impl<N, E, Ix> IndexMut<NodeIndex<Ix>> for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: NodeIndex<Ix>) -> &mut N {
+        &mut self.nodes[index.index()].weight
+    }
+}
+
+/// Index the `HyperGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ix> Index<EdgeIndex<Ix>> for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Output = E;
+    fn index(&self, index: EdgeIndex<Ix>) -> &E {
+        &self.edges[index.index()].weight
+    }
+}
+
+/// Index the `HyperGraph` by `EdgeIndex` to access edge weights.
+///
+/// **Panics** if the edge doesn't exist.
+impl<N, E, Ix> IndexMut<EdgeIndex<Ix>> for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn index_mut(&mut self, index: EdgeIndex<Ix>) -> &mut E {
+        &mut self.edges[index.index()].weight
+    }
+}
+
+impl<N, E, Ix> GraphBase for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeId = NodeIndex<Ix>;
+    type EdgeId = EdgeIndex<Ix>;
+}
+
+/// The neighbor relation of a `HyperGraph` is symmetric, so it is
+/// `Undirected`.
+impl<N, E, Ix> GraphProp for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type EdgeType = Undirected;
+}
+
+impl<N, E, Ix> Data for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<N, E, Ix> DataMap for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn node_weight(&self, a: Self::NodeId) -> Option<&N> {
+        self.node_weight(a)
+    }
+    fn edge_weight(&self, e: Self::EdgeId) -> Option<&E> {
+        self.edge_weight(e)
+    }
+}
+
+impl<N, E, Ix> NodeCount for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    fn node_count(&self) -> usize {
+        self.node_count()
+    }
+}
+
+impl<N, E, Ix> EdgeCount for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    #[inline]
+    fn edge_count(&self) -> usize {
+        self.edge_count()
+    }
+}
+
+impl<N, E, Ix> NodeIndexable for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    #[inline]
+    fn node_bound(&self) -> usize {
+        self.node_count()
+    }
+    #[inline]
+    fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
+        ix.index()
+    }
+    #[inline]
+    fn from_index(&self, ix: usize) -> Self::NodeId {
+        NodeIndex::new(ix)
+    }
+}
+
+impl<N, E, Ix> NodeCompactIndexable for HyperGraph<N, E, Ix> where Ix: IndexType {}
+
+impl<N, E, Ix> Visitable for HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Map = FixedBitSet;
+    fn visit_map(&self) -> FixedBitSet {
+        FixedBitSet::with_capacity(self.node_count())
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+        map.grow(self.node_count());
+    }
+}
+
+impl<N, E, Ix> IntoNodeIdentifiers for &HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeIdentifiers = NodeIndices<Ix>;
+    fn node_identifiers(self) -> NodeIndices<Ix> {
+        self.node_indices()
+    }
+}
+
+impl<'a, N, E, Ix> IntoNodeReferences for &'a HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type NodeRef = (NodeIndex<Ix>, &'a N);
+    type NodeReferences = NodeReferences<'a, N, Ix>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeReferences {
+            iter: self.nodes.iter().enumerate(),
+        }
+    }
+}
+
+impl<'a, N, E, Ix> IntoNeighbors for &'a HyperGraph<N, E, Ix>
+where
+    Ix: IndexType,
+{
+    type Neighbors = Neighbors<'a, E, Ix>;
+
+    /// Return an iterator of the nodes that share an edge with `a`, the same
+    /// as [`HyperGraph::neighbors`].
+    fn neighbors(self, a: NodeIndex<Ix>) -> Self::Neighbors {
+        HyperGraph::neighbors(self, a)
+    }
+}
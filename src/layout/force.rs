@@ -0,0 +1,297 @@
+//! Force-directed layout.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::{add, fit_unit_square, norm, scale, sub, SplitMix64};
+use crate::algo::undirected_adjacency;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The Fruchterman–Reingold force-directed layout.
+///
+/// Nodes repel each other like charged particles, and edges pull their
+/// endpoints together like springs. Starting from random positions, each
+/// iteration moves every node along the sum of the forces on it, by at most a
+/// *temperature* that decreases linearly to zero, so the layout settles.
+///
+/// The layout is finally scaled to fit the unit square `[0, 1] × [0, 1]`,
+/// keeping its aspect ratio. The graph is treated as if undirected; edge
+/// weights, self loops and parallel edges are ignored.
+///
+/// Each iteration takes **O(|V|² + |E|)** time, or **O(|V| log |V| + |E|)**
+/// with the Barnes–Hut approximation of the repulsive forces.
+///
+/// # Example
+/// ```rust
+/// use petgraph::layout::FruchtermanReingold;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let positions = FruchtermanReingold::new().iterations(100).seed(7).layout(&g);
+/// for n in g.node_indices() {
+///     let [x, y] = positions[&n];
+///     assert!(0. <= x && x <= 1. && 0. <= y && y <= 1.);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FruchtermanReingold {
+    iterations: usize,
+    seed: u64,
+    theta: Option<f64>,
+}
+
+impl Default for FruchtermanReingold {
+    fn default() -> Self {
+        FruchtermanReingold::new()
+    }
+}
+
+impl FruchtermanReingold {
+    /// Create a layout with 50 iterations, seed `0` and exact repulsive
+    /// forces.
+    pub fn new() -> Self {
+        FruchtermanReingold {
+            iterations: 50,
+            seed: 0,
+            theta: None,
+        }
+    }
+
+    /// Set the number of iterations.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Set the seed of the random initial positions.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Approximate the repulsive forces with the Barnes–Hut algorithm.
+    ///
+    /// The nodes are grouped in a quadtree, and the force of a group of nodes
+    /// is computed from their center of mass if the group's size divided by
+    /// its distance is less than `theta`. Smaller values of `theta` are more
+    /// accurate and slower; `1.0` is a common choice for large graphs.
+    pub fn barnes_hut(mut self, theta: f64) -> Self {
+        self.theta = Some(theta);
+        self
+    }
+
+    /// Compute the position of every node of `g`.
+    pub fn layout<G>(&self, g: G) -> HashMap<G::NodeId, [f64; 2]>
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+        G::NodeId: Eq + Hash,
+    {
+        let (nodes, adj) = undirected_adjacency(g);
+        let mut rng = SplitMix64(self.seed);
+        let mut pos = vec![[0.; 2]; adj.len()];
+        for &i in &nodes {
+            pos[i] = [rng.next_f64(), rng.next_f64()];
+        }
+
+        // the ideal distance between nodes
+        let k = (1. / nodes.len().max(1) as f64).sqrt();
+        let mut disp = vec![[0.; 2]; adj.len()];
+        for iteration in 0..self.iterations {
+            for &i in &nodes {
+                disp[i] = [0., 0.];
+            }
+            match self.theta {
+                None => {
+                    for (x, &i) in nodes.iter().enumerate() {
+                        for &j in &nodes[x + 1..] {
+                            let f = repulsion(pos[i], pos[j], 1., k, i < j);
+                            disp[i] = add(disp[i], f);
+                            disp[j] = sub(disp[j], f);
+                        }
+                    }
+                }
+                Some(theta) => {
+                    let tree = QuadTree::new(&nodes, &pos);
+                    for &i in &nodes {
+                        disp[i] = tree.repulsion(i, &pos, k, theta);
+                    }
+                }
+            }
+            for &i in &nodes {
+                for &j in &adj[i] {
+                    // every edge is seen from both ends
+                    let d = sub(pos[i], pos[j]);
+                    let dist = norm(d);
+                    disp[i] = sub(disp[i], scale(d, dist / k));
+                }
+            }
+
+            let temperature = 0.1 * (1. - iteration as f64 / self.iterations as f64);
+            for &i in &nodes {
+                let length = norm(disp[i]);
+                if length > 0. {
+                    pos[i] = add(pos[i], scale(disp[i], length.min(temperature) / length));
+                }
+            }
+        }
+
+        fit_unit_square(&nodes, &mut pos);
+        nodes
+            .into_iter()
+            .map(|i| (g.from_index(i), pos[i]))
+            .collect()
+    }
+}
+
+/// The repulsive force on a node at `a` from `mass` nodes at `b`.
+///
+/// Nodes at the same position are pushed apart along the x axis, in the
+/// direction given by `left`.
+fn repulsion(a: [f64; 2], b: [f64; 2], mass: f64, k: f64, left: bool) -> [f64; 2] {
+    let mut d = sub(a, b);
+    let mut dist = norm(d);
+    if dist < 1e-9 {
+        dist = 1e-3 * k;
+        d = [if left { -dist } else { dist }, 0.];
+    }
+    scale(d, mass * k * k / (dist * dist))
+}
+
+/// The depth at which cells of the quadtree are no longer split, so that
+/// nodes at the same position end in the same leaf.
+const MAX_DEPTH: usize = 32;
+
+/// A cell of the quadtree: a square with its lower corner at `min`.
+#[derive(Clone, Debug)]
+struct Cell {
+    min: [f64; 2],
+    size: f64,
+    mass: f64,
+    /// The sum of the positions of the nodes in the cell.
+    sum: [f64; 2],
+    /// The index of the first of four children, or zero for a leaf.
+    children: usize,
+    /// The nodes of a leaf.
+    nodes: Vec<usize>,
+}
+
+impl Cell {
+    fn new(min: [f64; 2], size: f64) -> Self {
+        Cell {
+            min,
+            size,
+            mass: 0.,
+            sum: [0., 0.],
+            children: 0,
+            nodes: Vec::new(),
+        }
+    }
+
+    fn quadrant(&self, p: [f64; 2]) -> usize {
+        let half = self.size / 2.;
+        (p[0] >= self.min[0] + half) as usize + 2 * (p[1] >= self.min[1] + half) as usize
+    }
+}
+
+/// A quadtree of the node positions, for the Barnes–Hut approximation.
+struct QuadTree {
+    cells: Vec<Cell>,
+}
+
+impl QuadTree {
+    fn new(nodes: &[usize], pos: &[[f64; 2]]) -> Self {
+        let mut min = [std::f64::INFINITY; 2];
+        let mut max = [std::f64::NEG_INFINITY; 2];
+        for &i in nodes {
+            for d in 0..2 {
+                min[d] = min[d].min(pos[i][d]);
+                max[d] = max[d].max(pos[i][d]);
+            }
+        }
+        let size = (max[0] - min[0]).max(max[1] - min[1]).max(1e-9);
+        let mut tree = QuadTree {
+            cells: vec![Cell::new(min, size)],
+        };
+        for &i in nodes {
+            tree.insert(i, pos);
+        }
+        tree
+    }
+
+    fn insert(&mut self, i: usize, pos: &[[f64; 2]]) {
+        let p = pos[i];
+        let mut c = 0;
+        for depth in 0.. {
+            let cell = &mut self.cells[c];
+            cell.mass += 1.;
+            cell.sum = add(cell.sum, p);
+            if cell.children == 0 {
+                if cell.nodes.is_empty() || depth == MAX_DEPTH {
+                    cell.nodes.push(i);
+                    return;
+                }
+                // split the leaf, moving its node to a child
+                let j = cell.nodes.pop().unwrap();
+                let (min, half) = (cell.min, cell.size / 2.);
+                let first = self.cells.len();
+                self.cells[c].children = first;
+                for q in 0..4 {
+                    let corner = [
+                        min[0] + half * (q % 2) as f64,
+                        min[1] + half * (q / 2) as f64,
+                    ];
+                    self.cells.push(Cell::new(corner, half));
+                }
+                let q = self.cells[c].quadrant(pos[j]);
+                let child = &mut self.cells[first + q];
+                child.mass = 1.;
+                child.sum = pos[j];
+                child.nodes.push(j);
+            }
+            c = self.cells[c].children + self.cells[c].quadrant(p);
+        }
+    }
+
+    /// Return the approximate repulsive force on node `i`.
+    fn repulsion(&self, i: usize, pos: &[[f64; 2]], k: f64, theta: f64) -> [f64; 2] {
+        let p = pos[i];
+        // the cells containing node `i`
+        let mut path = vec![0];
+        while self.cells[path[path.len() - 1]].children != 0 {
+            let cell = &self.cells[path[path.len() - 1]];
+            path.push(cell.children + cell.quadrant(p));
+        }
+        let mut force = [0., 0.];
+        let mut stack = vec![0];
+        while let Some(c) = stack.pop() {
+            let cell = &self.cells[c];
+            if cell.mass == 0. {
+                continue;
+            }
+            if cell.children == 0 {
+                for &j in &cell.nodes {
+                    if j != i {
+                        force = add(force, repulsion(p, pos[j], 1., k, i < j));
+                    }
+                }
+                continue;
+            }
+            let (mut mass, mut sum) = (cell.mass, cell.sum);
+            if path.contains(&c) {
+                // leave out the node itself
+                mass -= 1.;
+                sum = sub(sum, p);
+            }
+            if mass == 0. {
+                continue;
+            }
+            let center = scale(sum, 1. / mass);
+            if cell.size < theta * norm(sub(p, center)) {
+                force = add(force, repulsion(p, center, mass, k, true));
+            } else {
+                stack.extend(cell.children..cell.children + 4);
+            }
+        }
+        force
+    }
+}
@@ -0,0 +1,286 @@
+//! Layered layout of directed acyclic graphs.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::algo::{coffman_graham, topological_generations, Error};
+use crate::visit::{IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use crate::Outgoing;
+
+/// The Sugiyama layered layout of a directed acyclic graph, where edges point
+/// downwards.
+///
+/// The layout is computed in three phases:
+///
+/// 1. Every node is assigned to a layer, so that every edge goes to a later
+///    layer: by [`topological_generations`](../algo/fn.topological_generations.html),
+///    or with a bound on the number of nodes in a layer, by
+///    [`coffman_graham`](../algo/layering/fn.coffman_graham.html). Edges that
+///    span several layers are split by invisible nodes in the layers between.
+/// 2. The order of the nodes in each layer is chosen to reduce the number of
+///    edge crossings, by sweeping down and up the layers and sorting each
+///    layer by the *barycenter*, the average position, of the neighbors in
+///    the previous layer. The order with the fewest crossings is kept.
+/// 3. The nodes are given x coordinates close to the average of their
+///    neighbors, keeping the order and a distance of at least one between
+///    nodes of the same layer.
+///
+/// The y coordinate of a node is the number of its layer, starting from zero,
+/// and the smallest x coordinate is zero.
+///
+/// If the graph has a cycle, return an `Error::Cycle` error with a node on a
+/// cycle.
+/// Self loops are also cycles.
+///
+/// # Example
+/// ```rust
+/// use petgraph::layout::Sugiyama;
+/// use petgraph::prelude::*;
+///
+/// // Two crossing edges are drawn uncrossed.
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 3), (1, 2)]);
+/// let positions = Sugiyama::new().layout(&g).unwrap();
+/// let x = |i| positions[&NodeIndex::new(i)][0];
+/// assert_eq!(positions[&NodeIndex::new(3)][1], 1.);
+/// assert!((x(0) < x(1)) == (x(3) < x(2)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sugiyama {
+    width: Option<usize>,
+    sweeps: usize,
+}
+
+impl Default for Sugiyama {
+    fn default() -> Self {
+        Sugiyama::new()
+    }
+}
+
+impl Sugiyama {
+    /// Create a layout without a bound on the width of the layers, and with
+    /// 24 sweeps of crossing reduction.
+    pub fn new() -> Self {
+        Sugiyama {
+            width: None,
+            sweeps: 24,
+        }
+    }
+
+    /// Put at most `width` nodes in each layer, not counting the invisible
+    /// nodes of long edges.
+    ///
+    /// **Panics** in `layout` if `width` is zero.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the number of sweeps of crossing reduction.
+    pub fn sweeps(mut self, sweeps: usize) -> Self {
+        self.sweeps = sweeps;
+        self
+    }
+
+    /// Compute the position of every node of `g`.
+    #[allow(clippy::type_complexity)]
+    pub fn layout<G>(&self, g: G) -> Result<HashMap<G::NodeId, [f64; 2]>, Error<G::NodeId>>
+    where
+        G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+        G::NodeId: Eq + Hash,
+    {
+        let layers = match self.width {
+            Some(width) => coffman_graham(g, width)?,
+            None => topological_generations(g)?,
+        };
+        let mut layer_of = vec![0; g.node_bound()];
+        let mut order = layers
+            .iter()
+            .enumerate()
+            .map(|(l, layer)| {
+                layer
+                    .iter()
+                    .map(|&a| {
+                        layer_of[g.to_index(a)] = l;
+                        g.to_index(a)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Split long edges by invisible nodes, numbered from `node_bound`.
+        let mut up = vec![Vec::new(); g.node_bound()];
+        let mut down = vec![Vec::new(); g.node_bound()];
+        for layer in &layers {
+            for &a in layer {
+                let i = g.to_index(a);
+                let mut targets = g
+                    .neighbors_directed(a, Outgoing)
+                    .map(|b| g.to_index(b))
+                    .collect::<Vec<_>>();
+                targets.sort_unstable();
+                targets.dedup();
+                for j in targets {
+                    let mut prev = i;
+                    for layer in &mut order[layer_of[i] + 1..layer_of[j]] {
+                        let dummy = up.len();
+                        up.push(vec![prev]);
+                        down.push(Vec::new());
+                        down[prev].push(dummy);
+                        layer.push(dummy);
+                        prev = dummy;
+                    }
+                    down[prev].push(j);
+                    up[j].push(prev);
+                }
+            }
+        }
+
+        let mut pos = vec![0; up.len()];
+        let set_positions = |order: &[Vec<usize>], pos: &mut [usize]| {
+            for layer in order {
+                for (p, &v) in layer.iter().enumerate() {
+                    pos[v] = p;
+                }
+            }
+        };
+        set_positions(&order, &mut pos);
+        let mut best = order.clone();
+        let mut fewest = crossings(&order, &down, &pos);
+        for sweep in 0..self.sweeps {
+            if fewest == 0 {
+                break;
+            }
+            let downwards = sweep % 2 == 0;
+            let mut ls = (1..order.len()).collect::<Vec<_>>();
+            if !downwards {
+                ls = (0..order.len().saturating_sub(1)).rev().collect();
+            }
+            for l in ls {
+                let neighbors = if downwards { &up } else { &down };
+                let key = order[l]
+                    .iter()
+                    .map(|&v| barycenter(&neighbors[v], &pos).unwrap_or(pos[v] as f64))
+                    .collect::<Vec<_>>();
+                let mut sorted = (0..order[l].len()).collect::<Vec<_>>();
+                sorted.sort_by(|&x, &y| key[x].partial_cmp(&key[y]).unwrap());
+                order[l] = sorted.into_iter().map(|x| order[l][x]).collect();
+                for (p, &v) in order[l].iter().enumerate() {
+                    pos[v] = p;
+                }
+            }
+            let count = crossings(&order, &down, &pos);
+            if count < fewest {
+                fewest = count;
+                best = order.clone();
+            }
+        }
+        set_positions(&best, &mut pos);
+
+        // Move every node towards the average of its neighbors, keeping the
+        // order and the distance between nodes.
+        let mut x = pos.iter().map(|&p| p as f64).collect::<Vec<_>>();
+        for _ in 0..8 {
+            for layer in &best {
+                let desired = layer
+                    .iter()
+                    .map(|&v| {
+                        let both = up[v].iter().chain(&down[v]);
+                        let count = up[v].len() + down[v].len();
+                        if count == 0 {
+                            x[v]
+                        } else {
+                            both.map(|&w| x[w]).sum::<f64>() / count as f64
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                for (&v, x_v) in layer.iter().zip(spaced(&desired)) {
+                    x[v] = x_v;
+                }
+            }
+        }
+        let left = layers
+            .iter()
+            .flatten()
+            .map(|&a| x[g.to_index(a)])
+            .fold(std::f64::INFINITY, f64::min);
+
+        let mut positions = HashMap::with_capacity(g.node_bound());
+        for (l, layer) in layers.into_iter().enumerate() {
+            for a in layer {
+                positions.insert(a, [x[g.to_index(a)] - left, l as f64]);
+            }
+        }
+        Ok(positions)
+    }
+}
+
+/// Return the average position of `nodes`, if any.
+fn barycenter(nodes: &[usize], pos: &[usize]) -> Option<f64> {
+    if nodes.is_empty() {
+        None
+    } else {
+        Some(nodes.iter().map(|&v| pos[v] as f64).sum::<f64>() / nodes.len() as f64)
+    }
+}
+
+/// Count the crossings of the edges between consecutive layers.
+fn crossings(order: &[Vec<usize>], down: &[Vec<usize>], pos: &[usize]) -> usize {
+    let mut count = 0;
+    for l in 0..order.len().saturating_sub(1) {
+        let mut edges = Vec::new();
+        for &v in &order[l] {
+            for &w in &down[v] {
+                edges.push((pos[v], pos[w]));
+            }
+        }
+        edges.sort_unstable();
+        // Count the pairs of edges whose lower ends are in the opposite
+        // order, with a Fenwick tree over the positions in the lower layer.
+        let mut tree = vec![0; order[l + 1].len() + 1];
+        for (seen, &(_, p)) in edges.iter().enumerate() {
+            let mut i = p + 1;
+            let mut not_greater = 0;
+            while i > 0 {
+                not_greater += tree[i];
+                i &= i - 1;
+            }
+            count += seen - not_greater;
+            let mut i = p + 1;
+            while i < tree.len() {
+                tree[i] += 1;
+                i += i & i.wrapping_neg();
+            }
+        }
+    }
+    count
+}
+
+/// Return the positions closest to `desired`, in the least squares sense,
+/// that are increasing with a distance of at least one.
+fn spaced(desired: &[f64]) -> Vec<f64> {
+    // With y[k] = x[k] - k, this is an isotonic regression of
+    // desired[k] - k, solved by pooling adjacent violators.
+    let mut blocks: Vec<(f64, usize)> = Vec::new();
+    for (k, &d) in desired.iter().enumerate() {
+        let mut block = (d - k as f64, 1);
+        while let Some(&(mean, size)) = blocks.last() {
+            if mean < block.0 {
+                break;
+            }
+            blocks.pop();
+            let total = size + block.1;
+            block = (
+                (mean * size as f64 + block.0 * block.1 as f64) / total as f64,
+                total,
+            );
+        }
+        blocks.push(block);
+    }
+    let mut x = Vec::with_capacity(desired.len());
+    for (mean, size) in blocks {
+        for _ in 0..size {
+            x.push(mean + x.len() as f64);
+        }
+    }
+    x
+}
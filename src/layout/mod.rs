@@ -0,0 +1,76 @@
+//! Compute positions of the nodes of a graph, for drawing it.
+//!
+//! A layout assigns every node a position `[x, y]` in the plane. The layouts
+//! are deterministic: the same graph and settings give the same positions.
+
+mod force;
+mod layered;
+mod simple;
+#[cfg(feature = "spectral")]
+mod spectral;
+mod stress;
+
+pub use self::force::FruchtermanReingold;
+pub use self::layered::Sugiyama;
+pub use self::simple::{bipartite_layout, circular_layout, shell_layout};
+#[cfg(feature = "spectral")]
+pub use self::spectral::spectral_layout;
+pub use self::stress::KamadaKawai;
+
+/// The SplitMix64 pseudorandom number generator, used to seed initial
+/// positions without depending on the `rand` feature.
+#[derive(Clone, Debug)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Return a number in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn add(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn scale(a: [f64; 2], s: f64) -> [f64; 2] {
+    [a[0] * s, a[1] * s]
+}
+
+fn norm(a: [f64; 2]) -> f64 {
+    (a[0] * a[0] + a[1] * a[1]).sqrt()
+}
+
+/// Scale and translate the positions of `nodes` uniformly, so that they fit
+/// in the unit square, centered.
+fn fit_unit_square(nodes: &[usize], pos: &mut [[f64; 2]]) {
+    let mut min = [std::f64::INFINITY; 2];
+    let mut max = [std::f64::NEG_INFINITY; 2];
+    for &i in nodes {
+        for d in 0..2 {
+            min[d] = min[d].min(pos[i][d]);
+            max[d] = max[d].max(pos[i][d]);
+        }
+    }
+    let extent = (max[0] - min[0]).max(max[1] - min[1]);
+    let scale = if extent > 0. { 1. / extent } else { 0. };
+    for &i in nodes {
+        for d in 0..2 {
+            let x = 0.5 + (pos[i][d] - (min[d] + max[d]) / 2.) * scale;
+            // guard against rounding
+            pos[i][d] = x.max(0.).min(1.);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Simple deterministic layouts.
+
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::PI;
+use std::hash::Hash;
+
+use crate::visit::IntoNodeIdentifiers;
+
+/// Return the point at `angle` on the circle of `radius` around the center of
+/// the unit square.
+fn on_circle(radius: f64, angle: f64) -> [f64; 2] {
+    [0.5 + radius * angle.cos(), 0.5 + radius * angle.sin()]
+}
+
+/// \[Generic\] Place the nodes evenly on a circle, in the order of
+/// `g.node_identifiers()`.
+///
+/// The circle is inscribed in the unit square `[0, 1] × [0, 1]`, and the
+/// first node is at its right end.
+///
+/// # Example
+/// ```rust
+/// use petgraph::layout::circular_layout;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let positions = circular_layout(&g);
+/// assert_eq!(positions[&NodeIndex::new(0)], [1., 0.5]);
+/// ```
+pub fn circular_layout<G>(g: G) -> HashMap<G::NodeId, [f64; 2]>
+where
+    G: IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let nodes = g.node_identifiers().collect::<Vec<_>>();
+    let n = nodes.len() as f64;
+    nodes
+        .into_iter()
+        .enumerate()
+        .map(|(k, a)| (a, on_circle(0.5, 2. * PI * k as f64 / n)))
+        .collect()
+}
+
+/// \[Generic\] Place the nodes on concentric circles, one for each group of
+/// nodes in `shells`.
+///
+/// The first shell is the innermost, and the circles are evenly spaced up to
+/// the circle inscribed in the unit square `[0, 1] × [0, 1]`. A first shell
+/// with a single node is placed at the center. Nodes in no shell are placed
+/// on an extra outermost circle, and nodes in several shells in the first one.
+///
+/// # Example
+/// ```rust
+/// use petgraph::layout::shell_layout;
+/// use petgraph::prelude::*;
+///
+/// // A star with its center in the middle.
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let positions = shell_layout(&g, &[vec![NodeIndex::new(0)]]);
+/// assert_eq!(positions[&NodeIndex::new(0)], [0.5, 0.5]);
+/// assert_eq!(positions[&NodeIndex::new(1)], [1., 0.5]);
+/// ```
+pub fn shell_layout<G>(g: G, shells: &[Vec<G::NodeId>]) -> HashMap<G::NodeId, [f64; 2]>
+where
+    G: IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let mut placed = HashSet::new();
+    let mut circles = shells
+        .iter()
+        .map(|shell| {
+            shell
+                .iter()
+                .cloned()
+                .filter(|&a| placed.insert(a))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let rest = g
+        .node_identifiers()
+        .filter(|a| !placed.contains(a))
+        .collect::<Vec<_>>();
+    if !rest.is_empty() {
+        circles.push(rest);
+    }
+
+    let center = circles.first().map_or(false, |c| c.len() == 1);
+    let rings = if center {
+        circles.len() - 1
+    } else {
+        circles.len()
+    };
+    let mut positions = HashMap::with_capacity(placed.len());
+    for (k, circle) in circles.into_iter().enumerate() {
+        let ring = if center { k } else { k + 1 };
+        let radius = 0.5 * ring as f64 / rings.max(1) as f64;
+        let n = circle.len() as f64;
+        for (j, a) in circle.into_iter().enumerate() {
+            positions.insert(a, on_circle(radius, 2. * PI * j as f64 / n));
+        }
+    }
+    positions
+}
+
+/// \[Generic\] Place the nodes in two columns: the nodes in `left` at `x = 0`,
+/// and the other nodes at `x = 1`.
+///
+/// The nodes of each column are evenly spaced from `y = 0` to `y = 1`, in the
+/// order of `g.node_identifiers()`; a column of one node is centered. The
+/// sides of a bipartite graph are given by
+/// [`bipartite_partition`](../algo/bipartite/fn.bipartite_partition.html).
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::bipartite_partition;
+/// use petgraph::layout::bipartite_layout;
+/// use petgraph::prelude::*;
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 3), (2, 3)]);
+/// let (left, _) = bipartite_partition(&g).unwrap();
+/// let positions = bipartite_layout(&g, left);
+/// assert_eq!(positions[&NodeIndex::new(0)], [0., 0.]);
+/// assert_eq!(positions[&NodeIndex::new(2)], [0., 1.]);
+/// assert_eq!(positions[&NodeIndex::new(3)], [1., 1.]);
+/// ```
+pub fn bipartite_layout<G, I>(g: G, left: I) -> HashMap<G::NodeId, [f64; 2]>
+where
+    G: IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+{
+    let left = left.into_iter().collect::<HashSet<_>>();
+    let (first, second): (Vec<_>, Vec<_>) = g.node_identifiers().partition(|a| left.contains(a));
+    let mut positions = HashMap::with_capacity(first.len() + second.len());
+    for (x, column) in [(0., first), (1., second)].iter() {
+        let n = column.len();
+        for (k, &a) in column.iter().enumerate() {
+            let y = if n == 1 {
+                0.5
+            } else {
+                k as f64 / (n - 1) as f64
+            };
+            positions.insert(a, [*x, y]);
+        }
+    }
+    positions
+}
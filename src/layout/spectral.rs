@@ -0,0 +1,136 @@
+//! Spectral layout.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::{fit_unit_square, SplitMix64};
+use crate::algo::undirected_adjacency;
+use crate::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// \[Generic\] Place the nodes by two eigenvectors of the Laplacian matrix of
+/// the graph.
+///
+/// The coordinates of the nodes are the eigenvectors of the two smallest
+/// nonzero eigenvalues of the Laplacian `L = D - A`, which minimize the sum
+/// of the squared edge lengths for coordinates of unit length that are
+/// orthogonal to each other and to the constant vector. They are computed by
+/// subspace iteration, which takes **O(|V| + |E|)** time per iteration, for
+/// at most `iterations` iterations.
+///
+/// The graph is treated as if undirected; edge weights, self loops and
+/// parallel edges are ignored. The graph should be connected: otherwise each
+/// connected component tends to be drawn as a single point.
+///
+/// The layout is finally scaled to fit the unit square `[0, 1] × [0, 1]`,
+/// keeping its aspect ratio.
+///
+/// Requires crate feature `"spectral"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::layout::spectral_layout;
+/// use petgraph::prelude::*;
+///
+/// // A cycle is drawn as a regular polygon.
+/// let g = UnGraph::<(), ()>::from_edges((0..8).map(|i| (i, (i + 1) % 8)));
+/// let positions = spectral_layout(&g, 1000);
+/// for p in positions.values() {
+///     let radius = ((p[0] - 0.5).powi(2) + (p[1] - 0.5).powi(2)).sqrt();
+///     assert!(radius > 0.45);
+/// }
+/// ```
+pub fn spectral_layout<G>(g: G, iterations: usize) -> HashMap<G::NodeId, [f64; 2]>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, adj) = undirected_adjacency(g);
+    // The largest eigenvalues of `shift * I - L` are the smallest of `L`, as
+    // `shift` is at least the largest eigenvalue of `L`.
+    let shift = 2. * adj.iter().map(Vec::len).max().unwrap_or(0) as f64 + 1.;
+    let apply = |v: &[f64], out: &mut [f64]| {
+        for &i in &nodes {
+            let degree = adj[i].len() as f64;
+            out[i] = (shift - degree) * v[i] + adj[i].iter().map(|&j| v[j]).sum::<f64>();
+        }
+    };
+
+    let mut rng = SplitMix64(0);
+    let mut basis = [vec![0.; adj.len()], vec![0.; adj.len()]];
+    for v in &mut basis {
+        for &i in &nodes {
+            v[i] = rng.next_f64() - 0.5;
+        }
+    }
+    orthonormalize(&nodes, &mut basis);
+    let mut image = basis.clone();
+    for _ in 0..iterations {
+        apply(&basis[0], &mut image[0]);
+        apply(&basis[1], &mut image[1]);
+        orthonormalize(&nodes, &mut image);
+        // Rotate the basis within its plane to approximate the two
+        // eigenvectors separately (Rayleigh–Ritz).
+        let mut product = [vec![0.; adj.len()], vec![0.; adj.len()]];
+        apply(&image[0], &mut product[0]);
+        apply(&image[1], &mut product[1]);
+        let h00 = dot(&nodes, &image[0], &product[0]);
+        let h01 = dot(&nodes, &image[0], &product[1]);
+        let h11 = dot(&nodes, &image[1], &product[1]);
+        let angle = 0.5 * (2. * h01).atan2(h00 - h11);
+        let (sin, cos) = angle.sin_cos();
+        let mut change: f64 = 0.;
+        for &i in &nodes {
+            let (a, b) = (image[0][i], image[1][i]);
+            image[0][i] = cos * a + sin * b;
+            image[1][i] = -sin * a + cos * b;
+        }
+        for k in 0..2 {
+            // eigenvectors are defined up to sign
+            let sign = dot(&nodes, &basis[k], &image[k]).signum();
+            for &i in &nodes {
+                change = change.max((sign * image[k][i] - basis[k][i]).abs());
+                basis[k][i] = sign * image[k][i];
+            }
+        }
+        if change < 1e-10 {
+            break;
+        }
+    }
+
+    let mut pos = vec![[0.; 2]; adj.len()];
+    for &i in &nodes {
+        pos[i] = [basis[0][i], basis[1][i]];
+    }
+    fit_unit_square(&nodes, &mut pos);
+    nodes
+        .into_iter()
+        .map(|i| (g.from_index(i), pos[i]))
+        .collect()
+}
+
+fn dot(nodes: &[usize], u: &[f64], v: &[f64]) -> f64 {
+    nodes.iter().map(|&i| u[i] * v[i]).sum()
+}
+
+/// Make the vectors orthogonal to each other and to the constant vector, and
+/// of unit length, by the Gram–Schmidt process.
+fn orthonormalize(nodes: &[usize], basis: &mut [Vec<f64>; 2]) {
+    for k in 0..2 {
+        let mean = nodes.iter().map(|&i| basis[k][i]).sum::<f64>() / nodes.len() as f64;
+        for &i in nodes {
+            basis[k][i] -= mean;
+        }
+        if k == 1 {
+            let projection = dot(nodes, &basis[0], &basis[1]);
+            for &i in nodes {
+                basis[1][i] -= projection * basis[0][i];
+            }
+        }
+        let length = dot(nodes, &basis[k], &basis[k]).sqrt();
+        if length > 0. {
+            for &i in nodes {
+                basis[k][i] /= length;
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Stress minimizing layout.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::{add, fit_unit_square, norm, scale, sub, SplitMix64};
+use crate::algo::dijkstra;
+use crate::visit::{IntoEdges, IntoNodeIdentifiers, NodeIndexable, Visitable};
+
+/// The Kamada–Kawai layout, which places nodes at distances that match their
+/// shortest path distances in the graph.
+///
+/// The layout minimizes the energy of Kamada and Kawai, also called the
+/// *stress*: the sum over all pairs of nodes of `(|pᵢ - pⱼ| - dᵢⱼ)² / dᵢⱼ²`,
+/// where `dᵢⱼ` is the shortest path distance between the nodes, computed with
+/// [`dijkstra`](../algo/dijkstra/fn.dijkstra.html). Starting from random
+/// positions, the nodes are moved one at a time by *stress majorization*,
+/// which never increases the stress, until no node moves by more than a small
+/// fraction of the largest distance, or the number of iterations is reached.
+///
+/// The distance of two nodes of a directed graph is the shorter of the two
+/// directions. Nodes in different connected components are placed as if at
+/// the largest distance of two connected nodes.
+///
+/// The layout is finally scaled to fit the unit square `[0, 1] × [0, 1]`,
+/// keeping its aspect ratio.
+///
+/// Computing the distances takes **O(|V| (|V| + |E|) log |V|)** time, each
+/// iteration takes **O(|V|²)** time, and the layout uses **O(|V|²)** space.
+///
+/// # Example
+/// ```rust
+/// use petgraph::layout::KamadaKawai;
+/// use petgraph::prelude::*;
+///
+/// // a path is laid out on a line
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let positions = KamadaKawai::new().layout(&g);
+/// let [x0, y0] = positions[&NodeIndex::new(0)];
+/// let [x3, y3] = positions[&NodeIndex::new(3)];
+/// assert!(((x3 - x0).powi(2) + (y3 - y0).powi(2)).sqrt() > 0.99);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KamadaKawai {
+    iterations: usize,
+    seed: u64,
+}
+
+impl Default for KamadaKawai {
+    fn default() -> Self {
+        KamadaKawai::new()
+    }
+}
+
+impl KamadaKawai {
+    /// Create a layout with at most 300 iterations and seed `0`.
+    pub fn new() -> Self {
+        KamadaKawai {
+            iterations: 300,
+            seed: 0,
+        }
+    }
+
+    /// Set the largest number of iterations.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Set the seed of the random initial positions.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Compute the position of every node of `g`, where every edge has
+    /// length one.
+    pub fn layout<G>(&self, g: G) -> HashMap<G::NodeId, [f64; 2]>
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+        G::NodeId: Eq + Hash,
+    {
+        self.layout_with(g, |_| 1.)
+    }
+
+    /// Compute the position of every node of `g`, where `edge_length` gives
+    /// the length of each edge, which must be non-negative.
+    pub fn layout_with<G, F>(&self, g: G, mut edge_length: F) -> HashMap<G::NodeId, [f64; 2]>
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+        G::NodeId: Eq + Hash,
+        F: FnMut(G::EdgeRef) -> f64,
+    {
+        let nodes = g
+            .node_identifiers()
+            .map(|a| g.to_index(a))
+            .collect::<Vec<_>>();
+        let n = g.node_bound();
+        let mut dist = vec![std::f64::INFINITY; n * n];
+        for &i in &nodes {
+            for (b, d) in dijkstra(g, g.from_index(i), None, &mut edge_length) {
+                let j = g.to_index(b);
+                dist[i * n + j] = dist[i * n + j].min(d);
+                dist[j * n + i] = dist[j * n + i].min(d);
+            }
+        }
+        let largest = dist
+            .iter()
+            .cloned()
+            .filter(|d| d.is_finite())
+            .fold(0., f64::max);
+        for d in &mut dist {
+            if d.is_infinite() {
+                *d = largest;
+            }
+        }
+
+        let mut rng = SplitMix64(self.seed);
+        let mut pos = vec![[0.; 2]; n];
+        for &i in &nodes {
+            pos[i] = [rng.next_f64() * largest, rng.next_f64() * largest];
+        }
+        for _ in 0..self.iterations {
+            let mut moved: f64 = 0.;
+            for &i in &nodes {
+                // Minimize the stress as a function of `pos[i]` alone, using
+                // the directions to the other nodes from the old position.
+                let mut sum = [0., 0.];
+                let mut weight = 0.;
+                for &j in &nodes {
+                    let d = dist[i * n + j];
+                    if i == j || d == 0. {
+                        continue;
+                    }
+                    let w = 1. / (d * d);
+                    let delta = sub(pos[i], pos[j]);
+                    let length = norm(delta);
+                    let target = if length > 0. {
+                        add(pos[j], scale(delta, d / length))
+                    } else {
+                        pos[j]
+                    };
+                    sum = add(sum, scale(target, w));
+                    weight += w;
+                }
+                if weight > 0. {
+                    let next = scale(sum, 1. / weight);
+                    moved = moved.max(norm(sub(next, pos[i])));
+                    pos[i] = next;
+                }
+            }
+            if moved <= 1e-4 * largest {
+                break;
+            }
+        }
+
+        fit_unit_square(&nodes, &mut pos);
+        nodes
+            .into_iter()
+            .map(|i| (g.from_index(i), pos[i]))
+            .collect()
+    }
+}
@@ -49,6 +49,12 @@
 //!   An adjacency list graph with arbitrary associated data.
 //! * [`StableGraph`](./stable_graph/struct.StableGraph.html) -
 //!   Similar to `Graph`, but it keeps indices stable across removals.
+//! * [`GenerationalGraph`](./generational/struct.GenerationalGraph.html) -
+//!   A `Graph` with generational handles, that detect when they refer to a
+//!   removed node or edge.
+//! * [`ObservedGraph`](./observed/struct.ObservedGraph.html) -
+//!   A `Graph` that tells an observer about every change to it, to keep
+//!   derived data in sync.
 //! * [`GraphMap`](./graphmap/struct.GraphMap.html) -
 //!   An adjacency list graph backed by a hash table. The node identifiers are the keys
 //!   into the table.
@@ -56,6 +62,21 @@
 //!   An adjacency matrix graph.
 //! * [`CSR`](./csr/struct.Csr.html) -
 //!   A sparse adjacency matrix graph with arbitrary associated data.
+//! * [`StaticGraph`](./static_graph/struct.StaticGraph.html) -
+//!   An immutable graph, laid out for fast traversal.
+//! * [`BitSetGraph`](./bitset_graph/struct.BitSetGraph.html) -
+//!   A graph with the neighbors of each node in a compressed bitset, for fast
+//!   neighborhood intersections.
+//! * [`HyperGraph`](./hypergraph/struct.HyperGraph.html) -
+//!   A graph where an edge connects any number of nodes.
+//! * [`BipartiteGraph`](./bipartite_graph/struct.BipartiteGraph.html) -
+//!   A graph with two sides of nodes of different types, and edges only
+//!   between the sides.
+//! * [`ConcurrentGraph`](./concurrent_graph/struct.ConcurrentGraph.html) -
+//!   A graph that threads can read and add to concurrently.
+//! * [`PersistentGraph`](./persistent_graph/struct.PersistentGraph.html) -
+//!   An immutable graph where adding a node or an edge makes a new version,
+//!   that shares most of its structure with the old one.
 //!
 //! ### Generic parameters
 //!
@@ -93,15 +114,38 @@
 //! # Crate features
 //!
 //! * **serde-1** -
-//!   Defaults off. Enables serialization for ``Graph, StableGraph`` using
+//!   Defaults off. Enables serialization for ``Graph, StableGraph, MatrixGraph`` using
 //!   [`serde 1.0`](https://crates.io/crates/serde). May require a more recent version
 //!   of Rust than petgraph alone.
+//! * **node_link** -
+//!   Defaults off. Enables JSON [node-link data](./node_link/index.html), using
+//!   [`serde_json`](https://crates.io/crates/serde_json). Implies **serde-1**.
 //! * **graphmap** -
 //!   Defaults on. Enables [`GraphMap`](./graphmap/struct.GraphMap.html).
 //! * **stable_graph** -
 //!   Defaults on. Enables [`StableGraph`](./stable_graph/struct.StableGraph.html).
 //! * **matrix_graph** -
 //!   Defaults on. Enables [`MatrixGraph`](./matrix_graph/struct.MatrixGraph.html).
+//! * **rand** -
+//!   Defaults off. Enables randomized algorithms and random graph generators,
+//!   which take a random number generator from [`rand`](https://crates.io/crates/rand).
+//! * **spectral** -
+//!   Defaults off. Enables
+//!   [`spectral_layout`](./layout/fn.spectral_layout.html).
+//! * **bitset_graph** -
+//!   Defaults off. Enables [`BitSetGraph`](./bitset_graph/struct.BitSetGraph.html).
+//! * **graphml** -
+//!   Defaults off. Enables [GraphML](./graphml/index.html) output and reading.
+//! * **ndarray** -
+//!   Defaults off. Enables building [matrices](./linalg/index.html) of graphs
+//!   as [`ndarray`](https://crates.io/crates/ndarray) arrays. May require a more
+//!   recent version of Rust than petgraph alone.
+//! * **rayon** -
+//!   Defaults off. Enables parallel iterators over the nodes and edges of
+//!   `Graph`, `StableGraph` and `Csr`, the `IntoParallelNodeReferences` and
+//!   `IntoParallelEdgeReferences` traits, and parallel algorithms, using
+//!   [`rayon`](https://crates.io/crates/rayon).
+//!   May require a more recent version of Rust than petgraph alone.
 //!
 #![doc(html_root_url = "https://docs.rs/petgraph/0.4/")]
 
@@ -109,11 +153,19 @@ extern crate fixedbitset;
 #[cfg(feature = "graphmap")]
 extern crate indexmap;
 
+#[cfg(feature = "ndarray")]
+extern crate ndarray;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 #[cfg(feature = "serde-1")]
 extern crate serde;
 #[cfg(feature = "serde-1")]
 #[macro_use]
 extern crate serde_derive;
+#[cfg(feature = "node_link")]
+extern crate serde_json;
 
 #[cfg(all(feature = "serde-1", test))]
 extern crate itertools;
@@ -135,40 +187,66 @@ pub mod data;
 
 pub mod adj;
 pub mod algo;
+pub mod bipartite_graph;
+#[cfg(feature = "bitset_graph")]
+pub mod bitset_graph;
+pub mod concurrent_graph;
+pub mod convert;
 pub mod csr;
+pub mod csv;
 pub mod dot;
 #[cfg(feature = "generate")]
 pub mod generate;
+pub mod generators;
+pub mod gexf;
 mod graph_impl;
 #[cfg(feature = "graphmap")]
 pub mod graphmap;
+#[cfg(feature = "graphml")]
+pub mod graphml;
+pub mod hypergraph;
 mod iter_format;
 mod iter_utils;
+pub mod layout;
+pub mod linalg;
 #[cfg(feature = "matrix_graph")]
 pub mod matrix_graph;
+pub mod matrix_market;
+#[cfg(feature = "node_link")]
+pub mod node_link;
+pub mod pajek;
 #[cfg(feature = "quickcheck")]
 mod quickcheck;
 #[cfg(feature = "serde-1")]
 mod serde_utils;
+pub mod svg;
 mod traits_graph;
 pub mod unionfind;
 mod util;
 
 pub mod operator;
 pub mod prelude;
+pub mod pretty;
 
 /// `Graph<N, E, Ty, Ix>` is a graph datastructure using an adjacency list representation.
 pub mod graph {
     pub use crate::graph_impl::{
         edge_index, node_index, DefaultIx, DiGraph, Edge, EdgeIndex, EdgeIndices, EdgeReference,
         EdgeReferences, EdgeWeightsMut, Edges, EdgesConnecting, Externals, Frozen, Graph,
-        GraphIndex, IndexType, Neighbors, Node, NodeIndex, NodeIndices, NodeReferences,
-        NodeWeightsMut, UnGraph, WalkNeighbors,
+        GraphError, GraphIndex, IndexShift, IndexType, MemoryUsage, Neighbors, Node, NodeIndex,
+        NodeIndices, NodeReferences, NodeWeightsMut, UnGraph, WalkNeighbors,
     };
 }
 
+pub use crate::graph_impl::attributes;
+pub use crate::graph_impl::builder;
+pub use crate::graph_impl::generational;
+pub use crate::graph_impl::keyed_graph;
+pub use crate::graph_impl::observed;
+pub use crate::graph_impl::persistent_graph;
 #[cfg(feature = "stable_graph")]
 pub use crate::graph_impl::stable_graph;
+pub use crate::graph_impl::static_graph;
 
 macro_rules! copyclone {
     ($name:ident) => {
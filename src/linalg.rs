@@ -0,0 +1,149 @@
+//! Adjacency, incidence and Laplacian matrices of graphs.
+//!
+//! The matrices are built into any type that implements [`Matrix`]: dense
+//! `Vec<Vec<f64>>` rows, sparse `Vec<(usize, usize, f64)>` triplets (as taken
+//! by `sprs::TriMat::from_triplets`, say), or with crate feature `ndarray`,
+//! `ndarray::Array2<f64>`. Rows and columns of nodes are numbered by their
+//! index from `NodeIndexable`, and the weight of each edge is given by a
+//! closure.
+//!
+//! ```
+//! use petgraph::linalg::{adjacency_matrix, laplacian_matrix};
+//! use petgraph::prelude::*;
+//!
+//! let g = UnGraph::<(), f64>::from_edges(&[(0, 1, 2.), (1, 2, 0.5)]);
+//! let a: Vec<Vec<f64>> = adjacency_matrix(&g, |e| *e.weight());
+//! assert_eq!(a, [[0., 2., 0.], [2., 0., 0.5], [0., 0.5, 0.]]);
+//!
+//! let l: Vec<(usize, usize, f64)> = laplacian_matrix(&g, |_| 1.);
+//! assert_eq!(l[..4], [(0, 0, 1.), (1, 1, 1.), (0, 1, -1.), (1, 0, -1.)]);
+//! ```
+//!
+//! [`Matrix`]: trait.Matrix.html
+
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+/// A matrix that can be built entry by entry.
+pub trait Matrix {
+    /// Create a matrix of zeros, with `rows` rows and `columns` columns.
+    fn zeros(rows: usize, columns: usize) -> Self;
+    /// Add `value` to the entry in `row` and `column`.
+    fn add(&mut self, row: usize, column: usize, value: f64);
+}
+
+/// A dense matrix, as a vector of rows.
+impl Matrix for Vec<Vec<f64>> {
+    fn zeros(rows: usize, columns: usize) -> Self {
+        vec![vec![0.; columns]; rows]
+    }
+
+    fn add(&mut self, row: usize, column: usize, value: f64) {
+        self[row][column] += value;
+    }
+}
+
+/// A sparse matrix, as a list of `(row, column, value)` triplets, where the
+/// values of repeated entries add up.
+impl Matrix for Vec<(usize, usize, f64)> {
+    fn zeros(_rows: usize, _columns: usize) -> Self {
+        Vec::new()
+    }
+
+    fn add(&mut self, row: usize, column: usize, value: f64) {
+        self.push((row, column, value));
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl Matrix for ndarray::Array2<f64> {
+    fn zeros(rows: usize, columns: usize) -> Self {
+        ndarray::Array2::zeros((rows, columns))
+    }
+
+    fn add(&mut self, row: usize, column: usize, value: f64) {
+        self[[row, column]] += value;
+    }
+}
+
+/// \[Generic\] Return the weighted adjacency matrix of the graph.
+///
+/// The entry in row `i` and column `j` is the sum of the weights of the edges
+/// from `i` to `j`. In an undirected graph, each edge is counted from both of
+/// its ends, so the matrix is symmetric, and a self loop is counted once.
+pub fn adjacency_matrix<M, G, F>(g: G, mut edge_weight: F) -> M
+where
+    M: Matrix,
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let n = g.node_bound();
+    let mut matrix = M::zeros(n, n);
+    for edge in g.edge_references() {
+        let (s, t) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        let w = edge_weight(edge);
+        matrix.add(s, t, w);
+        if !g.is_directed() && s != t {
+            matrix.add(t, s, w);
+        }
+    }
+    matrix
+}
+
+/// \[Generic\] Return the weighted incidence matrix of the graph, with a row
+/// for each node and a column for each edge, in the order of
+/// `edge_references`.
+///
+/// In a directed graph, the column of an edge of weight `w` is `-w` at its
+/// source and `w` at its target, so it is all zeros for a self loop. In an
+/// undirected graph, it is `w` at both ends, and `2w` for a self loop.
+pub fn incidence_matrix<M, G, F>(g: G, mut edge_weight: F) -> M
+where
+    M: Matrix,
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let mut matrix = M::zeros(g.node_bound(), g.edge_references().count());
+    for (k, edge) in g.edge_references().enumerate() {
+        let (s, t) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        let w = edge_weight(edge);
+        if !g.is_directed() {
+            matrix.add(s, k, w);
+            matrix.add(t, k, w);
+        } else if s != t {
+            matrix.add(s, k, -w);
+            matrix.add(t, k, w);
+        }
+    }
+    matrix
+}
+
+/// \[Generic\] Return the weighted Laplacian matrix `L = D - A` of the graph.
+///
+/// `A` is the [adjacency matrix](fn.adjacency_matrix.html), and `D` is the
+/// diagonal matrix of the weighted degrees of the nodes: the row sums of `A`,
+/// which for a directed graph are the out-degrees. Self loops cancel out.
+pub fn laplacian_matrix<M, G, F>(g: G, mut edge_weight: F) -> M
+where
+    M: Matrix,
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> f64,
+{
+    let n = g.node_bound();
+    let mut matrix = M::zeros(n, n);
+    for edge in g.edge_references() {
+        let (s, t) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        if s == t {
+            continue;
+        }
+        let w = edge_weight(edge);
+        matrix.add(s, s, w);
+        if !g.is_directed() {
+            matrix.add(t, t, w);
+        }
+        matrix.add(s, t, -w);
+        if !g.is_directed() {
+            matrix.add(t, s, -w);
+        }
+    }
+    matrix
+}
@@ -106,3 +106,71 @@ impl (DoubleEndedIterator $($rest:tt)*)  for
             );
     );
 }
+
+/// Create a graph from a literal list of nodes and edges.
+///
+/// Nodes are listed first, each as a name, optionally followed by `:` and its
+/// weight. Edges follow as `a -> b`, optionally followed by `:` and the edge
+/// weight. Weights that are left out are `Default::default()`. The nodes are
+/// added in the order they are listed, then the edges.
+///
+/// The graph can be of any type that implements [`Build`] and `Default`, and
+/// is inferred from the context; edges of an undirected graph are written
+/// `a -> b` too. The node names are bound to the node identifiers within
+/// the macro only.
+///
+/// [`Build`]: data/trait.Build.html
+///
+/// ```
+/// # #[cfg(feature = "graphmap")] {
+/// use petgraph::graph::{DiGraph, NodeIndex};
+/// use petgraph::graphmap::UnGraphMap;
+///
+/// let g: DiGraph<&str, u32> = petgraph::graph! {
+///     nodes: { a: "A", b: "B", c },
+///     edges: { a -> b: 1, b -> c: 2, c -> a },
+/// };
+/// assert_eq!(g.node_count(), 3);
+/// assert_eq!(g[NodeIndex::new(2)], "");
+/// let ca = g.find_edge(NodeIndex::new(2), NodeIndex::new(0)).unwrap();
+/// assert_eq!(g[ca], 0);
+///
+/// // a `GraphMap` node is its own weight
+/// let m: UnGraphMap<char, ()> = petgraph::graph! {
+///     nodes: { a: 'a', b: 'b' },
+///     edges: { b -> a },
+/// };
+/// assert!(m.contains_edge('a', 'b'));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! graph {
+    (@weight) => {
+        ::std::default::Default::default()
+    };
+    (@weight $weight:expr) => {
+        $weight
+    };
+    (
+        nodes: { $($node:ident $(: $node_weight:expr)?),* $(,)? }
+        $(, edges: { $($a:ident -> $b:ident $(: $edge_weight:expr)?),* $(,)? })?
+        $(,)?
+    ) => {{
+        let mut graph = ::std::default::Default::default();
+        $(
+            let $node = $crate::data::Build::add_node(
+                &mut graph,
+                $crate::graph!(@weight $($node_weight)?),
+            );
+        )*
+        $($(
+            $crate::data::Build::add_edge(
+                &mut graph,
+                $a,
+                $b,
+                $crate::graph!(@weight $($edge_weight)?),
+            );
+        )*)?
+        graph
+    }};
+}
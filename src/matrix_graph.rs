@@ -4,6 +4,7 @@ use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
 
 use std::cmp;
+use std::convert::TryFrom;
 use std::mem;
 
 use indexmap::IndexSet;
@@ -12,18 +13,26 @@ use fixedbitset::FixedBitSet;
 
 use crate::{Directed, Direction, EdgeType, IntoWeightedEdge, Outgoing, Undirected};
 
+use crate::convert::ConversionError;
 use crate::graph::NodeIndex as GraphNodeIndex;
+use crate::graph::{Graph, GraphError, MemoryUsage};
+use crate::graph_impl::{index_available, vec_usage};
+#[cfg(feature = "stable_graph")]
+use crate::stable_graph::StableGraph;
 
 use crate::visit::{
     Data, EdgeCount, GetAdjacencyMatrix, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
     IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
-    IntoNodeReferences, NodeCount, NodeIndexable, Visitable,
+    IntoNodeReferences, NodeCount, NodeDegrees, NodeIndexable, Visitable,
 };
 
 use crate::data::Build;
 
 pub use crate::graph::IndexType;
 
+#[cfg(feature = "serde-1")]
+mod serialization;
+
 // The following types are used to control the max size of the adjacency matrix. Since the maximum
 // size of the matrix vector's is the square of the maximum number of nodes, the number of nodes
 // should be reasonably picked.
@@ -202,8 +211,11 @@ pub fn node_index(ax: usize) -> NodeIndex {
 /// as efficient graph search and graph algorithms on dense graphs.
 ///
 /// This graph is backed by a flattened 2D array. For undirected graphs, only the lower triangular
-/// matrix is stored. Since the backing array stores edge weights, it is recommended to box large
-/// edge weights.
+/// matrix is stored, which takes **|V| (|V| + 1) / 2** entries instead of **|V|^2**. Since the
+/// backing array stores edge weights, it is recommended to box large edge weights.
+///
+/// With crate feature `serde-1`, `MatrixGraph` can be serialized and deserialized with the same
+/// format as [`StableGraph`](../stable_graph/struct.StableGraph.html), keeping its node indices.
 #[derive(Clone)]
 pub struct MatrixGraph<N, E, Ty = Directed, Null: Nullable<Wrapped = E> = Option<E>, Ix = DefaultIx>
 {
@@ -298,8 +310,24 @@ impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType>
         NodeIndex::new(self.nodes.add(weight))
     }
 
+    /// Add a node (also called vertex) with associated data `weight` to the
+    /// graph, or return an error if the graph is at the maximum number of
+    /// nodes for its index type.
+    ///
+    /// Computes in **O(1)** time.
+    pub fn try_add_node(&mut self, weight: N) -> Result<NodeIndex<Ix>, GraphError> {
+        if self.nodes.removed_ids.is_empty() && !index_available::<Ix>(self.nodes.upper_bound) {
+            return Err(GraphError::NodeIxLimit);
+        }
+        Ok(self.add_node(weight))
+    }
+
     /// Remove `a` from the graph.
     ///
+    /// The indices of all other nodes stay valid. The index of `a` becomes
+    /// vacant and is reused by a later [`.add_node()`](#method.add_node); use
+    /// [`.compact()`](#method.compact) to close the gaps instead.
+    ///
     /// Computes in **O(V)** time, due to the removal of edges with other nodes.
     ///
     /// **Panics** if the node `a` does not exist.
@@ -307,13 +335,17 @@ impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType>
         for id in self.nodes.iter_ids() {
             let position = self.to_edge_position(a, NodeIndex::new(id));
             if let Some(pos) = position {
-                self.node_adjacencies[pos] = Default::default();
+                if !mem::take(&mut self.node_adjacencies[pos]).is_null() {
+                    self.nb_edges -= 1;
+                }
             }
 
             if Ty::is_directed() {
                 let position = self.to_edge_position(NodeIndex::new(id), a);
                 if let Some(pos) = position {
-                    self.node_adjacencies[pos] = Default::default();
+                    if !mem::take(&mut self.node_adjacencies[pos]).is_null() {
+                        self.nb_edges -= 1;
+                    }
                 }
             }
         }
@@ -321,6 +353,167 @@ impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType>
         self.nodes.remove(a.index())
     }
 
+    /// Return `true` if the node `a` exists in the graph.
+    pub fn contains_node(&self, a: NodeIndex<Ix>) -> bool {
+        self.nodes
+            .elements
+            .get(a.index())
+            .map_or(false, Option::is_some)
+    }
+
+    /// Move the nodes to close the gaps left by removed nodes, keeping their
+    /// order, and shrink the adjacency matrix to fit.
+    ///
+    /// Return a map from the old node indices to the new ones, where
+    /// `NodeIndex::end()` marks a vacant old index.
+    ///
+    /// Computes in **O(|V|^2)** time.
+    ///
+    /// ```
+    /// use petgraph::matrix_graph::MatrixGraph;
+    ///
+    /// let mut g = MatrixGraph::<_, _>::new();
+    /// let a = g.add_node('a');
+    /// let b = g.add_node('b');
+    /// let c = g.add_node('c');
+    /// g.add_edge(a, c, 1);
+    /// g.remove_node(b);
+    ///
+    /// let node_map = g.compact();
+    /// let (a, c) = (node_map[a.index()], node_map[c.index()]);
+    /// assert_eq!(c.index(), 1);
+    /// assert_eq!(g[c], 'c');
+    /// assert_eq!(g[(a, c)], 1);
+    /// ```
+    pub fn compact(&mut self) -> Vec<NodeIndex<Ix>> {
+        let old_capacity = self.node_capacity;
+        let mut old_adjacencies = mem::take(&mut self.node_adjacencies);
+        let old_nodes = mem::replace(&mut self.nodes, IdStorage::with_capacity(0));
+        *self = Self::with_capacity(old_nodes.len());
+
+        let mut node_map = vec![NodeIndex::end(); old_nodes.upper_bound];
+        for (i, weight) in old_nodes.elements.into_iter().enumerate() {
+            if let Some(weight) = weight {
+                node_map[i] = self.add_node(weight);
+            }
+        }
+        // nodes without edges may lie outside of the matrix
+        let bound = cmp::min(node_map.len(), old_capacity);
+        for a in 0..bound {
+            let columns = if Ty::is_directed() { bound } else { a + 1 };
+            for b in 0..columns {
+                let p = to_linearized_matrix_position::<Ty>(a, b, old_capacity);
+                let edge = mem::take(&mut old_adjacencies[p]);
+                if !edge.is_null() {
+                    let q = self.to_edge_position_unchecked(node_map[a], node_map[b]);
+                    self.node_adjacencies[q] = edge;
+                    self.nb_edges += 1;
+                }
+            }
+        }
+        node_map
+    }
+
+    /// Create a graph without edges, with the nodes `nodes` by index, where
+    /// `None` is a vacancy.
+    fn from_vacant_nodes(nodes: Vec<Option<N>>) -> Self {
+        let mut graph = Self::with_capacity(nodes.len());
+        let removed_ids = nodes
+            .iter()
+            .enumerate()
+            .filter(|&(_, node)| node.is_none())
+            .map(|(i, _)| i)
+            .collect();
+        graph.nodes = IdStorage {
+            upper_bound: nodes.len(),
+            elements: nodes,
+            removed_ids,
+        };
+        graph
+    }
+
+    /// Return the nodes by index, where `None` is a vacancy, and the edges
+    /// with their endpoints, in the order of `edge_references`.
+    #[allow(clippy::type_complexity)]
+    fn into_vacant_nodes_edges(
+        mut self,
+    ) -> (Vec<Option<N>>, Vec<(NodeIndex<Ix>, NodeIndex<Ix>, E)>) {
+        let mut edges = Vec::with_capacity(self.nb_edges);
+        // nodes without edges may lie outside of the matrix
+        let bound = cmp::min(self.nodes.upper_bound, self.node_capacity);
+        for a in 0..bound {
+            let columns = if Ty::is_directed() { bound } else { a + 1 };
+            for b in 0..columns {
+                let p = to_linearized_matrix_position::<Ty>(a, b, self.node_capacity);
+                if let Some(weight) = mem::take(&mut self.node_adjacencies[p]).into() {
+                    edges.push((NodeIndex::new(a), NodeIndex::new(b), weight));
+                }
+            }
+        }
+        let mut nodes = self.nodes.elements;
+        nodes.truncate(self.nodes.upper_bound);
+        (nodes, edges)
+    }
+
+    /// Remove the vacant node entries at the end of the graph and shrink the
+    /// adjacency matrix and the other storage as much as possible.
+    ///
+    /// Unlike [`.compact()`](#method.compact), this keeps all node indices
+    /// valid.
+    ///
+    /// Computes in **O(|V|^2)** time.
+    ///
+    /// ```
+    /// use petgraph::matrix_graph::MatrixGraph;
+    ///
+    /// let mut g = MatrixGraph::<u32, ()>::with_capacity(100);
+    /// let a = g.add_node(0);
+    /// let b = g.add_node(1);
+    /// g.add_edge(a, b, ());
+    /// let before = g.memory_usage();
+    ///
+    /// g.shrink_to_fit();
+    /// let after = g.memory_usage();
+    /// assert!(after.adjacency < before.adjacency);
+    /// assert_eq!(after.unused, 0);
+    /// assert!(g.has_edge(a, b));
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        let new_capacity = cmp::min(self.node_capacity, self.nodes.upper_bound);
+        shrink_linearized_matrix::<Ty, _>(
+            &mut self.node_adjacencies,
+            self.node_capacity,
+            new_capacity,
+        );
+        self.node_capacity = new_capacity;
+    }
+
+    /// Return an estimate of the heap memory held by the graph.
+    ///
+    /// The cells of the adjacency matrix without an edge are counted as
+    /// adjacency, and removed node indices kept for reuse as vacant.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let (nodes, unused_nodes) = vec_usage(&self.nodes.elements, self.nodes.elements.capacity());
+        let (matrix, unused_matrix) =
+            vec_usage(&self.node_adjacencies, self.node_adjacencies.capacity());
+        let node_size = mem::size_of::<Option<N>>();
+        // an entry of the set, with its hash and its slot in the hash table
+        let removed_id = 3 * mem::size_of::<usize>();
+        let removed_ids = &self.nodes.removed_ids;
+        let vacant_nodes = self.nodes.elements.len() - self.nodes.len();
+        let edges = self.nb_edges * mem::size_of::<Null>();
+        MemoryUsage {
+            nodes: nodes - vacant_nodes * node_size,
+            edges,
+            vacant: vacant_nodes * node_size + removed_ids.len() * removed_id,
+            adjacency: matrix - edges,
+            unused: unused_nodes
+                + unused_matrix
+                + (removed_ids.capacity() - removed_ids.len()) * removed_id,
+        }
+    }
+
     #[inline]
     fn extend_capacity_for_node(&mut self, min_node: NodeIndex<Ix>, exact: bool) {
         self.node_capacity = extend_linearized_matrix::<Ty, _>(
@@ -373,6 +566,41 @@ impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType>
         assert!(old_edge_id.is_none());
     }
 
+    /// Add an edge from `a` to `b` to the graph, with its associated
+    /// data `weight`, or return an error if either node doesn't exist or
+    /// there already is an edge from `a` to `b`.
+    ///
+    /// Computes in **O(1)** time, best case.
+    /// Computes in **O(|V|^2)** time, worst case (matrix needs to be re-allocated).
+    ///
+    /// ```
+    /// use petgraph::graph::GraphError;
+    /// use petgraph::matrix_graph::MatrixGraph;
+    ///
+    /// let mut g = MatrixGraph::<(), ()>::new();
+    /// let a = g.add_node(());
+    /// let b = g.add_node(());
+    /// assert_eq!(g.try_add_edge(a, b, ()), Ok(()));
+    /// assert_eq!(g.try_add_edge(a, b, ()), Err(GraphError::EdgeExists));
+    /// ```
+    pub fn try_add_edge(
+        &mut self,
+        a: NodeIndex<Ix>,
+        b: NodeIndex<Ix>,
+        weight: E,
+    ) -> Result<(), GraphError> {
+        for &n in &[a, b] {
+            if !self.contains_node(n) {
+                return Err(GraphError::NodeMissed(n.index()));
+            }
+        }
+        if self.has_edge(a, b) {
+            return Err(GraphError::EdgeExists);
+        }
+        self.update_edge(a, b, weight);
+        Ok(())
+    }
+
     /// Remove the edge from `a` to `b` to the graph.
     ///
     /// **Panics** if any of the nodes don't exist.
@@ -825,6 +1053,29 @@ fn extend_linearized_matrix<Ty: EdgeType, T: Default>(
     }
 }
 
+/// Shrink a matrix built by `extend_linearized_matrix` to `new_capacity`
+/// nodes, which must not have any edges to nodes past it.
+fn shrink_linearized_matrix<Ty: EdgeType, T>(
+    node_adjacencies: &mut Vec<T>,
+    old_capacity: usize,
+    new_capacity: usize,
+) {
+    if Ty::is_directed() {
+        for row in 1..new_capacity {
+            for column in 0..new_capacity {
+                node_adjacencies.swap(
+                    to_flat_square_matrix_position(row, column, old_capacity),
+                    to_flat_square_matrix_position(row, column, new_capacity),
+                );
+            }
+        }
+        node_adjacencies.truncate(new_capacity * new_capacity);
+    } else {
+        node_adjacencies.truncate(new_capacity * (new_capacity + 1) / 2);
+    }
+    node_adjacencies.shrink_to_fit();
+}
+
 #[inline]
 fn to_flat_square_matrix_position(row: usize, column: usize, width: usize) -> usize {
     row * width + column
@@ -953,6 +1204,16 @@ impl<T> IdStorage<T> {
         self.removed_ids.clear();
     }
 
+    /// Drop the removed ids at the end and shrink the storage to fit.
+    fn shrink_to_fit(&mut self) {
+        while self.upper_bound > 0 && self.removed_ids.swap_remove(&(self.upper_bound - 1)) {
+            self.upper_bound -= 1;
+        }
+        self.elements.truncate(self.upper_bound);
+        self.elements.shrink_to_fit();
+        self.removed_ids.shrink_to_fit();
+    }
+
     #[inline]
     fn len(&self) -> usize {
         self.upper_bound - self.removed_ids.len()
@@ -1086,6 +1347,20 @@ impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType> EdgeCount
     }
 }
 
+impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType> NodeDegrees
+    for MatrixGraph<N, E, Ty, Null, Ix>
+{
+    /// Computes in **O(|V|)** time.
+    fn degree_directed(&self, a: NodeIndex<Ix>, d: Direction) -> usize {
+        if d == Outgoing || !Ty::is_directed() {
+            self.neighbors(a).count()
+        } else {
+            Edges::<Ty, Null, Ix>::on_rows(a.index(), &self.node_adjacencies, self.node_capacity)
+                .count()
+        }
+    }
+}
+
 /// Index the `MatrixGraph` by `NodeIndex` pair to access edge weights.
 ///
 /// Also available with indexing syntax: `&graph[e]`.
@@ -1132,12 +1407,12 @@ impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType> Visitable
     type Map = FixedBitSet;
 
     fn visit_map(&self) -> FixedBitSet {
-        FixedBitSet::with_capacity(self.node_count())
+        FixedBitSet::with_capacity(self.node_bound())
     }
 
     fn reset_map(&self, map: &mut Self::Map) {
         map.clear();
-        map.grow(self.node_count());
+        map.grow(self.node_bound());
     }
 }
 
@@ -1234,7 +1509,7 @@ impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType> NodeIndexab
     for MatrixGraph<N, E, Ty, Null, Ix>
 {
     fn node_bound(&self) -> usize {
-        self.node_count()
+        self.nodes.upper_bound
     }
 
     fn to_index(&self, ix: NodeIndex<Ix>) -> usize {
@@ -1278,9 +1553,108 @@ impl<N, E, Ty: EdgeType, Null: Nullable<Wrapped = E>, Ix: IndexType> Build
     }
 }
 
+/// Convert a `MatrixGraph` into a `Graph`, with its nodes numbered
+/// consecutively in order.
+///
+/// Computes in **O(|V|^2)** time.
+impl<N, E, Ty, Null, Ix> From<MatrixGraph<N, E, Ty, Null, Ix>> for Graph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    fn from(graph: MatrixGraph<N, E, Ty, Null, Ix>) -> Self {
+        let (nodes, edges) = graph.into_vacant_nodes_edges();
+        let mut g = Graph::with_capacity(nodes.len(), edges.len());
+        let node_map = nodes
+            .into_iter()
+            .map(|weight| weight.map(|weight| g.add_node(weight)))
+            .collect::<Vec<_>>();
+        for (a, b, weight) in edges {
+            g.add_edge(
+                node_map[a.index()].unwrap(),
+                node_map[b.index()].unwrap(),
+                weight,
+            );
+        }
+        g
+    }
+}
+
+/// Convert a `Graph` into a `MatrixGraph`, failing if it has parallel edges.
+///
+/// The nodes keep their indices.
+impl<N, E, Ty, Null, Ix> TryFrom<Graph<N, E, Ty, Ix>> for MatrixGraph<N, E, Ty, Null, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: Graph<N, E, Ty, Ix>) -> Result<Self, ConversionError> {
+        let (nodes, edges) = graph.into_nodes_edges();
+        let mut g =
+            Self::from_vacant_nodes(nodes.into_iter().map(|node| Some(node.weight)).collect());
+        for edge in edges {
+            let (a, b) = (edge.source(), edge.target());
+            if g.has_edge(a, b) {
+                return Err(ConversionError::ParallelEdges);
+            }
+            g.add_edge(a, b, edge.weight);
+        }
+        Ok(g)
+    }
+}
+
+/// Convert a `MatrixGraph` into a `StableGraph`, keeping the node indices.
+///
+/// Computes in **O(|V|^2)** time.
+#[cfg(feature = "stable_graph")]
+impl<N, E, Ty, Null, Ix> From<MatrixGraph<N, E, Ty, Null, Ix>> for StableGraph<N, E, Ty, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    fn from(graph: MatrixGraph<N, E, Ty, Null, Ix>) -> Self {
+        let (nodes, edges) = graph.into_vacant_nodes_edges();
+        let mut g = StableGraph::from_vacant_nodes(nodes);
+        for (a, b, weight) in edges {
+            g.add_edge(a, b, weight);
+        }
+        g
+    }
+}
+
+/// Convert a `StableGraph` into a `MatrixGraph`, keeping the node indices,
+/// and failing if it has parallel edges.
+#[cfg(feature = "stable_graph")]
+impl<N, E, Ty, Null, Ix> TryFrom<StableGraph<N, E, Ty, Ix>> for MatrixGraph<N, E, Ty, Null, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    type Error = ConversionError;
+
+    fn try_from(graph: StableGraph<N, E, Ty, Ix>) -> Result<Self, ConversionError> {
+        let (nodes, edges) = graph.into_vacant_nodes_edges();
+        let mut g = Self::from_vacant_nodes(nodes);
+        for (a, b, weight) in edges {
+            if g.has_edge(a, b) {
+                return Err(ConversionError::ParallelEdges);
+            }
+            g.add_edge(a, b, weight);
+        }
+        Ok(g)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::visit::Dfs;
     use crate::{Incoming, Outgoing};
 
     #[test]
@@ -1304,6 +1678,20 @@ mod tests {
         assert_eq!(g.edge_count(), 0);
     }
 
+    #[test]
+    fn test_undirected_triangular_storage() {
+        let mut g = UnMatrix::<(), i32>::with_capacity(100);
+        assert_eq!(g.node_adjacencies.len(), 100 * 101 / 2);
+        let a = g.add_node(());
+        let b = g.add_node(());
+        g.add_edge(b, a, 1);
+        assert_eq!(g.edge_weight(a, b), &1);
+        assert_eq!(g.node_adjacencies.iter().filter(|e| e.is_some()).count(), 1);
+
+        let d = MatrixGraph::<(), i32>::with_capacity(100);
+        assert_eq!(d.node_adjacencies.len(), 100 * 100);
+    }
+
     #[test]
     fn test_node_indexing() {
         let mut g: MatrixGraph<char, ()> = MatrixGraph::new();
@@ -1560,6 +1948,7 @@ mod tests {
         g.remove_node(b);
 
         assert_eq!(g.node_count(), 2);
+        assert_eq!(g.edge_count(), 1);
 
         let a_neighbors = g.neighbors(a).into_sorted_vec();
         assert_eq!(a_neighbors, vec![]);
@@ -1582,6 +1971,7 @@ mod tests {
         g.remove_node(a);
 
         assert_eq!(g.node_count(), 2);
+        assert_eq!(g.edge_count(), 1);
 
         let b_neighbors = g.neighbors(b).into_sorted_vec();
         assert_eq!(b_neighbors, vec![c]);
@@ -1590,6 +1980,71 @@ mod tests {
         assert_eq!(c_neighbors, vec![b]);
     }
 
+    #[test]
+    fn test_remove_node_keeps_indices() {
+        let mut g = MatrixGraph::<_, _>::new();
+        let a = g.add_node('a');
+        let b = g.add_node('b');
+        let c = g.add_node('c');
+        let d = g.add_node('d');
+        g.add_edge(a, b, 1);
+        g.add_edge(c, a, 2);
+        g.add_edge(d, c, 3);
+        g.remove_node(b);
+        assert!(!g.contains_node(b));
+        assert!(g.contains_node(d));
+        assert_eq!(g[(c, a)], 2);
+        assert_eq!(g.node_bound(), 4);
+
+        // the search sees all nodes beyond the vacancy
+        let mut dfs = Dfs::new(&g, d);
+        let mut visited = Vec::new();
+        while let Some(n) = dfs.next(&g) {
+            visited.push(n);
+        }
+        assert_eq!(visited, vec![d, c, a]);
+
+        // the vacant index is reused
+        let e = g.add_node('e');
+        assert_eq!(e, b);
+        g.remove_node(e);
+        g.remove_node(a);
+
+        let node_map = g.compact();
+        assert_eq!(node_map, vec![NodeIndex::end(), NodeIndex::end(), a, b]);
+        let (c, d) = (node_map[c.index()], node_map[d.index()]);
+        assert_eq!(g.node_count(), 2);
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!((g[c], g[d]), ('c', 'd'));
+        assert_eq!(g[(d, c)], 3);
+        assert_eq!(g.node_adjacencies.len(), 4);
+    }
+
+    #[test]
+    fn test_compact_undirected() {
+        let mut g = UnMatrix::<_, _>::new_undirected();
+        let a = g.add_node('a');
+        let b = g.add_node('b');
+        let c = g.add_node('c');
+        let d = g.add_node('d');
+        g.add_edge(a, c, 1);
+        g.add_edge(c, c, 2);
+        g.add_edge(b, c, 3);
+        g.remove_node(b);
+
+        let node_map = g.compact();
+        let (a, c, d) = (
+            node_map[a.index()],
+            node_map[c.index()],
+            node_map[d.index()],
+        );
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g[(c, a)], 1);
+        assert_eq!(g[(c, c)], 2);
+        assert_eq!(g[d], 'd');
+        assert_eq!(g.neighbors(d).count(), 0);
+    }
+
     #[test]
     fn test_node_identifiers() {
         let mut g = MatrixGraph::new();
@@ -1782,4 +2237,67 @@ mod tests {
         assert!(!g.has_edge(a, b));
         assert_eq!(g.edge_count(), 0);
     }
+
+    #[test]
+    fn test_try_add() {
+        let mut g: MatrixGraph<(), (), Directed, Option<()>, u8> = MatrixGraph::default();
+        for _ in 0..255 {
+            g.try_add_node(()).unwrap();
+        }
+        assert_eq!(g.try_add_node(()), Err(GraphError::NodeIxLimit));
+        let a = NodeIndex::new(0);
+        let b = NodeIndex::new(1);
+        g.remove_node(b);
+        assert_eq!(g.try_add_edge(a, b, ()), Err(GraphError::NodeMissed(1)));
+        assert_eq!(g.try_add_node(()), Ok(b));
+        assert_eq!(g.try_add_edge(a, b, ()), Ok(()));
+        assert_eq!(g.try_add_edge(a, b, ()), Err(GraphError::EdgeExists));
+        assert_eq!(g.edge_count(), 1);
+    }
+
+    fn check_shrink_to_fit<Ty: EdgeType>() {
+        let mut g: MatrixGraph<usize, usize, Ty> = MatrixGraph::default();
+        let n: Vec<_> = (0..20).map(|i| g.add_node(i)).collect();
+        for i in 0..10 {
+            for j in 0..10 {
+                if (i + j) % 3 == 0 {
+                    g.update_edge(n[i], n[j], i * 10 + j);
+                }
+            }
+        }
+        g.add_edge(n[19], n[3], 1);
+        for &a in &[n[19], n[18], n[2], n[15]] {
+            g.remove_node(a);
+        }
+        let edges = |g: &MatrixGraph<usize, usize, Ty>| {
+            let mut edges = Vec::new();
+            for a in g.node_identifiers() {
+                for b in g.node_identifiers() {
+                    if g.has_edge(a, b) {
+                        edges.push((a, b, *g.edge_weight(a, b)));
+                    }
+                }
+            }
+            edges
+        };
+        let before = edges(&g);
+        let edge_count = g.edge_count();
+
+        g.shrink_to_fit();
+        assert_eq!(g.node_capacity, 18);
+        assert_eq!(g.memory_usage().unused, 0);
+        assert_eq!(edges(&g), before);
+        assert_eq!(g.add_node(15), n[15]);
+        assert_eq!(g.add_node(2), n[2]);
+        assert_eq!(g.add_node(18), n[18]);
+        g.add_edge(n[18], n[0], 0);
+        assert!(g.has_edge(n[18], n[0]));
+        assert_eq!(g.edge_count(), edge_count + 1);
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        check_shrink_to_fit::<Directed>();
+        check_shrink_to_fit::<Undirected>();
+    }
 }
@@ -0,0 +1,227 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::marker::PhantomData;
+
+use indexmap::IndexSet;
+
+use crate::graph::IndexType;
+use crate::graph_impl::serialization::{
+    invalid_hole_err, invalid_length_err, invalid_node_err, EdgeProperty,
+};
+use crate::serde_utils::{CollectSeqWithLength, FromDeserialized, IntoSerializable};
+use crate::visit::IntoEdgeReferences;
+use crate::EdgeType;
+
+use super::{IdStorage, MatrixGraph, NodeIndex, Nullable};
+
+// Serialization representation for MatrixGraph
+// Keep in sync with deserialization and StableGraph
+//
+// The format is the same as for `Graph` and `StableGraph`, so that a
+// `MatrixGraph` can be deserialized as a `StableGraph`, and a `StableGraph`
+// without parallel edges as a `MatrixGraph`.
+#[derive(Serialize)]
+#[serde(rename = "Graph")]
+#[serde(bound(serialize = "N: Serialize, E: Serialize, Ty: EdgeType, Ix: IndexType + Serialize"))]
+pub struct SerMatrixGraph<'a, N: 'a, E: 'a, Ty, Null: 'a + Nullable<Wrapped = E>, Ix: 'a> {
+    nodes: Somes<&'a [Option<N>]>,
+    node_holes: Holes<&'a [Option<N>], Ix>,
+    edge_property: EdgeProperty,
+    edges: Edges<'a, N, E, Ty, Null, Ix>,
+}
+
+// Deserialization representation for MatrixGraph
+// Keep in sync with serialization and StableGraph
+#[derive(Deserialize)]
+#[serde(rename = "Graph")]
+#[serde(bound(
+    deserialize = "N: Deserialize<'de>, E: Deserialize<'de>, Ix: IndexType + Deserialize<'de>"
+))]
+pub struct DeserMatrixGraph<N, E, Ix> {
+    nodes: Vec<N>,
+    #[serde(default = "Vec::new")]
+    node_holes: Vec<NodeIndex<Ix>>,
+    edge_property: EdgeProperty,
+    #[allow(clippy::type_complexity)]
+    edges: Vec<Option<(NodeIndex<Ix>, NodeIndex<Ix>, E)>>,
+}
+
+/// `Somes` are the present node weights N, with known length.
+struct Somes<T>(usize, T);
+
+impl<N> Serialize for Somes<&[Option<N>]>
+where
+    N: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq_with_length(self.0, self.1.iter().filter_map(Option::as_ref))
+    }
+}
+
+/// Holes are the node indices of vacancies, with known length
+struct Holes<T, Ix>(usize, T, PhantomData<Ix>);
+
+impl<N, Ix> Serialize for Holes<&[Option<N>], Ix>
+where
+    Ix: Serialize + IndexType,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq_with_length(
+            self.0,
+            self.1.iter().enumerate().filter_map(|(i, node)| {
+                if node.is_none() {
+                    Some(NodeIndex::<Ix>::new(i))
+                } else {
+                    None
+                }
+            }),
+        )
+    }
+}
+
+/// The edges of the graph, each as `Some((a, b, weight))`
+struct Edges<'a, N: 'a, E: 'a, Ty, Null: 'a + Nullable<Wrapped = E>, Ix: 'a>(
+    &'a MatrixGraph<N, E, Ty, Null, Ix>,
+);
+
+impl<'a, N, E, Ty, Null, Ix> Serialize for Edges<'a, N, E, Ty, Null, Ix>
+where
+    E: Serialize,
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: Serialize + IndexType,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq_with_length(
+            self.0.edge_count(),
+            self.0.edge_references().map(|(a, b, w)| Some((a, b, w))),
+        )
+    }
+}
+
+impl<'a, N, E, Ty, Null, Ix> IntoSerializable for &'a MatrixGraph<N, E, Ty, Null, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    type Output = SerMatrixGraph<'a, N, E, Ty, Null, Ix>;
+    fn into_serializable(self) -> Self::Output {
+        let nodes = &self.nodes.elements[..self.nodes.upper_bound];
+        let node_count = self.node_count();
+        SerMatrixGraph {
+            nodes: Somes(node_count, nodes),
+            node_holes: Holes(nodes.len() - node_count, nodes, PhantomData),
+            edge_property: EdgeProperty::from(PhantomData::<Ty>),
+            edges: Edges(self),
+        }
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+impl<N, E, Ty, Null, Ix> Serialize for MatrixGraph<N, E, Ty, Null, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType + Serialize,
+    N: Serialize,
+    E: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.into_serializable().serialize(serializer)
+    }
+}
+
+impl<N, E, Ty, Null, Ix> FromDeserialized for MatrixGraph<N, E, Ty, Null, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType,
+{
+    type Input = DeserMatrixGraph<N, E, Ix>;
+    fn from_deserialized<E2>(input: Self::Input) -> Result<Self, E2>
+    where
+        E2: Error,
+    {
+        let _ = PhantomData::<Ty>::from_deserialized(input.edge_property)?;
+        let node_holes = input.node_holes;
+        let total_nodes = input.nodes.len() + node_holes.len();
+        if total_nodes > <Ix as IndexType>::max().index() {
+            return Err(invalid_length_err::<Ix, _>("node", total_nodes));
+        }
+
+        let mut elements = Vec::with_capacity(total_nodes);
+        let mut removed_ids = IndexSet::with_capacity(node_holes.len());
+        let mut compact_nodes = input.nodes.into_iter();
+        let mut node_pos = 0;
+        for hole_pos in node_holes.iter() {
+            let hole_pos = hole_pos.index();
+            if !(node_pos..total_nodes).contains(&hole_pos) {
+                return Err(invalid_hole_err(hole_pos));
+            }
+            elements.extend(compact_nodes.by_ref().take(hole_pos - node_pos).map(Some));
+            elements.push(None);
+            removed_ids.insert(hole_pos);
+            node_pos = hole_pos + 1;
+        }
+        elements.extend(compact_nodes.map(Some));
+
+        let mut graph = MatrixGraph::with_capacity(total_nodes);
+        graph.nodes = IdStorage {
+            elements,
+            upper_bound: total_nodes,
+            removed_ids,
+        };
+        for (a, b, weight) in input.edges.into_iter().flatten() {
+            for &n in &[a, b] {
+                if graph
+                    .nodes
+                    .elements
+                    .get(n.index())
+                    .map_or(true, Option::is_none)
+                {
+                    return Err(invalid_node_err(n.index(), total_nodes));
+                }
+            }
+            if graph.update_edge(a, b, weight).is_some() {
+                return Err(E2::custom(format_args!(
+                    "invalid value: parallel edge from `{}` to `{}` \
+                     is not allowed in MatrixGraph",
+                    a.index(),
+                    b.index()
+                )));
+            }
+        }
+        Ok(graph)
+    }
+}
+
+/// Requires crate feature `"serde-1"`
+impl<'de, N, E, Ty, Null, Ix> Deserialize<'de> for MatrixGraph<N, E, Ty, Null, Ix>
+where
+    Ty: EdgeType,
+    Null: Nullable<Wrapped = E>,
+    Ix: IndexType + Deserialize<'de>,
+    N: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::from_deserialized(DeserMatrixGraph::deserialize(deserializer)?)
+    }
+}
@@ -0,0 +1,335 @@
+//! [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html) `.mtx`
+//! output and reading, for graphs as sparse adjacency matrices.
+//!
+//! The coordinate format lists the nonzero entries of a matrix, with rows and
+//! columns numbered from 1. An entry in row `i` and column `j` is an edge
+//! from node `i - 1` to node `j - 1`, weighted with the value of the entry:
+//!
+//! ```text
+//! %%MatrixMarket matrix coordinate real general
+//! % a comment
+//! 3 3 2
+//! 1 2 2.5
+//! 3 1 -1
+//! ```
+//!
+//! [`MatrixMarket`] writes a graph, and [`parse`] reads one back.
+//!
+//! [`MatrixMarket`]: struct.MatrixMarket.html
+//! [`parse`]: fn.parse.html
+
+use std::error::Error;
+use std::fmt;
+
+use crate::graph::{DefaultIx, Graph, IndexType, NodeIndex};
+use crate::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+use crate::EdgeType;
+
+/// `MatrixMarket` implements output to the Matrix Market coordinate format
+/// for a graph.
+///
+/// The matrix has a row and a column for each index from `NodeIndexable`.
+/// A directed graph is written as a `general` matrix, and an undirected
+/// graph as a `symmetric` matrix, with each edge in the lower triangle. The
+/// entries are `real` edge weights, or a `pattern` without values if no
+/// weights are set.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::matrix_market::MatrixMarket;
+/// use petgraph::prelude::*;
+///
+/// let graph = UnGraph::<(), f64>::from_edges(&[(0, 1, 2.5), (2, 1, 1.)]);
+/// let mtx = MatrixMarket::new(&graph).with_edge_weights(&|_, edge| *edge.weight());
+/// assert_eq!(
+///     mtx.to_string(),
+///     "%%MatrixMarket matrix coordinate real symmetric\n3 3 2\n2 1 2.5\n3 2 1\n"
+/// );
+/// ```
+pub struct MatrixMarket<'a, G>
+where
+    G: IntoEdgeReferences,
+{
+    graph: G,
+    get_edge_weight: Option<&'a dyn Fn(G, G::EdgeRef) -> f64>,
+}
+
+impl<'a, G> MatrixMarket<'a, G>
+where
+    G: IntoEdgeReferences,
+{
+    /// Create a `MatrixMarket` formatting wrapper, without weights.
+    pub fn new(graph: G) -> Self {
+        MatrixMarket {
+            graph,
+            get_edge_weight: None,
+        }
+    }
+
+    /// Set the weight of each edge.
+    pub fn with_edge_weights(mut self, get_edge_weight: &'a dyn Fn(G, G::EdgeRef) -> f64) -> Self {
+        self.get_edge_weight = Some(get_edge_weight);
+        self
+    }
+}
+
+impl<'a, G> fmt::Display for MatrixMarket<'a, G>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let g = self.graph;
+        let field = if self.get_edge_weight.is_some() {
+            "real"
+        } else {
+            "pattern"
+        };
+        let symmetry = if g.is_directed() {
+            "general"
+        } else {
+            "symmetric"
+        };
+        writeln!(f, "%%MatrixMarket matrix coordinate {} {}", field, symmetry)?;
+        let n = g.node_bound();
+        writeln!(f, "{} {} {}", n, n, g.edge_references().count())?;
+        for edge in g.edge_references() {
+            let mut row = g.to_index(edge.source()) + 1;
+            let mut column = g.to_index(edge.target()) + 1;
+            if !g.is_directed() && row < column {
+                std::mem::swap(&mut row, &mut column);
+            }
+            write!(f, "{} {}", row, column)?;
+            if let Some(get_weight) = self.get_edge_weight {
+                write!(f, " {}", get_weight(g, edge))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// An error reading a `.mtx` file, at a line of the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    kind: ParseErrorKind,
+}
+
+/// The kind of a [`ParseError`].
+///
+/// [`ParseError`]: struct.ParseError.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The first line is not a `%%MatrixMarket matrix` header.
+    Header,
+    /// The header has a format, field or symmetry that is not supported,
+    /// like `array` or `complex`.
+    Unsupported(String),
+    /// The line ends before a field that is required.
+    MissingField,
+    /// This is not a number where one is required.
+    InvalidNumber(String),
+    /// The row or column of an entry is outside of the matrix.
+    OutOfBounds,
+    /// The matrix is not square, so it is not an adjacency matrix.
+    NotSquare,
+    /// The matrix has more rows than the graph can have nodes.
+    TooManyNodes,
+    /// The number of entries is not the one in the size line.
+    EntryCount,
+    /// The matrix is `general` or `skew-symmetric`, and the graph was read
+    /// as undirected.
+    GraphType,
+}
+
+impl ParseError {
+    /// The line of the error, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// What the error is.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match self.kind {
+            ParseErrorKind::Header => f.write_str("expected a %%MatrixMarket matrix header"),
+            ParseErrorKind::Unsupported(ref word) => write!(f, "unsupported matrix {}", word),
+            ParseErrorKind::MissingField => f.write_str("missing field"),
+            ParseErrorKind::InvalidNumber(ref text) => write!(f, "invalid number {:?}", text),
+            ParseErrorKind::OutOfBounds => f.write_str("entry outside of the matrix"),
+            ParseErrorKind::NotSquare => f.write_str("the matrix is not square"),
+            ParseErrorKind::TooManyNodes => f.write_str("too many rows for the graph's index type"),
+            ParseErrorKind::EntryCount => {
+                f.write_str("the number of entries does not match the size line")
+            }
+            ParseErrorKind::GraphType => f.write_str("unsymmetric matrix in an undirected graph"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+/// Read a graph from a Matrix Market file in coordinate format, with a node
+/// for each row of the matrix and an edge for each entry.
+///
+/// Entries of `real` and `integer` matrices are the weights of their edges,
+/// and those of `pattern` matrices have weight 1.
+///
+/// `Ty` picks whether a directed or an undirected graph is read. A
+/// `symmetric` matrix lists only one of each pair of mirrored entries,
+/// which is an undirected edge, or a pair of directed edges in a directed
+/// graph. A `skew-symmetric` matrix is read the same, with the mirrored
+/// edge negated, and only as a directed graph. A `general` matrix can only
+/// be read as a directed graph.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::matrix_market::parse;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::EdgeRef;
+///
+/// let graph = parse::<Directed>(
+///     "%%MatrixMarket matrix coordinate integer symmetric\n3 3 2\n2 1 7\n3 3 1\n",
+/// )
+/// .unwrap();
+/// let edges = graph
+///     .edge_references()
+///     .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(edges, [(1, 0, 7.), (0, 1, 7.), (2, 2, 1.)]);
+/// ```
+pub fn parse<Ty: EdgeType>(input: &str) -> Result<Graph<(), f64, Ty>, ParseError> {
+    let mut lines = input.lines().enumerate();
+    let error = |line: usize, kind| ParseError {
+        line: line + 1,
+        kind,
+    };
+    // errors at the end of the input are on the last line
+    let last = input.lines().count().max(1) - 1;
+
+    let header = lines.next().map(|(_, line)| line).unwrap_or("");
+    let words = header
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>();
+    if words.len() != 5 || words[0] != "%%matrixmarket" || words[1] != "matrix" {
+        return Err(error(0, ParseErrorKind::Header));
+    }
+    if words[2] != "coordinate" {
+        return Err(error(0, ParseErrorKind::Unsupported(words[2].clone())));
+    }
+    let pattern = match &*words[3] {
+        "real" | "integer" => false,
+        "pattern" => true,
+        _ => return Err(error(0, ParseErrorKind::Unsupported(words[3].clone()))),
+    };
+    let symmetry = match &*words[4] {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        _ => return Err(error(0, ParseErrorKind::Unsupported(words[4].clone()))),
+    };
+    if symmetry != Symmetry::Symmetric && !Ty::is_directed() {
+        return Err(error(0, ParseErrorKind::GraphType));
+    }
+
+    // skip comments and blank lines
+    let mut lines = lines.filter(|&(_, line)| {
+        let line = line.trim_start();
+        !line.is_empty() && !line.starts_with('%')
+    });
+    let (i, size) = lines
+        .next()
+        .ok_or_else(|| error(last, ParseErrorKind::MissingField))?;
+    let size = numbers(size, 3).map_err(|kind| error(i, kind))?;
+    if size[0] != size[1] {
+        return Err(error(i, ParseErrorKind::NotSquare));
+    }
+    let n = size[0];
+    if n > <DefaultIx as IndexType>::max().index() {
+        return Err(error(i, ParseErrorKind::TooManyNodes));
+    }
+    // the size line is not checked against the entries yet, so nodes are
+    // added as the entries reach them, and the rest at the end
+    let mut graph = Graph::with_capacity(0, 0);
+
+    let mut entries = 0;
+    for (i, line) in lines {
+        let line_error = |kind| error(i, kind);
+        entries += 1;
+        if entries > size[2] {
+            return Err(line_error(ParseErrorKind::EntryCount));
+        }
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let index = numbers(line, 2).map_err(line_error)?;
+        if index[0] == 0 || index[0] > n || index[1] == 0 || index[1] > n {
+            return Err(line_error(ParseErrorKind::OutOfBounds));
+        }
+        let weight = if pattern {
+            1.
+        } else {
+            let field = fields
+                .get(2)
+                .ok_or_else(|| line_error(ParseErrorKind::MissingField))?;
+            field
+                .parse()
+                .map_err(|_| line_error(ParseErrorKind::InvalidNumber(field.to_string())))?
+        };
+        while graph.node_count() < index[0].max(index[1]) {
+            graph.add_node(());
+        }
+        let row = NodeIndex::new(index[0] - 1);
+        let column = NodeIndex::new(index[1] - 1);
+        graph.add_edge(row, column, weight);
+        if Ty::is_directed() && row != column {
+            match symmetry {
+                Symmetry::General => {}
+                Symmetry::Symmetric => {
+                    graph.add_edge(column, row, weight);
+                }
+                Symmetry::SkewSymmetric => {
+                    graph.add_edge(column, row, -weight);
+                }
+            }
+        }
+    }
+    if entries < size[2] {
+        return Err(error(last, ParseErrorKind::EntryCount));
+    }
+    while graph.node_count() < n {
+        graph.add_node(());
+    }
+    Ok(graph)
+}
+
+/// Parse the first `count` fields of `line` as numbers.
+fn numbers(line: &str, count: usize) -> Result<Vec<usize>, ParseErrorKind> {
+    let fields = line.split_whitespace().take(count).collect::<Vec<_>>();
+    if fields.len() < count {
+        return Err(ParseErrorKind::MissingField);
+    }
+    fields
+        .iter()
+        .map(|field| {
+            field
+                .parse()
+                .map_err(|_| ParseErrorKind::InvalidNumber(field.to_string()))
+        })
+        .collect()
+}
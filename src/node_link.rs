@@ -0,0 +1,280 @@
+//! JSON node-link data, the format of NetworkX's `node_link_data` and of D3
+//! force layouts.
+//!
+//! ```json
+//! {
+//!   "directed": true,
+//!   "multigraph": false,
+//!   "graph": {},
+//!   "nodes": [{"id": 0, "name": "a"}, {"id": 1, "name": "b"}],
+//!   "links": [{"source": 0, "target": 1, "weight": 2.5}]
+//! }
+//! ```
+//!
+//! Unlike the serde serialization of the graph types, which writes their
+//! internal structure, node-link data is made to be read by other tools.
+//!
+//! Requires crate feature `"node_link"`.
+
+use std::collections::HashMap;
+
+use serde::de::{Deserialize, Error as _};
+use serde::ser::Serialize;
+use serde_json::{Error, Map, Value};
+
+use crate::graph::{Graph, IndexType, NodeIndex};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+use crate::EdgeType;
+
+/// The field names of node-link data.
+///
+/// The default matches NetworkX: `"id"`, `"source"`, `"target"` and
+/// `"links"`, with the weights of nodes and edges flattened into their
+/// objects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NodeLinkFormat<'a> {
+    /// The field with the ID of a node.
+    pub id: &'a str,
+    /// The field with the ID of the source node of a link.
+    pub source: &'a str,
+    /// The field with the ID of the target node of a link.
+    pub target: &'a str,
+    /// The field with the list of links.
+    pub links: &'a str,
+    /// The field with the weight of a node or a link.
+    ///
+    /// If `None`, weights must serialize to objects (or to `null`, like
+    /// `()`), and their fields are written next to the ID, source and
+    /// target fields.
+    pub weight: Option<&'a str>,
+}
+
+impl<'a> Default for NodeLinkFormat<'a> {
+    fn default() -> Self {
+        NodeLinkFormat {
+            id: "id",
+            source: "source",
+            target: "target",
+            links: "links",
+            weight: None,
+        }
+    }
+}
+
+impl<'a> NodeLinkFormat<'a> {
+    /// Add the serialized `weight` to `object`.
+    fn insert_weight<W>(&self, object: &mut Map<String, Value>, weight: W) -> Result<(), Error>
+    where
+        W: Serialize,
+    {
+        let weight = serde_json::to_value(weight)?;
+        match self.weight {
+            Some(field) => {
+                object.insert(field.to_string(), weight);
+            }
+            None => match weight {
+                Value::Null => {}
+                Value::Object(fields) => {
+                    for (field, value) in fields {
+                        if object.contains_key(&field) {
+                            return Err(Error::custom(format_args!(
+                                "weight field `{}` conflicts with node-link data",
+                                field
+                            )));
+                        }
+                        object.insert(field, value);
+                    }
+                }
+                _ => return Err(Error::custom("flattened weights must serialize to objects")),
+            },
+        }
+        Ok(())
+    }
+
+    /// Take the weight out of what is left of `object`.
+    fn take_weight<W>(&self, mut object: Map<String, Value>) -> Result<W, Error>
+    where
+        W: for<'de> Deserialize<'de>,
+    {
+        match self.weight {
+            Some(field) => serde_json::from_value(object.remove(field).unwrap_or(Value::Null)),
+            None => {
+                let empty = object.is_empty();
+                serde_json::from_value(Value::Object(object)).or_else(|error| {
+                    // weights like `()` are written as no fields at all
+                    if empty {
+                        serde_json::from_value(Value::Null).map_err(|_| error)
+                    } else {
+                        Err(error)
+                    }
+                })
+            }
+        }
+    }
+}
+
+/// Convert `graph` to node-link data in the given `format`.
+///
+/// Nodes have their index from `NodeIndexable` as ID. The graph is always
+/// written as not being a multigraph.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::node_link::{to_json_node_link, NodeLinkFormat};
+/// use petgraph::prelude::*;
+/// use serde_json::json;
+///
+/// let mut graph = Graph::<&str, f64>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// graph.add_edge(a, b, 2.5);
+///
+/// let format = NodeLinkFormat {
+///     weight: Some("value"),
+///     ..NodeLinkFormat::default()
+/// };
+/// assert_eq!(
+///     to_json_node_link(&graph, format).unwrap(),
+///     json!({
+///         "directed": true,
+///         "multigraph": false,
+///         "graph": {},
+///         "nodes": [{"id": 0, "value": "a"}, {"id": 1, "value": "b"}],
+///         "links": [{"source": 0, "target": 1, "value": 2.5}],
+///     })
+/// );
+/// ```
+pub fn to_json_node_link<G>(graph: G, format: NodeLinkFormat) -> Result<Value, Error>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G::NodeWeight: Serialize,
+    G::EdgeWeight: Serialize,
+{
+    let mut nodes = Vec::new();
+    for node in graph.node_references() {
+        let mut object = Map::new();
+        object.insert(format.id.to_string(), graph.to_index(node.id()).into());
+        format.insert_weight(&mut object, node.weight())?;
+        nodes.push(Value::Object(object));
+    }
+    let mut links = Vec::new();
+    for edge in graph.edge_references() {
+        let mut object = Map::new();
+        object.insert(
+            format.source.to_string(),
+            graph.to_index(edge.source()).into(),
+        );
+        object.insert(
+            format.target.to_string(),
+            graph.to_index(edge.target()).into(),
+        );
+        format.insert_weight(&mut object, edge.weight())?;
+        links.push(Value::Object(object));
+    }
+
+    let mut data = Map::new();
+    data.insert("directed".to_string(), graph.is_directed().into());
+    data.insert("multigraph".to_string(), false.into());
+    data.insert("graph".to_string(), Value::Object(Map::new()));
+    data.insert("nodes".to_string(), Value::Array(nodes));
+    data.insert(format.links.to_string(), Value::Array(links));
+    Ok(Value::Object(data))
+}
+
+/// Build a graph from node-link `data` in the given `format`.
+///
+/// Node IDs may be any JSON values; nodes are added in the order they are
+/// listed. The `"directed"` field, if present, must match the edge type of the
+/// graph. Other graph level fields are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::node_link::{from_json_node_link, NodeLinkFormat};
+/// use petgraph::prelude::*;
+/// use serde_json::json;
+/// use std::collections::HashMap;
+///
+/// let data = json!({
+///     "nodes": [{"id": "a", "group": 1}, {"id": "b", "group": 2}],
+///     "links": [{"source": "a", "target": "b", "value": 5}],
+/// });
+/// let graph: UnGraph<HashMap<String, u32>, HashMap<String, u32>> =
+///     from_json_node_link(data, NodeLinkFormat::default()).unwrap();
+/// assert_eq!(graph[NodeIndex::new(1)]["group"], 2);
+/// assert_eq!(graph.edge_weights().next().unwrap()["value"], 5);
+/// ```
+pub fn from_json_node_link<N, E, Ty, Ix>(
+    data: Value,
+    format: NodeLinkFormat,
+) -> Result<Graph<N, E, Ty, Ix>, Error>
+where
+    N: for<'de> Deserialize<'de>,
+    E: for<'de> Deserialize<'de>,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut data = match data {
+        Value::Object(data) => data,
+        _ => return Err(Error::custom("node-link data must be an object")),
+    };
+    if let Some(directed) = data.get("directed") {
+        if directed.as_bool() != Some(Ty::is_directed()) {
+            return Err(Error::custom(if Ty::is_directed() {
+                "expected a directed graph"
+            } else {
+                "expected an undirected graph"
+            }));
+        }
+    }
+    let nodes = take_array(&mut data, "nodes")?;
+    let links = take_array(&mut data, format.links)?;
+
+    let mut graph = Graph::with_capacity(nodes.len(), links.len());
+    // JSON text of the ID of each node
+    let mut indices = HashMap::new();
+    for node in nodes {
+        let mut object = take_object(node, "node")?;
+        let id = take_field(&mut object, format.id)?.to_string();
+        let index = graph.add_node(format.take_weight(object)?);
+        if indices.insert(id, index).is_some() {
+            return Err(Error::custom("duplicate node id"));
+        }
+    }
+    let node = |indices: &HashMap<String, NodeIndex<Ix>>, id: Value| {
+        indices
+            .get(&id.to_string())
+            .copied()
+            .ok_or_else(|| Error::custom(format_args!("link to unknown node {}", id)))
+    };
+    for link in links {
+        let mut object = take_object(link, "link")?;
+        let source = node(&indices, take_field(&mut object, format.source)?)?;
+        let target = node(&indices, take_field(&mut object, format.target)?)?;
+        graph.add_edge(source, target, format.take_weight(object)?);
+    }
+    Ok(graph)
+}
+
+fn take_field(object: &mut Map<String, Value>, field: &str) -> Result<Value, Error> {
+    object
+        .remove(field)
+        .ok_or_else(|| Error::custom(format_args!("missing field `{}`", field)))
+}
+
+fn take_array(object: &mut Map<String, Value>, field: &str) -> Result<Vec<Value>, Error> {
+    match take_field(object, field)? {
+        Value::Array(values) => Ok(values),
+        _ => Err(Error::custom(format_args!("`{}` must be a list", field))),
+    }
+}
+
+fn take_object(value: Value, what: &str) -> Result<Map<String, Value>, Error> {
+    match value {
+        Value::Object(object) => Ok(object),
+        _ => Err(Error::custom(format_args!("{} must be an object", what))),
+    }
+}
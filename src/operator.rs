@@ -1,18 +1,32 @@
-use super::graph::{Graph, IndexType};
-use super::EdgeType;
-use crate::visit::IntoNodeReferences;
+//! Operators for creating new graphs from existing ones.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::Hash;
+
+use fixedbitset::FixedBitSet;
+
+use crate::algo::{toposort, Error};
+use crate::data::{Build, DataMap};
+use crate::unionfind::UnionFind;
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNeighborsDirected,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeIndexable, NodeRef, Visitable,
+};
 
 /// \[Generic\] complement of the graph
 ///
-/// Computes the graph complement of the input Graphand stores it
-/// in the provided empty output Graph.
+/// Computes the graph complement of the input graph and stores it
+/// in the provided empty output graph: the output has a copy of each node
+/// of the input, and an edge between two distinct nodes exactly when the
+/// input has none. If the input is undirected, each edge of the complement
+/// is added once.
 ///
-/// The function does not create self-loops.
+/// The function does not create self-loops. Edge weights and parallel edges
+/// of the input are ignored.
 ///
-/// Computes in **O(|V|^2*log(|V|))** time (average).
+/// Computes in **O(|V|^2 + |E|)** time.
 ///
 /// Returns the complement.
-///
 /// # Example
 /// ```rust
 /// use petgraph::Graph;
@@ -60,24 +74,1166 @@ use crate::visit::IntoNodeReferences;
 ///     }
 /// }
 /// ```
-pub fn complement<N, E, Ty, Ix>(
-    input: &Graph<N, E, Ty, Ix>,
-    output: &mut Graph<N, E, Ty, Ix>,
-    weight: E,
-) where
-    Ty: EdgeType,
-    Ix: IndexType,
-    E: Clone,
-    N: Clone,
-{
-    for (_node, weight) in input.node_references() {
-        output.add_node(weight.clone());
-    }
-    for x in input.node_indices() {
-        for y in input.node_indices() {
-            if x != y && !input.contains_edge(x, y) {
-                output.add_edge(x, y, weight.clone());
+pub fn complement<G, Out>(input: G, output: &mut Out, weight: Out::EdgeWeight)
+where
+    G: IntoNodeReferences + IntoNeighbors + NodeIndexable + GraphProp,
+    G::NodeWeight: Clone,
+    Out: Build<NodeWeight = G::NodeWeight>,
+    Out::EdgeWeight: Clone,
+{
+    let mut nodes = vec![None; input.node_bound()];
+    for node in input.node_references() {
+        nodes[input.to_index(node.id())] = Some(output.add_node(node.weight().clone()));
+    }
+    let mut adjacent = FixedBitSet::with_capacity(nodes.len());
+    for (i, &x) in nodes.iter().enumerate() {
+        let x = match x {
+            Some(x) => x,
+            None => continue,
+        };
+        adjacent.clear();
+        adjacent.put(i);
+        for y in input.neighbors(input.from_index(i)) {
+            adjacent.put(input.to_index(y));
+        }
+        let start = if input.is_directed() { 0 } else { i + 1 };
+        for (j, &y) in nodes.iter().enumerate().skip(start) {
+            if let Some(y) = y {
+                if !adjacent[j] {
+                    output.add_edge(x, y, weight.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Copy the nodes of `g` into `output`, and return the map from the nodes of
+/// `g` to their copies.
+fn copy_nodes<G, Out>(g: G, output: &mut Out) -> HashMap<G::NodeId, Out::NodeId>
+where
+    G: IntoNodeReferences,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Clone,
+    Out: Build<NodeWeight = G::NodeWeight>,
+{
+    g.node_references()
+        .map(|node| (node.id(), output.add_node(node.weight().clone())))
+        .collect()
+}
+
+/// Copy the edges of `g` into `output`, between the copies of their
+/// endpoints given by `nodes`.
+fn copy_edges<G, Out>(g: G, output: &mut Out, nodes: &HashMap<G::NodeId, Out::NodeId>)
+where
+    G: IntoEdgeReferences,
+    G::NodeId: Eq + Hash,
+    G::EdgeWeight: Clone,
+    Out: Build<EdgeWeight = G::EdgeWeight>,
+{
+    for edge in g.edge_references() {
+        let (a, b) = (nodes[&edge.source()], nodes[&edge.target()]);
+        output.add_edge(a, b, edge.weight().clone());
+    }
+}
+
+/// \[Generic\] Disjoint union of two graphs.
+///
+/// Returns a new graph with a copy of each node and edge of `a` and of `b`,
+/// the nodes of `a` first, together with the maps from the nodes of `a` and
+/// of `b` to their copies in the new graph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::disjoint_union;
+/// use petgraph::prelude::*;
+///
+/// let a = UnGraph::<&str, ()>::from_edges(&[(0, 1)]);
+/// let mut b = UnGraph::<&str, ()>::new_undirected();
+/// let x = b.add_node("x");
+/// let y = b.add_node("y");
+/// b.add_edge(x, y, ());
+///
+/// let (union, _, b_nodes): (UnGraph<_, _>, _, _) = disjoint_union(&a, &b);
+/// assert_eq!(union.node_count(), 4);
+/// assert_eq!(union.edge_count(), 2);
+/// assert_eq!(union[b_nodes[&x]], "x");
+/// assert!(union.contains_edge(b_nodes[&x], b_nodes[&y]));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn disjoint_union<G1, G2, Out>(
+    a: G1,
+    b: G2,
+) -> (
+    Out,
+    HashMap<G1::NodeId, Out::NodeId>,
+    HashMap<G2::NodeId, Out::NodeId>,
+)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences
+        + IntoEdgeReferences<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight>,
+    G2::NodeId: Eq + Hash,
+    Out: Build<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight> + Default,
+{
+    let mut output = Out::default();
+    let a_nodes = copy_nodes(a, &mut output);
+    let b_nodes = copy_nodes(b, &mut output);
+    copy_edges(a, &mut output, &a_nodes);
+    copy_edges(b, &mut output, &b_nodes);
+    (output, a_nodes, b_nodes)
+}
+
+/// \[Generic\] Join of two graphs.
+///
+/// Returns the [`disjoint_union`](fn.disjoint_union.html) of `a` and `b`
+/// with an extra edge of weight `weight` from each node of `a` to each node
+/// of `b`, together with the maps from the nodes of `a` and of `b` to their
+/// copies in the new graph. If `a` is directed, the extra edges are added in
+/// both directions.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::join;
+/// use petgraph::prelude::*;
+///
+/// // A wheel is the join of a cycle and a single hub.
+/// let rim = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let mut hub = UnGraph::<(), ()>::new_undirected();
+/// hub.add_node(());
+///
+/// let (wheel, _, hub_nodes): (UnGraph<_, _>, _, _) = join(&rim, &hub, ());
+/// assert_eq!(wheel.edge_count(), 8);
+/// assert_eq!(wheel.neighbors(hub_nodes[&NodeIndex::new(0)]).count(), 4);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn join<G1, G2, Out>(
+    a: G1,
+    b: G2,
+    weight: G1::EdgeWeight,
+) -> (
+    Out,
+    HashMap<G1::NodeId, Out::NodeId>,
+    HashMap<G2::NodeId, Out::NodeId>,
+)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + GraphProp,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences
+        + IntoEdgeReferences<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight>,
+    G2::NodeId: Eq + Hash,
+    Out: Build<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight> + Default,
+{
+    let (mut output, a_nodes, b_nodes) = disjoint_union::<_, _, Out>(a, b);
+    for x in a.node_references() {
+        for y in b.node_references() {
+            let (x, y) = (a_nodes[&x.id()], b_nodes[&y.id()]);
+            output.add_edge(x, y, weight.clone());
+            if a.is_directed() {
+                output.add_edge(y, x, weight.clone());
+            }
+        }
+    }
+    (output, a_nodes, b_nodes)
+}
+
+/// \[Generic\] Union of two graphs, merging the nodes with equal keys.
+///
+/// Returns a new graph with a node for each distinct key `key(weight)` of
+/// the nodes of `a` and `b`, with the weight of the first node of that key,
+/// looking at the nodes of `a` before those of `b`. Each edge of `a` and `b`
+/// is copied between the nodes of the keys of its endpoints; edges that end
+/// up between the same pair of nodes are merged, with the weight of the last
+/// one, `b`'s edges coming after `a`'s.
+///
+/// Also returns the maps from the nodes of `a` and of `b` to their nodes in
+/// the new graph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::union_by_key;
+/// use petgraph::prelude::*;
+///
+/// let mut a = DiGraph::<&str, ()>::new();
+/// let serde = a.add_node("serde");
+/// let json = a.add_node("serde_json");
+/// a.add_edge(json, serde, ());
+/// let mut b = DiGraph::<&str, ()>::new();
+/// let toml = b.add_node("toml");
+/// let serde_b = b.add_node("serde");
+/// b.add_edge(toml, serde_b, ());
+///
+/// let (union, a_nodes, b_nodes): (DiGraph<_, _>, _, _) = union_by_key(&a, &b, |&name| name);
+/// assert_eq!(union.node_count(), 3);
+/// assert_eq!(a_nodes[&serde], b_nodes[&serde_b]);
+/// assert_eq!(union.neighbors_directed(a_nodes[&serde], Incoming).count(), 2);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn union_by_key<G1, G2, Out, F, K>(
+    a: G1,
+    b: G2,
+    mut key: F,
+) -> (
+    Out,
+    HashMap<G1::NodeId, Out::NodeId>,
+    HashMap<G2::NodeId, Out::NodeId>,
+)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences
+        + IntoEdgeReferences<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight>,
+    G2::NodeId: Eq + Hash,
+    Out: Build<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight> + Default,
+    F: FnMut(&G1::NodeWeight) -> K,
+    K: Eq + Hash,
+{
+    let mut output = Out::default();
+    let mut by_key = HashMap::new();
+    let mut add = |weight: &G1::NodeWeight| {
+        *by_key
+            .entry(key(weight))
+            .or_insert_with(|| output.add_node(weight.clone()))
+    };
+    let a_nodes = a
+        .node_references()
+        .map(|node| (node.id(), add(node.weight())))
+        .collect::<HashMap<_, _>>();
+    let b_nodes = b
+        .node_references()
+        .map(|node| (node.id(), add(node.weight())))
+        .collect::<HashMap<_, _>>();
+    for edge in a.edge_references() {
+        let (x, y) = (a_nodes[&edge.source()], a_nodes[&edge.target()]);
+        output.update_edge(x, y, edge.weight().clone());
+    }
+    for edge in b.edge_references() {
+        let (x, y) = (b_nodes[&edge.source()], b_nodes[&edge.target()]);
+        output.update_edge(x, y, edge.weight().clone());
+    }
+    (output, a_nodes, b_nodes)
+}
+
+/// Compute the intersection (if `intersect`) or the difference of `a` and
+/// `b`, where the nodes of `a` and `b` are matched by their keys under
+/// `key_a` and `key_b`.
+fn filter_by_keys<G1, G2, Out, F1, F2, K>(
+    a: G1,
+    b: G2,
+    mut key_a: F1,
+    mut key_b: F2,
+    intersect: bool,
+) -> (Out, HashMap<G1::NodeId, Out::NodeId>)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences + IntoEdgeReferences + GraphProp,
+    G2::NodeId: Eq + Hash,
+    Out: Build<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight> + Default,
+    F1: FnMut(G1::NodeRef) -> K,
+    F2: FnMut(G2::NodeRef) -> K,
+    K: Clone + Eq + Hash,
+{
+    let b_keys = b
+        .node_references()
+        .map(|node| (node.id(), key_b(node)))
+        .collect::<HashMap<_, _>>();
+    let mut b_edges = HashSet::new();
+    for edge in b.edge_references() {
+        let (x, y) = (&b_keys[&edge.source()], &b_keys[&edge.target()]);
+        if !b.is_directed() {
+            b_edges.insert((y.clone(), x.clone()));
+        }
+        b_edges.insert((x.clone(), y.clone()));
+    }
+    let b_nodes = b_keys.into_iter().map(|(_, k)| k).collect::<HashSet<_>>();
+
+    let mut output = Out::default();
+    let mut a_keys = HashMap::new();
+    let mut nodes = HashMap::new();
+    for node in a.node_references() {
+        let k = key_a(node);
+        if !intersect || b_nodes.contains(&k) {
+            nodes.insert(node.id(), output.add_node(node.weight().clone()));
+        }
+        a_keys.insert(node.id(), k);
+    }
+    for edge in a.edge_references() {
+        let (x, y) = match (nodes.get(&edge.source()), nodes.get(&edge.target())) {
+            (Some(&x), Some(&y)) => (x, y),
+            _ => continue,
+        };
+        let pair = (
+            a_keys[&edge.source()].clone(),
+            a_keys[&edge.target()].clone(),
+        );
+        if b_edges.contains(&pair) == intersect {
+            output.add_edge(x, y, edge.weight().clone());
+        }
+    }
+    (output, nodes)
+}
+
+/// \[Generic\] Intersection of two graphs, matching their nodes by index.
+///
+/// Returns a new graph with a copy of each node of `a` whose index is also a
+/// node index of `b`, and of each edge of `a` between two such nodes whose
+/// endpoints' indices are also joined by an edge in `b`, with the weights of
+/// `a`. Also returns the map from the copied nodes of `a` to their copies.
+///
+/// See [`intersection_by_key`](fn.intersection_by_key.html) to match the
+/// nodes by their weights.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::intersection;
+/// use petgraph::prelude::*;
+///
+/// let a = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let b = UnGraph::<(), ()>::from_edges(&[(1, 0), (1, 3), (2, 3)]);
+/// let (both, _): (UnGraph<_, _>, _) = intersection(&a, &b);
+/// assert_eq!(both.node_count(), 4);
+/// assert_eq!(both.edge_count(), 2);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn intersection<G1, G2, Out>(a: G1, b: G2) -> (Out, HashMap<G1::NodeId, Out::NodeId>)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + NodeIndexable,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G2::NodeId: Eq + Hash,
+    Out: Build<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight> + Default,
+{
+    filter_by_keys(
+        a,
+        b,
+        |node| a.to_index(node.id()),
+        |node| b.to_index(node.id()),
+        true,
+    )
+}
+
+/// \[Generic\] Difference of two graphs, matching their nodes by index.
+///
+/// Returns a new graph with a copy of each node of `a`, and of each edge of
+/// `a` whose endpoints' indices are not joined by an edge in `b`, with the
+/// weights of `a`. Also returns the map from the nodes of `a` to their
+/// copies.
+///
+/// See [`difference_by_key`](fn.difference_by_key.html) to match the nodes by
+/// their weights.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::difference;
+/// use petgraph::prelude::*;
+///
+/// let a = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let b = DiGraph::<(), ()>::from_edges(&[(1, 2), (0, 2)]);
+/// let (rest, _): (DiGraph<_, _>, _) = difference(&a, &b);
+/// assert_eq!(rest.edge_count(), 2);
+/// assert!(rest.contains_edge(0.into(), 1.into()));
+/// assert!(rest.contains_edge(2.into(), 0.into()));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn difference<G1, G2, Out>(a: G1, b: G2) -> (Out, HashMap<G1::NodeId, Out::NodeId>)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + NodeIndexable,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G2::NodeId: Eq + Hash,
+    Out: Build<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight> + Default,
+{
+    filter_by_keys(
+        a,
+        b,
+        |node| a.to_index(node.id()),
+        |node| b.to_index(node.id()),
+        false,
+    )
+}
+
+/// \[Generic\] Intersection of two graphs, matching the nodes with equal
+/// keys.
+///
+/// Like [`intersection`](fn.intersection.html), but a node of `a` matches the
+/// nodes of `b` with the same key `key(weight)`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::intersection_by_key;
+/// use petgraph::prelude::*;
+///
+/// let mut a = UnGraph::<&str, ()>::new_undirected();
+/// let x = a.add_node("x");
+/// let y = a.add_node("y");
+/// a.add_edge(x, y, ());
+/// let mut b = UnGraph::<&str, ()>::new_undirected();
+/// let z = b.add_node("z");
+/// let y = b.add_node("y");
+/// let x = b.add_node("x");
+/// b.extend_with_edges(&[(x, y), (y, z)]);
+///
+/// let (both, _): (UnGraph<_, _>, _) = intersection_by_key(&a, &b, |&w| w);
+/// assert_eq!(both.node_count(), 2);
+/// assert_eq!(both.edge_count(), 1);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn intersection_by_key<G1, G2, Out, F, K>(
+    a: G1,
+    b: G2,
+    mut key: F,
+) -> (Out, HashMap<G1::NodeId, Out::NodeId>)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences<NodeWeight = G1::NodeWeight> + IntoEdgeReferences + GraphProp,
+    G2::NodeId: Eq + Hash,
+    Out: Build<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight> + Default,
+    F: FnMut(&G1::NodeWeight) -> K,
+    K: Clone + Eq + Hash,
+{
+    let b_keys = b
+        .node_references()
+        .map(|node| (node.id(), key(node.weight())))
+        .collect::<HashMap<_, _>>();
+    filter_by_keys(
+        a,
+        b,
+        |node| key(node.weight()),
+        |node| b_keys[&node.id()].clone(),
+        true,
+    )
+}
+
+/// \[Generic\] Difference of two graphs, matching the nodes with equal keys.
+///
+/// Like [`difference`](fn.difference.html), but a node of `a` matches the
+/// nodes of `b` with the same key `key(weight)`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::difference_by_key;
+/// use petgraph::prelude::*;
+///
+/// let mut a = DiGraph::<&str, ()>::new();
+/// let x = a.add_node("x");
+/// let y = a.add_node("y");
+/// a.extend_with_edges(&[(x, y), (y, x)]);
+/// let mut b = DiGraph::<&str, ()>::new();
+/// let y = b.add_node("y");
+/// let x = b.add_node("x");
+/// b.add_edge(x, y, ());
+///
+/// let (rest, _): (DiGraph<_, _>, _) = difference_by_key(&a, &b, |&w| w);
+/// assert_eq!(rest.edge_count(), 1);
+/// assert!(rest.contains_edge(1.into(), 0.into()));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn difference_by_key<G1, G2, Out, F, K>(
+    a: G1,
+    b: G2,
+    mut key: F,
+) -> (Out, HashMap<G1::NodeId, Out::NodeId>)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences<NodeWeight = G1::NodeWeight> + IntoEdgeReferences + GraphProp,
+    G2::NodeId: Eq + Hash,
+    Out: Build<NodeWeight = G1::NodeWeight, EdgeWeight = G1::EdgeWeight> + Default,
+    F: FnMut(&G1::NodeWeight) -> K,
+    K: Clone + Eq + Hash,
+{
+    let b_keys = b
+        .node_references()
+        .map(|node| (node.id(), key(node.weight())))
+        .collect::<HashMap<_, _>>();
+    filter_by_keys(
+        a,
+        b,
+        |node| key(node.weight()),
+        |node| b_keys[&node.id()].clone(),
+        false,
+    )
+}
+
+/// Copy each pair of a node of `a` and a node of `b` into `output`, with the
+/// pair of their weights, and return the map from the pairs to their nodes.
+fn product_nodes<G1, G2, Out>(
+    a: G1,
+    b: G2,
+    output: &mut Out,
+) -> HashMap<(G1::NodeId, G2::NodeId), Out::NodeId>
+where
+    G1: IntoNodeReferences,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G2: IntoNodeReferences,
+    G2::NodeId: Eq + Hash,
+    G2::NodeWeight: Clone,
+    Out: Build<NodeWeight = (G1::NodeWeight, G2::NodeWeight)>,
+{
+    let mut nodes = HashMap::new();
+    for x in a.node_references() {
+        for y in b.node_references() {
+            let weight = (x.weight().clone(), y.weight().clone());
+            nodes.insert((x.id(), y.id()), output.add_node(weight));
+        }
+    }
+    nodes
+}
+
+/// \[Generic\] Cartesian product of two graphs.
+///
+/// Returns a new graph with a node for each pair of a node `u` of `a` and a
+/// node `v` of `b`, weighted by the pair of their weights, and the map from
+/// the pairs `(u, v)` to the new nodes. For each edge from `u` to `u'` in `a`
+/// and each node `v` of `b` there is an edge from `(u, v)` to `(u', v)`, and
+/// for each node `u` of `a` and each edge from `v` to `v'` in `b` an edge from
+/// `(u, v)` to `(u, v')`, with the weight of the edge of `a` or `b`.
+///
+/// The product of two paths is a grid, and the product of a graph with a
+/// single edge is a prism over it.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::cartesian_product;
+/// use petgraph::prelude::*;
+///
+/// // A 3 × 2 grid.
+/// let a = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let b = UnGraph::<u32, ()>::from_edges(&[(0, 1)]);
+/// let (grid, nodes): (UnGraph<_, _>, _) = cartesian_product(&a, &b);
+/// assert_eq!(grid.node_count(), 6);
+/// assert_eq!(grid.edge_count(), 7);
+/// let (u, v) = (NodeIndex::new(1), NodeIndex::new(0));
+/// assert_eq!(grid.neighbors(nodes[&(u, v)]).count(), 3);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn cartesian_product<G1, G2, Out>(
+    a: G1,
+    b: G2,
+) -> (Out, HashMap<(G1::NodeId, G2::NodeId), Out::NodeId>)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences + IntoEdgeReferences<EdgeWeight = G1::EdgeWeight>,
+    G2::NodeId: Eq + Hash,
+    G2::NodeWeight: Clone,
+    Out:
+        Build<NodeWeight = (G1::NodeWeight, G2::NodeWeight), EdgeWeight = G1::EdgeWeight> + Default,
+{
+    let mut output = Out::default();
+    let nodes = product_nodes(a, b, &mut output);
+    for edge in a.edge_references() {
+        for v in b.node_identifiers() {
+            let x = nodes[&(edge.source(), v)];
+            let y = nodes[&(edge.target(), v)];
+            output.add_edge(x, y, edge.weight().clone());
+        }
+    }
+    for u in a.node_identifiers() {
+        for edge in b.edge_references() {
+            let x = nodes[&(u, edge.source())];
+            let y = nodes[&(u, edge.target())];
+            output.add_edge(x, y, edge.weight().clone());
+        }
+    }
+    (output, nodes)
+}
+
+/// \[Generic\] Tensor product of two graphs, also called the categorical,
+/// direct or Kronecker product.
+///
+/// Returns a new graph with a node for each pair of a node `u` of `a` and a
+/// node `v` of `b`, weighted by the pair of their weights, and the map from
+/// the pairs `(u, v)` to the new nodes. For each edge from `u` to `u'` in `a`
+/// and each edge from `v` to `v'` in `b` there is an edge from `(u, v)` to
+/// `(u', v')`, weighted by the pair of their weights: the product moves in
+/// both graphs at once, like two automata running in lockstep.
+///
+/// An undirected edge counts as two opposite edges, so that if `b` is
+/// undirected there is also an edge from `(u, v')` to `(u', v)`, and if only
+/// `a` is undirected, one from `(u', v)` to `(u, v')`; these are left out
+/// when they would repeat the first edge because of a self loop in an
+/// undirected graph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::tensor_product;
+/// use petgraph::prelude::*;
+///
+/// // Two automata reading the letters 'a' and 'b'.
+/// let mut a = DiGraph::<u32, char>::new();
+/// let a0 = a.add_node(0);
+/// let a1 = a.add_node(1);
+/// a.extend_with_edges(&[(a0, a1, 'a'), (a1, a0, 'b')]);
+/// let mut b = DiGraph::<u32, char>::new();
+/// let b0 = b.add_node(0);
+/// b.extend_with_edges(&[(b0, b0, 'a'), (b0, b0, 'b')]);
+///
+/// let (product, nodes): (DiGraph<_, _>, _) = tensor_product(&a, &b);
+/// assert_eq!(product.edge_count(), 4);
+/// // Only matching letters move both automata.
+/// let synchronized = product.edge_weights().filter(|(x, y)| x == y).count();
+/// assert_eq!(synchronized, 2);
+/// assert!(product.contains_edge(nodes[&(a0, b0)], nodes[&(a1, b0)]));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn tensor_product<G1, G2, Out>(
+    a: G1,
+    b: G2,
+) -> (Out, HashMap<(G1::NodeId, G2::NodeId), Out::NodeId>)
+where
+    G1: IntoNodeReferences + IntoEdgeReferences + GraphProp,
+    G1::NodeId: Eq + Hash,
+    G1::NodeWeight: Clone,
+    G1::EdgeWeight: Clone,
+    G2: IntoNodeReferences + IntoEdgeReferences + GraphProp,
+    G2::NodeId: Eq + Hash,
+    G2::NodeWeight: Clone,
+    G2::EdgeWeight: Clone,
+    Out: Build<
+            NodeWeight = (G1::NodeWeight, G2::NodeWeight),
+            EdgeWeight = (G1::EdgeWeight, G2::EdgeWeight),
+        > + Default,
+{
+    let mut output = Out::default();
+    let nodes = product_nodes(a, b, &mut output);
+    for e in a.edge_references() {
+        let (u, u1) = (e.source(), e.target());
+        for f in b.edge_references() {
+            let (v, v1) = (f.source(), f.target());
+            let weight = (e.weight().clone(), f.weight().clone());
+            output.add_edge(nodes[&(u, v)], nodes[&(u1, v1)], weight.clone());
+            let reverse = match (a.is_directed(), b.is_directed()) {
+                (true, true) => None,
+                (false, true) if u != u1 => Some(((u1, v), (u, v1))),
+                (true, false) if v != v1 => Some(((u, v1), (u1, v))),
+                (false, false) if u != u1 && v != v1 => Some(((u, v1), (u1, v))),
+                _ => None,
+            };
+            if let Some((x, y)) = reverse {
+                output.add_edge(nodes[&x], nodes[&y], weight);
+            }
+        }
+    }
+    (output, nodes)
+}
+
+/// \[Generic\] Bipartite double cover of a graph, its tensor product with a
+/// single edge.
+///
+/// Returns a new graph with two copies of each node of `g`, first copies
+/// then second copies, and the map from the nodes of `g` to the pairs of
+/// their copies. For each edge from `u` to `v` in `g` there is an edge from
+/// the first copy of `u` to the second copy of `v`, and one from the second
+/// copy of `u` to the first copy of `v`, with the weight of the edge; for a
+/// self loop in an undirected graph there is only the first one.
+///
+/// The double cover is bipartite, with the first copies on one side, and it
+/// is connected exactly when `g` is connected and not bipartite.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::connected_components;
+/// use petgraph::operator::bipartite_double_cover;
+/// use petgraph::prelude::*;
+///
+/// // The double cover of a triangle is a hexagon.
+/// let triangle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let (cover, copies): (UnGraph<_, _>, _) = bipartite_double_cover(&triangle);
+/// assert_eq!(cover.node_count(), 6);
+/// assert_eq!(cover.edge_count(), 6);
+/// assert_eq!(connected_components(&cover), 1);
+/// let (first, second) = copies[&NodeIndex::new(0)];
+/// assert!(!cover.contains_edge(first, second));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bipartite_double_cover<G, Out>(g: G) -> (Out, HashMap<G::NodeId, (Out::NodeId, Out::NodeId)>)
+where
+    G: IntoNodeReferences + IntoEdgeReferences + GraphProp,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+    Out: Build<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight> + Default,
+{
+    let mut output = Out::default();
+    let first = copy_nodes(g, &mut output);
+    let second = copy_nodes(g, &mut output);
+    for edge in g.edge_references() {
+        let (u, v) = (edge.source(), edge.target());
+        output.add_edge(first[&u], second[&v], edge.weight().clone());
+        if g.is_directed() || u != v {
+            output.add_edge(second[&u], first[&v], edge.weight().clone());
+        }
+    }
+    let copies = first
+        .into_iter()
+        .map(|(a, x)| (a, (x, second[&a])))
+        .collect();
+    (output, copies)
+}
+
+/// \[Generic\] Quotient graph of a partition of the nodes.
+///
+/// Returns a new graph with a node for each class of nodes of `g` with the
+/// same key `class(node)`, weighted by the weights of its nodes in the order
+/// of `g.node_references()`, and the map from the nodes of `g` to the nodes
+/// of their classes. The classes are ordered by their first node.
+///
+/// Each edge of `g` is copied between the classes of its endpoints, so edges
+/// within a class become self loops. If `merge_edges` is true, edges that end
+/// up between the same pair of classes are merged, with the weight of the
+/// last one; otherwise they are kept as parallel edges.
+///
+/// This generalizes [`condensation`](../algo/fn.condensation.html), whose
+/// classes are the strongly connected components.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::quotient_graph;
+/// use petgraph::prelude::*;
+///
+/// // Collapse the cities of each country.
+/// let mut g = UnGraph::<(&str, &str), u32>::new_undirected();
+/// let paris = g.add_node(("Paris", "France"));
+/// let lyon = g.add_node(("Lyon", "France"));
+/// let turin = g.add_node(("Turin", "Italy"));
+/// let milan = g.add_node(("Milan", "Italy"));
+/// g.extend_with_edges(&[(paris, lyon, 1), (lyon, turin, 2), (lyon, milan, 3), (turin, milan, 4)]);
+///
+/// let (countries, class): (UnGraph<_, _>, _) = quotient_graph(&g, |n| g[n].1, true);
+/// assert_eq!(countries.node_count(), 2);
+/// assert_eq!(countries.edge_count(), 3);
+/// assert_eq!(countries[class[&turin]], vec![("Turin", "Italy"), ("Milan", "Italy")]);
+/// let e = countries.find_edge(class[&paris], class[&milan]).unwrap();
+/// assert_eq!(countries[e], 3);
+/// ```
+pub fn quotient_graph<G, Out, F, K>(
+    g: G,
+    mut class: F,
+    merge_edges: bool,
+) -> (Out, HashMap<G::NodeId, Out::NodeId>)
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+    Out: Build<NodeWeight = Vec<G::NodeWeight>, EdgeWeight = G::EdgeWeight> + Default,
+    F: FnMut(G::NodeId) -> K,
+    K: Eq + Hash,
+{
+    let mut keys = HashMap::new();
+    let mut classes: Vec<Vec<G::NodeWeight>> = Vec::new();
+    let mut members = Vec::new();
+    for node in g.node_references() {
+        let next = classes.len();
+        let k = *keys.entry(class(node.id())).or_insert(next);
+        if k == next {
+            classes.push(Vec::new());
+        }
+        classes[k].push(node.weight().clone());
+        members.push((node.id(), k));
+    }
+
+    let mut output = Out::default();
+    let class_nodes = classes
+        .into_iter()
+        .map(|weights| output.add_node(weights))
+        .collect::<Vec<_>>();
+    let nodes = members
+        .into_iter()
+        .map(|(a, k)| (a, class_nodes[k]))
+        .collect::<HashMap<_, _>>();
+    for edge in g.edge_references() {
+        let (x, y) = (nodes[&edge.source()], nodes[&edge.target()]);
+        if merge_edges {
+            output.update_edge(x, y, edge.weight().clone());
+        } else {
+            output.add_edge(x, y, edge.weight().clone());
+        }
+    }
+    (output, nodes)
+}
+
+/// \[Generic\] Mycielskian of a graph.
+///
+/// Returns a new graph with a copy `v` of each node of `g`, then a shadow
+/// node `u` for each of them, in the order of `g.node_references()`, and a
+/// final hub node `w`. For each edge from `a` to `b` in `g` there are edges
+/// from `v_a` to `v_b`, from `u_a` to `v_b` and from `v_a` to `u_b`, with its
+/// weight, and there is an edge from each shadow node to the hub, with the
+/// default weight. The copies and the shadows have the weights of the nodes
+/// of `g`, and the hub the default weight.
+///
+/// The Mycielskian of a triangle-free graph is triangle-free, and its
+/// chromatic number is one more than that of `g`: starting from a single
+/// edge it gives the 5-cycle and then the Grötzsch graph, which needs four
+/// colors.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::mycielskian;
+/// use petgraph::prelude::*;
+///
+/// let edge = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+/// let cycle: UnGraph<(), ()> = mycielskian(&edge);
+/// assert_eq!(cycle.node_count(), 5);
+/// assert!(cycle.node_indices().all(|a| cycle.neighbors(a).count() == 2));
+/// let grotzsch: UnGraph<(), ()> = mycielskian(&cycle);
+/// assert_eq!((grotzsch.node_count(), grotzsch.edge_count()), (11, 20));
+/// ```
+pub fn mycielskian<G, Out>(g: G) -> Out
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Clone + Default,
+    G::EdgeWeight: Clone + Default,
+    Out: Build<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight> + Default,
+{
+    let mut output = Out::default();
+    let copies = copy_nodes(g, &mut output);
+    let shadows = copy_nodes(g, &mut output);
+    let hub = output.add_node(G::NodeWeight::default());
+    for edge in g.edge_references() {
+        let (a, b) = (edge.source(), edge.target());
+        output.add_edge(copies[&a], copies[&b], edge.weight().clone());
+        output.add_edge(shadows[&a], copies[&b], edge.weight().clone());
+        output.add_edge(copies[&a], shadows[&b], edge.weight().clone());
+    }
+    for node in g.node_references() {
+        output.add_edge(shadows[&node.id()], hub, G::EdgeWeight::default());
+    }
+    output
+}
+
+/// \[Generic\] Subgraph induced by a set of nodes.
+///
+/// Returns a new graph with a copy of each node of `g` in `nodes`, in the
+/// order of `g.node_references()`, and of each edge of `g` between two of
+/// them, together with the maps from the kept nodes of `g` to their copies
+/// and back. Repeated nodes and nodes not in `g` are ignored.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::induced_subgraph;
+/// use petgraph::prelude::*;
+///
+/// let mut g = UnGraph::<&str, u32>::new_undirected();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// let c = g.add_node("c");
+/// let d = g.add_node("d");
+/// g.extend_with_edges(&[(a, b, 1), (b, c, 2), (c, d, 3), (d, a, 4)]);
+///
+/// let (sub, new, old): (UnGraph<_, _>, _, _) = induced_subgraph(&g, vec![b, c, d]);
+/// assert_eq!(sub.node_count(), 3);
+/// assert_eq!(sub.edge_count(), 2);
+/// assert_eq!(sub[new[&c]], "c");
+/// assert_eq!(old[&NodeIndex::new(0)], b);
+/// assert!(!new.contains_key(&a));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn induced_subgraph<G, Out, I>(
+    g: G,
+    nodes: I,
+) -> (
+    Out,
+    HashMap<G::NodeId, Out::NodeId>,
+    HashMap<Out::NodeId, G::NodeId>,
+)
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+    Out: Build<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight> + Default,
+    Out::NodeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+{
+    let keep = nodes.into_iter().collect::<HashSet<_>>();
+    let mut output = Out::default();
+    let new = g
+        .node_references()
+        .filter(|node| keep.contains(&node.id()))
+        .map(|node| (node.id(), output.add_node(node.weight().clone())))
+        .collect::<HashMap<_, _>>();
+    for edge in g.edge_references() {
+        if let (Some(&x), Some(&y)) = (new.get(&edge.source()), new.get(&edge.target())) {
+            output.add_edge(x, y, edge.weight().clone());
+        }
+    }
+    let old = new.iter().map(|(&a, &x)| (x, a)).collect();
+    (output, new, old)
+}
+
+/// \[Generic\] Split a graph into its weakly connected components.
+///
+/// Returns a new graph for each weakly connected component of `g`, with a
+/// copy of its nodes and edges, together with the map from the nodes of the
+/// new graph back to the nodes of `g`. The components are ordered by their
+/// first node, and the nodes of each component keep their order in
+/// `g.node_references()`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::split_into_components;
+/// use petgraph::prelude::*;
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 1), (3, 4)]);
+/// let components: Vec<(DiGraph<_, _>, _)> = split_into_components(&g);
+/// assert_eq!(components.len(), 2);
+///
+/// let (second, original) = &components[1];
+/// assert_eq!(second.node_count(), 2);
+/// assert_eq!(original[&NodeIndex::new(0)], NodeIndex::new(3));
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn split_into_components<G, Out>(g: G) -> Vec<(Out, HashMap<Out::NodeId, G::NodeId>)>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable,
+    G::NodeWeight: Clone,
+    G::EdgeWeight: Clone,
+    Out: Build<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight> + Default,
+    Out::NodeId: Eq + Hash,
+{
+    let mut sets = UnionFind::new(g.node_bound());
+    for edge in g.edge_references() {
+        sets.union(g.to_index(edge.source()), g.to_index(edge.target()));
+    }
+
+    // component and copy of each node, by node index
+    let mut copies = vec![None; g.node_bound()];
+    let mut roots = HashMap::new();
+    let mut components: Vec<(Out, HashMap<_, _>)> = Vec::new();
+    for node in g.node_references() {
+        let next = components.len();
+        let k = *roots
+            .entry(sets.find_mut(g.to_index(node.id())))
+            .or_insert(next);
+        if k == next {
+            components.push((Out::default(), HashMap::new()));
+        }
+        let (output, original) = &mut components[k];
+        let x = output.add_node(node.weight().clone());
+        original.insert(x, node.id());
+        copies[g.to_index(node.id())] = Some((k, x));
+    }
+    for edge in g.edge_references() {
+        if let (Some((k, x)), Some((_, y))) = (
+            copies[g.to_index(edge.source())],
+            copies[g.to_index(edge.target())],
+        ) {
+            components[k].0.add_edge(x, y, edge.weight().clone());
+        }
+    }
+    components
+}
+
+/// \[Generic\] One-mode projection of a bipartite graph onto the set of
+/// nodes `nodes`.
+///
+/// Returns a new graph with a copy of each node of `g` in `nodes`, in the
+/// order of `g.node_references()`, and an edge between two of them when they
+/// have a common neighbor outside of `nodes`, together with the map from the
+/// kept nodes of `g` to their copies. Each edge goes from the earlier of its
+/// two nodes to the later one. For a directed `g`, only edges from `nodes`
+/// count, so the linked nodes are those with a common successor.
+///
+/// The weight of an edge starts at `init` and is updated by
+/// `aggregate(&mut weight, e1, e2)` for each pair of edges `e1` and `e2`
+/// joining its two nodes to a common neighbor, `e1` from the earlier node.
+/// Edges between two nodes of `nodes` are ignored.
+///
+/// Computes in **O(|V| + |E| + Σ d(v)²)** time, the sum over the nodes
+/// outside of `nodes`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::bipartite_projection;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::EdgeRef;
+///
+/// // People 0, 1, 2 attending events 3 and 4, with the hours spent there.
+/// let g = UnGraph::<(), u32>::from_edges(&[(0, 3, 1), (1, 3, 2), (1, 4, 3), (2, 4, 4), (0, 4, 5)]);
+/// let people = vec![NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(2)];
+///
+/// // The number of events two people attended together.
+/// let (shared, _): (UnGraph<(), usize>, _) =
+///     bipartite_projection(&g, people.clone(), 0, |w, _, _| *w += 1);
+/// assert_eq!(shared.edge_count(), 3);
+/// let e = shared.find_edge(NodeIndex::new(0), NodeIndex::new(1)).unwrap();
+/// assert_eq!(shared[e], 2);
+///
+/// // The hours two people spent at events together.
+/// let (hours, _): (UnGraph<(), u32>, _) =
+///     bipartite_projection(&g, people, 0, |w, e1, e2| *w += e1.weight().min(e2.weight()));
+/// let e = hours.find_edge(NodeIndex::new(0), NodeIndex::new(1)).unwrap();
+/// assert_eq!(hours[e], 1 + 3);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn bipartite_projection<G, Out, I, F>(
+    g: G,
+    nodes: I,
+    init: Out::EdgeWeight,
+    mut aggregate: F,
+) -> (Out, HashMap<G::NodeId, Out::NodeId>)
+where
+    G: IntoNodeReferences + IntoEdgeReferences + GraphProp,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Clone,
+    Out: Build<NodeWeight = G::NodeWeight> + Default,
+    Out::EdgeWeight: Clone,
+    I: IntoIterator<Item = G::NodeId>,
+    F: FnMut(&mut Out::EdgeWeight, G::EdgeRef, G::EdgeRef),
+{
+    let keep = nodes.into_iter().collect::<HashSet<_>>();
+    let mut output = Out::default();
+    let mut order = HashMap::new();
+    let mut copies = Vec::new();
+    for node in g.node_references() {
+        if keep.contains(&node.id()) {
+            order.insert(node.id(), copies.len());
+            copies.push(output.add_node(node.weight().clone()));
+        }
+    }
+
+    // the edges from `nodes` to each of their neighbors
+    let mut incident = HashMap::<_, Vec<_>>::new();
+    for edge in g.edge_references() {
+        let (a, b) = (edge.source(), edge.target());
+        match (order.get(&a), order.get(&b)) {
+            (Some(&i), None) => incident.entry(b).or_default().push((i, edge)),
+            (None, Some(&j)) if !g.is_directed() => incident.entry(a).or_default().push((j, edge)),
+            _ => {}
+        }
+    }
+
+    let mut weights = BTreeMap::new();
+    for edges in incident.values_mut() {
+        edges.sort_by_key(|&(i, _)| i);
+        for (k, &(i, e1)) in edges.iter().enumerate() {
+            for &(j, e2) in &edges[k + 1..] {
+                if i != j {
+                    let w = weights.entry((i, j)).or_insert_with(|| init.clone());
+                    aggregate(w, e1, e2);
+                }
+            }
+        }
+    }
+    for ((i, j), w) in weights {
+        output.add_edge(copies[i], copies[j], w);
+    }
+    let nodes = order.into_iter().map(|(a, i)| (a, copies[i])).collect();
+    (output, nodes)
+}
+
+/// \[Generic\] Merge the structurally equivalent nodes of a directed acyclic
+/// graph, also known as hash-consing.
+///
+/// Two nodes are equivalent when they have equal weights and the same
+/// multiset of outgoing edges, compared by edge weight and by the class of
+/// their target, so that the classes are found bottom-up from the sinks. This
+/// merges common subexpressions in an expression graph.
+///
+/// Returns a new graph with a node for each class, in topological order, and
+/// the map from the nodes of `g` to the nodes of their classes. The outgoing
+/// edges of a class are copied from one of its nodes. If `g` has a cycle,
+/// returns it as an error instead.
+///
+/// # Example
+/// ```rust
+/// use petgraph::operator::dedup_dag;
+/// use petgraph::prelude::*;
+///
+/// // (x + y) * (x + y), with the two sums built separately
+/// let mut g = DiGraph::<&str, u8>::new();
+/// let mul = g.add_node("*");
+/// let sum1 = g.add_node("+");
+/// let sum2 = g.add_node("+");
+/// let x = g.add_node("x");
+/// let y = g.add_node("y");
+/// g.extend_with_edges(&[
+///     (mul, sum1, 0), (mul, sum2, 1),
+///     (sum1, x, 0), (sum1, y, 1),
+///     (sum2, x, 0), (sum2, y, 1),
+/// ]);
+///
+/// let (dag, class): (DiGraph<_, _>, _) = dedup_dag(&g).unwrap();
+/// assert_eq!(dag.node_count(), 4);
+/// assert_eq!(dag.edge_count(), 4);
+/// assert_eq!(class[&sum1], class[&sum2]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn dedup_dag<G, Out>(g: G) -> Result<(Out, HashMap<G::NodeId, Out::NodeId>), Error<G::NodeId>>
+where
+    G: IntoEdges + IntoNeighborsDirected + IntoNodeIdentifiers + Visitable + DataMap,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: Clone + Eq + Hash,
+    G::EdgeWeight: Clone + Eq + Hash,
+    Out: Build<NodeWeight = G::NodeWeight, EdgeWeight = G::EdgeWeight> + Default,
+{
+    let order = toposort(g, None)?;
+
+    // number the edge weights and the classes, successors first
+    let mut edge_weights = HashMap::new();
+    let mut keys = HashMap::new();
+    let mut class = HashMap::with_capacity(order.len());
+    for &a in order.iter().rev() {
+        let mut successors = g
+            .edges(a)
+            .map(|edge| {
+                let next = edge_weights.len();
+                let w = *edge_weights.entry(edge.weight().clone()).or_insert(next);
+                (w, class[&edge.target()])
+            })
+            .collect::<Vec<_>>();
+        successors.sort_unstable();
+        let weight = g.node_weight(a).unwrap().clone();
+        let next = keys.len();
+        class.insert(a, *keys.entry((weight, successors)).or_insert(next));
+    }
+
+    let mut output = Out::default();
+    let mut copies = vec![None; keys.len()];
+    let mut representatives = Vec::with_capacity(keys.len());
+    let mut nodes = HashMap::with_capacity(order.len());
+    for &a in &order {
+        let k = class[&a];
+        let x = match copies[k] {
+            Some(x) => x,
+            None => {
+                let x = output.add_node(g.node_weight(a).unwrap().clone());
+                copies[k] = Some(x);
+                representatives.push(a);
+                x
             }
+        };
+        nodes.insert(a, x);
+    }
+    for a in representatives {
+        for edge in g.edges(a) {
+            output.add_edge(nodes[&a], nodes[&edge.target()], edge.weight().clone());
         }
     }
+    Ok((output, nodes))
 }
@@ -0,0 +1,470 @@
+//! [Pajek](http://mrvar.fdv.uni-lj.si/pajek/) `.net` output and reading.
+//!
+//! A `.net` file lists the vertices, numbered from 1, with their labels and
+//! optional positions, and then the weighted arcs (directed edges) and edges
+//! between them:
+//!
+//! ```text
+//! *Vertices 3
+//! 1 "a" 0.1 0.5
+//! 2 "b" 0.9 0.5
+//! 3 "c"
+//! *Arcs
+//! 1 2 2.5
+//! *Edges
+//! 2 3
+//! ```
+//!
+//! [`Pajek`] writes a graph, and [`parse`] reads one back.
+//!
+//! [`Pajek`]: struct.Pajek.html
+//! [`parse`]: fn.parse.html
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::hash::Hash;
+
+use crate::graph::{DefaultIx, Graph, IndexType, NodeIndex};
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+use crate::EdgeType;
+
+/// `Pajek` implements output to the Pajek `.net` format for a graph.
+///
+/// Nodes are labeled with their weights, using their `Display` or `Debug`
+/// implementation, and are numbered by their index from `NodeIndexable`,
+/// plus one. Pajek has no way to escape quotes, so `"` in labels is written
+/// as `'`, and line breaks as spaces.
+///
+/// Edges are written as arcs in a directed graph and as edges in an
+/// undirected graph.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::pajek::Pajek;
+/// use petgraph::prelude::*;
+/// use std::collections::HashMap;
+///
+/// let mut graph = Graph::<&str, f64>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// graph.add_edge(a, b, 2.5);
+///
+/// let mut positions = HashMap::new();
+/// positions.insert(a, [0.25, 0.5]);
+/// let pajek = Pajek::new(&graph)
+///     .with_positions(&positions)
+///     .with_edge_weights(&|_, edge| *edge.weight());
+/// assert_eq!(
+///     pajek.to_string(),
+///     "*Vertices 2\n1 \"a\" 0.25 0.5\n2 \"b\"\n*Arcs\n1 2 2.5\n"
+/// );
+/// ```
+pub struct Pajek<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    positions: Option<&'a HashMap<G::NodeId, [f64; 2]>>,
+    get_edge_weight: Option<&'a dyn Fn(G, G::EdgeRef) -> f64>,
+}
+
+impl<'a, G> Pajek<'a, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+{
+    /// Create a `Pajek` formatting wrapper, without positions or weights.
+    pub fn new(graph: G) -> Self {
+        Pajek {
+            graph,
+            positions: None,
+            get_edge_weight: None,
+        }
+    }
+
+    /// Set the positions of the nodes, for example from the
+    /// [`layout`](../layout/index.html) module. Nodes without a position are
+    /// written without one.
+    pub fn with_positions(mut self, positions: &'a HashMap<G::NodeId, [f64; 2]>) -> Self {
+        self.positions = Some(positions);
+        self
+    }
+
+    /// Set the weight of each edge.
+    pub fn with_edge_weights(mut self, get_edge_weight: &'a dyn Fn(G, G::EdgeRef) -> f64) -> Self {
+        self.get_edge_weight = Some(get_edge_weight);
+        self
+    }
+}
+
+impl<'a, G> Pajek<'a, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    fn graph_fmt<NF>(&self, f: &mut fmt::Formatter, node_fmt: NF) -> fmt::Result
+    where
+        NF: Fn(&G::NodeWeight, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let g = self.graph;
+        writeln!(f, "*Vertices {}", g.node_bound())?;
+        for node in g.node_references() {
+            write!(f, "{} \"", g.to_index(node.id()) + 1)?;
+            write!(&mut Escaper(&mut *f), "{}", FnFmt(node.weight(), &node_fmt))?;
+            write!(f, "\"")?;
+            if let Some(p) = self.positions.and_then(|p| p.get(&node.id())) {
+                write!(f, " {} {}", p[0], p[1])?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "{}", if g.is_directed() { "*Arcs" } else { "*Edges" })?;
+        for edge in g.edge_references() {
+            write!(
+                f,
+                "{} {}",
+                g.to_index(edge.source()) + 1,
+                g.to_index(edge.target()) + 1
+            )?;
+            if let Some(get_weight) = self.get_edge_weight {
+                write!(f, " {}", get_weight(g, edge))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, G> fmt::Display for Pajek<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Display::fmt)
+    }
+}
+
+impl<'a, G> fmt::Debug for Pajek<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Debug::fmt)
+    }
+}
+
+/// Replace what a Pajek label can't hold
+struct Escaper<W>(W);
+
+impl<W> fmt::Write for Escaper<W>
+where
+    W: fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '"' => self.0.write_char('\''),
+            '\r' | '\n' => self.0.write_char(' '),
+            _ => self.0.write_char(c),
+        }
+    }
+}
+
+/// Format data using a specific format function
+struct FnFmt<'a, T, F>(&'a T, F);
+
+impl<'a, T, F> Display for FnFmt<'a, T, F>
+where
+    F: Fn(&'a T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.1(self.0, f)
+    }
+}
+
+/// A vertex read from a `.net` file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PajekNode {
+    /// The label of the vertex, or its number if it has none.
+    pub label: String,
+    /// The position of the vertex, if it has one. A third coordinate, for 3D
+    /// layouts, is skipped.
+    pub position: Option<[f64; 2]>,
+}
+
+/// A graph read from a `.net` file, by [`parse`].
+///
+/// [`parse`]: fn.parse.html
+#[derive(Clone, Debug)]
+pub struct ParsedGraph<Ty: EdgeType> {
+    /// The name from the `*Network` line, if there is one.
+    pub name: Option<String>,
+    /// The vertices, in the order of their numbers, and the arcs and edges
+    /// with their weights.
+    pub graph: Graph<PajekNode, f64, Ty>,
+}
+
+/// An error reading a `.net` file, at a line of the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    kind: ParseErrorKind,
+}
+
+/// The kind of a [`ParseError`].
+///
+/// [`ParseError`]: struct.ParseError.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// A line before the first section.
+    MissingSection,
+    /// A section that is not supported, like `*Matrix`.
+    UnknownSection(String),
+    /// The line ends before a field that is required.
+    MissingField,
+    /// This is not a number where one is required.
+    InvalidNumber(String),
+    /// There is no vertex with this number.
+    UnknownVertex(String),
+    /// There are more vertices than the graph can have nodes.
+    TooManyVertices,
+    /// A quoted label has no closing quote.
+    UnterminatedString,
+    /// The file has arcs, and the graph was read as undirected.
+    GraphType,
+}
+
+impl ParseError {
+    /// The line of the error, counting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// What the error is.
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match self.kind {
+            ParseErrorKind::MissingSection => f.write_str("expected a section like *Vertices"),
+            ParseErrorKind::UnknownSection(ref name) => write!(f, "unsupported section {}", name),
+            ParseErrorKind::MissingField => f.write_str("missing field"),
+            ParseErrorKind::InvalidNumber(ref text) => write!(f, "invalid number {:?}", text),
+            ParseErrorKind::UnknownVertex(ref text) => write!(f, "there is no vertex {}", text),
+            ParseErrorKind::TooManyVertices => {
+                f.write_str("too many vertices for the graph's index type")
+            }
+            ParseErrorKind::UnterminatedString => f.write_str("unterminated string"),
+            ParseErrorKind::GraphType => f.write_str("arcs in an undirected graph"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Section {
+    None,
+    Vertices,
+    Arcs,
+    Edges,
+    ArcsList,
+    EdgesList,
+}
+
+/// Read a graph from a Pajek `.net` file.
+///
+/// `Ty` picks whether a directed or an undirected graph is read. In a
+/// directed graph, each edge is read as a pair of arcs; in an undirected
+/// graph, arcs are an error. Arcs and edges without a weight have weight 1,
+/// and the drawing parameters after the weight are skipped. `*Arcslist` and
+/// `*Edgeslist` sections are read as well.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::pajek::parse;
+/// use petgraph::prelude::*;
+///
+/// let parsed = parse::<Undirected>(
+///     "*Network karate\n*Vertices 3\n1 \"Mr Hi\" 0.5 0.5\n*Edges\n1 2 4\n1 3\n",
+/// )
+/// .unwrap();
+/// let graph = parsed.graph;
+/// assert_eq!(parsed.name.as_deref(), Some("karate"));
+/// assert_eq!(graph[NodeIndex::new(0)].label, "Mr Hi");
+/// assert_eq!(graph[NodeIndex::new(0)].position, Some([0.5, 0.5]));
+/// assert_eq!(graph[NodeIndex::new(2)].label, "3");
+/// assert_eq!(graph.edge_weights().collect::<Vec<_>>(), [&4., &1.]);
+/// ```
+pub fn parse<Ty: EdgeType>(input: &str) -> Result<ParsedGraph<Ty>, ParseError> {
+    let mut name = None;
+    let mut graph = Graph::<PajekNode, f64, Ty>::default();
+    // the vertices of the header are added as they are reached, and the
+    // rest at the end, so that the header alone allocates nothing
+    let mut vertices = 0;
+    let mut section = Section::None;
+    for (i, line) in input.lines().enumerate() {
+        let error = |kind| ParseError { line: i + 1, kind };
+        let tokens = tokenize(line).map_err(error)?;
+        let first = match tokens.first() {
+            Some(first) => first,
+            None => continue,
+        };
+        if first.starts_with('*') {
+            section = match &*first.to_lowercase() {
+                "*network" => {
+                    name = Some(tokens[1..].join(" "));
+                    Section::None
+                }
+                "*vertices" => {
+                    let count = tokens
+                        .get(1)
+                        .ok_or_else(|| error(ParseErrorKind::MissingField))?;
+                    let count = number(count).map_err(error)?;
+                    if count > <DefaultIx as IndexType>::max().index() {
+                        return Err(error(ParseErrorKind::TooManyVertices));
+                    }
+                    graph.clear();
+                    vertices = count;
+                    Section::Vertices
+                }
+                "*arcs" | "*arcslist" if !Ty::is_directed() => {
+                    return Err(error(ParseErrorKind::GraphType))
+                }
+                "*arcs" => Section::Arcs,
+                "*edges" => Section::Edges,
+                "*arcslist" => Section::ArcsList,
+                "*edgeslist" => Section::EdgesList,
+                _ => return Err(error(ParseErrorKind::UnknownSection(first.to_string()))),
+            };
+            continue;
+        }
+        let vertex = |token: &String| {
+            let n = number(token)?;
+            if n == 0 || n > vertices {
+                return Err(ParseErrorKind::UnknownVertex(token.to_string()));
+            }
+            Ok(NodeIndex::new(n - 1))
+        };
+        let field = |index: usize| tokens.get(index).ok_or(ParseErrorKind::MissingField);
+        match section {
+            Section::None => return Err(error(ParseErrorKind::MissingSection)),
+            Section::Vertices => {
+                let node = vertex(first).map_err(error)?;
+                add_vertices(&mut graph, node.index() + 1);
+                let mut position = [0.; 2];
+                let mut coordinates = 0;
+                for (p, token) in position.iter_mut().zip(tokens.iter().skip(2)) {
+                    match token.parse() {
+                        Ok(x) => *p = x,
+                        Err(_) => break,
+                    }
+                    coordinates += 1;
+                }
+                if let Some(label) = tokens.get(1) {
+                    graph[node].label = label.to_string();
+                }
+                if coordinates == 2 {
+                    graph[node].position = Some(position);
+                }
+            }
+            Section::Arcs | Section::Edges => {
+                let source = field(0).and_then(vertex).map_err(error)?;
+                let target = field(1).and_then(vertex).map_err(error)?;
+                add_vertices(&mut graph, source.max(target).index() + 1);
+                let weight = match tokens.get(2) {
+                    Some(token) => token
+                        .parse()
+                        .map_err(|_| error(ParseErrorKind::InvalidNumber(token.to_string())))?,
+                    None => 1.,
+                };
+                add_edge(&mut graph, section, source, target, weight);
+            }
+            Section::ArcsList | Section::EdgesList => {
+                let source = vertex(first).map_err(error)?;
+                add_vertices(&mut graph, source.index() + 1);
+                for token in &tokens[1..] {
+                    let target = vertex(token).map_err(error)?;
+                    add_vertices(&mut graph, target.index() + 1);
+                    add_edge(&mut graph, section, source, target, 1.);
+                }
+            }
+        }
+    }
+    add_vertices(&mut graph, vertices);
+    Ok(ParsedGraph { name, graph })
+}
+
+/// Add vertices until the graph has `count` of them, with their numbers as
+/// labels.
+fn add_vertices<Ty: EdgeType>(graph: &mut Graph<PajekNode, f64, Ty>, count: usize) {
+    for n in graph.node_count() + 1..=count {
+        graph.add_node(PajekNode {
+            label: n.to_string(),
+            position: None,
+        });
+    }
+}
+
+fn add_edge<Ty: EdgeType>(
+    graph: &mut Graph<PajekNode, f64, Ty>,
+    section: Section,
+    source: NodeIndex,
+    target: NodeIndex,
+    weight: f64,
+) {
+    graph.add_edge(source, target, weight);
+    let edges = section == Section::Edges || section == Section::EdgesList;
+    if edges && Ty::is_directed() && source != target {
+        graph.add_edge(target, source, weight);
+    }
+}
+
+fn number(token: &str) -> Result<usize, ParseErrorKind> {
+    token
+        .parse()
+        .map_err(|_| ParseErrorKind::InvalidNumber(token.to_string()))
+}
+
+/// Split a line into whitespace separated tokens, with quoted tokens
+/// unquoted. A line starting with `%` is a comment.
+fn tokenize(line: &str) -> Result<Vec<String>, ParseErrorKind> {
+    let mut tokens = Vec::new();
+    let mut rest = line.trim_start();
+    if rest.starts_with('%') {
+        return Ok(tokens);
+    }
+    while !rest.is_empty() {
+        if rest.starts_with('"') {
+            let end = rest[1..]
+                .find('"')
+                .ok_or(ParseErrorKind::UnterminatedString)?;
+            tokens.push(rest[1..end + 1].to_string());
+            rest = &rest[end + 2..];
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            tokens.push(rest[..end].to_string());
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
@@ -0,0 +1,462 @@
+//! Plain text output of small graphs, for reading in a terminal.
+
+use std::fmt::{self, Display};
+
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+/// `Pretty` implements output of a small graph as plain text, as an
+/// adjacency table or as a diagram of boxes, drawn with Unicode box drawing
+/// characters or with ASCII.
+///
+/// The table has a row for each node, labeled with its index and weight,
+/// and a column for each node, labeled with its index. The cell in row `a`
+/// and column `b` holds the weights of the edges from `a` to `b`, both ways
+/// for an undirected graph. The diagram draws a box with the label of each
+/// node, followed by its edges with their weights and the labels of the
+/// nodes they lead to.
+///
+/// Like [`Dot`](../dot/struct.Dot.html), this is intended for debugging, and
+/// the exact output may change. Line breaks in weights are replaced with
+/// spaces.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::prelude::*;
+/// use petgraph::pretty::{Config, Pretty};
+///
+/// let mut graph = Graph::<&str, u32>::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// graph.add_edge(a, b, 1);
+/// graph.add_edge(b, c, 2);
+/// graph.add_edge(c, a, 3);
+///
+/// assert_eq!(
+///     Pretty::new(&graph).to_string(),
+///     "    │ 0 1 2
+/// ────┼──────
+/// 0 a │ · 1 ·
+/// 1 b │ · · 2
+/// 2 c │ 3 · ·
+/// "
+/// );
+///
+/// assert_eq!(
+///     Pretty::with_config(&graph, &[Config::Boxes, Config::Ascii]).to_string(),
+///     "+-----+
+/// | 0 a +-[1]-> 1 b
+/// +-----+
+/// +-----+
+/// | 1 b +-[2]-> 2 c
+/// +-----+
+/// +-----+
+/// | 2 c +-[3]-> 0 a
+/// +-----+
+/// "
+/// );
+/// ```
+pub struct Pretty<G> {
+    graph: G,
+    config: Configs,
+}
+
+impl<G> Pretty<G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+{
+    /// Create a `Pretty` formatting wrapper with default configuration: an
+    /// adjacency table drawn with Unicode characters.
+    #[inline]
+    pub fn new(graph: G) -> Self {
+        Self::with_config(graph, &[])
+    }
+
+    /// Create a `Pretty` formatting wrapper with custom configuration.
+    #[inline]
+    pub fn with_config(graph: G, config: &[Config]) -> Self {
+        let mut configs = Configs::default();
+        for c in config {
+            match *c {
+                Config::Boxes => configs.boxes = true,
+                Config::Ascii => configs.ascii = true,
+                Config::EdgeNoLabel => configs.edge_no_label = true,
+                Config::NodeNoLabel => configs.node_no_label = true,
+            }
+        }
+        Pretty {
+            graph,
+            config: configs,
+        }
+    }
+}
+
+/// `Pretty` configuration.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Config {
+    /// Draw a diagram of boxes instead of an adjacency table.
+    Boxes,
+    /// Draw with ASCII characters only.
+    Ascii,
+    /// Use no edge labels: mark each edge instead of showing its weight.
+    EdgeNoLabel,
+    /// Use no node labels: show only the index of each node.
+    NodeNoLabel,
+}
+
+#[derive(Default)]
+struct Configs {
+    boxes: bool,
+    ascii: bool,
+    edge_no_label: bool,
+    node_no_label: bool,
+}
+
+/// The characters to draw with.
+struct Charset {
+    horizontal: char,
+    vertical: char,
+    cross: char,
+    corners: [char; 4],
+    tee: char,
+    arrow: char,
+    edge: char,
+    empty: char,
+}
+
+static UNICODE: Charset = Charset {
+    horizontal: '─',
+    vertical: '│',
+    cross: '┼',
+    corners: ['┌', '┐', '└', '┘'],
+    tee: '├',
+    arrow: '▶',
+    edge: '●',
+    empty: '·',
+};
+
+static ASCII: Charset = Charset {
+    horizontal: '-',
+    vertical: '|',
+    cross: '+',
+    corners: ['+', '+', '+', '+'],
+    tee: '+',
+    arrow: '>',
+    edge: 'x',
+    empty: '.',
+};
+
+impl<G> Pretty<G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    fn graph_fmt<NF, EF>(&self, f: &mut fmt::Formatter, node_fmt: NF, edge_fmt: EF) -> fmt::Result
+    where
+        NF: Fn(&G::NodeWeight, &mut fmt::Formatter) -> fmt::Result,
+        EF: Fn(&G::EdgeWeight, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let g = self.graph;
+        let chars = if self.config.ascii { &ASCII } else { &UNICODE };
+
+        // the row of each node by index, and the label of each row
+        let mut rows = vec![None; g.node_bound()];
+        let mut labels = Vec::new();
+        for node in g.node_references() {
+            let index = g.to_index(node.id());
+            rows[index] = Some(labels.len());
+            labels.push(if self.config.node_no_label {
+                index.to_string()
+            } else {
+                one_line(format!("{} {}", index, FnFmt(node.weight(), &node_fmt)))
+            });
+        }
+        // the edges from each row, with the row they lead to
+        let mut edges = vec![Vec::new(); labels.len()];
+        for edge in g.edge_references() {
+            let a = rows[g.to_index(edge.source())].unwrap();
+            let b = rows[g.to_index(edge.target())].unwrap();
+            let label = if self.config.edge_no_label {
+                // the boxes show the edge without a label
+                if self.config.boxes {
+                    String::new()
+                } else {
+                    chars.edge.to_string()
+                }
+            } else {
+                one_line(FnFmt(edge.weight(), &edge_fmt).to_string())
+            };
+            if !g.is_directed() && a != b {
+                edges[b].push((a, label.clone()));
+            }
+            edges[a].push((b, label));
+        }
+
+        if self.config.boxes {
+            boxes_fmt(f, chars, g.is_directed(), &labels, &edges)
+        } else {
+            let indices = g
+                .node_references()
+                .map(|node| g.to_index(node.id()).to_string())
+                .collect::<Vec<_>>();
+            table_fmt(f, chars, &labels, &indices, &edges)
+        }
+    }
+}
+
+fn table_fmt(
+    f: &mut fmt::Formatter,
+    chars: &Charset,
+    labels: &[String],
+    indices: &[String],
+    edges: &[Vec<(usize, String)>],
+) -> fmt::Result {
+    let n = labels.len();
+    let mut cells = vec![vec![Vec::new(); n]; n];
+    for (a, row) in edges.iter().enumerate() {
+        for (b, label) in row {
+            cells[a][*b].push(label.as_str());
+        }
+    }
+    let cells = cells
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|cell| {
+                    if cell.is_empty() {
+                        chars.empty.to_string()
+                    } else {
+                        cell.join(",")
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let label_width = labels.iter().map(|s| width(s)).max().unwrap_or(0);
+    let widths = (0..n)
+        .map(|b| {
+            cells
+                .iter()
+                .map(|row| width(&row[b]))
+                .chain(Some(width(&indices[b])))
+                .max()
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let mut line = pad("", label_width);
+    line.push(' ');
+    line.push(chars.vertical);
+    for (index, &w) in indices.iter().zip(&widths) {
+        line.push(' ');
+        line.push_str(&pad(index, w));
+    }
+    writeln!(f, "{}", line.trim_end())?;
+    let rule = |count: usize| (0..count).map(|_| chars.horizontal).collect::<String>();
+    let columns_width = widths.iter().map(|w| w + 1).sum::<usize>();
+    writeln!(
+        f,
+        "{}{}{}",
+        rule(label_width + 1),
+        chars.cross,
+        rule(columns_width)
+    )?;
+    for (label, row) in labels.iter().zip(&cells) {
+        let mut line = pad(label, label_width);
+        line.push(' ');
+        line.push(chars.vertical);
+        for (cell, &w) in row.iter().zip(&widths) {
+            line.push(' ');
+            line.push_str(&pad(cell, w));
+        }
+        writeln!(f, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+fn boxes_fmt(
+    f: &mut fmt::Formatter,
+    chars: &Charset,
+    directed: bool,
+    labels: &[String],
+    edges: &[Vec<(usize, String)>],
+) -> fmt::Result {
+    let label_width = labels.iter().map(|s| width(s)).max().unwrap_or(0);
+    let border = (0..label_width + 2)
+        .map(|_| chars.horizontal)
+        .collect::<String>();
+    let head = if directed {
+        chars.arrow
+    } else {
+        chars.horizontal
+    };
+    for (label, row) in labels.iter().zip(edges) {
+        let [top_left, top_right, bottom_left, bottom_right] = chars.corners;
+        writeln!(f, "{}{}{}", top_left, border, top_right)?;
+        for i in 0..row.len().max(1) {
+            let text = if i == 0 { label.as_str() } else { "" };
+            write!(f, "{} {} ", chars.vertical, pad(text, label_width))?;
+            match row.get(i) {
+                Some((b, weight)) => {
+                    write!(f, "{}{}", chars.tee, chars.horizontal)?;
+                    if !weight.is_empty() {
+                        write!(f, "[{}]{}", weight, chars.horizontal)?;
+                    }
+                    writeln!(f, "{} {}", head, labels[*b])?;
+                }
+                None => writeln!(f, "{}", chars.vertical)?,
+            }
+        }
+        writeln!(f, "{}{}{}", bottom_left, border, bottom_right)?;
+    }
+    Ok(())
+}
+
+/// The width of `s` in characters.
+fn width(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Pad `s` with spaces to `width` characters.
+fn pad(s: &str, width: usize) -> String {
+    let mut padded = s.to_string();
+    padded.extend((self::width(s)..width).map(|_| ' '));
+    padded
+}
+
+/// Replace the line breaks in `s` with spaces.
+fn one_line(s: String) -> String {
+    if s.contains('\n') {
+        s.replace("\r\n", " ").replace('\n', " ")
+    } else {
+        s
+    }
+}
+
+impl<G> fmt::Display for Pretty<G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::EdgeWeight: fmt::Display,
+    G::NodeWeight: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Display::fmt, fmt::Display::fmt)
+    }
+}
+
+impl<G> fmt::Debug for Pretty<G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::EdgeWeight: fmt::Debug,
+    G::NodeWeight: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Debug::fmt, fmt::Debug::fmt)
+    }
+}
+
+/// Format data using a specific format function
+struct FnFmt<'a, T, F>(&'a T, F);
+
+impl<'a, T, F> Display for FnFmt<'a, T, F>
+where
+    F: Fn(&'a T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.1(self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Config, Pretty};
+    use crate::prelude::*;
+    use crate::EdgeType;
+
+    fn simple_graph<Ty: EdgeType>() -> Graph<&'static str, u32, Ty> {
+        let mut graph = Graph::default();
+        let a = graph.add_node("A");
+        let b = graph.add_node("B\nb");
+        let c = graph.add_node("C");
+        graph.add_edge(a, b, 10);
+        graph.add_edge(a, b, 11);
+        graph.add_edge(b, b, 2);
+        graph.add_edge(c, a, 3);
+        graph
+    }
+
+    #[test]
+    fn test_table() {
+        let graph = simple_graph::<Directed>();
+        assert_eq!(
+            format!("{}", Pretty::new(&graph)),
+            "      │ 0 1     2
+──────┼──────────
+0 A   │ · 10,11 ·
+1 B b │ · 2     ·
+2 C   │ 3 ·     ·
+"
+        );
+        let graph = simple_graph::<Undirected>();
+        assert_eq!(
+            format!(
+                "{:?}",
+                Pretty::with_config(
+                    &graph,
+                    &[Config::Ascii, Config::EdgeNoLabel, Config::NodeNoLabel]
+                )
+            ),
+            "  | 0   1   2
+--+----------
+0 | .   x,x x
+1 | x,x x   .
+2 | x   .   .
+"
+        );
+    }
+
+    #[test]
+    fn test_boxes() {
+        let mut graph = simple_graph::<Undirected>();
+        graph.add_node("D");
+        assert_eq!(
+            format!("{}", Pretty::with_config(&graph, &[Config::Boxes])),
+            "┌───────┐
+│ 0 A   ├─[10]── 1 B b
+│       ├─[11]── 1 B b
+│       ├─[3]── 2 C
+└───────┘
+┌───────┐
+│ 1 B b ├─[10]── 0 A
+│       ├─[11]── 0 A
+│       ├─[2]── 1 B b
+└───────┘
+┌───────┐
+│ 2 C   ├─[3]── 0 A
+└───────┘
+┌───────┐
+│ 3 D   │
+└───────┘
+"
+        );
+        let graph = Graph::<(), ()>::from_edges(&[(0, 1)]);
+        assert_eq!(
+            format!(
+                "{:?}",
+                Pretty::with_config(
+                    &graph,
+                    &[Config::Boxes, Config::EdgeNoLabel, Config::NodeNoLabel]
+                )
+            ),
+            "┌───┐
+│ 0 ├─▶ 1
+└───┘
+┌───┐
+│ 1 │
+└───┘
+"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 extern crate quickcheck;
 use self::quickcheck::{Arbitrary, Gen};
 
-use crate::graph::{node_index, IndexType};
+use crate::graph::{edge_index, node_index, IndexType};
 #[cfg(feature = "stable_graph")]
 use crate::stable_graph::StableGraph;
 use crate::{EdgeType, Graph};
@@ -15,7 +15,8 @@ fn random_01<G: Gen>(g: &mut G) -> f64 {
     // from rand
     let bits = 53;
     let scale = 1. / ((1u64 << bits) as f64);
-    let x: u64 = Arbitrary::arbitrary(g);
+    // not u64::arbitrary, which is bounded by the size of the generator
+    let x = g.next_u64();
     (x >> (64 - bits)) as f64 * scale
 }
 
@@ -25,6 +26,9 @@ fn random_01<G: Gen>(g: &mut G) -> f64 {
 /// The result will be simple graph or digraph, self loops
 /// possible, no parallel edges.
 ///
+/// Shrinking splits the graph in two by node index, or removes a single
+/// node or edge.
+///
 /// The exact properties of the produced graph is subject to change.
 ///
 /// Requires crate feature `"quickcheck"`
@@ -62,10 +66,11 @@ where
     }
 
     // shrink the graph by splitting it in two by a very
-    // simple algorithm, just even and odd node indices
+    // simple algorithm, just even and odd node indices,
+    // then by removing a single node or edge
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
         let self_ = self.clone();
-        Box::new((0..2).filter_map(move |x| {
+        let halves = (0..2).filter_map(move |x| {
             let gr = self_.filter_map(
                 |i, w| {
                     if i.index() % 2 == x {
@@ -82,7 +87,20 @@ where
             } else {
                 None
             }
-        }))
+        });
+        let self_ = self.clone();
+        let nodes = (0..self.node_count()).rev().map(move |i| {
+            let mut gr = self_.clone();
+            gr.remove_node(node_index(i));
+            gr
+        });
+        let self_ = self.clone();
+        let edges = (0..self.edge_count()).rev().map(move |i| {
+            let mut gr = self_.clone();
+            gr.remove_edge(edge_index(i));
+            gr
+        });
+        Box::new(halves.chain(nodes).chain(edges))
     }
 }
 
@@ -93,6 +111,9 @@ where
 /// The result will be simple graph or digraph, with possible
 /// self loops, no parallel edges.
 ///
+/// Shrinking splits the graph in two by node index, or removes a single
+/// node or edge.
+///
 /// The exact properties of the produced graph is subject to change.
 ///
 /// Requires crate features `"quickcheck"` and `"stable_graph"`
@@ -142,10 +163,11 @@ where
     }
 
     // shrink the graph by splitting it in two by a very
-    // simple algorithm, just even and odd node indices
+    // simple algorithm, just even and odd node indices,
+    // then by removing a single node or edge
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
         let self_ = self.clone();
-        Box::new((0..2).filter_map(move |x| {
+        let halves = (0..2).filter_map(move |x| {
             let gr = self_.filter_map(
                 |i, w| {
                     if i.index() % 2 == x {
@@ -162,7 +184,22 @@ where
             } else {
                 None
             }
-        }))
+        });
+        let self_ = self.clone();
+        let nodes = self.node_indices().collect::<Vec<_>>();
+        let nodes = nodes.into_iter().rev().map(move |a| {
+            let mut gr = self_.clone();
+            gr.remove_node(a);
+            gr
+        });
+        let self_ = self.clone();
+        let edges = self.edge_indices().collect::<Vec<_>>();
+        let edges = edges.into_iter().rev().map(move |e| {
+            let mut gr = self_.clone();
+            gr.remove_edge(e);
+            gr
+        });
+        Box::new(halves.chain(nodes).chain(edges))
     }
 }
 
@@ -172,6 +209,8 @@ where
 /// The result will be simple graph or digraph, self loops
 /// possible, no parallel edges.
 ///
+/// Shrinking removes a single node or edge.
+///
 /// The exact properties of the produced graph is subject to change.
 ///
 /// Requires crate features `"quickcheck"` and `"graphmap"`
@@ -213,4 +252,23 @@ where
         }
         gr
     }
+
+    // shrink the graph by removing a single node or edge
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let self_ = self.clone();
+        let nodes = self.nodes().collect::<Vec<_>>();
+        let nodes = nodes.into_iter().rev().map(move |a| {
+            let mut gr = self_.clone();
+            gr.remove_node(a);
+            gr
+        });
+        let self_ = self.clone();
+        let edges = self.all_edges().map(|(a, b, _)| (a, b)).collect::<Vec<_>>();
+        let edges = edges.into_iter().rev().map(move |(a, b)| {
+            let mut gr = self_.clone();
+            gr.remove_edge(a, b);
+            gr
+        });
+        Box::new(nodes.chain(edges))
+    }
 }
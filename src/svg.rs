@@ -0,0 +1,360 @@
+//! Simple SVG output of a graph drawn with given node positions.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Write};
+use std::hash::Hash;
+
+use crate::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable, NodeRef,
+};
+
+/// `Svg` implements output to an SVG document for a graph whose nodes have
+/// positions, for example from the [`layout`](../layout/index.html) module.
+///
+/// Nodes are drawn as labeled circles and edges as straight lines, with an
+/// arrowhead for directed graphs. The positions are scaled uniformly to fit
+/// the document, 400 by 400 pixels by default. Nodes without a position are
+/// left out, along with their edges.
+///
+/// The attribute getters return extra SVG attributes for the `<circle>` of
+/// each node and the `<line>` of each edge, like `fill="red"`; they override
+/// the default style.
+///
+/// # Examples
+///
+/// ```
+/// use petgraph::layout::circular_layout;
+/// use petgraph::prelude::*;
+/// use petgraph::svg::Svg;
+///
+/// let graph = UnGraph::<&str, ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let positions = circular_layout(&graph);
+/// let svg = Svg::with_attr_getters(
+///     &graph,
+///     &positions,
+///     &|_, _| String::new(),
+///     &|_, node| {
+///         if node.0.index() == 0 {
+///             "fill=\"orange\"".to_string()
+///         } else {
+///             String::new()
+///         }
+///     },
+/// );
+/// let document = format!("{:?}", svg);
+/// assert!(document.starts_with("<svg"));
+/// assert_eq!(document.matches("<circle").count(), 3);
+/// assert_eq!(document.matches("<line").count(), 3);
+/// ```
+pub struct Svg<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences,
+{
+    graph: G,
+    positions: &'a HashMap<G::NodeId, [f64; 2]>,
+    get_edge_attributes: &'a dyn Fn(G, G::EdgeRef) -> String,
+    get_node_attributes: &'a dyn Fn(G, G::NodeRef) -> String,
+    size: [f64; 2],
+}
+
+/// The radius of the node circles.
+const RADIUS: f64 = 12.;
+/// The space around the drawing.
+const MARGIN: f64 = 2. * RADIUS;
+static INDENT: &str = "  ";
+
+impl<'a, G> Svg<'a, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences,
+{
+    /// Create an `Svg` formatting wrapper with the default style.
+    #[inline]
+    pub fn new(graph: G, positions: &'a HashMap<G::NodeId, [f64; 2]>) -> Self {
+        Self::with_attr_getters(graph, positions, &|_, _| String::new(), &|_, _| {
+            String::new()
+        })
+    }
+
+    /// Create an `Svg` formatting wrapper with extra attributes for each edge
+    /// and node.
+    #[inline]
+    pub fn with_attr_getters(
+        graph: G,
+        positions: &'a HashMap<G::NodeId, [f64; 2]>,
+        get_edge_attributes: &'a dyn Fn(G, G::EdgeRef) -> String,
+        get_node_attributes: &'a dyn Fn(G, G::NodeRef) -> String,
+    ) -> Self {
+        Svg {
+            graph,
+            positions,
+            get_edge_attributes,
+            get_node_attributes,
+            size: [400., 400.],
+        }
+    }
+
+    /// Set the width and height of the document, in pixels.
+    pub fn with_size(mut self, width: f64, height: f64) -> Self {
+        self.size = [width, height];
+        self
+    }
+}
+
+impl<'a, G> Svg<'a, G>
+where
+    G: IntoNodeReferences + IntoEdgeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    fn graph_fmt<NF>(&self, f: &mut fmt::Formatter, node_fmt: NF) -> fmt::Result
+    where
+        NF: Fn(&G::NodeWeight, &mut fmt::Formatter) -> fmt::Result,
+    {
+        let g = self.graph;
+        let [width, height] = self.size;
+
+        // Map the bounding box of the positions into the document.
+        let mut min = [std::f64::INFINITY; 2];
+        let mut max = [std::f64::NEG_INFINITY; 2];
+        for p in self.positions.values() {
+            for d in 0..2 {
+                min[d] = min[d].min(p[d]);
+                max[d] = max[d].max(p[d]);
+            }
+        }
+        let extent = [max[0] - min[0], max[1] - min[1]];
+        let fit = |d: usize| {
+            if extent[d] > 0. {
+                ((self.size[d] - 2. * MARGIN) / extent[d]).max(0.)
+            } else {
+                std::f64::INFINITY
+            }
+        };
+        let mut scale = fit(0).min(fit(1));
+        if scale.is_infinite() {
+            scale = 0.;
+        }
+        let offset = [
+            (width - extent[0] * scale) / 2.,
+            (height - extent[1] * scale) / 2.,
+        ];
+        let point = |id: G::NodeId| {
+            self.positions.get(&id).map(|p| {
+                [
+                    offset[0] + (p[0] - min[0]) * scale,
+                    offset[1] + (p[1] - min[1]) * scale,
+                ]
+            })
+        };
+        // a hundredth of a pixel is precise enough
+        let round = |x: f64| (x * 100.).round() / 100.;
+
+        writeln!(
+            f,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"0 0 {} {}\">",
+            width, height, width, height
+        )?;
+        if g.is_directed() {
+            writeln!(f, "{}<defs>", INDENT)?;
+            writeln!(
+                f,
+                "{0}{0}<marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" \
+                 markerWidth=\"8\" markerHeight=\"8\" orient=\"auto\">",
+                INDENT
+            )?;
+            writeln!(f, "{0}{0}{0}<path d=\"M 0 0 L 10 5 L 0 10 z\"/>", INDENT)?;
+            writeln!(f, "{0}{0}</marker>", INDENT)?;
+            writeln!(f, "{}</defs>", INDENT)?;
+        }
+
+        // output all edges, below the nodes
+        writeln!(f, "{}<g stroke=\"black\" fill=\"none\">", INDENT)?;
+        for edge in g.edge_references() {
+            let (a, b) = match (point(edge.source()), point(edge.target())) {
+                (Some(a), Some(b)) => (a, b),
+                _ => continue,
+            };
+            let attributes = (self.get_edge_attributes)(g, edge);
+            if edge.source() == edge.target() {
+                write!(
+                    f,
+                    "{0}{0}<circle cx=\"{1}\" cy=\"{2}\" r=\"{3}\" ",
+                    INDENT,
+                    round(a[0]),
+                    round(a[1] - RADIUS),
+                    RADIUS
+                )?;
+                writeln!(f, "{}/>", attributes)?;
+                continue;
+            }
+            let mut end = b;
+            let length = ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt();
+            if g.is_directed() && length > RADIUS {
+                // end the arrow at the border of the target node
+                let cut = RADIUS / length;
+                end = [b[0] - (b[0] - a[0]) * cut, b[1] - (b[1] - a[1]) * cut];
+            }
+            write!(
+                f,
+                "{0}{0}<line x1=\"{1}\" y1=\"{2}\" x2=\"{3}\" y2=\"{4}\" ",
+                INDENT,
+                round(a[0]),
+                round(a[1]),
+                round(end[0]),
+                round(end[1])
+            )?;
+            if g.is_directed() {
+                write!(f, "marker-end=\"url(#arrow)\" ")?;
+            }
+            writeln!(f, "{}/>", attributes)?;
+        }
+        writeln!(f, "{}</g>", INDENT)?;
+
+        // output all nodes
+        writeln!(
+            f,
+            "{}<g stroke=\"black\" fill=\"white\" font-family=\"sans-serif\" \
+             font-size=\"{}\" text-anchor=\"middle\">",
+            INDENT, RADIUS
+        )?;
+        for node in g.node_references() {
+            let p = match point(node.id()) {
+                Some(p) => p,
+                None => continue,
+            };
+            write!(
+                f,
+                "{0}{0}<circle cx=\"{1}\" cy=\"{2}\" r=\"{3}\" ",
+                INDENT,
+                round(p[0]),
+                round(p[1]),
+                RADIUS
+            )?;
+            writeln!(f, "{}/>", (self.get_node_attributes)(g, node))?;
+            write!(
+                f,
+                "{0}{0}<text x=\"{1}\" y=\"{2}\" dy=\"0.35em\" stroke=\"none\" \
+                 fill=\"black\">",
+                INDENT,
+                round(p[0]),
+                round(p[1])
+            )?;
+            write!(&mut Escaper(&mut *f), "{}", FnFmt(node.weight(), &node_fmt))?;
+            writeln!(f, "</text>")?;
+        }
+        writeln!(f, "{}</g>", INDENT)?;
+        writeln!(f, "</svg>")
+    }
+}
+
+impl<'a, G> fmt::Display for Svg<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Display::fmt)
+    }
+}
+
+impl<'a, G> fmt::Debug for Svg<'a, G>
+where
+    G: IntoEdgeReferences + IntoNodeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    G::NodeWeight: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.graph_fmt(f, fmt::Debug::fmt)
+    }
+}
+
+/// Escape for XML
+struct Escaper<W>(W);
+
+impl<W> fmt::Write for Escaper<W>
+where
+    W: fmt::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '&' => self.0.write_str("&amp;"),
+            '<' => self.0.write_str("&lt;"),
+            '>' => self.0.write_str("&gt;"),
+            '"' => self.0.write_str("&quot;"),
+            _ => self.0.write_char(c),
+        }
+    }
+}
+
+/// Format data using a specific format function
+struct FnFmt<'a, T, F>(&'a T, F);
+
+impl<'a, T, F> Display for FnFmt<'a, T, F>
+where
+    F: Fn(&'a T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.1(self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Escaper, Svg};
+    use crate::prelude::*;
+    use std::collections::HashMap;
+    use std::fmt::Write;
+
+    #[test]
+    fn test_escape() {
+        let mut buff = String::new();
+        {
+            let mut e = Escaper(&mut buff);
+            let _ = e.write_str("<a & \"b\">");
+        }
+        assert_eq!(buff, "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+
+    #[test]
+    fn test_svg() {
+        let mut graph = Graph::<&str, ()>::new();
+        let a = graph.add_node("A");
+        let b = graph.add_node("<B>");
+        let c = graph.add_node("C");
+        graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+        let positions = [(a, [0., 0.]), (b, [1., 0.5])]
+            .iter()
+            .cloned()
+            .collect::<HashMap<_, _>>();
+        let svg = format!("{}", Svg::new(&graph, &positions).with_size(200., 100.));
+        assert_eq!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"200\" height=\"100\" viewBox=\"0 0 200 100\">
+  <defs>
+    <marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" markerWidth=\"8\" markerHeight=\"8\" orient=\"auto\">
+      <path d=\"M 0 0 L 10 5 L 0 10 z\"/>
+    </marker>
+  </defs>
+  <g stroke=\"black\" fill=\"none\">
+    <line x1=\"48\" y1=\"24\" x2=\"141.27\" y2=\"70.63\" marker-end=\"url(#arrow)\" />
+  </g>
+  <g stroke=\"black\" fill=\"white\" font-family=\"sans-serif\" font-size=\"12\" text-anchor=\"middle\">
+    <circle cx=\"48\" cy=\"24\" r=\"12\" />
+    <text x=\"48\" y=\"24\" dy=\"0.35em\" stroke=\"none\" fill=\"black\">A</text>
+    <circle cx=\"152\" cy=\"76\" r=\"12\" />
+    <text x=\"152\" y=\"76\" dy=\"0.35em\" stroke=\"none\" fill=\"black\">&lt;B&gt;</text>
+  </g>
+</svg>
+"
+        );
+    }
+}
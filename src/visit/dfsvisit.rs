@@ -1,6 +1,9 @@
-use crate::visit::IntoNeighbors;
+use crate::visit::{EdgeRef, IntoEdges, IntoNeighbors};
 use crate::visit::{VisitMap, Visitable};
 
+use std::collections::HashMap;
+use std::hash::Hash;
+
 /// Strictly monotonically increasing event time for a depth first search.
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Default, Hash)]
 pub struct Time(pub usize);
@@ -22,6 +25,26 @@ pub enum DfsEvent<N> {
     Finish(N, Time),
 }
 
+/// A depth first search (DFS) visitor event, with the ids of the edges.
+///
+/// The events are the same as for [`DfsEvent`](enum.DfsEvent.html), but
+/// each edge event also carries the id of the edge it reports.
+#[derive(Copy, Clone, Debug)]
+pub enum DfsEdgeEvent<N, E> {
+    Discover(N, Time),
+    /// An edge of the tree formed by the traversal.
+    TreeEdge(N, N, E),
+    /// An edge to an already visited node.
+    BackEdge(N, N, E),
+    /// A cross or forward edge.
+    ///
+    /// For an edge *(u, v)*, if the discover time of *v* is greater than *u*,
+    /// then it is a forward edge, else a cross edge.
+    CrossForwardEdge(N, N, E),
+    /// All edges from a node have been reported.
+    Finish(N, Time),
+}
+
 /// Return if the expression is a break value, execute the provided statement
 /// if it is a prune value.
 macro_rules! try_control {
@@ -50,8 +73,10 @@ pub enum Control<B> {
     Continue,
     /// Prune the current node from the DFS traversal. No more edges from this
     /// node will be reported to the callback. A `DfsEvent::Finish` for this
-    /// node will still be reported. This can be returned in response to any
-    /// `DfsEvent`, except `Finish`, which will panic.
+    /// node will still be reported. In response to a `DfsEvent::TreeEdge`,
+    /// the target of the edge is not visited through it instead. This can be
+    /// returned in response to any `DfsEvent`, except `Finish`, which will
+    /// panic.
     Prune,
     /// Stop the DFS traversal and return the provided value.
     Break(B),
@@ -151,8 +176,14 @@ impl<B> Default for Control<B> {
 ///
 /// `Control` Implements `ControlFlow` such that `Control::Continue` resumes the search.
 /// `Control::Break` will stop the visit early, returning the contained value.
-/// `Control::Prune` will stop traversing any additional edges from the current
-/// node and proceed immediately to the `Finish` event.
+/// `Control::Prune` skips part of the search space, depending on the event:
+///
+/// - On `Discover(u, _)`, no edges from *u* are traversed and the search
+///   proceeds immediately to the `Finish` event of *u*.
+/// - On `TreeEdge(u, v)`, the search does not descend into *v*, which stays
+///   undiscovered, and continues with the next edge from *u*. *v* is still
+///   discovered if it is reached through another edge later.
+/// - On `BackEdge` and `CrossForwardEdge`, it is the same as `Continue`.
 ///
 /// There are implementations of `ControlFlow` for `()`, and `Result<C, E>` where
 /// `C: ControlFlow`. The implementation for `()` will continue until finished.
@@ -205,6 +236,32 @@ impl<B> Default for Control<B> {
 /// assert_eq!(&path, &[n(0), n(2), n(4), n(5)]);
 /// ```
 ///
+/// # Example pruning the search.
+///
+/// Collect the nodes reachable from vertex 0 without passing through
+/// vertex 2.
+///
+/// ```
+/// use petgraph::graph::node_index as n;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::depth_first_search;
+/// use petgraph::visit::{Control, DfsEvent};
+///
+/// let gr: Graph<(), ()> = Graph::from_edges(&[(0, 1), (0, 2), (2, 3), (1, 4)]);
+/// let mut reached = Vec::new();
+/// depth_first_search(&gr, Some(n(0)), |event| {
+///     match event {
+///         DfsEvent::TreeEdge(_, v) if v == n(2) => return Control::<()>::Prune,
+///         DfsEvent::Discover(u, _) => reached.push(u),
+///         _ => {}
+///     }
+///     Control::Continue
+/// });
+///
+/// reached.sort();
+/// assert_eq!(reached, vec![n(0), n(1), n(4)]);
+/// ```
+///
 /// # Example returning a `Result`.
 /// ```
 /// use petgraph::graph::node_index as n;
@@ -250,6 +307,182 @@ where
     I: IntoIterator<Item = G::NodeId>,
     F: FnMut(DfsEvent<G::NodeId>) -> C,
     C: ControlFlow,
+{
+    dfs(
+        &graph,
+        starts,
+        &|u| graph.neighbors(u).map(|v| (v, ())),
+        &mut |event| {
+            visitor(match event {
+                DfsEdgeEvent::Discover(u, t) => DfsEvent::Discover(u, t),
+                DfsEdgeEvent::TreeEdge(u, v, ()) => DfsEvent::TreeEdge(u, v),
+                DfsEdgeEvent::BackEdge(u, v, ()) => DfsEvent::BackEdge(u, v),
+                DfsEdgeEvent::CrossForwardEdge(u, v, ()) => DfsEvent::CrossForwardEdge(u, v),
+                DfsEdgeEvent::Finish(u, t) => DfsEvent::Finish(u, t),
+            })
+        },
+    )
+}
+
+/// A recursive depth first search, reporting the edges it traverses.
+///
+/// This is the same traversal as [`depth_first_search`][dfs], but the edge
+/// events are [`DfsEdgeEvent`][dee]s that also carry the id of the edge, so
+/// that parallel edges of a multigraph can be told apart. The edges of a node
+/// are visited in the order of `graph.edges()`.
+///
+/// [dfs]: fn.depth_first_search.html
+/// [dee]: enum.DfsEdgeEvent.html
+///
+/// # Example
+///
+/// Classify the two parallel edges from vertex 1 back to vertex 0.
+///
+/// ```
+/// use petgraph::graph::node_index as n;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::depth_first_search_edges;
+/// use petgraph::visit::DfsEdgeEvent;
+///
+/// let mut gr = Graph::<(), ()>::new();
+/// let a = gr.add_node(());
+/// let b = gr.add_node(());
+/// let ab = gr.add_edge(a, b, ());
+/// let ba1 = gr.add_edge(b, a, ());
+/// let ba2 = gr.add_edge(b, a, ());
+///
+/// let mut tree_edges = Vec::new();
+/// let mut back_edges = Vec::new();
+/// depth_first_search_edges(&gr, Some(n(0)), |event| match event {
+///     DfsEdgeEvent::TreeEdge(_, _, e) => tree_edges.push(e),
+///     DfsEdgeEvent::BackEdge(_, _, e) => back_edges.push(e),
+///     _ => {}
+/// });
+///
+/// back_edges.sort();
+/// assert_eq!(tree_edges, vec![ab]);
+/// assert_eq!(back_edges, vec![ba1, ba2]);
+/// ```
+pub fn depth_first_search_edges<G, I, F, C>(graph: G, starts: I, mut visitor: F) -> C
+where
+    G: IntoEdges + Visitable,
+    I: IntoIterator<Item = G::NodeId>,
+    F: FnMut(DfsEdgeEvent<G::NodeId, G::EdgeId>) -> C,
+    C: ControlFlow,
+{
+    dfs(
+        &graph,
+        starts,
+        &|u| graph.edges(u).map(|edge| (edge.target(), edge.id())),
+        &mut visitor,
+    )
+}
+
+/// The discovery and finish times of a depth first search, and the forest
+/// it formed, as computed by
+/// [`depth_first_search_times`](fn.depth_first_search_times.html).
+///
+/// Only the nodes that the search reached have times.
+#[derive(Clone, Debug)]
+pub struct DfsTimes<N> {
+    /// The time each node was discovered.
+    pub discover_time: HashMap<N, Time>,
+    /// The time each node was finished, after all its descendants.
+    pub finish_time: HashMap<N, Time>,
+    /// The parent of each node in the DFS forest. Roots have no entry.
+    pub parent: HashMap<N, N>,
+    /// The roots of the trees of the DFS forest, in the order they were
+    /// discovered.
+    pub roots: Vec<N>,
+}
+
+impl<N> DfsTimes<N>
+where
+    N: Copy + Eq + Hash,
+{
+    /// Return `true` if `u` is an ancestor of `v` in the DFS forest, or `u`
+    /// and `v` are the same reached node.
+    ///
+    /// This is the parenthesis theorem: `u` is an ancestor of `v` exactly
+    /// when the time interval of `u` contains that of `v`.
+    pub fn is_ancestor(&self, u: N, v: N) -> bool {
+        match (
+            self.discover_time.get(&u),
+            self.finish_time.get(&u),
+            self.discover_time.get(&v),
+            self.finish_time.get(&v),
+        ) {
+            (Some(du), Some(fu), Some(dv), Some(fv)) => du <= dv && fv <= fu,
+            _ => false,
+        }
+    }
+}
+
+/// Run a depth first search and return the discovery and finish time of each
+/// node it reaches, along with the DFS forest.
+///
+/// The search is the same as [`depth_first_search`][dfs] from `starts`, and
+/// the times are those of its `Discover` and `Finish` events. A start node
+/// that is not yet discovered when the search gets to it becomes the root of
+/// a new tree.
+///
+/// [dfs]: fn.depth_first_search.html
+///
+/// # Example
+///
+/// ```
+/// use petgraph::graph::node_index as n;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::{depth_first_search_times, Time};
+///
+/// let gr: Graph<(), ()> = Graph::from_edges(&[(0, 1), (1, 2), (0, 3)]);
+/// let times = depth_first_search_times(&gr, gr.node_indices());
+///
+/// assert_eq!(times.roots, vec![n(0)]);
+/// assert_eq!(times.parent[&n(2)], n(1));
+/// assert_eq!(times.discover_time[&n(0)], Time(0));
+/// assert_eq!(times.finish_time[&n(0)], Time(7));
+/// assert!(times.is_ancestor(n(0), n(2)));
+/// assert!(!times.is_ancestor(n(3), n(2)));
+/// ```
+pub fn depth_first_search_times<G, I>(graph: G, starts: I) -> DfsTimes<G::NodeId>
+where
+    G: IntoNeighbors + Visitable,
+    G::NodeId: Eq + Hash,
+    I: IntoIterator<Item = G::NodeId>,
+{
+    let mut times = DfsTimes {
+        discover_time: HashMap::new(),
+        finish_time: HashMap::new(),
+        parent: HashMap::new(),
+        roots: Vec::new(),
+    };
+    depth_first_search(graph, starts, |event| match event {
+        DfsEvent::Discover(u, t) => {
+            if !times.parent.contains_key(&u) {
+                times.roots.push(u);
+            }
+            times.discover_time.insert(u, t);
+        }
+        DfsEvent::TreeEdge(u, v) => {
+            times.parent.insert(v, u);
+        }
+        DfsEvent::Finish(u, t) => {
+            times.finish_time.insert(u, t);
+        }
+        DfsEvent::BackEdge(..) | DfsEvent::CrossForwardEdge(..) => {}
+    });
+    times
+}
+
+fn dfs<G, I, S, J, E, F, C>(graph: &G, starts: I, successors: &S, visitor: &mut F) -> C
+where
+    G: Visitable,
+    I: IntoIterator<Item = G::NodeId>,
+    S: Fn(G::NodeId) -> J,
+    J: Iterator<Item = (G::NodeId, E)>,
+    F: FnMut(DfsEdgeEvent<G::NodeId, E>) -> C,
+    C: ControlFlow,
 {
     let time = &mut Time(0);
     let discovered = &mut graph.visit_map();
@@ -257,24 +490,27 @@ where
 
     for start in starts {
         try_control!(
-            dfs_visitor(graph, start, &mut visitor, discovered, finished, time),
+            dfs_visitor(successors, start, visitor, discovered, finished, time),
             unreachable!()
         );
     }
     C::continuing()
 }
 
-fn dfs_visitor<G, F, C>(
-    graph: G,
-    u: G::NodeId,
+fn dfs_visitor<N, M, S, J, E, F, C>(
+    successors: &S,
+    u: N,
     visitor: &mut F,
-    discovered: &mut G::Map,
-    finished: &mut G::Map,
+    discovered: &mut M,
+    finished: &mut M,
     time: &mut Time,
 ) -> C
 where
-    G: IntoNeighbors + Visitable,
-    F: FnMut(DfsEvent<G::NodeId>) -> C,
+    N: Copy,
+    M: VisitMap<N>,
+    S: Fn(N) -> J,
+    J: Iterator<Item = (N, E)>,
+    F: FnMut(DfsEdgeEvent<N, E>) -> C,
     C: ControlFlow,
 {
     if !discovered.visit(u) {
@@ -282,26 +518,26 @@ where
     }
 
     try_control!(
-        visitor(DfsEvent::Discover(u, time_post_inc(time))),
+        visitor(DfsEdgeEvent::Discover(u, time_post_inc(time))),
         {},
-        for v in graph.neighbors(u) {
+        for (v, e) in successors(u) {
             if !discovered.is_visited(&v) {
-                try_control!(visitor(DfsEvent::TreeEdge(u, v)), continue);
+                try_control!(visitor(DfsEdgeEvent::TreeEdge(u, v, e)), continue);
                 try_control!(
-                    dfs_visitor(graph, v, visitor, discovered, finished, time),
+                    dfs_visitor(successors, v, visitor, discovered, finished, time),
                     unreachable!()
                 );
             } else if !finished.is_visited(&v) {
-                try_control!(visitor(DfsEvent::BackEdge(u, v)), continue);
+                try_control!(visitor(DfsEdgeEvent::BackEdge(u, v, e)), continue);
             } else {
-                try_control!(visitor(DfsEvent::CrossForwardEdge(u, v)), continue);
+                try_control!(visitor(DfsEdgeEvent::CrossForwardEdge(u, v, e)), continue);
             }
         }
     );
     let first_finish = finished.visit(u);
     debug_assert!(first_finish);
     try_control!(
-        visitor(DfsEvent::Finish(u, time_post_inc(time))),
+        visitor(DfsEdgeEvent::Finish(u, time_post_inc(time))),
         panic!("Pruning on the `DfsEvent::Finish` is not supported!")
     );
     C::continuing()
@@ -5,11 +5,11 @@ use std::collections::HashSet;
 use std::marker::PhantomData;
 
 use crate::data::DataMap;
-use crate::visit::{Data, NodeCompactIndexable, NodeCount};
+use crate::visit::{Data, NodeCompactIndexable, NodeCount, NodeDegrees};
 use crate::visit::{
-    EdgeIndexable, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges, IntoEdgesDirected,
-    IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences, NodeIndexable,
-    NodeRef, VisitMap, Visitable,
+    EdgeIndexable, GraphBase, GraphProp, GraphRef, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeIndexable, NodeRef, VisitMap, Visitable,
 };
 
 /// A graph filter for nodes.
@@ -313,6 +313,31 @@ where
     }
 }
 
+/// Counts the included nodes, in **O(|V|)** time.
+impl<G, F> NodeCount for NodeFiltered<G, F>
+where
+    G: IntoNodeIdentifiers,
+    F: FilterNode<G::NodeId>,
+{
+    fn node_count(&self) -> usize {
+        self.0
+            .node_identifiers()
+            .filter(|&a| self.1.include_node(a))
+            .count()
+    }
+}
+
+/// Counts the edges between included nodes, walking all the edges of the node.
+impl<G, F> NodeDegrees for NodeFiltered<G, F>
+where
+    G: IntoNeighborsDirected + GraphProp,
+    F: FilterNode<G::NodeId>,
+{
+    fn degree_directed(&self, a: G::NodeId, dir: Direction) -> usize {
+        self.neighbors_directed(a, dir).count()
+    }
+}
+
 impl<G, F> DataMap for NodeFiltered<G, F>
 where
     G: DataMap,
@@ -343,6 +368,252 @@ EdgeIndexable! {delegate_impl [[G, F], G, NodeFiltered<G, F>, access0]}
 GraphProp! {delegate_impl [[G, F], G, NodeFiltered<G, F>, access0]}
 Visitable! {delegate_impl [[G, F], G, NodeFiltered<G, F>, access0]}
 
+/// A graph adaptor that views the subgraph induced by a set of nodes.
+///
+/// The view has the nodes of the set, and the edges of the graph between
+/// them. Unlike [`NodeFiltered`](struct.NodeFiltered.html), it is itself a
+/// copyable graph reference, and it knows its node count, which it counts
+/// when it is created. The set is often a `FixedBitSet` of node indices, or a
+/// `HashSet` of node identifiers.
+///
+/// # Example
+/// ```rust
+/// use fixedbitset::FixedBitSet;
+/// use petgraph::algo::bellman_ford;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::SubgraphView;
+///
+/// let g = DiGraph::<(), f32>::from_edges(&[(0, 1, 1.), (1, 3, 1.), (0, 2, 1.), (2, 3, 0.5)]);
+/// // Leave out node 2.
+/// let mut nodes = FixedBitSet::with_capacity(4);
+/// nodes.insert_range(..);
+/// nodes.set(2, false);
+/// let view = SubgraphView::new(&g, &nodes);
+///
+/// let paths = bellman_ford(view, NodeIndex::new(0)).unwrap();
+/// assert_eq!(paths.distances[3], 2.);
+/// ```
+#[derive(Debug)]
+pub struct SubgraphView<'a, G, S: 'a = FixedBitSet> {
+    graph: G,
+    nodes: &'a S,
+    node_count: usize,
+}
+
+impl<'a, G, S> SubgraphView<'a, G, S>
+where
+    G: IntoNodeIdentifiers,
+    S: FilterNode<G::NodeId>,
+{
+    /// Create a view of the subgraph of `graph` induced by `nodes`.
+    ///
+    /// Computes in **O(|V|)** time, to count the nodes.
+    pub fn new(graph: G, nodes: &'a S) -> Self {
+        let node_count = graph
+            .node_identifiers()
+            .filter(|&a| nodes.include_node(a))
+            .count();
+        SubgraphView {
+            graph,
+            nodes,
+            node_count,
+        }
+    }
+}
+
+impl<'a, G, S> SubgraphView<'a, G, S> {
+    /// Return the underlying graph.
+    pub fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    /// Return the set of nodes of the view.
+    pub fn nodes(&self) -> &'a S {
+        self.nodes
+    }
+}
+
+impl<'a, G: Copy, S> Copy for SubgraphView<'a, G, S> {}
+
+impl<'a, G: Clone, S> Clone for SubgraphView<'a, G, S> {
+    fn clone(&self) -> Self {
+        SubgraphView {
+            graph: self.graph.clone(),
+            nodes: self.nodes,
+            node_count: self.node_count,
+        }
+    }
+}
+
+impl<'a, G, S> GraphBase for SubgraphView<'a, G, S>
+where
+    G: GraphBase,
+{
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<'a, G, S> GraphRef for SubgraphView<'a, G, S> where G: GraphRef {}
+
+impl<'a, G, S> NodeCount for SubgraphView<'a, G, S>
+where
+    G: GraphBase,
+{
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+}
+
+/// Counts the edges between included nodes, walking all the edges of the node.
+impl<'a, G, S> NodeDegrees for SubgraphView<'a, G, S>
+where
+    G: IntoNeighborsDirected + GraphProp,
+    S: FilterNode<G::NodeId>,
+{
+    fn degree_directed(&self, a: G::NodeId, dir: Direction) -> usize {
+        self.neighbors_directed(a, dir).count()
+    }
+}
+
+impl<'a, G, S> IntoNeighbors for SubgraphView<'a, G, S>
+where
+    G: IntoNeighbors,
+    S: FilterNode<G::NodeId>,
+{
+    type Neighbors = NodeFilteredNeighbors<'a, G::Neighbors, S>;
+    fn neighbors(self, n: G::NodeId) -> Self::Neighbors {
+        NodeFilteredNeighbors {
+            include_source: self.nodes.include_node(n),
+            iter: self.graph.neighbors(n),
+            f: self.nodes,
+        }
+    }
+}
+
+impl<'a, G, S> IntoNeighborsDirected for SubgraphView<'a, G, S>
+where
+    G: IntoNeighborsDirected,
+    S: FilterNode<G::NodeId>,
+{
+    type NeighborsDirected = NodeFilteredNeighbors<'a, G::NeighborsDirected, S>;
+    fn neighbors_directed(self, n: G::NodeId, dir: Direction) -> Self::NeighborsDirected {
+        NodeFilteredNeighbors {
+            include_source: self.nodes.include_node(n),
+            iter: self.graph.neighbors_directed(n, dir),
+            f: self.nodes,
+        }
+    }
+}
+
+impl<'a, G, S> IntoNodeIdentifiers for SubgraphView<'a, G, S>
+where
+    G: IntoNodeIdentifiers,
+    S: FilterNode<G::NodeId>,
+{
+    type NodeIdentifiers = NodeFilteredNeighbors<'a, G::NodeIdentifiers, S>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        NodeFilteredNeighbors {
+            include_source: true,
+            iter: self.graph.node_identifiers(),
+            f: self.nodes,
+        }
+    }
+}
+
+impl<'a, G, S> IntoNodeReferences for SubgraphView<'a, G, S>
+where
+    G: IntoNodeReferences,
+    S: FilterNode<G::NodeId>,
+{
+    type NodeRef = G::NodeRef;
+    type NodeReferences = NodeFilteredNodes<'a, G::NodeReferences, S>;
+    fn node_references(self) -> Self::NodeReferences {
+        NodeFilteredNodes {
+            include_source: true,
+            iter: self.graph.node_references(),
+            f: self.nodes,
+        }
+    }
+}
+
+impl<'a, G, S> IntoEdgeReferences for SubgraphView<'a, G, S>
+where
+    G: IntoEdgeReferences,
+    S: FilterNode<G::NodeId>,
+{
+    type EdgeRef = G::EdgeRef;
+    type EdgeReferences = NodeFilteredEdgeReferences<'a, G, G::EdgeReferences, S>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        NodeFilteredEdgeReferences {
+            graph: PhantomData,
+            iter: self.graph.edge_references(),
+            f: self.nodes,
+        }
+    }
+}
+
+impl<'a, G, S> IntoEdges for SubgraphView<'a, G, S>
+where
+    G: IntoEdges,
+    S: FilterNode<G::NodeId>,
+{
+    type Edges = NodeFilteredEdges<'a, G, G::Edges, S>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        NodeFilteredEdges {
+            graph: PhantomData,
+            include_source: self.nodes.include_node(a),
+            iter: self.graph.edges(a),
+            f: self.nodes,
+        }
+    }
+}
+
+impl<'a, G, S> IntoEdgesDirected for SubgraphView<'a, G, S>
+where
+    G: IntoEdgesDirected,
+    S: FilterNode<G::NodeId>,
+{
+    type EdgesDirected = NodeFilteredEdges<'a, G, G::EdgesDirected, S>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        NodeFilteredEdges {
+            graph: PhantomData,
+            include_source: self.nodes.include_node(a),
+            iter: self.graph.edges_directed(a, dir),
+            f: self.nodes,
+        }
+    }
+}
+
+impl<'a, G, S> DataMap for SubgraphView<'a, G, S>
+where
+    G: DataMap,
+    S: FilterNode<G::NodeId>,
+{
+    fn node_weight(&self, id: Self::NodeId) -> Option<&Self::NodeWeight> {
+        if self.nodes.include_node(id) {
+            self.graph.node_weight(id)
+        } else {
+            None
+        }
+    }
+
+    fn edge_weight(&self, id: Self::EdgeId) -> Option<&Self::EdgeWeight> {
+        self.graph.edge_weight(id)
+    }
+}
+
+macro_rules! access_graph {
+    ($e:expr) => {
+        $e.graph
+    };
+}
+
+Data! {delegate_impl [['a, G, S], G, SubgraphView<'a, G, S>, access_graph]}
+NodeIndexable! {delegate_impl [['a, G, S], G, SubgraphView<'a, G, S>, access_graph]}
+EdgeIndexable! {delegate_impl [['a, G, S], G, SubgraphView<'a, G, S>, access_graph]}
+GraphProp! {delegate_impl [['a, G, S], G, SubgraphView<'a, G, S>, access_graph]}
+Visitable! {delegate_impl [['a, G, S], G, SubgraphView<'a, G, S>, access_graph]}
+
 /// A graph filter for edges
 pub trait FilterEdge<Edge> {
     /// Return true to have the edge be part of the graph
@@ -366,6 +637,26 @@ where
 ///
 /// The filter may use edge source, target, id, and weight to select whether to
 /// include the edge or not.
+///
+/// A reference to the adaptor implements `IntoEdges` and `IntoEdgesDirected`,
+/// so that it can be used with the shortest path algorithms.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::dijkstra;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::EdgeFiltered;
+///
+/// // Roads, some of which are closed.
+/// let g = UnGraph::<(), (u32, bool)>::from_edges(&[
+///     (0, 1, (1, true)),
+///     (1, 2, (1, false)),
+///     (0, 2, (5, true)),
+/// ]);
+/// let open = EdgeFiltered::from_fn(&g, |e| e.weight().1);
+/// let dist = dijkstra(&open, 0.into(), None, |e| e.weight().0);
+/// assert_eq!(dist[&2.into()], 5);
+/// ```
 #[derive(Copy, Clone, Debug)]
 pub struct EdgeFiltered<G, F>(pub G, pub F);
 
@@ -563,6 +854,17 @@ where
     }
 }
 
+/// Counts the included edges, walking all the edges of the node.
+impl<G, F> NodeDegrees for EdgeFiltered<G, F>
+where
+    G: IntoEdgesDirected + GraphProp,
+    F: FilterEdge<G::EdgeRef>,
+{
+    fn degree_directed(&self, a: G::NodeId, dir: Direction) -> usize {
+        self.neighbors_directed(a, dir).count()
+    }
+}
+
 Data! {delegate_impl [[G, F], G, EdgeFiltered<G, F>, access0]}
 GraphProp! {delegate_impl [[G, F], G, EdgeFiltered<G, F>, access0]}
 IntoNodeIdentifiers! {delegate_impl [['a, G, F], G, &'a EdgeFiltered<G, F>, access0]}
@@ -4,6 +4,7 @@
 /// There *must* be section markers in the trait definition:
 /// @section type for associated types
 /// @section self for methods
+/// @section default for methods with a default body, forwarded like methods
 /// @section nodelegate for arbitrary tail that is not forwarded.
 macro_rules! trait_template {
     ($(#[$doc:meta])* pub trait $name:ident $($methods:tt)*) => {
@@ -108,6 +109,15 @@ macro_rules! delegate_impl {
             fn $method_name:ident(self $(: $self_selftype:ty)* $(,$marg:ident : $marg_ty:ty)*) $(-> $mret:ty)?;
         )+
         )*
+        // Methods with a default body. Forwarded, like the methods above,
+        // so that an adaptor overriding them is seen through delegation.
+        $(
+        @section default
+        $(
+            $(#[$_dmethod_attr:meta])*
+            fn $dmethod_name:ident(self $(: $dself_selftype:ty)* $(,$dmarg:ident : $dmarg_ty:ty)*) $(-> $dmret:ty)? $_dbody:block
+        )+
+        )*
         // Arbitrary tail that is ignored when forwarding.
         $(
         @section nodelegate
@@ -130,6 +140,13 @@ macro_rules! delegate_impl {
                 }
             )*
             )*
+            $(
+            $(
+                fn $dmethod_name(self $(: $dself_selftype)* $(,$dmarg: $dmarg_ty)*) $(-> $dmret)? {
+                    $self_map!(self).$dmethod_name($($dmarg),*)
+                }
+            )*
+            )*
         }
     }
 }
@@ -0,0 +1,293 @@
+use std::marker::PhantomData;
+
+use crate::Direction;
+
+use crate::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp,
+    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeDegrees,
+    NodeIndexable, NodeRef, Visitable,
+};
+
+/// A graph adaptor that maps the node and edge weights on the fly.
+///
+/// The weights of the adaptor are `node_map(weight)` and `edge_map(weight)`
+/// of the weights of the underlying graph, computed each time a node or edge
+/// reference is created, without copying the graph. The mapped weights are
+/// held by the references, so they must be `Copy`, like costs.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::min_spanning_tree;
+/// use petgraph::data::FromElements;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::MapWeights;
+///
+/// // Find the spanning tree of the greatest weight, by negating the weights.
+/// let g = UnGraph::<(), i32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 0, 3)]);
+/// let negated = MapWeights::new(&g, |_: &()| (), |&w: &i32| -w);
+/// let tree = UnGraph::<(), i32>::from_elements(min_spanning_tree(&negated));
+/// assert_eq!(tree.edge_weights().sum::<i32>(), -5);
+/// ```
+pub struct MapWeights<G, FN, FE, N, E> {
+    graph: G,
+    node_map: FN,
+    edge_map: FE,
+    weights: PhantomData<fn() -> (N, E)>,
+}
+
+impl<G, FN, FE, N, E> MapWeights<G, FN, FE, N, E>
+where
+    G: Data,
+    FN: Fn(&G::NodeWeight) -> N,
+    FE: Fn(&G::EdgeWeight) -> E,
+{
+    /// Create a `MapWeights` adaptor of `graph`, mapping its node weights with
+    /// `node_map` and its edge weights with `edge_map`.
+    pub fn new(graph: G, node_map: FN, edge_map: FE) -> Self {
+        MapWeights {
+            graph,
+            node_map,
+            edge_map,
+            weights: PhantomData,
+        }
+    }
+}
+
+impl<G, FN, FE, N, E> Clone for MapWeights<G, FN, FE, N, E>
+where
+    G: Clone,
+    FN: Clone,
+    FE: Clone,
+{
+    fn clone(&self) -> Self {
+        MapWeights {
+            graph: self.graph.clone(),
+            node_map: self.node_map.clone(),
+            edge_map: self.edge_map.clone(),
+            weights: PhantomData,
+        }
+    }
+}
+
+impl<G, FN, FE, N, E> GraphBase for MapWeights<G, FN, FE, N, E>
+where
+    G: GraphBase,
+{
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G, FN, FE, N, E> Data for MapWeights<G, FN, FE, N, E>
+where
+    G: GraphBase,
+{
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<'a, G, FN, FE, N, E> IntoNodeReferences for &'a MapWeights<G, FN, FE, N, E>
+where
+    G: IntoNodeReferences,
+    FN: Fn(&G::NodeWeight) -> N,
+    N: Copy,
+{
+    type NodeRef = MappedNodeReference<G::NodeRef, N>;
+    type NodeReferences = MapWeightsNodes<'a, G::NodeReferences, FN, N>;
+    fn node_references(self) -> Self::NodeReferences {
+        MapWeightsNodes {
+            iter: self.graph.node_references(),
+            f: &self.node_map,
+            weight: PhantomData,
+        }
+    }
+}
+
+/// A node reference of a [`MapWeights`](struct.MapWeights.html) adaptor,
+/// with its mapped weight.
+#[derive(Copy, Clone, Debug)]
+pub struct MappedNodeReference<R, N> {
+    node: R,
+    weight: N,
+}
+
+impl<R, N> MappedNodeReference<R, N> {
+    /// Return the node reference of the underlying graph.
+    pub fn as_original(&self) -> &R {
+        &self.node
+    }
+}
+
+impl<R, N> NodeRef for MappedNodeReference<R, N>
+where
+    R: NodeRef,
+    N: Copy,
+{
+    type NodeId = R::NodeId;
+    type Weight = N;
+    fn id(&self) -> Self::NodeId {
+        self.node.id()
+    }
+    fn weight(&self) -> &N {
+        &self.weight
+    }
+}
+
+/// An iterator of the node references of a
+/// [`MapWeights`](struct.MapWeights.html) adaptor.
+pub struct MapWeightsNodes<'a, I, FN: 'a, N> {
+    iter: I,
+    f: &'a FN,
+    weight: PhantomData<fn() -> N>,
+}
+
+impl<'a, I, FN, N> Iterator for MapWeightsNodes<'a, I, FN, N>
+where
+    I: Iterator,
+    I::Item: NodeRef,
+    FN: Fn(&<I::Item as NodeRef>::Weight) -> N,
+{
+    type Item = MappedNodeReference<I::Item, N>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = self.f;
+        self.iter.next().map(|node| MappedNodeReference {
+            node,
+            weight: f(node.weight()),
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, G, FN, FE, N, E> IntoEdgeReferences for &'a MapWeights<G, FN, FE, N, E>
+where
+    G: IntoEdgeReferences,
+    FE: Fn(&G::EdgeWeight) -> E,
+    E: Copy,
+{
+    type EdgeRef = MappedEdgeReference<G::EdgeRef, E>;
+    type EdgeReferences = MapWeightsEdges<'a, G::EdgeReferences, FE, E>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        MapWeightsEdges {
+            iter: self.graph.edge_references(),
+            f: &self.edge_map,
+            weight: PhantomData,
+        }
+    }
+}
+
+impl<'a, G, FN, FE, N, E> IntoEdges for &'a MapWeights<G, FN, FE, N, E>
+where
+    G: IntoEdges,
+    FE: Fn(&G::EdgeWeight) -> E,
+    E: Copy,
+{
+    type Edges = MapWeightsEdges<'a, G::Edges, FE, E>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        MapWeightsEdges {
+            iter: self.graph.edges(a),
+            f: &self.edge_map,
+            weight: PhantomData,
+        }
+    }
+}
+
+impl<'a, G, FN, FE, N, E> IntoEdgesDirected for &'a MapWeights<G, FN, FE, N, E>
+where
+    G: IntoEdgesDirected,
+    FE: Fn(&G::EdgeWeight) -> E,
+    E: Copy,
+{
+    type EdgesDirected = MapWeightsEdges<'a, G::EdgesDirected, FE, E>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        MapWeightsEdges {
+            iter: self.graph.edges_directed(a, dir),
+            f: &self.edge_map,
+            weight: PhantomData,
+        }
+    }
+}
+
+/// An edge reference of a [`MapWeights`](struct.MapWeights.html) adaptor,
+/// with its mapped weight.
+#[derive(Copy, Clone, Debug)]
+pub struct MappedEdgeReference<R, E> {
+    edge: R,
+    weight: E,
+}
+
+impl<R, E> MappedEdgeReference<R, E> {
+    /// Return the edge reference of the underlying graph.
+    pub fn as_original(&self) -> &R {
+        &self.edge
+    }
+}
+
+impl<R, E> EdgeRef for MappedEdgeReference<R, E>
+where
+    R: EdgeRef,
+    E: Copy,
+{
+    type NodeId = R::NodeId;
+    type EdgeId = R::EdgeId;
+    type Weight = E;
+    fn source(&self) -> Self::NodeId {
+        self.edge.source()
+    }
+    fn target(&self) -> Self::NodeId {
+        self.edge.target()
+    }
+    fn weight(&self) -> &E {
+        &self.weight
+    }
+    fn id(&self) -> Self::EdgeId {
+        self.edge.id()
+    }
+}
+
+/// An iterator of the edge references of a
+/// [`MapWeights`](struct.MapWeights.html) adaptor.
+pub struct MapWeightsEdges<'a, I, FE: 'a, E> {
+    iter: I,
+    f: &'a FE,
+    weight: PhantomData<fn() -> E>,
+}
+
+impl<'a, I, FE, E> Iterator for MapWeightsEdges<'a, I, FE, E>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    FE: Fn(&<I::Item as EdgeRef>::Weight) -> E,
+{
+    type Item = MappedEdgeReference<I::Item, E>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = self.f;
+        self.iter.next().map(|edge| MappedEdgeReference {
+            edge,
+            weight: f(edge.weight()),
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+macro_rules! access_graph {
+    ($e:expr) => {
+        $e.graph
+    };
+}
+
+IntoNeighbors! {delegate_impl [['a, G, FN, FE, N, E], G, &'a MapWeights<G, FN, FE, N, E>, access_graph]}
+IntoNeighborsDirected! {delegate_impl [['a, G, FN, FE, N, E], G, &'a MapWeights<G, FN, FE, N, E>, access_graph]}
+IntoNodeIdentifiers! {delegate_impl [['a, G, FN, FE, N, E], G, &'a MapWeights<G, FN, FE, N, E>, access_graph]}
+NodeIndexable! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
+NodeCompactIndexable! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
+NodeCount! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
+EdgeCount! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
+NodeDegrees! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
+EdgeIndexable! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
+GraphProp! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
+Visitable! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
+GetAdjacencyMatrix! {delegate_impl [[G, FN, FE, N, E], G, MapWeights<G, FN, FE, N, E>, access_graph]}
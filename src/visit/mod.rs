@@ -48,6 +48,7 @@
 //! | NodeIndexable         | x     |  x          |    x     | x           | x     |  x    |
 //! | NodeCompactIndexable  | x     |             |    x     |             | x     |  x    |
 //! | EdgeCount             | x     |  x          |    x     | x           | x     |  x    |
+//! | NodeDegrees           | x     |  x          |    x     | x           | x     |  x    |
 //! | EdgeIndexable         | x     |  x          |    x     |             |       |       |
 //! | Data                  | x     |  x          |    x     | x           | x     |  x    |
 //! | IntoNodeIdentifiers   | x     |  x          |    x     | x           | x     |  x    |
@@ -60,17 +61,28 @@
 //! | Visitable             | x     |  x          |    x     | x           | x     |  x    |
 //! | GetAdjacencyMatrix    | x     |  x          |    x     | x           | x     |  x    |
 
-// filter, reversed have their `mod` lines at the end,
+// filter, map_weights, reversed, undirected_adaptor, union_view have their `mod` lines at the end,
 // so that they can use the trait template macros
 pub use self::filter::*;
+pub use self::map_weights::*;
 pub use self::reversed::*;
+pub use self::undirected_adaptor::*;
+pub use self::union_view::*;
 
 #[macro_use]
 mod macros;
 
 mod dfsvisit;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "rand")]
+mod random_walk;
 mod traversal;
 pub use self::dfsvisit::*;
+#[cfg(feature = "rayon")]
+pub use self::parallel::*;
+#[cfg(feature = "rand")]
+pub use self::random_walk::*;
 pub use self::traversal::*;
 
 use fixedbitset::FixedBitSet;
@@ -79,6 +91,7 @@ use std::hash::{BuildHasher, Hash};
 
 use super::EdgeType;
 use crate::prelude::Direction;
+use crate::{Incoming, Outgoing};
 
 use crate::graph::IndexType;
 
@@ -320,13 +333,18 @@ IntoEdgeReferences! {delegate_impl [] }
 
 trait_template! {
     /// Edge kind property (directed or undirected edges)
+#[allow(clippy::needless_arbitrary_self_type)]
 pub trait GraphProp : GraphBase {
     @section type
     /// The kind edges in the graph.
     type EdgeType: EdgeType;
 
-    @section nodelegate
-    fn is_directed(&self) -> bool {
+    @section default
+    /// Return `true` if the edges are directed.
+    ///
+    /// By default this is the `EdgeType` of the graph, but adaptors may
+    /// decide it at runtime, like [`RuntimeDirected`](struct.RuntimeDirected.html).
+    fn is_directed(self: &Self) -> bool {
         <Self::EdgeType>::is_directed()
     }
 }
@@ -475,5 +493,49 @@ pub trait EdgeCount : GraphBase {
 
 EdgeCount! {delegate_impl []}
 
+trait_template! {
+/// A graph that can count the edges of its nodes.
+///
+/// The count is **O(1)** where the graph stores the edges of a node together,
+/// and otherwise it walks them.
+#[allow(clippy::needless_arbitrary_self_type)]
+pub trait NodeDegrees : GraphProp {
+    @section self
+    /// Return the number of edges of `a` in direction `dir`, which is the
+    /// number of neighbors listed by `neighbors_directed(a, dir)`.
+    ///
+    /// For an undirected graph, both directions count all the edges of `a`.
+    fn degree_directed(self: &Self, a: Self::NodeId, dir: Direction) -> usize;
+
+    @section nodelegate
+    /// Return the number of outgoing edges of `a`.
+    fn out_degree(&self, a: Self::NodeId) -> usize {
+        self.degree_directed(a, Outgoing)
+    }
+
+    /// Return the number of incoming edges of `a`.
+    fn in_degree(&self, a: Self::NodeId) -> usize {
+        self.degree_directed(a, Incoming)
+    }
+
+    /// Return the number of edges of `a`.
+    ///
+    /// For a directed graph, this is the number of outgoing and incoming edges
+    /// together, so that a self loop counts twice.
+    fn degree(&self, a: Self::NodeId) -> usize {
+        if self.is_directed() {
+            self.out_degree(a) + self.in_degree(a)
+        } else {
+            self.out_degree(a)
+        }
+    }
+}
+}
+
+NodeDegrees! {delegate_impl []}
+
 mod filter;
+mod map_weights;
 mod reversed;
+mod undirected_adaptor;
+mod union_view;
@@ -0,0 +1,127 @@
+//! Parallel iteration over the nodes and edges of a graph, with
+//! [`rayon`](https://crates.io/crates/rayon).
+
+use rayon::iter::plumbing::UnindexedConsumer;
+use rayon::iter::ParallelIterator;
+
+use super::{IntoEdgeReferences, IntoNodeReferences};
+use crate::csr::{self, Csr};
+use crate::graph::{self, Graph, IndexType};
+#[cfg(feature = "stable_graph")]
+use crate::stable_graph::{self, StableGraph};
+use crate::EdgeType;
+
+/// Access to a parallel iterator over the nodes of a graph, like
+/// `IntoNodeReferences`.
+///
+/// Requires crate feature `"rayon"`.
+pub trait IntoParallelNodeReferences: IntoNodeReferences {
+    type ParNodeReferences: ParallelIterator<Item = Self::NodeRef>;
+    /// Return a parallel iterator over the nodes of the graph.
+    fn par_node_references(self) -> Self::ParNodeReferences;
+}
+
+/// Access to a parallel iterator over the edges of a graph, like
+/// `IntoEdgeReferences`.
+///
+/// Requires crate feature `"rayon"`.
+pub trait IntoParallelEdgeReferences: IntoEdgeReferences {
+    type ParEdgeReferences: ParallelIterator<Item = Self::EdgeRef>;
+    /// Return a parallel iterator over the edges of the graph.
+    fn par_edge_references(self) -> Self::ParEdgeReferences;
+}
+
+/// A parallel iterator over the nodes of the graph `G`.
+///
+/// Created with [`IntoParallelNodeReferences::par_node_references`].
+///
+/// [`IntoParallelNodeReferences::par_node_references`]: trait.IntoParallelNodeReferences.html#tymethod.par_node_references
+#[derive(Copy, Clone, Debug)]
+pub struct ParNodeReferences<G> {
+    graph: G,
+}
+
+/// A parallel iterator over the edges of the graph `G`.
+///
+/// Created with [`IntoParallelEdgeReferences::par_edge_references`].
+///
+/// [`IntoParallelEdgeReferences::par_edge_references`]: trait.IntoParallelEdgeReferences.html#tymethod.par_edge_references
+#[derive(Copy, Clone, Debug)]
+pub struct ParEdgeReferences<G> {
+    graph: G,
+}
+
+/// Implement the parallel traits for references to a graph type, with
+/// iterators from its inherent `par_node_references` and
+/// `par_edge_references` methods.
+macro_rules! impl_parallel_references {
+    ($graph:ty, $node_ref:ty, $edge_ref:ty, [$($bounds:tt)*]) => {
+        impl<'a, N, E, Ty, Ix> IntoParallelNodeReferences for &'a $graph
+        where
+            $($bounds)*
+        {
+            type ParNodeReferences = ParNodeReferences<Self>;
+            fn par_node_references(self) -> Self::ParNodeReferences {
+                ParNodeReferences { graph: self }
+            }
+        }
+
+        impl<'a, N, E, Ty, Ix> IntoParallelEdgeReferences for &'a $graph
+        where
+            $($bounds)*
+        {
+            type ParEdgeReferences = ParEdgeReferences<Self>;
+            fn par_edge_references(self) -> Self::ParEdgeReferences {
+                ParEdgeReferences { graph: self }
+            }
+        }
+
+        impl<'a, N, E, Ty, Ix> ParallelIterator for ParNodeReferences<&'a $graph>
+        where
+            $($bounds)*
+        {
+            type Item = $node_ref;
+            fn drive_unindexed<C>(self, consumer: C) -> C::Result
+            where
+                C: UnindexedConsumer<Self::Item>,
+            {
+                <$graph>::par_node_references(self.graph).drive_unindexed(consumer)
+            }
+        }
+
+        impl<'a, N, E, Ty, Ix> ParallelIterator for ParEdgeReferences<&'a $graph>
+        where
+            $($bounds)*
+        {
+            type Item = $edge_ref;
+            fn drive_unindexed<C>(self, consumer: C) -> C::Result
+            where
+                C: UnindexedConsumer<Self::Item>,
+            {
+                <$graph>::par_edge_references(self.graph).drive_unindexed(consumer)
+            }
+        }
+    };
+}
+
+impl_parallel_references!(
+    Graph<N, E, Ty, Ix>,
+    (graph::NodeIndex<Ix>, &'a N),
+    graph::EdgeReference<'a, E, Ix>,
+    [N: Sync, E: Sync, Ty: EdgeType + Sync, Ix: IndexType + Send + Sync]
+);
+
+#[cfg(feature = "stable_graph")]
+impl_parallel_references!(
+    StableGraph<N, E, Ty, Ix>,
+    (graph::NodeIndex<Ix>, &'a N),
+    stable_graph::EdgeReference<'a, E, Ix>,
+    [N: Sync, E: Sync, Ty: EdgeType + Sync, Ix: IndexType + Send + Sync]
+);
+
+impl_parallel_references!(
+    Csr<N, E, Ty, Ix>,
+    (csr::NodeIndex<Ix>, &'a N),
+    csr::EdgeReference<'a, E, Ty, Ix>,
+    [N: Sync, E: Sync, Ty: EdgeType + Send + Sync, Ix: IndexType + Send + Sync]
+);
@@ -0,0 +1,385 @@
+use rand::Rng;
+
+use super::{EdgeRef, IntoEdges, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable, Walker};
+
+/// A random walk on a graph.
+///
+/// The walk starts at a given node and, at each step, moves to a neighbor of
+/// the current node drawn uniformly at random from `rng`, so parallel edges
+/// make a neighbor more likely. With
+/// [`next_weighted`](#method.next_weighted), the neighbor is instead drawn
+/// with probability proportional to the weight of the edge leading to it.
+///
+/// With a restart probability *p*, each step instead goes back to the start
+/// node with probability *p*, like the random surfer of PageRank. When the
+/// walk is at a node without neighbors, it restarts if *p* is positive, and
+/// otherwise ends.
+///
+/// The first node emitted is the start node. Unless it ends, the walk is
+/// infinite, so use it with `Iterator::take` or similar.
+///
+/// Requires crate feature `"rand"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::visit::{RandomWalk, Walker};
+///
+/// let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let walk = RandomWalk::new(0.into(), rand::thread_rng()).with_restart_probability(0.15);
+/// let path = walk.iter(&g).take(100).collect::<Vec<NodeIndex>>();
+/// assert_eq!(path.len(), 100);
+/// assert_eq!(path[0], 0.into());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RandomWalk<N, R> {
+    start: N,
+    current: Option<N>,
+    restart_probability: f64,
+    rng: R,
+}
+
+impl<N, R> RandomWalk<N, R>
+where
+    N: Copy,
+    R: Rng,
+{
+    /// Create a new **RandomWalk** from **start**, drawing its steps from
+    /// `rng`, without restarts.
+    pub fn new(start: N, rng: R) -> Self {
+        RandomWalk {
+            start,
+            current: None,
+            restart_probability: 0.,
+            rng,
+        }
+    }
+
+    /// Set the probability of restarting from the start node at each step.
+    ///
+    /// **Panics** if `probability` is not in the range `[0, 1]`.
+    pub fn with_restart_probability(mut self, probability: f64) -> Self {
+        assert!(
+            (0. ..=1.).contains(&probability),
+            "RandomWalk: restart probability {} is not in [0, 1]",
+            probability
+        );
+        self.restart_probability = probability;
+        self
+    }
+
+    /// Return the node the walk is at, or `None` if it has not started yet.
+    pub fn current(&self) -> Option<N> {
+        self.current
+    }
+
+    /// Return the next node of the walk, stepping to a neighbor drawn
+    /// uniformly at random, or **None** if the walk ended.
+    pub fn next<G>(&mut self, graph: G) -> Option<N>
+    where
+        G: IntoNeighbors<NodeId = N>,
+    {
+        self.step(|current, rng| {
+            let count = graph.neighbors(current).count();
+            if count == 0 {
+                None
+            } else {
+                graph.neighbors(current).nth(rng.gen_range(0, count))
+            }
+        })
+    }
+
+    /// Return the next node of the walk, stepping along an edge drawn with
+    /// probability proportional to `edge_weight`, or **None** if the walk
+    /// ended.
+    ///
+    /// Edges of weight zero are never taken, so a node whose edges all have
+    /// weight zero has no neighbors for the walk. Weights must be
+    /// non-negative.
+    pub fn next_weighted<G, F>(&mut self, graph: G, mut edge_weight: F) -> Option<N>
+    where
+        G: IntoEdges<NodeId = N>,
+        F: FnMut(G::EdgeRef) -> f64,
+    {
+        self.step(|current, rng| {
+            let total: f64 = graph.edges(current).map(&mut edge_weight).sum();
+            if total <= 0. {
+                return None;
+            }
+            let mut x = rng.gen::<f64>() * total;
+            let mut last = None;
+            for edge in graph.edges(current) {
+                let weight = edge_weight(edge);
+                if weight > 0. {
+                    last = Some(edge.target());
+                    if x < weight {
+                        break;
+                    }
+                    x -= weight;
+                }
+            }
+            // rounding can leave `x` past the last edge
+            last
+        })
+    }
+
+    fn step<F>(&mut self, choose: F) -> Option<N>
+    where
+        F: FnOnce(N, &mut R) -> Option<N>,
+    {
+        let next = match self.current {
+            None => Some(self.start),
+            Some(_)
+                if self.restart_probability > 0.
+                    && self.rng.gen::<f64>() < self.restart_probability =>
+            {
+                Some(self.start)
+            }
+            Some(current) => match choose(current, &mut self.rng) {
+                None if self.restart_probability > 0. => Some(self.start),
+                next => next,
+            },
+        };
+        if next.is_some() {
+            self.current = next;
+        }
+        next
+    }
+}
+
+impl<G, R> Walker<G> for RandomWalk<G::NodeId, R>
+where
+    G: IntoNeighbors,
+    R: Rng,
+{
+    type Item = G::NodeId;
+    fn walk_next(&mut self, context: G) -> Option<Self::Item> {
+        self.next(context)
+    }
+}
+
+/// Biased second order random walks, as sampled by node2vec.
+///
+/// A walk that just stepped from node *t* to node *v* steps next to a
+/// neighbor *x* of *v* with probability proportional to the weight of the
+/// edge from *v* to *x*, multiplied by:
+///
+/// - *1 / p* if *x* is *t*, returning to the previous node,
+/// - *1* if *x* is a neighbor of *t*,
+/// - *1 / q* otherwise, moving further away from *t*.
+///
+/// So a low return parameter `p` keeps the walks close to where they
+/// started, while a low in-out parameter `q` makes them explore outwards,
+/// like a depth first search. The first step of a walk is drawn by edge
+/// weight alone. With `p = q = 1`, the walks are plain weighted random walks.
+///
+/// The graph is preprocessed once into alias tables, after which each step
+/// takes constant time. The tables take **O(Σ deg(v)²)** space. Parallel
+/// edges are merged into one edge with the sum of their weights, and edges
+/// of weight zero or less are left out.
+///
+/// Requires crate feature `"rand"`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::visit::BiasedWalks;
+///
+/// let g = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 1.), (2, 0, 2.), (2, 3, 1.)]);
+/// let walks = BiasedWalks::new(&g, 1., 0.5, |e| *e.weight());
+///
+/// // ten walks of length five from each node
+/// let batch = walks.walks(10, 5, &mut rand::thread_rng());
+/// assert_eq!(batch.len(), 40);
+/// assert!(batch.iter().all(|walk| walk.len() == 5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BiasedWalks<G> {
+    graph: G,
+    /// The sorted neighbor indices of each node index.
+    neighbors: Vec<Vec<usize>>,
+    /// The alias table for the first step from each node.
+    first: Vec<AliasTable>,
+    /// The offset of the edges of each node in `next`.
+    offsets: Vec<usize>,
+    /// The alias table for the step after the edge to the `j`th neighbor of
+    /// node `t`, at `offsets[t] + j`.
+    next: Vec<AliasTable>,
+}
+
+impl<G> BiasedWalks<G>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+{
+    /// Preprocess `graph` for walks with return parameter `p` and in-out
+    /// parameter `q`, using the weights given by `edge_weight`.
+    ///
+    /// **Panics** if `p` or `q` is not positive.
+    pub fn new<F>(graph: G, p: f64, q: f64, mut edge_weight: F) -> Self
+    where
+        F: FnMut(G::EdgeRef) -> f64,
+    {
+        assert!(
+            p > 0. && q > 0.,
+            "BiasedWalks: p = {} and q = {} must be positive",
+            p,
+            q
+        );
+        let n = graph.node_bound();
+        let mut adjacency = vec![Vec::new(); n];
+        for a in graph.node_identifiers() {
+            let list = &mut adjacency[graph.to_index(a)];
+            for edge in graph.edges(a) {
+                let weight = edge_weight(edge);
+                if weight > 0. {
+                    list.push((graph.to_index(edge.target()), weight));
+                }
+            }
+            list.sort_by_key(|&(b, _)| b);
+            list.dedup_by(|next, prev| {
+                if next.0 == prev.0 {
+                    prev.1 += next.1;
+                    true
+                } else {
+                    false
+                }
+            });
+        }
+
+        let first = adjacency
+            .iter()
+            .map(|list| AliasTable::new(list.iter().map(|&(_, w)| w).collect()))
+            .collect();
+        let mut offsets = Vec::with_capacity(n);
+        let mut next = Vec::new();
+        for (t, list) in adjacency.iter().enumerate() {
+            offsets.push(next.len());
+            for &(v, _) in list {
+                let weights = adjacency[v]
+                    .iter()
+                    .map(|&(x, w)| {
+                        if x == t {
+                            w / p
+                        } else if list.binary_search_by_key(&x, |&(b, _)| b).is_ok() {
+                            w
+                        } else {
+                            w / q
+                        }
+                    })
+                    .collect();
+                next.push(AliasTable::new(weights));
+            }
+        }
+        let neighbors = adjacency
+            .into_iter()
+            .map(|list| list.into_iter().map(|(b, _)| b).collect())
+            .collect();
+
+        BiasedWalks {
+            graph,
+            neighbors,
+            first,
+            offsets,
+            next,
+        }
+    }
+
+    /// Return a walk from `start` of `length` nodes, including `start`.
+    ///
+    /// The walk is shorter if it reaches a node without neighbors.
+    pub fn walk<R: Rng>(&self, start: G::NodeId, length: usize, rng: &mut R) -> Vec<G::NodeId> {
+        let mut walk = Vec::with_capacity(length);
+        if length == 0 {
+            return walk;
+        }
+        let mut t = self.graph.to_index(start);
+        walk.push(start);
+        let mut j = match self.first[t].sample(rng) {
+            Some(j) => j,
+            None => return walk,
+        };
+        let mut v = self.neighbors[t][j];
+        walk.push(self.graph.from_index(v));
+        while walk.len() < length {
+            let k = match self.next[self.offsets[t] + j].sample(rng) {
+                Some(k) => k,
+                None => break,
+            };
+            t = v;
+            j = k;
+            v = self.neighbors[t][j];
+            walk.push(self.graph.from_index(v));
+        }
+        walk
+    }
+
+    /// Return `walks_per_node` walks of `length` nodes from every node of
+    /// the graph.
+    ///
+    /// The walks are made in rounds of one walk from each node, in an order
+    /// shuffled for every round.
+    pub fn walks<R: Rng>(
+        &self,
+        walks_per_node: usize,
+        length: usize,
+        rng: &mut R,
+    ) -> Vec<Vec<G::NodeId>> {
+        let mut starts = self.graph.node_identifiers().collect::<Vec<_>>();
+        let mut walks = Vec::with_capacity(walks_per_node * starts.len());
+        for _ in 0..walks_per_node {
+            rng.shuffle(&mut starts);
+            for &start in &starts {
+                walks.push(self.walk(start, length, rng));
+            }
+        }
+        walks
+    }
+}
+
+/// An alias table, for drawing from a discrete distribution in constant
+/// time (Vose's method).
+#[derive(Clone, Debug)]
+struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Create the table of the distribution proportional to the positive
+    /// `weights`.
+    fn new(mut weights: Vec<f64>) -> Self {
+        let n = weights.len();
+        let total: f64 = weights.iter().sum();
+        for w in &mut weights {
+            *w *= n as f64 / total;
+        }
+        let mut prob = vec![1.; n];
+        let mut alias = (0..n).collect::<Vec<_>>();
+        let (mut small, mut large): (Vec<_>, Vec<_>) = (0..n).partition(|&i| weights[i] < 1.);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            prob[s] = weights[s];
+            alias[s] = l;
+            weights[l] += weights[s] - 1.;
+            if weights[l] < 1. {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // what remains has probability one, up to rounding
+        AliasTable { prob, alias }
+    }
+
+    /// Draw an index, or return `None` if the table is empty.
+    fn sample<R: Rng>(&self, rng: &mut R) -> Option<usize> {
+        if self.prob.is_empty() {
+            return None;
+        }
+        let i = rng.gen_range(0, self.prob.len());
+        if rng.gen::<f64>() < self.prob[i] {
+            Some(i)
+        } else {
+            Some(self.alias[i])
+        }
+    }
+}
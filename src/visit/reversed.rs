@@ -3,8 +3,8 @@ use crate::{Direction, Incoming};
 use crate::visit::{
     Data, EdgeCount, EdgeIndexable, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp, GraphRef,
     IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
-    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeIndexable,
-    Visitable,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeDegrees,
+    NodeIndexable, Visitable,
 };
 
 /// An edge-reversing graph adaptor.
@@ -66,6 +66,22 @@ where
     }
 }
 
+impl<G: GetAdjacencyMatrix> GetAdjacencyMatrix for Reversed<G> {
+    type AdjMatrix = G::AdjMatrix;
+    fn adjacency_matrix(&self) -> G::AdjMatrix {
+        self.0.adjacency_matrix()
+    }
+    fn is_adjacent(&self, matrix: &G::AdjMatrix, a: G::NodeId, b: G::NodeId) -> bool {
+        self.0.is_adjacent(matrix, b, a)
+    }
+}
+
+impl<G: NodeDegrees> NodeDegrees for Reversed<G> {
+    fn degree_directed(&self, a: G::NodeId, dir: Direction) -> usize {
+        self.0.degree_directed(a, dir.opposite())
+    }
+}
+
 impl<G: Visitable> Visitable for Reversed<G> {
     type Map = G::Map;
     fn visit_map(&self) -> G::Map {
@@ -181,4 +197,3 @@ GraphProp! {delegate_impl [[G], G, Reversed<G>, access0]}
 NodeCount! {delegate_impl [[G], G, Reversed<G>, access0]}
 EdgeCount! {delegate_impl [[G], G, Reversed<G>, access0]}
 EdgeIndexable! {delegate_impl [[G], G, Reversed<G>, access0]}
-GetAdjacencyMatrix! {delegate_impl [[G], G, Reversed<G>, access0]}
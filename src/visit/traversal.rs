@@ -1,7 +1,9 @@
-use super::{GraphRef, IntoNodeIdentifiers, Reversed};
+use super::{EdgeRef, GraphRef, IntoEdges, IntoNodeIdentifiers, Reversed};
 use super::{IntoNeighbors, IntoNeighborsDirected, VisitMap, Visitable};
+use crate::algo::Measure;
+use crate::scored::MinScored;
 use crate::Incoming;
-use std::collections::VecDeque;
+use std::collections::{BinaryHeap, VecDeque};
 
 /// Visit nodes of a graph in a depth-first-search (DFS) emitting nodes in
 /// preorder (when they are first discovered).
@@ -305,11 +307,247 @@ where
     }
 }
 
+/// A depth first search (DFS) that doesn't expand nodes beyond a maximum
+/// depth, emitting nodes in preorder.
+///
+/// The start node has depth 0, and the neighbors of a node at depth *d* that
+/// it discovers have depth *d + 1*. Nodes at `max_depth` are emitted, but
+/// their neighbors are not. Like `Dfs`, a node is only visited once, the
+/// first time it is reached, so a node that is first reached along a path
+/// longer than its distance from the start can leave nodes within
+/// `max_depth` of the start unvisited. Use
+/// [`DepthLimitedBfs`](struct.DepthLimitedBfs.html) to visit exactly the
+/// nodes within a distance.
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::visit::{DepthLimitedDfs, Walker};
+///
+/// let graph = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let dfs = DepthLimitedDfs::new(&graph, 0.into(), 2);
+/// assert_eq!(dfs.iter(&graph).count(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DepthLimitedDfs<N, VM> {
+    /// The stack of nodes to visit, with their depths
+    pub stack: Vec<(N, usize)>,
+    /// The map of discovered nodes
+    pub discovered: VM,
+    /// The depth of the deepest nodes to visit
+    pub max_depth: usize,
+}
+
+impl<N, VM> DepthLimitedDfs<N, VM>
+where
+    N: Copy + PartialEq,
+    VM: VisitMap<N>,
+{
+    /// Create a new **DepthLimitedDfs**, using the graph's visitor map, and
+    /// put **start** in the stack of nodes to visit.
+    pub fn new<G>(graph: G, start: N, max_depth: usize) -> Self
+    where
+        G: GraphRef + Visitable<NodeId = N, Map = VM>,
+    {
+        DepthLimitedDfs {
+            stack: vec![(start, 0)],
+            discovered: graph.visit_map(),
+            max_depth,
+        }
+    }
+
+    /// Return the next node in the dfs, or **None** if the traversal is done.
+    pub fn next<G>(&mut self, graph: G) -> Option<N>
+    where
+        G: IntoNeighbors<NodeId = N>,
+    {
+        while let Some((node, depth)) = self.stack.pop() {
+            if self.discovered.visit(node) {
+                if depth < self.max_depth {
+                    for succ in graph.neighbors(node) {
+                        if !self.discovered.is_visited(&succ) {
+                            self.stack.push((succ, depth + 1));
+                        }
+                    }
+                }
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+/// A breadth first search (BFS) that doesn't expand nodes beyond a maximum
+/// depth.
+///
+/// The start node has depth 0, and the neighbors of a node at depth *d* that
+/// it discovers have depth *d + 1*, so the traversal visits exactly the nodes
+/// at a distance of at most `max_depth` edges from the start.
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::visit::{DepthLimitedBfs, Walker};
+///
+/// let graph = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (0, 3)]);
+/// let bfs = DepthLimitedBfs::new(&graph, 0.into(), 1);
+/// assert_eq!(bfs.iter(&graph).count(), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DepthLimitedBfs<N, VM> {
+    /// The queue of nodes to visit, with their depths
+    pub stack: VecDeque<(N, usize)>,
+    /// The map of discovered nodes
+    pub discovered: VM,
+    /// The depth of the deepest nodes to visit
+    pub max_depth: usize,
+}
+
+impl<N, VM> DepthLimitedBfs<N, VM>
+where
+    N: Copy + PartialEq,
+    VM: VisitMap<N>,
+{
+    /// Create a new **DepthLimitedBfs**, using the graph's visitor map, and
+    /// put **start** in the stack of nodes to visit.
+    pub fn new<G>(graph: G, start: N, max_depth: usize) -> Self
+    where
+        G: GraphRef + Visitable<NodeId = N, Map = VM>,
+    {
+        let mut discovered = graph.visit_map();
+        discovered.visit(start);
+        let mut stack = VecDeque::new();
+        stack.push_front((start, 0));
+        DepthLimitedBfs {
+            stack,
+            discovered,
+            max_depth,
+        }
+    }
+
+    /// Return the next node in the bfs, or **None** if the traversal is done.
+    pub fn next<G>(&mut self, graph: G) -> Option<N>
+    where
+        G: IntoNeighbors<NodeId = N>,
+    {
+        if let Some((node, depth)) = self.stack.pop_front() {
+            if depth < self.max_depth {
+                for succ in graph.neighbors(node) {
+                    if self.discovered.visit(succ) {
+                        self.stack.push_back((succ, depth + 1));
+                    }
+                }
+            }
+
+            return Some(node);
+        }
+        None
+    }
+}
+
+/// A priority first traversal, visiting nodes in the order of their least
+/// accumulated priority from the start, like uniform cost search.
+///
+/// The start node has priority `K::default()`, and stepping along an edge
+/// adds the priority given by the edge priority function to the priority of
+/// its source. Each node is emitted once, together with its least priority,
+/// so with non-negative edge priorities the nodes come in the order of
+/// Dijkstra's algorithm. Ties are emitted in an unspecified order.
+///
+/// `PriorityWalker` does not itself borrow the graph, but it owns the edge
+/// priority function.
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::visit::{EdgeRef, PriorityWalker, Walker};
+///
+/// let graph = Graph::<(), u32>::from_edges(&[(0, 1, 5), (0, 2, 1), (2, 1, 1)]);
+/// let walker = PriorityWalker::new(&graph, 0.into(), |e| *e.weight());
+/// let order = walker.iter(&graph).collect::<Vec<_>>();
+/// assert_eq!(order, vec![(0.into(), 0), (2.into(), 1), (1.into(), 2)]);
+/// ```
+///
+/// **Note:** The algorithm may not behave correctly if nodes are removed
+/// during iteration. It may not necessarily visit added nodes or edges.
+#[derive(Clone)]
+pub struct PriorityWalker<N, VM, K, F> {
+    /// The heap of nodes to visit, with their priorities
+    pub stack: BinaryHeap<MinScored<K, N>>,
+    /// The map of visited nodes
+    pub discovered: VM,
+    /// The edge priority function
+    pub edge_priority: F,
+}
+
+impl<N, VM, K, F> PriorityWalker<N, VM, K, F>
+where
+    N: Copy + PartialEq,
+    VM: VisitMap<N>,
+    K: Measure + Copy,
+{
+    /// Create a new **PriorityWalker**, using the graph's visitor map, and
+    /// put **start** in the heap of nodes to visit.
+    pub fn new<G>(graph: G, start: N, edge_priority: F) -> Self
+    where
+        G: IntoEdges + Visitable<NodeId = N, Map = VM>,
+        F: FnMut(G::EdgeRef) -> K,
+    {
+        let mut stack = BinaryHeap::new();
+        stack.push(MinScored(K::default(), start));
+        PriorityWalker {
+            stack,
+            discovered: graph.visit_map(),
+            edge_priority,
+        }
+    }
+
+    /// Keep the visited map, but clear the heap and restart the traversal
+    /// from a particular node.
+    pub fn move_to(&mut self, start: N) {
+        self.stack.clear();
+        self.stack.push(MinScored(K::default(), start));
+    }
+
+    /// Return the next node in the traversal with its priority, or **None**
+    /// if the traversal is done.
+    pub fn next<G>(&mut self, graph: G) -> Option<(N, K)>
+    where
+        G: IntoEdges<NodeId = N>,
+        F: FnMut(G::EdgeRef) -> K,
+    {
+        while let Some(MinScored(priority, node)) = self.stack.pop() {
+            if self.discovered.visit(node) {
+                for edge in graph.edges(node) {
+                    let succ = edge.target();
+                    if !self.discovered.is_visited(&succ) {
+                        let succ_priority = priority + (self.edge_priority)(edge);
+                        self.stack.push(MinScored(succ_priority, succ));
+                    }
+                }
+                return Some((node, priority));
+            }
+        }
+        None
+    }
+}
+
 /// A topological order traversal for a graph.
 ///
 /// **Note** that `Topo` only visits nodes that are not part of cycles,
 /// i.e. nodes in a true DAG. Use other visitors like `DfsPostOrder` or
 /// algorithms like kosaraju_scc to handle graphs with possible cycles.
+/// Use [`finish`](#method.finish) after the traversal to find out whether
+/// it stopped short because of a cycle.
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::visit::Topo;
+///
+/// let graph = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 1), (2, 3)]);
+/// let mut topo = Topo::new(&graph);
+/// while let Some(nx) = topo.next(&graph) {
+///     assert_eq!(nx, 0.into());
+/// }
+/// assert_eq!(topo.finish(&graph), Err(vec![1.into(), 2.into(), 3.into()]));
+/// ```
 #[derive(Clone)]
 pub struct Topo<N, VM> {
     tovisit: Vec<N>,
@@ -407,6 +645,28 @@ where
         }
         None
     }
+
+    /// Check that the traversal emitted every node of the graph.
+    ///
+    /// Call it once `next` returned `None`. Return `Ok(())` if every node was
+    /// emitted, and otherwise the nodes that were not, in the order of
+    /// `node_identifiers`: these are the nodes on cycles and the nodes
+    /// reachable from them. Nodes still waiting to be emitted, if the
+    /// traversal was stopped early, are counted as not emitted.
+    pub fn finish<G>(&self, graph: G) -> Result<(), Vec<N>>
+    where
+        G: IntoNodeIdentifiers<NodeId = N>,
+    {
+        let remaining = graph
+            .node_identifiers()
+            .filter(|n| !self.ordered.is_visited(n))
+            .collect::<Vec<_>>();
+        if remaining.is_empty() {
+            Ok(())
+        } else {
+            Err(remaining)
+        }
+    }
 }
 
 /// A walker is a traversal state, but where part of the traversal
@@ -432,6 +692,73 @@ pub trait Walker<Context> {
     }
 }
 
+/// A walker that stops after a maximum number of items.
+///
+/// For the graph traversals, this is a budget of visited nodes: the
+/// traversal does no more work once it is used up.
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::visit::{Bfs, Walker, WalkerLimit};
+///
+/// let graph = Graph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let mut bfs = WalkerLimit::new(Bfs::new(&graph, 0.into()), 2);
+/// assert!(bfs.walk_next(&graph).is_some());
+/// assert!(bfs.walk_next(&graph).is_some());
+/// assert_eq!(bfs.walk_next(&graph), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WalkerLimit<W> {
+    walker: W,
+    remaining: usize,
+}
+
+impl<W> WalkerLimit<W> {
+    /// Create a walker that stops after at most `max_items` items of
+    /// `walker`.
+    pub fn new(walker: W, max_items: usize) -> Self {
+        WalkerLimit {
+            walker,
+            remaining: max_items,
+        }
+    }
+
+    /// Return the number of items the walker may still produce.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    pub fn inner_ref(&self) -> &W {
+        &self.walker
+    }
+
+    pub fn inner_mut(&mut self) -> &mut W {
+        &mut self.walker
+    }
+
+    /// Consume `self` and return the underlying walker.
+    pub fn into_inner(self) -> W {
+        self.walker
+    }
+}
+
+impl<W, C> Walker<C> for WalkerLimit<W>
+where
+    W: Walker<C>,
+{
+    type Item = W::Item;
+    fn walk_next(&mut self, context: C) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = self.walker.walk_next(context);
+        if item.is_some() {
+            self.remaining -= 1;
+        }
+        item
+    }
+}
+
 /// A walker and its context wrapped into an iterator.
 #[derive(Clone, Debug)]
 pub struct WalkerIter<W, C> {
@@ -517,3 +844,35 @@ where
         self.next(context)
     }
 }
+
+impl<G> Walker<G> for DepthLimitedDfs<G::NodeId, G::Map>
+where
+    G: IntoNeighbors + Visitable,
+{
+    type Item = G::NodeId;
+    fn walk_next(&mut self, context: G) -> Option<Self::Item> {
+        self.next(context)
+    }
+}
+
+impl<G> Walker<G> for DepthLimitedBfs<G::NodeId, G::Map>
+where
+    G: IntoNeighbors + Visitable,
+{
+    type Item = G::NodeId;
+    fn walk_next(&mut self, context: G) -> Option<Self::Item> {
+        self.next(context)
+    }
+}
+
+impl<G, K, F> Walker<G> for PriorityWalker<G::NodeId, G::Map, K, F>
+where
+    G: IntoEdges + Visitable,
+    K: Measure + Copy,
+    F: FnMut(G::EdgeRef) -> K,
+{
+    type Item = (G::NodeId, K);
+    fn walk_next(&mut self, context: G) -> Option<Self::Item> {
+        self.next(context)
+    }
+}
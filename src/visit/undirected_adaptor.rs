@@ -0,0 +1,506 @@
+use crate::{Direction, Incoming, Outgoing, Undirected};
+
+use crate::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, GetAdjacencyMatrix, GraphBase, GraphProp, GraphRef,
+    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected,
+    IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeDegrees,
+    NodeIndexable, Visitable,
+};
+
+/// An adaptor that views a directed graph as undirected.
+///
+/// The neighbors of a node are the endpoints of all its edges, in either
+/// direction, and the edges of a node all have it as their source, like in
+/// an undirected graph; self loops are only listed once. The edges keep the
+/// identifiers of the underlying graph.
+///
+/// Adapting a graph that is already undirected changes nothing.
+///
+/// # Example
+/// ```rust
+/// use petgraph::prelude::*;
+/// use petgraph::visit::{UndirectedAdaptor, Walker};
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 1)]);
+/// // 2 can't be reached from 0 along the edges, but it can without their
+/// // directions.
+/// assert_eq!(Dfs::new(&g, 0.into()).iter(&g).count(), 2);
+/// assert_eq!(Dfs::new(&g, 0.into()).iter(UndirectedAdaptor(&g)).count(), 3);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct UndirectedAdaptor<G>(pub G);
+
+impl<G: GraphBase> GraphBase for UndirectedAdaptor<G> {
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: GraphRef> GraphRef for UndirectedAdaptor<G> {}
+
+Data! {delegate_impl [[G], G, UndirectedAdaptor<G>, access0]}
+
+impl<G: GraphBase> GraphProp for UndirectedAdaptor<G> {
+    type EdgeType = Undirected;
+}
+
+impl<G> IntoNeighbors for UndirectedAdaptor<G>
+where
+    G: IntoNeighborsDirected + GraphProp,
+{
+    type Neighbors = UndirectedNeighbors<G>;
+    fn neighbors(self, a: G::NodeId) -> Self::Neighbors {
+        UndirectedNeighbors {
+            start: a,
+            outgoing: self.0.neighbors_directed(a, Outgoing),
+            incoming: self.0.neighbors_directed(a, Incoming),
+            both: self.0.is_directed(),
+        }
+    }
+}
+
+impl<G> IntoNeighborsDirected for UndirectedAdaptor<G>
+where
+    G: IntoNeighborsDirected + GraphProp,
+{
+    type NeighborsDirected = UndirectedNeighbors<G>;
+    fn neighbors_directed(self, a: G::NodeId, _d: Direction) -> Self::NeighborsDirected {
+        self.neighbors(a)
+    }
+}
+
+/// The neighbors of a node in an [`UndirectedAdaptor`](struct.UndirectedAdaptor.html).
+pub struct UndirectedNeighbors<G>
+where
+    G: IntoNeighborsDirected,
+{
+    start: G::NodeId,
+    outgoing: G::NeighborsDirected,
+    incoming: G::NeighborsDirected,
+    both: bool,
+}
+
+impl<G> Iterator for UndirectedNeighbors<G>
+where
+    G: IntoNeighborsDirected,
+{
+    type Item = G::NodeId;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(b) = self.outgoing.next() {
+            return Some(b);
+        }
+        if self.both {
+            // self loops were already listed among the outgoing edges
+            let start = self.start;
+            return self.incoming.find(|&b| b != start);
+        }
+        None
+    }
+}
+
+impl<G> Clone for UndirectedNeighbors<G>
+where
+    G: IntoNeighborsDirected,
+    G::NeighborsDirected: Clone,
+{
+    fn clone(&self) -> Self {
+        UndirectedNeighbors {
+            start: self.start,
+            outgoing: self.outgoing.clone(),
+            incoming: self.incoming.clone(),
+            both: self.both,
+        }
+    }
+}
+
+impl<G> IntoEdgeReferences for UndirectedAdaptor<G>
+where
+    G: IntoEdgeReferences,
+{
+    type EdgeRef = UndirectedEdgeReference<G::EdgeRef>;
+    type EdgeReferences = UndirectedEdgeReferences<G::EdgeReferences>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        UndirectedEdgeReferences {
+            iter: self.0.edge_references(),
+        }
+    }
+}
+
+impl<G> IntoEdges for UndirectedAdaptor<G>
+where
+    G: IntoEdgesDirected + GraphProp,
+{
+    type Edges = UndirectedEdges<G>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        self.edges_directed(a, Outgoing)
+    }
+}
+
+impl<G> IntoEdgesDirected for UndirectedAdaptor<G>
+where
+    G: IntoEdgesDirected + GraphProp,
+{
+    type EdgesDirected = UndirectedEdges<G>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        UndirectedEdges {
+            forward: self.0.edges_directed(a, dir),
+            backward: self.0.edges_directed(a, dir.opposite()),
+            both: self.0.is_directed(),
+        }
+    }
+}
+
+/// The edges of a node in an [`UndirectedAdaptor`](struct.UndirectedAdaptor.html).
+pub struct UndirectedEdges<G>
+where
+    G: IntoEdgesDirected,
+{
+    forward: G::EdgesDirected,
+    backward: G::EdgesDirected,
+    both: bool,
+}
+
+impl<G> Iterator for UndirectedEdges<G>
+where
+    G: IntoEdgesDirected,
+{
+    type Item = UndirectedEdgeReference<G::EdgeRef>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(edge) = self.forward.next() {
+            return Some(UndirectedEdgeReference {
+                edge,
+                reversed: false,
+            });
+        }
+        if self.both {
+            // self loops were already listed in the other direction
+            return self
+                .backward
+                .find(|edge| edge.source() != edge.target())
+                .map(|edge| UndirectedEdgeReference {
+                    edge,
+                    reversed: true,
+                });
+        }
+        None
+    }
+}
+
+impl<G> Clone for UndirectedEdges<G>
+where
+    G: IntoEdgesDirected,
+    G::EdgesDirected: Clone,
+{
+    fn clone(&self) -> Self {
+        UndirectedEdges {
+            forward: self.forward.clone(),
+            backward: self.backward.clone(),
+            both: self.both,
+        }
+    }
+}
+
+/// An edge reference of an [`UndirectedAdaptor`](struct.UndirectedAdaptor.html),
+/// possibly reversed from the underlying graph.
+#[derive(Copy, Clone, Debug)]
+pub struct UndirectedEdgeReference<R> {
+    edge: R,
+    reversed: bool,
+}
+
+impl<R> UndirectedEdgeReference<R> {
+    /// Return `true` if the edge is reversed from the underlying graph.
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Return the edge reference of the underlying graph.
+    pub fn as_original(&self) -> &R {
+        &self.edge
+    }
+
+    /// Consume `self` and return the edge reference of the underlying graph.
+    pub fn into_original(self) -> R {
+        self.edge
+    }
+}
+
+impl<R> EdgeRef for UndirectedEdgeReference<R>
+where
+    R: EdgeRef,
+{
+    type NodeId = R::NodeId;
+    type EdgeId = R::EdgeId;
+    type Weight = R::Weight;
+    fn source(&self) -> Self::NodeId {
+        if self.reversed {
+            self.edge.target()
+        } else {
+            self.edge.source()
+        }
+    }
+    fn target(&self) -> Self::NodeId {
+        if self.reversed {
+            self.edge.source()
+        } else {
+            self.edge.target()
+        }
+    }
+    fn weight(&self) -> &Self::Weight {
+        self.edge.weight()
+    }
+    fn id(&self) -> Self::EdgeId {
+        self.edge.id()
+    }
+}
+
+/// The edge references of an [`UndirectedAdaptor`](struct.UndirectedAdaptor.html).
+#[derive(Debug, Clone)]
+pub struct UndirectedEdgeReferences<I> {
+    iter: I,
+}
+
+impl<I> Iterator for UndirectedEdgeReferences<I>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+{
+    type Item = UndirectedEdgeReference<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|edge| UndirectedEdgeReference {
+            edge,
+            reversed: false,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Counts the neighbors, walking all the edges of the node.
+impl<G> NodeDegrees for UndirectedAdaptor<G>
+where
+    G: IntoNeighborsDirected + GraphProp,
+{
+    fn degree_directed(&self, a: G::NodeId, _dir: Direction) -> usize {
+        self.neighbors(a).count()
+    }
+}
+
+impl<G: Visitable> Visitable for UndirectedAdaptor<G> {
+    type Map = G::Map;
+    fn visit_map(&self) -> G::Map {
+        self.0.visit_map()
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        self.0.reset_map(map);
+    }
+}
+
+impl<G: GetAdjacencyMatrix> GetAdjacencyMatrix for UndirectedAdaptor<G> {
+    type AdjMatrix = G::AdjMatrix;
+    fn adjacency_matrix(&self) -> G::AdjMatrix {
+        self.0.adjacency_matrix()
+    }
+    fn is_adjacent(&self, matrix: &G::AdjMatrix, a: G::NodeId, b: G::NodeId) -> bool {
+        self.0.is_adjacent(matrix, a, b) || self.0.is_adjacent(matrix, b, a)
+    }
+}
+
+/// An adaptor that views a graph as directed or undirected, decided at runtime.
+///
+/// With `directed` set, the adaptor is like the underlying graph; without it,
+/// it is like an [`UndirectedAdaptor`](struct.UndirectedAdaptor.html) of it.
+/// [`is_directed`](trait.GraphProp.html#method.is_directed) reports the
+/// choice, and algorithms that check it at runtime follow it, so a graph
+/// whose directedness is only known when it is loaded needs only one code
+/// path. The `EdgeType` of the adaptor is the one of the underlying graph.
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::connected_components;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::{GraphProp, RuntimeDirected, Walker};
+///
+/// let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 1)]);
+/// for &directed in &[true, false] {
+///     let view = RuntimeDirected::new(&g, directed);
+///     assert_eq!(view.is_directed(), directed);
+///     let reachable = Dfs::new(view, 0.into()).iter(view).count();
+///     assert_eq!(reachable, if directed { 2 } else { 3 });
+/// }
+/// assert_eq!(connected_components(RuntimeDirected::new(&g, false)), 1);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct RuntimeDirected<G> {
+    graph: G,
+    directed: bool,
+}
+
+impl<G> RuntimeDirected<G> {
+    /// Create a `RuntimeDirected` adaptor of `graph`, directed if `directed`
+    /// is `true` and undirected otherwise.
+    pub fn new(graph: G, directed: bool) -> Self {
+        RuntimeDirected { graph, directed }
+    }
+
+    /// Return the underlying graph.
+    pub fn into_inner(self) -> G {
+        self.graph
+    }
+}
+
+impl<G: GraphBase> GraphBase for RuntimeDirected<G> {
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: GraphRef> GraphRef for RuntimeDirected<G> {}
+
+Data! {delegate_impl [[G], G, RuntimeDirected<G>, access_graph]}
+
+impl<G: GraphProp> GraphProp for RuntimeDirected<G> {
+    type EdgeType = G::EdgeType;
+    fn is_directed(&self) -> bool {
+        self.directed && self.graph.is_directed()
+    }
+}
+
+impl<G> RuntimeDirected<G>
+where
+    G: GraphProp,
+{
+    /// Return `true` if edges must also be followed backwards, because the
+    /// underlying graph is directed but the adaptor is not.
+    fn both_directions(&self) -> bool {
+        !self.directed && self.graph.is_directed()
+    }
+}
+
+impl<G> IntoNeighbors for RuntimeDirected<G>
+where
+    G: IntoNeighborsDirected + GraphProp,
+{
+    type Neighbors = UndirectedNeighbors<G>;
+    fn neighbors(self, a: G::NodeId) -> Self::Neighbors {
+        self.neighbors_directed(a, Outgoing)
+    }
+}
+
+impl<G> IntoNeighborsDirected for RuntimeDirected<G>
+where
+    G: IntoNeighborsDirected + GraphProp,
+{
+    type NeighborsDirected = UndirectedNeighbors<G>;
+    fn neighbors_directed(self, a: G::NodeId, d: Direction) -> Self::NeighborsDirected {
+        UndirectedNeighbors {
+            start: a,
+            outgoing: self.graph.neighbors_directed(a, d),
+            incoming: self.graph.neighbors_directed(a, d.opposite()),
+            both: self.both_directions(),
+        }
+    }
+}
+
+impl<G> IntoEdgeReferences for RuntimeDirected<G>
+where
+    G: IntoEdgeReferences,
+{
+    type EdgeRef = UndirectedEdgeReference<G::EdgeRef>;
+    type EdgeReferences = UndirectedEdgeReferences<G::EdgeReferences>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        UndirectedEdgeReferences {
+            iter: self.graph.edge_references(),
+        }
+    }
+}
+
+impl<G> IntoEdges for RuntimeDirected<G>
+where
+    G: IntoEdgesDirected + GraphProp,
+{
+    type Edges = UndirectedEdges<G>;
+    fn edges(self, a: Self::NodeId) -> Self::Edges {
+        self.edges_directed(a, Outgoing)
+    }
+}
+
+impl<G> IntoEdgesDirected for RuntimeDirected<G>
+where
+    G: IntoEdgesDirected + GraphProp,
+{
+    type EdgesDirected = UndirectedEdges<G>;
+    fn edges_directed(self, a: Self::NodeId, dir: Direction) -> Self::EdgesDirected {
+        UndirectedEdges {
+            forward: self.graph.edges_directed(a, dir),
+            backward: self.graph.edges_directed(a, dir.opposite()),
+            both: self.both_directions(),
+        }
+    }
+}
+
+/// Counts in the time of the underlying graph if it is undirected or the
+/// adaptor is directed, and otherwise walks all the edges of the node.
+impl<G> NodeDegrees for RuntimeDirected<G>
+where
+    G: IntoNeighborsDirected + NodeDegrees,
+{
+    fn degree_directed(&self, a: G::NodeId, dir: Direction) -> usize {
+        if self.both_directions() {
+            self.neighbors_directed(a, dir).count()
+        } else {
+            self.graph.degree_directed(a, dir)
+        }
+    }
+}
+
+impl<G: Visitable> Visitable for RuntimeDirected<G> {
+    type Map = G::Map;
+    fn visit_map(&self) -> G::Map {
+        self.graph.visit_map()
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        self.graph.reset_map(map);
+    }
+}
+
+impl<G> GetAdjacencyMatrix for RuntimeDirected<G>
+where
+    G: GetAdjacencyMatrix + GraphProp,
+{
+    type AdjMatrix = G::AdjMatrix;
+    fn adjacency_matrix(&self) -> G::AdjMatrix {
+        self.graph.adjacency_matrix()
+    }
+    fn is_adjacent(&self, matrix: &G::AdjMatrix, a: G::NodeId, b: G::NodeId) -> bool {
+        self.graph.is_adjacent(matrix, a, b)
+            || (self.both_directions() && self.graph.is_adjacent(matrix, b, a))
+    }
+}
+
+macro_rules! access0 {
+    ($e:expr) => {
+        $e.0
+    };
+}
+
+macro_rules! access_graph {
+    ($e:expr) => {
+        $e.graph
+    };
+}
+
+NodeIndexable! {delegate_impl [[G], G, UndirectedAdaptor<G>, access0]}
+NodeCompactIndexable! {delegate_impl [[G], G, UndirectedAdaptor<G>, access0]}
+IntoNodeIdentifiers! {delegate_impl [[G], G, UndirectedAdaptor<G>, access0]}
+IntoNodeReferences! {delegate_impl [[G], G, UndirectedAdaptor<G>, access0]}
+NodeCount! {delegate_impl [[G], G, UndirectedAdaptor<G>, access0]}
+EdgeCount! {delegate_impl [[G], G, UndirectedAdaptor<G>, access0]}
+EdgeIndexable! {delegate_impl [[G], G, UndirectedAdaptor<G>, access0]}
+
+NodeIndexable! {delegate_impl [[G], G, RuntimeDirected<G>, access_graph]}
+NodeCompactIndexable! {delegate_impl [[G], G, RuntimeDirected<G>, access_graph]}
+IntoNodeIdentifiers! {delegate_impl [[G], G, RuntimeDirected<G>, access_graph]}
+IntoNodeReferences! {delegate_impl [[G], G, RuntimeDirected<G>, access_graph]}
+NodeCount! {delegate_impl [[G], G, RuntimeDirected<G>, access_graph]}
+EdgeCount! {delegate_impl [[G], G, RuntimeDirected<G>, access_graph]}
+EdgeIndexable! {delegate_impl [[G], G, RuntimeDirected<G>, access_graph]}
@@ -0,0 +1,312 @@
+use std::iter::Chain;
+
+use crate::Direction;
+
+use crate::visit::{
+    Data, EdgeCount, EdgeRef, GraphBase, GraphProp, GraphRef, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeCompactIndexable, NodeCount, NodeDegrees, NodeIndexable, Visitable,
+};
+
+/// A graph adaptor that overlays the edges of a second graph onto a first
+/// one, without copying either.
+///
+/// The nodes are those of the first graph, and the edges are those of both
+/// graphs, so the edges of the second graph must join nodes of the first one,
+/// with the same node identifiers. The edges are told apart by
+/// [`UnionEdgeId`](enum.UnionEdgeId.html).
+///
+/// # Example
+/// ```rust
+/// use petgraph::algo::has_path_connecting;
+/// use petgraph::prelude::*;
+/// use petgraph::visit::UnionView;
+///
+/// let base = DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 3)]);
+/// // tentative edges between the nodes of `base`
+/// let overlay = DiGraph::<(), ()>::from_edges(&[(1, 2)]);
+///
+/// let a = NodeIndex::new(0);
+/// let b = NodeIndex::new(3);
+/// assert!(!has_path_connecting(&base, a, b, None));
+/// assert!(has_path_connecting(UnionView(&base, &overlay), a, b, None));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct UnionView<G1, G2>(pub G1, pub G2);
+
+/// The identifier of an edge of a [`UnionView`](struct.UnionView.html): an
+/// edge of either its first or its second graph.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnionEdgeId<E1, E2> {
+    /// An edge of the first graph.
+    First(E1),
+    /// An edge of the second graph.
+    Second(E2),
+}
+
+impl<G1, G2> GraphBase for UnionView<G1, G2>
+where
+    G1: GraphBase,
+    G2: GraphBase<NodeId = G1::NodeId>,
+{
+    type NodeId = G1::NodeId;
+    type EdgeId = UnionEdgeId<G1::EdgeId, G2::EdgeId>;
+}
+
+impl<G1, G2> GraphRef for UnionView<G1, G2>
+where
+    G1: GraphRef,
+    G2: GraphRef<NodeId = G1::NodeId>,
+{
+}
+
+impl<G1, G2> Data for UnionView<G1, G2>
+where
+    G1: Data,
+    G2: Data<NodeId = G1::NodeId, EdgeWeight = G1::EdgeWeight>,
+{
+    type NodeWeight = G1::NodeWeight;
+    type EdgeWeight = G1::EdgeWeight;
+}
+
+impl<G1, G2> GraphProp for UnionView<G1, G2>
+where
+    G1: GraphProp,
+    G2: GraphProp<NodeId = G1::NodeId, EdgeType = G1::EdgeType>,
+{
+    type EdgeType = G1::EdgeType;
+    fn is_directed(&self) -> bool {
+        self.0.is_directed()
+    }
+}
+
+impl<G1, G2> IntoNeighbors for UnionView<G1, G2>
+where
+    G1: IntoNeighbors,
+    G2: IntoNeighbors<NodeId = G1::NodeId>,
+{
+    type Neighbors = Chain<G1::Neighbors, G2::Neighbors>;
+    fn neighbors(self, a: G1::NodeId) -> Self::Neighbors {
+        self.0.neighbors(a).chain(self.1.neighbors(a))
+    }
+}
+
+impl<G1, G2> IntoNeighborsDirected for UnionView<G1, G2>
+where
+    G1: IntoNeighborsDirected,
+    G2: IntoNeighborsDirected<NodeId = G1::NodeId>,
+{
+    type NeighborsDirected = Chain<G1::NeighborsDirected, G2::NeighborsDirected>;
+    fn neighbors_directed(self, a: G1::NodeId, d: Direction) -> Self::NeighborsDirected {
+        self.0
+            .neighbors_directed(a, d)
+            .chain(self.1.neighbors_directed(a, d))
+    }
+}
+
+impl<G1, G2> IntoEdgeReferences for UnionView<G1, G2>
+where
+    G1: IntoEdgeReferences,
+    G2: IntoEdgeReferences<NodeId = G1::NodeId, EdgeWeight = G1::EdgeWeight>,
+{
+    type EdgeRef = UnionEdgeReference<G1::EdgeRef, G2::EdgeRef>;
+    type EdgeReferences = UnionEdges<G1::EdgeReferences, G2::EdgeReferences>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        UnionEdges {
+            first: self.0.edge_references(),
+            second: self.1.edge_references(),
+        }
+    }
+}
+
+impl<G1, G2> IntoEdges for UnionView<G1, G2>
+where
+    G1: IntoEdges,
+    G2: IntoEdges<NodeId = G1::NodeId, EdgeWeight = G1::EdgeWeight>,
+{
+    type Edges = UnionEdges<G1::Edges, G2::Edges>;
+    fn edges(self, a: G1::NodeId) -> Self::Edges {
+        UnionEdges {
+            first: self.0.edges(a),
+            second: self.1.edges(a),
+        }
+    }
+}
+
+impl<G1, G2> IntoEdgesDirected for UnionView<G1, G2>
+where
+    G1: IntoEdgesDirected,
+    G2: IntoEdgesDirected<NodeId = G1::NodeId, EdgeWeight = G1::EdgeWeight>,
+{
+    type EdgesDirected = UnionEdges<G1::EdgesDirected, G2::EdgesDirected>;
+    fn edges_directed(self, a: G1::NodeId, dir: Direction) -> Self::EdgesDirected {
+        UnionEdges {
+            first: self.0.edges_directed(a, dir),
+            second: self.1.edges_directed(a, dir),
+        }
+    }
+}
+
+/// An edge reference of a [`UnionView`](struct.UnionView.html).
+#[derive(Copy, Clone, Debug)]
+pub enum UnionEdgeReference<R1, R2> {
+    /// An edge of the first graph.
+    First(R1),
+    /// An edge of the second graph.
+    Second(R2),
+}
+
+impl<R1, R2> EdgeRef for UnionEdgeReference<R1, R2>
+where
+    R1: EdgeRef,
+    R2: EdgeRef<NodeId = R1::NodeId, Weight = R1::Weight>,
+{
+    type NodeId = R1::NodeId;
+    type EdgeId = UnionEdgeId<R1::EdgeId, R2::EdgeId>;
+    type Weight = R1::Weight;
+    fn source(&self) -> Self::NodeId {
+        match self {
+            UnionEdgeReference::First(edge) => edge.source(),
+            UnionEdgeReference::Second(edge) => edge.source(),
+        }
+    }
+    fn target(&self) -> Self::NodeId {
+        match self {
+            UnionEdgeReference::First(edge) => edge.target(),
+            UnionEdgeReference::Second(edge) => edge.target(),
+        }
+    }
+    fn weight(&self) -> &Self::Weight {
+        match self {
+            UnionEdgeReference::First(edge) => edge.weight(),
+            UnionEdgeReference::Second(edge) => edge.weight(),
+        }
+    }
+    fn id(&self) -> Self::EdgeId {
+        match self {
+            UnionEdgeReference::First(edge) => UnionEdgeId::First(edge.id()),
+            UnionEdgeReference::Second(edge) => UnionEdgeId::Second(edge.id()),
+        }
+    }
+}
+
+/// An iterator of the edges of a [`UnionView`](struct.UnionView.html): the
+/// edges of its first graph, then those of its second graph.
+#[derive(Debug, Clone)]
+pub struct UnionEdges<I1, I2> {
+    first: I1,
+    second: I2,
+}
+
+impl<I1, I2> Iterator for UnionEdges<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator,
+{
+    type Item = UnionEdgeReference<I1::Item, I2::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.first.next() {
+            Some(edge) => Some(UnionEdgeReference::First(edge)),
+            None => self.second.next().map(UnionEdgeReference::Second),
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low1, high1) = self.first.size_hint();
+        let (low2, high2) = self.second.size_hint();
+        let high = match (high1, high2) {
+            (Some(h1), Some(h2)) => h1.checked_add(h2),
+            _ => None,
+        };
+        (low1.saturating_add(low2), high)
+    }
+}
+
+impl<G1, G2> EdgeCount for UnionView<G1, G2>
+where
+    G1: EdgeCount,
+    G2: EdgeCount<NodeId = G1::NodeId>,
+{
+    fn edge_count(&self) -> usize {
+        self.0.edge_count() + self.1.edge_count()
+    }
+}
+
+impl<G1, G2> NodeDegrees for UnionView<G1, G2>
+where
+    G1: NodeDegrees,
+    G2: NodeDegrees<NodeId = G1::NodeId, EdgeType = G1::EdgeType>,
+{
+    fn degree_directed(&self, a: G1::NodeId, dir: Direction) -> usize {
+        self.0.degree_directed(a, dir) + self.1.degree_directed(a, dir)
+    }
+}
+
+impl<G1, G2> IntoNodeIdentifiers for UnionView<G1, G2>
+where
+    G1: IntoNodeIdentifiers,
+    G2: GraphRef<NodeId = G1::NodeId>,
+{
+    type NodeIdentifiers = G1::NodeIdentifiers;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.0.node_identifiers()
+    }
+}
+
+impl<G1, G2> IntoNodeReferences for UnionView<G1, G2>
+where
+    G1: IntoNodeReferences,
+    G2: GraphRef + Data<NodeId = G1::NodeId, EdgeWeight = G1::EdgeWeight>,
+{
+    type NodeRef = G1::NodeRef;
+    type NodeReferences = G1::NodeReferences;
+    fn node_references(self) -> Self::NodeReferences {
+        self.0.node_references()
+    }
+}
+
+impl<G1, G2> NodeCount for UnionView<G1, G2>
+where
+    G1: NodeCount,
+    G2: GraphBase<NodeId = G1::NodeId>,
+{
+    fn node_count(&self) -> usize {
+        self.0.node_count()
+    }
+}
+
+impl<G1, G2> NodeIndexable for UnionView<G1, G2>
+where
+    G1: NodeIndexable,
+    G2: GraphBase<NodeId = G1::NodeId>,
+{
+    fn node_bound(&self) -> usize {
+        self.0.node_bound()
+    }
+    fn to_index(&self, a: G1::NodeId) -> usize {
+        self.0.to_index(a)
+    }
+    fn from_index(&self, i: usize) -> G1::NodeId {
+        self.0.from_index(i)
+    }
+}
+
+impl<G1, G2> NodeCompactIndexable for UnionView<G1, G2>
+where
+    G1: NodeCompactIndexable,
+    G2: GraphBase<NodeId = G1::NodeId>,
+{
+}
+
+impl<G1, G2> Visitable for UnionView<G1, G2>
+where
+    G1: Visitable,
+    G2: GraphBase<NodeId = G1::NodeId>,
+{
+    type Map = G1::Map;
+    fn visit_map(&self) -> G1::Map {
+        self.0.visit_map()
+    }
+    fn reset_map(&self, map: &mut Self::Map) {
+        self.0.reset_map(map);
+    }
+}
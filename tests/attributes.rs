@@ -0,0 +1,97 @@
+extern crate petgraph;
+
+use petgraph::attributes::{EdgeMap, NodeMap};
+use petgraph::graph::{EdgeIndex, Graph, NodeIndex};
+use petgraph::observed::ObservedGraph;
+
+#[test]
+fn dense() {
+    let mut g = Graph::<u32, u32>::new();
+    let a = g.add_node(0);
+    let b = g.add_node(1);
+    let c = g.add_node(2);
+    let ab = g.add_edge(a, b, 10);
+    let bc = g.add_edge(b, c, 11);
+    let mut nodes = NodeMap::from_fn(&g, |a| g[a] * 2);
+    let mut edges = EdgeMap::from_elem(&g, 'x');
+    assert_eq!(nodes.as_slice(), &[0, 2, 4]);
+    assert_eq!(edges.len(), 2);
+
+    assert_eq!(nodes.push(6), g.add_node(3));
+    assert_eq!(edges.push('y'), g.add_edge(c, a, 12));
+    g.remove_edge(ab);
+    edges.swap_remove(ab);
+    assert_eq!((edges[ab], g[ab]), ('y', 12));
+    g.remove_node(b);
+    nodes.swap_remove(b);
+    edges.swap_remove(bc);
+    assert_eq!(edges.len(), g.edge_count());
+    for (a, &x) in &nodes {
+        assert_eq!(x, g[a] * 2);
+    }
+    assert_eq!(nodes.get(NodeIndex::new(3)), None);
+    assert!(nodes.iter().rev().map(|(a, _)| a.index()).eq(vec![2, 1, 0]));
+    assert_eq!(nodes.into_vec(), vec![0, 6, 4]);
+}
+
+#[test]
+fn observed() {
+    let mut g = ObservedGraph::<u32, u32, (NodeMap<u32>, EdgeMap<u32>)>::default();
+    let mut nodes = Vec::new();
+    for i in 0..10 {
+        let a = g.add_node(i);
+        g.observer_mut().0[a] = i * 2;
+        nodes.push(a);
+    }
+    for i in 0..20 {
+        let e = g.add_edge(nodes[i % 10], nodes[i * 3 % 10], i as u32);
+        g.observer_mut().1[e] = i as u32 * 3;
+    }
+    g.remove_node(NodeIndex::new(3));
+    g.remove_node(NodeIndex::new(0));
+    g.remove_edge(EdgeIndex::new(4));
+    let (node_map, edge_map) = g.observer();
+    assert_eq!(node_map.len(), g.graph().node_count());
+    assert_eq!(edge_map.len(), g.graph().edge_count());
+    for (a, &x) in node_map {
+        assert_eq!(x, g.graph()[a] * 2);
+    }
+    for (e, &x) in edge_map {
+        assert_eq!(x, g.graph()[e] * 3);
+    }
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn sparse() {
+    use petgraph::attributes::{SparseEdgeMap, SparseNodeMap};
+    use petgraph::stable_graph::StableGraph;
+
+    let mut g = StableGraph::<u32, u32>::new();
+    let a = g.add_node(0);
+    let b = g.add_node(1);
+    let c = g.add_node(2);
+    let ab = g.add_edge(a, b, 10);
+    let bc = g.add_edge(b, c, 11);
+    g.remove_node(a);
+    let mut nodes = SparseNodeMap::from_fn(&g, |a| g[a]);
+    let mut edges: SparseEdgeMap<_> = vec![(bc, "bc")].into_iter().collect();
+    assert_eq!(nodes.len(), 2);
+    assert!(!nodes.contains_key(a));
+    assert!(nodes.iter().eq(vec![(b, &1), (c, &2)]));
+    assert!(nodes.iter().rev().eq(vec![(c, &2), (b, &1)]));
+    assert_eq!(edges.get(ab), None);
+    assert_eq!(edges[bc], "bc");
+
+    assert_eq!(nodes.insert(NodeIndex::new(6), 6), None);
+    assert_eq!(nodes.insert(b, 3), Some(1));
+    assert_eq!(nodes.len(), 3);
+    g.remove_node(c);
+    nodes.retain(|a, _| g.contains_node(a));
+    edges.retain(|e, _| g.edge_weight(e).is_some());
+    assert!(nodes.iter().eq(vec![(b, &3)]));
+    assert!(edges.is_empty());
+    assert_eq!(nodes.remove(b), Some(3));
+    assert_eq!(nodes.remove(b), None);
+    assert!(nodes.is_empty());
+}
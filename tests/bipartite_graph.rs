@@ -0,0 +1,149 @@
+extern crate petgraph;
+
+use petgraph::algo::{bipartite_partition, maximum_matching};
+use petgraph::bipartite_graph::{BipartiteGraph, LeftIndex, Partition, RightIndex};
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::EdgeRef;
+
+fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+    v.sort();
+    v
+}
+
+fn graph(left: usize, right: usize, edges: &[(usize, usize)]) -> BipartiteGraph<usize, char, u32> {
+    let mut g = BipartiteGraph::new();
+    for i in 0..left {
+        g.add_left(i);
+    }
+    for j in 0..right {
+        g.add_right((b'a' + j as u8) as char);
+    }
+    for (w, &(a, b)) in edges.iter().enumerate() {
+        g.add_edge(LeftIndex::new(a), RightIndex::new(b), w as u32);
+    }
+    g
+}
+
+/// Check that the edges of `matching` are disjoint, and return its size.
+fn check_matching(g: &BipartiteGraph<usize, char, u32>, matching: &[EdgeIndex]) -> usize {
+    let mut left = vec![false; g.left_count()];
+    let mut right = vec![false; g.right_count()];
+    for &e in matching {
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        assert!(!left[a.index()] && !right[b.index()]);
+        left[a.index()] = true;
+        right[b.index()] = true;
+    }
+    matching.len()
+}
+
+#[test]
+fn edges() {
+    let g = graph(2, 3, &[(0, 0), (0, 2), (1, 2), (0, 2)]);
+    let (l, r) = (LeftIndex::new, RightIndex::new);
+    assert_eq!(g.left_count(), 2);
+    assert_eq!(g.right_count(), 3);
+    assert_eq!(g.edge_count(), 4);
+    assert_eq!(g[l(1)], 1);
+    assert_eq!(g[r(2)], 'c');
+    assert_eq!(g[EdgeIndex::new(3)], 3);
+    assert_eq!(g.edge_endpoints(EdgeIndex::new(1)), Some((l(0), r(2))));
+    assert_eq!(g.find_edge(l(0), r(2)), Some(EdgeIndex::new(1)));
+    assert_eq!(g.find_edge(l(1), r(0)), None);
+    assert_eq!(
+        g.left_neighbors(l(0)).collect::<Vec<_>>(),
+        vec![r(0), r(2), r(2)]
+    );
+    assert_eq!(
+        g.right_neighbors(r(2)).collect::<Vec<_>>(),
+        vec![l(0), l(1), l(0)]
+    );
+    assert_eq!(g.right_edges(r(1)), &[]);
+}
+
+#[test]
+#[should_panic]
+fn add_edge_missing_node() {
+    let mut g = graph(2, 1, &[]);
+    g.add_edge(LeftIndex::new(0), RightIndex::new(1), 0);
+}
+
+#[test]
+fn matching() {
+    // a perfect matching only if 0 takes its last choice
+    let g = graph(
+        4,
+        4,
+        &[(0, 0), (0, 1), (0, 3), (1, 0), (2, 1), (2, 2), (3, 2)],
+    );
+    let matching = g.maximum_matching();
+    assert_eq!(check_matching(&g, &matching), 4);
+    assert_eq!(
+        g.edge_endpoints(matching[0]),
+        Some((LeftIndex::new(0), RightIndex::new(3)))
+    );
+
+    let empty = graph(3, 0, &[]);
+    assert!(empty.maximum_matching().is_empty());
+}
+
+#[test]
+fn matching_size_agrees() {
+    let mut seed = 7u32;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as usize
+    };
+    for _ in 0..50 {
+        let (left, right) = (1 + next() % 12, 1 + next() % 12);
+        let edges: Vec<_> = (0..next() % 30)
+            .map(|_| (next() % left, next() % right))
+            .collect();
+        let g = graph(left, right, &edges);
+        let size = check_matching(&g, &g.maximum_matching());
+        let general = g.to_graph();
+        assert_eq!(size, maximum_matching(&general).len());
+    }
+}
+
+#[test]
+fn projections() {
+    // people 0, 1, 2 and the events a, b they attended
+    let g = graph(3, 2, &[(0, 0), (1, 0), (1, 1), (2, 1), (0, 1)]);
+    let shared = g.project_left(0, |w, _, _| *w += 1);
+    assert_eq!(shared.node_count(), 3);
+    assert_eq!(shared[NodeIndex::new(2)], 2);
+    let edges: Vec<_> = shared
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect();
+    assert_eq!(edges, vec![(0, 1, 2), (0, 2, 1), (1, 2, 1)]);
+
+    // the pairs of edge weights, from the earlier node first
+    let pairs = g.project_right(vec![], |w, &w1, &w2| w.push((w1, w2)));
+    assert_eq!(pairs[NodeIndex::new(1)], 'b');
+    let e = pairs
+        .find_edge(NodeIndex::new(0), NodeIndex::new(1))
+        .unwrap();
+    assert_eq!(pairs[e], vec![(0, 4), (1, 2)]);
+}
+
+#[test]
+fn to_graph() {
+    let g = graph(2, 2, &[(0, 1), (1, 1), (1, 0)]);
+    let u = g.to_graph();
+    assert_eq!(u.node_count(), 4);
+    assert_eq!(u[NodeIndex::new(1)], Partition::Left(1));
+    assert_eq!(u[NodeIndex::new(3)], Partition::Right('b'));
+    assert_eq!(
+        u.edge_endpoints(EdgeIndex::new(2)),
+        Some((NodeIndex::new(1), NodeIndex::new(2)))
+    );
+    let (left, right) = bipartite_partition(&u).unwrap();
+    assert_eq!(
+        sorted(left.into_iter().chain(right).collect()),
+        (0..4).map(NodeIndex::new).collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,110 @@
+extern crate petgraph;
+
+use petgraph::builder::{BuildError, DiGraphBuilder, EdgePolicy, UnGraphBuilder};
+use petgraph::graph::{GraphError, NodeIndex};
+use petgraph::visit::EdgeRef;
+
+fn edges<K: Copy, Ty: petgraph::EdgeType>(g: &petgraph::Graph<K, u32, Ty>) -> Vec<(K, K, u32)> {
+    g.edge_references()
+        .map(|e| (g[e.source()], g[e.target()], *e.weight()))
+        .collect()
+}
+
+fn sample<Ty: petgraph::EdgeType>(
+    mut builder: petgraph::builder::GraphBuilder<char, u32, Ty>,
+) -> petgraph::builder::GraphBuilder<char, u32, Ty> {
+    assert!(builder.add_node('x'));
+    builder.add_edge('a', 'b', 1);
+    builder.add_edge('b', 'a', 2);
+    builder.add_edge('a', 'a', 3);
+    builder.add_edge('a', 'b', 4);
+    builder.add_edge('b', 'c', 5);
+    assert!(!builder.add_node('a'));
+    builder
+}
+
+#[test]
+fn nodes() {
+    let mut builder = sample(DiGraphBuilder::new());
+    assert_eq!((builder.node_count(), builder.edge_count()), (4, 5));
+    assert!(builder.contains_node(&'c'));
+    assert!(!builder.contains_node(&'d'));
+    builder.add_node('d');
+    let (g, nodes) = builder.build().unwrap();
+    assert!(g.node_weights().eq(&['x', 'a', 'b', 'c', 'd']));
+    assert_eq!(nodes.len(), 5);
+    for (&key, &a) in &nodes {
+        assert_eq!(g[a], key);
+    }
+    assert_eq!(
+        edges(&g),
+        vec![
+            ('a', 'b', 1),
+            ('b', 'a', 2),
+            ('a', 'a', 3),
+            ('a', 'b', 4),
+            ('b', 'c', 5)
+        ]
+    );
+}
+
+#[test]
+fn policies() {
+    let (g, _) = sample(DiGraphBuilder::new())
+        .self_loops(EdgePolicy::Ignore)
+        .parallel_edges(EdgePolicy::Ignore)
+        .build()
+        .unwrap();
+    assert_eq!(edges(&g), vec![('a', 'b', 1), ('b', 'a', 2), ('b', 'c', 5)]);
+
+    let (g, _) = sample(UnGraphBuilder::new())
+        .parallel_edges(EdgePolicy::Ignore)
+        .build()
+        .unwrap();
+    assert_eq!(edges(&g), vec![('a', 'b', 1), ('a', 'a', 3), ('b', 'c', 5)]);
+
+    let (g, _) = sample(UnGraphBuilder::new())
+        .merge_parallel_edges(|w, x| *w = *w * 10 + x)
+        .build()
+        .unwrap();
+    assert_eq!(
+        edges(&g),
+        vec![('a', 'b', 124), ('a', 'a', 3), ('b', 'c', 5)]
+    );
+
+    let err = sample(DiGraphBuilder::new())
+        .self_loops(EdgePolicy::Reject)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::SelfLoop('a'));
+    let err = sample(DiGraphBuilder::new())
+        .parallel_edges(EdgePolicy::Reject)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::ParallelEdges('a', 'b'));
+    let err = sample(UnGraphBuilder::new())
+        .parallel_edges(EdgePolicy::Reject)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::ParallelEdges('b', 'a'));
+}
+
+#[test]
+fn index_limit() {
+    let mut builder = DiGraphBuilder::<u32, (), u8>::new();
+    for i in 0..255 {
+        builder.add_node(i);
+    }
+    let (g, nodes) = builder.build().unwrap();
+    assert_eq!(nodes[&254], NodeIndex::new(254));
+    assert_eq!(g.node_count(), 255);
+
+    let mut builder = DiGraphBuilder::<u32, (), u8>::new();
+    for i in 0..256 {
+        builder.add_node(i);
+    }
+    assert_eq!(
+        builder.build().unwrap_err(),
+        BuildError::Graph(GraphError::NodeIxLimit)
+    );
+}
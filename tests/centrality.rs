@@ -0,0 +1,63 @@
+extern crate petgraph;
+
+use petgraph::algo::{
+    betweenness_centrality, closeness_centrality, page_rank, weighted_betweenness_centrality, Error,
+};
+use petgraph::prelude::*;
+
+#[cfg(feature = "graphmap")]
+#[test]
+fn graphmap() {
+    use petgraph::visit::NodeIndexable;
+
+    // A path a - b - c with a pendant d on b.
+    let g = UnGraphMap::<char, f64>::from_edges(&[('a', 'b', 1.), ('b', 'c', 1.), ('b', 'd', 3.)]);
+    let b = g.to_index('b');
+
+    let betweenness = betweenness_centrality(&g);
+    assert_eq!(betweenness[b], 3.);
+    assert_eq!(betweenness.iter().sum::<f64>(), 3.);
+    assert_eq!(
+        weighted_betweenness_centrality(&g, |(_, _, &w)| w),
+        betweenness
+    );
+
+    let closeness = closeness_centrality(&g);
+    assert_eq!(closeness[b], 1.);
+    assert_eq!(closeness[g.to_index('a')], 3. / 5.);
+
+    let ranks = page_rank(&g, 0.85, 1e-12, 1000).unwrap();
+    assert!(ranks.iter().all(|&r| r <= ranks[b]));
+}
+
+#[test]
+fn weighted_paths() {
+    // Two shortest paths from a to d, of cost 2, and a longer direct edge.
+    let g = DiGraph::<(), f64>::from_edges(&[
+        (0, 1, 1.),
+        (1, 3, 1.),
+        (0, 2, 1.5),
+        (2, 3, 0.5),
+        (0, 3, 3.),
+    ]);
+    let betweenness = weighted_betweenness_centrality(&g, |e| *e.weight());
+    assert_eq!(betweenness, [0., 0.5, 0.5, 0.]);
+    assert_eq!(betweenness_centrality(&g), [0., 0., 0., 0.]);
+}
+
+#[test]
+fn empty() {
+    let g = DiGraph::<(), ()>::new();
+    assert!(betweenness_centrality(&g).is_empty());
+    assert!(closeness_centrality(&g).is_empty());
+    assert_eq!(page_rank(&g, 0.85, 1e-9, 10), Ok(vec![]));
+}
+
+#[test]
+fn page_rank_unconverged() {
+    // The ranks of a directed cycle with a tail converge slowly.
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+    assert_eq!(page_rank(&g, 0.85, 1e-12, 5), Err(Error::Unconverged));
+    assert_eq!(page_rank(&g, 0.85, 1e-12, 0), Err(Error::Unconverged));
+    assert!(page_rank(&g, 0.85, 1e-12, 1000).is_ok());
+}
@@ -0,0 +1,153 @@
+extern crate petgraph;
+
+use std::sync::Arc;
+use std::thread;
+
+use petgraph::algo::{dijkstra, kosaraju_scc};
+use petgraph::concurrent_graph::{ConcurrentGraph, DiConcurrentGraph, UnConcurrentGraph};
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+
+fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+    v.sort();
+    v
+}
+
+#[test]
+fn directed() {
+    let g = DiConcurrentGraph::<&str, u32>::with_shards(3);
+    let n: Vec<_> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|&w| g.add_node(w))
+        .collect();
+    g.add_edge(n[0], n[1], 1);
+    g.add_edge(n[1], n[2], 2);
+    g.add_edge(n[2], n[0], 3);
+    g.add_edge(n[2], n[2], 4);
+    assert_eq!(g.add_edges(vec![(n[3], n[0], 5), (n[0], n[1], 6)]), 2);
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 6);
+    assert_eq!(g.node_weight(n[3]), Some("d"));
+    assert_eq!(g.set_node_weight(n[3], "e"), Some("d"));
+    assert!(g.contains_edge(n[3], n[0]));
+    assert!(!g.contains_edge(n[0], n[3]));
+    assert_eq!(g.neighbors(n[0]), vec![n[1], n[1]]);
+    assert_eq!(g.neighbors_directed(n[0], Incoming), vec![n[2], n[3]]);
+
+    let s = g.snapshot();
+    assert_eq!(s.node_count(), 4);
+    assert_eq!(s.edge_count(), 6);
+    assert_eq!(s.node_weight(n[3]), Some(&"e"));
+    assert_eq!(s.edge_weight(n[2], n[2]), Some(&4));
+    let incoming: Vec<_> = s
+        .edges_directed(n[0], Incoming)
+        .map(|e| (e.source(), e.target(), *e.weight()))
+        .collect();
+    assert_eq!(incoming, vec![(n[2], n[0], 3), (n[3], n[0], 5)]);
+    let edges: Vec<_> = s.edge_references().map(|e| *e.weight()).collect();
+    assert_eq!(sorted(edges), vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(kosaraju_scc(&s).len(), 2);
+}
+
+#[test]
+fn undirected() {
+    let g = UnConcurrentGraph::<(), u32>::with_shards(2);
+    let n: Vec<_> = (0..3).map(|_| g.add_node(())).collect();
+    g.add_edges(vec![(n[0], n[1], 1), (n[2], n[1], 2), (n[1], n[1], 3)]);
+    assert_eq!(g.neighbors(n[1]), vec![n[1], n[0], n[2]]);
+    assert_eq!(g.neighbors_directed(n[1], Incoming), vec![n[1], n[0], n[2]]);
+    assert!(g.contains_edge(n[1], n[2]));
+
+    let s = g.snapshot();
+    let edges: Vec<_> = s
+        .edges_directed(n[1], Incoming)
+        .map(|e| (e.source(), e.target(), *e.weight()))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![(n[1], n[1], 3), (n[0], n[1], 1), (n[2], n[1], 2)]
+    );
+    assert_eq!(s.edge_references().count(), 3);
+    let distances = dijkstra(&s, n[0], None, |e| *e.weight());
+    assert_eq!(distances[&n[2]], 3);
+}
+
+#[test]
+#[should_panic]
+fn add_edge_missing_node() {
+    let g = ConcurrentGraph::<(), ()>::with_shards(4);
+    let a = g.add_node(());
+    g.add_edge(a, NodeIndex::new(1), ());
+}
+
+#[test]
+fn missing_node_adds_nothing() {
+    let g = Arc::new(DiConcurrentGraph::<(), ()>::new());
+    let a = g.add_node(());
+    let h = {
+        let g = g.clone();
+        thread::spawn(move || g.add_edges(vec![(a, a, ()), (a, NodeIndex::new(100), ())]))
+    };
+    assert!(h.join().is_err());
+    // the shards are still usable after the panic
+    assert_eq!(g.edge_count(), 0);
+    g.add_edge(a, a, ());
+    assert_eq!(g.snapshot().edge_count(), 1);
+}
+
+#[test]
+fn threads() {
+    let threads = 8;
+    let per_thread = 200;
+    let g = Arc::new(DiConcurrentGraph::<usize, usize>::new());
+    let root = g.add_node(std::usize::MAX);
+
+    let writers: Vec<_> = (0..threads)
+        .map(|t| {
+            let g = g.clone();
+            thread::spawn(move || {
+                let mut previous = root;
+                let mut batch = Vec::new();
+                for i in 0..per_thread {
+                    let a = g.add_node(t * per_thread + i);
+                    batch.push((previous, a, 1));
+                    if batch.len() == 10 {
+                        g.add_edges(batch.drain(..));
+                    }
+                    previous = a;
+                }
+                g.add_edges(batch);
+            })
+        })
+        .collect();
+    let reader = {
+        let g = g.clone();
+        thread::spawn(move || {
+            for _ in 0..20 {
+                let s = g.snapshot();
+                // every edge of a snapshot is complete on both ends
+                let incoming: usize = s
+                    .node_indices()
+                    .map(|a| s.edges_directed(a, Incoming).count())
+                    .sum();
+                assert_eq!(incoming, s.edge_count());
+            }
+        })
+    };
+    for w in writers {
+        w.join().unwrap();
+    }
+    reader.join().unwrap();
+
+    let s = g.snapshot();
+    assert_eq!(s.node_count(), threads * per_thread + 1);
+    assert_eq!(s.node_bound(), s.node_count());
+    assert_eq!(s.edge_count(), threads * per_thread);
+    // each thread built a path from the root
+    let distances = dijkstra(&s, root, None, |e| *e.weight());
+    assert_eq!(distances.len(), s.node_count());
+    assert_eq!(distances.values().max(), Some(&per_thread));
+    for a in s.node_indices() {
+        assert_eq!(s.from_index(s.to_index(a)), a);
+    }
+}
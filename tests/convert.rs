@@ -0,0 +1,222 @@
+#![cfg(all(
+    feature = "graphmap",
+    feature = "matrix_graph",
+    feature = "stable_graph"
+))]
+
+extern crate petgraph;
+
+use std::convert::TryFrom;
+
+use petgraph::adj::List;
+use petgraph::convert::ConversionError;
+use petgraph::csr::Csr;
+use petgraph::graphmap::{DiGraphMap, UnGraphMap};
+use petgraph::matrix_graph::{DiMatrix, MatrixGraph, UnMatrix};
+use petgraph::prelude::*;
+use petgraph::visit::{
+    EdgeRef, IntoEdgeReferences, IntoNodeReferences, NodeCount, NodeIndexable, NodeRef,
+};
+use petgraph::EdgeType;
+
+fn edges<G>(g: G) -> Vec<(usize, usize, u32)>
+where
+    G: IntoEdgeReferences<EdgeWeight = u32> + NodeIndexable,
+{
+    let mut edges = g
+        .edge_references()
+        .map(|e| (g.to_index(e.source()), g.to_index(e.target()), *e.weight()))
+        .collect::<Vec<_>>();
+    edges.sort_by_key(|&(_, _, w)| w);
+    edges
+}
+
+fn graph_edges<N, Ty: EdgeType>(g: &Graph<N, u32, Ty>) -> Vec<(usize, usize, u32)> {
+    g.edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect()
+}
+
+fn sample<Ty: EdgeType>() -> Graph<char, u32, Ty> {
+    let mut g = Graph::default();
+    let a = g.add_node('a');
+    let b = g.add_node('b');
+    let c = g.add_node('c');
+    g.add_node('d');
+    g.add_edge(a, b, 1);
+    g.add_edge(b, c, 2);
+    g.add_edge(c, c, 3);
+    g.add_edge(c, a, 4);
+    g
+}
+
+fn sorted(mut edges: Vec<(usize, usize, u32)>, directed: bool) -> Vec<(usize, usize, u32)> {
+    if !directed {
+        for e in &mut edges {
+            if e.1 < e.0 {
+                *e = (e.1, e.0, e.2);
+            }
+        }
+    }
+    edges.sort_by_key(|&(_, _, w)| w);
+    edges
+}
+
+fn round_trips<Ty: EdgeType>() {
+    let g = sample::<Ty>();
+    let expected = sorted(graph_edges(&g), Ty::is_directed());
+    let weights = g.node_weights().cloned().collect::<Vec<_>>();
+    let check = |h: Graph<char, u32, Ty>| {
+        assert_eq!(h.node_weights().cloned().collect::<Vec<_>>(), weights);
+        assert_eq!(sorted(graph_edges(&h), Ty::is_directed()), expected);
+    };
+
+    check(Graph::from(StableGraph::from(g.clone())));
+    let map = GraphMap::<char, u32, Ty>::try_from(g.clone()).unwrap();
+    check(Graph::from(map));
+    let matrix = MatrixGraph::<char, u32, Ty, Option<u32>, u32>::try_from(g.clone()).unwrap();
+    check(Graph::from(matrix));
+    let csr = Csr::<char, u32, Ty, u32>::try_from(g.clone()).unwrap();
+    assert_eq!(csr.edge_count(), 4);
+    check(Graph::from(csr));
+
+    // between the other graph types
+    let csr =
+        Csr::<char, u32, Ty, u32>::from(GraphMap::<char, u32, Ty>::try_from(g.clone()).unwrap());
+    let matrix = MatrixGraph::<char, u32, Ty, Option<u32>, u32>::from(csr);
+    let stable = StableGraph::<char, u32, Ty, u32>::from(matrix);
+    let map = GraphMap::<char, u32, Ty>::try_from(stable).unwrap();
+    let matrix = MatrixGraph::<char, u32, Ty, Option<u32>, u32>::from(map);
+    let csr = Csr::<char, u32, Ty, u32>::from(matrix);
+    let stable = StableGraph::<char, u32, Ty, u32>::from(csr);
+    let matrix = MatrixGraph::<char, u32, Ty, Option<u32>, u32>::try_from(stable).unwrap();
+    check(Graph::from(matrix));
+}
+
+#[test]
+fn round_trip_directed() {
+    round_trips::<Directed>();
+}
+
+#[test]
+fn round_trip_undirected() {
+    round_trips::<Undirected>();
+}
+
+#[test]
+fn parallel_edges() {
+    let mut g = sample::<Directed>();
+    g.add_edge(NodeIndex::new(0), NodeIndex::new(1), 5);
+    assert_eq!(
+        DiGraphMap::<char, u32>::try_from(g.clone()).err(),
+        Some(ConversionError::ParallelEdges)
+    );
+    assert_eq!(
+        DiMatrix::<char, u32, Option<u32>, u32>::try_from(g.clone()).err(),
+        Some(ConversionError::ParallelEdges)
+    );
+    assert_eq!(
+        Csr::<char, u32>::try_from(g.clone()).err(),
+        Some(ConversionError::ParallelEdges)
+    );
+    let stable = StableGraph::from(g.clone());
+    assert_eq!(
+        DiMatrix::<char, u32, Option<u32>, u32>::try_from(stable).err(),
+        Some(ConversionError::ParallelEdges)
+    );
+    let list = List::<u32>::from(g);
+    assert_eq!(
+        Csr::<(), u32>::try_from(list).err(),
+        Some(ConversionError::ParallelEdges)
+    );
+
+    // in an undirected graph, edges in opposite directions are parallel
+    let mut g = sample::<Undirected>();
+    g.add_edge(NodeIndex::new(1), NodeIndex::new(0), 5);
+    assert_eq!(
+        UnGraphMap::<char, u32>::try_from(g.clone()).err(),
+        Some(ConversionError::ParallelEdges)
+    );
+    assert_eq!(
+        UnMatrix::<char, u32, Option<u32>, u32>::try_from(g.clone()).err(),
+        Some(ConversionError::ParallelEdges)
+    );
+    assert_eq!(
+        Csr::<char, u32, Undirected>::try_from(g).err(),
+        Some(ConversionError::ParallelEdges)
+    );
+}
+
+#[test]
+fn duplicate_nodes() {
+    let mut g = sample::<Directed>();
+    g.add_node('a');
+    assert_eq!(
+        DiGraphMap::<char, u32>::try_from(g.clone()).err(),
+        Some(ConversionError::DuplicateNodes)
+    );
+    let csr = Csr::<char, u32>::try_from(g).unwrap();
+    assert_eq!(
+        DiGraphMap::<char, u32>::try_from(csr).err(),
+        Some(ConversionError::DuplicateNodes)
+    );
+}
+
+#[test]
+fn vacant_nodes() {
+    let mut stable = StableGraph::from(sample::<Directed>());
+    stable.remove_node(NodeIndex::new(1));
+    let matrix = DiMatrix::<char, u32, Option<u32>, u32>::try_from(stable.clone()).unwrap();
+    assert_eq!(matrix.node_count(), 3);
+    assert_eq!(matrix[NodeIndex::new(3)], 'd');
+    assert_eq!(matrix[(NodeIndex::new(2), NodeIndex::new(0))], 4);
+
+    let mut back = StableGraph::from(matrix.clone());
+    assert_eq!(back.node_count(), 3);
+    assert_eq!(back.node_bound(), 4);
+    assert_eq!(sorted(edges(&back), true), sorted(edges(&stable), true));
+    // the vacancy is reused
+    assert_eq!(back.add_node('x'), NodeIndex::new(1));
+
+    // other graph types number the nodes consecutively
+    let g = Graph::from(matrix);
+    assert_eq!(
+        g.node_weights().cloned().collect::<Vec<_>>(),
+        ['a', 'c', 'd']
+    );
+    assert_eq!(graph_edges(&g), [(1, 0, 4), (1, 1, 3)]);
+    let csr = Csr::<char, u32>::try_from(stable).unwrap();
+    assert_eq!(csr.node_count(), 3);
+    assert_eq!(csr[2], 'd');
+}
+
+#[test]
+fn adjacency_list() {
+    let g = sample::<Directed>();
+    let list = List::<u32>::from(g.clone());
+    assert_eq!(list.node_count(), 4);
+    assert_eq!(edges(&list), graph_edges(&g));
+
+    let h = Graph::from(list.clone());
+    assert_eq!(h.node_count(), 4);
+    assert_eq!(graph_edges(&h), graph_edges(&g));
+
+    let map = DiGraphMap::<u32, u32>::try_from(list.clone()).unwrap();
+    assert_eq!(map.edge_weight(2, 0), Some(&4));
+    let matrix = DiMatrix::<(), u32, Option<u32>, u32>::try_from(list.clone()).unwrap();
+    assert_eq!(matrix[(NodeIndex::new(1), NodeIndex::new(2))], 2);
+    let csr = Csr::<(), u32>::try_from(list).unwrap();
+    assert_eq!(csr.neighbors_slice(2), &[0, 2]);
+    let list = List::<u32>::from(csr);
+    assert_eq!(edges(&list), graph_edges(&g));
+
+    let stable = StableGraph::from(List::<u32>::from(StableGraph::from(g.clone())));
+    let names = ['a', 'b', 'c', 'd'];
+    assert_eq!(
+        stable
+            .node_references()
+            .map(|n| names[n.id().index()])
+            .collect::<String>(),
+        "abcd"
+    );
+}
@@ -0,0 +1,140 @@
+extern crate petgraph;
+
+use std::collections::HashMap;
+
+use petgraph::csv::{
+    read_edge_list, read_node_list, write_edge_list, write_node_list, CsvFormat, ReadErrorKind,
+};
+use petgraph::prelude::*;
+use petgraph::visit::EdgeRef;
+
+#[test]
+fn write_and_read() {
+    let mut graph = DiGraph::<&str, f64>::new();
+    let a = graph.add_node("a \"quoted\"");
+    let b = graph.add_node("b,c");
+    let c = graph.add_node("d\ne");
+    graph.add_edge(a, b, 1.5);
+    graph.add_edge(b, c, -2.);
+    graph.add_edge(c, c, 0.);
+
+    let format = CsvFormat::csv();
+    let mut nodes = Vec::new();
+    write_node_list(&graph, &mut nodes, &format, &["label"], |node| {
+        vec![node.1.to_string()]
+    })
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(nodes.clone()).unwrap(),
+        "id,label\n0,\"a \"\"quoted\"\"\"\n1,\"b,c\"\n2,\"d\ne\"\n"
+    );
+    let mut edges = Vec::new();
+    write_edge_list(&graph, &mut edges, &format, &["weight"], |edge| {
+        vec![edge.weight().to_string()]
+    })
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(edges.clone()).unwrap(),
+        "source,target,weight\n0,1,1.5\n1,2,-2\n2,2,0\n"
+    );
+
+    let mut read = DiGraph::<String, f64>::new();
+    let mut ids = HashMap::new();
+    read_node_list(&mut read, &mut ids, &nodes[..], &format, |_, record| {
+        Ok(record.get("label")?.to_string())
+    })
+    .unwrap();
+    read_edge_list(
+        &mut read,
+        &mut ids,
+        &edges[..],
+        &format,
+        |id| id.to_string(),
+        |record| record.parse("weight"),
+    )
+    .unwrap();
+    assert_eq!(read.node_count(), 3);
+    assert_eq!(read[ids["2"]], "d\ne");
+    for (e1, e2) in graph.edge_references().zip(read.edge_references()) {
+        assert_eq!(e1.source(), e2.source());
+        assert_eq!(e1.target(), e2.target());
+        assert_eq!(e1.weight(), e2.weight());
+    }
+}
+
+#[cfg(feature = "graphmap")]
+#[test]
+fn tsv_without_header() {
+    use petgraph::csv::Column;
+    use petgraph::graphmap::UnGraphMap;
+
+    let input = "1\t10\tx\r\n\n2\t10\ty\n1\t10\tz\n";
+    let format = CsvFormat::tsv()
+        .with_header(false)
+        .with_source(1)
+        .with_target(Column::Index(0));
+    let mut graph = UnGraphMap::<u32, String>::new();
+    let mut ids = HashMap::new();
+    read_edge_list(
+        &mut graph,
+        &mut ids,
+        input.as_bytes(),
+        &format,
+        |id| id.parse().unwrap(),
+        |record| Ok(record.get(2)?.to_string()),
+    )
+    .unwrap();
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.edge_count(), 2);
+    // the graph map has no parallel edges, and the first edge is kept
+    assert_eq!(graph.edge_weight(10, 1).unwrap(), "x");
+    assert_eq!(graph.edge_weight(2, 10).unwrap(), "y");
+}
+
+#[test]
+fn errors() {
+    let error = |input: &str| {
+        let mut graph = DiGraph::<(), u32>::new();
+        let error = read_edge_list(
+            &mut graph,
+            &mut HashMap::new(),
+            input.as_bytes(),
+            &CsvFormat::csv(),
+            |_| (),
+            |record| record.parse("weight"),
+        )
+        .unwrap_err();
+        (error.line(), error.to_string())
+    };
+    assert_eq!(
+        error("a,b,weight\nx,y,1\n\nx,z,one\n"),
+        (4, "line 4: invalid value \"one\"".to_string())
+    );
+    assert_eq!(
+        error("a,b,w\nx,y,1\n"),
+        (2, "line 2: there is no column \"weight\"".to_string())
+    );
+    assert_eq!(
+        error("a,b,weight\nx\n"),
+        (2, "line 2: there is no column 1".to_string())
+    );
+    assert_eq!(
+        error("a,b,weight\nx,\"y\n,1\n"),
+        (3, "line 3: unterminated quoted field".to_string())
+    );
+
+    let mut graph = DiGraph::<(), ()>::new();
+    let error = read_node_list(
+        &mut graph,
+        &mut HashMap::new(),
+        "id\na\nb\na\n".as_bytes(),
+        &CsvFormat::csv(),
+        |_, _| Ok(()),
+    )
+    .unwrap_err();
+    assert_eq!(error.line(), 4);
+    match *error.kind() {
+        ReadErrorKind::DuplicateNode(ref id) => assert_eq!(id, "a"),
+        ref kind => panic!("unexpected error {:?}", kind),
+    }
+}
@@ -214,3 +214,13 @@ fn floyd_warshall_negative_cycle() {
 
     assert!(res.is_err());
 }
+
+#[test]
+fn floyd_warshall_float_unreachable() {
+    let graph = Graph::<(), f64, Directed>::from_edges(&[(0, 1, 1.5), (1, 2, -0.5)]);
+    let (a, c) = (NodeIndex::new(0), NodeIndex::new(2));
+
+    let res = floyd_warshall(&graph, |edge| *edge.weight()).unwrap();
+    assert_eq!(res[&(a, c)], 1.);
+    assert_eq!(res[&(c, a)], std::f64::INFINITY);
+}
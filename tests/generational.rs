@@ -0,0 +1,118 @@
+extern crate petgraph;
+extern crate rand;
+
+use petgraph::generational::GenerationalGraph;
+use petgraph::prelude::*;
+
+#[test]
+fn stale_handles() {
+    let mut g = GenerationalGraph::<i32, i32>::new();
+    let a = g.add_node(0);
+    let b = g.add_node(1);
+    let c = g.add_node(2);
+    let ab = g.add_edge(a, b, 10);
+    let bc = g.add_edge(b, c, 11);
+    let ca = g.add_edge(c, a, 12);
+
+    assert_eq!(g.remove_edge(ab), Some(10));
+    assert_eq!(g.remove_edge(ab), None);
+    assert_eq!(g[ca], 12);
+    assert_eq!(g.edge_endpoints(ca), Some((c, a)));
+
+    // `c` moves into the place of `a`
+    assert_eq!(g.remove_node(a), Some(0));
+    assert_eq!(g.node_index(c), Some(NodeIndex::new(0)));
+    assert!(!g.contains_edge(ca));
+    assert_eq!(g.edge_endpoints(bc), Some((b, c)));
+    assert_eq!(g.find_edge(b, c), Some(bc));
+    assert_eq!(
+        g.neighbors_directed(c, Incoming).collect::<Vec<_>>(),
+        vec![b]
+    );
+
+    // the slots are reused with new generations
+    let d = g.add_node(3);
+    let bd = g.add_edge(b, d, 13);
+    assert_eq!(g.node_weight(a), None);
+    assert_eq!(g.edge_weight(ab), None);
+    assert_eq!(g.edge_weight(ca), None);
+    assert!(d.generation() > a.generation());
+    assert_eq!(g[bd], 13);
+    assert_eq!(g.remove_node(a), None);
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 2);
+}
+
+#[test]
+fn from_graph() {
+    let graph = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 2)]);
+    let mut g = GenerationalGraph::from(graph);
+    let a = g.node_handle(NodeIndex::new(0)).unwrap();
+    let c = g.node_handle(NodeIndex::new(2)).unwrap();
+    let loop_ = g.edge_handle(EdgeIndex::new(2)).unwrap();
+    assert_eq!(g.edge_endpoints(loop_), Some((c, c)));
+    g.remove_node(a);
+    assert_eq!(g.edge_count(), 2);
+    g.remove_node(c);
+    assert_eq!(g.edge_count(), 0);
+    assert!(!g.contains_edge(loop_));
+    assert_eq!(g.node_handle(NodeIndex::new(1)), None);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn random_removals() {
+    use petgraph::generational::{GenEdgeIndex, GenNodeIndex};
+    use rand::{Rng, SeedableRng, XorShiftRng};
+
+    // mirror every operation in a `StableGraph`, whose indices are stable
+    let mut rng = XorShiftRng::seed_from_u64(2195);
+    let mut g = GenerationalGraph::<usize, usize, Undirected>::default();
+    let mut s = StableUnGraph::<usize, usize>::default();
+    let mut nodes: Vec<(GenNodeIndex, NodeIndex)> = Vec::new();
+    let mut edges: Vec<(GenEdgeIndex, EdgeIndex)> = Vec::new();
+    let mut stale_nodes = Vec::new();
+    let mut stale_edges = Vec::new();
+    for i in 0..2000 {
+        match rng.gen_range(0, 4) {
+            0 => nodes.push((g.add_node(i), s.add_node(i))),
+            1 if !nodes.is_empty() => {
+                let (a, sa) = nodes[rng.gen_range(0, nodes.len())];
+                let (b, sb) = nodes[rng.gen_range(0, nodes.len())];
+                edges.push((g.add_edge(a, b, i), s.add_edge(sa, sb, i)));
+            }
+            2 if !nodes.is_empty() => {
+                let (a, sa) = nodes.swap_remove(rng.gen_range(0, nodes.len()));
+                assert_eq!(g.remove_node(a), s.remove_node(sa));
+                stale_nodes.push(a);
+            }
+            3 if !edges.is_empty() => {
+                let (e, se) = edges.swap_remove(rng.gen_range(0, edges.len()));
+                assert_eq!(g.remove_edge(e), s.remove_edge(se));
+                stale_edges.push(e);
+            }
+            _ => {}
+        }
+        edges.retain(|&(e, se)| {
+            let live = s.edge_weight(se).is_some();
+            if !live {
+                stale_edges.push(e);
+            }
+            live
+        });
+    }
+    assert_eq!(g.node_count(), s.node_count());
+    assert_eq!(g.edge_count(), s.edge_count());
+    for &(a, sa) in &nodes {
+        assert_eq!(g.node_weight(a), s.node_weight(sa));
+        assert_eq!(g.node_handle(g.node_index(a).unwrap()), Some(a));
+    }
+    for &(e, se) in &edges {
+        assert_eq!(g.edge_weight(e), s.edge_weight(se));
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        let (sa, sb) = s.edge_endpoints(se).unwrap();
+        assert_eq!((g[a], g[b]), (s[sa], s[sb]));
+    }
+    assert!(stale_nodes.iter().all(|&a| !g.contains_node(a)));
+    assert!(stale_edges.iter().all(|&e| !g.contains_edge(e)));
+}
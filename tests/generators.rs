@@ -0,0 +1,327 @@
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+#[cfg(feature = "rand")]
+use rand::{ChaChaRng, SeedableRng};
+use std::collections::HashSet;
+
+/// Assert that `g` has no self loops and no parallel edges.
+fn assert_simple<N, E, Ty: EdgeType>(g: &Graph<N, E, Ty>) {
+    let mut seen = HashSet::new();
+    for e in g.edge_references() {
+        let (a, b) = (e.source().index(), e.target().index());
+        assert_ne!(a, b, "self loop at {}", a);
+        let key = if g.is_directed() {
+            (a, b)
+        } else {
+            (a.min(b), a.max(b))
+        };
+        assert!(seen.insert(key), "parallel edge {:?}", key);
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn watts_strogatz() {
+    use petgraph::generators::watts_strogatz;
+
+    let mut rng = ChaChaRng::from_seed([7; 32]);
+    let g: UnGraph<(), ()> = watts_strogatz(30, 6, 0., &mut rng);
+    assert_eq!(g.edge_count(), 90);
+    assert!(g.node_indices().all(|a| g.neighbors(a).count() == 6));
+    assert!(g.contains_edge(0.into(), 29.into()));
+
+    for &beta in &[0.2, 0.5, 1.] {
+        let g: UnGraph<(), ()> = watts_strogatz(30, 6, beta, &mut rng);
+        assert_eq!(g.node_count(), 30);
+        assert_eq!(g.edge_count(), 90);
+        assert_simple(&g);
+    }
+
+    // every node is already adjacent to all other nodes
+    let g: UnGraph<(), ()> = watts_strogatz(5, 4, 1., &mut rng);
+    assert_eq!(g.edge_count(), 10);
+    assert_simple(&g);
+
+    let g: DiGraph<(), ()> = watts_strogatz(10, 3, 0., &mut rng);
+    assert_eq!(g.edge_count(), 10);
+    assert!(g.contains_edge(9.into(), 0.into()));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic]
+fn watts_strogatz_too_many_neighbors() {
+    let _: UnGraph<(), ()> =
+        petgraph::generators::watts_strogatz(4, 4, 0., &mut rand::thread_rng());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_dag() {
+    use petgraph::algo::is_cyclic_directed;
+    use petgraph::generators::random_dag;
+
+    let mut rng = ChaChaRng::from_seed([7; 32]);
+    let g: DiGraph<(), ()> = random_dag(12, 0., &mut rng);
+    assert_eq!(g.node_count(), 12);
+    assert_eq!(g.edge_count(), 0);
+    let g: DiGraph<(), ()> = random_dag(12, 1., &mut rng);
+    assert_eq!(g.edge_count(), 66);
+    assert!(!is_cyclic_directed(&g));
+    assert_simple(&g);
+    for _ in 0..20 {
+        let g: DiGraph<(), ()> = random_dag(15, 0.4, &mut rng);
+        assert!(!is_cyclic_directed(&g));
+        assert_simple(&g);
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_dag_with_edge_count() {
+    use petgraph::algo::is_cyclic_directed;
+    use petgraph::generators::random_dag_with_edge_count;
+
+    let mut rng = ChaChaRng::from_seed([7; 32]);
+    for &(n, m) in &[(0, 0), (1, 0), (2, 1), (10, 0), (10, 7), (10, 30), (10, 45)] {
+        let g: DiGraph<(), ()> = random_dag_with_edge_count(n, m, &mut rng);
+        assert_eq!(g.node_count(), n);
+        assert_eq!(g.edge_count(), m);
+        assert!(!is_cyclic_directed(&g));
+        assert_simple(&g);
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic]
+fn random_dag_with_too_many_edges() {
+    let _: DiGraph<(), ()> =
+        petgraph::generators::random_dag_with_edge_count(10, 46, &mut rand::thread_rng());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_tournament() {
+    use petgraph::algo::hamiltonian_path_in_tournament;
+    use petgraph::generators::random_tournament;
+
+    let mut rng = ChaChaRng::from_seed([7; 32]);
+    for n in 0..20 {
+        let g: DiGraph<(), ()> = random_tournament(n, &mut rng);
+        assert_eq!(g.node_count(), n);
+        assert_eq!(g.edge_count(), n * n.saturating_sub(1) / 2);
+        for a in g.node_indices() {
+            for b in g.node_indices().filter(|&b| b != a) {
+                assert!(g.contains_edge(a, b) != g.contains_edge(b, a));
+            }
+        }
+        let mut path = hamiltonian_path_in_tournament(&g);
+        assert!(path.windows(2).all(|w| g.contains_edge(w[0], w[1])));
+        path.sort();
+        assert!(path.into_iter().eq(g.node_indices()));
+    }
+}
+
+#[test]
+fn complete_graph() {
+    use petgraph::generators::complete_graph;
+
+    for n in 0..8 {
+        let g: UnGraph<(), ()> = complete_graph(n);
+        assert_eq!(g.node_count(), n);
+        assert_eq!(g.edge_count(), n * n.saturating_sub(1) / 2);
+        assert_simple(&g);
+        assert!(g
+            .node_indices()
+            .all(|a| g.neighbors(a).count() == n.saturating_sub(1)));
+
+        let g: DiGraph<(), ()> = complete_graph(n);
+        assert_eq!(g.edge_count(), n * n.saturating_sub(1));
+        for a in g.node_indices() {
+            for b in g.node_indices() {
+                assert_eq!(g.contains_edge(a, b), a != b);
+            }
+        }
+    }
+}
+
+#[test]
+fn complete_bipartite_graph() {
+    use petgraph::algo::bipartite_partition;
+    use petgraph::generators::complete_bipartite_graph;
+
+    let g: DiGraph<(), ()> = complete_bipartite_graph(3, 4);
+    assert_eq!(g.node_count(), 7);
+    assert_eq!(g.edge_count(), 12);
+    assert_simple(&g);
+    for a in 0..3 {
+        for b in 3..7 {
+            assert!(g.contains_edge(a.into(), b.into()));
+        }
+    }
+    let g: UnGraph<(), ()> = complete_bipartite_graph(3, 4);
+    let (left, right) = bipartite_partition(&g).unwrap();
+    assert_eq!((left.len(), right.len()), (3, 4));
+
+    let g: UnGraph<(), ()> = complete_bipartite_graph(0, 4);
+    assert_eq!((g.node_count(), g.edge_count()), (4, 0));
+}
+
+#[test]
+fn grid() {
+    use petgraph::generators::Grid;
+
+    let grid = Grid::new(&[4, 3]);
+    let g: UnGraph<Vec<usize>, ()> = grid.build_with(|c| c.to_vec());
+    assert_eq!(g.node_count(), 12);
+    assert_eq!(g.edge_count(), 17);
+    assert_simple(&g);
+    for a in g.node_indices() {
+        assert_eq!(g[a], grid.coordinates(a.index()));
+    }
+    for e in g.edge_references() {
+        let (p, q) = (&g[e.source()], &g[e.target()]);
+        let dist = (0..2)
+            .map(|k| (p[k] as isize - q[k] as isize).abs())
+            .sum::<isize>();
+        assert_eq!(dist, 1);
+    }
+
+    // 8-neighborhood: 4 * 2 horizontal, 3 * 3 vertical and 2 * 3 * 2 diagonal
+    let g: UnGraph<(), ()> = Grid::new(&[4, 3]).diagonals(true).build();
+    assert_eq!(g.edge_count(), 8 + 9 + 12);
+    assert_simple(&g);
+
+    // 3D grids
+    let g: UnGraph<(), ()> = Grid::new(&[3, 3, 3]).build();
+    assert_eq!(g.node_count(), 27);
+    assert_eq!(g.edge_count(), 3 * 2 * 9);
+    assert_eq!(g.neighbors(13.into()).count(), 6);
+    let g: UnGraph<(), ()> = Grid::new(&[3, 3, 3]).diagonals(true).build();
+    assert_eq!(g.neighbors(13.into()).count(), 26);
+    assert_simple(&g);
+
+    // tori
+    let g: UnGraph<(), ()> = Grid::new(&[5, 4, 3]).periodic(true).build();
+    assert_eq!(g.edge_count(), 3 * 60);
+    assert!(g.node_indices().all(|a| g.neighbors(a).count() == 6));
+    let g: UnGraph<(), ()> = Grid::new(&[3, 3, 3]).diagonals(true).periodic(true).build();
+    assert_eq!(g.edge_count(), 26 * 27 / 2);
+    assert!(g.node_indices().all(|a| g.neighbors(a).count() == 26));
+
+    // small sides wrap around without self loops or parallel edges
+    for dims in &[[1, 1], [1, 5], [2, 2], [2, 3]] {
+        for &diagonals in &[false, true] {
+            let g: DiGraph<(), ()> = Grid::new(dims).diagonals(diagonals).periodic(true).build();
+            assert_simple(&g.clone().into_edge_type::<Undirected>());
+            assert_simple(&g);
+        }
+    }
+    let g: UnGraph<(), ()> = Grid::new(&[2, 2]).periodic(true).build();
+    assert_eq!(g.edge_count(), 4);
+
+    // directed edges go forward, wrapping around
+    let g: DiGraph<(), ()> = Grid::new(&[3]).periodic(true).build();
+    assert!(g.contains_edge(0.into(), 1.into()));
+    assert!(g.contains_edge(2.into(), 0.into()));
+}
+
+#[test]
+fn path_cycle_star_wheel() {
+    use petgraph::generators::{cycle_graph, path_graph, star_graph, wheel_graph};
+
+    for n in 0..8 {
+        let path: UnGraph<(), ()> = path_graph(n);
+        let cycle: UnGraph<(), ()> = cycle_graph(n);
+        let star: UnGraph<(), ()> = star_graph(n);
+        let wheel: UnGraph<(), ()> = wheel_graph(n);
+        for g in &[&path, &cycle, &star, &wheel] {
+            assert_eq!(g.node_count(), n);
+            assert_simple(g);
+        }
+        let m = n.saturating_sub(1);
+        assert_eq!(path.edge_count(), m);
+        assert_eq!(star.edge_count(), m);
+        if n >= 3 {
+            assert_eq!(cycle.edge_count(), n);
+            assert!(cycle
+                .node_indices()
+                .all(|a| cycle.neighbors(a).count() == 2));
+        } else {
+            assert_eq!(cycle.edge_count(), m);
+        }
+        if n >= 4 {
+            assert_eq!(wheel.edge_count(), 2 * m);
+            assert_eq!(wheel.neighbors(0.into()).count(), m);
+            assert!(wheel
+                .node_indices()
+                .skip(1)
+                .all(|a| wheel.neighbors(a).count() == 3));
+        }
+    }
+    let g: UnGraph<(), ()> = wheel_graph(4);
+    assert_eq!(g.edge_count(), 6);
+}
+
+/// Return the largest distance between two nodes of a connected graph.
+fn diameter(g: &UnGraph<(), ()>) -> usize {
+    use petgraph::algo::dijkstra;
+
+    g.node_indices()
+        .map(|a| *dijkstra(g, a, None, |_| 1).values().max().unwrap())
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn named_graphs() {
+    use petgraph::algo::{bipartite_partition, is_isomorphic};
+    use petgraph::generators::{
+        complete_graph, complete_multipartite_graph, cube_graph, dodecahedron_graph,
+        hypercube_graph, kneser_graph, petersen_graph,
+    };
+
+    let petersen: UnGraph<(), ()> = petersen_graph();
+    assert_eq!((petersen.node_count(), petersen.edge_count()), (10, 15));
+    assert_simple(&petersen);
+    assert_eq!(diameter(&petersen), 2);
+    let kneser: UnGraph<(), ()> = kneser_graph(5, 2);
+    assert!(is_isomorphic(&petersen, &kneser));
+
+    for d in 0..6 {
+        let g: UnGraph<(), ()> = hypercube_graph(d);
+        let d = d as usize;
+        assert_eq!(g.node_count(), 1 << d);
+        assert_eq!(g.edge_count(), d << d >> 1);
+        assert_simple(&g);
+        assert!(g.node_indices().all(|a| g.neighbors(a).count() == d));
+        assert!(bipartite_partition(&g).is_ok());
+    }
+    let cube: UnGraph<(), ()> = cube_graph();
+    assert_eq!(diameter(&cube), 3);
+
+    let g: UnGraph<(), ()> = dodecahedron_graph();
+    assert_eq!((g.node_count(), g.edge_count()), (20, 30));
+    assert_simple(&g);
+    assert!(g.node_indices().all(|a| g.neighbors(a).count() == 3));
+    assert_eq!(diameter(&g), 5);
+
+    let g: DiGraph<(), ()> = complete_multipartite_graph(&[1, 2, 3]);
+    assert_eq!(g.node_count(), 6);
+    assert_eq!(g.edge_count(), 2 + 3 + 6);
+    assert_simple(&g);
+    assert!(g.contains_edge(0.into(), 5.into()));
+    assert!(!g.contains_edge(3.into(), 4.into()));
+    let g: UnGraph<(), ()> = complete_multipartite_graph(&[1, 1, 1, 1]);
+    let k4: UnGraph<(), ()> = complete_graph(4);
+    assert!(is_isomorphic(&g, &k4));
+
+    // K(n, 1) is complete, and K(2k - 1, k) has no edges
+    let g: UnGraph<(), ()> = kneser_graph(6, 1);
+    assert!(is_isomorphic(&g, &complete_graph::<UnGraph<(), ()>>(6)));
+    let g: UnGraph<(), ()> = kneser_graph(7, 4);
+    assert_eq!((g.node_count(), g.edge_count()), (35, 0));
+    let g: UnGraph<(), ()> = kneser_graph(3, 4);
+    assert_eq!(g.node_count(), 0);
+}
@@ -18,10 +18,11 @@ use petgraph::graph::IndexType;
 
 use petgraph::algo::{astar, dijkstra, DfsSpace};
 use petgraph::visit::{
-    IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNodeIdentifiers, NodeFiltered, Reversed, Topo,
-    VisitMap, Walker,
+    GetAdjacencyMatrix, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNodeIdentifiers,
+    NodeFiltered, Reversed, Topo, VisitMap, Walker,
 };
 
+use petgraph::data::FromElements;
 use petgraph::dot::Dot;
 
 fn set<I>(iter: I) -> HashSet<I::Item>
@@ -1319,6 +1320,30 @@ fn make_edge_iterator_graph<Ty: EdgeType>() -> Graph<f64, f64, Ty> {
     gr
 }
 
+#[test]
+fn reversed_adjacency_and_mst() {
+    let mut gr = Graph::<_, _>::new();
+    let a = gr.add_node("A");
+    let b = gr.add_node("B");
+    let c = gr.add_node("C");
+    gr.extend_with_edges(&[(a, b, 2.), (b, c, 1.), (a, c, 3.)]);
+
+    let rev = Reversed(&gr);
+    let matrix = rev.adjacency_matrix();
+    assert!(rev.is_adjacent(&matrix, b, a));
+    assert!(!rev.is_adjacent(&matrix, a, b));
+    assert!(rev.is_adjacent(&matrix, c, a));
+    assert!(!rev.is_adjacent(&matrix, a, c));
+
+    let mst = UnGraph::<_, _>::from_elements(min_spanning_tree(rev));
+    assert_eq!(mst.edge_count(), 2);
+    assert_eq!(mst.edge_weights().sum::<f64>(), 3.);
+    for e in mst.edge_references() {
+        let (s, t) = (e.source(), e.target());
+        assert!(gr.contains_edge(t, s), "edge {:?} not reversed", (s, t));
+    }
+}
+
 #[test]
 fn test_edge_iterators_directed() {
     let gr = make_edge_iterator_graph::<Directed>();
@@ -1408,6 +1433,33 @@ fn test_edge_filtered_iterators_directed() {
     }
 }
 
+#[test]
+fn test_edge_filtered_shortest_paths() {
+    use petgraph::algo::{astar, bellman_ford, dijkstra};
+    use petgraph::visit::EdgeFiltered;
+
+    // a short path over a negative edge, and a long path
+    let gr = Graph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, -0.5), (2, 3, 1.), (0, 3, 4.)]);
+    let filtered = EdgeFiltered::from_fn(&gr, |edge| *edge.weight() >= 0.);
+
+    let dist = dijkstra(&filtered, n(0), None, |edge| *edge.weight());
+    assert_eq!(dist[&n(3)], 4.);
+    assert!(!dist.contains_key(&n(2)));
+
+    let (cost, path) = astar(
+        &filtered,
+        n(0),
+        |a| a == n(3),
+        |edge| *edge.weight(),
+        |_| 0.,
+    )
+    .unwrap();
+    assert_eq!((cost, path), (4., vec![n(0), n(3)]));
+
+    assert_eq!(bellman_ford(&gr, n(0)).unwrap().distances[3], 1.5);
+    assert_eq!(bellman_ford(&filtered, n(0)).unwrap().distances[3], 4.);
+}
+
 #[test]
 fn test_node_filtered_iterators_directed() {
     use petgraph::{
@@ -2165,6 +2217,61 @@ fn dfs_visit() {
         Control::Continue
     });
     assert!(ret.break_value().is_none());
+
+    // pruning the tree edge to 2 skips its subtree, but 2 itself is still
+    // reached through another edge
+    let gr: Graph<(), ()> = Graph::from_edges(&[(1, 2), (0, 1), (2, 3), (0, 2)]);
+    let mut discovered = Vec::new();
+    let mut pruned = false;
+    depth_first_search(&gr, Some(n(0)), |event| {
+        match event {
+            TreeEdge(u, v) if u == n(0) && v == n(2) => {
+                pruned = true;
+                return Control::<()>::Prune;
+            }
+            Discover(u, _) => discovered.push(u),
+            _ => {}
+        }
+        Control::Continue
+    });
+    assert!(pruned);
+    discovered.sort();
+    assert_eq!(discovered, vec![n(0), n(1), n(2), n(3)]);
+}
+
+#[test]
+fn dfs_visit_edges() {
+    use petgraph::visit::DfsEdgeEvent::*;
+    use petgraph::visit::{depth_first_search, depth_first_search_edges};
+
+    // a multigraph with parallel edges and a self loop
+    let gr: Graph<(), ()> =
+        Graph::from_edges(&[(0, 1), (0, 1), (1, 2), (2, 0), (2, 0), (0, 3), (3, 3)]);
+    let mut edges = HashSet::new();
+    let mut tree_edges = 0;
+    let mut nodes = Vec::new();
+    depth_first_search_edges(&gr, Some(n(0)), |event| match event {
+        Discover(u, _) => nodes.push(u),
+        TreeEdge(u, v, e) | BackEdge(u, v, e) | CrossForwardEdge(u, v, e) => {
+            assert_eq!(gr.edge_endpoints(e), Some((u, v)));
+            assert!(edges.insert(e));
+            if let TreeEdge(..) = event {
+                tree_edges += 1;
+            }
+        }
+        Finish(..) => {}
+    });
+    assert_eq!(edges, gr.edge_indices().collect());
+    assert_eq!(tree_edges, gr.node_count() - 1);
+
+    // the nodes are discovered in the same order as by `depth_first_search`
+    let mut plain_nodes = Vec::new();
+    depth_first_search(&gr, Some(n(0)), |event| {
+        if let petgraph::visit::DfsEvent::Discover(u, _) = event {
+            plain_nodes.push(u);
+        }
+    });
+    assert_eq!(nodes, plain_nodes);
 }
 
 #[test]
@@ -2188,6 +2295,37 @@ fn filtered_post_order() {
     assert!(!po.contains(&n(1)));
 }
 
+#[test]
+fn node_filtered_algorithms() {
+    use petgraph::algo::{bellman_ford, connected_components, min_spanning_tree};
+    use petgraph::data::FromElements;
+    use petgraph::visit::{NodeCount, NodeFiltered};
+
+    // a square with a cheap shortcut through node 4
+    let gr = UnGraph::<(), f32>::from_edges(&[
+        (0, 1, 2.),
+        (1, 2, 2.),
+        (2, 3, 2.),
+        (3, 0, 2.),
+        (0, 4, 0.5),
+        (4, 2, 0.5),
+    ]);
+    assert_eq!(connected_components(&gr), 1);
+    assert_eq!(bellman_ford(&gr, n(0)).unwrap().distances[2], 1.);
+
+    let without_4 = NodeFiltered::from_fn(&gr, |a: NodeIndex| a != n(4));
+    assert_eq!(without_4.node_count(), 4);
+    assert_eq!(connected_components(&without_4), 1);
+    assert_eq!(bellman_ford(&without_4, n(0)).unwrap().distances[2], 4.);
+    let mst = UnGraph::<(), f32>::from_elements(min_spanning_tree(&without_4));
+    assert_eq!(mst.edge_count(), 3);
+
+    // splitting the square in two
+    let corners = NodeFiltered::from_fn(&gr, |a: NodeIndex| a == n(0) || a == n(2));
+    assert_eq!(corners.node_count(), 2);
+    assert_eq!(connected_components(&corners), 2);
+}
+
 #[test]
 fn filter_elements() {
     use petgraph::data::Element::{Edge, Node};
@@ -2484,3 +2622,377 @@ fn test_dominators_simple_fast() {
         "nodes that aren't reachable from the root do not have an idom"
     );
 }
+
+#[test]
+fn test_post_dominators() {
+    // Construct the following graph, with exits x and y:
+    //
+    //      r
+    //     / \
+    //    a   b
+    //    |   |\
+    //    c   d y
+    //     \ /
+    //      x
+    let mut graph = DiGraph::<_, ()>::new();
+    let r = graph.add_node("r");
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let x = graph.add_node("x");
+    let y = graph.add_node("y");
+    graph.extend_with_edges(&[(r, a), (r, b), (a, c), (b, d), (b, y), (c, x), (d, x)]);
+
+    let doms = dominators::post_dominators(&graph, Some(x));
+    assert_eq!(doms.root(), x);
+    assert_eq!(doms.immediate_dominator(x), None);
+    assert_eq!(doms.immediate_dominator(c), Some(x));
+    assert_eq!(doms.immediate_dominator(a), Some(c));
+    assert_eq!(doms.immediate_dominator(b), Some(d));
+    assert_eq!(doms.immediate_dominator(r), Some(x));
+    assert_eq!(
+        doms.immediate_dominator(y),
+        None,
+        "nodes that can't reach the exit do not have an idom"
+    );
+    let reversed = dominators::simple_fast(Reversed(&graph), x);
+    for n in graph.node_indices() {
+        assert_eq!(doms.immediate_dominator(n), reversed.immediate_dominator(n));
+    }
+
+    let doms = dominators::post_dominators(&graph, vec![x, y]);
+    assert_eq!(doms.root(), x);
+    assert_eq!(doms.immediate_dominator(x), None);
+    assert_eq!(doms.immediate_dominator(y), None);
+    assert_eq!(doms.immediate_dominator(a), Some(c));
+    assert_eq!(
+        doms.immediate_dominator(b),
+        None,
+        "b is only post-dominated by the virtual exit"
+    );
+    assert_eq!(doms.immediate_dominator(r), None);
+    assert_eq!(
+        doms.dominators(a).unwrap().collect::<Vec<_>>(),
+        vec![a, c, x]
+    );
+    assert_eq!(set(doms.immediately_dominated_by(x)), set(vec![c, d]));
+}
+
+#[test]
+fn test_dominance_frontiers() {
+    // r -> a -> b -> c, with a loop b -> a, and a branch r -> d -> c
+    let mut graph = DiGraph::<_, ()>::new();
+    let r = graph.add_node("r");
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    let c = graph.add_node("c");
+    let d = graph.add_node("d");
+    let z = graph.add_node("z");
+    graph.extend_with_edges(&[(r, a), (a, b), (b, a), (b, c), (r, d), (d, c), (z, c)]);
+
+    let doms = dominators::simple_fast(&graph, r);
+    let frontiers = doms.dominance_frontiers(&graph);
+    assert_eq!(frontiers[&r], set(vec![]));
+    assert_eq!(frontiers[&a], set(vec![a, c]));
+    assert_eq!(frontiers[&b], set(vec![a, c]));
+    assert_eq!(frontiers[&c], set(vec![]));
+    assert_eq!(frontiers[&d], set(vec![c]));
+    assert!(
+        !frontiers.contains_key(&z),
+        "nodes that aren't reachable from the root have no frontier"
+    );
+
+    // post-dominance frontiers, with the exit c
+    let doms = dominators::post_dominators(&graph, Some(c));
+    let frontiers = doms.dominance_frontiers(Reversed(&graph));
+    assert_eq!(frontiers[&c], set(vec![]));
+    assert_eq!(frontiers[&a], set(vec![b, r]));
+    assert_eq!(frontiers[&b], set(vec![b, r]));
+    assert_eq!(frontiers[&d], set(vec![r]));
+}
+
+#[test]
+fn maximal_cliques() {
+    use petgraph::algo::{degeneracy_ordering, maximal_cliques};
+
+    //  a --- b --- e
+    //  | \ / |
+    //  | / \ |
+    //  d --- c     f
+    let mut g = UnGraph::<&str, ()>::new_undirected();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let c = g.add_node("c");
+    let d = g.add_node("d");
+    let e = g.add_node("e");
+    let f = g.add_node("f");
+    g.extend_with_edges(&[(a, b), (a, c), (a, d), (b, c), (b, d), (c, d), (b, e)]);
+
+    let order = degeneracy_ordering(&g);
+    assert_eq!(order.len(), 6);
+    assert_eq!(set(order[..2].iter().cloned()), set(vec![e, f]));
+
+    let mut cliques = maximal_cliques(&g);
+    cliques.sort_by_key(|c| c.len());
+    assert_eq!(
+        cliques,
+        vec![set(vec![f]), set(vec![b, e]), set(vec![a, b, c, d])]
+    );
+
+    // Directed graphs are treated as undirected.
+    let h = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (0, 0)]);
+    assert_eq!(maximal_cliques(&h), vec![set(vec![n(0), n(1), n(2)])]);
+}
+
+#[test]
+fn subdivide_edge() {
+    let mut g = DiGraph::<u32, &str>::new();
+    let a = g.add_node(1);
+    let b = g.add_node(2);
+    let ab = g.add_edge(a, b, "ab");
+    g.add_edge(b, a, "ba");
+
+    let x = g.subdivide_edge(ab, 3).unwrap();
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 3);
+    assert_eq!(g[x], 3);
+    assert!(!g.contains_edge(a, b));
+    assert_eq!(g[g.find_edge(a, x).unwrap()], "ab");
+    assert_eq!(g[g.find_edge(x, b).unwrap()], "ab");
+    assert!(g.contains_edge(b, a));
+    assert_eq!(g.subdivide_edge(EdgeIndex::new(3), 4), None);
+}
+
+#[test]
+fn contract_edge() {
+    //  a -> b -> c
+    //  |  ^ |
+    //  v /  v
+    //  d    e
+    let mut g = DiGraph::<u32, u32>::new();
+    let a = g.add_node(1);
+    let b = g.add_node(2);
+    let c = g.add_node(4);
+    let d = g.add_node(8);
+    let e = g.add_node(16);
+    let ab = g.add_edge(a, b, 0);
+    g.add_edge(b, c, 1);
+    g.add_edge(a, d, 2);
+    g.add_edge(d, b, 3);
+    g.add_edge(b, e, 4);
+    g.add_edge(b, a, 5);
+
+    let m = g.contract_edge(ab, |x, y| *x += y).unwrap();
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 5);
+    assert_eq!(g[m], 3);
+    assert!(g.contains_edge(m, m));
+    let targets = set(g.neighbors(m).map(|x| g[x]));
+    assert_eq!(targets, set(vec![3, 4, 8, 16]));
+    let sources = set(g.neighbors_directed(m, Incoming).map(|x| g[x]));
+    assert_eq!(sources, set(vec![3, 8]));
+
+    // the merged node is displaced when it is the last node
+    let mut g = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    for (i, w) in g.node_weights_mut().enumerate() {
+        *w = 1 << i;
+    }
+    let m = g.contract_edge(EdgeIndex::new(3), |x, y| *x += y).unwrap();
+    assert_eq!(m, n(0));
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g[m], 8 + 1);
+    assert_eq!(g.neighbors(m).count(), 2);
+    assert!(g.node_indices().all(|x| g.neighbors(x).count() == 2));
+
+    // contracting a self loop removes it
+    let mut g = DiGraph::<(), ()>::from_edges(&[(0, 0), (0, 1)]);
+    assert_eq!(g.contract_edge(EdgeIndex::new(0), |_, _| ()), Some(n(0)));
+    assert_eq!((g.node_count(), g.edge_count()), (2, 1));
+    assert_eq!(g.contract_edge(EdgeIndex::new(1), |_, _| ()), None);
+}
+
+#[test]
+fn try_add() {
+    use petgraph::graph::GraphError;
+
+    let mut g = Graph::<(), (), Directed, u8>::with_capacity(0, 0);
+    let a = g.try_add_node(()).unwrap();
+    for _ in 0..255 {
+        g.try_add_edge(a, a, ()).unwrap();
+    }
+    assert_eq!(g.try_add_edge(a, a, ()), Err(GraphError::EdgeIxLimit));
+    assert_eq!(g.edge_count(), 255);
+    g.remove_edge(EdgeIndex::new(0));
+    assert!(g.try_add_edge(a, a, ()).is_ok());
+
+    for _ in 1..255 {
+        g.try_add_node(()).unwrap();
+    }
+    assert_eq!(g.try_add_node(()), Err(GraphError::NodeIxLimit));
+    assert_eq!(g.node_count(), 255);
+
+    let mut g = Graph::<(), ()>::new();
+    let a = g.add_node(());
+    assert_eq!(g.try_add_edge(n(3), a, ()), Err(GraphError::NodeMissed(3)));
+    assert_eq!(g.edge_count(), 0);
+}
+
+#[test]
+fn drain() {
+    let edges = &[
+        (0, 1, 0),
+        (1, 2, 1),
+        (2, 3, 2),
+        (3, 0, 3),
+        (1, 1, 4),
+        (2, 0, 5),
+    ];
+    let mut g = Graph::<u32, u32, Undirected>::from_edges(edges);
+    for (i, w) in g.node_weights_mut().enumerate() {
+        *w = i as u32;
+    }
+
+    // agrees with retain_edges
+    let mut h = g.clone();
+    h.retain_edges(|g, e| g[e] % 2 == 0);
+    let drained = g.drain_edges(|e| *e.weight() % 2 == 1);
+    let drained: Vec<_> = drained
+        .into_iter()
+        .map(|(a, b, w)| (a.index(), b.index(), w))
+        .collect();
+    assert_eq!(drained, vec![(1, 2, 1), (3, 0, 3), (2, 0, 5)]);
+    assert!(g.edge_references().eq(h.edge_references()));
+
+    h.retain_nodes(|g, a| g[a] != 0 && g[a] != 2);
+    assert_eq!(g.drain_nodes(|a, &w| a.index() == 2 || w == 0), vec![0, 2]);
+    assert!(g.node_weights().eq(h.node_weights()));
+    assert!(g.edge_references().eq(h.edge_references()));
+    assert!(g.drain_nodes(|_, _| false).is_empty());
+}
+
+fn assert_from_parallel_arrays<Ty: EdgeType>() {
+    let mut seed = 2221u32;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed % 40
+    };
+    let edges: Vec<(u32, u32, u32)> = (0..300).map(|i| (next(), next(), i)).collect();
+    let sources: Vec<_> = edges.iter().map(|e| e.0).collect();
+    let targets: Vec<_> = edges.iter().map(|e| e.1).collect();
+    let weights: Vec<_> = edges.iter().map(|e| e.2).collect();
+    let g = Graph::<(), u32, Ty>::from_parallel_arrays(&sources, &targets, weights);
+    let h = Graph::<(), u32, Ty>::from_edges(&edges);
+    assert_eq!(g.node_count(), h.node_count());
+    assert!(g.edge_references().eq(h.edge_references()));
+    for a in h.node_indices() {
+        for &dir in &[Outgoing, Incoming] {
+            assert!(g.edges_directed(a, dir).eq(h.edges_directed(a, dir)));
+        }
+    }
+}
+
+#[test]
+fn from_parallel_arrays() {
+    assert_from_parallel_arrays::<Directed>();
+    assert_from_parallel_arrays::<Undirected>();
+    let g = Graph::<(), ()>::from_parallel_arrays::<u32>(&[], &[], vec![]);
+    assert_eq!((g.node_count(), g.edge_count()), (0, 0));
+}
+
+#[test]
+#[should_panic]
+fn from_parallel_arrays_lengths() {
+    Graph::<(), ()>::from_parallel_arrays(&[0u32, 1], &[1u32, 0], vec![()]);
+}
+
+#[test]
+fn graph_macro() {
+    let g: Graph<&str, i32> = pg::graph! {
+        nodes: { a: "a", b: "b", c: "c", d },
+        edges: { a -> b: 1, b -> c: 2, c -> a, a -> a: 4, },
+    };
+    let mut h = Graph::new();
+    let a = h.add_node("a");
+    let b = h.add_node("b");
+    let c = h.add_node("c");
+    h.add_node("");
+    h.add_edge(a, b, 1);
+    h.add_edge(b, c, 2);
+    h.add_edge(c, a, 0);
+    h.add_edge(a, a, 4);
+    assert!(is_isomorphic_matching(&g, &h, |x, y| x == y, |x, y| x == y));
+    assert!(g.node_weights().eq(h.node_weights()));
+    assert!(g.edge_references().eq(h.edge_references()));
+
+    let g: UnGraph<(), ()> = pg::graph! { nodes: { a, b }, edges: { b -> a } };
+    assert!(g.contains_edge(n(0), n(1)));
+    #[cfg(feature = "stable_graph")]
+    {
+        let g: StableGraph<u8, ()> = pg::graph! { nodes: {} };
+        assert_eq!(g.node_count(), 0);
+    }
+}
+
+#[test]
+fn index_by_endpoints() {
+    let mut g = Graph::<(), f64>::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.add_edge(a, b, 1.5);
+    g.add_edge(b, c, 2.5);
+    assert_eq!(g[(a, b)], 1.5);
+    g[(b, c)] *= 2.;
+    assert_eq!(g.edge_weight_between(b, c), Some(&5.));
+    assert_eq!(g.edge_weight_between(c, b), None);
+    assert_eq!(g.edge_weight_between(a, n(7)), None);
+    *g.edge_weight_between_mut(a, b).unwrap() = 0.5;
+    assert_eq!(g[(a, b)], 0.5);
+
+    let mut g = UnGraph::<(), i32>::from_edges(&[(0, 1, 10)]);
+    g[(n(1), n(0))] += 1;
+    assert_eq!(g[(n(0), n(1))], 11);
+}
+
+#[test]
+#[should_panic]
+fn index_by_endpoints_missing() {
+    let g = Graph::<(), ()>::from_edges(&[(0, 1)]);
+    g[(n(1), n(0))]
+}
+
+#[test]
+fn graph_equality() {
+    use petgraph::algo::{graph_eq, is_same_up_to_iso};
+
+    let a = UnGraph::<i32, i32>::from_edges(&[(0, 1, 1), (1, 2, 2)]);
+    let mut b = UnGraph::<i32, i32>::with_capacity(0, 0);
+    b.extend_with_edges(&[(1, 0, 1), (2, 1, 2)]);
+    assert_eq!(a, b);
+    assert!(graph_eq(&a, &b));
+    b[n(2)] = 5;
+    assert_ne!(a, b);
+    b[n(2)] = 0;
+    b[EdgeIndex::new(0)] = 3;
+    assert_ne!(a, b);
+
+    // edges in another order are different, but the same up to isomorphism
+    let c = UnGraph::<i32, i32>::from_edges(&[(1, 2, 2), (0, 1, 1)]);
+    assert_ne!(a, c);
+    assert!(!graph_eq(&a, &c));
+    assert!(is_same_up_to_iso(&a, &c));
+    let d = UnGraph::<i32, i32>::from_edges(&[(2, 1, 1), (1, 0, 2)]);
+    assert!(is_same_up_to_iso(&a, &d));
+    let e = UnGraph::<i32, i32>::from_edges(&[(0, 1, 2), (1, 2, 2)]);
+    assert!(!is_same_up_to_iso(&a, &e));
+
+    let f = DiGraph::<i32, i32>::from_edges(&[(0, 1, 1), (1, 2, 2)]);
+    let g = DiGraph::<i32, i32>::from_edges(&[(1, 0, 1), (2, 1, 2)]);
+    assert_ne!(f, g);
+    assert!(!graph_eq(&a, &f));
+    #[cfg(feature = "stable_graph")]
+    assert!(graph_eq(&f, &StableGraph::from(f.clone())));
+}
@@ -378,3 +378,123 @@ fn self_loops_can_be_removed() {
     assert_eq!(graph.neighbors_directed((), Outgoing).next(), None);
     assert_eq!(graph.neighbors_directed((), Incoming).next(), None);
 }
+
+#[test]
+fn multi_graph_map_parallel_edges() {
+    use petgraph::graphmap::{DiMultiGraphMap, UnMultiGraphMap};
+
+    let mut g = DiMultiGraphMap::new();
+    assert_eq!(g.add_edge(1, 2, 'a', 10), None);
+    assert_eq!(g.add_edge(1, 2, 'b', 11), None);
+    assert_eq!(g.add_edge(2, 1, 'a', 12), None);
+    assert_eq!(g.add_edge(2, 2, 'a', 13), None);
+    assert_eq!(g.add_edge(1, 2, 'a', 14), Some(10));
+    assert_eq!(g.edge_count(), 4);
+    assert_eq!(g.as_graph_map().edge_count(), 3);
+    assert_eq!(
+        g.edges_between(1, 2).collect::<Vec<_>>(),
+        vec![(&'a', &14), (&'b', &11)]
+    );
+    assert_eq!(g.edges(1).count(), 2);
+    assert_eq!(g.all_edges().count(), 4);
+    assert_eq!(g.neighbors(1).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(g.edge_weight(2, 1, &'a'), Some(&12));
+
+    assert_eq!(g.remove_edge(1, 2, &'a'), Some(14));
+    assert_eq!(g.remove_edge(1, 2, &'a'), None);
+    assert!(g.contains_edge(1, 2, &'b'));
+    assert_eq!(g.remove_edge(1, 2, &'b'), Some(11));
+    assert!(!g.as_graph_map().contains_edge(1, 2));
+    assert_eq!(g.edge_count(), 2);
+
+    g.add_edge(1, 2, 'c', 15);
+    assert!(g.remove_node(2));
+    assert_eq!(g.edge_count(), 0);
+    assert_eq!(g.node_count(), 1);
+
+    let mut u = UnMultiGraphMap::new();
+    u.add_edge(1, 2, 'a', ());
+    u.add_edge(2, 1, 'b', ());
+    u.add_edge(1, 1, 'a', ());
+    u.add_edge(2, 3, 'a', ());
+    assert_eq!(u.edges_between(1, 2).count(), 2);
+    assert_eq!(u.remove_edges_between(2, 1).map(|e| e.len()), Some(2));
+    u.add_edge(1, 2, 'c', ());
+    assert!(u.remove_node(1));
+    assert_eq!(u.edge_count(), 1);
+}
+
+#[test]
+fn graph_map_with_hasher() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+
+    type Hasher = BuildHasherDefault<DefaultHasher>;
+
+    let mut g = DiGraphMap::<_, _, Hasher>::with_capacity_and_hasher(3, 3, Hasher::default());
+    g.add_edge("a", "b", 1);
+    g.add_edge("b", "c", 2);
+    g.add_edge("a", "c", 5);
+    assert_eq!(g.edges("a").count(), 2);
+    let scores = dijkstra(&g, "a", None, |e| *e.weight());
+    assert_eq!(scores["c"], 3);
+
+    let h: UnGraphMap<u32, (), Hasher> = vec![(0, 1), (1, 2)].into_iter().collect();
+    assert_eq!(h.neighbors(1).count(), 2);
+    assert!(h.contains_edge(1, 0));
+}
+
+#[test]
+fn edge_ids() {
+    use petgraph::algo::{is_isomorphic_matching, min_spanning_tree_edges};
+    use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences};
+
+    let mut g = UnGraphMap::new();
+    g.add_edge(1, 2, 3.);
+    g.add_edge(3, 2, 1.);
+    g.add_edge(1, 3, 2.);
+
+    // the edge ids seen from either endpoint map to the same index
+    let mut marked = vec![false; g.edge_bound()];
+    for edge in g.edges(2) {
+        marked[EdgeIndexable::to_index(&g, edge.id())] = true;
+    }
+    assert_eq!(marked.iter().filter(|&&m| m).count(), 2);
+    for edge in g.edges(1).chain(g.edges(3)) {
+        let i = EdgeIndexable::to_index(&g, edge.id());
+        assert_eq!(marked[i], edge.source() == 2 || edge.target() == 2);
+        assert_eq!(
+            EdgeIndexable::from_index(&g, i),
+            (edge.id().0.min(edge.id().1), edge.id().0.max(edge.id().1))
+        );
+    }
+    for (i, edge) in g.edge_references().enumerate() {
+        assert_eq!(EdgeIndexable::to_index(&g, edge.id()), i);
+    }
+
+    let tree = min_spanning_tree_edges(&g)
+        .map(|e| g[e.id()])
+        .collect::<Vec<_>>();
+    assert_eq!(tree, vec![1., 2.]);
+
+    let mut h = UnGraphMap::new();
+    h.add_edge(20, 10, 3.);
+    h.add_edge(20, 30, 1.);
+    h.add_edge(30, 10, 2.);
+    assert!(is_isomorphic_matching(&g, &h, |_, _| true, |a, b| a == b));
+    h[(10, 30)] = 4.;
+    assert!(!is_isomorphic_matching(&g, &h, |_, _| true, |a, b| a == b));
+}
+
+#[test]
+fn graph_macro() {
+    let g: DiGraphMap<&str, u32> = petgraph::graph! {
+        nodes: { a: "a", b: "b", c: "c" },
+        edges: { a -> b: 1, b -> c, c -> a: 3 },
+    };
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_weight("a", "b"), Some(&1));
+    assert_eq!(g.edge_weight("b", "c"), Some(&0));
+    assert_eq!(g.edge_weight("a", "c"), None);
+    assert!(g.nodes().eq(vec!["a", "b", "c"]));
+}
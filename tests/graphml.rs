@@ -0,0 +1,196 @@
+#![cfg(feature = "graphml")]
+extern crate petgraph;
+
+use petgraph::graph::NodeIndex;
+use petgraph::graphml::{parse, AttributeMap, AttributeValue, GraphMl, ParseErrorKind};
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeRef, NodeRef};
+
+#[test]
+fn write() {
+    let mut graph = UnGraph::<&str, i32>::new_undirected();
+    let a = graph.add_node("a & <b>");
+    let b = graph.add_node("b");
+    graph.add_node("c");
+    graph.add_edge(a, b, 7);
+
+    let node_attributes = |_, node: (NodeIndex, &&str)| {
+        let mut attributes = AttributeMap::new();
+        if *node.weight() != "c" {
+            attributes.insert("name".to_string(), (*node.weight()).into());
+        }
+        attributes
+    };
+    let edge_attributes = |_, edge: petgraph::graph::EdgeReference<i32>| {
+        let mut attributes = AttributeMap::new();
+        attributes.insert("weight".to_string(), (*edge.weight()).into());
+        attributes.insert("cost".to_string(), std::f64::INFINITY.into());
+        attributes
+    };
+    let graphml = GraphMl::new(&graph)
+        .with_node_attributes(&node_attributes)
+        .with_edge_attributes(&edge_attributes)
+        .with_graph_attribute("directed", false)
+        .to_string();
+    assert_eq!(
+        graphml,
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="d0" for="graph" attr.name="directed" attr.type="boolean"/>
+  <key id="d1" for="node" attr.name="name" attr.type="string"/>
+  <key id="d2" for="edge" attr.name="weight" attr.type="int"/>
+  <key id="d3" for="edge" attr.name="cost" attr.type="double"/>
+  <graph edgedefault="undirected">
+    <data key="d0">false</data>
+    <node id="n0">
+      <data key="d1">a &amp; &lt;b&gt;</data>
+    </node>
+    <node id="n1">
+      <data key="d1">b</data>
+    </node>
+    <node id="n2"/>
+    <edge source="n0" target="n1">
+      <data key="d2">7</data>
+      <data key="d3">INF</data>
+    </edge>
+  </graph>
+</graphml>
+"#
+    );
+
+    let parsed = parse::<Undirected>(&graphml).unwrap();
+    assert_eq!(
+        parsed.attributes["directed"],
+        AttributeValue::Boolean(false)
+    );
+    let g = parsed.graph;
+    assert_eq!(g[a].id, "n0");
+    assert_eq!(g[a].attributes["name"], "a & <b>".into());
+    assert!(g[NodeIndex::new(2)].attributes.is_empty());
+    let edge = g.edge_references().next().unwrap();
+    assert_eq!((edge.source(), edge.target()), (a, b));
+    assert_eq!(edge.weight()["weight"], AttributeValue::Int(7));
+    assert_eq!(
+        edge.weight()["cost"],
+        AttributeValue::Double(std::f64::INFINITY)
+    );
+}
+
+#[test]
+fn read() {
+    let parsed = parse::<Directed>(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE graphml [ <!ENTITY x "y"> ]>
+<!-- written by hand -->
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns"
+    xmlns:y="http://www.yworks.com/xml/graphml">
+  <key id="color" for="node" attr.name="color" attr.type="string">
+    <default>yellow</default>
+  </key>
+  <key id="w" for="edge" attr.name="weight" attr.type='float'><default>1.5</default></key>
+  <key id="g" for="node" yfiles.type="nodegraphics"/>
+  <key id="big" for="all" attr.name="big" attr.type="long"/>
+  <graph id="G" edgedefault="directed">
+    <data key="big">12345678901</data>
+    <edge source="a" target="b"><data key="w">-2</data></edge>
+    <node id="a"><data key="color">&#x72;ed</data></node>
+    <node id="b">
+      <port name="p"/>
+      <data key="g"><y:ShapeNode><y:Label>B</y:Label></y:ShapeNode></data>
+      <graph id="inner" edgedefault="directed">
+        <node id="c"/>
+      </graph>
+    </node>
+    <edge id="e" source="b" target="c" directed="true">
+      <data key="big"><![CDATA[ 3 ]]></data>
+    </edge>
+  </graph>
+</graphml>
+"#,
+    )
+    .unwrap();
+    assert_eq!(parsed.id.as_deref(), Some("G"));
+    assert_eq!(parsed.attributes["big"], AttributeValue::Long(12345678901));
+    let g = parsed.graph;
+    let ids = g.node_weights().map(|n| &*n.id).collect::<Vec<_>>();
+    assert_eq!(ids, ["a", "b", "c"]);
+    assert_eq!(g[NodeIndex::new(0)].attributes["color"], "red".into());
+    assert_eq!(g[NodeIndex::new(1)].attributes["g"], "B".into());
+    assert_eq!(g[NodeIndex::new(2)].attributes["color"], "yellow".into());
+    let edges = g
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), e.weight().clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(edges.len(), 2);
+    assert_eq!((edges[0].0, edges[0].1), (0, 1));
+    assert_eq!(edges[0].2["weight"], AttributeValue::Float(-2.));
+    assert_eq!((edges[1].0, edges[1].1), (1, 2));
+    assert_eq!(edges[1].2["big"], AttributeValue::Long(3));
+    assert_eq!(edges[1].2["weight"], AttributeValue::Float(1.5));
+}
+
+#[test]
+fn errors() {
+    let error = |input: &str| {
+        let error = parse::<Directed>(input).unwrap_err();
+        (error.line(), error.column(), error.kind().clone())
+    };
+    let graph = |content: &str| {
+        format!(
+            "<graphml>\n<key id=\"k\" for=\"node\" attr.type=\"int\"/>\n<graph edgedefault=\"directed\">\n{}\n</graph>\n</graphml>",
+            content
+        )
+    };
+    assert_eq!(
+        error("<graphml><graph edgedefault=\"undirected\"/></graphml>"),
+        (1, 10, ParseErrorKind::GraphType)
+    );
+    assert_eq!(
+        error(&graph(
+            "<node id=\"a\"/><edge source=\"a\" target=\"a\" directed=\"false\"/>"
+        )),
+        (4, 15, ParseErrorKind::GraphType)
+    );
+    assert_eq!(
+        error(&graph("<node id=\"a\"/>\n  <node id=\"a\"/>")),
+        (5, 3, ParseErrorKind::DuplicateNode("a".to_string()))
+    );
+    assert_eq!(
+        error(&graph("<edge source=\"a\" target=\"b\"/>")),
+        (4, 1, ParseErrorKind::UnknownNode("a".to_string()))
+    );
+    assert_eq!(
+        error(&graph("<node id=\"a\"><data key=\"x\">1</data></node>")),
+        (4, 14, ParseErrorKind::UnknownKey("x".to_string()))
+    );
+    assert_eq!(
+        error(&graph("<node id=\"a\"><data key=\"k\">1.5</data></node>")),
+        (4, 14, ParseErrorKind::InvalidValue("1.5".to_string()))
+    );
+    assert_eq!(
+        error(&graph("<node/>")),
+        (4, 1, ParseErrorKind::MissingAttribute("id"))
+    );
+    assert_eq!(
+        error(&graph("<hyperedge/>")),
+        (4, 1, ParseErrorKind::Hyperedge)
+    );
+    assert_eq!(
+        error(&graph("<node id=\"a\"></edge>")),
+        (4, 14, ParseErrorKind::Syntax)
+    );
+    assert_eq!(
+        error(&graph("<node id=\"a &bogus;\"/>")),
+        (4, 13, ParseErrorKind::Syntax)
+    );
+    assert_eq!(
+        error("<graphml><graph>"),
+        (1, 17, ParseErrorKind::UnexpectedEnd)
+    );
+    assert_eq!(
+        parse::<Directed>("<graphml><graph>")
+            .unwrap_err()
+            .to_string(),
+        "1:17: unexpected end of input"
+    );
+}
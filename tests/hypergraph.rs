@@ -0,0 +1,130 @@
+extern crate petgraph;
+
+use petgraph::algo::has_path_connecting;
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::hypergraph::{HyperGraph, Incidence};
+use petgraph::visit::{Bfs, EdgeRef};
+
+fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+    v.sort();
+    v
+}
+
+/// Reactions between molecules, with each molecule as a node and each
+/// reaction as an edge.
+fn reactions() -> HyperGraph<&'static str, &'static str> {
+    let mut g = HyperGraph::new();
+    let h2 = g.add_node("H2");
+    let o2 = g.add_node("O2");
+    let h2o = g.add_node("H2O");
+    let c = g.add_node("C");
+    let co2 = g.add_node("CO2");
+    g.add_node("He");
+    g.add_edge(vec![h2, o2, h2o], "combustion of hydrogen");
+    g.add_edge(vec![c, o2, co2], "combustion of carbon");
+    g.add_edge(vec![h2o, h2, o2], "electrolysis");
+    g
+}
+
+#[test]
+fn incidence() {
+    let g = reactions();
+    let n = NodeIndex::new;
+    let e = EdgeIndex::new;
+    assert_eq!(g.node_count(), 6);
+    assert_eq!(g.edge_count(), 3);
+    assert_eq!(g.edge_nodes(e(1)), &[n(3), n(1), n(4)]);
+    assert_eq!(g.incident_edges(n(1)), &[e(0), e(1), e(2)]);
+    assert_eq!(g.incident_edges(n(5)), &[]);
+    assert_eq!(g[e(2)], "electrolysis");
+    assert_eq!(
+        sorted(g.neighbors(n(0)).collect()),
+        vec![n(1), n(1), n(2), n(2)]
+    );
+    assert_eq!(g.neighbors(n(5)).count(), 0);
+}
+
+#[test]
+fn repeated_nodes() {
+    let mut g = HyperGraph::<_, _>::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let e = g.add_edge(vec![a, b, a, b, b], ());
+    let empty = g.add_edge(vec![], ());
+    assert_eq!(g.edge_nodes(e), &[a, b]);
+    assert_eq!(g.incident_edges(a), &[e]);
+    assert_eq!(g.edge_nodes(empty), &[]);
+    assert_eq!(g.neighbors(b).collect::<Vec<_>>(), vec![a]);
+}
+
+#[test]
+#[should_panic]
+fn add_edge_missing_node() {
+    let mut g = HyperGraph::<(), ()>::new();
+    let a = g.add_node(());
+    g.add_edge(vec![a, NodeIndex::new(1)], ());
+}
+
+#[test]
+fn two_section() {
+    let g = reactions();
+    let s = g.to_two_section();
+    assert_eq!(s.node_count(), g.node_count());
+    assert_eq!(s[NodeIndex::new(4)], "CO2");
+    let edges: Vec<_> = s
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect();
+    assert_eq!(
+        sorted(edges),
+        vec![
+            (0, 1, 2),
+            (0, 2, 2),
+            (1, 2, 2),
+            (1, 3, 1),
+            (1, 4, 1),
+            (3, 4, 1)
+        ]
+    );
+}
+
+#[test]
+fn incidence_graph() {
+    let g = reactions();
+    let b = g.to_incidence_graph();
+    assert_eq!(b.node_count(), 9);
+    assert_eq!(b.edge_count(), 9);
+    assert_eq!(b[NodeIndex::new(2)], Incidence::Node("H2O"));
+    assert_eq!(
+        b[NodeIndex::new(7)],
+        Incidence::Edge("combustion of carbon")
+    );
+    assert_eq!(
+        sorted(b.neighbors(NodeIndex::new(7)).collect()),
+        vec![NodeIndex::new(1), NodeIndex::new(3), NodeIndex::new(4)]
+    );
+    assert_eq!(b.neighbors(NodeIndex::new(5)).count(), 0);
+}
+
+#[test]
+fn traversal() {
+    let g = reactions();
+    let mut bfs = Bfs::new(&g, NodeIndex::new(0));
+    let mut order = Vec::new();
+    while let Some(a) = bfs.next(&g) {
+        order.push(g[a]);
+    }
+    assert_eq!(order, vec!["H2", "O2", "H2O", "C", "CO2"]);
+    assert!(has_path_connecting(
+        &g,
+        NodeIndex::new(0),
+        NodeIndex::new(4),
+        None
+    ));
+    assert!(!has_path_connecting(
+        &g,
+        NodeIndex::new(0),
+        NodeIndex::new(5),
+        None
+    ));
+}
@@ -7,7 +7,12 @@ use petgraph::graph::{edge_index, node_index};
 use petgraph::prelude::*;
 use petgraph::EdgeType;
 
-use petgraph::algo::{is_isomorphic, is_isomorphic_matching, is_isomorphic_subgraph};
+use petgraph::algo::{
+    automorphism_generators, find_isomorphism, find_isomorphism_matching, is_isomorphic,
+    is_isomorphic_matching, is_isomorphic_subgraph, is_monomorphic_subgraph,
+    is_monomorphic_subgraph_matching, node_orbits, subgraph_isomorphisms_iter,
+    subgraph_isomorphisms_iter_matching, subgraph_monomorphisms_iter,
+};
 
 /// Petersen A and B are isomorphic
 ///
@@ -460,6 +465,101 @@ fn iso_matching() {
     ));
 }
 
+#[test]
+fn mono_subgraph() {
+    let path = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+    let triangle = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    assert!(is_monomorphic_subgraph(&path, &triangle));
+    let mut mappings = subgraph_monomorphisms_iter(&path, &triangle)
+        .map(|m| m.iter().map(|n| n.index()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    mappings.sort();
+    assert_eq!(mappings, vec![vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]]);
+
+    // Edges must still agree in direction.
+    let reverse = Graph::<(), ()>::from_edges(&[(1, 0), (2, 1), (0, 2)]);
+    assert!(!is_monomorphic_subgraph(&reverse, &path));
+    assert!(is_monomorphic_subgraph(&reverse, &triangle));
+
+    // A pattern with more edges than the target is not a subgraph.
+    assert!(!is_monomorphic_subgraph(&triangle, &path));
+
+    // Only the edges of the pattern are compared.
+    let mut pattern = Graph::<&str, u32>::new();
+    let a = pattern.add_node("a");
+    let b = pattern.add_node("b");
+    pattern.add_edge(a, b, 1);
+    let mut target = Graph::<&str, u32>::new();
+    let x = target.add_node("a");
+    let y = target.add_node("b");
+    target.add_edge(x, y, 1);
+    target.add_edge(y, x, 2);
+    assert!(is_monomorphic_subgraph_matching(
+        &pattern,
+        &target,
+        |x, y| x == y,
+        |x, y| x == y
+    ));
+    pattern[a] = "b";
+    assert!(!is_monomorphic_subgraph_matching(
+        &pattern,
+        &target,
+        |x, y| x == y,
+        |x, y| x == y
+    ));
+}
+
+#[test]
+fn automorphisms_transitive() {
+    // Petersen and Coxeter graphs are vertex-transitive
+    for g in &[str_to_graph(PETERSEN_A), str_to_graph(COXETER_A)] {
+        assert_eq!(node_orbits(g).len(), 1);
+        let generators = automorphism_generators(g);
+        assert!(!generators.is_empty());
+        for p in &generators {
+            for edge in g.edge_references() {
+                let (a, b) = (p[edge.source().index()], p[edge.target().index()]);
+                assert!(g.contains_edge(a, b));
+            }
+        }
+    }
+
+    // A directed cycle with a chord has no symmetries.
+    let g = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)]);
+    assert!(automorphism_generators(&g).is_empty());
+    assert_eq!(node_orbits(&g).len(), 4);
+}
+
+#[test]
+fn iso_mapping() {
+    let g0 = Graph::<(), char>::from_edges(&[(0, 1, 'a'), (1, 2, 'b'), (2, 0, 'c'), (0, 3, 'd')]);
+    let g0 = g0.map(|i, _| i.index(), |_, &w| w);
+    // Rotate the nodes and edges of g0.
+    let mut g1 = Graph::new();
+    let n = (0..4).map(|i| g1.add_node((i + 1) % 4)).collect::<Vec<_>>();
+    g1.add_edge(n[3], n[0], 'a');
+    g1.add_edge(n[0], n[1], 'b');
+    g1.add_edge(n[1], n[3], 'c');
+    g1.add_edge(n[3], n[2], 'd');
+
+    let mapping = find_isomorphism_matching(&g0, &g1, |x, y| x == y, |x, y| x == y).unwrap();
+    for a in g0.node_indices() {
+        assert_eq!(g0[a], g1[mapping[a.index()]]);
+    }
+    for edge in g0.edge_references() {
+        let (a, b) = (
+            mapping[edge.source().index()],
+            mapping[edge.target().index()],
+        );
+        assert_eq!(*edge.weight(), g1[g1.find_edge(a, b).unwrap()]);
+    }
+    assert!(find_isomorphism(&g0, &g1).is_some());
+    assert_eq!(
+        find_isomorphism_matching(&g0, &g1, |x, y| x == y, |_, _| false),
+        None
+    );
+}
+
 #[test]
 fn iso_100n_100e() {
     let g0 = graph_from_file("tests/res/graph_100n_100e.txt");
@@ -493,6 +593,60 @@ fn iso_subgraph() {
     assert!(is_isomorphic_subgraph(&g0, &g1));
 }
 
+#[test]
+fn iso_subgraph_iter() {
+    // A subgraph match must be node-induced: the path 0 -> 1 -> 2 is not a
+    // subgraph of the directed triangle, since it would miss the edge 2 -> 0.
+    let path = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+    let triangle = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    assert!(!is_isomorphic_subgraph(&path, &triangle));
+    assert_eq!(subgraph_isomorphisms_iter(&path, &triangle).count(), 0);
+
+    let edge = Graph::<(), ()>::from_edges(&[(0, 1)]);
+    let g1 = Graph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+    let mut mappings = subgraph_isomorphisms_iter(&edge, &g1)
+        .map(|m| (m[0].index(), m[1].index()))
+        .collect::<Vec<_>>();
+    mappings.sort();
+    assert_eq!(mappings, vec![(0, 1), (1, 2), (2, 0), (2, 3)]);
+
+    // Isolated nodes must map to nodes that are not adjacent.
+    let mut pair = Graph::<(), ()>::new();
+    pair.add_node(());
+    pair.add_node(());
+    let mut g2 = Graph::<(), ()>::from_edges(&[(0, 1)]);
+    g2.add_node(());
+    let mut mappings = subgraph_isomorphisms_iter(&pair, &g2)
+        .map(|m| (m[0].index(), m[1].index()))
+        .collect::<Vec<_>>();
+    mappings.sort();
+    assert_eq!(mappings, vec![(0, 2), (1, 2), (2, 0), (2, 1)]);
+
+    // The empty graph has exactly one embedding.
+    let empty = Graph::<(), ()>::new();
+    assert_eq!(subgraph_isomorphisms_iter(&empty, &g2).count(), 1);
+    assert_eq!(subgraph_isomorphisms_iter(&g2, &empty).count(), 0);
+}
+
+#[test]
+fn iso_subgraph_iter_matching() {
+    let mut pattern = UnGraph::<char, ()>::new_undirected();
+    let a = pattern.add_node('a');
+    let b = pattern.add_node('b');
+    pattern.add_edge(a, b, ());
+
+    let target = UnGraph::<char, ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+    let mut target = target.map(|_, _| 'a', |_, _| ());
+    target[node_index(2)] = 'b';
+
+    let mut mappings =
+        subgraph_isomorphisms_iter_matching(&pattern, &target, |x, y| x == y, |x, y| x == y)
+            .map(|m| m[0].index())
+            .collect::<Vec<_>>();
+    mappings.sort();
+    assert_eq!(mappings, vec![1, 3]);
+}
+
 /// Isomorphic pair
 const COXETER_A: &str = "
  0 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 1 0 0 0 0 0 0 0 1
@@ -0,0 +1,64 @@
+extern crate petgraph;
+
+use petgraph::graph::NodeIndex;
+use petgraph::keyed_graph::KeyedGraph;
+use petgraph::Undirected;
+
+/// Check that the keys and the map from keys agree with each other.
+fn assert_consistent<N, E>(g: &KeyedGraph<String, N, E>) {
+    assert_eq!(g.keys().len(), g.node_count());
+    for (i, key) in g.keys().iter().enumerate() {
+        assert_eq!(g.node_index(key.as_str()), Some(NodeIndex::new(i)));
+    }
+}
+
+#[test]
+fn keys() {
+    let mut g = KeyedGraph::<String, u32, u32>::new();
+    let a = g.add_node("a".to_string(), 1);
+    let e = g.add_edge_by_key("a".to_string(), "b".to_string(), 10);
+    g.add_edge_by_key("b".to_string(), "c".to_string(), 11);
+    g.add_edge_by_key("c".to_string(), "d".to_string(), 12);
+    assert_eq!(g.add_node("a".to_string(), 2), a);
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.node_weight_by_key("a"), Some(&2));
+    assert_eq!(g.node_weight_by_key("b"), Some(&0));
+    assert_eq!(g.node_weight_by_key("x"), None);
+    *g.node_weight_by_key_mut("d").unwrap() = 4;
+    assert_eq!(g.find_edge_by_key("a", "b"), Some(e));
+    assert_eq!(g.find_edge_by_key("b", "a"), None);
+    assert_eq!(
+        g.update_edge_by_key("a".to_string(), "b".to_string(), 13),
+        e
+    );
+    assert_eq!(g[e], 13);
+    assert_consistent(&g);
+
+    assert_eq!(g.remove_node_by_key("b"), Some(("b".to_string(), 0)));
+    assert_eq!(g.remove_node_by_key("b"), None);
+    assert!(!g.contains_key("b"));
+    assert_consistent(&g);
+    assert_eq!(g.node_weight_by_key("d"), Some(&4));
+    assert_eq!(g.edge_count(), 1);
+    assert_eq!(g.find_edge_by_key("c", "d").map(|e| g[e]), Some(12));
+    assert_eq!(g.remove_node(a), Some(("a".to_string(), 2)));
+    assert_consistent(&g);
+    assert_eq!(g.keys(), &["c".to_string(), "d".to_string()]);
+
+    g.clear();
+    assert_eq!(g.node_count(), 0);
+    assert!(!g.contains_key("c"));
+}
+
+#[test]
+fn from_edges() {
+    let g: KeyedGraph<&str, (), u32, Undirected> =
+        vec![("a", "b", 1), ("b", "c", 2), ("c", "a", 3), ("d", "d", 4)]
+            .into_iter()
+            .collect();
+    assert_eq!(g.keys(), &["a", "b", "c", "d"]);
+    assert_eq!(g.edge_count(), 4);
+    assert_eq!(g.find_edge_by_key("a", "c").map(|e| g[e]), Some(3));
+    let (graph, keys) = g.into_parts();
+    assert_eq!(graph.node_count(), keys.len());
+}
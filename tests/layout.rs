@@ -0,0 +1,183 @@
+use petgraph::layout::{
+    bipartite_layout, circular_layout, shell_layout, FruchtermanReingold, KamadaKawai, Sugiyama,
+};
+use petgraph::prelude::*;
+use std::collections::HashMap;
+
+fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+}
+
+/// Two triangles joined by a single edge.
+fn two_triangles() -> UnGraph<(), ()> {
+    UnGraph::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)])
+}
+
+#[test]
+fn fruchterman_reingold() {
+    let g = two_triangles();
+    let layout = FruchtermanReingold::new().iterations(200).seed(1);
+    let pos = layout.layout(&g);
+    assert_eq!(pos.len(), g.node_count());
+    assert_eq!(pos, layout.layout(&g));
+    for p in pos.values() {
+        assert!(p.iter().all(|&x| (0. ..=1.).contains(&x)));
+    }
+    // the triangles are drawn apart
+    let n = NodeIndex::new;
+    assert!(distance(pos[&n(0)], pos[&n(1)]) < distance(pos[&n(0)], pos[&n(5)]));
+    assert!(distance(pos[&n(4)], pos[&n(5)]) < distance(pos[&n(1)], pos[&n(4)]));
+}
+
+#[test]
+fn fruchterman_reingold_barnes_hut() {
+    // a grid, where every node ends up near its grid neighbors
+    let mut g = UnGraph::<(), ()>::new_undirected();
+    let nodes = (0..100).map(|_| g.add_node(())).collect::<Vec<_>>();
+    for i in 0..10 {
+        for j in 0..10 {
+            if i + 1 < 10 {
+                g.add_edge(nodes[i * 10 + j], nodes[(i + 1) * 10 + j], ());
+            }
+            if j + 1 < 10 {
+                g.add_edge(nodes[i * 10 + j], nodes[i * 10 + j + 1], ());
+            }
+        }
+    }
+    let pos = FruchtermanReingold::new()
+        .iterations(300)
+        .barnes_hut(0.8)
+        .layout(&g);
+    let edge_length = g
+        .edge_references()
+        .map(|e| distance(pos[&e.source()], pos[&e.target()]))
+        .sum::<f64>()
+        / g.edge_count() as f64;
+    let corners = distance(pos[&nodes[0]], pos[&nodes[99]]);
+    assert!(corners > 5. * edge_length, "{} {}", corners, edge_length);
+}
+
+#[test]
+fn kamada_kawai() {
+    let g = two_triangles();
+    let layout = KamadaKawai::new().seed(3);
+    let pos = layout.layout(&g);
+    assert_eq!(pos, layout.layout(&g));
+    // every edge has about the same length
+    let lengths = g
+        .edge_references()
+        .map(|e| distance(pos[&e.source()], pos[&e.target()]))
+        .collect::<Vec<_>>();
+    let shortest = lengths.iter().cloned().fold(std::f64::INFINITY, f64::min);
+    let longest = lengths.iter().cloned().fold(0., f64::max);
+    assert!(longest < 1.3 * shortest, "{:?}", lengths);
+
+    // a cycle is laid out as a circle
+    let cycle = DiGraph::<(), ()>::from_edges((0..12).map(|i| (i, (i + 1) % 12)));
+    let pos = KamadaKawai::new().layout(&cycle);
+    let radii = pos
+        .values()
+        .map(|&p| distance(p, [0.5, 0.5]))
+        .collect::<Vec<_>>();
+    assert!(radii.iter().all(|r| (r - 0.5).abs() < 0.02), "{:?}", radii);
+}
+
+#[test]
+fn kamada_kawai_edge_length() {
+    // a path with edges of lengths 1 and 3
+    let g = UnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 3.)]);
+    let pos = KamadaKawai::new().layout_with(&g, |e| *e.weight());
+    let n = NodeIndex::new;
+    let ratio = distance(pos[&n(1)], pos[&n(2)]) / distance(pos[&n(0)], pos[&n(1)]);
+    assert!((ratio - 3.).abs() < 1e-2, "{}", ratio);
+}
+
+/// Count the crossings of edges between consecutive layers.
+fn layered_crossings<N, E>(g: &DiGraph<N, E>, pos: &HashMap<NodeIndex, [f64; 2]>) -> usize {
+    let edges = g
+        .edge_references()
+        .map(|e| (pos[&e.source()], pos[&e.target()]))
+        .collect::<Vec<_>>();
+    let mut count = 0;
+    for (i, &(a, b)) in edges.iter().enumerate() {
+        for &(c, d) in &edges[i + 1..] {
+            if a[1] == c[1] && b[1] == d[1] && (a[0] - c[0]) * (b[0] - d[0]) < 0. {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+#[test]
+fn sugiyama() {
+    // two interleaved chains, and an edge spanning layers
+    let g =
+        DiGraph::<(), ()>::from_edges(&[(0, 3), (1, 2), (3, 5), (2, 4), (4, 6), (5, 7), (0, 7)]);
+    let pos = Sugiyama::new().layout(&g).unwrap();
+    for e in g.edge_references() {
+        assert!(pos[&e.source()][1] < pos[&e.target()][1]);
+    }
+    let layer = |i| pos[&NodeIndex::new(i)][1];
+    assert_eq!((layer(0), layer(3), layer(5), layer(7)), (0., 1., 2., 3.));
+    assert_eq!(layered_crossings(&g, &pos), 0);
+    // nodes in a layer are at least one apart
+    for a in g.node_indices() {
+        for b in g.node_indices() {
+            if a != b && pos[&a][1] == pos[&b][1] {
+                assert!((pos[&a][0] - pos[&b][0]).abs() >= 1. - 1e-9);
+            }
+        }
+    }
+    assert!(pos.values().any(|p| p[0] == 0.));
+
+    let pos = Sugiyama::new().width(1).layout(&g).unwrap();
+    let mut layers = pos.values().map(|p| p[1] as usize).collect::<Vec<_>>();
+    layers.sort();
+    assert_eq!(layers, (0..8).collect::<Vec<_>>());
+
+    let cycle = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 0)]);
+    assert!(Sugiyama::new().layout(&cycle).is_err());
+}
+
+#[test]
+fn simple_layouts() {
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4), (4, 5), (5, 6)]);
+    let n = NodeIndex::new;
+
+    let pos = circular_layout(&g);
+    assert_eq!(pos.len(), 7);
+    for (a, b) in g.node_indices().zip(g.node_indices().skip(1)) {
+        let side = distance(pos[&n(0)], pos[&n(1)]);
+        assert!((distance(pos[&a], pos[&b]) - side).abs() < 1e-9);
+    }
+
+    // the center, its neighbors, and the rest
+    let pos = shell_layout(&g, &[vec![n(0)], vec![n(1), n(2), n(3), n(4)]]);
+    assert_eq!(pos.len(), 7);
+    assert_eq!(pos[&n(0)], [0.5, 0.5]);
+    assert!((distance(pos[&n(2)], [0.5, 0.5]) - 0.25).abs() < 1e-9);
+    assert!((distance(pos[&n(6)], [0.5, 0.5]) - 0.5).abs() < 1e-9);
+
+    let pos = bipartite_layout(&g, vec![n(0), n(5)]);
+    assert_eq!(pos[&n(0)], [0., 0.]);
+    assert_eq!(pos[&n(5)], [0., 1.]);
+    assert_eq!(pos[&n(1)], [1., 0.]);
+    assert_eq!(pos[&n(6)], [1., 1.]);
+}
+
+#[cfg(feature = "spectral")]
+#[test]
+fn spectral_layout() {
+    // the first coordinate of a path is its Fiedler vector, which is monotone
+    let g = UnGraph::<(), ()>::from_edges((0..6).map(|i| (i, i + 1)));
+    let pos = petgraph::layout::spectral_layout(&g, 1000);
+    let x = (0..7)
+        .map(|i| pos[&NodeIndex::new(i)][0])
+        .collect::<Vec<_>>();
+    assert!(
+        x.windows(2).all(|w| w[0] < w[1]) || x.windows(2).all(|w| w[0] > w[1]),
+        "{:?}",
+        x
+    );
+}
@@ -0,0 +1,58 @@
+extern crate petgraph;
+
+use petgraph::linalg::{adjacency_matrix, incidence_matrix, laplacian_matrix};
+use petgraph::prelude::*;
+
+#[test]
+fn directed() {
+    let g = DiGraph::<(), f64>::from_edges(&[(0, 1, 2.), (1, 2, 3.), (1, 2, 1.), (2, 2, 5.)]);
+    let a: Vec<Vec<f64>> = adjacency_matrix(&g, |e| *e.weight());
+    assert_eq!(a, [[0., 2., 0.], [0., 0., 4.], [0., 0., 5.]]);
+    let b: Vec<Vec<f64>> = incidence_matrix(&g, |e| *e.weight());
+    assert_eq!(b, [[-2., 0., 0., 0.], [2., -3., -1., 0.], [0., 3., 1., 0.]]);
+    let l: Vec<Vec<f64>> = laplacian_matrix(&g, |e| *e.weight());
+    assert_eq!(l, [[2., -2., 0.], [0., 4., -4.], [0., 0., 0.]]);
+}
+
+#[test]
+fn undirected() {
+    let g = UnGraph::<(), f64>::from_edges(&[(0, 1, 2.), (1, 2, 3.), (2, 2, 5.)]);
+    let a: Vec<Vec<f64>> = adjacency_matrix(&g, |e| *e.weight());
+    assert_eq!(a, [[0., 2., 0.], [2., 0., 3.], [0., 3., 5.]]);
+    let b: Vec<Vec<f64>> = incidence_matrix(&g, |_| 1.);
+    assert_eq!(b, [[1., 0., 0.], [1., 1., 0.], [0., 1., 2.]]);
+    let l: Vec<Vec<f64>> = laplacian_matrix(&g, |e| *e.weight());
+    assert_eq!(l, [[2., -2., 0.], [-2., 5., -3.], [0., -3., 3.]]);
+    // the Laplacian is the product of the incidence matrix of any
+    // orientation with its transpose
+    let d = g.into_edge_type::<Directed>();
+    let b: Vec<Vec<f64>> = incidence_matrix(&d, |e| e.weight().sqrt());
+    for i in 0..3 {
+        for j in 0..3 {
+            let product = (0..2).map(|k| b[i][k] * b[j][k]).sum::<f64>();
+            assert!((product - l[i][j]).abs() < 1e-12);
+        }
+    }
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn sparse_and_holes() {
+    let mut g = StableUnGraph::<(), f64>::from_edges(&[(0, 1, 1.), (1, 2, 1.)]);
+    g.remove_node(NodeIndex::new(0));
+    let a: Vec<(usize, usize, f64)> = adjacency_matrix(&g, |e| *e.weight());
+    assert_eq!(a, [(1, 2, 1.), (2, 1, 1.)]);
+    let dense: Vec<Vec<f64>> = adjacency_matrix(&g, |e| *e.weight());
+    assert_eq!(dense.len(), 3);
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn ndarray_array() {
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+    let l: ndarray::Array2<f64> = laplacian_matrix(&g, |_| 1.);
+    assert_eq!(
+        l,
+        ndarray::arr2(&[[1., -1., 0.], [-1., 2., -1.], [0., -1., 1.]])
+    );
+}
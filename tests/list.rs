@@ -238,6 +238,83 @@ fn iterators_undir() {
     itertools::assert_equal(g.neighbors(b), vec![c]);
 }
 
+#[test]
+fn remove_node() {
+    let mut g = List::<i32>::new();
+    let a = g.add_node();
+    let b = g.add_node();
+    let c = g.add_node();
+    let d = g.add_node();
+    g.add_edge(a, b, 1);
+    let bd = g.add_edge(b, d, 2);
+    let bc = g.add_edge(b, c, 3);
+    g.add_edge(c, c, 4);
+    g.add_edge(d, a, 5);
+    g.add_edge(d, c, 6);
+
+    // `d` moves into the place of `b`
+    assert!(g.remove_node(b));
+    assert!(!g.remove_node(d));
+    assert_eq!(g.node_count(), 3);
+    assert_eq!(g.edge_count(), 3);
+    let d = b;
+    itertools::assert_equal(g.neighbors(a), vec![]);
+    itertools::assert_equal(g.neighbors(c), vec![c]);
+    itertools::assert_equal(g.neighbors(d), vec![a, c]);
+    assert_eq!(g.edge_endpoints(bd), Some((d, a)));
+    assert_eq!(g.edge_endpoints(bc), Some((d, c)));
+
+    // the last node
+    g.add_edge(a, d, 7);
+    assert!(g.remove_node(c));
+    itertools::assert_equal(g.neighbors(a), vec![d]);
+    itertools::assert_equal(g.neighbors(d), vec![a]);
+}
+
+#[test]
+fn remove_edge() {
+    let mut g = List::<i32>::new();
+    let a = g.add_node();
+    let b = g.add_node();
+    let ab = g.add_edge(a, b, 1);
+    let aa = g.add_edge(a, a, 2);
+    let ab2 = g.add_edge(a, b, 3);
+
+    assert_eq!(g.remove_edge(ab), Some(1));
+    // the last edge from `a` moves into the place of `ab`
+    assert_eq!(g.edge_weight(ab), Some(&3));
+    assert_eq!(g.edge_weight(aa), Some(&2));
+    assert_eq!(g.edge_weight(ab2), None);
+    assert_eq!(g.remove_edge(ab2), None);
+    assert_eq!(g.remove_edge(aa), Some(2));
+    assert_eq!(g.edge_count(), 1);
+}
+
+#[test]
+fn retain_nodes() {
+    let mut g = scc_graph();
+    let edge_count = g.edge_count();
+    g.retain_nodes(|a| a % 3 != 0);
+    assert_eq!(g.node_count(), 6);
+    let kept: Vec<DefaultIx> = (0..10).filter(|a| a % 3 != 0).collect();
+    let old = scc_graph();
+    let mut removed = 0;
+    for e in old.edge_references() {
+        match (
+            kept.iter().position(|&a| a == e.source()),
+            kept.iter().position(|&a| a == e.target()),
+        ) {
+            (Some(a), Some(b)) => assert!(g.contains_edge(n(a as _), n(b as _))),
+            _ => removed += 1,
+        }
+    }
+    assert_eq!(g.edge_count(), edge_count - removed);
+
+    g.retain_nodes(|_| false);
+    assert_eq!(g.node_count(), 0);
+    assert_eq!(g.edge_count(), 0);
+}
+
 #[test]
 fn dot() {
     let mut gr = List::new();
@@ -257,3 +334,60 @@ fn dot() {
 "#
     );
 }
+
+#[test]
+fn sorted_neighbors() {
+    let mut g = List::<i32>::new();
+    let a = g.add_node();
+    let b = g.add_node();
+    let c = g.add_node();
+    let d = g.add_node();
+    g.add_edge(a, d, 1);
+    g.add_edge(a, b, 2);
+    g.add_edge(a, d, 3);
+    g.add_edge(a, c, 4);
+    g.add_edge(b, c, 5);
+    g.add_edge(b, d, 6);
+    assert!(!g.is_sorted());
+    assert_eq!(g.find_edge(a, d).and_then(|e| g.edge_weight(e)), Some(&1));
+
+    g.sort_neighbors();
+    assert!(g.is_sorted());
+    assert_equal(g.neighbors(a), vec![b, c, d, d]);
+    // parallel edges keep their order
+    assert_equal(g.edges(a).map(|e| *e.weight()), vec![2, 4, 1, 3]);
+    assert_eq!(g.find_edge(a, d).and_then(|e| g.edge_weight(e)), Some(&1));
+    assert!(g.contains_edge(a, c));
+    assert!(!g.contains_edge(a, a));
+    assert!(!g.contains_edge(c, a));
+    assert_equal(g.common_neighbors(a, b), vec![c, d]);
+    assert_equal(g.common_neighbors(a, a), vec![b, c, d, d]);
+
+    // `d` moves into the place of `b`, and rows are kept sorted
+    g.add_edge(c, d, 7);
+    assert!(g.remove_node(b));
+    assert!(g.is_sorted());
+    assert_equal(g.neighbors(a), vec![b, b, c]);
+    assert_equal(g.neighbors(c), vec![b]);
+
+    // removing the first edge from `a` moves the last one out of order
+    let e = g.find_edge(a, b).unwrap();
+    g.remove_edge(e);
+    assert!(!g.is_sorted());
+    assert!(g.contains_edge(a, b));
+    g.sort_neighbors();
+    assert_equal(g.neighbors(a), vec![b, c]);
+    g.add_edge(a, a, 8);
+    assert!(!g.is_sorted());
+}
+
+#[test]
+#[should_panic]
+fn common_neighbors_unsorted() {
+    let mut g = List::<()>::new();
+    let a = g.add_node();
+    let b = g.add_node();
+    g.add_edge(a, b, ());
+    g.add_edge(a, a, ());
+    g.common_neighbors(a, b);
+}
@@ -0,0 +1,128 @@
+extern crate petgraph;
+
+use petgraph::matrix_market::{parse, MatrixMarket, ParseErrorKind};
+use petgraph::prelude::*;
+use petgraph::visit::EdgeRef;
+
+fn edges<Ty: petgraph::EdgeType>(graph: &Graph<(), f64, Ty>) -> Vec<(usize, usize, f64)> {
+    graph
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect()
+}
+
+#[test]
+fn write_and_read() {
+    let mut graph = DiGraph::<&str, f64>::new();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    graph.add_node("c");
+    graph.add_edge(b, a, 0.5);
+    graph.add_edge(a, a, -2.);
+
+    let mtx = MatrixMarket::new(&graph)
+        .with_edge_weights(&|_, edge| *edge.weight())
+        .to_string();
+    assert_eq!(
+        mtx,
+        "%%MatrixMarket matrix coordinate real general\n3 3 2\n2 1 0.5\n1 1 -2\n"
+    );
+    let read = parse::<Directed>(&mtx).unwrap();
+    assert_eq!(read.node_count(), 3);
+    assert_eq!(edges(&read), [(1, 0, 0.5), (0, 0, -2.)]);
+
+    let pattern = MatrixMarket::new(&graph).to_string();
+    assert_eq!(
+        pattern,
+        "%%MatrixMarket matrix coordinate pattern general\n3 3 2\n2 1\n1 1\n"
+    );
+    assert_eq!(
+        edges(&parse::<Directed>(&pattern).unwrap()),
+        [(1, 0, 1.), (0, 0, 1.)]
+    );
+}
+
+#[test]
+fn read() {
+    let symmetric = "%%MatrixMarket Matrix Coordinate Real Symmetric\r\n\
+                     % comment\n\
+                     \n\
+                     3 3 2\n\
+                     3 1 1.5e1\n\
+                     2 2 4\n";
+    assert_eq!(
+        edges(&parse::<Undirected>(symmetric).unwrap()),
+        [(2, 0, 15.), (1, 1, 4.)]
+    );
+    let skew = "%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n2 1 3\n";
+    assert_eq!(
+        edges(&parse::<Directed>(skew).unwrap()),
+        [(1, 0, 3.), (0, 1, -3.)]
+    );
+}
+
+#[test]
+fn errors() {
+    let error = |input: &str| {
+        let error = parse::<Directed>(input).unwrap_err();
+        (error.line(), error.kind().clone())
+    };
+    let header = "%%MatrixMarket matrix coordinate real general\n";
+    assert_eq!(error("3 3 1\n"), (1, ParseErrorKind::Header));
+    assert_eq!(
+        error("%%MatrixMarket matrix array real general\n"),
+        (1, ParseErrorKind::Unsupported("array".to_string()))
+    );
+    assert_eq!(
+        error("%%MatrixMarket matrix coordinate complex hermitian\n"),
+        (1, ParseErrorKind::Unsupported("complex".to_string()))
+    );
+    assert_eq!(
+        parse::<Undirected>(header).unwrap_err().kind(),
+        &ParseErrorKind::GraphType
+    );
+    assert_eq!(error(header), (1, ParseErrorKind::MissingField));
+    assert_eq!(
+        error(&format!("{}2 3 0\n", header)),
+        (2, ParseErrorKind::NotSquare)
+    );
+    assert_eq!(
+        error(&format!("{}5000000000 5000000000 0\n", header)),
+        (2, ParseErrorKind::TooManyNodes)
+    );
+    assert_eq!(
+        error(&format!("{}2 2 1\n1 3 1\n", header)),
+        (3, ParseErrorKind::OutOfBounds)
+    );
+    assert_eq!(
+        error(&format!("{}2 2 1\n1 2\n", header)),
+        (3, ParseErrorKind::MissingField)
+    );
+    assert_eq!(
+        error(&format!("{}2 2 1\n1 x 1\n", header)),
+        (3, ParseErrorKind::InvalidNumber("x".to_string()))
+    );
+    assert_eq!(
+        error(&format!("{}2 2 2\n1 2 1\n", header)),
+        (3, ParseErrorKind::EntryCount)
+    );
+    assert_eq!(
+        error(&format!("{}2 2 1\n1 2 1\n2 1 1\n", header)),
+        (4, ParseErrorKind::EntryCount)
+    );
+    // the size line does not allocate the nodes or edges before they are read
+    assert_eq!(
+        error(&format!("{}1 1 100000000000\n", header)),
+        (2, ParseErrorKind::EntryCount)
+    );
+    assert_eq!(
+        error(&format!("{}4000000000 4000000000 2\n1 2 1\n", header)),
+        (3, ParseErrorKind::EntryCount)
+    );
+    assert_eq!(
+        parse::<Directed>(&format!("{}2 2 1\n1 3 1\n", header))
+            .unwrap_err()
+            .to_string(),
+        "line 3: entry outside of the matrix"
+    );
+}
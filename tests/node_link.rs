@@ -0,0 +1,80 @@
+#![cfg(feature = "node_link")]
+
+extern crate petgraph;
+extern crate serde_json;
+
+use petgraph::node_link::{from_json_node_link, to_json_node_link, NodeLinkFormat};
+use petgraph::prelude::*;
+use petgraph::visit::EdgeRef;
+use petgraph::EdgeType;
+use serde_json::json;
+
+fn make_graph<Ty: EdgeType>() -> Graph<String, i32, Ty> {
+    let mut g = Graph::default();
+    let a = g.add_node("A".to_string());
+    let b = g.add_node("B".to_string());
+    let c = g.add_node("C".to_string());
+    g.extend_with_edges(&[(a, b, 7), (c, a, 9), (b, c, 10), (c, c, 2)]);
+    g
+}
+
+fn edges<N, E: Clone, Ty: EdgeType>(g: &Graph<N, E, Ty>) -> Vec<(usize, usize, E)> {
+    g.edge_references()
+        .map(|e| (e.source().index(), e.target().index(), e.weight().clone()))
+        .collect()
+}
+
+#[test]
+fn roundtrip() {
+    let format = NodeLinkFormat {
+        id: "name",
+        source: "from",
+        target: "to",
+        links: "edges",
+        weight: Some("w"),
+    };
+    let g1: UnGraph<_, _> = make_graph();
+    let data = to_json_node_link(&g1, format).unwrap();
+    assert_eq!(data["directed"], false);
+    assert_eq!(data["nodes"][1], json!({"name": 1, "w": "B"}));
+    assert_eq!(data["edges"][0], json!({"from": 0, "to": 1, "w": 7}));
+    let g2: UnGraph<String, i32> = from_json_node_link(data, format).unwrap();
+    assert_eq!(
+        g1.node_weights().collect::<Vec<_>>(),
+        g2.node_weights().collect::<Vec<_>>()
+    );
+    assert_eq!(edges(&g1), edges(&g2));
+
+    let g1 = make_graph::<Directed>().map(|_, _| (), |_, _| ());
+    let data = to_json_node_link(&g1, NodeLinkFormat::default()).unwrap();
+    assert_eq!(data["links"][0], json!({"source": 0, "target": 1}));
+    let g2: DiGraph<(), ()> = from_json_node_link(data, NodeLinkFormat::default()).unwrap();
+    assert_eq!(g1.node_count(), g2.node_count());
+    assert_eq!(edges(&g1), edges(&g2));
+}
+
+#[test]
+fn errors() {
+    let format = NodeLinkFormat::default();
+    let g = make_graph::<Directed>();
+    assert!(to_json_node_link(&g, format).is_err());
+
+    let read = |data: serde_json::Value| {
+        from_json_node_link::<(), (), Directed, u32>(data, format)
+            .unwrap_err()
+            .to_string()
+    };
+    assert_eq!(
+        read(json!({"directed": false, "nodes": [], "links": []})),
+        "expected a directed graph"
+    );
+    assert_eq!(read(json!({"nodes": []})), "missing field `links`");
+    assert_eq!(
+        read(json!({"nodes": [{"id": "a"}], "links": [{"source": "a", "target": "b"}]})),
+        "link to unknown node \"b\""
+    );
+    assert_eq!(
+        read(json!({"nodes": [{"id": 1}, {"id": 1}], "links": []})),
+        "duplicate node id"
+    );
+}
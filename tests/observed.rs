@@ -0,0 +1,202 @@
+extern crate petgraph;
+extern crate rand;
+
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::observed::{GraphObserver, ObservedGraph};
+use petgraph::visit::EdgeRef;
+use petgraph::Undirected;
+use rand::{Rng, SeedableRng, XorShiftRng};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Event {
+    NodeAdded(usize, i32),
+    NodeRemoved(usize, i32),
+    NodeMoved(usize, usize),
+    NodeWeightChanged(usize, i32, i32),
+    EdgeAdded(usize, usize, usize, i32),
+    EdgeRemoved(usize, usize, usize, i32),
+    EdgeMoved(usize, usize),
+    EdgeWeightChanged(usize, i32, i32),
+}
+
+#[derive(Default)]
+struct Log(Vec<Event>);
+
+impl GraphObserver<i32, i32> for Log {
+    fn node_added(&mut self, a: NodeIndex, weight: &i32) {
+        self.0.push(Event::NodeAdded(a.index(), *weight));
+    }
+    fn node_removed(&mut self, a: NodeIndex, weight: &i32) {
+        self.0.push(Event::NodeRemoved(a.index(), *weight));
+    }
+    fn node_moved(&mut self, from: NodeIndex, to: NodeIndex) {
+        self.0.push(Event::NodeMoved(from.index(), to.index()));
+    }
+    fn node_weight_changed(&mut self, a: NodeIndex, old: &i32, new: &i32) {
+        self.0.push(Event::NodeWeightChanged(a.index(), *old, *new));
+    }
+    fn edge_added(&mut self, e: EdgeIndex, a: NodeIndex, b: NodeIndex, weight: &i32) {
+        self.0
+            .push(Event::EdgeAdded(e.index(), a.index(), b.index(), *weight));
+    }
+    fn edge_removed(&mut self, e: EdgeIndex, a: NodeIndex, b: NodeIndex, weight: &i32) {
+        self.0
+            .push(Event::EdgeRemoved(e.index(), a.index(), b.index(), *weight));
+    }
+    fn edge_moved(&mut self, from: EdgeIndex, to: EdgeIndex) {
+        self.0.push(Event::EdgeMoved(from.index(), to.index()));
+    }
+    fn edge_weight_changed(&mut self, e: EdgeIndex, old: &i32, new: &i32) {
+        self.0.push(Event::EdgeWeightChanged(e.index(), *old, *new));
+    }
+}
+
+#[test]
+fn events() {
+    use Event::*;
+
+    let mut g = ObservedGraph::<i32, i32, Log>::default();
+    let a = g.add_node(0);
+    let b = g.add_node(1);
+    let c = g.add_node(2);
+    let ab = g.add_edge(a, b, 10);
+    g.add_edge(b, c, 11);
+    g.add_edge(c, a, 12);
+    assert_eq!(g.update_edge(a, b, 13), ab);
+    assert_eq!(g.set_node_weight(c, 3), Some(2));
+    assert_eq!(g.set_node_weight(NodeIndex::new(5), 3), None);
+    assert_eq!(g.remove_node(a), Some(0));
+    assert_eq!(g.remove_node(a), Some(3));
+    assert_eq!(g.remove_node(a), Some(1));
+    assert_eq!(g.remove_node(a), None);
+    assert_eq!(
+        g.observer().0,
+        vec![
+            NodeAdded(0, 0),
+            NodeAdded(1, 1),
+            NodeAdded(2, 2),
+            EdgeAdded(0, 0, 1, 10),
+            EdgeAdded(1, 1, 2, 11),
+            EdgeAdded(2, 2, 0, 12),
+            EdgeWeightChanged(0, 10, 13),
+            NodeWeightChanged(2, 2, 3),
+            // removing node 0 removes its edges, then moves node 2
+            EdgeRemoved(0, 0, 1, 13),
+            EdgeMoved(2, 0),
+            EdgeRemoved(0, 2, 0, 12),
+            EdgeMoved(1, 0),
+            NodeRemoved(0, 0),
+            NodeMoved(2, 0),
+            EdgeRemoved(0, 1, 0, 11),
+            NodeRemoved(0, 3),
+            NodeMoved(1, 0),
+            NodeRemoved(0, 1),
+        ]
+    );
+}
+
+#[test]
+fn clear() {
+    let mut g = ObservedGraph::<i32, i32, Log>::default();
+    let a = g.add_node(0);
+    let b = g.add_node(1);
+    g.add_edge(a, b, 10);
+    g.add_edge(b, a, 11);
+    g.observer_mut().0.clear();
+    g.clear();
+    assert_eq!(
+        g.observer().0,
+        vec![
+            Event::EdgeRemoved(1, 1, 0, 11),
+            Event::EdgeRemoved(0, 0, 1, 10),
+            Event::NodeRemoved(1, 1),
+            Event::NodeRemoved(0, 0),
+        ]
+    );
+}
+
+/// Copies of the node weights and of the edges, kept by the events alone.
+#[derive(Clone, Default)]
+struct Mirror {
+    nodes: Vec<i32>,
+    edges: Vec<(usize, usize, i32)>,
+}
+
+impl GraphObserver<i32, i32> for Mirror {
+    fn node_added(&mut self, a: NodeIndex, weight: &i32) {
+        assert_eq!(a.index(), self.nodes.len());
+        self.nodes.push(*weight);
+    }
+    fn node_removed(&mut self, a: NodeIndex, weight: &i32) {
+        assert_eq!(self.nodes.swap_remove(a.index()), *weight);
+    }
+    fn node_moved(&mut self, from: NodeIndex, to: NodeIndex) {
+        assert_eq!(from.index(), self.nodes.len());
+        for edge in &mut self.edges {
+            for x in &mut [&mut edge.0, &mut edge.1] {
+                if **x == from.index() {
+                    **x = to.index();
+                }
+            }
+        }
+    }
+    fn node_weight_changed(&mut self, a: NodeIndex, old: &i32, new: &i32) {
+        assert_eq!(std::mem::replace(&mut self.nodes[a.index()], *new), *old);
+    }
+    fn edge_added(&mut self, e: EdgeIndex, a: NodeIndex, b: NodeIndex, weight: &i32) {
+        assert_eq!(e.index(), self.edges.len());
+        self.edges.push((a.index(), b.index(), *weight));
+    }
+    fn edge_removed(&mut self, e: EdgeIndex, a: NodeIndex, b: NodeIndex, weight: &i32) {
+        let removed = self.edges.swap_remove(e.index());
+        assert_eq!(removed, (a.index(), b.index(), *weight));
+    }
+    fn edge_moved(&mut self, from: EdgeIndex, _: EdgeIndex) {
+        assert_eq!(from.index(), self.edges.len());
+    }
+    fn edge_weight_changed(&mut self, e: EdgeIndex, old: &i32, new: &i32) {
+        assert_eq!(std::mem::replace(&mut self.edges[e.index()].2, *new), *old);
+    }
+}
+
+#[test]
+fn random_mirror() {
+    let mut rng = XorShiftRng::seed_from_u64(2215);
+    let mut g = ObservedGraph::<i32, i32, (Mirror, Log), Undirected>::default();
+    for i in 0..2000 {
+        let n = g.node_count();
+        let m = g.edge_count();
+        match rng.gen_range(0, 6) {
+            0 | 1 => {
+                g.add_node(i);
+            }
+            2 if n > 0 => {
+                let a = NodeIndex::new(rng.gen_range(0, n));
+                let b = NodeIndex::new(rng.gen_range(0, n));
+                g.update_edge(a, b, i);
+            }
+            3 if n > 0 => {
+                g.remove_node(NodeIndex::new(rng.gen_range(0, n)));
+            }
+            4 if m > 0 => {
+                g.remove_edge(EdgeIndex::new(rng.gen_range(0, m)));
+            }
+            5 if n > 0 => {
+                g.set_node_weight(NodeIndex::new(rng.gen_range(0, n)), i);
+            }
+            _ if m > 0 => {
+                g.set_edge_weight(EdgeIndex::new(rng.gen_range(0, m)), i);
+            }
+            _ => {}
+        }
+        let mirror = &g.observer().0;
+        assert!(g.graph().node_weights().eq(&mirror.nodes));
+        let edges: Vec<_> = g
+            .graph()
+            .edge_references()
+            .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+            .collect();
+        assert_eq!(edges, mirror.edges);
+    }
+    assert!(!g.observer().1 .0.is_empty());
+}
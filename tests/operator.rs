@@ -1,4 +1,10 @@
-use petgraph::operator::complement;
+use petgraph::graph::node_index as n;
+use petgraph::operator::{
+    bipartite_double_cover, bipartite_projection, cartesian_product, complement, dedup_dag,
+    difference, difference_by_key, disjoint_union, induced_subgraph, intersection,
+    intersection_by_key, join, mycielskian, quotient_graph, split_into_components, tensor_product,
+    union_by_key,
+};
 use petgraph::prelude::*;
 use petgraph::Graph;
 
@@ -38,3 +44,491 @@ fn test_complement() {
         }
     }
 }
+
+#[test]
+fn test_complement_undirected() {
+    let graph = UnGraph::<u32, ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let mut output = UnGraph::new_undirected();
+    complement(&graph, &mut output, ());
+    assert_eq!(output.node_count(), 4);
+    assert_eq!(output.edge_count(), 2);
+    assert!(output.contains_edge(0.into(), 2.into()));
+    assert!(output.contains_edge(1.into(), 3.into()));
+}
+
+#[test]
+#[cfg(feature = "stable_graph")]
+fn test_complement_stable_graph() {
+    use petgraph::stable_graph::StableUnGraph;
+
+    let mut graph = StableUnGraph::<char, ()>::default();
+    let a = graph.add_node('a');
+    let b = graph.add_node('b');
+    let c = graph.add_node('c');
+    graph.add_node('d');
+    graph.extend_with_edges(&[(a, c)]);
+    graph.remove_node(b);
+
+    let mut output = UnGraph::new_undirected();
+    complement(&graph, &mut output, ());
+    let weights = output.node_weights().cloned().collect::<Vec<_>>();
+    assert_eq!(weights, ['a', 'c', 'd']);
+    assert_eq!(output.edge_count(), 2);
+    assert!(!output.contains_edge(0.into(), 1.into()));
+    assert!(output.contains_edge(1.into(), 2.into()));
+}
+
+#[test]
+fn test_disjoint_union() {
+    let a = DiGraph::<u32, u32>::from_edges(&[(0, 1, 10), (1, 1, 11)]);
+    let b = DiGraph::<u32, u32>::from_edges(&[(0, 1, 20), (0, 1, 21), (2, 0, 22)]);
+    let (union, a_nodes, b_nodes): (DiGraph<_, _>, _, _) = disjoint_union(&a, &b);
+    assert_eq!(union.node_count(), 5);
+    assert_eq!(union.edge_count(), 5);
+    assert_eq!(a_nodes.len(), 2);
+    assert_eq!(b_nodes.len(), 3);
+    for e in a.edge_references() {
+        let copy = union
+            .find_edge(a_nodes[&e.source()], a_nodes[&e.target()])
+            .unwrap();
+        assert_eq!(union[copy], *e.weight());
+    }
+    assert_eq!(
+        union
+            .edges_connecting(b_nodes[&0.into()], b_nodes[&1.into()])
+            .count(),
+        2
+    );
+    assert!(union.contains_edge(b_nodes[&2.into()], b_nodes[&0.into()]));
+    let mut copies = a_nodes.values().chain(b_nodes.values()).collect::<Vec<_>>();
+    copies.sort();
+    copies.dedup();
+    assert_eq!(copies.len(), 5);
+}
+
+#[test]
+fn test_union_by_key() {
+    let mut a = UnGraph::<(char, u32), u32>::default();
+    let a1 = a.add_node(('a', 1));
+    let b1 = a.add_node(('b', 1));
+    let c1 = a.add_node(('c', 1));
+    a.extend_with_edges(&[(a1, b1, 1), (b1, c1, 2)]);
+    let mut b = UnGraph::<(char, u32), u32>::default();
+    let b2 = b.add_node(('b', 2));
+    let c2 = b.add_node(('c', 2));
+    let d2 = b.add_node(('d', 2));
+    b.extend_with_edges(&[(c2, b2, 3), (c2, d2, 4)]);
+
+    let (union, a_nodes, b_nodes): (UnGraph<_, _>, _, _) = union_by_key(&a, &b, |w| w.0);
+    assert_eq!(union.node_count(), 4);
+    assert_eq!(union.edge_count(), 3);
+    assert_eq!(a_nodes[&b1], b_nodes[&b2]);
+    assert_eq!(union[b_nodes[&b2]], ('b', 1));
+    assert_eq!(union[b_nodes[&d2]], ('d', 2));
+    let e = union.find_edge(a_nodes[&b1], a_nodes[&c1]).unwrap();
+    assert_eq!(union[e], 3);
+}
+
+#[test]
+fn test_intersection_difference() {
+    let a = DiGraph::<u32, u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4)]);
+    let b = UnGraph::<u32, u32>::from_edges(&[(1, 0, 0), (3, 2, 0), (2, 4, 0)]);
+
+    let (both, nodes): (DiGraph<_, _>, _) = intersection(&a, &b);
+    assert_eq!(both.node_count(), 4);
+    let weights = both.edge_weights().cloned().collect::<Vec<_>>();
+    assert_eq!(weights, [1, 3]);
+    assert!(both.contains_edge(nodes[&2.into()], nodes[&3.into()]));
+
+    let (rest, nodes): (DiGraph<_, _>, _) = difference(&a, &b);
+    assert_eq!(rest.node_count(), 4);
+    let weights = rest.edge_weights().cloned().collect::<Vec<_>>();
+    assert_eq!(weights, [2, 4]);
+    assert!(rest.contains_edge(nodes[&3.into()], nodes[&0.into()]));
+
+    // a directed `b` only matches edges in the same direction
+    let b = DiGraph::<u32, u32>::from_edges(&[(1, 0, 0), (2, 3, 0)]);
+    let (both, _): (DiGraph<_, _>, _) = intersection(&a, &b);
+    assert_eq!(both.edge_weights().cloned().collect::<Vec<_>>(), [3]);
+
+    // nodes missing from `b` are left out of the intersection
+    let b = DiGraph::<u32, u32>::from_edges(&[(0, 1, 0)]);
+    let (both, nodes): (DiGraph<_, _>, _) = intersection(&a, &b);
+    assert_eq!((both.node_count(), both.edge_count()), (2, 1));
+    assert!(!nodes.contains_key(&2.into()));
+}
+
+#[test]
+fn test_intersection_difference_by_key() {
+    let mut a = UnGraph::<&str, ()>::new_undirected();
+    let x = a.add_node("x");
+    let y = a.add_node("y");
+    let z = a.add_node("z");
+    a.extend_with_edges(&[(x, y), (y, z), (z, x)]);
+    let mut b = UnGraph::<&str, ()>::new_undirected();
+    let w2 = b.add_node("w");
+    let z2 = b.add_node("z");
+    let x2 = b.add_node("x");
+    b.extend_with_edges(&[(w2, x2), (x2, z2)]);
+
+    let (both, nodes): (UnGraph<_, _>, _) = intersection_by_key(&a, &b, |&w| w);
+    assert_eq!(both.node_count(), 2);
+    assert_eq!(both.edge_count(), 1);
+    assert!(both.contains_edge(nodes[&x], nodes[&z]));
+    assert!(!nodes.contains_key(&y));
+
+    let (rest, nodes): (UnGraph<_, _>, _) = difference_by_key(&a, &b, |&w| w);
+    assert_eq!(rest.node_count(), 3);
+    assert_eq!(rest.edge_count(), 2);
+    assert!(!rest.contains_edge(nodes[&x], nodes[&z]));
+}
+
+#[test]
+fn test_cartesian_product() {
+    // a directed triangle times a directed edge
+    let a = DiGraph::<char, u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 0, 3)]);
+    let b = DiGraph::<char, u32>::from_edges(&[(0, 1, 10)]);
+    let (prism, nodes): (DiGraph<_, _>, _) = cartesian_product(&a, &b);
+    assert_eq!(prism.node_count(), 6);
+    assert_eq!(prism.edge_count(), 3 * 2 + 3);
+    for u in a.node_indices() {
+        for v in b.node_indices() {
+            assert_eq!(prism[nodes[&(u, v)]], (a[u], b[v]));
+        }
+        let e = prism
+            .find_edge(nodes[&(u, 0.into())], nodes[&(u, 1.into())])
+            .unwrap();
+        assert_eq!(prism[e], 10);
+    }
+    for e in a.edge_references() {
+        for v in b.node_indices() {
+            let copy = prism
+                .find_edge(nodes[&(e.source(), v)], nodes[&(e.target(), v)])
+                .unwrap();
+            assert_eq!(prism[copy], *e.weight());
+        }
+    }
+
+    // the product with an empty graph is empty
+    let (empty, _): (DiGraph<_, _>, _) = cartesian_product(&a, &DiGraph::<char, u32>::new());
+    assert_eq!(empty.node_count(), 0);
+}
+
+#[test]
+fn test_tensor_product() {
+    // K2 × K3 is the 6-cycle
+    let k2 = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+    let k3 = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+    let (g, _): (UnGraph<_, _>, _) = tensor_product(&k2, &k3);
+    assert_eq!(g.node_count(), 6);
+    assert_eq!(g.edge_count(), 6);
+    assert!(g.node_indices().all(|a| g.neighbors(a).count() == 2));
+    assert!(petgraph::algo::bipartite_partition(&g).is_ok());
+    assert_eq!(petgraph::algo::connected_components(&g), 1);
+
+    // a self loop in one factor gives no repeated edges
+    let looped = UnGraph::<(), ()>::from_edges(&[(0, 0)]);
+    let (g, _): (UnGraph<_, _>, _) = tensor_product(&looped, &k3);
+    assert_eq!(g.edge_count(), 3);
+
+    // directed factors give one edge per pair of edges
+    let a = DiGraph::<(), u8>::from_edges(&[(0, 1, 1), (1, 2, 2)]);
+    let b = DiGraph::<(), u8>::from_edges(&[(0, 1, 3), (1, 0, 4)]);
+    let (g, nodes): (DiGraph<_, _>, _) = tensor_product(&a, &b);
+    assert_eq!(g.edge_count(), 4);
+    let e = g
+        .find_edge(nodes[&(1.into(), 1.into())], nodes[&(2.into(), 0.into())])
+        .unwrap();
+    assert_eq!(g[e], (2, 4));
+
+    // an undirected factor counts as both directions
+    let (g, nodes): (DiGraph<_, _>, _) = tensor_product(&a, &k2);
+    assert_eq!(g.edge_count(), 4);
+    assert!(g.contains_edge(nodes[&(0.into(), 1.into())], nodes[&(1.into(), 0.into())]));
+    let (g, nodes): (DiGraph<_, _>, _) = tensor_product(&k2, &a);
+    assert_eq!(g.edge_count(), 4);
+    assert!(g.contains_edge(nodes[&(1.into(), 0.into())], nodes[&(0.into(), 1.into())]));
+
+    // a directed self loop times an undirected edge gives both directions
+    let a = DiGraph::<(), u8>::from_edges(&[(0, 0, 1)]);
+    let (g, _): (DiGraph<_, _>, _) = tensor_product(&k2, &a);
+    assert_eq!(g.edge_count(), 2);
+}
+
+#[test]
+fn test_quotient_graph() {
+    // classes {0, 3}, {1, 4}, {2}
+    let g = DiGraph::<u32, u32>::from_edges(&[
+        (0, 1, 1),
+        (3, 4, 2),
+        (0, 3, 3),
+        (1, 2, 4),
+        (4, 2, 5),
+        (2, 0, 6),
+    ]);
+    let (q, class): (DiGraph<_, _>, _) = quotient_graph(&g, |n| n.index() % 3, false);
+    assert_eq!(q.node_count(), 3);
+    assert_eq!(q.edge_count(), 6);
+    assert_eq!(q[class[&n(3)]].len(), 2);
+    assert_eq!(class[&n(0)], class[&n(3)]);
+    assert_eq!(q.edges_connecting(class[&n(0)], class[&n(1)]).count(), 2);
+    assert!(q.contains_edge(class[&n(0)], class[&n(0)]));
+
+    let (q, class): (DiGraph<_, _>, _) = quotient_graph(&g, |n| n.index() % 3, true);
+    assert_eq!(q.edge_count(), 4);
+    let e = q.find_edge(class[&n(1)], class[&n(2)]).unwrap();
+    assert_eq!(q[e], 5);
+
+    // a single class
+    let (q, _): (DiGraph<_, _>, _) = quotient_graph(&g, |_| (), true);
+    assert_eq!((q.node_count(), q.edge_count()), (1, 1));
+}
+
+#[test]
+fn test_mycielskian() {
+    use petgraph::algo::is_isomorphic;
+
+    fn has_triangle(g: &UnGraph<(), ()>) -> bool {
+        g.edge_references().any(|e| {
+            g.neighbors(e.source())
+                .any(|c| c != e.target() && g.contains_edge(c, e.target()))
+        })
+    }
+
+    let edge = UnGraph::<(), ()>::from_edges(&[(0, 1)]);
+    let c5: UnGraph<(), ()> = mycielskian(&edge);
+    let cycle = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+    assert!(is_isomorphic(&c5, &cycle));
+
+    let grotzsch: UnGraph<(), ()> = mycielskian(&c5);
+    assert_eq!(grotzsch.node_count(), 11);
+    assert_eq!(grotzsch.edge_count(), 20);
+    assert!(!has_triangle(&grotzsch));
+
+    // the hub is the last node, adjacent to the shadows
+    assert_eq!(grotzsch.neighbors(10.into()).count(), 5);
+    assert!(grotzsch
+        .neighbors(10.into())
+        .all(|a| (5..10).contains(&a.index())));
+}
+
+#[test]
+fn test_induced_subgraph() {
+    let mut g = DiGraph::<u32, u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 0, 3), (2, 3, 4)]);
+    g.add_edge(n(1), n(1), 5);
+    g.add_edge(n(1), n(2), 6);
+    for a in g.node_indices() {
+        g[a] = a.index() as u32;
+    }
+
+    let (sub, new, old): (DiGraph<_, _>, _, _) = induced_subgraph(&g, vec![n(3), n(2), n(1), n(2)]);
+    assert_eq!(sub.node_count(), 3);
+    assert_eq!(sub.edge_count(), 4);
+    // the nodes keep their order in `g`
+    assert_eq!(
+        sub.raw_nodes().iter().map(|n| n.weight).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    for (&a, &x) in &new {
+        assert_eq!(old[&x], a);
+        assert_eq!(sub[x], g[a]);
+    }
+    assert!(!new.contains_key(&n(0)));
+    assert_eq!(sub.edges_connecting(new[&n(1)], new[&n(2)]).count(), 2);
+    assert!(sub.contains_edge(new[&n(1)], new[&n(1)]));
+
+    let (empty, new, old): (DiGraph<u32, u32>, _, _) = induced_subgraph(&g, vec![n(7)]);
+    assert_eq!(empty.node_count(), 0);
+    assert!(new.is_empty() && old.is_empty());
+}
+
+#[test]
+fn test_split_into_components() {
+    // components {0, 1, 3}, {2}, {4, 5}
+    let mut g = DiGraph::<u32, u32>::from_edges(&[(1, 0, 1), (3, 1, 2), (4, 5, 3), (5, 4, 4)]);
+    g.add_edge(n(2), n(2), 5);
+    for a in g.node_indices() {
+        g[a] = a.index() as u32;
+    }
+
+    let components: Vec<(DiGraph<_, _>, _)> = split_into_components(&g);
+    let sizes = components
+        .iter()
+        .map(|(c, _)| (c.node_count(), c.edge_count()))
+        .collect::<Vec<_>>();
+    assert_eq!(sizes, vec![(3, 2), (1, 1), (2, 2)]);
+    for (c, original) in &components {
+        assert_eq!(original.len(), c.node_count());
+        for (&x, &a) in original {
+            assert_eq!(c[x], g[a]);
+        }
+        for e in c.edge_references() {
+            let (a, b) = (original[&e.source()], original[&e.target()]);
+            assert!(g.edges_connecting(a, b).any(|f| f.weight() == e.weight()));
+        }
+    }
+    assert_eq!(components[2].1[&n(0)], n(4));
+
+    let empty: Vec<(DiGraph<u32, u32>, _)> = split_into_components(&DiGraph::<u32, u32>::new());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_join() {
+    let a = UnGraph::<(), u32>::from_edges(&[(0, 1, 1)]);
+    let b = UnGraph::<(), u32>::from_edges(&[(0, 1, 2), (1, 2, 3)]);
+    let (j, a_nodes, b_nodes): (UnGraph<_, _>, _, _) = join(&a, &b, 0);
+    assert_eq!(j.node_count(), 5);
+    assert_eq!(j.edge_count(), 3 + 6);
+    for x in a.node_indices() {
+        for y in b.node_indices() {
+            let e = j.find_edge(a_nodes[&x], b_nodes[&y]).unwrap();
+            assert_eq!(j[e], 0);
+        }
+    }
+    let e = j.find_edge(b_nodes[&n(1)], b_nodes[&n(2)]).unwrap();
+    assert_eq!(j[e], 3);
+
+    // directed joins get both arcs
+    let a = DiGraph::<(), u32>::from_edges(&[(0, 1, 1)]);
+    let mut b = DiGraph::<(), u32>::new();
+    let c = b.add_node(());
+    let (j, a_nodes, b_nodes): (DiGraph<_, _>, _, _) = join(&a, &b, 0);
+    assert_eq!(j.edge_count(), 1 + 4);
+    assert!(j.contains_edge(a_nodes[&n(1)], b_nodes[&c]));
+    assert!(j.contains_edge(b_nodes[&c], a_nodes[&n(1)]));
+
+    // joining with an empty graph adds no edges
+    let (j, _, _): (DiGraph<_, _>, _, _) = join(&a, &DiGraph::<(), u32>::new(), 0);
+    assert_eq!(j.edge_count(), 1);
+}
+
+#[test]
+fn test_bipartite_projection() {
+    // authors 0..3 and papers 3..6, author 1 on paper 3 twice
+    let mut g = UnGraph::<(), u32>::from_edges(&[
+        (0, 3, 1),
+        (4, 1, 2),
+        (1, 3, 3),
+        (2, 4, 4),
+        (5, 0, 5),
+        (1, 3, 6),
+    ]);
+    // ignored: an edge between two authors
+    g.add_edge(n(0), n(2), 7);
+    let authors = vec![n(2), n(1), n(0)];
+
+    let (p, nodes): (UnGraph<(), Vec<(u32, u32)>>, _) =
+        bipartite_projection(&g, authors, vec![], |w: &mut Vec<_>, e1, e2| {
+            w.push((*e1.weight(), *e2.weight()))
+        });
+    assert_eq!(p.node_count(), 3);
+    assert_eq!(p.edge_count(), 2);
+    let e = p.find_edge(nodes[&n(0)], nodes[&n(1)]).unwrap();
+    let mut w = p[e].clone();
+    w.sort();
+    assert_eq!(w, vec![(1, 3), (1, 6)]);
+    // each edge goes from the earlier node to the later one
+    let e = p.find_edge(nodes[&n(1)], nodes[&n(2)]).unwrap();
+    assert_eq!(p.edge_endpoints(e), Some((nodes[&n(1)], nodes[&n(2)])));
+    assert_eq!(p[e], vec![(2, 4)]);
+    assert!(!p.contains_edge(nodes[&n(0)], nodes[&n(2)]));
+
+    // the other side
+    let (p, nodes): (UnGraph<(), usize>, _) =
+        bipartite_projection(&g, vec![n(3), n(4), n(5)], 0, |w, _, _| *w += 1);
+    assert_eq!(p.edge_count(), 2);
+    let e = p.find_edge(nodes[&n(3)], nodes[&n(4)]).unwrap();
+    assert_eq!(p[e], 2);
+    let e = p.find_edge(nodes[&n(3)], nodes[&n(5)]).unwrap();
+    assert_eq!(p[e], 1);
+
+    // directed: common successors
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 2), (1, 2), (3, 0)]);
+    let (p, _): (DiGraph<(), usize>, _) =
+        bipartite_projection(&g, vec![n(0), n(1), n(3)], 0, |w, _, _| *w += 1);
+    assert_eq!(p.edge_count(), 1);
+    assert!(p.contains_edge(n(0), n(1)));
+}
+
+#[test]
+fn test_bipartite_double_cover() {
+    use petgraph::algo::{connected_components, is_bipartite_undirected};
+
+    // a square is bipartite: its cover is two squares
+    let square = UnGraph::<u32, u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4)]);
+    let (cover, copies): (UnGraph<_, _>, _) = bipartite_double_cover(&square);
+    assert_eq!((cover.node_count(), cover.edge_count()), (8, 8));
+    assert_eq!(connected_components(&cover), 2);
+    let (x, _) = copies[&n(0)];
+    assert!(is_bipartite_undirected(&cover, x));
+    let e = cover.find_edge(copies[&n(2)].1, copies[&n(3)].0).unwrap();
+    assert_eq!(cover[e], 3);
+    // first copies, then second copies
+    assert_eq!(copies[&n(3)], (n(3), n(7)));
+
+    // a self loop becomes a single edge between the copies
+    let mut g = UnGraph::<(), ()>::new_undirected();
+    let a = g.add_node(());
+    g.add_edge(a, a, ());
+    let (cover, copies): (UnGraph<_, _>, _) = bipartite_double_cover(&g);
+    assert_eq!(cover.edge_count(), 1);
+    assert!(cover.contains_edge(copies[&a].0, copies[&a].1));
+
+    // directed edges keep their direction
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 1)]);
+    let (cover, copies): (DiGraph<_, _>, _) = bipartite_double_cover(&g);
+    assert_eq!(cover.edge_count(), 4);
+    assert!(cover.contains_edge(copies[&n(0)].0, copies[&n(1)].1));
+    assert!(cover.contains_edge(copies[&n(0)].1, copies[&n(1)].0));
+    assert!(!cover.contains_edge(copies[&n(1)].0, copies[&n(0)].1));
+    assert!(cover.contains_edge(copies[&n(1)].1, copies[&n(1)].0));
+}
+
+#[test]
+fn test_dedup_dag() {
+    use petgraph::algo::is_isomorphic_matching;
+
+    // two copies of the same diamond below a root, and a third differing in
+    // an edge weight
+    let mut g = DiGraph::<char, u32>::new();
+    let root = g.add_node('r');
+    let mut tops = Vec::new();
+    for &w in &[0, 0, 1] {
+        let top = g.add_node('a');
+        let left = g.add_node('b');
+        let right = g.add_node('b');
+        let bottom = g.add_node('c');
+        g.extend_with_edges(&[
+            (root, top, 0),
+            (top, left, 0),
+            (top, right, 0),
+            (left, bottom, 0),
+            (right, bottom, w),
+        ]);
+        tops.push(top);
+    }
+
+    let (dag, class): (DiGraph<_, _>, _) = dedup_dag(&g).unwrap();
+    assert_eq!(class.len(), g.node_count());
+    assert_eq!(class[&tops[0]], class[&tops[1]]);
+    assert_ne!(class[&tops[0]], class[&tops[2]]);
+    // the root, the two kinds of tops, the two kinds of sides, and a single
+    // bottom; parallel edges are kept
+    assert_eq!(dag.node_count(), 6);
+    assert_eq!(dag.edge_count(), 3 + 2 + 2 + 1 + 1);
+    assert_eq!(dag[class[&root]], 'r');
+    assert_eq!(dag.edges(class[&root]).count(), 3);
+
+    // deduplicating again changes nothing
+    let (again, _): (DiGraph<_, _>, _) = dedup_dag(&dag).unwrap();
+    assert!(is_isomorphic_matching(
+        &dag,
+        &again,
+        |a, b| a == b,
+        |a, b| a == b
+    ));
+
+    g.add_edge(tops[2], root, 0);
+    assert!(dedup_dag::<_, DiGraph<_, _>>(&g).is_err());
+}
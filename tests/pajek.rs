@@ -0,0 +1,118 @@
+extern crate petgraph;
+
+use petgraph::pajek::{parse, Pajek, ParseErrorKind};
+use petgraph::prelude::*;
+use petgraph::visit::EdgeRef;
+
+#[test]
+fn write_and_read() {
+    let mut graph = UnGraph::<&str, f64>::new_undirected();
+    let a = graph.add_node("say \"hi\"");
+    let b = graph.add_node("b");
+    graph.add_edge(a, b, 0.5);
+    graph.add_edge(b, b, 3.);
+
+    let pajek = Pajek::new(&graph)
+        .with_edge_weights(&|_, edge| *edge.weight())
+        .to_string();
+    assert_eq!(
+        pajek,
+        "*Vertices 2\n1 \"say 'hi'\"\n2 \"b\"\n*Edges\n1 2 0.5\n2 2 3\n"
+    );
+    let parsed = parse::<Undirected>(&pajek).unwrap();
+    assert_eq!(parsed.name, None);
+    let g = parsed.graph;
+    assert_eq!(g[a].label, "say 'hi'");
+    assert_eq!(g[b].position, None);
+    let edges = g
+        .edge_references()
+        .map(|e| (e.source(), e.target(), *e.weight()))
+        .collect::<Vec<_>>();
+    assert_eq!(edges, [(a, b, 0.5), (b, b, 3.)]);
+}
+
+#[test]
+fn read() {
+    let parsed = parse::<Directed>(
+        "% a comment\n\
+         *network \"two words\"\n\
+         *Vertices 4\r\n\
+         1 \"a b\" 0.1 0.2 0.3 ic Red\n\
+         3 c 0.5\n\
+         \n\
+         *Arcs :1 \"relation\"\n\
+         1 2 -1.5 c Blue\n\
+         *Edges\n\
+         3 4\n\
+         *Arcslist\n\
+         4 1 2\n",
+    )
+    .unwrap();
+    assert_eq!(parsed.name.as_deref(), Some("two words"));
+    let g = parsed.graph;
+    let labels = g.node_weights().map(|n| &*n.label).collect::<Vec<_>>();
+    assert_eq!(labels, ["a b", "2", "c", "4"]);
+    assert_eq!(g[NodeIndex::new(0)].position, Some([0.1, 0.2]));
+    assert_eq!(g[NodeIndex::new(2)].position, None);
+    let edges = g
+        .edge_references()
+        .map(|e| (e.source().index(), e.target().index(), *e.weight()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        edges,
+        [(0, 1, -1.5), (2, 3, 1.), (3, 2, 1.), (3, 0, 1.), (3, 1, 1.)]
+    );
+}
+
+#[test]
+fn errors() {
+    let error = |input: &str| {
+        let error = parse::<Undirected>(input).unwrap_err();
+        (error.line(), error.kind().clone())
+    };
+    assert_eq!(error("1 2\n"), (1, ParseErrorKind::MissingSection));
+    assert_eq!(
+        error("*Vertices 2\n*Arcs\n"),
+        (2, ParseErrorKind::GraphType)
+    );
+    assert_eq!(
+        error("*Vertices 2\n*Matrix\n"),
+        (2, ParseErrorKind::UnknownSection("*Matrix".to_string()))
+    );
+    assert_eq!(error("*Vertices\n"), (1, ParseErrorKind::MissingField));
+    assert_eq!(
+        error("*Vertices x\n"),
+        (1, ParseErrorKind::InvalidNumber("x".to_string()))
+    );
+    assert_eq!(
+        error("*Vertices 5000000000\n"),
+        (1, ParseErrorKind::TooManyVertices)
+    );
+    // the vertex count alone does not allocate the vertices
+    assert_eq!(
+        error("*Vertices 4000000000\n*Edges\n1 2 x\n"),
+        (3, ParseErrorKind::InvalidNumber("x".to_string()))
+    );
+    assert_eq!(
+        error("*Vertices 2\n*Edges\n1 3\n"),
+        (3, ParseErrorKind::UnknownVertex("3".to_string()))
+    );
+    assert_eq!(
+        error("*Vertices 2\n*Edges\n1 2 heavy\n"),
+        (3, ParseErrorKind::InvalidNumber("heavy".to_string()))
+    );
+    assert_eq!(
+        error("*Vertices 2\n*Edges\n1\n"),
+        (3, ParseErrorKind::MissingField)
+    );
+    assert_eq!(
+        error("*Vertices 2\n1 \"a\n"),
+        (2, ParseErrorKind::UnterminatedString)
+    );
+    assert_eq!(
+        parse::<Undirected>("*Vertices 2\n1 \"a\n")
+            .unwrap_err()
+            .to_string(),
+        "line 2: unterminated string"
+    );
+}
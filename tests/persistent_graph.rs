@@ -0,0 +1,154 @@
+extern crate petgraph;
+
+use petgraph::algo::{dijkstra, toposort};
+use petgraph::graph::{DiGraph, EdgeIndex, Graph, NodeIndex, UnGraph};
+use petgraph::persistent_graph::{DiPersistentGraph, PersistentGraph, UnPersistentGraph};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
+use petgraph::{Directed, EdgeType, Incoming, Outgoing, Undirected};
+
+fn edges_of<E: Copy>(
+    edges: impl Iterator<Item = impl EdgeRef<NodeId = NodeIndex, EdgeId = EdgeIndex, Weight = E>>,
+) -> Vec<(usize, usize, usize, E)> {
+    edges
+        .map(|e| {
+            (
+                e.id().index(),
+                e.source().index(),
+                e.target().index(),
+                *e.weight(),
+            )
+        })
+        .collect()
+}
+
+/// Check that `p` has the same nodes and edges as `g`, listed in the same
+/// order.
+fn assert_same<Ty: EdgeType>(p: &PersistentGraph<u32, u32, Ty>, g: &Graph<u32, u32, Ty>) {
+    assert_eq!(p.node_count(), g.node_count());
+    assert_eq!(p.edge_count(), g.edge_count());
+    assert!(p.node_references().eq(g.node_references()));
+    assert_eq!(edges_of(p.edge_references()), edges_of(g.edge_references()));
+    for a in g.node_indices() {
+        for &dir in &[Outgoing, Incoming] {
+            assert!(p
+                .neighbors_directed(a, dir)
+                .eq(g.neighbors_directed(a, dir)));
+            assert_eq!(
+                edges_of(p.edges_directed(a, dir)),
+                edges_of(g.edges_directed(a, dir))
+            );
+        }
+        for b in g.node_indices() {
+            assert_eq!(p.find_edge(a, b), g.find_edge(a, b));
+        }
+    }
+}
+
+/// Add random nodes and edges to a `PersistentGraph` and a `Graph`, checking
+/// that they agree and that the old versions are unchanged.
+fn agrees_with_graph<Ty: EdgeType>() {
+    let mut seed = 7u32;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed
+    };
+    let mut p = PersistentGraph::<u32, u32, Ty>::default();
+    let mut g = Graph::<u32, u32, Ty>::default();
+    let mut versions = Vec::new();
+    for i in 0..2000 {
+        if g.node_count() == 0 || next() % 4 == 0 {
+            let (q, a) = p.add_node(i);
+            assert_eq!(a, g.add_node(i));
+            p = q;
+        } else {
+            let n = g.node_count() as u32;
+            let a = NodeIndex::new((next() % n) as usize);
+            // self loops now and then
+            let b = if next() % 10 == 0 {
+                a
+            } else {
+                NodeIndex::new((next() % n) as usize)
+            };
+            let (q, e) = p.add_edge(a, b, i);
+            assert_eq!(e, g.add_edge(a, b, i));
+            p = q;
+        }
+        if i % 200 == 0 {
+            versions.push((p.clone(), g.clone()));
+        }
+    }
+    assert_same(&p, &g);
+    for (p, g) in &versions {
+        assert_same(p, g);
+    }
+}
+
+#[test]
+fn agrees_with_graph_directed() {
+    agrees_with_graph::<Directed>();
+}
+
+#[test]
+fn agrees_with_graph_undirected() {
+    agrees_with_graph::<Undirected>();
+}
+
+#[test]
+fn versions() {
+    let (g, a) = DiPersistentGraph::<&str, u32>::new().add_node("a");
+    let (g, b) = g.add_node("b");
+    let (v1, ab) = g.add_edge(a, b, 1);
+    let (v2, c) = v1.add_node("c");
+    let (v2, bc) = v2.add_edge(b, c, 2);
+    let v3 = v2.with_node_weight(a, "A").with_edge_weight(ab, 10);
+
+    assert_eq!(g.edge_count(), 0);
+    assert_eq!(g.neighbors(a).count(), 0);
+    assert_eq!((v1.node_count(), v1.edge_count()), (2, 1));
+    assert_eq!(v1.node_weight(c), None);
+    assert_eq!(v1.neighbors_directed(b, Outgoing).count(), 0);
+    assert_eq!(v2.edge_endpoints(bc), Some((b, c)));
+    assert_eq!(v2.neighbors(b).collect::<Vec<_>>(), vec![c]);
+    assert_eq!((v2[a], v2[ab]), ("a", 1));
+    assert_eq!((v3[a], v3[ab]), ("A", 10));
+    assert_eq!(v3.edge_weight(bc), Some(&2));
+}
+
+#[test]
+#[should_panic]
+fn add_edge_missing_node() {
+    let (g, a) = UnPersistentGraph::<(), ()>::new().add_node(());
+    g.add_edge(a, NodeIndex::new(1), ());
+}
+
+#[test]
+fn conversions() {
+    let mut g = UnGraph::<u32, u32>::new_undirected();
+    let a = g.add_node(0);
+    let b = g.add_node(1);
+    g.add_edge(a, b, 2);
+    g.add_edge(b, b, 3);
+    let p = PersistentGraph::from(g.clone());
+    assert_same(&p, &g);
+    let (q, c) = p.add_node(4);
+    let (q, _) = q.add_edge(c, a, 5);
+    g.add_node(4);
+    g.add_edge(c, a, 5);
+    assert_same(&q, &g);
+    assert_same(&q, &q.to_graph());
+}
+
+#[test]
+fn algorithms() {
+    let g = DiGraph::<(), u32>::from_edges(&[(0, 1, 4), (0, 2, 1), (2, 1, 1), (1, 3, 1)]);
+    let p = PersistentGraph::from(g);
+    let n = NodeIndex::new;
+    let dist = dijkstra(&p, n(0), None, |e| *e.weight());
+    assert_eq!(dist[&n(3)], 3);
+    assert_eq!(toposort(&p, None), Ok(vec![n(0), n(2), n(1), n(3)]));
+    let (q, _) = p.add_edge(n(3), n(0), 1);
+    assert!(toposort(&q, None).is_err());
+    assert!(toposort(&p, None).is_ok());
+}
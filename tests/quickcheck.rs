@@ -8,35 +8,57 @@ extern crate defmac;
 
 extern crate itertools;
 extern crate odds;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 
 mod utils;
 
 use utils::{Small, Tournament};
 
 use odds::prelude::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
+use fixedbitset::FixedBitSet;
 use itertools::assert_equal;
 use itertools::cloned;
 use quickcheck::{Arbitrary, Gen};
 use rand::Rng;
 
+#[cfg(feature = "rayon")]
+use petgraph::algo::delta_stepping;
+#[cfg(feature = "rand")]
+use petgraph::algo::luby_maximal_independent_set;
 use petgraph::algo::{
-    bellman_ford, condensation, dijkstra, find_negative_cycle, floyd_warshall,
-    greedy_feedback_arc_set, greedy_matching, is_cyclic_directed, is_cyclic_undirected,
-    is_isomorphic, is_isomorphic_matching, k_shortest_path, kosaraju_scc, maximum_matching,
-    min_spanning_tree, tarjan_scc, toposort, Matching,
+    astar, astar_indexed, automorphism_generators, bellman_ford, betweenness_centrality,
+    bidirectional_bfs, bipartite_partition, coffman_graham, condensation, connected_components,
+    count_walks, degeneracy_ordering, dijkstra, dijkstra_indexed, find_negative_cycle,
+    floyd_warshall, graph_diff, graph_hash, graph_hash_with, greedy_color, greedy_color_with,
+    greedy_dominating_set, greedy_feedback_arc_set, greedy_matching, greedy_spanner,
+    greedy_weighted_dominating_set, has_path_connecting, is_cyclic_directed, is_cyclic_undirected,
+    is_isomorphic, is_isomorphic_matching, k_shortest_path, kosaraju_scc, lex_bfs, max_antichain,
+    max_weight_closure, maximal_cliques_iter, maximal_independent_set, maximum_flow,
+    maximum_matching, min_chain_cover, min_spanning_tree, min_spanning_tree_edges, minimum_cut,
+    node_orbits, page_rank, reverse_cuthill_mckee, subgraph_isomorphisms_iter,
+    subgraph_monomorphisms_iter, tarjan_scc, tarjan_scc_iter, topological_generations, toposort,
+    toposort_by_key, weighted_betweenness_centrality, ColoringStrategy, Error, Matching,
+};
+#[cfg(feature = "rayon")]
+use petgraph::algo::{
+    par_bellman_ford, par_hits, par_katz_centrality, par_page_rank, parallel_bfs,
 };
 use petgraph::data::FromElements;
 use petgraph::dot::{Config, Dot};
 use petgraph::graph::{edge_index, node_index, IndexType};
 use petgraph::graphmap::NodeTrait;
-use petgraph::operator::complement;
+use petgraph::operator::{complement, induced_subgraph};
 use petgraph::prelude::*;
 use petgraph::visit::{
-    EdgeFiltered, EdgeRef, IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNodeIdentifiers,
-    IntoNodeReferences, NodeCount, NodeIndexable, Reversed, Topo, VisitMap, Visitable,
+    Bfs, DepthLimitedBfs, DepthLimitedDfs, Dfs, EdgeCount, EdgeFiltered, EdgeRef, GraphProp,
+    IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors, IntoNodeIdentifiers,
+    IntoNodeReferences, MapWeights, NodeCount, NodeDegrees, NodeIndexable, NodeRef, PriorityWalker,
+    Reversed, RuntimeDirected, SubgraphView, Topo, UndirectedAdaptor, UnionView, VisitMap,
+    Visitable, Walker, WalkerLimit,
 };
 use petgraph::EdgeType;
 
@@ -84,6 +106,26 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // The tree edges are edges of the graph, and weigh as much as the tree
+    fn mst_edges(g: Graph<(), u32>) -> bool {
+        let mst = mst_graph(&g);
+        let edges = min_spanning_tree_edges(&g).collect::<Vec<_>>();
+        assert_eq!(edges.len(), mst.edge_count());
+        for e in &edges {
+            assert_eq!(g.edge_endpoints(e.id()), Some((e.source(), e.target())));
+            assert_eq!(g[e.id()], *e.weight());
+        }
+        let weight = |w: &u32| *w as u64;
+        assert_eq!(edges.iter().map(|e| weight(e.weight())).sum::<u64>(),
+                   mst.edge_weights().map(weight).sum::<u64>());
+        let tree = Graph::<(), u32, Undirected>::from_edges(
+            edges.iter().map(|e| (e.source(), e.target(), *e.weight())));
+        assert!(!is_cyclic_undirected(&tree));
+        true
+    }
+}
+
 quickcheck! {
     fn reverse_undirected(g: Small<UnGraph<(), ()>>) -> bool {
         let mut h = (*g).clone();
@@ -92,6 +134,44 @@ quickcheck! {
     }
 }
 
+#[test]
+fn arbitrary_graph_density() {
+    // arbitrary graphs should be neither always empty nor always complete
+    let mut gen = quickcheck::StdThreadGen::new(30);
+    let (mut incomplete, mut nonempty) = (false, false);
+    for _ in 0..100 {
+        let g = DiGraph::<(), ()>::arbitrary(&mut gen);
+        let n = g.node_count();
+        incomplete |= g.edge_count() < n * n / 2;
+        nonempty |= g.edge_count() > 0;
+    }
+    assert!(incomplete && nonempty);
+}
+
+fn shrinks_structure(size: usize, mut shrunk: impl Iterator<Item = (usize, usize)>) -> bool {
+    shrunk.all(|(nodes, edges)| nodes + edges < size)
+}
+
+quickcheck! {
+    fn shrink_graph(g: Small<Graph<(), (), Undirected>>) -> bool {
+        let size = g.node_count() + g.edge_count();
+        shrinks_structure(size, g.shrink().map(|h| (h.node_count(), h.edge_count())))
+            && g.shrink().count() >= size
+    }
+
+    fn shrink_stable_graph(g: Small<StableDiGraph<(), ()>>) -> bool {
+        let size = g.node_count() + g.edge_count();
+        shrinks_structure(size, g.shrink().map(|h| (h.node_count(), h.edge_count())))
+            && g.shrink().count() >= size
+    }
+
+    fn shrink_graphmap(g: Small<DiGraphMap<u8, ()>>) -> bool {
+        let size = g.node_count() + g.edge_count();
+        shrinks_structure(size, g.shrink().map(|h| (h.node_count(), h.edge_count())))
+            && g.shrink().count() == size
+    }
+}
+
 fn assert_graph_consistent<N, E, Ty, Ix>(g: &Graph<N, E, Ty, Ix>)
 where
     Ty: EdgeType,
@@ -138,6 +218,46 @@ fn reverse_directed() {
     quickcheck::quickcheck(prop as fn(Graph<_, _, Directed>) -> bool);
 }
 
+#[test]
+fn stable_graph_reverse() {
+    fn prop<Ty: EdgeType>(mut g: StableGraph<(), i32, Ty>) -> bool {
+        let edges = g
+            .edge_indices()
+            .map(|e| (e, g.edge_endpoints(e).unwrap()))
+            .collect::<Vec<_>>();
+        let outgoing = g
+            .node_indices()
+            .map(|a| set(g.edges_directed(a, Outgoing).map(|e| e.id())))
+            .collect::<Vec<_>>();
+        g.reverse();
+        for (e, (a, b)) in edges {
+            assert_eq!(g.edge_endpoints(e), Some((b, a)));
+        }
+        let incoming = g
+            .node_indices()
+            .map(|a| set(g.edges_directed(a, Incoming).map(|e| e.id())))
+            .collect::<Vec<_>>();
+        assert_eq!(outgoing, incoming);
+
+        // the free lists of vacant entries are intact
+        let nodes = set(g.node_indices());
+        let edges = set(g.edge_indices());
+        let new_nodes = (0..nodes.len() + 4)
+            .map(|_| g.add_node(()))
+            .collect::<Vec<_>>();
+        let new_edges = new_nodes
+            .windows(2)
+            .map(|w| g.add_edge(w[0], w[1], 0))
+            .collect::<Vec<_>>();
+        assert!(new_nodes.iter().all(|a| !nodes.contains(a)));
+        assert!(new_edges.iter().all(|e| !edges.contains(e)));
+        assert_eq!(set(new_nodes.iter()).len(), new_nodes.len());
+        assert_eq!(set(new_edges.iter()).len(), new_edges.len());
+        true
+    }
+    quickcheck::quickcheck(prop as fn(StableGraph<_, _, Directed>) -> bool);
+}
+
 #[test]
 fn graph_retain_nodes() {
     fn prop<Ty: EdgeType>(mut g: Graph<i32, i32, Ty>) -> bool {
@@ -254,6 +374,79 @@ fn stable_graph_retain_edges() {
     quickcheck::quickcheck(prop as fn(StableGraph<_, _, Undirected>) -> bool);
 }
 
+quickcheck! {
+    fn stable_graph_into_graph_with_map(g: StableGraph<i32, i32>) -> bool {
+        let (h, node_map, edge_map) = g.clone().into_graph_with_map();
+        assert_eq!(h.node_count(), g.node_count());
+        assert_eq!(h.edge_count(), g.edge_count());
+        for (i, &n) in node_map.iter().enumerate() {
+            match g.node_weight(node_index(i)) {
+                Some(w) => assert_eq!(h.node_weight(n), Some(w)),
+                None => assert_eq!(n, NodeIndex::end()),
+            }
+        }
+        for (i, &e) in edge_map.iter().enumerate() {
+            match g.edge_endpoints(edge_index(i)) {
+                Some((a, b)) => {
+                    assert_eq!(h.edge_endpoints(e), Some((node_map[a.index()], node_map[b.index()])));
+                    assert_eq!(h.edge_weight(e), g.edge_weight(edge_index(i)));
+                }
+                None => assert_eq!(e, EdgeIndex::end()),
+            }
+        }
+        // the new indices are in increasing order
+        let nodes = node_map.iter().filter(|&&n| n != NodeIndex::end()).collect::<Vec<_>>();
+        assert!(nodes.windows(2).all(|w| w[0] < w[1]));
+        true
+    }
+}
+
+quickcheck! {
+    fn remove_nodes_shift(g: Graph<i32, i32>, remove: Vec<usize>) -> bool {
+        let remove = remove
+            .into_iter()
+            .filter(|&i| i < g.node_count())
+            .map(node_index)
+            .collect::<HashSet<_>>();
+        let expected = g.filter_map(
+            |a, w| if remove.contains(&a) { None } else { Some(*w) },
+            |_, w| Some(*w),
+        );
+        let mut h = g.clone();
+        let shift = h.remove_nodes_shift(remove.iter().cloned());
+        assert_graph_consistent(&h);
+        assert_eq!(h.raw_nodes().iter().map(|n| n.weight).collect::<Vec<_>>(),
+                   expected.raw_nodes().iter().map(|n| n.weight).collect::<Vec<_>>());
+        assert_equal(h.edge_references().map(|e| (e.source(), e.target(), *e.weight())),
+                     expected.edge_references().map(|e| (e.source(), e.target(), *e.weight())));
+        for a in g.node_indices() {
+            match shift.node(a) {
+                Some(b) => assert_eq!(g[a], h[b]),
+                None => assert!(remove.contains(&a)),
+            }
+        }
+        for e in g.edge_indices() {
+            if let Some(f) = shift.edge(e) {
+                let (a, b) = g.edge_endpoints(e).unwrap();
+                assert_eq!(h.edge_endpoints(f), Some((shift.node(a).unwrap(), shift.node(b).unwrap())));
+            }
+        }
+        if let Some(&a) = remove.iter().next() {
+            let mut h = g.clone();
+            let (w, shift) = h.remove_node_shift(a).unwrap();
+            assert_eq!(w, g[a]);
+            assert!(g.node_indices().all(|b| shift.node(b) == if b < a {
+                Some(b)
+            } else if b > a {
+                Some(node_index(b.index() - 1))
+            } else {
+                None
+            }));
+        }
+        true
+    }
+}
+
 #[test]
 fn isomorphism_1() {
     // using small weights so that duplicates are likely
@@ -381,6 +574,34 @@ fn stable_graph_remove_edge() {
 }
 
 #[cfg(feature = "stable_graph")]
+#[test]
+fn contract_edge() {
+    // contracting an edge of a Graph and of a StableGraph gives the same graph
+    fn prop<Ty: EdgeType>(g: Small<Graph<u32, u32, Ty>>, e: usize) -> bool {
+        if g.edge_count() == 0 {
+            return true;
+        }
+        let e = edge_index(e % g.edge_count());
+        let mut h = g.0.clone();
+        let mut s = StableGraph::from(g.0.clone());
+        let m = h.contract_edge(e, |x, y| *x = x.wrapping_add(y)).unwrap();
+        let ms = s.contract_edge(e, |x, y| *x = x.wrapping_add(y)).unwrap();
+        assert_eq!(h[m], s[ms]);
+        assert_eq!(h.edge_count(), g.edge_count() - 1);
+        assert_graph_consistent(&h);
+        // edge weights are compared separately, as the isomorphism check
+        // doesn't match parallel edges by weight
+        let mut weights = h.edge_weights().collect::<Vec<_>>();
+        let mut stable_weights = s.edge_weights().collect::<Vec<_>>();
+        weights.sort();
+        stable_weights.sort();
+        assert_eq!(weights, stable_weights);
+        is_isomorphic_matching(&h, &Graph::from(s), PartialEq::eq, |_, _| true)
+    }
+    quickcheck::quickcheck(prop as fn(Small<Graph<_, _, Directed>>, _) -> bool);
+    quickcheck::quickcheck(prop as fn(Small<Graph<_, _, Undirected>>, _) -> bool);
+}
+
 #[test]
 fn stable_graph_add_remove_edges() {
     fn prop<Ty: EdgeType>(mut g: StableGraph<(), (), Ty>, edges: Vec<(u8, u8)>) -> bool {
@@ -419,6 +640,130 @@ fn stable_graph_add_remove_edges() {
     quickcheck::quickcheck(prop as fn(StableGraph<_, _, Directed>, _) -> bool);
 }
 
+/// Check that the edge lookups of `g`, which has the endpoint index, agree
+/// with those of `h`, the same graph without it.
+fn assert_endpoint_index_agrees<Ty: EdgeType>(
+    g: &Graph<(), (), Ty>,
+    h: &Graph<(), (), Ty>,
+    pairs: &[(NodeIndex, NodeIndex)],
+) {
+    let edge_pairs = h.edge_references().map(|e| (e.source(), e.target()));
+    for (a, b) in edge_pairs.chain(pairs.iter().cloned()) {
+        let edge = g.find_edge(a, b);
+        assert_eq!(edge.is_some(), h.find_edge(a, b).is_some());
+        if let Some(e) = edge {
+            let (x, y) = g.edge_endpoints(e).unwrap();
+            assert!((x, y) == (a, b) || !g.is_directed() && (y, x) == (a, b));
+        }
+        let undirected = g.find_edge_undirected(a, b);
+        assert_eq!(undirected.is_some(), h.find_edge_undirected(a, b).is_some());
+        if let Some((e, dir)) = undirected {
+            let (x, y) = g.edge_endpoints(e).unwrap();
+            match dir {
+                Outgoing => assert_eq!((x, y), (a, b)),
+                Incoming => assert_eq!((y, x), (a, b)),
+            }
+        }
+    }
+}
+
+#[test]
+fn graph_endpoint_index() {
+    fn prop<Ty: EdgeType>(g: Small<Graph<(), (), Ty>>, ops: Vec<(u8, u8, u8)>) -> bool {
+        let mut h = g.0;
+        let mut g = h.clone();
+        g.enable_endpoint_index();
+        let mut pairs = Vec::new();
+        for &(op, a, b) in &ops {
+            let a = node_index(a as usize % (h.node_count() + 1));
+            let b = node_index(b as usize % (h.node_count() + 1));
+            pairs.push((a, b));
+            match op % 8 {
+                0 => {
+                    g.add_node(());
+                    h.add_node(());
+                }
+                1 | 2 if a.index() < h.node_count() && b.index() < h.node_count() => {
+                    g.add_edge(a, b, ());
+                    h.add_edge(a, b, ());
+                }
+                3 => {
+                    g.remove_node(a);
+                    h.remove_node(a);
+                }
+                4 | 5 => {
+                    let e = edge_index(a.index() + b.index());
+                    g.remove_edge(e);
+                    h.remove_edge(e);
+                }
+                6 => {
+                    g.reverse();
+                    h.reverse();
+                }
+                7 => {
+                    g.remove_nodes_shift(vec![a, b]);
+                    h.remove_nodes_shift(vec![a, b]);
+                }
+                _ => {}
+            }
+            assert_endpoint_index_agrees(&g, &h, &pairs);
+        }
+        true
+    }
+    quickcheck::quickcheck(prop as fn(Small<Graph<_, _, Directed>>, _) -> bool);
+    quickcheck::quickcheck(prop as fn(Small<Graph<_, _, Undirected>>, _) -> bool);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn stable_graph_endpoint_index() {
+    fn prop<Ty: EdgeType>(g: Small<StableGraph<(), (), Ty>>, ops: Vec<(u8, u8, u8)>) -> bool {
+        let mut h = g.0;
+        let mut g = h.clone();
+        g.enable_endpoint_index();
+        let mut pairs = Vec::new();
+        for &(op, a, b) in &ops {
+            let a = node_index(a as usize % (h.node_bound() + 1));
+            let b = node_index(b as usize % (h.node_bound() + 1));
+            pairs.push((a, b));
+            match op % 6 {
+                0 => {
+                    assert_eq!(g.add_node(()), h.add_node(()));
+                }
+                1 | 2 if h.contains_node(a) && h.contains_node(b) => {
+                    assert_eq!(g.add_edge(a, b, ()), h.add_edge(a, b, ()));
+                }
+                3 => {
+                    g.remove_node(a);
+                    h.remove_node(a);
+                }
+                4 => {
+                    let e = edge_index(a.index() + b.index());
+                    g.remove_edge(e);
+                    h.remove_edge(e);
+                }
+                5 => {
+                    g.reverse();
+                    h.reverse();
+                }
+                _ => {}
+            }
+            let edge_pairs = h.edge_references().map(|e| (e.source(), e.target()));
+            for (a, b) in edge_pairs.chain(pairs.iter().cloned()) {
+                let edge = g.find_edge(a, b);
+                assert_eq!(edge.is_some(), h.find_edge(a, b).is_some());
+                if let Some(e) = edge {
+                    let (x, y) = g.edge_endpoints(e).unwrap();
+                    assert!((x, y) == (a, b) || !g.is_directed() && (y, x) == (a, b));
+                }
+            }
+        }
+        true
+    }
+    quickcheck::quickcheck(prop as fn(Small<StableGraph<_, _, Directed>>, _) -> bool);
+    quickcheck::quickcheck(prop as fn(Small<StableGraph<_, _, Undirected>>, _) -> bool);
+}
+
 fn assert_graphmap_consistent<N, E, Ty>(g: &GraphMap<N, E, Ty>)
 where
     Ty: EdgeType,
@@ -480,6 +825,28 @@ fn graphmap_add_remove() {
     quickcheck::quickcheck(prop as fn(_, _, _) -> bool);
 }
 
+#[test]
+fn graphmap_graph_round_trip() {
+    fn prop<Ty: EdgeType + Clone>(g: GraphMap<i8, i32, Ty>) -> bool {
+        let (graph, node_map): (Graph<_, _, Ty>, _) = g.clone().into_graph_with_map();
+        assert_eq!(node_map.len(), g.node_count());
+        for n in g.nodes() {
+            assert_eq!(graph[node_map[&n]], n);
+        }
+        for (a, b, w) in g.all_edges() {
+            let e = graph.find_edge(node_map[&a], node_map[&b]).unwrap();
+            assert_eq!(&graph[e], w);
+        }
+        let h = GraphMap::<_, _, Ty>::from_graph(&graph, |_, &n| n);
+        assert_graphmap_consistent(&h);
+        assert!(g.nodes().eq(h.nodes()));
+        assert!(g.all_edges().eq(h.all_edges()));
+        true
+    }
+    quickcheck::quickcheck(prop as fn(DiGraphMap<_, _>) -> bool);
+    quickcheck::quickcheck(prop as fn(UnGraphMap<_, _>) -> bool);
+}
+
 fn sort_sccs<T: Ord>(v: &mut [Vec<T>]) {
     for scc in &mut *v {
         scc.sort();
@@ -521,6 +888,16 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn tarjan_scc_iter_is_tarjan_scc(g: Graph<(), ()>, take: usize) -> bool {
+        let tsccs = tarjan_scc(&g);
+        assert_eq!(tarjan_scc_iter(&g).collect::<Vec<_>>(), tsccs);
+        let take = take % (tsccs.len() + 1);
+        assert_eq!(tarjan_scc_iter(&g).take(take).collect::<Vec<_>>(), &tsccs[..take]);
+        true
+    }
+}
+
 quickcheck! {
     // Reversed edges gives the same sccs (when sorted)
     fn graph_reverse_sccs(g: Graph<(), ()>) -> bool {
@@ -559,6 +936,177 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // An undirected view of a directed graph is like the undirected graph
+    fn undirected_adaptor(g: DiGraph<(), u8>) -> bool {
+        let ug = g.clone().into_edge_type::<Undirected>();
+        let view = UndirectedAdaptor(&g);
+        assert!(!view.is_directed());
+        for a in g.node_indices() {
+            let mut neighbors = view.neighbors(a).collect::<Vec<_>>();
+            let mut expected = ug.neighbors(a).collect::<Vec<_>>();
+            neighbors.sort();
+            expected.sort();
+            assert_eq!(neighbors, expected);
+            for &dir in &[Outgoing, Incoming] {
+                let mut edges = view
+                    .edges_directed(a, dir)
+                    .map(|e| (e.source(), e.target(), e.id(), *e.weight()))
+                    .collect::<Vec<_>>();
+                let mut expected = ug
+                    .edges_directed(a, dir)
+                    .map(|e| (e.source(), e.target(), e.id(), *e.weight()))
+                    .collect::<Vec<_>>();
+                edges.sort();
+                expected.sort();
+                assert_eq!(edges, expected);
+            }
+        }
+        assert_eq!(connected_components(view), connected_components(&ug));
+        true
+    }
+}
+
+quickcheck! {
+    // Degrees count the neighbors, also for adaptors
+    fn node_degrees(g: DiGraph<(), u8>) -> bool {
+        let ug = g.clone().into_edge_type::<Undirected>();
+        let gm = DiGraphMap::<_, u8>::from_edges(
+            g.edge_references().map(|e| (e.source().index(), e.target().index(), *e.weight())),
+        );
+        for a in g.node_indices() {
+            for &dir in &[Outgoing, Incoming] {
+                let count = g.neighbors_directed(a, dir).count();
+                assert_eq!(g.degree_directed(a, dir), count);
+                assert_eq!(Reversed(&g).degree_directed(a, dir.opposite()), count);
+                assert_eq!(ug.degree_directed(a, dir), ug.neighbors(a).count());
+                if gm.contains_node(a.index()) {
+                    let count = gm.neighbors_directed(a.index(), dir).count();
+                    assert_eq!(gm.degree_directed(a.index(), dir), count);
+                }
+            }
+            assert_eq!(g.degree(a), g.out_degree(a) + g.in_degree(a));
+            assert_eq!(UndirectedAdaptor(&g).degree(a), ug.degree(a));
+        }
+        true
+    }
+}
+
+quickcheck! {
+    // A runtime directed view follows its flag, also through other adaptors
+    fn runtime_directed(g: DiGraph<(), u8>) -> bool {
+        let ug = g.clone().into_edge_type::<Undirected>();
+        for &directed in &[true, false] {
+            let view = RuntimeDirected::new(&g, directed);
+            assert_eq!(view.is_directed(), directed);
+            assert_eq!(Reversed(view).is_directed(), directed);
+            for a in g.node_indices() {
+                let mut neighbors = view.neighbors(a).collect::<Vec<_>>();
+                let mut expected = if directed {
+                    g.neighbors(a).collect::<Vec<_>>()
+                } else {
+                    ug.neighbors(a).collect::<Vec<_>>()
+                };
+                neighbors.sort();
+                expected.sort();
+                assert_eq!(neighbors, expected);
+            }
+            let components = if directed {
+                connected_components(&g)
+            } else {
+                connected_components(&ug)
+            };
+            assert_eq!(connected_components(view), components);
+        }
+        true
+    }
+}
+
+quickcheck! {
+    // A graph split in two edge sets is the union of their views
+    fn union_view(g: DiGraph<(), u8>) -> bool {
+        let mut a = g.clone();
+        a.retain_edges(|g, e| g[e] % 2 == 0);
+        let mut b = g.clone();
+        b.retain_edges(|g, e| g[e] % 2 == 1);
+        let view = UnionView(&a, &b);
+        assert_eq!(view.edge_count(), g.edge_count());
+        for n in g.node_indices() {
+            for &dir in &[Outgoing, Incoming] {
+                let mut edges = view
+                    .edges_directed(n, dir)
+                    .map(|e| (e.source(), e.target(), *e.weight()))
+                    .collect::<Vec<_>>();
+                let mut expected = g
+                    .edges_directed(n, dir)
+                    .map(|e| (e.source(), e.target(), *e.weight()))
+                    .collect::<Vec<_>>();
+                edges.sort();
+                expected.sort();
+                assert_eq!(edges, expected);
+            }
+        }
+        if let Some(n) = g.node_indices().next() {
+            let dist = dijkstra(view, n, None, |e| *e.weight() as u32);
+            assert_eq!(dist, dijkstra(&g, n, None, |e| *e.weight() as u32));
+        }
+        true
+    }
+}
+
+quickcheck! {
+    // A subgraph view is like the induced subgraph
+    fn subgraph_view(g: DiGraph<(), u8>, bits: Vec<bool>) -> bool {
+        let g = g.map(|_, _| (), |_, &w| w as f32);
+        let mut nodes = FixedBitSet::with_capacity(g.node_count());
+        for (i, &b) in bits.iter().take(g.node_count()).enumerate() {
+            nodes.set(i, b);
+        }
+        let view = SubgraphView::new(&g, &nodes);
+        let (sub, new, old): (DiGraph<_, _>, _, _) =
+            induced_subgraph(&g, nodes.ones().map(node_index));
+        assert_eq!(view.node_count(), sub.node_count());
+        assert_eq!(view.edge_references().count(), sub.edge_count());
+        assert_eq!(set(view.node_identifiers()), set(new.keys().cloned()));
+        for a in g.node_indices() {
+            let mut neighbors = view.neighbors(a).collect::<Vec<_>>();
+            let mut expected = match new.get(&a) {
+                Some(&x) => sub.neighbors(x).map(|y| old[&y]).collect(),
+                None => vec![],
+            };
+            neighbors.sort();
+            expected.sort();
+            assert_eq!(neighbors, expected);
+        }
+        if let Some(a) = nodes.ones().next() {
+            let dist = bellman_ford(view, node_index(a)).unwrap().distances;
+            let sub_dist =
+                bellman_ford(&sub, new[&node_index(a)]).unwrap().distances;
+            for (x, d) in sub_dist.into_iter().enumerate() {
+                assert_eq!(dist[old[&node_index(x)].index()], d);
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    // Algorithms on mapped weights agree with algorithms on a mapped copy
+    fn map_weights(g: DiGraph<u8, u8>) -> bool {
+        let view = MapWeights::new(&g, |&w: &u8| w as u32 * 2, |&w: &u8| w as u32 + 1);
+        let copy = g.map(|_, &w| w as u32 * 2, |_, &w| w as u32 + 1);
+        assert_eq!(set(view.node_references().map(|n| (n.id(), *n.weight()))),
+                   set(copy.node_references().map(|n| (n.id(), *n.weight()))));
+        assert_eq!(view.edge_references().map(|e| (e.id(), *e.weight())).collect::<Vec<_>>(),
+                   copy.edge_references().map(|e| (e.id(), *e.weight())).collect::<Vec<_>>());
+        if let Some(n) = g.node_indices().next() {
+            let dist = dijkstra(&view, n, None, |e| *e.weight());
+            assert_eq!(dist, dijkstra(&copy, n, None, |e| *e.weight()));
+        }
+        true
+    }
+}
+
 #[test]
 fn graph_condensation_acyclic() {
     fn prop(g: Graph<(), ()>) -> bool {
@@ -732,6 +1280,28 @@ fn full_topo_generic() {
     quickcheck::quickcheck(prop_generic as fn(_) -> bool);
 }
 
+quickcheck! {
+    fn topo_finish(gr: Graph<(), ()>) -> bool {
+        let mut topo = Topo::new(&gr);
+        let mut emitted = HashSet::new();
+        while let Some(nx) = topo.next(&gr) {
+            emitted.insert(nx);
+        }
+        match topo.finish(&gr) {
+            Ok(()) => {
+                assert!(!is_cyclic_directed(&gr));
+                assert_eq!(emitted.len(), gr.node_count());
+            }
+            Err(remaining) => {
+                assert!(is_cyclic_directed(&gr));
+                assert_eq!(emitted.len() + remaining.len(), gr.node_count());
+                assert!(remaining.iter().all(|n| !emitted.contains(n)));
+            }
+        }
+        true
+    }
+}
+
 quickcheck! {
     // checks that the distances computed by dijkstra satisfy the triangle
     // inequality.
@@ -841,6 +1411,35 @@ where
     iter.into_iter().collect()
 }
 
+quickcheck! {
+    fn dfs_times(gr: Graph<(), ()>) -> bool {
+        use petgraph::visit::depth_first_search_times;
+        let times = depth_first_search_times(&gr, gr.node_indices());
+        assert_eq!(times.discover_time.len(), gr.node_count());
+        assert_eq!(times.finish_time.len(), gr.node_count());
+        assert_eq!(times.parent.len() + times.roots.len(), gr.node_count());
+        for (&v, &u) in &times.parent {
+            assert!(gr.contains_edge(u, v));
+            assert!(times.is_ancestor(u, v) && !times.is_ancestor(v, u));
+        }
+        for &r in &times.roots {
+            assert!(!times.parent.contains_key(&r));
+        }
+        for u in gr.node_indices() {
+            assert!(times.discover_time[&u] < times.finish_time[&u]);
+            // the ancestors of a node are the nodes on its path to its root
+            let mut path = vec![u];
+            while let Some(&p) = times.parent.get(path.last().unwrap()) {
+                path.push(p);
+            }
+            for v in gr.node_indices() {
+                assert_eq!(times.is_ancestor(v, u), path.contains(&v));
+            }
+        }
+        true
+    }
+}
+
 quickcheck! {
     fn dfs_visit(gr: Graph<(), ()>, node: usize) -> bool {
         use petgraph::visit::{Visitable, VisitMap};
@@ -906,92 +1505,486 @@ quickcheck! {
 }
 
 quickcheck! {
-    fn test_find_negative_cycle(gr: Graph<(), f32>) -> bool {
-        let gr = gr;
-        if gr.node_count() == 0 {
-            return true;
-        }
-        for (i, start) in gr.node_indices().enumerate() {
-            if i >= 10 { break; } // testing all is too slow
-            if let Some(path) = find_negative_cycle(&gr, start) {
-                assert!(path.len() >= 1);
+    // Integer costs give the same distances as dijkstra
+    fn test_bellman_ford_integer(gr: Graph<(), u8>) -> bool {
+        let gr = gr.map(|_, _| (), |_, &w| w as i32);
+        if let Some(start) = gr.node_indices().next() {
+            let distances = bellman_ford(&gr, start).unwrap().distances;
+            let expected = dijkstra(&gr, start, None, |e| *e.weight());
+            for (i, d) in distances.into_iter().enumerate() {
+                assert_eq!(expected.get(&node_index(i)).cloned().unwrap_or(i32::MAX), d);
             }
         }
         true
     }
 }
 
+#[cfg(feature = "rayon")]
 quickcheck! {
-    fn test_bellman_ford_undir(gr: Graph<(), f32, Undirected>) -> bool {
-        let mut gr = gr;
-        for elt in gr.edge_weights_mut() {
-            *elt = elt.abs();
-        }
-        if gr.node_count() == 0 {
-            return true;
-        }
-        for (i, start) in gr.node_indices().enumerate() {
-            if i >= 10 { break; } // testing all is too slow
-            bellman_ford(&gr, start).unwrap();
+    // Integer costs give the same distances as dijkstra, for any bucket width
+    fn delta_stepping_(g: Graph<(), u8>, delta: u8) -> bool {
+        let delta = delta as f64 / 16. + 1.;
+        for start in g.node_indices().take(10) {
+            let expected = dijkstra(&g, start, None, |e| *e.weight() as u32);
+            let distances = delta_stepping(&g, start, delta, |e| *e.weight() as f64);
+            for (i, d) in distances.into_iter().enumerate() {
+                assert_eq!(expected.get(&node_index(i)).map(|&d| d as f64), d);
+            }
         }
         true
     }
 }
 
-defmac!(iter_eq a, b => a.eq(b));
-defmac!(nodes_eq ref a, ref b => a.node_references().eq(b.node_references()));
-defmac!(edgew_eq ref a, ref b => a.edge_references().eq(b.edge_references()));
-defmac!(edges_eq ref a, ref b =>
-        iter_eq!(
-            a.edge_references().map(|e| (e.source(), e.target())),
-            b.edge_references().map(|e| (e.source(), e.target()))));
+#[cfg(feature = "rayon")]
+fn check_parallel_bfs<Ty: EdgeType + Sync>(g: &Graph<(), (), Ty>) {
+    for start in g.node_indices().take(10) {
+        let expected = dijkstra(g, start, None, |_| 1usize);
+        let tree = parallel_bfs(g, start);
+        for (i, &d) in tree.distances.iter().enumerate() {
+            assert_eq!(expected.get(&node_index(i)).cloned(), d);
+            match tree.parents[i] {
+                None => assert!(i == start.index() || d.is_none()),
+                Some(p) => {
+                    assert_eq!(tree.distances[p.index()], Some(d.unwrap() - 1));
+                    assert!(g.contains_edge(p, node_index(i)));
+                }
+            }
+        }
+    }
+}
 
+#[cfg(feature = "rayon")]
 quickcheck! {
-    fn test_di_from(gr1: DiGraph<i32, i32>) -> () {
-        let sgr = StableGraph::from(gr1.clone());
-        let gr2 = Graph::from(sgr);
-
-        assert!(nodes_eq!(&gr1, &gr2));
-        assert!(edgew_eq!(&gr1, &gr2));
-        assert!(edges_eq!(&gr1, &gr2));
+    fn parallel_bfs_directed(g: Graph<(), ()>) -> bool {
+        check_parallel_bfs(&g);
+        true
     }
-    fn test_un_from(gr1: UnGraph<i32, i32>) -> () {
-        let sgr = StableGraph::from(gr1.clone());
-        let gr2 = Graph::from(sgr);
 
-        assert!(nodes_eq!(&gr1, &gr2));
-        assert!(edgew_eq!(&gr1, &gr2));
-        assert!(edges_eq!(&gr1, &gr2));
+    fn parallel_bfs_undirected(g: UnGraph<(), ()>) -> bool {
+        check_parallel_bfs(&g);
+        true
     }
+}
 
-    fn test_graph_from_stable_graph(gr1: StableDiGraph<usize, usize>) -> () {
-        let mut gr1 = gr1;
-        let gr2 = Graph::from(gr1.clone());
+#[cfg(feature = "rayon")]
+quickcheck! {
+    // The scores do not depend on the number of threads
+    fn parallel_centrality_deterministic(g: Graph<(), ()>) -> bool {
+        let scores = |threads| {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+            pool.install(|| {
+                (
+                    par_page_rank(&g, 0.85, 1e-12, 50),
+                    par_hits(&g, 1e-12, 50),
+                    par_katz_centrality(&g, 0.05, 1., 1e-12, 50),
+                )
+            })
+        };
+        scores(1) == scores(4)
+    }
 
-        // renumber the stablegraph nodes and put the new index in the
-        // associated data
-        let mut index = 0;
-        for i in 0..gr1.node_bound() {
-            let ni = node_index(i);
-            if gr1.contains_node(ni) {
-                gr1[ni] = index;
-                index += 1;
-            }
+    // The ranks are a fixed point of the PageRank iteration
+    fn par_page_rank_stationary(g: Graph<(), ()>) -> bool {
+        let n = g.node_count() as f64;
+        let ranks = par_page_rank(&g, 0.85, 1e-14, 1000).unwrap();
+        if n == 0. {
+            return ranks.is_empty();
         }
-        if let Some(edge_bound) = gr1.edge_references().next_back()
-            .map(|ed| ed.id().index() + 1)
-        {
-            index = 0;
-            for i in 0..edge_bound {
-                let ni = edge_index(i);
-                if gr1.edge_weight(ni).is_some() {
-                    gr1[ni] = index;
-                    index += 1;
-                }
-            }
+        assert!((ranks.iter().sum::<f64>() - 1.).abs() < 1e-9);
+        let dangling: f64 = g
+            .node_indices()
+            .filter(|&a| g.neighbors(a).next().is_none())
+            .map(|a| ranks[a.index()])
+            .sum();
+        for a in g.node_indices() {
+            let incoming: f64 = g
+                .edges_directed(a, Incoming)
+                .map(|e| ranks[e.source().index()] / g.neighbors(e.source()).count() as f64)
+                .sum();
+            let expected = (0.15 + 0.85 * dangling) / n + 0.85 * incoming;
+            assert!((ranks[a.index()] - expected).abs() < 1e-9);
         }
-
-        assert_equal(
+        true
+    }
+}
+
+fn check_indexed_shortest_paths<G>(g: G)
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    G::NodeId: Eq + Hash + fmt::Debug,
+    G::EdgeWeight: Copy + Into<u32>,
+{
+    for start in g.node_identifiers().take(5) {
+        let expected = dijkstra(g, start, None, |e| (*e.weight()).into());
+        let scores = dijkstra_indexed(g, start, None, |e| (*e.weight()).into());
+        assert_eq!(scores.len(), g.node_bound());
+        for (i, &score) in scores.iter().enumerate() {
+            assert_eq!(expected.get(&g.from_index(i)).cloned(), score);
+        }
+        for goal in g.node_identifiers().take(5) {
+            let path = astar_indexed(g, start, |n| n == goal, |e| (*e.weight()).into(), |_| 0);
+            let hashed = astar(g, start, |n| n == goal, |e| (*e.weight()).into(), |_| 0);
+            assert_eq!(path.as_ref().map(|p| p.0), expected.get(&goal).cloned());
+            assert_eq!(path.map(|p| p.0), hashed.map(|p| p.0));
+        }
+    }
+}
+
+quickcheck! {
+    // The indexed algorithms find the same costs as the hashing ones
+    fn indexed_shortest_paths(g: Graph<(), u8>) -> bool {
+        check_indexed_shortest_paths(&g);
+        true
+    }
+
+    fn indexed_shortest_paths_stable(g: StableGraph<(), u8>) -> bool {
+        check_indexed_shortest_paths(&g);
+        true
+    }
+}
+
+fn check_maximum_flow<Ty: EdgeType>(g: &Graph<(), u8, Ty>) {
+    let capacity = |e: petgraph::graph::EdgeReference<u8>| *e.weight() as i32;
+    for s in g.node_indices().take(4) {
+        for t in g.node_indices().take(4) {
+            let (value, flows) = maximum_flow(g, s, t, capacity);
+            let mut excess = vec![0; g.node_count()];
+            for (e, &f) in g.edge_references().zip(&flows) {
+                if g.is_directed() {
+                    assert!(0 <= f && f <= capacity(e));
+                } else {
+                    assert!(f.abs() <= capacity(e));
+                }
+                excess[e.source().index()] -= f;
+                excess[e.target().index()] += f;
+            }
+            for (i, &x) in excess.iter().enumerate() {
+                if s != t && i == s.index() {
+                    assert_eq!(x, -value);
+                } else if s != t && i == t.index() {
+                    assert_eq!(x, value);
+                } else {
+                    assert_eq!(x, 0);
+                }
+            }
+
+            let (cut_value, source_side, cut) = minimum_cut(g, s, t, capacity);
+            assert_eq!(cut_value, value);
+            assert!(source_side.contains(&s));
+            assert!(s == t || !source_side.contains(&t));
+            if s != t {
+                assert_eq!(cut.iter().map(|&e| capacity(e)).sum::<i32>(), value);
+            }
+        }
+    }
+}
+
+quickcheck! {
+    // Flows respect capacities and conservation, and equal the minimum cut
+    fn maximum_flow_directed(g: Graph<(), u8>) -> bool {
+        check_maximum_flow(&g);
+        true
+    }
+
+    fn maximum_flow_undirected(g: UnGraph<(), u8>) -> bool {
+        check_maximum_flow(&g);
+        true
+    }
+}
+
+/// The distances and numbers of shortest paths between all pairs of nodes.
+fn naive_shortest_path_counts<Ty: EdgeType>(
+    g: &Graph<(), (), Ty>,
+) -> (Vec<Vec<Option<usize>>>, Vec<Vec<f64>>) {
+    let n = g.node_count();
+    let mut dist = vec![vec![None; n]; n];
+    let mut count = vec![vec![0.; n]; n];
+    for s in 0..n {
+        dist[s][s] = Some(0);
+        count[s][s] = 1.;
+        // relax by levels, so that counts are complete before they are used
+        for d in 0..n {
+            if !dist[s].contains(&Some(d)) {
+                break;
+            }
+            for e in g.edge_references() {
+                let mut ends = vec![(e.source().index(), e.target().index())];
+                if !g.is_directed() {
+                    ends.push((e.target().index(), e.source().index()));
+                }
+                for (a, b) in ends {
+                    if a == b || dist[s][a] != Some(d) {
+                        continue;
+                    }
+                    if dist[s][b].is_none() {
+                        dist[s][b] = Some(d + 1);
+                    }
+                    if dist[s][b] == Some(d + 1) {
+                        count[s][b] += count[s][a];
+                    }
+                }
+            }
+        }
+    }
+    (dist, count)
+}
+
+fn check_betweenness<Ty: EdgeType>(g: &Graph<(), (), Ty>) {
+    let n = g.node_count();
+    let (dist, count) = naive_shortest_path_counts(g);
+    let mut expected = vec![0.; n];
+    for s in 0..n {
+        for t in 0..n {
+            let d = match dist[s][t] {
+                Some(d) if s != t => d,
+                _ => continue,
+            };
+            for (v, x) in expected.iter_mut().enumerate() {
+                if v == s || v == t {
+                    continue;
+                }
+                if let (Some(a), Some(b)) = (dist[s][v], dist[v][t]) {
+                    if a + b == d {
+                        *x += count[s][v] * count[v][t] / count[s][t];
+                    }
+                }
+            }
+        }
+    }
+    if !g.is_directed() {
+        for x in &mut expected {
+            *x /= 2.;
+        }
+    }
+    let unweighted = betweenness_centrality(g);
+    let weighted = weighted_betweenness_centrality(g, |_| 1u32);
+    for v in 0..n {
+        assert!((unweighted[v] - expected[v]).abs() < 1e-6);
+        assert!((weighted[v] - expected[v]).abs() < 1e-6);
+    }
+}
+
+quickcheck! {
+    // Brandes' algorithm agrees with counting the shortest paths of all pairs
+    fn betweenness_directed(g: Small<Graph<(), ()>>) -> bool {
+        check_betweenness(&g);
+        true
+    }
+
+    fn betweenness_undirected(g: Small<UnGraph<(), ()>>) -> bool {
+        check_betweenness(&g);
+        true
+    }
+
+    // Ranks add up to one
+    fn page_rank_sum(g: Graph<(), ()>) -> bool {
+        let ranks = page_rank(&g, 0.85, 1e-12, 1000).unwrap();
+        g.node_count() == 0 || (ranks.iter().sum::<f64>() - 1.).abs() < 1e-9
+    }
+}
+
+#[cfg(feature = "rayon")]
+quickcheck! {
+    fn page_rank_parallel(g: Graph<(), ()>) -> bool {
+        let ranks = page_rank(&g, 0.85, 1e-12, 1000).unwrap();
+        let par_ranks = par_page_rank(&g, 0.85, 1e-12, 1000).unwrap();
+        ranks.iter().zip(&par_ranks).all(|(x, y)| (x - y).abs() < 1e-9)
+    }
+}
+
+#[cfg(feature = "rayon")]
+fn check_par_bellman_ford<Ty: EdgeType + Sync>(g: &Graph<(), i8, Ty>) {
+    let g = g.map(|_, _| (), |_, &w| w as f64);
+    for start in g.node_indices().take(3) {
+        match (
+            bellman_ford(&g, start),
+            par_bellman_ford(&g, start, |e| *e.weight()),
+        ) {
+            (Ok(paths), Ok(distances)) => assert_eq!(paths.distances, distances),
+            (Err(_), Err(_)) => {}
+            (expected, result) => panic!("{:?} != {:?}", expected, result),
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+quickcheck! {
+    // Same distances and negative cycles as bellman_ford
+    fn par_bellman_ford_directed(g: Small<Graph<(), i8>>) -> bool {
+        check_par_bellman_ford(&g);
+        true
+    }
+
+    fn par_bellman_ford_undirected(g: Small<UnGraph<(), i8>>) -> bool {
+        check_par_bellman_ford(&g);
+        true
+    }
+}
+
+fn check_bidirectional_bfs<Ty: EdgeType>(g: &Graph<(), (), Ty>) {
+    for start in g.node_indices() {
+        let distances = dijkstra(g, start, None, |_| 1usize);
+        for goal in g.node_indices() {
+            match bidirectional_bfs(g, start, goal) {
+                None => assert!(!distances.contains_key(&goal)),
+                Some(path) => {
+                    assert_eq!(path.len() - 1, distances[&goal]);
+                    assert_eq!(path.first(), Some(&start));
+                    assert_eq!(path.last(), Some(&goal));
+                    for w in path.windows(2) {
+                        assert!(g.contains_edge(w[0], w[1]));
+                    }
+                }
+            }
+        }
+    }
+}
+
+quickcheck! {
+    fn test_bidirectional_bfs_directed(g: Small<Graph<(), ()>>) -> bool {
+        check_bidirectional_bfs(&g);
+        true
+    }
+
+    fn test_bidirectional_bfs_undirected(g: Small<UnGraph<(), ()>>) -> bool {
+        check_bidirectional_bfs(&g);
+        true
+    }
+}
+
+quickcheck! {
+    fn depth_limited_traversals(g: Small<Graph<(), ()>>, max_depth: u8, budget: u8) -> bool {
+        let max_depth = max_depth as usize % 5;
+        let budget = budget as usize % 10;
+        if let Some(start) = g.node_indices().next() {
+            let distances = dijkstra(&*g, start, None, |_| 1usize);
+            let mut bfs_nodes = DepthLimitedBfs::new(&*g, start, max_depth)
+                .iter(&*g)
+                .collect::<Vec<_>>();
+            bfs_nodes.sort();
+            let mut near = distances.iter()
+                .filter(|&(_, &d)| d <= max_depth)
+                .map(|(&n, _)| n)
+                .collect::<Vec<_>>();
+            near.sort();
+            assert_eq!(bfs_nodes, near);
+
+            for n in DepthLimitedDfs::new(&*g, start, max_depth).iter(&*g) {
+                assert!(distances[&n] <= max_depth);
+            }
+
+            let limited = WalkerLimit::new(Dfs::new(&*g, start), budget);
+            assert_equal(limited.iter(&*g), Dfs::new(&*g, start).iter(&*g).take(budget));
+            let limited = WalkerLimit::new(Bfs::new(&*g, start), budget);
+            assert_equal(limited.iter(&*g), Bfs::new(&*g, start).iter(&*g).take(budget));
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn priority_walker(g: Small<Graph<(), u32>>) -> bool {
+        if let Some(start) = g.node_indices().next() {
+            let distances = dijkstra(&*g, start, None, |e| *e.weight() as u64);
+            let walker = PriorityWalker::new(&*g, start, |e| *e.weight() as u64);
+            let order = walker.iter(&*g).collect::<Vec<_>>();
+            assert_eq!(order.len(), distances.len());
+            assert!(order.windows(2).all(|w| w[0].1 <= w[1].1));
+            for (n, priority) in order {
+                assert_eq!(distances[&n], priority);
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn test_find_negative_cycle(gr: Graph<(), f32>) -> bool {
+        let gr = gr;
+        if gr.node_count() == 0 {
+            return true;
+        }
+        for (i, start) in gr.node_indices().enumerate() {
+            if i >= 10 { break; } // testing all is too slow
+            if let Some(path) = find_negative_cycle(&gr, start) {
+                assert!(path.len() >= 1);
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn test_bellman_ford_undir(gr: Graph<(), f32, Undirected>) -> bool {
+        let mut gr = gr;
+        for elt in gr.edge_weights_mut() {
+            *elt = elt.abs();
+        }
+        if gr.node_count() == 0 {
+            return true;
+        }
+        for (i, start) in gr.node_indices().enumerate() {
+            if i >= 10 { break; } // testing all is too slow
+            bellman_ford(&gr, start).unwrap();
+        }
+        true
+    }
+}
+
+defmac!(iter_eq a, b => a.eq(b));
+defmac!(nodes_eq ref a, ref b => a.node_references().eq(b.node_references()));
+defmac!(edgew_eq ref a, ref b => a.edge_references().eq(b.edge_references()));
+defmac!(edges_eq ref a, ref b =>
+        iter_eq!(
+            a.edge_references().map(|e| (e.source(), e.target())),
+            b.edge_references().map(|e| (e.source(), e.target()))));
+
+quickcheck! {
+    fn test_di_from(gr1: DiGraph<i32, i32>) -> () {
+        let sgr = StableGraph::from(gr1.clone());
+        let gr2 = Graph::from(sgr);
+
+        assert!(nodes_eq!(&gr1, &gr2));
+        assert!(edgew_eq!(&gr1, &gr2));
+        assert!(edges_eq!(&gr1, &gr2));
+    }
+    fn test_un_from(gr1: UnGraph<i32, i32>) -> () {
+        let sgr = StableGraph::from(gr1.clone());
+        let gr2 = Graph::from(sgr);
+
+        assert!(nodes_eq!(&gr1, &gr2));
+        assert!(edgew_eq!(&gr1, &gr2));
+        assert!(edges_eq!(&gr1, &gr2));
+    }
+
+    fn test_graph_from_stable_graph(gr1: StableDiGraph<usize, usize>) -> () {
+        let mut gr1 = gr1;
+        let gr2 = Graph::from(gr1.clone());
+
+        // renumber the stablegraph nodes and put the new index in the
+        // associated data
+        let mut index = 0;
+        for i in 0..gr1.node_bound() {
+            let ni = node_index(i);
+            if gr1.contains_node(ni) {
+                gr1[ni] = index;
+                index += 1;
+            }
+        }
+        if let Some(edge_bound) = gr1.edge_references().next_back()
+            .map(|ed| ed.id().index() + 1)
+        {
+            index = 0;
+            for i in 0..edge_bound {
+                let ni = edge_index(i);
+                if gr1.edge_weight(ni).is_some() {
+                    gr1[ni] = index;
+                    index += 1;
+                }
+            }
+        }
+
+        assert_equal(
             // Remap the stablegraph to compact indices
             gr1.edge_references().map(|ed| (edge_index(*ed.weight()), gr1[ed.source()], gr1[ed.target()])),
             gr2.edge_references().map(|ed| (ed.id(), ed.source().index(), ed.target().index()))
@@ -1283,3 +2276,813 @@ quickcheck! {
         true
     }
 }
+
+fn is_clique<G>(g: G, clique: &[G::NodeId]) -> bool
+where
+    G: IntoNeighbors,
+{
+    clique.iter().all(|&a| {
+        clique
+            .iter()
+            .all(|&b| a == b || g.neighbors(a).any(|n| n == b))
+    })
+}
+
+quickcheck! {
+    fn maximal_cliques_(g: Small<UnGraph<(), ()>>) -> bool {
+        let cliques = maximal_cliques_iter(&*g).collect::<Vec<_>>();
+        let mut seen = HashSet::new();
+        for clique in &cliques {
+            assert!(is_clique(&*g, clique), "not a clique: {:?}", clique);
+            // no node outside the clique can extend it
+            for n in g.node_indices() {
+                if !clique.contains(&n) {
+                    let mut extended = clique.clone();
+                    extended.push(n);
+                    assert!(!is_clique(&*g, &extended), "not maximal: {:?}", clique);
+                }
+            }
+            let mut sorted = clique.clone();
+            sorted.sort();
+            assert!(seen.insert(sorted), "reported twice: {:?}", clique);
+        }
+        // every node is in some maximal clique
+        g.node_indices().all(|n| cliques.iter().any(|c| c.contains(&n)))
+    }
+}
+
+quickcheck! {
+    fn degeneracy_ordering_(g: UnGraph<(), ()>) -> bool {
+        let order = degeneracy_ordering(&g);
+        assert_eq!(order.len(), g.node_count());
+        let mut position = vec![0; g.node_count()];
+        for (i, n) in order.iter().enumerate() {
+            position[n.index()] = i;
+        }
+        let later = |n: NodeIndex| {
+            let mut later = g
+                .neighbors(n)
+                .filter(|m| position[m.index()] > position[n.index()])
+                .collect::<Vec<_>>();
+            later.sort();
+            later.dedup();
+            later.len()
+        };
+        // each node is of minimum degree in the remaining graph when removed
+        order.iter().enumerate().all(|(i, &n)| {
+            order[i..].iter().all(|&m| later(n) <= {
+                let mut rest = g
+                    .neighbors(m)
+                    .filter(|k| position[k.index()] >= i && *k != m)
+                    .collect::<Vec<_>>();
+                rest.sort();
+                rest.dedup();
+                rest.len()
+            })
+        })
+    }
+}
+
+fn assert_maximal_independent<G>(g: G, set: &HashSet<G::NodeId>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Hash + Eq + fmt::Debug,
+{
+    for a in g.node_identifiers() {
+        let has_neighbor_in_set = g.neighbors(a).any(|b| b != a && set.contains(&b));
+        if set.contains(&a) {
+            assert!(!has_neighbor_in_set, "{:?} has a neighbor in the set", a);
+        } else {
+            assert!(has_neighbor_in_set, "{:?} could be added to the set", a);
+        }
+    }
+}
+
+quickcheck! {
+    fn maximal_independent_set_(g: UnGraph<(), ()>) -> bool {
+        let set = maximal_independent_set(&g);
+        assert_maximal_independent(&g, &set);
+        true
+    }
+
+    fn maximal_independent_set_stable(g: StableGraph<(), (), Undirected>) -> bool {
+        let set = maximal_independent_set(&g);
+        assert_maximal_independent(&g, &set);
+        true
+    }
+}
+
+#[cfg(feature = "rand")]
+quickcheck! {
+    fn luby_maximal_independent_set_(g: UnGraph<(), ()>) -> bool {
+        let set = luby_maximal_independent_set(&g, &mut rand::thread_rng());
+        assert_maximal_independent(&g, &set);
+        true
+    }
+}
+
+#[cfg(feature = "rand")]
+quickcheck! {
+    fn random_walk(g: Small<Graph<(), u8>>, seed: u64, restart: bool) -> bool {
+        use petgraph::visit::RandomWalk;
+        use rand::{SeedableRng, XorShiftRng};
+
+        let start = match g.node_indices().next() {
+            Some(start) => start,
+            None => return true,
+        };
+        let restart_probability = if restart { 0.25 } else { 0. };
+        let rng = XorShiftRng::seed_from_u64(seed);
+        let mut walk = RandomWalk::new(start, rng).with_restart_probability(restart_probability);
+        let mut weighted = walk.clone();
+
+        let path = (&mut walk).iter(&*g).take(50).collect::<Vec<_>>();
+        assert_eq!(path[0], start);
+        for w in path.windows(2) {
+            assert!(g.contains_edge(w[0], w[1]) || (restart && w[1] == start));
+        }
+        if path.len() < 50 {
+            // only a walk without restarts ends, at a node without neighbors
+            assert!(!restart);
+            assert_eq!(g.neighbors(*path.last().unwrap()).count(), 0);
+        }
+
+        // edges of even weight are never taken
+        let weight = |e: petgraph::graph::EdgeReference<u8>| (*e.weight() % 2) as f64;
+        let mut previous = None;
+        for _ in 0..50 {
+            let node = match weighted.next_weighted(&*g, weight) {
+                Some(node) => node,
+                None => break,
+            };
+            if let Some(previous) = previous {
+                assert!(g.edges_connecting(previous, node).any(|e| weight(e) > 0.)
+                        || (restart && node == start));
+            }
+            assert_eq!(weighted.current(), Some(node));
+            previous = Some(node);
+        }
+        true
+    }
+}
+
+#[cfg(feature = "rand")]
+quickcheck! {
+    fn biased_walks(g: Small<Graph<(), u8>>, seed: u64, p: u8, q: u8) -> bool {
+        use petgraph::visit::BiasedWalks;
+        use rand::{SeedableRng, XorShiftRng};
+
+        let weight = |e: petgraph::graph::EdgeReference<u8>| (*e.weight() % 3) as f64;
+        let (p, q) = (1. + p as f64 / 16., 1. + q as f64 / 16.);
+        let walks = BiasedWalks::new(&*g, p, q, weight);
+        let mut rng = XorShiftRng::seed_from_u64(seed);
+        let batch = walks.walks(2, 8, &mut rng);
+        assert_eq!(batch.len(), 2 * g.node_count());
+        for walk in &batch {
+            assert!(!walk.is_empty() && walk.len() <= 8);
+            for w in walk.windows(2) {
+                assert!(g.edges_connecting(w[0], w[1]).any(|e| weight(e) > 0.));
+            }
+            if walk.len() < 8 {
+                let last = *walk.last().unwrap();
+                assert!(g.edges(last).all(|e| weight(e) == 0.));
+            }
+        }
+        true
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn biased_walks_extremes() {
+    use petgraph::visit::BiasedWalks;
+
+    // a triangle 0, 1, 2 with a tail 2 - 3 - 4
+    let g = UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)]);
+    let mut rng = rand::thread_rng();
+
+    // with a tiny p, a walk keeps returning along its first edge
+    let walks = BiasedWalks::new(&g, 1e-12, 1., |_| 1.);
+    for _ in 0..20 {
+        let walk = walks.walk(node_index(2), 10, &mut rng);
+        assert_eq!(walk.len(), 10);
+        for w in walk.windows(3) {
+            assert_eq!(w[0], w[2]);
+        }
+    }
+
+    // with a tiny q and a huge p, a walk from 0 through 2 moves away along
+    // the tail instead of staying in the triangle
+    let walks = BiasedWalks::new(&g, 1e12, 1e-12, |_| 1.);
+    for _ in 0..20 {
+        let walk = walks.walk(node_index(1), 5, &mut rng);
+        if walk[1] == node_index(2) {
+            assert_eq!(&walk[2..], &[node_index(3), node_index(4), node_index(3)]);
+        }
+    }
+    assert!(walks.walk(node_index(0), 0, &mut rng).is_empty());
+}
+
+fn assert_dominating<G>(g: G, set: &HashSet<G::NodeId>)
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Hash + Eq + fmt::Debug,
+{
+    for a in g.node_identifiers() {
+        assert!(
+            set.contains(&a) || g.neighbors(a).any(|b| set.contains(&b)),
+            "{:?} is not dominated",
+            a
+        );
+    }
+}
+
+quickcheck! {
+    fn greedy_dominating_set_(g: UnGraph<(), ()>) -> bool {
+        let set = greedy_dominating_set(&g);
+        assert_dominating(&g, &set);
+        true
+    }
+
+    fn greedy_weighted_dominating_set_(g: UnGraph<u8, ()>) -> bool {
+        let set = greedy_weighted_dominating_set(&g, |n| g[n] as f64);
+        assert_dominating(&g, &set);
+        true
+    }
+}
+
+quickcheck! {
+    fn graph_diff_(g: Graph<u8, u8>, node: usize, edge: usize) -> bool {
+        // key the nodes by their index
+        let g = g.map(|a, &w| (a.index(), w), |_, &w| w);
+        assert!(graph_diff(&g, &g, |w| w.0).is_empty());
+        if g.node_count() == 0 {
+            return true;
+        }
+
+        // remove a node and change an edge and another node
+        let a = node_index(node % g.node_count());
+        let mut h = g.clone();
+        let last = h.node_indices().next_back().unwrap();
+        h[last].1 = h[last].1.wrapping_add(1);
+        let mut changed = None;
+        if g.edge_count() > 0 {
+            let e = edge_index(edge % g.edge_count());
+            h[e] = h[e].wrapping_add(1);
+            let (x, y) = g.edge_endpoints(e).unwrap();
+            changed = Some((x.index(), y.index()));
+        }
+        h.remove_node(a);
+        let diff = graph_diff(&g, &h, |w| w.0);
+        assert_eq!(diff.removed_nodes, vec![a.index()]);
+        assert!(diff.added_nodes.is_empty());
+        assert_eq!(diff.changed_nodes, if last != a { vec![last.index()] } else { vec![] });
+        let removed = g.edges_directed(a, Outgoing).count() + g.edges_directed(a, Incoming).count()
+            - g.edges_connecting(a, a).count();
+        assert_eq!(diff.removed_edges.len(), removed);
+        assert!(diff.removed_edges.iter().all(|&(x, y)| x == a.index() || y == a.index()));
+        assert!(diff.added_edges.is_empty());
+        match changed {
+            Some((x, y)) if x != a.index() && y != a.index() => {
+                assert!(diff.changed_edges.len() <= 1);
+                assert!(diff.changed_edges.iter().all(|&e| e == (x, y)));
+            }
+            _ => assert!(diff.changed_edges.is_empty()),
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn greedy_spanner_(g: Small<UnGraph<(), u8>>, stretch: u8) -> bool {
+        let stretch = 1 + stretch as u32 % 4;
+        let edges = greedy_spanner(&*g, |e| *e.weight() as u32, stretch);
+        let mut spanner = UnGraph::<(), u32>::with_capacity(g.node_count(), edges.len());
+        for _ in g.node_indices() {
+            spanner.add_node(());
+        }
+        for e in &edges {
+            assert!(e.source() != e.target());
+            spanner.add_edge(e.source(), e.target(), *e.weight() as u32);
+        }
+        // every edge of the graph is spanned within the stretch factor
+        for a in g.node_indices() {
+            let dist = dijkstra(&spanner, a, None, |e| *e.weight());
+            for e in g.edges(a) {
+                if e.target() != a {
+                    assert!(dist[&e.target()] <= stretch * *e.weight() as u32);
+                }
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn max_weight_closure_(g: Small<DiGraph<i8, ()>>) -> bool {
+        let (weight, closure) = max_weight_closure(&*g, |n| g[n] as i32);
+        // closed under successors
+        for &a in &closure {
+            for b in g.neighbors(a) {
+                assert!(closure.contains(&b));
+            }
+        }
+        assert_eq!(weight, closure.iter().map(|&n| g[n] as i32).sum::<i32>());
+        // no closure generated from a single node and its descendants is better
+        for a in g.node_indices() {
+            let mut dfs = Dfs::new(&*g, a);
+            let mut sum = 0;
+            while let Some(b) = dfs.next(&*g) {
+                sum += g[b] as i32;
+            }
+            assert!(sum <= weight);
+        }
+        true
+    }
+}
+
+/// Return a copy of `g` without parallel edges.
+fn simple_graph<Ty: EdgeType>(g: &Graph<(), (), Ty>) -> Graph<(), (), Ty> {
+    let mut h = g.map(|_, _| (), |_, _| ());
+    h.clear_edges();
+    for edge in g.edge_references() {
+        h.update_edge(edge.source(), edge.target(), ());
+    }
+    h
+}
+
+quickcheck! {
+    fn subgraph_isomorphisms_iter_(g: Small<DiGraph<(), ()>>, keep: Vec<bool>) -> bool {
+        // Keep the number of embeddings small.
+        let g = simple_graph(&g);
+        let g = g.filter_map(
+            |n, _| if n.index() < 8 { Some(()) } else { None },
+            |_, _| Some(()),
+        );
+        // The subgraph induced by some of the nodes, with pattern node `i`
+        // taken from target node `nodes[i]`.
+        let nodes = g
+            .node_indices()
+            .zip(keep.iter().chain(std::iter::repeat(&false)))
+            .filter(|&(_, &k)| k)
+            .map(|(n, _)| n)
+            .collect::<Vec<_>>();
+        let pattern = g.filter_map(
+            |n, _| if nodes.contains(&n) { Some(()) } else { None },
+            |_, _| Some(()),
+        );
+
+        let mut found = false;
+        for mapping in subgraph_isomorphisms_iter(&pattern, &g) {
+            assert_eq!(mapping.len(), pattern.node_count());
+            let distinct = mapping.iter().collect::<HashSet<_>>();
+            assert_eq!(distinct.len(), mapping.len());
+            for a in pattern.node_indices() {
+                for b in pattern.node_indices() {
+                    assert_eq!(
+                        pattern.contains_edge(a, b),
+                        g.contains_edge(mapping[a.index()], mapping[b.index()])
+                    );
+                }
+            }
+            found |= mapping == nodes;
+        }
+        found
+    }
+}
+
+quickcheck! {
+    fn subgraph_monomorphisms_iter_(
+        g: Small<DiGraph<(), ()>>,
+        keep: Vec<bool>,
+        keep_edges: Vec<bool>
+    ) -> bool {
+        // Keep the number of embeddings small.
+        let g = simple_graph(&g);
+        let g = g.filter_map(
+            |n, _| if n.index() < 7 { Some(()) } else { None },
+            |_, _| Some(()),
+        );
+        // A subgraph on some of the nodes and edges, with pattern node `i`
+        // taken from target node `nodes[i]`.
+        let nodes = g
+            .node_indices()
+            .zip(keep.iter().chain(std::iter::repeat(&false)))
+            .filter(|&(_, &k)| k)
+            .map(|(n, _)| n)
+            .collect::<Vec<_>>();
+        let pattern = g.filter_map(
+            |n, _| if nodes.contains(&n) { Some(()) } else { None },
+            |e, _| if keep_edges.get(e.index()) == Some(&true) { Some(()) } else { None },
+        );
+
+        let mut found = false;
+        for mapping in subgraph_monomorphisms_iter(&pattern, &g) {
+            assert_eq!(mapping.len(), pattern.node_count());
+            let distinct = mapping.iter().collect::<HashSet<_>>();
+            assert_eq!(distinct.len(), mapping.len());
+            for edge in pattern.edge_references() {
+                let a = mapping[edge.source().index()];
+                let b = mapping[edge.target().index()];
+                assert!(g.contains_edge(a, b));
+            }
+            found |= mapping == nodes;
+        }
+        found
+    }
+}
+
+#[test]
+fn graph_hash_isomorphic() {
+    // using small weights so that duplicates are likely
+    fn prop<Ty: EdgeType>(g: Small<Graph<i8, i8, Ty>>) -> bool {
+        let mut rng = rand::thread_rng();
+        let mut map = g.node_indices().collect::<Vec<_>>();
+        rng.shuffle(&mut map);
+        let mut ng = Graph::<_, _, Ty>::with_capacity(g.node_count(), g.edge_count());
+        for _ in g.node_indices() {
+            ng.add_node(0);
+        }
+        for i in g.node_indices() {
+            ng[map[i.index()]] = g[i];
+        }
+        let mut edges = g.edge_indices().collect::<Vec<_>>();
+        rng.shuffle(&mut edges);
+        for i in edges {
+            let (s, t) = g.edge_endpoints(i).unwrap();
+            ng.add_edge(map[s.index()], map[t.index()], g[i]);
+        }
+        assert_eq!(graph_hash(&*g), graph_hash(&ng));
+        assert_eq!(
+            graph_hash_with(&*g, |n| g[n], |e| *e.weight()),
+            graph_hash_with(&ng, |n| ng[n], |e| *e.weight())
+        );
+        true
+    }
+    quickcheck::quickcheck(prop::<Undirected> as fn(_) -> bool);
+    quickcheck::quickcheck(prop::<Directed> as fn(_) -> bool);
+}
+
+quickcheck! {
+    fn automorphism_generators_(g: Small<DiGraph<(), ()>>) -> bool {
+        // Keep the automorphism group small enough to enumerate.
+        let g = simple_graph(&g);
+        let g = g.filter_map(
+            |n, _| if n.index() < 6 { Some(()) } else { None },
+            |_, _| Some(()),
+        );
+        let n = g.node_count();
+        let generators = automorphism_generators(&g)
+            .into_iter()
+            .map(|p| p.into_iter().map(|a| a.index()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        // The closure of the generators is the whole automorphism group.
+        let mut group = HashSet::new();
+        let mut stack = vec![(0..n).collect::<Vec<_>>()];
+        while let Some(p) = stack.pop() {
+            if !group.insert(p.clone()) {
+                continue;
+            }
+            for q in &generators {
+                stack.push(p.iter().map(|&i| q[i]).collect());
+            }
+        }
+        let automorphisms = subgraph_isomorphisms_iter(&g, &g)
+            .map(|p| p.into_iter().map(|a| a.index()).collect::<Vec<_>>())
+            .collect::<HashSet<_>>();
+        assert_eq!(group, automorphisms);
+
+        let orbits = node_orbits(&g);
+        assert_eq!(orbits.iter().map(|orbit| orbit.len()).sum::<usize>(), n);
+        for orbit in &orbits {
+            let expected = orbit.iter().map(|a| a.index()).collect::<HashSet<_>>();
+            let images = automorphisms
+                .iter()
+                .map(|p| p[orbit[0].index()])
+                .collect::<HashSet<_>>();
+            assert_eq!(images, expected);
+        }
+        true
+    }
+}
+
+fn assert_proper_coloring<N, E, Ty: EdgeType>(
+    g: &Graph<N, E, Ty>,
+    coloring: &HashMap<NodeIndex, usize>,
+    colors: usize,
+) {
+    assert_eq!(coloring.len(), g.node_count());
+    assert!(coloring.values().all(|&c| c < colors));
+    for edge in g.edge_references() {
+        if edge.source() != edge.target() {
+            assert_ne!(coloring[&edge.source()], coloring[&edge.target()]);
+        }
+    }
+    // every color is used
+    assert_eq!(coloring.values().collect::<HashSet<_>>().len(), colors);
+}
+
+quickcheck! {
+    fn greedy_color_(g: Graph<(), (), Undirected>) -> bool {
+        let (coloring, colors) = greedy_color(&g);
+        assert_proper_coloring(&g, &coloring, colors);
+        for &strategy in &[ColoringStrategy::Dsatur, ColoringStrategy::LargestFirst] {
+            let (coloring, colors) = greedy_color_with(&g, strategy);
+            assert_proper_coloring(&g, &coloring, colors);
+        }
+        true
+    }
+
+    fn greedy_color_bipartite(g: Small<Graph<(), (), Undirected>>) -> bool {
+        // keep the edges between even and odd nodes
+        let g = g.filter_map(|_, _| Some(()), |e, _| {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            if (a.index() + b.index()) % 2 == 1 { Some(()) } else { None }
+        });
+        let (_, colors) = greedy_color(&g);
+        colors <= 2
+    }
+}
+
+quickcheck! {
+    fn bipartite_partition_(g: Graph<(), (), Undirected>) -> bool {
+        match bipartite_partition(&g) {
+            Ok((left, right)) => {
+                assert_eq!(left.len() + right.len(), g.node_count());
+                for edge in g.edge_references() {
+                    let (a, b) = (edge.source(), edge.target());
+                    assert!(left.contains(&a) != left.contains(&b));
+                    assert!(right.contains(&a) != right.contains(&b));
+                }
+                let (_, colors) = greedy_color(&g);
+                assert!(colors <= 2);
+            }
+            Err(Error::NotBipartite(cycle)) => {
+                assert!(cycle.len() % 2 == 1);
+                assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len());
+                for (i, &a) in cycle.iter().enumerate() {
+                    assert!(g.contains_edge(a, cycle[(i + 1) % cycle.len()]));
+                }
+            }
+            Err(err) => panic!("unexpected error: {}", err),
+        }
+        true
+    }
+}
+
+/// Return the largest distance between the positions of adjacent nodes in
+/// `order`.
+fn bandwidth<N, E, Ty: EdgeType>(g: &Graph<N, E, Ty>, order: &[NodeIndex]) -> usize {
+    let mut position = vec![0; g.node_count()];
+    for (p, n) in order.iter().enumerate() {
+        position[n.index()] = p;
+    }
+    g.edge_references()
+        .map(|edge| {
+            let (a, b) = (
+                position[edge.source().index()],
+                position[edge.target().index()],
+            );
+            if a > b {
+                a - b
+            } else {
+                b - a
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+quickcheck! {
+    fn reverse_cuthill_mckee_(g: Graph<(), (), Undirected>) -> bool {
+        let order = reverse_cuthill_mckee(&g);
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, g.node_indices().collect::<Vec<_>>());
+        // a path is ordered along the path
+        let path = UnGraph::<(), ()>::from_edges(
+            order.windows(2).map(|w| (w[0].index() as u32, w[1].index() as u32)),
+        );
+        let order = reverse_cuthill_mckee(&path);
+        bandwidth(&path, &order) <= 1
+    }
+}
+
+quickcheck! {
+    fn lex_bfs_(edges: Vec<(u8, u8)>) -> bool {
+        let g = UnGraph::<(), ()>::from_edges(edges.iter().map(|&(a, b)| (a as u32 % 16, b as u32 % 16)));
+        let order = lex_bfs(&g);
+        let mut position = vec![0; g.node_count()];
+        for (p, n) in order.iter().enumerate() {
+            position[n.index()] = p;
+        }
+        let mut sorted = order.clone();
+        sorted.sort();
+        assert_eq!(sorted, g.node_indices().collect::<Vec<_>>());
+        let adjacent = |a: usize, b: usize| a != b && g.contains_edge(order[a], order[b]);
+        // The four point condition: if a < b < c with ac an edge and ab not
+        // an edge, then some d < a has db an edge and dc not an edge.
+        let n = order.len();
+        for a in 0..n {
+            for b in a + 1..n {
+                if adjacent(a, b) {
+                    continue;
+                }
+                for c in b + 1..n {
+                    if adjacent(a, c) {
+                        assert!((0..a).any(|d| adjacent(d, b) && !adjacent(d, c)));
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn toposort_by_key_(edges: Vec<(u8, u8)>) -> bool {
+        let g = DiGraph::<(), ()>::from_edges(edges.iter().map(|&(a, b)| (a as u32 % 16, b as u32 % 16)));
+        // prefer larger node indices
+        let key = |n: NodeIndex| std::cmp::Reverse(n.index());
+        match toposort_by_key(&g, key) {
+            Ok(order) => {
+                assert!(!is_cyclic_directed(&g));
+                assert!(is_topo_order(&g, &order));
+                // every node is the largest of those that are ready
+                let mut placed = vec![false; g.node_count()];
+                for &a in &order {
+                    for b in g.node_indices() {
+                        if !placed[b.index()]
+                            && g.neighbors_directed(b, Incoming).all(|c| placed[c.index()])
+                        {
+                            assert!(b.index() <= a.index());
+                        }
+                    }
+                    placed[a.index()] = true;
+                }
+            }
+            Err(cycle) => {
+                let a = cycle.node_id().unwrap();
+                assert!(g
+                    .neighbors(a)
+                    .any(|b| has_path_connecting(&g, b, a, None)));
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn topological_generations_(edges: Vec<(u8, u8)>) -> bool {
+        let g = DiGraph::<(), ()>::from_edges(edges.iter().map(|&(a, b)| (a as u32 % 16, b as u32 % 16)));
+        match topological_generations(&g) {
+            Ok(generations) => {
+                let mut generation = vec![0; g.node_count()];
+                for (i, nodes) in generations.iter().enumerate() {
+                    assert!(!nodes.is_empty());
+                    for n in nodes {
+                        generation[n.index()] = i;
+                    }
+                }
+                let order = generations.concat();
+                assert!(is_topo_order(&g, &order));
+                // every node is in the generation after its last predecessor
+                for n in g.node_indices() {
+                    let expected = g
+                        .neighbors_directed(n, Incoming)
+                        .map(|m| generation[m.index()] + 1)
+                        .max()
+                        .unwrap_or(0);
+                    assert_eq!(generation[n.index()], expected);
+                }
+            }
+            Err(cycle) => {
+                let a = cycle.node_id().unwrap();
+                assert!(g
+                    .neighbors(a)
+                    .any(|b| has_path_connecting(&g, b, a, None)));
+            }
+        }
+        true
+    }
+}
+
+quickcheck! {
+    fn coffman_graham_(edges: Vec<(u8, u8)>, width: u8) -> bool {
+        // edges from smaller to larger nodes make the graph acyclic
+        let g = DiGraph::<(), ()>::from_edges(
+            edges
+                .iter()
+                .map(|&(a, b)| (a as u32 % 16, b as u32 % 16))
+                .filter(|&(a, b)| a != b)
+                .map(|(a, b)| (a.min(b), a.max(b))),
+        );
+        let width = width as usize % 4 + 1;
+        let layers = coffman_graham(&g, width).unwrap();
+        let mut layer = vec![0; g.node_count()];
+        for (i, nodes) in layers.iter().enumerate() {
+            assert!(!nodes.is_empty() && nodes.len() <= width);
+            for n in nodes {
+                layer[n.index()] = i;
+            }
+        }
+        let mut sorted = layers.concat();
+        sorted.sort();
+        assert_eq!(sorted, g.node_indices().collect::<Vec<_>>());
+        for edge in g.edge_references() {
+            assert!(layer[edge.source().index()] < layer[edge.target().index()]);
+        }
+        // unbounded, the layers are as many as the nodes on a longest path
+        let unbounded = coffman_graham(&g, g.node_count().max(1)).unwrap();
+        assert_eq!(unbounded.len(), topological_generations(&g).unwrap().len());
+        true
+    }
+}
+
+#[test]
+fn coffman_graham_cycle() {
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 1)]);
+    let cycle = coffman_graham(&g, 2).unwrap_err();
+    let a = cycle.node_id().unwrap();
+    assert!(a.index() == 1 || a.index() == 2);
+}
+
+quickcheck! {
+    fn dilworth_(edges: Vec<(u8, u8)>) -> bool {
+        // edges from smaller to larger nodes make the graph acyclic
+        let g = DiGraph::<(), ()>::from_edges(
+            edges
+                .iter()
+                .map(|&(a, b)| (a as u32 % 10, b as u32 % 10))
+                .filter(|&(a, b)| a != b)
+                .map(|(a, b)| (a.min(b), a.max(b))),
+        );
+        let reaches = |a: NodeIndex, b: NodeIndex| a != b && has_path_connecting(&g, a, b, None);
+        let chains = min_chain_cover(&g).unwrap();
+        let mut nodes = chains.concat();
+        nodes.sort();
+        assert_eq!(nodes, g.node_indices().collect::<Vec<_>>());
+        for chain in &chains {
+            assert!(chain.windows(2).all(|w| reaches(w[0], w[1])));
+        }
+        let antichain = max_antichain(&g).unwrap();
+        assert_eq!(antichain.len(), chains.len());
+        for &a in &antichain {
+            assert!(antichain.iter().all(|&b| !reaches(a, b)));
+        }
+        // no antichain is larger
+        let n = g.node_count();
+        for set in 0..1u32 << n {
+            let members = g.node_indices().filter(|n| set >> n.index() & 1 == 1);
+            let members = members.collect::<Vec<_>>();
+            if members.len() > antichain.len() {
+                assert!(members.iter().any(|&a| members.iter().any(|&b| reaches(a, b))));
+            }
+        }
+        true
+    }
+}
+
+#[test]
+fn dilworth_cycle() {
+    let g = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 1)]);
+    assert_eq!(
+        min_chain_cover(&g).unwrap_err(),
+        Error::Cycle(NodeIndex::new(1))
+    );
+    assert_eq!(
+        max_antichain(&g).unwrap_err(),
+        Error::Cycle(NodeIndex::new(1))
+    );
+}
+
+/// Count the walks of `k` edges from `a` to `b` by going through them.
+fn naive_count_walks<G>(g: G, a: G::NodeId, b: G::NodeId, k: usize) -> usize
+where
+    G: IntoNeighbors,
+    G::NodeId: PartialEq,
+{
+    if k == 0 {
+        return (a == b) as usize;
+    }
+    g.neighbors(a)
+        .map(|c| naive_count_walks(g, c, b, k - 1))
+        .sum()
+}
+
+quickcheck! {
+    fn count_walks_(g: Small<Graph<(), (), Undirected>>, k: u8) -> bool {
+        fn check<Ty: EdgeType>(g: &Graph<(), (), Ty>, k: usize) {
+            let walks = count_walks(g, k);
+            for a in g.node_indices() {
+                for b in g.node_indices() {
+                    assert_eq!(walks[a.index()][b.index()], naive_count_walks(g, a, b, k));
+                }
+            }
+        }
+        let k = k as usize % 4;
+        check(&*g, k);
+        check(&(*g).clone().into_edge_type::<Directed>(), k);
+        true
+    }
+}
@@ -0,0 +1,196 @@
+#![cfg(feature = "rayon")]
+
+extern crate petgraph;
+extern crate rayon;
+
+use petgraph::algo::{par_hits, par_katz_centrality, par_page_rank, Error};
+use petgraph::csr::Csr;
+use petgraph::prelude::*;
+use petgraph::visit::{
+    EdgeRef, IntoEdgeReferences, IntoNodeReferences, IntoParallelEdgeReferences,
+    IntoParallelNodeReferences, NodeIndexable, NodeRef,
+};
+use rayon::prelude::*;
+
+#[test]
+fn graph() {
+    let mut g = DiGraph::<i32, i32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 0, 3), (2, 2, 4)]);
+    for (i, w) in g.node_weights_mut().enumerate() {
+        *w = i as i32;
+    }
+    let nodes = g
+        .par_node_references()
+        .map(|(i, &w)| (i, w))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        nodes,
+        g.node_references()
+            .map(|(i, &w)| (i, w))
+            .collect::<Vec<_>>()
+    );
+    let par_edges = g
+        .par_edge_references()
+        .map(|e| (e.id(), e.source(), e.target(), *e.weight()))
+        .collect::<Vec<_>>();
+    let seq_edges = g
+        .edge_references()
+        .map(|e| (e.id(), e.source(), e.target(), *e.weight()))
+        .collect::<Vec<_>>();
+    assert_eq!(par_edges, seq_edges);
+
+    g.par_node_weights_mut().for_each(|w| *w *= 10);
+    g.par_edge_weights_mut().for_each(|w| *w += 1);
+    assert_eq!(g.node_weights().cloned().collect::<Vec<_>>(), [0, 10, 20]);
+    assert_eq!(g.edge_weights().cloned().collect::<Vec<_>>(), [2, 3, 4, 5]);
+}
+
+#[test]
+fn stable_graph() {
+    let mut g = StableGraph::<i32, i32>::new();
+    let a = g.add_node(1);
+    let b = g.add_node(2);
+    let c = g.add_node(3);
+    g.add_edge(a, b, 1);
+    let bc = g.add_edge(b, c, 2);
+    g.add_edge(c, a, 3);
+    g.remove_edge(bc);
+    g.remove_node(b);
+
+    let nodes = g
+        .par_node_references()
+        .map(|(i, &w)| (i, w))
+        .collect::<Vec<_>>();
+    assert_eq!(nodes, [(a, 1), (c, 3)]);
+    let par_edges = g
+        .par_edge_references()
+        .map(|e| (e.id(), e.source(), e.target(), *e.weight()))
+        .collect::<Vec<_>>();
+    let seq_edges = g
+        .edge_references()
+        .map(|e| (e.id(), e.source(), e.target(), *e.weight()))
+        .collect::<Vec<_>>();
+    assert_eq!(par_edges, seq_edges);
+
+    g.par_node_weights_mut().for_each(|w| *w *= 10);
+    g.par_edge_weights_mut().for_each(|w| *w += 1);
+    assert_eq!(g[a], 10);
+    assert_eq!(g[c], 30);
+    assert_eq!(g.edge_weights().cloned().collect::<Vec<_>>(), [4]);
+}
+
+#[test]
+fn csr() {
+    let mut g: Csr<i32, i32, Undirected> = Csr::with_nodes(4);
+    g.add_edge(0, 1, 1);
+    g.add_edge(1, 2, 2);
+    g.add_edge(3, 1, 3);
+    g.add_edge(2, 2, 4);
+
+    let nodes = g
+        .par_node_references()
+        .map(|(i, &w)| (i, w))
+        .collect::<Vec<_>>();
+    assert_eq!(nodes, [(0, 0), (1, 0), (2, 0), (3, 0)]);
+    let par_edges = g
+        .par_edge_references()
+        .map(|e| (e.source(), e.target(), *e.weight()))
+        .collect::<Vec<_>>();
+    let seq_edges = g
+        .edge_references()
+        .map(|e| (e.source(), e.target(), *e.weight()))
+        .collect::<Vec<_>>();
+    assert_eq!(par_edges, seq_edges);
+    assert_eq!(par_edges.len(), 4);
+
+    g.par_node_weights_mut().for_each(|w| *w = 7);
+    assert!(g.par_node_references().all(|(_, &w)| w == 7));
+    g.par_edge_weights_mut().for_each(|w| *w *= 2);
+    assert_eq!(g.edges(0).map(|e| *e.weight()).collect::<Vec<_>>(), [2]);
+}
+
+/// The node and edge indices from the parallel traits, in the order of the
+/// sequential ones.
+fn check_parallel_traits<G>(g: G)
+where
+    G: IntoParallelNodeReferences + IntoParallelEdgeReferences + NodeIndexable + Sync,
+{
+    let nodes = g
+        .par_node_references()
+        .map(|n| g.to_index(n.id()))
+        .collect::<Vec<_>>();
+    let seq_nodes = g
+        .node_references()
+        .map(|n| g.to_index(n.id()))
+        .collect::<Vec<_>>();
+    assert_eq!(nodes, seq_nodes);
+    let edges = g
+        .par_edge_references()
+        .map(|e| (g.to_index(e.source()), g.to_index(e.target())))
+        .collect::<Vec<_>>();
+    let seq_edges = g
+        .edge_references()
+        .map(|e| (g.to_index(e.source()), g.to_index(e.target())))
+        .collect::<Vec<_>>();
+    assert_eq!(edges, seq_edges);
+}
+
+#[test]
+fn parallel_traits() {
+    let edges = [(0, 1), (1, 2), (3, 1), (2, 2)];
+    let g = UnGraph::<(), ()>::from_edges(&edges);
+    check_parallel_traits(&g);
+    let mut stable = StableGraph::<(), ()>::from_edges(&edges);
+    stable.remove_node(0.into());
+    check_parallel_traits(&stable);
+    let mut csr = Csr::<(), (), Undirected>::with_nodes(4);
+    for &(a, b) in &edges {
+        csr.add_edge(a, b, ());
+    }
+    check_parallel_traits(&csr);
+}
+
+#[test]
+fn centrality_unconverged() {
+    let cycle = DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+    assert_eq!(
+        par_page_rank(&cycle, 0.85, 1e-12, 5),
+        Err(Error::Unconverged)
+    );
+    assert!(par_page_rank(&cycle, 0.85, 1e-12, 1000).is_ok());
+    // The first iteration moves all the hub score to the source of an edge.
+    let edge = DiGraph::<(), ()>::from_edges(&[(0, 1)]);
+    assert_eq!(par_hits(&edge, 1e-12, 1), Err(Error::Unconverged));
+    assert!(par_hits(&edge, 1e-12, 10).is_ok());
+    // Katz centrality diverges when alpha is at least one over the largest
+    // eigenvalue, which is one for a cycle.
+    assert_eq!(
+        par_katz_centrality(&cycle, 1., 1., 1e-12, 1000),
+        Err(Error::Unconverged)
+    );
+    assert!(par_katz_centrality(&cycle, 0.5, 1., 1e-12, 1000).is_ok());
+}
+
+#[test]
+fn par_from_parallel_arrays() {
+    let mut seed = 2221u32;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed % 40
+    };
+    let sources: Vec<u32> = (0..300).map(|_| next()).collect();
+    let targets: Vec<u32> = (0..300).map(|_| next()).collect();
+    let weights: Vec<u32> = (0..300).collect();
+    let g = DiGraph::<(), u32>::from_parallel_arrays(&sources, &targets, weights.clone());
+    let h = DiGraph::<(), u32>::par_from_parallel_arrays(&sources, &targets, weights);
+    assert_eq!(g.node_count(), h.node_count());
+    assert!(g.edge_references().eq(h.edge_references()));
+    for a in g.node_indices() {
+        for &dir in &[Outgoing, Incoming] {
+            assert!(g.edges_directed(a, dir).eq(h.edges_directed(a, dir)));
+        }
+    }
+    let g = UnGraph::<(), ()>::par_from_parallel_arrays::<u32>(&[], &[], vec![]);
+    assert_eq!((g.node_count(), g.edge_count()), (0, 0));
+}
@@ -427,3 +427,163 @@ fn weights_mut_iterator() {
     assert_eq!(gr.node_weights_mut().count(), gr.node_count());
     assert_eq!(gr.edge_weights_mut().count(), gr.edge_count());
 }
+
+#[test]
+fn subdivide_and_contract_edge() {
+    let mut g = StableGraph::<u32, u32>::new();
+    let a = g.add_node(1);
+    let b = g.add_node(2);
+    let c = g.add_node(4);
+    let ab = g.add_edge(a, b, 10);
+    let bc = g.add_edge(b, c, 20);
+    g.add_edge(c, b, 30);
+
+    let x = g.subdivide_edge(ab, 8).unwrap();
+    assert_eq!((g.node_count(), g.edge_count()), (4, 4));
+    assert!(!g.contains_edge(a, b));
+    assert!(g.contains_edge(a, x) && g.contains_edge(x, b));
+
+    assert_eq!(g.contract_edge(bc, |x, y| *x += y), Some(b));
+    assert_eq!(g[b], 6);
+    assert!(!g.contains_node(c));
+    assert_eq!((g.node_count(), g.edge_count()), (3, 3));
+    let e = g.find_edge(b, b).unwrap();
+    assert_eq!(g[e], 30);
+    assert!(g.contains_edge(x, b));
+}
+
+#[test]
+fn try_add() {
+    use petgraph::stable_graph::GraphError;
+
+    let mut g = StableGraph::<(), (), Directed, u8>::with_capacity(0, 0);
+    for _ in 0..255 {
+        g.try_add_node(()).unwrap();
+    }
+    assert_eq!(g.try_add_node(()), Err(GraphError::NodeIxLimit));
+    // vacant indices are reused
+    g.remove_node(n(3));
+    assert_eq!(g.try_add_node(()), Ok(n(3)));
+
+    let a = n(0);
+    for _ in 0..255 {
+        g.try_add_edge(a, a, ()).unwrap();
+    }
+    assert_eq!(g.try_add_edge(a, a, ()), Err(GraphError::EdgeIxLimit));
+    g.remove_edge(e(7));
+    assert_eq!(g.try_add_edge(a, a, ()), Ok(e(7)));
+
+    g.remove_node(n(3));
+    assert_eq!(g.try_add_edge(a, n(3), ()), Err(GraphError::NodeMissed(3)));
+}
+
+#[test]
+fn shrink_to_fit() {
+    let mut g = StableGraph::<usize, usize>::new();
+    let nodes: Vec<_> = (0..50).map(|i| g.add_node(i)).collect();
+    for i in 0..200 {
+        g.add_edge(nodes[i % 50], nodes[i * 7 % 50], i);
+    }
+    // remove the second half of the nodes and every other edge
+    for &a in &nodes[25..] {
+        g.remove_node(a);
+    }
+    for i in (0..200).step_by(2) {
+        g.remove_edge(e(i));
+    }
+    g.remove_node(nodes[10]);
+    let before = g.memory_usage();
+    let graph = g.clone();
+
+    g.shrink_to_fit();
+    let after = g.memory_usage();
+    assert!(after.vacant < before.vacant);
+    assert_eq!(after.unused, 0);
+    assert_eq!(after.nodes, before.nodes);
+    assert_eq!(after.edges, before.edges);
+    assert_eq!(g.node_bound(), 25);
+    assert_equal(g.node_references(), graph.node_references());
+    assert_equal(
+        g.edge_references()
+            .map(|e| (e.id(), e.source(), e.target())),
+        graph
+            .edge_references()
+            .map(|e| (e.id(), e.source(), e.target())),
+    );
+
+    // vacant indices are still reused
+    assert_eq!(g.add_node(10), nodes[10]);
+    let a = g.add_node(25);
+    assert_eq!(a, nodes[25]);
+    let edge_bound = g.edge_bound();
+    g.add_edge(a, a, 0);
+    assert_eq!(g.edge_bound(), edge_bound);
+}
+
+#[test]
+fn drain() {
+    let mut g = StableGraph::<u32, u32>::new();
+    let n: Vec<_> = (0..5).map(|i| g.add_node(i)).collect();
+    for i in 0..5 {
+        g.add_edge(n[i], n[(i + 1) % 5], i as u32);
+        g.add_edge(n[i], n[(i + 2) % 5], 10 + i as u32);
+    }
+    g.remove_edge(e(3));
+
+    let drained = g.drain_edges(|e| *e.weight() >= 10);
+    assert_eq!(drained.len(), 4);
+    assert_eq!(drained[0], (n[0], n[2], 10));
+    assert_eq!(drained[3], (n[4], n[1], 14));
+    assert_eq!(g.edge_count(), 5);
+    assert!(g.edge_weights().all(|&w| w < 10));
+
+    assert_eq!(g.drain_nodes(|a, _| a == n[1] || a == n[3]), vec![1, 3]);
+    assert_eq!(g.node_count(), 3);
+    assert_equal(g.node_indices(), vec![n[0], n[2], n[4]]);
+    assert_equal(g.edge_weights().cloned(), vec![4]);
+    assert_eq!(g.add_node(5), n[3]);
+}
+
+#[test]
+fn from_parallel_arrays() {
+    let g =
+        StableGraph::<(), u32>::from_parallel_arrays(&[0u32, 3, 1], &[1u32, 3, 0], vec![1, 2, 3]);
+    assert_eq!(g.node_count(), 4);
+    assert_eq!(g.edge_count(), 3);
+    assert_equal(g.neighbors(n(0)), vec![n(1)]);
+    assert_equal(g.neighbors_directed(n(0), Incoming), vec![n(1)]);
+    assert_eq!(g.find_edge(n(3), n(3)), Some(e(1)));
+    let mut g = g;
+    g.remove_node(n(2));
+    assert_eq!(g.add_node(()), n(2));
+}
+
+#[test]
+fn index_by_endpoints() {
+    let mut g = StableGraph::<(), i32>::from_edges(&[(0, 1, 10), (1, 2, 20), (2, 0, 30)]);
+    g.remove_node(n(1));
+    assert_eq!(g[(n(2), n(0))], 30);
+    g[(n(2), n(0))] += 1;
+    assert_eq!(g.edge_weight_between(n(2), n(0)), Some(&31));
+    assert_eq!(g.edge_weight_between(n(0), n(1)), None);
+    assert_eq!(g.edge_weight_between_mut(n(1), n(2)), None);
+}
+
+#[test]
+fn graph_equality() {
+    let mut a = StableGraph::<u32, u32>::from_edges(&[(0, 1, 1), (1, 2, 2), (2, 3, 3)]);
+    let mut b = a.clone();
+    assert_eq!(a, b);
+    a.remove_node(n(1));
+    assert_ne!(a, b);
+    b.remove_edge(e(0));
+    b.remove_edge(e(1));
+    b.remove_node(n(1));
+    assert_eq!(a, b);
+    a.add_node(7);
+    b.add_node(7);
+    assert_eq!(a, b);
+    a.add_edge(n(1), n(0), 4);
+    b.add_edge(n(0), n(1), 4);
+    assert_ne!(a, b);
+}
@@ -0,0 +1,158 @@
+extern crate petgraph;
+
+use petgraph::algo::{dijkstra, kosaraju_scc, tarjan_scc, toposort};
+use petgraph::prelude::*;
+use petgraph::static_graph::StaticGraph;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNeighbors, NodeDegrees};
+use petgraph::EdgeType;
+
+fn sorted<T: Ord>(mut v: Vec<T>) -> Vec<T> {
+    v.sort();
+    v
+}
+
+/// Check that `s` has the same nodes and edges as `g`, with the same indices.
+fn assert_same_graph<Ty: EdgeType>(g: &Graph<i32, i32, Ty>, s: &StaticGraph<i32, i32, Ty>) {
+    assert_eq!(s.node_count(), g.node_count());
+    assert_eq!(s.edge_count(), g.edge_count());
+    for a in g.node_indices() {
+        assert_eq!(s[a], g[a]);
+        assert_eq!(
+            sorted(s.neighbors(a).collect()),
+            sorted(g.neighbors(a).collect())
+        );
+        for &dir in &[Outgoing, Incoming] {
+            let edges = |edges: Vec<(NodeIndex, NodeIndex, EdgeIndex, i32)>| sorted(edges);
+            assert_eq!(
+                edges(
+                    s.edges_directed(a, dir)
+                        .map(|e| (e.source(), e.target(), e.id(), *e.weight()))
+                        .collect()
+                ),
+                edges(
+                    g.edges_directed(a, dir)
+                        .map(|e| (e.source(), e.target(), e.id(), *e.weight()))
+                        .collect()
+                )
+            );
+            assert_eq!(
+                s.degree_directed(a, dir),
+                g.neighbors_directed(a, dir).count()
+            );
+        }
+    }
+    for e in g.edge_indices() {
+        assert_eq!(s.edge_endpoints(e), g.edge_endpoints(e));
+        assert_eq!(s[e], g[e]);
+    }
+    for (e, f) in s.edge_references().zip(g.edge_references()) {
+        assert_eq!(e.id(), f.id());
+        assert_eq!((e.source(), e.target()), (f.source(), f.target()));
+    }
+}
+
+fn example_edges() -> Vec<(u32, u32, i32)> {
+    vec![
+        (0, 3, 1),
+        (0, 1, 2),
+        (1, 2, 3),
+        (2, 0, 4),
+        (2, 2, 5),
+        (3, 4, 6),
+        (4, 3, 7),
+        (1, 2, 8),
+        (5, 4, 9),
+    ]
+}
+
+#[test]
+fn directed() {
+    let g = DiGraph::<i32, i32>::from_edges(example_edges());
+    let s = StaticGraph::from(&g).with_incoming_edges();
+    assert_same_graph(&g, &s);
+    assert_same_graph(&g, &s.clone().with_sorted_neighbors());
+    assert_same_graph(&g, &StaticGraph::from(g.clone()).with_incoming_edges());
+
+    let mut sccs = kosaraju_scc(&s);
+    let mut expected = tarjan_scc(&g);
+    for scc in sccs.iter_mut().chain(&mut expected) {
+        scc.sort();
+    }
+    assert_eq!(sorted(sccs), sorted(expected));
+
+    let a = NodeIndex::new(0);
+    let distances = dijkstra(&s, a, None, |e| *e.weight());
+    assert_eq!(distances, dijkstra(&g, a, None, |e| *e.weight()));
+}
+
+#[test]
+fn undirected() {
+    let g = UnGraph::<i32, i32>::from_edges(example_edges());
+    let s = StaticGraph::from(&g);
+    assert!(s.has_incoming_edges());
+    assert_same_graph(&g, &s);
+    assert_same_graph(&g, &s.with_sorted_neighbors().with_incoming_edges());
+}
+
+#[test]
+fn sorted_neighbors() {
+    let g = DiGraph::<i32, i32>::from_edges(example_edges());
+    let s = StaticGraph::from(&g)
+        .with_sorted_neighbors()
+        .with_incoming_edges();
+    assert!(s.has_sorted_neighbors());
+    let n = NodeIndex::new;
+    assert_eq!(s.neighbors_slice(n(0)), &[n(1), n(3)]);
+    assert_eq!(s.neighbors_slice(n(1)), &[n(2), n(2)]);
+    assert_eq!(s.neighbors_slice_directed(n(3), Incoming), &[n(0), n(4)]);
+    assert_eq!(s.neighbors_slice_directed(n(4), Incoming), &[n(3), n(5)]);
+    assert_eq!(s.find_edge(n(1), n(2)), Some(EdgeIndex::new(2)));
+    assert_eq!(s.find_edge(n(2), n(2)), Some(EdgeIndex::new(4)));
+    assert_eq!(s.find_edge(n(2), n(1)), None);
+    for a in g.node_indices() {
+        for b in g.node_indices() {
+            assert_eq!(s.contains_edge(a, b), g.contains_edge(a, b));
+        }
+    }
+}
+
+#[test]
+fn incoming_edges_not_stored() {
+    let g = DiGraph::<i32, i32>::from_edges(example_edges());
+    let s = StaticGraph::from(&g);
+    assert!(!s.has_incoming_edges());
+    assert_same_graph(&g, &s);
+    assert_eq!(toposort(&s, None), toposort(&g, None));
+}
+
+#[test]
+#[should_panic]
+fn incoming_slice_not_stored() {
+    let g = DiGraph::<i32, i32>::from_edges(example_edges());
+    let s = StaticGraph::from(&g);
+    s.neighbors_slice_directed(NodeIndex::new(0), Incoming);
+}
+
+#[cfg(feature = "stable_graph")]
+#[test]
+fn from_stable_graph() {
+    let mut g = StableGraph::<_, _>::new();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let c = g.add_node("c");
+    let d = g.add_node("d");
+    g.add_edge(a, b, 1);
+    g.add_edge(b, c, 2);
+    g.add_edge(c, d, 3);
+    g.add_edge(d, b, 4);
+    g.remove_node(a);
+
+    // the nodes and edges are renumbered without holes
+    let s: StaticGraph<_, _> = StaticGraph::from_graph(&g);
+    assert_eq!(s.node_weights(), &["b", "c", "d"]);
+    assert_eq!(s.edge_weights(), &[2, 3, 4]);
+    let (b, d) = (NodeIndex::new(0), NodeIndex::new(2));
+    assert_eq!(s.edge_endpoints(EdgeIndex::new(2)), Some((d, b)));
+    let order = toposort(&s, None);
+    assert!(order.is_err());
+}